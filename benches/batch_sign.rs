@@ -0,0 +1,37 @@
+//! Compares signing many messages with `BatchSigner` with calling the one-shot signers in a loop
+//!
+//! Run with `cargo bench --bench batch_sign`.
+
+use std::time::Instant;
+use toy_secp256k1::batch_sign::BatchSigner;
+use toy_secp256k1::{ecdsa, schnorr, Keypair, Message, SecretKey};
+
+fn main() {
+    let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap());
+    let signer = BatchSigner::new(&keypair);
+    for &len in &[1, 10, 100] {
+        let msgs = (0..len).map(|i: u32| {
+            let mut msg = [0; 32];
+            msg[..4].copy_from_slice(&i.to_be_bytes());
+            msg
+        }).collect::<Vec<_>>();
+
+        let start = Instant::now();
+        let one_shot = msgs.iter().map(|msg| ecdsa::sign(&Message::from_digest(*msg), keypair.secret_key())).collect::<Vec<_>>();
+        let sequential = start.elapsed();
+        let start = Instant::now();
+        let batch = signer.sign_ecdsa_many(&msgs);
+        let batched = start.elapsed();
+        assert_eq!(one_shot, batch);
+        println!("{:>4} ECDSA signatures:   one-shot {:?}, batch {:?}", len, sequential, batched);
+
+        let start = Instant::now();
+        let one_shot = msgs.iter().map(|msg| schnorr::sign(&Message::from_digest(*msg), &keypair, &[0; 32])).collect::<Vec<_>>();
+        let sequential = start.elapsed();
+        let start = Instant::now();
+        let batch = signer.sign_schnorr_many(&msgs, &[0; 32]);
+        let batched = start.elapsed();
+        assert_eq!(one_shot, batch);
+        println!("{:>4} BIP340 signatures:  one-shot {:?}, batch {:?}", len, sequential, batched);
+    }
+}
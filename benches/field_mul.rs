@@ -0,0 +1,26 @@
+//! Measures `Zp::mul_many`, run it with and without the `simd` feature to compare the AVX2
+//! backend with the scalar one
+//!
+//! Run with `cargo bench --bench field_mul [--features simd]`.
+
+use std::time::Instant;
+use toy_secp256k1::{Scalar, Zp, G};
+
+fn main() {
+    let points = (1..=1000u64).map(|i| G * Scalar::from(i)).collect::<Vec<_>>();
+    let a = points.iter().map(|point| point.x()).collect::<Vec<_>>();
+    let b = points.iter().map(|point| point.y()).collect::<Vec<_>>();
+
+    let start = Instant::now();
+    let one_by_one = a.iter().zip(&b).map(|(a, b)| *a * *b).collect::<Vec<_>>();
+    println!("1000 products with the operator: {:?}", start.elapsed());
+
+    let rounds = 1000;
+    let start = Instant::now();
+    let mut products = Vec::new();
+    for _ in 0..rounds {
+        products = Zp::mul_many(&a, &b);
+    }
+    println!("1000 products with mul_many:     {:?}", start.elapsed() / rounds);
+    assert_eq!(products, one_by_one);
+}
@@ -0,0 +1,27 @@
+//! Compares lifting many x coordinates with `Point::lift_x_batch` with calling `Point::lift_x`
+//! in a loop
+//!
+//! Both do the same work. The loop is still about 2x slower because the generic `lift_x` gets
+//! instantiated in this crate where the field helpers of the library can't be inlined without
+//! LTO.
+//!
+//! Run with `cargo bench --bench lift_x`.
+
+use std::time::Instant;
+use toy_secp256k1::{Point, Zp, U256};
+
+fn main() {
+    for &len in &[10, 100, 1000] {
+        // mix of valid and invalid x coordinates
+        let xs = (0..len).map(|i: u64| Zp::wrapping_from(U256::from(i * 0x9E37_79B9_7F4A_7C15))).collect::<Vec<_>>();
+
+        let start = Instant::now();
+        let one_by_one = xs.iter().map(|x| Point::lift_x(*x)).collect::<Vec<_>>();
+        let sequential = start.elapsed();
+        let start = Instant::now();
+        let batch = Point::lift_x_batch(&xs);
+        let batched = start.elapsed();
+        assert_eq!(one_by_one, batch);
+        println!("{:>5} x coordinates: lift_x {:?}, batch {:?}", len, sequential, batched);
+    }
+}
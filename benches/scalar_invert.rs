@@ -0,0 +1,22 @@
+//! Compares inverting many scalars with `Scalar::batch_invert` with inverting them one by one
+//!
+//! Run with `cargo bench --bench scalar_invert`.
+
+use std::time::Instant;
+use toy_secp256k1::Scalar;
+
+fn main() {
+    for &len in &[10, 100, 1000] {
+        let scalars = (1..=len).map(|i: u64| Scalar::from(i.wrapping_mul(0x9E37_79B9_7F4A_7C15))).collect::<Vec<_>>();
+
+        let start = Instant::now();
+        let one_by_one = scalars.iter().map(|scalar| scalar.multiplicative_inverse()).collect::<Vec<_>>();
+        let sequential = start.elapsed();
+        let start = Instant::now();
+        let mut batch = scalars.clone();
+        Scalar::batch_invert(&mut batch).unwrap();
+        let batched = start.elapsed();
+        assert_eq!(one_by_one, batch);
+        println!("{:>5} scalars: one by one {:?}, batch {:?}", len, sequential, batched);
+    }
+}
@@ -0,0 +1,61 @@
+//! Compares batch verification of BIP340 signatures with verifying them one by one
+//!
+//! Run with `cargo bench --bench schnorr_batch`.
+
+use std::time::Instant;
+use toy_secp256k1::{schnorr, Keypair, Message, SecretKey};
+
+struct Rng(u64);
+
+impl rand_core::RngCore for Rng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+// Only for benchmarking, the generator is not secure
+impl rand_core::CryptoRng for Rng {}
+
+fn main() {
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+    for &len in &[1, 10, 100] {
+        let items = (0..len)
+            .map(|i| {
+                let mut secret = [0; 32];
+                secret[24..].copy_from_slice(&(i as u64 + 1).to_be_bytes());
+                let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&secret).unwrap());
+                let msg = [i as u8; 32];
+                (msg, schnorr::sign(&Message::from_digest(msg), &keypair, &[0; 32]), keypair.x_only_public_key().0)
+            })
+            .collect::<Vec<_>>();
+        let refs = items.iter().map(|(msg, signature, public_key)| (msg, signature, public_key)).collect::<Vec<_>>();
+
+        let start = Instant::now();
+        for (msg, signature, public_key) in &items {
+            schnorr::verify(&Message::from_digest(*msg), signature, public_key).unwrap();
+        }
+        let sequential = start.elapsed();
+
+        let start = Instant::now();
+        schnorr::verify_batch(&refs, &mut rng).unwrap();
+        let batch = start.elapsed();
+
+        println!("{:>4} signatures: sequential {:?}, batch {:?}", len, sequential, batch);
+    }
+}
@@ -0,0 +1,198 @@
+//! Bitcoin addresses
+
+use alloc::string::String;
+#[cfg(feature = "bitcoin-hashes")]
+use core::convert::TryInto;
+use crate::{bech32, Error, XOnlyPoint};
+#[cfg(feature = "bitcoin-hashes")]
+use crate::{base58, Network, PublicKey};
+#[cfg(feature = "bitcoin-hashes")]
+use crate::hashes::hash160;
+
+#[cfg(feature = "bitcoin-hashes")]
+impl Network {
+    fn p2pkh_version(self) -> u8 {
+        match self {
+            Network::Bitcoin => 0x00,
+            Network::Testnet => 0x6F,
+        }
+    }
+}
+
+#[cfg(feature = "bitcoin-hashes")]
+fn encode_p2pkh(hash: &[u8; 20], network: Network) -> String {
+    let mut payload = [0; 21];
+    payload[0] = network.p2pkh_version();
+    payload[1..].copy_from_slice(hash);
+    base58::encode_check(&payload)
+}
+
+#[cfg(feature = "bitcoin-hashes")]
+impl PublicKey {
+    /// Computes legacy (P2PKH) address of the compressed key
+    pub fn p2pkh_address(&self, network: Network) -> String {
+        encode_p2pkh(&hash160(&self.serialize()), network)
+    }
+
+    /// Computes legacy (P2PKH) address of the uncompressed key
+    ///
+    /// This is only useful for old wallets, the addresses are different from `p2pkh_address`.
+    pub fn p2pkh_address_uncompressed(&self, network: Network) -> String {
+        encode_p2pkh(&hash160(&self.serialize_uncompressed()), network)
+    }
+}
+
+/// Parses legacy (P2PKH) address returning the network and HASH160 of the public key
+#[cfg(feature = "bitcoin-hashes")]
+pub fn p2pkh_address_to_hash160(address: &str) -> Result<(Network, [u8; 20]), Error> {
+    let payload = base58::decode_check(address)?;
+    if payload.len() != 21 {
+        return Err(Error::InvalidLength(payload.len()));
+    }
+    let network = match payload[0] {
+        0x00 => Network::Bitcoin,
+        0x6F => Network::Testnet,
+        version => return Err(Error::UnknownAddressVersion(version)),
+    };
+    Ok((network, payload[1..].try_into().expect("20 bytes")))
+}
+
+impl XOnlyPoint {
+    /// Computes taproot (P2TR) address using this key as the output key.
+    ///
+    /// The key must already be tweaked as described in BIP341. `hrp` has to be lower-case, e.g.
+    /// `bc` for mainnet or `tb` for testnet.
+    pub fn p2tr_address(&self, hrp: &str) -> String {
+        bech32::encode_segwit(hrp, 1, &self.serialize())
+    }
+}
+
+/// Parses taproot (P2TR) address returning its human-readable part and the output key.
+///
+/// Only witness version 1 with bech32m checksum is accepted. The human-readable part is not
+/// validated - the caller should compare it with the expected network.
+pub fn p2tr_address_to_xonly(address: &str) -> Result<(String, XOnlyPoint), Error> {
+    let (hrp, version, program) = bech32::decode_segwit(address)?;
+    if version != 1 {
+        return Err(Error::UnsupportedWitnessVersion(version));
+    }
+    Ok((hrp, XOnlyPoint::from_slice(&program)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::p2tr_address_to_xonly;
+    use crate::{Error, XOnlyPoint, G};
+    #[cfg(feature = "bitcoin-hashes")]
+    use super::p2pkh_address_to_hash160;
+    #[cfg(feature = "bitcoin-hashes")]
+    use crate::{Network, SecretKey};
+    #[cfg(feature = "bitcoin-hashes")]
+    use crate::hashes::hash160;
+
+    #[cfg(feature = "bitcoin-hashes")]
+    fn secret_key_one() -> SecretKey {
+        let mut bytes = [0; 32];
+        bytes[31] = 1;
+        SecretKey::from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    #[cfg(feature = "bitcoin-hashes")]
+    fn private_key_one() {
+        let public_key = secret_key_one().public_key();
+        assert_eq!(public_key.p2pkh_address(Network::Bitcoin), "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH");
+        assert_eq!(public_key.p2pkh_address_uncompressed(Network::Bitcoin), "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm");
+        assert_eq!(public_key.p2pkh_address(Network::Testnet), "mrCDrCybB6J1vRfbwM5hemdJz73FwDBC8r");
+    }
+
+    #[test]
+    #[cfg(feature = "bitcoin-hashes")]
+    fn parse_round_trip() {
+        let public_key = secret_key_one().public_key();
+        for &network in &[Network::Bitcoin, Network::Testnet] {
+            let address = public_key.p2pkh_address(network);
+            assert_eq!(p2pkh_address_to_hash160(&address), Ok((network, hash160(&public_key.serialize()))));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "bitcoin-hashes")]
+    fn parse_errors() {
+        assert_eq!(p2pkh_address_to_hash160("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMJ"), Err(Error::InvalidChecksum));
+        assert_eq!(p2pkh_address_to_hash160("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAM0"), Err(Error::InvalidBase58Character('0')));
+        // P2SH address
+        assert_eq!(p2pkh_address_to_hash160("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy"), Err(Error::UnknownAddressVersion(0x05)));
+    }
+
+    #[test]
+    fn p2tr_generator() {
+        // BIP350 test vector having G as the program
+        let address = "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0";
+        let key = XOnlyPoint::from_point(G).unwrap().0;
+        assert_eq!(key.p2tr_address("bc"), address);
+        assert_eq!(p2tr_address_to_xonly(address), Ok(("bc".to_owned(), key)));
+        assert_eq!(p2tr_address_to_xonly(&address.to_ascii_uppercase()), Ok(("bc".to_owned(), key)));
+    }
+
+    #[test]
+    fn p2tr_bip350_invalid() {
+        let cases = [
+            // bech32 checksum instead of bech32m
+            ("bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd", Error::InvalidChecksum),
+            ("tb1z0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqglt7rf", Error::InvalidChecksum),
+            ("BC1S0XLXVLHEMJA6C4DQV22UAPCTQUPFHLXM9H8Z3K2E72Q4K9HCZ7VQ54WELL", Error::InvalidChecksum),
+            // bech32m checksum for version 0
+            ("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kemeawh", Error::InvalidChecksum),
+            ("tb1q0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq24jc47", Error::InvalidChecksum),
+            ("bc1p38j9r5y49hruaue7wxjce0updqjuyyx0kh56v8s25huc6995vvpql3jow4", Error::InvalidBech32Character('o')),
+            ("BC130XLXVLHEMJA6C4DQV22UAPCTQUPFHLXM9H8Z3K2E72Q4K9HCZ7VQ7ZWS8R", Error::UnsupportedWitnessVersion(17)),
+            ("bc1pw5dgrnzv", Error::InvalidLength(1)),
+            ("bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7v8n0nx0muaewav253zgeav", Error::InvalidLength(41)),
+            ("BC1QR508D6QEJXTDG4Y5R3ZARVARYV98GJ9P", Error::InvalidLength(16)),
+            ("tb1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq47Zagq", Error::MixedCase),
+            ("bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7v07qwwzcrf", Error::InvalidPadding),
+            ("tb1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vpggkg4j", Error::InvalidPadding),
+            ("bc1gmk9yu", Error::InvalidLength(6)),
+            // valid segwit addresses that are not taproot
+            ("bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7kt5nd6y", Error::InvalidLength(40)),
+            ("bc1zw508d6qejxtdg4y5r3zarvaryvaxxpcs", Error::UnsupportedWitnessVersion(2)),
+        ];
+        for (address, error) in &cases {
+            assert_eq!(p2tr_address_to_xonly(address), Err(*error), "{}", address);
+        }
+    }
+
+    #[test]
+    fn p2tr_program_not_on_curve() {
+        // x = 5 is not on the curve
+        let mut program = [0; 32];
+        program[31] = 5;
+        let address = crate::bech32::encode_segwit("bc", 1, &program);
+        assert_eq!(p2tr_address_to_xonly(&address), Err(Error::NotOnCurve));
+    }
+
+    /// Key-path-only output from BIP341 wallet test vectors, tweaked by hand
+    #[test]
+    #[cfg(feature = "bitcoin-hashes")]
+    fn p2tr_bip341_pipeline() {
+        use crate::hashes::sha256;
+        use crate::test_util::hex32;
+        use crate::Scalar;
+
+        let internal = hex32("d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d");
+        let internal = XOnlyPoint::from_bytes(&internal).unwrap();
+
+        let tag = sha256(b"TapTweak");
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(&tag);
+        preimage.extend_from_slice(&tag);
+        preimage.extend_from_slice(&internal.serialize());
+        let tweak = Scalar::from_be_bytes(&sha256(&preimage)).unwrap();
+        let output = XOnlyPoint::from_point(internal.to_point() + G * tweak).unwrap().0;
+
+        let address = "bc1p2wsldez5mud2yam29q22wgfh9439spgduvct83k3pm50fcxa5dps59h4z5";
+        assert_eq!(output.p2tr_address("bc"), address);
+        assert_eq!(p2tr_address_to_xonly(address), Ok(("bc".to_owned(), output)));
+    }
+}
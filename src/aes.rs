@@ -0,0 +1,212 @@
+//! AES-256 block cipher (FIPS 197)
+//!
+//! Only single blocks are encrypted and decrypted, which is all BIP38 needs. The S-box is
+//! computed at compile time from its definition - the multiplicative inverse in GF(2⁸) followed
+//! by the affine transformation. The table lookups are indexed by secret data so this is
+//! **NOT CONSTANT TIME!!!**
+
+const ROUNDS: usize = 14;
+
+/// Multiplication in GF(2⁸) modulo `x⁸ + x⁴ + x³ + x + 1`
+const fn gmul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            product ^= a;
+        }
+        a = (a << 1) ^ if a & 0x80 != 0 { 0x1B } else { 0 };
+        b >>= 1;
+    }
+    product
+}
+
+const fn sbox() -> [u8; 256] {
+    let mut sbox = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        // a²⁵⁴ is the inverse of a, zero maps to zero
+        let mut inverse = 1;
+        let mut j = 0;
+        while j < 254 {
+            inverse = gmul(inverse, i as u8);
+            j += 1;
+        }
+        sbox[i] = inverse ^ inverse.rotate_left(1) ^ inverse.rotate_left(2) ^ inverse.rotate_left(3) ^ inverse.rotate_left(4) ^ 0x63;
+        i += 1;
+    }
+    sbox
+}
+
+const fn inverse_sbox(sbox: &[u8; 256]) -> [u8; 256] {
+    let mut inverse = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        inverse[sbox[i] as usize] = i as u8;
+        i += 1;
+    }
+    inverse
+}
+
+const SBOX: [u8; 256] = sbox();
+const INVERSE_SBOX: [u8; 256] = inverse_sbox(&SBOX);
+
+/// AES-256 with expanded key
+pub(crate) struct Aes256 {
+    round_keys: [[u8; 16]; ROUNDS + 1],
+}
+
+impl Aes256 {
+    pub(crate) fn new(key: &[u8; 32]) -> Self {
+        let mut words = [[0u8; 4]; 4 * (ROUNDS + 1)];
+        for (word, chunk) in words.iter_mut().zip(key.chunks(4)) {
+            word.copy_from_slice(chunk);
+        }
+        let mut round_constant = 1;
+        for i in 8..words.len() {
+            let mut temp = words[i - 1];
+            if i % 8 == 0 {
+                temp = [SBOX[usize::from(temp[1])] ^ round_constant, SBOX[usize::from(temp[2])], SBOX[usize::from(temp[3])], SBOX[usize::from(temp[0])]];
+                round_constant = gmul(round_constant, 2);
+            } else if i % 8 == 4 {
+                temp = temp.map(|byte| SBOX[usize::from(byte)]);
+            }
+            for (j, byte) in temp.iter().enumerate() {
+                words[i][j] = words[i - 8][j] ^ byte;
+            }
+        }
+
+        let mut round_keys = [[0; 16]; ROUNDS + 1];
+        for (round_key, chunk) in round_keys.iter_mut().zip(words.chunks(4)) {
+            for (dst, word) in round_key.chunks_mut(4).zip(chunk) {
+                dst.copy_from_slice(word);
+            }
+        }
+        Aes256 { round_keys }
+    }
+
+    pub(crate) fn encrypt_block(&self, block: &mut [u8; 16]) {
+        add_round_key(block, &self.round_keys[0]);
+        for round_key in &self.round_keys[1..ROUNDS] {
+            sub_bytes(block, &SBOX);
+            shift_rows(block);
+            mix_columns(block);
+            add_round_key(block, round_key);
+        }
+        sub_bytes(block, &SBOX);
+        shift_rows(block);
+        add_round_key(block, &self.round_keys[ROUNDS]);
+    }
+
+    pub(crate) fn decrypt_block(&self, block: &mut [u8; 16]) {
+        add_round_key(block, &self.round_keys[ROUNDS]);
+        for round_key in self.round_keys[1..ROUNDS].iter().rev() {
+            inverse_shift_rows(block);
+            sub_bytes(block, &INVERSE_SBOX);
+            add_round_key(block, round_key);
+            inverse_mix_columns(block);
+        }
+        inverse_shift_rows(block);
+        sub_bytes(block, &INVERSE_SBOX);
+        add_round_key(block, &self.round_keys[0]);
+    }
+}
+
+impl Drop for Aes256 {
+    fn drop(&mut self) {
+        use crate::zeroize::Wipe;
+
+        for round_key in &mut self.round_keys {
+            round_key.wipe();
+        }
+    }
+}
+
+fn add_round_key(block: &mut [u8; 16], round_key: &[u8; 16]) {
+    for (byte, key) in block.iter_mut().zip(round_key) {
+        *byte ^= key;
+    }
+}
+
+fn sub_bytes(block: &mut [u8; 16], sbox: &[u8; 256]) {
+    for byte in block {
+        *byte = sbox[usize::from(*byte)];
+    }
+}
+
+// The state is stored by columns so the byte in row `r` and column `c` is at `r + 4c`
+
+fn shift_rows(block: &mut [u8; 16]) {
+    let state = *block;
+    for row in 1..4 {
+        for column in 0..4 {
+            block[row + 4 * column] = state[row + 4 * ((column + row) % 4)];
+        }
+    }
+}
+
+fn inverse_shift_rows(block: &mut [u8; 16]) {
+    let state = *block;
+    for row in 1..4 {
+        for column in 0..4 {
+            block[row + 4 * ((column + row) % 4)] = state[row + 4 * column];
+        }
+    }
+}
+
+/// Multiplies each column by the circulant matrix with the first row `coefficients`
+fn multiply_columns(block: &mut [u8; 16], coefficients: [u8; 4]) {
+    for column in block.chunks_mut(4) {
+        let a = [column[0], column[1], column[2], column[3]];
+        for (row, byte) in column.iter_mut().enumerate() {
+            *byte = (0..4).fold(0, |sum, i| sum ^ gmul(coefficients[(4 + i - row) % 4], a[i]));
+        }
+    }
+}
+
+fn mix_columns(block: &mut [u8; 16]) {
+    multiply_columns(block, [2, 3, 1, 1]);
+}
+
+fn inverse_mix_columns(block: &mut [u8; 16]) {
+    multiply_columns(block, [14, 11, 13, 9]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Aes256, SBOX, INVERSE_SBOX};
+    use crate::test_util::{hex, hex32};
+
+    #[test]
+    fn sbox() {
+        assert_eq!(&SBOX[..8], &[0x63, 0x7C, 0x77, 0x7B, 0xF2, 0x6B, 0x6F, 0xC5]);
+        assert_eq!(SBOX[0x53], 0xED);
+        assert_eq!(SBOX[0xFF], 0x16);
+        assert_eq!(INVERSE_SBOX[0xED], 0x53);
+    }
+
+    /// FIPS 197 appendix C.3 and the first block of NIST SP 800-38A F.1.5 (ECB-AES256)
+    #[test]
+    fn vectors() {
+        let vectors = [
+            (
+                "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+                "00112233445566778899aabbccddeeff",
+                "8ea2b7ca516745bfeafc49904b496089",
+            ),
+            (
+                "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+                "6bc1bee22e409f96e93d7e117393172a",
+                "f3eed1bdb5d2a03c064b5a7e3db181f8",
+            ),
+        ];
+        for (key, plaintext, ciphertext) in &vectors {
+            let aes = Aes256::new(&hex32(key));
+            let mut block = [0; 16];
+            block.copy_from_slice(&hex(plaintext));
+            aes.encrypt_block(&mut block);
+            assert_eq!(block[..], hex(ciphertext)[..]);
+            aes.decrypt_block(&mut block);
+            assert_eq!(block[..], hex(plaintext)[..]);
+        }
+    }
+}
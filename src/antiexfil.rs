@@ -0,0 +1,339 @@
+//! Anti-exfiltration signing protocol
+//!
+//! A malicious signer (e.g. a compromised hardware wallet) can leak its key through the nonces
+//! it chooses, no matter how valid the signatures are. In this protocol the host contributes
+//! randomness to the nonce and checks that it was used:
+//!
+//! 1. `Host::new` picks random `t` and returns the `HostCommitment` to it for the signer.
+//! 2. `Signer::new_ecdsa` or `Signer::new_schnorr` derives its nonce `R₀` from the key, the
+//!    message and the host commitment and returns the `SignerCommitment` to it for the host.
+//! 3. `Host::reveal` stores `R₀` and returns the `HostRandomness` `t` for the signer.
+//! 4. `Signer::sign` checks `t` against the commitment and signs with the nonce
+//!    `R = R₀ + H(R₀ || t)·G`.
+//! 5. `Host::verify` checks the signature and that its nonce is exactly `R`.
+//!
+//! The signer commits to `R₀` before learning `t` so it can't bias `R` and the host commits to
+//! `t` before learning `R₀` so it can't bias it either. This follows the anti-exfil protocol of
+//! libsecp256k1-zkp but the hashes differ so it's not interoperable with it.
+
+use rand_core::{CryptoRng, RngCore};
+use crate::ecdsa;
+use crate::nonce::{NonceGenerator, Rfc6979};
+use crate::schnorr::{challenge, derive_nonce, signing_key};
+use crate::tagged_hash::TaggedHash;
+use crate::zeroize::Zeroizing;
+use crate::{Error, Keypair, Message, Parity, Point, PublicKey, Scalar, SecretKey, XOnlyPoint, G};
+
+const HOST_COMMITMENT_TAG: &str = "toy-secp256k1/antiexfil/host-commitment";
+const TWEAK_TAG: &str = "toy-secp256k1/antiexfil/tweak";
+
+/// Tweaks the signer's nonce with the host randomness returning `R₀ + H(R₀ || t)·G` and the tweak
+fn tweak_nonce(original_nonce: Point, randomness: &[u8; 32]) -> (Point, Scalar) {
+    let hash = TaggedHash::new(TWEAK_TAG).hash(&[&original_nonce.serialize_compressed(), randomness]);
+    let tweak = Scalar::from_be_bytes_reduce(&hash);
+    (original_nonce + G * tweak, tweak)
+}
+
+/// Hash of the host randomness sent to the signer in the first step
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct HostCommitment([u8; 32]);
+
+impl HostCommitment {
+    pub fn from_bytes(bytes: &[u8; 32]) -> Self {
+        HostCommitment(*bytes)
+    }
+
+    pub fn serialize(&self) -> [u8; 32] {
+        self.0
+    }
+}
+
+/// The original nonce `R₀` of the signer sent to the host in the second step
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SignerCommitment(Point);
+
+impl SignerCommitment {
+    /// Parses the compressed nonce.
+    ///
+    /// Returns the error of `Point::from_sec1_bytes` if the bytes are not a valid point.
+    pub fn from_bytes(bytes: &[u8; 33]) -> Result<Self, Error> {
+        Point::from_sec1_bytes(bytes).map(SignerCommitment)
+    }
+
+    pub fn serialize(&self) -> [u8; 33] {
+        self.0.serialize_compressed()
+    }
+
+    pub fn original_nonce(&self) -> Point {
+        self.0
+    }
+}
+
+/// The host randomness `t` revealed to the signer in the third step
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct HostRandomness([u8; 32]);
+
+impl HostRandomness {
+    pub fn from_bytes(bytes: &[u8; 32]) -> Self {
+        HostRandomness(*bytes)
+    }
+
+    pub fn serialize(&self) -> [u8; 32] {
+        self.0
+    }
+
+    fn commitment(&self) -> HostCommitment {
+        HostCommitment(TaggedHash::new(HOST_COMMITMENT_TAG).hash(&[&self.0]))
+    }
+}
+
+/// Signature produced by the signer in the last step
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Signature {
+    Ecdsa(ecdsa::Signature),
+    /// BIP340 signature
+    Schnorr([u8; 64]),
+}
+
+/// The host side of the protocol
+#[derive(Clone, Debug)]
+pub struct Host {
+    randomness: HostRandomness,
+    original_nonce: Option<Point>,
+}
+
+impl Host {
+    /// Generates the randomness returning the commitment to send to the signer.
+    pub fn new<R: RngCore + CryptoRng>(rng: &mut R) -> (Self, HostCommitment) {
+        let mut randomness = [0; 32];
+        rng.fill_bytes(&mut randomness);
+        let randomness = HostRandomness(randomness);
+        (Host { randomness, original_nonce: None }, randomness.commitment())
+    }
+
+    /// Stores the signer's nonce returning the randomness to reveal to the signer.
+    pub fn reveal(&mut self, commitment: &SignerCommitment) -> HostRandomness {
+        self.original_nonce = Some(commitment.0);
+        self.randomness
+    }
+
+    /// Verifies the signature and checks that it uses the nonce derived from the signer's
+    /// commitment and the host randomness.
+    ///
+    /// Returns `Error::InvalidSignature` if the signature is invalid and
+    /// `Error::CommitmentMismatch` if it uses another nonce or `reveal` wasn't called.
+    pub fn verify(&self, msg32: &[u8; 32], signature: &Signature, public_key: &PublicKey) -> Result<(), Error> {
+        let original_nonce = self.original_nonce.ok_or(Error::CommitmentMismatch)?;
+        let (big_r, _) = tweak_nonce(original_nonce, &self.randomness.0);
+        if big_r.is_at_infinity() {
+            return Err(Error::CommitmentMismatch);
+        }
+        let nonce_matches = match signature {
+            Signature::Ecdsa(signature) => {
+                ecdsa::verify(&Message::from_digest(*msg32), signature, public_key)?;
+                big_r.x().to_scalar_reduce() == signature.r()
+            },
+            Signature::Schnorr(signature) => {
+                crate::schnorr::verify(&Message::from_digest(*msg32), signature, &public_key.x_only_public_key().0)?;
+                big_r.x().to_be_bytes()[..] == signature[..32]
+            },
+        };
+        if nonce_matches {
+            Ok(())
+        } else {
+            Err(Error::CommitmentMismatch)
+        }
+    }
+}
+
+enum Scheme {
+    Ecdsa { secret_key: SecretKey },
+    Schnorr { public_key: XOnlyPoint, d: Zeroizing<Scalar> },
+}
+
+/// The signer side of the protocol
+pub struct Signer {
+    scheme: Scheme,
+    msg32: [u8; 32],
+    host_commitment: HostCommitment,
+    k: Zeroizing<Scalar>,
+    original_nonce: Point,
+}
+
+/// Doesn't print the secrets
+impl core::fmt::Debug for Signer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let scheme = match self.scheme {
+            Scheme::Ecdsa { .. } => "ECDSA",
+            Scheme::Schnorr { .. } => "Schnorr",
+        };
+        write!(f, "Signer {{ scheme: {}, original_nonce: {:?}, .. }}", scheme, self.original_nonce)
+    }
+}
+
+impl Signer {
+    /// Derives the ECDSA nonce using RFC 6979 with the host commitment as extra data.
+    pub fn new_ecdsa(msg32: &[u8; 32], secret_key: &SecretKey, host_commitment: &HostCommitment) -> (Self, SignerCommitment) {
+        let k = Zeroizing::new(Rfc6979::with_extra_data(host_commitment.0).nonce(msg32, secret_key, 0));
+        Self::with_nonce(Scheme::Ecdsa { secret_key: *secret_key }, msg32, host_commitment, k)
+    }
+
+    /// Derives the BIP340 nonce with the host commitment appended to the message.
+    pub fn new_schnorr(msg32: &[u8; 32], keypair: &Keypair, host_commitment: &HostCommitment, aux_rand: &[u8; 32]) -> (Self, SignerCommitment) {
+        let (public_key, d) = signing_key(keypair);
+        let k = Zeroizing::new(derive_nonce(&d, &public_key, &[msg32, &host_commitment.0], aux_rand));
+        Self::with_nonce(Scheme::Schnorr { public_key, d }, msg32, host_commitment, k)
+    }
+
+    fn with_nonce(scheme: Scheme, msg32: &[u8; 32], host_commitment: &HostCommitment, k: Zeroizing<Scalar>) -> (Self, SignerCommitment) {
+        let original_nonce = Point::mul_gen(&k);
+        let signer = Signer { scheme, msg32: *msg32, host_commitment: *host_commitment, k, original_nonce };
+        (signer, SignerCommitment(original_nonce))
+    }
+
+    /// Signs with the nonce tweaked by the host randomness.
+    ///
+    /// The ECDSA signature is normalized to low-S which doesn't change the x coordinate of the
+    /// nonce the host checks.
+    ///
+    /// Returns `Error::CommitmentMismatch` if the randomness doesn't match the host commitment
+    /// and `Error::InvalidNonce` if the tweaked nonce is unusable, which happens with negligible
+    /// probability.
+    pub fn sign(self, randomness: &HostRandomness) -> Result<Signature, Error> {
+        if randomness.commitment() != self.host_commitment {
+            return Err(Error::CommitmentMismatch);
+        }
+        let (big_r, tweak) = tweak_nonce(self.original_nonce, &randomness.0);
+        let mut k = Zeroizing::new(*self.k + tweak);
+        match &self.scheme {
+            Scheme::Ecdsa { secret_key } => {
+                let r = big_r.x().to_scalar_reduce();
+                if k.is_zero() {
+                    return Err(Error::InvalidNonce);
+                }
+                let s = (Scalar::from_be_bytes_reduce(&self.msg32) + r * secret_key.to_scalar()) / *k;
+                let mut signature = ecdsa::Signature::from_scalars(r, s).ok_or(Error::InvalidNonce)?;
+                signature.normalize_s();
+                Ok(Signature::Ecdsa(signature))
+            },
+            Scheme::Schnorr { public_key, d } => {
+                let (big_r, parity) = XOnlyPoint::from_point(big_r).ok_or(Error::InvalidNonce)?;
+                if parity == Parity::Odd {
+                    *k = -*k;
+                }
+                let r = big_r.serialize();
+                let s = *k + challenge(&r, public_key, &self.msg32) * **d;
+                let mut signature = [0; 64];
+                signature[..32].copy_from_slice(&r);
+                signature[32..].copy_from_slice(&s.to_be_bytes());
+                Ok(Signature::Schnorr(signature))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Host, HostCommitment, HostRandomness, Signature, Signer, SignerCommitment};
+    use crate::test_utils::DeterministicRng;
+    use crate::{ecdsa, schnorr, Error, Keypair, Message, Point, Scalar, SecretKey, G};
+
+    fn keypair(rng: &mut DeterministicRng) -> Keypair {
+        Keypair::from_secret_key(&SecretKey::from_scalar(Scalar::random(rng)).unwrap())
+    }
+
+    /// Runs the protocol passing every message through serialization
+    fn run(rng: &mut DeterministicRng, keypair: &Keypair, msg: &[u8; 32], schnorr: bool) -> (Host, Signature) {
+        let (mut host, host_commitment) = Host::new(rng);
+        let host_commitment = HostCommitment::from_bytes(&host_commitment.serialize());
+        let (signer, signer_commitment) = if schnorr {
+            Signer::new_schnorr(msg, keypair, &host_commitment, &[0x11; 32])
+        } else {
+            Signer::new_ecdsa(msg, keypair.secret_key(), &host_commitment)
+        };
+        let signer_commitment = SignerCommitment::from_bytes(&signer_commitment.serialize()).unwrap();
+        let randomness = host.reveal(&signer_commitment);
+        let randomness = HostRandomness::from_bytes(&randomness.serialize());
+        (host, signer.sign(&randomness).unwrap())
+    }
+
+    #[test]
+    fn honest_run() {
+        let mut rng = DeterministicRng::new(218);
+        for i in 0..8 {
+            let keypair = keypair(&mut rng);
+            let msg = [i; 32];
+            for schnorr in &[false, true] {
+                let (host, signature) = run(&mut rng, &keypair, &msg, *schnorr);
+                assert_eq!(host.verify(&msg, &signature, keypair.public_key()), Ok(()));
+                match signature {
+                    Signature::Ecdsa(signature) => {
+                        assert!(!*schnorr);
+                        assert!(signature.is_low_s());
+                        assert_eq!(ecdsa::verify_strict(&Message::from_digest(msg), &signature, keypair.public_key()), Ok(()));
+                    },
+                    Signature::Schnorr(signature) => {
+                        assert!(*schnorr);
+                        assert_eq!(schnorr::verify(&Message::from_digest(msg), &signature, &keypair.x_only_public_key().0), Ok(()));
+                    },
+                }
+                assert_eq!(host.verify(&[0xFF; 32], &signature, keypair.public_key()), Err(Error::InvalidSignature));
+            }
+        }
+    }
+
+    #[test]
+    fn substituted_nonce_is_caught() {
+        let mut rng = DeterministicRng::new(218);
+        let keypair = keypair(&mut rng);
+        let msg = [0x42; 32];
+        for schnorr in &[false, true] {
+            // the signer commits honestly but signs with a nonce of its choice, e.g. one leaking
+            // bits of the key, producing a perfectly valid signature
+            let (mut host, host_commitment) = Host::new(&mut rng);
+            let (_, signer_commitment) = if *schnorr {
+                Signer::new_schnorr(&msg, &keypair, &host_commitment, &[0; 32])
+            } else {
+                Signer::new_ecdsa(&msg, keypair.secret_key(), &host_commitment)
+            };
+            host.reveal(&signer_commitment);
+            let signature = if *schnorr {
+                Signature::Schnorr(schnorr::sign(&Message::from_digest(msg), &keypair, &[0; 32]))
+            } else {
+                Signature::Ecdsa(ecdsa::sign(&Message::from_digest(msg), keypair.secret_key()))
+            };
+            assert_eq!(host.verify(&msg, &signature, keypair.public_key()), Err(Error::CommitmentMismatch));
+
+            // the signer uses the host randomness but commits to a different R₀ than it uses
+            let (mut host, host_commitment) = Host::new(&mut rng);
+            let (signer, signer_commitment) = if *schnorr {
+                Signer::new_schnorr(&msg, &keypair, &host_commitment, &[0; 32])
+            } else {
+                Signer::new_ecdsa(&msg, keypair.secret_key(), &host_commitment)
+            };
+            let randomness = host.reveal(&SignerCommitment(signer_commitment.original_nonce() + G));
+            let signature = signer.sign(&randomness).unwrap();
+            assert_eq!(host.verify(&msg, &signature, keypair.public_key()), Err(Error::CommitmentMismatch));
+        }
+    }
+
+    #[test]
+    fn invalid_messages() {
+        let mut rng = DeterministicRng::new(218);
+        let keypair = keypair(&mut rng);
+        let (mut host, host_commitment) = Host::new(&mut rng);
+        let (signer, signer_commitment) = Signer::new_ecdsa(&[1; 32], keypair.secret_key(), &host_commitment);
+        let signature = Signature::Ecdsa(ecdsa::sign(&Message::from_digest([1; 32]), keypair.secret_key()));
+        // verifying before the signer commitment was received
+        assert_eq!(host.verify(&[1; 32], &signature, keypair.public_key()), Err(Error::CommitmentMismatch));
+        let randomness = host.reveal(&signer_commitment);
+        let mut other = randomness.serialize();
+        other[0] ^= 1;
+        assert_eq!(signer.sign(&HostRandomness::from_bytes(&other)).map(|_| ()), Err(Error::CommitmentMismatch));
+
+        let mut bytes = signer_commitment.serialize();
+        bytes[0] = 0x04;
+        assert_eq!(SignerCommitment::from_bytes(&bytes), Err(Error::InvalidPrefix(0x04)));
+        assert_eq!(SignerCommitment::from_bytes(&Point::GENERATOR.serialize_compressed()).map(|commitment| commitment.original_nonce()), Ok(G));
+    }
+}
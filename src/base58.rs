@@ -0,0 +1,94 @@
+//! Base58 and Base58Check encoding as used by Bitcoin
+
+use alloc::{string::String, vec::Vec};
+use crate::Error;
+use crate::hashes::sha256d;
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+pub(crate) fn encode(data: &[u8]) -> String {
+    // Little-endian base58 digits
+    let mut digits = Vec::<u8>::with_capacity(data.len() * 138 / 100 + 1);
+    for &byte in data {
+        let mut carry = u32::from(byte);
+        for digit in &mut digits {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let leading_zeros = data.iter().take_while(|&&byte| byte == 0).count();
+    core::iter::repeat_n(b'1', leading_zeros)
+        .chain(digits.iter().rev().map(|&digit| ALPHABET[usize::from(digit)]))
+        .map(char::from)
+        .collect()
+}
+
+pub(crate) fn decode(string: &str) -> Result<Vec<u8>, Error> {
+    // Little-endian bytes
+    let mut bytes = Vec::<u8>::with_capacity(string.len());
+    for c in string.chars() {
+        let mut carry = ALPHABET
+            .iter()
+            .position(|&a| char::from(a) == c)
+            .ok_or(Error::InvalidBase58Character(c))? as u32;
+        for byte in &mut bytes {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let leading_ones = string.chars().take_while(|&c| c == '1').count();
+    bytes.extend(core::iter::repeat_n(0, leading_ones));
+    bytes.reverse();
+    Ok(bytes)
+}
+
+/// Encodes the data with 4-byte double-SHA256 checksum appended
+pub(crate) fn encode_check(data: &[u8]) -> String {
+    let mut buf = Vec::with_capacity(data.len() + 4);
+    buf.extend_from_slice(data);
+    buf.extend_from_slice(&sha256d(data)[..4]);
+    encode(&buf)
+}
+
+/// Decodes the data and verifies (and strips) the checksum
+pub(crate) fn decode_check(string: &str) -> Result<Vec<u8>, Error> {
+    let mut data = decode(string)?;
+    if data.len() < 4 {
+        return Err(Error::InvalidLength(data.len()));
+    }
+    let checksum_pos = data.len() - 4;
+    if sha256d(&data[..checksum_pos])[..4] != data[checksum_pos..] {
+        return Err(Error::InvalidChecksum);
+    }
+    data.truncate(checksum_pos);
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn round_trip_with_leading_zeros() {
+        let data = [0, 0, 1, 2, 3, 255];
+        let encoded = super::encode(&data);
+        assert!(encoded.starts_with("11"));
+        assert_eq!(super::decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn known_value() {
+        assert_eq!(super::encode(b"Hello World!"), "2NEpo7TZRRrLZSi2U");
+        assert_eq!(super::decode("2NEpo7TZRRrLZSi2U").unwrap(), b"Hello World!");
+    }
+}
@@ -0,0 +1,124 @@
+//! Base64 encoding with padding (RFC 4648) as used by signed messages and PEM and its unpadded
+//! URL-safe variant used by JOSE
+
+use alloc::{string::String, vec::Vec};
+use crate::Error;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+pub(crate) fn encode(data: &[u8]) -> String {
+    encode_with(data, ALPHABET, true)
+}
+
+/// Encodes using URL-safe alphabet without padding
+pub(crate) fn encode_url(data: &[u8]) -> String {
+    encode_with(data, URL_ALPHABET, false)
+}
+
+fn encode_with(data: &[u8], alphabet: &[u8; 64], pad: bool) -> String {
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let mut group = [0; 3];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let bits = u32::from(group[0]) << 16 | u32::from(group[1]) << 8 | u32::from(group[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(char::from(alphabet[(bits >> (18 - 6 * i) & 0x3F) as usize]));
+            } else if pad {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Decodes the string, requiring canonical padding
+pub(crate) fn decode(string: &str) -> Result<Vec<u8>, Error> {
+    decode_with(string, ALPHABET)
+}
+
+/// Decodes unpadded string using URL-safe alphabet, the unused bits must be zero
+pub(crate) fn decode_url(string: &str) -> Result<Vec<u8>, Error> {
+    if string.contains('=') {
+        return Err(Error::InvalidBase64Character('='));
+    }
+    // a single character can't encode a whole byte
+    if string.len() % 4 == 1 {
+        return Err(Error::InvalidLength(string.len()));
+    }
+    let mut padded = String::with_capacity(string.len() + 2);
+    padded.push_str(string);
+    while !padded.len().is_multiple_of(4) {
+        padded.push('=');
+    }
+    decode_with(&padded, URL_ALPHABET)
+}
+
+fn decode_with(string: &str, alphabet: &[u8; 64]) -> Result<Vec<u8>, Error> {
+    if !string.len().is_multiple_of(4) {
+        return Err(Error::InvalidLength(string.len()));
+    }
+    let padding = string.bytes().rev().take_while(|&c| c == b'=').count();
+    if padding > 2 {
+        return Err(Error::InvalidPadding);
+    }
+    let mut bytes = Vec::with_capacity(string.len() / 4 * 3);
+    let mut bits = 0u32;
+    for (i, c) in string[..string.len() - padding].chars().enumerate() {
+        let value = alphabet
+            .iter()
+            .position(|&a| char::from(a) == c)
+            .ok_or(Error::InvalidBase64Character(c))? as u32;
+        bits = bits << 6 | value;
+        if i % 4 == 3 {
+            bytes.extend_from_slice(&bits.to_be_bytes()[1..]);
+            bits = 0;
+        }
+    }
+    // the unused bits of the last character must be zero
+    match padding {
+        1 if bits & 0x3 == 0 => bytes.extend_from_slice(&(bits >> 2).to_be_bytes()[2..]),
+        2 if bits & 0xF == 0 => bytes.push((bits >> 4) as u8),
+        0 => (),
+        _ => return Err(Error::InvalidPadding),
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error;
+
+    #[test]
+    fn rfc4648_vectors() {
+        let vectors = [("", ""), ("f", "Zg=="), ("fo", "Zm8="), ("foo", "Zm9v"), ("foob", "Zm9vYg=="), ("fooba", "Zm9vYmE="), ("foobar", "Zm9vYmFy")];
+        for (data, encoded) in &vectors {
+            assert_eq!(super::encode(data.as_bytes()), *encoded);
+            assert_eq!(super::decode(encoded).unwrap(), data.as_bytes());
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(super::decode("Zm9"), Err(Error::InvalidLength(3)));
+        assert_eq!(super::decode("Zm9="), Err(Error::InvalidPadding));
+        assert_eq!(super::decode("Z==="), Err(Error::InvalidPadding));
+        assert_eq!(super::decode("Zh=="), Err(Error::InvalidPadding));
+        assert_eq!(super::decode("Zm-v"), Err(Error::InvalidBase64Character('-')));
+        assert_eq!(super::decode("Zm=v"), Err(Error::InvalidBase64Character('=')));
+    }
+
+    #[test]
+    fn url_safe() {
+        let vectors: [(&[u8], &str); 5] = [(b"", ""), (b"f", "Zg"), (b"fo", "Zm8"), (b"foo", "Zm9v"), (&[0xfb, 0xff], "-_8")];
+        for (data, encoded) in &vectors {
+            assert_eq!(super::encode_url(data), *encoded);
+            assert_eq!(super::decode_url(encoded).unwrap(), *data);
+        }
+        assert_eq!(super::decode_url("Zg=="), Err(Error::InvalidBase64Character('=')));
+        assert_eq!(super::decode_url("Zm9vY"), Err(Error::InvalidLength(5)));
+        assert_eq!(super::decode_url("Zh"), Err(Error::InvalidPadding));
+        assert_eq!(super::decode_url("+/8"), Err(Error::InvalidBase64Character('+')));
+    }
+}
@@ -0,0 +1,273 @@
+//! Batch verification of mixed ECDSA and BIP340 signatures
+//!
+//! `BatchVerifier` collects signatures of both kinds and checks them all using a single random
+//! linear combination. The equations are the same as the ones used by `schnorr::verify_batch`
+//! and `ecdsa::verify_batch`, only summed together so that one multiexponentiation covers
+//! everything. ECDSA signatures need to be recoverable for the same reason as in
+//! `ecdsa::verify_batch`.
+
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use crate::ecdsa::{self, message_scalar, RecoverableSignature};
+use crate::schnorr::challenge;
+use crate::{array_ref_32, multiexp, BatchError, Message, Point, PublicKey, Scalar, XOnlyPoint, Zp, G};
+
+#[derive(Clone, Debug)]
+enum Entry {
+    Schnorr {
+        msg: [u8; 32],
+        signature: [u8; 64],
+        public_key: XOnlyPoint,
+    },
+    Ecdsa {
+        msg32: [u8; 32],
+        signature: RecoverableSignature,
+        public_key: PublicKey,
+    },
+}
+
+/// Collects ECDSA and BIP340 signatures to verify them at once
+///
+/// The signatures are only stored when queued, all the work happens in `verify`.
+#[derive(Clone, Debug, Default)]
+pub struct BatchVerifier {
+    entries: Vec<Entry>,
+    bisect: bool,
+}
+
+impl BatchVerifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes `verify` search for the invalid signature if the batch fails.
+    ///
+    /// The search bisects the batch which costs about `log₂(len)` additional batch
+    /// verifications.
+    pub fn bisect_on_failure(mut self, bisect: bool) -> Self {
+        self.bisect = bisect;
+        self
+    }
+
+    /// Queues BIP340 signature of a 32-byte message
+    pub fn queue_schnorr(&mut self, msg: &[u8; 32], signature: &[u8; 64], public_key: &XOnlyPoint) -> &mut Self {
+        self.entries.push(Entry::Schnorr {
+            msg: *msg,
+            signature: *signature,
+            public_key: *public_key,
+        });
+        self
+    }
+
+    /// Queues ECDSA signature which must have been made by `expected_public_key`.
+    ///
+    /// The recovery id is checked as well. If the point `R` can not be reconstructed from it the
+    /// signature is verified separately and the recovery id is ignored.
+    pub fn queue_ecdsa_recoverable(&mut self, msg32: &[u8; 32], signature: &RecoverableSignature, expected_public_key: &PublicKey) -> &mut Self {
+        self.entries.push(Entry::Ecdsa {
+            msg32: *msg32,
+            signature: *signature,
+            public_key: *expected_public_key,
+        });
+        self
+    }
+
+    /// Returns the number of queued signatures
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Verifies all queued signatures.
+    ///
+    /// Returns `BatchError::InvalidEntry` with the index (in the order of queueing) of the first
+    /// invalid signature if bisection is enabled, `BatchError::Invalid` otherwise. An empty batch
+    /// is valid.
+    pub fn verify<R: RngCore + CryptoRng>(self, rng: &mut R) -> Result<(), BatchError> {
+        if verify_entries(&self.entries, rng) {
+            return Ok(());
+        }
+        if !self.bisect {
+            return Err(BatchError::Invalid);
+        }
+        let mut offset = 0;
+        let mut entries = &*self.entries;
+        // invariant: `entries` contains an invalid signature
+        while entries.len() > 1 {
+            let (left, right) = entries.split_at(entries.len() / 2);
+            if verify_entries(left, rng) {
+                offset += left.len();
+                entries = right;
+            } else {
+                entries = left;
+            }
+        }
+        Err(BatchError::InvalidEntry(offset))
+    }
+}
+
+/// Checks `Σ aᵢ·(sᵢ·G - Rᵢ - eᵢ·Pᵢ) + Σ aⱼ·(u₁ⱼ·G + u₂ⱼ·Pⱼ - Rⱼ) = 0` for random `a`.
+fn verify_entries<R: RngCore + CryptoRng>(entries: &[Entry], rng: &mut R) -> bool {
+    let mut g_scalar = Scalar::ZERO;
+    let mut pairs = Vec::with_capacity(entries.len() * 2 + 1);
+    for entry in entries {
+        match entry {
+            Entry::Schnorr { msg, signature, public_key } => {
+                let r = array_ref_32(&signature[..32]);
+                let big_r = match Zp::from_be_bytes(r).and_then(Point::lift_x) {
+                    Some(big_r) => big_r,
+                    None => return false,
+                };
+                let s = match Scalar::from_be_bytes(array_ref_32(&signature[32..])) {
+                    Some(s) => s,
+                    None => return false,
+                };
+                let e = challenge(r, public_key, msg);
+                let a = Scalar::random(rng);
+                g_scalar += a * s;
+                pairs.push(((-a).to_u256(), big_r));
+                pairs.push(((-(a * e)).to_u256(), public_key.to_point()));
+            },
+            Entry::Ecdsa { msg32, signature, public_key } => {
+                let big_r = match signature.nonce_point() {
+                    Ok(big_r) => big_r,
+                    Err(_) => {
+                        if ecdsa::verify(&Message::from_digest(*msg32), &signature.to_standard(), public_key).is_err() {
+                            return false;
+                        }
+                        continue;
+                    },
+                };
+                let standard = signature.to_standard();
+                let s_inv = standard.s().multiplicative_inverse();
+                let a = Scalar::random(rng);
+                g_scalar += a * message_scalar(&Message::from_digest(*msg32)) * s_inv;
+                pairs.push(((a * standard.r() * s_inv).to_u256(), public_key.to_point()));
+                pairs.push(((-a).to_u256(), big_r));
+            },
+        }
+    }
+    pairs.push((g_scalar.to_u256(), G));
+    multiexp(&pairs) == Point::AT_INFINITY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BatchVerifier;
+    use crate::ecdsa::{sign_recoverable, RecoverableSignature, RecoveryId};
+    use crate::test_utils::DeterministicRng;
+    use crate::{schnorr, BatchError, Keypair, Message, PublicKey, Scalar, SecretKey, XOnlyPoint};
+
+    #[derive(Clone)]
+    enum Item {
+        Schnorr([u8; 32], [u8; 64], XOnlyPoint),
+        Ecdsa([u8; 32], RecoverableSignature, PublicKey),
+    }
+
+    /// Alternates the kinds of signatures, keys are reused because computing them is slow
+    fn items(rng: &mut DeterministicRng, len: usize) -> Vec<Item> {
+        let keypairs = (0..2)
+            .map(|_| Keypair::from_secret_key(&SecretKey::from_scalar(Scalar::random(rng)).unwrap()))
+            .collect::<Vec<_>>();
+        (0..len)
+            .map(|i| {
+                let keypair = &keypairs[i / 2 % 2];
+                let msg = Scalar::random(rng).to_be_bytes();
+                if i % 2 == 0 {
+                    let signature = schnorr::sign(&Message::from_digest(msg), keypair, &Scalar::random(rng).to_be_bytes());
+                    Item::Schnorr(msg, signature, keypair.x_only_public_key().0)
+                } else {
+                    Item::Ecdsa(msg, sign_recoverable(&Message::from_digest(msg), keypair.secret_key()), *keypair.public_key())
+                }
+            })
+            .collect()
+    }
+
+    fn verifier(items: &[Item], bisect: bool) -> BatchVerifier {
+        let mut verifier = BatchVerifier::new().bisect_on_failure(bisect);
+        for item in items {
+            match item {
+                Item::Schnorr(msg, signature, public_key) => verifier.queue_schnorr(msg, signature, public_key),
+                Item::Ecdsa(msg, signature, public_key) => verifier.queue_ecdsa_recoverable(msg, signature, public_key),
+            };
+        }
+        verifier
+    }
+
+    fn corrupt(item: &mut Item) {
+        match item {
+            Item::Schnorr(_, signature, _) => signature[63] ^= 1,
+            Item::Ecdsa(msg, _, _) => msg[0] ^= 1,
+        }
+    }
+
+    #[test]
+    fn valid_mixed_batch() {
+        let mut rng = DeterministicRng::new(221);
+        let items = items(&mut rng, 6);
+        let verifier = verifier(&items, true);
+        assert_eq!(verifier.len(), 6);
+        assert_eq!(verifier.verify(&mut rng), Ok(()));
+        assert!(BatchVerifier::new().is_empty());
+        assert_eq!(BatchVerifier::new().verify(&mut rng), Ok(()));
+    }
+
+    #[test]
+    fn corrupted_entry() {
+        let mut rng = DeterministicRng::new(221);
+        let items = items(&mut rng, 5);
+        for i in 0..items.len() {
+            let mut corrupted = items.clone();
+            corrupt(&mut corrupted[i]);
+            assert_eq!(verifier(&corrupted, false).verify(&mut rng), Err(BatchError::Invalid), "entry {}", i);
+        }
+        // unparsable R of a BIP340 signature
+        let mut corrupted = items.clone();
+        if let Item::Schnorr(_, signature, _) = &mut corrupted[2] {
+            signature[..32].copy_from_slice(&[0xFF; 32]);
+        }
+        assert_eq!(verifier(&corrupted, true).verify(&mut rng), Err(BatchError::InvalidEntry(2)));
+        // the recovery id doesn't match
+        let mut corrupted = items;
+        if let Item::Ecdsa(_, signature, _) = &mut corrupted[3] {
+            let id = RecoveryId::from_u8(signature.recovery_id().to_u8() ^ 1).unwrap();
+            *signature = RecoverableSignature::new(signature.to_standard(), id);
+        }
+        assert_eq!(verifier(&corrupted, true).verify(&mut rng), Err(BatchError::InvalidEntry(3)));
+    }
+
+    #[test]
+    fn bisection() {
+        let mut rng = DeterministicRng::new(221);
+        let items = items(&mut rng, 7);
+        for i in 0..items.len() {
+            let mut corrupted = items.clone();
+            corrupt(&mut corrupted[i]);
+            assert_eq!(verifier(&corrupted, true).verify(&mut rng), Err(BatchError::InvalidEntry(i)));
+        }
+        // the first one is reported
+        let mut corrupted = items;
+        corrupt(&mut corrupted[4]);
+        corrupt(&mut corrupted[6]);
+        assert_eq!(verifier(&corrupted, true).verify(&mut rng), Err(BatchError::InvalidEntry(4)));
+    }
+
+    /// `R` can not be reconstructed so the signature is verified separately
+    #[test]
+    fn unreconstructible_nonce_point() {
+        let mut rng = DeterministicRng::new(221);
+        let mut items = items(&mut rng, 4);
+        if let Item::Ecdsa(_, signature, _) = &mut items[1] {
+            // r + n overflows
+            assert!(signature.to_standard().r().to_be_bytes()[0] > 0);
+            let id = RecoveryId::from_u8(signature.recovery_id().to_u8() | 2).unwrap();
+            *signature = RecoverableSignature::new(signature.to_standard(), id);
+        }
+        assert_eq!(verifier(&items, true).verify(&mut rng), Ok(()));
+        corrupt(&mut items[1]);
+        assert_eq!(verifier(&items, true).verify(&mut rng), Err(BatchError::InvalidEntry(1)));
+    }
+}
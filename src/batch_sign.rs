@@ -0,0 +1,98 @@
+//! Signing many messages with the same key
+//!
+//! `BatchSigner` computes everything that depends only on the key once: the x-only public key
+//! and the matching secret key for BIP340, the nonce hasher with the masked key for BIP340 and the
+//! first HMAC of RFC 6979 for ECDSA. The signatures are identical to the ones produced by
+//! `ecdsa::sign` and `schnorr::sign`.
+
+use alloc::vec::Vec;
+use crate::ecdsa::{self, Signature};
+use crate::nonce::NonceGenerator;
+use crate::rfc6979::KeyedDrbg;
+use crate::zeroize::Zeroizing;
+use crate::{schnorr, Keypair, Message, Scalar, SecretKey, XOnlyPoint};
+
+/// RFC 6979 nonces using the precomputed part of the initialization
+struct PreparedRfc6979<'a>(&'a KeyedDrbg);
+
+impl NonceGenerator for PreparedRfc6979<'_> {
+    fn nonce(&mut self, msg32: &[u8; 32], _secret_key: &SecretKey, attempt: u32) -> Scalar {
+        let mut drbg = self.0.drbg(msg32, None);
+        for _ in 0..attempt {
+            drbg.next_nonce();
+        }
+        drbg.next_nonce()
+    }
+}
+
+/// Signs many messages with one key, amortizing the per-key setup
+pub struct BatchSigner {
+    keypair: Keypair,
+    drbg: KeyedDrbg,
+    x_only: XOnlyPoint,
+    /// The secret key negated if needed to match `x_only`
+    schnorr_key: Zeroizing<Scalar>,
+}
+
+impl BatchSigner {
+    pub fn new(keypair: &Keypair) -> Self {
+        let (x_only, schnorr_key) = schnorr::signing_key(keypair);
+        BatchSigner {
+            keypair: *keypair,
+            drbg: KeyedDrbg::new(&Zeroizing::new(keypair.secret_key().to_bytes())),
+            x_only,
+            schnorr_key,
+        }
+    }
+
+    /// Signs the message hashes with ECDSA, same as calling `ecdsa::sign` for each of them
+    pub fn sign_ecdsa_many(&self, msgs: &[[u8; 32]]) -> Vec<Signature> {
+        msgs.iter()
+            .map(|msg| ecdsa::sign_with(&Message::from_digest(*msg), self.keypair.secret_key(), &mut PreparedRfc6979(&self.drbg)))
+            .collect()
+    }
+
+    /// Signs the messages with BIP340, same as calling `schnorr::sign` for each of them with the
+    /// same `aux_rand`.
+    ///
+    /// Reusing `aux_rand` for different messages is secure since the nonce is derived from the
+    /// message as well.
+    pub fn sign_schnorr_many(&self, msgs: &[[u8; 32]], aux_rand: &[u8; 32]) -> Vec<[u8; 64]> {
+        let nonce_hasher = schnorr::nonce_hasher(&self.schnorr_key, &self.x_only, aux_rand);
+        msgs.iter()
+            .map(|msg| schnorr::sign_prepared(msg, &self.x_only, &self.schnorr_key, &nonce_hasher))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+    use super::BatchSigner;
+    use crate::test_utils::{deterministic_keypair, DeterministicRng};
+    use crate::{ecdsa, schnorr, Message};
+
+    #[test]
+    fn identical_to_one_shot() {
+        let mut rng = DeterministicRng::new(201);
+        let mut msgs = [[0; 32]; 20];
+        for msg in &mut msgs {
+            rng.fill_bytes(msg);
+        }
+        // zero and the order reduce to the same scalar which RFC 6979 hashes
+        msgs[0] = [0; 32];
+        msgs[1] = crate::params::N.to_be_bytes();
+        for index in 0..4 {
+            let keypair = deterministic_keypair(index);
+            let signer = BatchSigner::new(&keypair);
+
+            let expected = msgs.iter().map(|msg| ecdsa::sign(&Message::from_digest(*msg), keypair.secret_key())).collect::<Vec<_>>();
+            assert_eq!(signer.sign_ecdsa_many(&msgs), expected);
+
+            let aux_rand = [index as u8; 32];
+            let expected = msgs.iter().map(|msg| schnorr::sign(&Message::from_digest(*msg), &keypair, &aux_rand)).collect::<Vec<_>>();
+            assert_eq!(signer.sign_schnorr_many(&msgs, &aux_rand), expected);
+        }
+        assert!(BatchSigner::new(&deterministic_keypair(0)).sign_ecdsa_many(&[]).is_empty());
+    }
+}
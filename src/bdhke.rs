@@ -0,0 +1,120 @@
+//! Blind Diffie-Hellman key exchange as used by Cashu ecash (NUT-00)
+//!
+//! The user hashes its secret to a point `Y`, blinds it as `B' = Y + r·G` and sends it to the
+//! mint. The mint signs it as `C' = k·B'` without learning `Y`. The user unblinds the signature
+//! as `C = C' - r·K = k·Y` where `K = k·G` is the public key of the mint. Later the mint accepts
+//! the token `(secret, C)` if `C = k·hash_to_curve(secret)`.
+//!
+//! Hashing to the curve follows NUT-00 so the points (serialized compressed) are interoperable
+//! with other implementations. This is **NOT CONSTANT TIME!!!**
+
+use crate::hashes::sha256_chunks;
+use crate::{Point, PublicKey, Scalar, SecretKey, Zp, G};
+
+const DOMAIN_SEPARATOR: &[u8] = b"Secp256k1_HashToCurve_Cashu_";
+
+/// Maps the message to a point with unknown discrete logarithm.
+///
+/// Computes `h = SHA256(DOMAIN_SEPARATOR || msg)` and returns the point with even y and the x
+/// coordinate `SHA256(h || counter)` for the lowest 4-byte little-endian `counter` for which it
+/// exists.
+pub fn hash_to_curve(msg: &[u8]) -> Point {
+    let msg_hash = sha256_chunks(&[DOMAIN_SEPARATOR, msg]);
+    // NUT-00 gives up after 2¹⁶ attempts, each one succeeds with probability about 1/2
+    (0..1u32 << 16)
+        .find_map(|counter| Zp::from_be_bytes(&sha256_chunks(&[&msg_hash, &counter.to_le_bytes()])).and_then(Point::lift_x))
+        .expect("the probability of 65536 consecutive failures is negligible")
+}
+
+/// Blinds the secret for the mint, computing `B' = hash_to_curve(secret_msg) + r·G`.
+///
+/// `blinding` must be random and secret, it's needed again to unblind the signature.
+pub fn blind_message(secret_msg: &[u8], blinding: &Scalar) -> Point {
+    hash_to_curve(secret_msg) + G * *blinding
+}
+
+/// Signs the blinded message by the key of the mint, computing `C' = k·B'`
+pub fn sign_blinded(key: &SecretKey, blinded_message: &Point) -> Point {
+    *blinded_message * key.to_scalar()
+}
+
+/// Unblinds the signature returned by the mint, computing `C = C' - r·K`.
+///
+/// The result is a valid token only if `mint_public_key` is the key that signed it.
+pub fn unblind(blinded_signature: &Point, blinding: &Scalar, mint_public_key: &PublicKey) -> Point {
+    *blinded_signature + -(mint_public_key.to_point() * *blinding)
+}
+
+/// Checks that the unblinded signature is `k·hash_to_curve(secret_msg)`
+pub fn verify_token(key: &SecretKey, secret_msg: &[u8], signature: &Point) -> bool {
+    (hash_to_curve(secret_msg) * key.to_scalar()).eq_ct(signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blind_message, hash_to_curve, sign_blinded, unblind, verify_token};
+    use crate::test_util::{hex, hex32};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Point, Scalar, SecretKey};
+
+    fn point(hex_str: &str) -> Point {
+        Point::from_sec1_bytes(&hex(hex_str)).unwrap()
+    }
+
+    fn scalar(hex_str: &str) -> Scalar {
+        Scalar::from_be_bytes(&hex32(hex_str)).unwrap()
+    }
+
+    /// NUT-00 test vectors
+    #[test]
+    fn hash_to_curve_vectors() {
+        let vectors = [
+            ("0000000000000000000000000000000000000000000000000000000000000000", "024cce997d3b518f739663b757deaec95bcd9473c30a14ac2fd04023a739d1a725"),
+            ("0000000000000000000000000000000000000000000000000000000000000001", "022e7158e11c9506f1aa4248bf531298daa7febd6194f003edcd9b93ade6253acf"),
+            ("0000000000000000000000000000000000000000000000000000000000000002", "026cdbe15362df59cd1dd3c9c11de8aedac2106eca69236ecd9fbe117af897be4f"),
+        ];
+        for (msg, expected) in &vectors {
+            assert_eq!(hash_to_curve(&hex(msg)), point(expected), "{}", msg);
+        }
+    }
+
+    /// NUT-00 test vectors
+    #[test]
+    fn blinding_and_signing_vectors() {
+        let blinded = blind_message(b"test_message", &scalar("0000000000000000000000000000000000000000000000000000000000000001"));
+        assert_eq!(blinded, point("025cc16fe33b953e2ace39653efb3e7a7049711ae1d8a2f7a9108753f1cdea742b"));
+
+        let blinded = point("02a9acc1e48c25eeeb9289b5031cc57da9fe72f3fe2861d264bdc074209b107ba2");
+        let key = SecretKey::from_bytes(&hex32("0000000000000000000000000000000000000000000000000000000000000001")).unwrap();
+        assert_eq!(sign_blinded(&key, &blinded), blinded);
+        let key = SecretKey::from_bytes(&hex32("7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f")).unwrap();
+        assert_eq!(sign_blinded(&key, &blinded), point("0398bc70ce8184d27ba89834d19f5199c84443c31131e48d3c1214db24247d005d"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut rng = DeterministicRng::new(223);
+        let key = SecretKey::from_scalar(Scalar::random(&mut rng)).unwrap();
+        let blinding = Scalar::random(&mut rng);
+        let blinded = blind_message(b"secret", &blinding);
+        // the mint only sees a point unrelated to the secret
+        assert_ne!(blinded, hash_to_curve(b"secret"));
+        let signature = unblind(&sign_blinded(&key, &blinded), &blinding, &key.public_key());
+        assert!(verify_token(&key, b"secret", &signature));
+        assert!(!verify_token(&key, b"other secret", &signature));
+        let other_key = SecretKey::from_scalar(Scalar::random(&mut rng)).unwrap();
+        assert!(!verify_token(&other_key, b"secret", &signature));
+    }
+
+    #[test]
+    fn wrong_mint_key() {
+        let mut rng = DeterministicRng::new(223);
+        let key = SecretKey::from_scalar(Scalar::random(&mut rng)).unwrap();
+        let other_key = SecretKey::from_scalar(Scalar::random(&mut rng)).unwrap();
+        let blinding = Scalar::random(&mut rng);
+        let blinded_signature = sign_blinded(&key, &blind_message(b"secret", &blinding));
+        let signature = unblind(&blinded_signature, &blinding, &other_key.public_key());
+        assert!(!verify_token(&key, b"secret", &signature));
+        assert!(!verify_token(&other_key, b"secret", &signature));
+    }
+}
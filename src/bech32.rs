@@ -0,0 +1,148 @@
+//! Segwit address encoding - bech32 (BIP173) and bech32m (BIP350)
+
+use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
+use crate::Error;
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+const BECH32M_CONST: u32 = 0x2bc830a3;
+
+/// Checksum variant, witness version 0 uses bech32, the later ones bech32m
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum Variant {
+    Bech32,
+    Bech32m,
+}
+
+impl Variant {
+    fn constant(self) -> u32 {
+        match self {
+            Variant::Bech32 => 1,
+            Variant::Bech32m => BECH32M_CONST,
+        }
+    }
+}
+
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+    let mut checksum = 1u32;
+    for value in values {
+        let top = checksum >> 25;
+        checksum = (checksum & 0x1ffffff) << 5 ^ u32::from(value);
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+fn hrp_expand(hrp: &str) -> impl Iterator<Item = u8> + '_ {
+    hrp.bytes().map(|b| b >> 5).chain(Some(0)).chain(hrp.bytes().map(|b| b & 31))
+}
+
+/// Regroups bits, `pad` controls whether the incomplete last group is padded or rejected
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, Error> {
+    let mut acc = 0u32;
+    let mut bits = 0;
+    let mut result = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+    let max = (1 << to) - 1;
+    for &value in data {
+        acc = (acc << from) | u32::from(value);
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            result.push(((acc >> bits) & max) as u8);
+        }
+    }
+    if pad {
+        if bits > 0 {
+            result.push(((acc << (to - bits)) & max) as u8);
+        }
+    } else if bits >= from || (acc << (to - bits)) & max != 0 {
+        return Err(Error::InvalidPadding);
+    }
+    Ok(result)
+}
+
+/// Encodes a segwit address, the checksum variant is chosen based on the version
+pub(crate) fn encode_segwit(hrp: &str, version: u8, program: &[u8]) -> String {
+    let variant = if version == 0 { Variant::Bech32 } else { Variant::Bech32m };
+    let mut data = vec![version];
+    data.extend(convert_bits(program, 8, 5, true).expect("padding never fails"));
+
+    let checksum = polymod(hrp_expand(hrp).chain(data.iter().copied()).chain([0; 6].iter().copied()))
+        ^ variant.constant();
+    let checksum = (0..6).map(|i| ((checksum >> (5 * (5 - i))) & 31) as u8);
+
+    let mut address = String::with_capacity(hrp.len() + 1 + data.len() + 6);
+    address.push_str(hrp);
+    address.push('1');
+    address.extend(data.into_iter().chain(checksum).map(|value| char::from(CHARSET[usize::from(value)])));
+    address
+}
+
+/// Decodes a segwit address returning the (lower-case) hrp, witness version and program.
+///
+/// The checksum variant is enforced according to BIP350.
+pub(crate) fn decode_segwit(address: &str) -> Result<(String, u8, Vec<u8>), Error> {
+    let has_lower = address.bytes().any(|b| b.is_ascii_lowercase());
+    let has_upper = address.bytes().any(|b| b.is_ascii_uppercase());
+    if has_lower && has_upper {
+        return Err(Error::MixedCase);
+    }
+    let address = address.to_ascii_lowercase();
+    let separator = address.rfind('1').ok_or(Error::MissingSeparator)?;
+    let (hrp, data) = (&address[..separator], &address[(separator + 1)..]);
+    if hrp.is_empty() || hrp.bytes().any(|b| !(33..=126).contains(&b)) {
+        return Err(Error::InvalidHrp);
+    }
+    // at least witness version and checksum
+    if data.len() < 7 {
+        return Err(Error::InvalidLength(data.len()));
+    }
+    let data = data
+        .chars()
+        .map(|c| CHARSET.iter().position(|&v| char::from(v) == c).map(|v| v as u8).ok_or(Error::InvalidBech32Character(c)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let version = data[0];
+    if version > 16 {
+        return Err(Error::UnsupportedWitnessVersion(version));
+    }
+    let variant = if version == 0 { Variant::Bech32 } else { Variant::Bech32m };
+    if polymod(hrp_expand(hrp).chain(data.iter().copied())) != variant.constant() {
+        return Err(Error::InvalidChecksum);
+    }
+    let program = convert_bits(&data[1..(data.len() - 6)], 5, 8, false)?;
+    if program.len() < 2 || program.len() > 40 || (version == 0 && program.len() != 20 && program.len() != 32) {
+        return Err(Error::InvalidLength(program.len()));
+    }
+
+    Ok((hrp.to_owned(), version, program))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_segwit, encode_segwit};
+
+    #[test]
+    fn bip350_valid_round_trip() {
+        let addresses = [
+            "BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4",
+            "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7",
+            "bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7kt5nd6y",
+            "BC1SW50QGDZ25J",
+            "bc1zw508d6qejxtdg4y5r3zarvaryvaxxpcs",
+            "tb1qqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesrxh6hy",
+            "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+        ];
+        for address in &addresses {
+            let (hrp, version, program) = decode_segwit(address).unwrap();
+            assert_eq!(encode_segwit(&hrp, version, &program), address.to_ascii_lowercase());
+        }
+    }
+}
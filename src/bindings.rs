@@ -0,0 +1,188 @@
+//! Byte-oriented API for foreign-language bindings
+//!
+//! Foreign languages can't use the types of this crate directly so this module exposes the
+//! common operations taking and returning plain bytes. Secret keys, tweaks and 32-byte messages
+//! are big-endian 32-byte arrays, public keys are compressed SEC1 on output and either SEC1
+//! form on input, x-only keys and signatures are 32 and 64 bytes respectively. Lengths are
+//! checked, `BindingsError::InvalidLength` is returned for wrong ones. The errors are the flat
+//! `BindingsError` rather than `Error` so that they map one-to-one to an error enum of the
+//! foreign language.
+//!
+//! The crate can't generate randomness so key generation takes 32 bytes from the platform's
+//! secure random generator and only rejects the negligible fraction of invalid keys.
+
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use crate::ecdsa::{self, Signature};
+use crate::{ecdh, schnorr, BindingsError, Keypair, Message, PublicKey, Scalar, SecretKey, XOnlyPoint, G};
+
+fn array_32(bytes: &[u8]) -> Result<&[u8; 32], BindingsError> {
+    bytes.try_into().map_err(|_| BindingsError::InvalidLength)
+}
+
+fn array_64(bytes: &[u8]) -> Result<&[u8; 64], BindingsError> {
+    bytes.try_into().map_err(|_| BindingsError::InvalidLength)
+}
+
+fn secret_key(bytes: &[u8]) -> Result<SecretKey, BindingsError> {
+    SecretKey::from_bytes(array_32(bytes)?).map_err(|_| BindingsError::InvalidSecretKey)
+}
+
+fn public_key(bytes: &[u8]) -> Result<PublicKey, BindingsError> {
+    match bytes.len() {
+        33 | 65 => PublicKey::from_sec1_bytes(bytes).map_err(|_| BindingsError::InvalidPublicKey),
+        _ => Err(BindingsError::InvalidLength),
+    }
+}
+
+fn x_only_public_key(bytes: &[u8]) -> Result<XOnlyPoint, BindingsError> {
+    XOnlyPoint::from_bytes(array_32(bytes)?).map_err(|_| BindingsError::InvalidPublicKey)
+}
+
+fn tweak(bytes: &[u8]) -> Result<Scalar, BindingsError> {
+    Scalar::from_be_bytes(array_32(bytes)?).ok_or(BindingsError::InvalidTweak)
+}
+
+/// Checks that the random bytes form a valid secret key and returns them.
+///
+/// Returns `BindingsError::InvalidSecretKey` if they don't in which case the caller should draw
+/// new ones.
+pub fn secret_key_from_random(random: &[u8]) -> Result<Vec<u8>, BindingsError> {
+    Ok(secret_key(random)?.to_bytes().to_vec())
+}
+
+/// Computes the compressed public key of the secret key
+pub fn public_key_create(secret_key: &[u8]) -> Result<Vec<u8>, BindingsError> {
+    Ok(self::secret_key(secret_key)?.public_key().serialize().to_vec())
+}
+
+/// Parses the public key in either SEC1 form returning it compressed
+pub fn public_key_parse(public_key: &[u8]) -> Result<Vec<u8>, BindingsError> {
+    Ok(self::public_key(public_key)?.serialize().to_vec())
+}
+
+/// Parses the public key in either SEC1 form returning it uncompressed
+pub fn public_key_serialize_uncompressed(public_key: &[u8]) -> Result<Vec<u8>, BindingsError> {
+    Ok(self::public_key(public_key)?.serialize_uncompressed().to_vec())
+}
+
+/// Returns the x-only (BIP340) public key of the secret key
+pub fn x_only_public_key_create(secret_key: &[u8]) -> Result<Vec<u8>, BindingsError> {
+    Ok(self::secret_key(secret_key)?.public_key().x_only_public_key().0.serialize().to_vec())
+}
+
+/// Signs the 32-byte message hash returning compact (`r || s`) low-S signature
+pub fn ecdsa_sign(msg32: &[u8], secret_key: &[u8]) -> Result<Vec<u8>, BindingsError> {
+    Ok(ecdsa::sign(&Message::from_digest(*array_32(msg32)?), &self::secret_key(secret_key)?).serialize_compact().to_vec())
+}
+
+/// Verifies compact ECDSA signature of the 32-byte message hash.
+///
+/// Returns `Ok(false)` if the signature doesn't match and errors only for malformed inputs.
+pub fn ecdsa_verify(msg32: &[u8], signature: &[u8], public_key: &[u8]) -> Result<bool, BindingsError> {
+    let signature = Signature::from_compact(array_64(signature)?).map_err(|_| BindingsError::InvalidSignature)?;
+    let public_key = self::public_key(public_key)?;
+    Ok(ecdsa::verify(&Message::from_digest(*array_32(msg32)?), &signature, &public_key).is_ok())
+}
+
+/// Creates BIP340 signature of the message
+pub fn schnorr_sign(msg: &[u8], secret_key: &[u8], aux_rand: &[u8]) -> Result<Vec<u8>, BindingsError> {
+    let keypair = Keypair::from_secret_key(&self::secret_key(secret_key)?);
+    Ok(schnorr::sign_arbitrary(msg, &keypair, array_32(aux_rand)?).to_vec())
+}
+
+/// Verifies BIP340 signature of the message with the 32-byte x-only public key.
+///
+/// Returns `Ok(false)` if the signature doesn't match and errors only for malformed inputs.
+pub fn schnorr_verify(msg: &[u8], signature: &[u8], public_key: &[u8]) -> Result<bool, BindingsError> {
+    let signature = array_64(signature)?;
+    let public_key = x_only_public_key(public_key)?;
+    Ok(schnorr::verify_arbitrary(msg, signature, &public_key).is_ok())
+}
+
+/// Computes the ECDH shared secret, see `ecdh::shared_secret`
+pub fn ecdh(secret_key: &[u8], public_key: &[u8]) -> Result<Vec<u8>, BindingsError> {
+    let public_key = self::public_key(public_key)?;
+    Ok(ecdh::shared_secret(&self::secret_key(secret_key)?, &public_key).to_vec())
+}
+
+/// Computes `secret_key + tweak`
+///
+/// Returns `BindingsError::InvalidTweak` if the tweak is not less than the curve order or the
+/// result is zero.
+pub fn secret_key_tweak_add(secret_key: &[u8], tweak: &[u8]) -> Result<Vec<u8>, BindingsError> {
+    let tweaked = self::secret_key(secret_key)?.to_scalar() + self::tweak(tweak)?;
+    Ok(SecretKey::from_scalar(tweaked).ok_or(BindingsError::InvalidTweak)?.to_bytes().to_vec())
+}
+
+/// Computes `public_key + tweak·G` returning it compressed
+///
+/// Returns `BindingsError::InvalidTweak` if the tweak is not less than the curve order or the
+/// result is the point at infinity.
+pub fn public_key_tweak_add(public_key: &[u8], tweak: &[u8]) -> Result<Vec<u8>, BindingsError> {
+    let tweaked = self::public_key(public_key)?.to_point() + G * self::tweak(tweak)?;
+    Ok(PublicKey::from_point(tweaked).ok_or(BindingsError::InvalidTweak)?.serialize().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BindingsError;
+
+    #[test]
+    fn keys() {
+        let secret_key = super::secret_key_from_random(&[0x42; 32]).unwrap();
+        assert_eq!(super::secret_key_from_random(&[0xFF; 32]), Err(BindingsError::InvalidSecretKey));
+        assert_eq!(super::secret_key_from_random(&[0x42; 31]), Err(BindingsError::InvalidLength));
+        let public_key = super::public_key_create(&secret_key).unwrap();
+        assert_eq!(public_key.len(), 33);
+        let uncompressed = super::public_key_serialize_uncompressed(&public_key).unwrap();
+        assert_eq!(super::public_key_parse(&uncompressed), Ok(public_key.clone()));
+        assert_eq!(super::x_only_public_key_create(&secret_key).unwrap(), public_key[1..]);
+        assert_eq!(super::public_key_parse(&public_key[1..]), Err(BindingsError::InvalidLength));
+    }
+
+    #[test]
+    fn signatures() {
+        let secret_key = [0x42; 32];
+        let public_key = super::public_key_create(&secret_key).unwrap();
+        let msg32 = [0x07; 32];
+        let signature = super::ecdsa_sign(&msg32, &secret_key).unwrap();
+        assert_eq!(super::ecdsa_verify(&msg32, &signature, &public_key), Ok(true));
+        assert_eq!(super::ecdsa_verify(&[0x08; 32], &signature, &public_key), Ok(false));
+        assert_eq!(super::ecdsa_verify(&msg32, &signature[1..], &public_key), Err(BindingsError::InvalidLength));
+
+        let x_only = super::x_only_public_key_create(&secret_key).unwrap();
+        let signature = super::schnorr_sign(b"message", &secret_key, &[0; 32]).unwrap();
+        assert_eq!(super::schnorr_verify(b"message", &signature, &x_only), Ok(true));
+        assert_eq!(super::schnorr_verify(b"Message", &signature, &x_only), Ok(false));
+        assert_eq!(super::schnorr_verify(b"message", &signature, &public_key), Err(BindingsError::InvalidLength));
+    }
+
+    #[test]
+    fn ecdh_and_tweaks() {
+        let alice = [0x42; 32];
+        let bob = [0x43; 32];
+        let shared = super::ecdh(&alice, &super::public_key_create(&bob).unwrap()).unwrap();
+        assert_eq!(super::ecdh(&bob, &super::public_key_create(&alice).unwrap()), Ok(shared));
+
+        let tweak = [0x01; 32];
+        let tweaked = super::secret_key_tweak_add(&alice, &tweak).unwrap();
+        let public_key = super::public_key_create(&alice).unwrap();
+        assert_eq!(super::public_key_tweak_add(&public_key, &tweak), super::public_key_create(&tweaked));
+        assert_eq!(super::secret_key_tweak_add(&alice, &[0xFF; 32]), Err(BindingsError::InvalidTweak));
+    }
+
+    #[test]
+    fn malformed_inputs() {
+        let secret_key = [0x42; 32];
+        let mut public_key = super::public_key_create(&secret_key).unwrap();
+        let signature = super::ecdsa_sign(&[0x07; 32], &secret_key).unwrap();
+        assert_eq!(super::public_key_create(&[0; 32]), Err(BindingsError::InvalidSecretKey));
+        assert_eq!(super::ecdsa_verify(&[0x07; 32], &[0xFF; 64], &public_key), Err(BindingsError::InvalidSignature));
+        assert_eq!(super::schnorr_verify(b"message", &[0; 64], &[0xFF; 32]), Err(BindingsError::InvalidPublicKey));
+        public_key[0] = 0x05;
+        assert_eq!(super::public_key_parse(&public_key), Err(BindingsError::InvalidPublicKey));
+        assert_eq!(super::ecdsa_verify(&[0x07; 32], &signature, &public_key), Err(BindingsError::InvalidPublicKey));
+        assert_eq!(super::ecdh(&secret_key, &public_key), Err(BindingsError::InvalidPublicKey));
+    }
+}
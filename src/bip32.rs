@@ -0,0 +1,607 @@
+//! BIP32 hierarchical deterministic keys
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+use core::str::FromStr;
+use crate::{base58, array_ref_32, Error, Network, PublicKey, Scalar, SecretKey, G};
+use crate::hashes::{hash160, hmac_sha512};
+
+const VERSION_XPRV: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4];
+const VERSION_XPUB: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+const VERSION_TPRV: [u8; 4] = [0x04, 0x35, 0x83, 0x94];
+const VERSION_TPUB: [u8; 4] = [0x04, 0x35, 0x87, 0xCF];
+
+/// Length of the serialized extended key without the checksum
+const PAYLOAD_LEN: usize = 78;
+
+/// Index of a child key, hardened indices have the highest bit set
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ChildNumber(u32);
+
+impl ChildNumber {
+    const HARDENED_BIT: u32 = 1 << 31;
+
+    /// Returns an error if the index is not less than 2^31
+    pub fn from_normal_idx(index: u32) -> Result<Self, Error> {
+        if index & Self::HARDENED_BIT == 0 {
+            Ok(ChildNumber(index))
+        } else {
+            Err(Error::ChildNumberOutOfRange)
+        }
+    }
+
+    /// Returns an error if the index is not less than 2^31
+    pub fn from_hardened_idx(index: u32) -> Result<Self, Error> {
+        Self::from_normal_idx(index).map(|child| ChildNumber(child.0 | Self::HARDENED_BIT))
+    }
+
+    pub fn is_hardened(&self) -> bool {
+        self.0 & Self::HARDENED_BIT != 0
+    }
+
+    /// Returns the index without the hardened bit
+    pub fn index(&self) -> u32 {
+        self.0 & !Self::HARDENED_BIT
+    }
+}
+
+/// Displays the index with `'` suffix if the number is hardened
+impl fmt::Display for ChildNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_hardened() {
+            write!(f, "{}'", self.index())
+        } else {
+            write!(f, "{}", self.index())
+        }
+    }
+}
+
+/// Parses the index optionally followed by `'` or `h` marking it as hardened
+impl FromStr for ChildNumber {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (index, hardened) = match s.strip_suffix(|c| c == '\'' || c == 'h') {
+            Some(index) => (index, true),
+            None => (s, false),
+        };
+        if index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::InvalidChildNumber);
+        }
+        // only digits are present so the only possible failure is overflow
+        let index = index.parse::<u32>().map_err(|_| Error::ChildNumberOutOfRange)?;
+        if hardened {
+            ChildNumber::from_hardened_idx(index)
+        } else {
+            ChildNumber::from_normal_idx(index)
+        }
+    }
+}
+
+impl From<u32> for ChildNumber {
+    fn from(value: u32) -> Self {
+        ChildNumber(value)
+    }
+}
+
+impl From<ChildNumber> for u32 {
+    fn from(value: ChildNumber) -> Self {
+        value.0
+    }
+}
+
+/// Sequence of child numbers leading from the master key to a derived key
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct DerivationPath(Vec<ChildNumber>);
+
+impl DerivationPath {
+    /// The empty path (`m`) - refers to the master key itself
+    pub fn master() -> Self {
+        DerivationPath(Vec::new())
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, ChildNumber> {
+        self.0.iter()
+    }
+}
+
+impl From<Vec<ChildNumber>> for DerivationPath {
+    fn from(value: Vec<ChildNumber>) -> Self {
+        DerivationPath(value)
+    }
+}
+
+impl AsRef<[ChildNumber]> for DerivationPath {
+    fn as_ref(&self) -> &[ChildNumber] {
+        &self.0
+    }
+}
+
+impl<'a> IntoIterator for &'a DerivationPath {
+    type Item = &'a ChildNumber;
+    type IntoIter = core::slice::Iter<'a, ChildNumber>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("m")?;
+        for child in &self.0 {
+            write!(f, "/{}", child)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses paths like `m/84'/0'/0'` or `m/84h/0h/0h`
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.ends_with('/') {
+            return Err(Error::TrailingSlash);
+        }
+        let mut components = s.split('/');
+        if components.next() != Some("m") {
+            return Err(Error::InvalidPathPrefix);
+        }
+        components
+            .map(|component| if component.is_empty() {
+                Err(Error::EmptyPathComponent)
+            } else {
+                component.parse()
+            })
+            .collect::<Result<_, _>>()
+            .map(DerivationPath)
+    }
+}
+
+/// Identifies the key by the first four bytes of HASH160 of the key
+fn fingerprint(public_key: &PublicKey) -> [u8; 4] {
+    let mut fingerprint = [0; 4];
+    fingerprint.copy_from_slice(&hash160(&public_key.serialize())[..4]);
+    fingerprint
+}
+
+/// Extended private key
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ExtendedPrivKey {
+    pub network: Network,
+    pub depth: u8,
+    pub parent_fingerprint: [u8; 4],
+    pub child_number: ChildNumber,
+    pub chain_code: [u8; 32],
+    pub private_key: SecretKey,
+}
+
+/// Extended public key
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ExtendedPubKey {
+    pub network: Network,
+    pub depth: u8,
+    pub parent_fingerprint: [u8; 4],
+    pub child_number: ChildNumber,
+    pub chain_code: [u8; 32],
+    pub public_key: PublicKey,
+}
+
+impl ExtendedPrivKey {
+    /// Computes the master key from the seed
+    pub fn new_master(network: Network, seed: &[u8]) -> Result<Self, Error> {
+        let i = hmac_sha512(b"Bitcoin seed", &[seed]);
+
+        Ok(ExtendedPrivKey {
+            network,
+            depth: 0,
+            parent_fingerprint: [0; 4],
+            child_number: ChildNumber(0),
+            chain_code: *array_ref_32(&i[32..]),
+            private_key: SecretKey::from_bytes(array_ref_32(&i[..32]))?,
+        })
+    }
+
+    pub fn fingerprint(&self) -> [u8; 4] {
+        fingerprint(&self.private_key.public_key())
+    }
+
+    /// Derives the child private key (CKDpriv)
+    ///
+    /// The error is returned if the resulting key would be invalid, the caller should proceed with
+    /// the next index as mandated by BIP32. (This is astronomically unlikely.)
+    pub fn derive_child(&self, child_number: ChildNumber) -> Result<Self, Error> {
+        let public_key = self.private_key.public_key();
+        let index = child_number.0.to_be_bytes();
+        let i = if child_number.is_hardened() {
+            hmac_sha512(&self.chain_code, &[&[0], &self.private_key.to_bytes(), &index])
+        } else {
+            hmac_sha512(&self.chain_code, &[&public_key.serialize(), &index])
+        };
+        let tweak = Scalar::from_be_bytes(array_ref_32(&i[..32])).ok_or(Error::InvalidChildKey)?;
+        let private_key = SecretKey::from_scalar(self.private_key.to_scalar() + tweak).ok_or(Error::InvalidChildKey)?;
+
+        Ok(ExtendedPrivKey {
+            network: self.network,
+            depth: self.depth.checked_add(1).ok_or(Error::MaxDepthExceeded)?,
+            parent_fingerprint: fingerprint(&public_key),
+            child_number,
+            chain_code: *array_ref_32(&i[32..]),
+            private_key,
+        })
+    }
+
+    /// Derives the key at `path` relative to this key
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, Error> {
+        path.iter().try_fold(*self, |key, child| key.derive_child(*child))
+    }
+}
+
+impl ExtendedPubKey {
+    /// Computes the public counterpart of the private key
+    pub fn from_priv(xpriv: &ExtendedPrivKey) -> Self {
+        ExtendedPubKey {
+            network: xpriv.network,
+            depth: xpriv.depth,
+            parent_fingerprint: xpriv.parent_fingerprint,
+            child_number: xpriv.child_number,
+            chain_code: xpriv.chain_code,
+            public_key: xpriv.private_key.public_key(),
+        }
+    }
+
+    pub fn fingerprint(&self) -> [u8; 4] {
+        fingerprint(&self.public_key)
+    }
+
+    /// Derives the child public key (CKDpub)
+    ///
+    /// Hardened children can not be derived from public keys.
+    pub fn derive_child(&self, child_number: ChildNumber) -> Result<Self, Error> {
+        if child_number.is_hardened() {
+            return Err(Error::HardenedDerivationFromPublicKey);
+        }
+        let i = hmac_sha512(&self.chain_code, &[&self.public_key.serialize(), &child_number.0.to_be_bytes()]);
+        let tweak = Scalar::from_be_bytes(array_ref_32(&i[..32])).ok_or(Error::InvalidChildKey)?;
+        let public_key = PublicKey::from_point(self.public_key.to_point() + G * tweak).ok_or(Error::InvalidChildKey)?;
+
+        Ok(ExtendedPubKey {
+            network: self.network,
+            depth: self.depth.checked_add(1).ok_or(Error::MaxDepthExceeded)?,
+            parent_fingerprint: self.fingerprint(),
+            child_number,
+            chain_code: *array_ref_32(&i[32..]),
+            public_key,
+        })
+    }
+
+    /// Derives the key at `path` relative to this key
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, Error> {
+        path.iter().try_fold(*self, |key, child| key.derive_child(*child))
+    }
+}
+
+/// Fields shared by both kinds of extended keys
+struct Payload {
+    version: [u8; 4],
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: ChildNumber,
+    chain_code: [u8; 32],
+    key: [u8; 33],
+}
+
+impl Payload {
+    fn encode(&self) -> String {
+        let mut buf = [0; PAYLOAD_LEN];
+        buf[0..4].copy_from_slice(&self.version);
+        buf[4] = self.depth;
+        buf[5..9].copy_from_slice(&self.parent_fingerprint);
+        buf[9..13].copy_from_slice(&self.child_number.0.to_be_bytes());
+        buf[13..45].copy_from_slice(&self.chain_code);
+        buf[45..78].copy_from_slice(&self.key);
+        base58::encode_check(&buf)
+    }
+
+    fn decode(string: &str) -> Result<Self, Error> {
+        use core::convert::TryInto;
+
+        let data = base58::decode_check(string)?;
+        if data.len() != PAYLOAD_LEN {
+            return Err(Error::InvalidLength(data.len()));
+        }
+
+        let payload = Payload {
+            version: data[0..4].try_into().expect("4 bytes"),
+            depth: data[4],
+            parent_fingerprint: data[5..9].try_into().expect("4 bytes"),
+            child_number: ChildNumber(u32::from_be_bytes(data[9..13].try_into().expect("4 bytes"))),
+            chain_code: *array_ref_32(&data[13..45]),
+            key: data[45..78].try_into().expect("33 bytes"),
+        };
+        if payload.depth == 0 && (payload.parent_fingerprint != [0; 4] || payload.child_number.0 != 0) {
+            return Err(Error::InvalidMasterKey);
+        }
+        Ok(payload)
+    }
+}
+
+impl fmt::Display for ExtendedPrivKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut key = [0; 33];
+        key[1..].copy_from_slice(&self.private_key.to_bytes());
+        let version = match self.network {
+            Network::Bitcoin => VERSION_XPRV,
+            Network::Testnet => VERSION_TPRV,
+        };
+        let payload = Payload {
+            version,
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            chain_code: self.chain_code,
+            key,
+        };
+        f.write_str(&payload.encode())
+    }
+}
+
+impl FromStr for ExtendedPrivKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let payload = Payload::decode(s)?;
+        let network = match payload.version {
+            VERSION_XPRV => Network::Bitcoin,
+            VERSION_TPRV => Network::Testnet,
+            version => return Err(Error::UnknownVersion(version)),
+        };
+        match payload.key[0] {
+            0x00 => (),
+            0x02 | 0x03 => return Err(Error::UnexpectedPublicKey),
+            _ => return Err(Error::InvalidSecretKey),
+        }
+
+        Ok(ExtendedPrivKey {
+            network,
+            depth: payload.depth,
+            parent_fingerprint: payload.parent_fingerprint,
+            child_number: payload.child_number,
+            chain_code: payload.chain_code,
+            private_key: SecretKey::from_bytes(array_ref_32(&payload.key[1..]))?,
+        })
+    }
+}
+
+impl fmt::Display for ExtendedPubKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let version = match self.network {
+            Network::Bitcoin => VERSION_XPUB,
+            Network::Testnet => VERSION_TPUB,
+        };
+        let payload = Payload {
+            version,
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            chain_code: self.chain_code,
+            key: self.public_key.serialize(),
+        };
+        f.write_str(&payload.encode())
+    }
+}
+
+impl FromStr for ExtendedPubKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let payload = Payload::decode(s)?;
+        let network = match payload.version {
+            VERSION_XPUB => Network::Bitcoin,
+            VERSION_TPUB => Network::Testnet,
+            version => return Err(Error::UnknownVersion(version)),
+        };
+
+        Ok(ExtendedPubKey {
+            network,
+            depth: payload.depth,
+            parent_fingerprint: payload.parent_fingerprint,
+            child_number: payload.child_number,
+            chain_code: payload.chain_code,
+            public_key: PublicKey::from_sec1_bytes(&payload.key)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChildNumber, DerivationPath, ExtendedPrivKey, ExtendedPubKey};
+    use crate::{base58, Error, Network};
+    use crate::test_util::hex;
+
+    struct TestVector {
+        seed: &'static str,
+        // (path, xprv, xpub)
+        keys: &'static [(&'static str, &'static str, &'static str)],
+    }
+
+    // BIP32 test vectors 1-3
+    const VECTORS: &[TestVector] = &[
+        TestVector {
+            seed: "000102030405060708090a0b0c0d0e0f",
+            keys: &[
+                ("m",
+                 "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi",
+                 "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8"),
+                ("m/0h",
+                 "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7",
+                 "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw"),
+                ("m/0h/1",
+                 "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs",
+                 "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ"),
+                ("m/0h/1/2h",
+                 "xprv9z4pot5VBttmtdRTWfWQmoH1taj2axGVzFqSb8C9xaxKymcFzXBDptWmT7FwuEzG3ryjH4ktypQSAewRiNMjANTtpgP4mLTj34bhnZX7UiM",
+                 "xpub6D4BDPcP2GT577Vvch3R8wDkScZWzQzMMUm3PWbmWvVJrZwQY4VUNgqFJPMM3No2dFDFGTsxxpG5uJh7n7epu4trkrX7x7DogT5Uv6fcLW5"),
+                ("m/0h/1/2h/2",
+                 "xprvA2JDeKCSNNZky6uBCviVfJSKyQ1mDYahRjijr5idH2WwLsEd4Hsb2Tyh8RfQMuPh7f7RtyzTtdrbdqqsunu5Mm3wDvUAKRHSC34sJ7in334",
+                 "xpub6FHa3pjLCk84BayeJxFW2SP4XRrFd1JYnxeLeU8EqN3vDfZmbqBqaGJAyiLjTAwm6ZLRQUMv1ZACTj37sR62cfN7fe5JnJ7dh8zL4fiyLHV"),
+                ("m/0h/1/2h/2/1000000000",
+                 "xprvA41z7zogVVwxVSgdKUHDy1SKmdb533PjDz7J6N6mV6uS3ze1ai8FHa8kmHScGpWmj4WggLyQjgPie1rFSruoUihUZREPSL39UNdE3BBDu76",
+                 "xpub6H1LXWLaKsWFhvm6RVpEL9P4KfRZSW7abD2ttkWP3SSQvnyA8FSVqNTEcYFgJS2UaFcxupHiYkro49S8yGasTvXEYBVPamhGW6cFJodrTHy"),
+            ],
+        },
+        TestVector {
+            seed: "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+            keys: &[
+                ("m",
+                 "xprv9s21ZrQH143K31xYSDQpPDxsXRTUcvj2iNHm5NUtrGiGG5e2DtALGdso3pGz6ssrdK4PFmM8NSpSBHNqPqm55Qn3LqFtT2emdEXVYsCzC2U",
+                 "xpub661MyMwAqRbcFW31YEwpkMuc5THy2PSt5bDMsktWQcFF8syAmRUapSCGu8ED9W6oDMSgv6Zz8idoc4a6mr8BDzTJY47LJhkJ8UB7WEGuduB"),
+                ("m/0",
+                 "xprv9vHkqa6EV4sPZHYqZznhT2NPtPCjKuDKGY38FBWLvgaDx45zo9WQRUT3dKYnjwih2yJD9mkrocEZXo1ex8G81dwSM1fwqWpWkeS3v86pgKt",
+                 "xpub69H7F5d8KSRgmmdJg2KhpAK8SR3DjMwAdkxj3ZuxV27CprR9LgpeyGmXUbC6wb7ERfvrnKZjXoUmmDznezpbZb7ap6r1D3tgFxHmwMkQTPH"),
+                ("m/0/2147483647h",
+                 "xprv9wSp6B7kry3Vj9m1zSnLvN3xH8RdsPP1Mh7fAaR7aRLcQMKTR2vidYEeEg2mUCTAwCd6vnxVrcjfy2kRgVsFawNzmjuHc2YmYRmagcEPdU9",
+                 "xpub6ASAVgeehLbnwdqV6UKMHVzgqAG8Gr6riv3Fxxpj8ksbH9ebxaEyBLZ85ySDhKiLDBrQSARLq1uNRts8RuJiHjaDMBU4Zn9h8LZNnBC5y4a"),
+                ("m/0/2147483647h/1",
+                 "xprv9zFnWC6h2cLgpmSA46vutJzBcfJ8yaJGg8cX1e5StJh45BBciYTRXSd25UEPVuesF9yog62tGAQtHjXajPPdbRCHuWS6T8XA2ECKADdw4Ef",
+                 "xpub6DF8uhdarytz3FWdA8TvFSvvAh8dP3283MY7p2V4SeE2wyWmG5mg5EwVvmdMVCQcoNJxGoWaU9DCWh89LojfZ537wTfunKau47EL2dhHKon"),
+                ("m/0/2147483647h/1/2147483646h",
+                 "xprvA1RpRA33e1JQ7ifknakTFpgNXPmW2YvmhqLQYMmrj4xJXXWYpDPS3xz7iAxn8L39njGVyuoseXzU6rcxFLJ8HFsTjSyQbLYnMpCqE2VbFWc",
+                 "xpub6ERApfZwUNrhLCkDtcHTcxd75RbzS1ed54G1LkBUHQVHQKqhMkhgbmJbZRkrgZw4koxb5JaHWkY4ALHY2grBGRjaDMzQLcgJvLJuZZvRcEL"),
+                ("m/0/2147483647h/1/2147483646h/2",
+                 "xprvA2nrNbFZABcdryreWet9Ea4LvTJcGsqrMzxHx98MMrotbir7yrKCEXw7nadnHM8Dq38EGfSh6dqA9QWTyefMLEcBYJUuekgW4BYPJcr9E7j",
+                 "xpub6FnCn6nSzZAw5Tw7cgR9bi15UV96gLZhjDstkXXxvCLsUXBGXPdSnLFbdpq8p9HmGsApME5hQTZ3emM2rnY5agb9rXpVGyy3bdW6EEgAtqt"),
+            ],
+        },
+        TestVector {
+            seed: "4b381541583be4423346c643850da4b320e46a87ae3d2a4e6da11eba819cd4acba45d239319ac14f863b8d5ab5a0d0c64d2e8a1e7d1457df2e5a3c51c73235be",
+            keys: &[
+                ("m",
+                 "xprv9s21ZrQH143K25QhxbucbDDuQ4naNntJRi4KUfWT7xo4EKsHt2QJDu7KXp1A3u7Bi1j8ph3EGsZ9Xvz9dGuVrtHHs7pXeTzjuxBrCmmhgC6",
+                 "xpub661MyMwAqRbcEZVB4dScxMAdx6d4nFc9nvyvH3v4gJL378CSRZiYmhRoP7mBy6gSPSCYk6SzXPTf3ND1cZAceL7SfJ1Z3GC8vBgp2epUt13"),
+                ("m/0h",
+                 "xprv9uPDJpEQgRQfDcW7BkF7eTya6RPxXeJCqCJGHuCJ4GiRVLzkTXBAJMu2qaMWPrS7AANYqdq6vcBcBUdJCVVFceUvJFjaPdGZ2y9WACViL4L",
+                 "xpub68NZiKmJWnxxS6aaHmn81bvJeTESw724CRDs6HbuccFQN9Ku14VQrADWgqbhhTHBaohPX4CjNLf9fq9MYo6oDaPPLPxSb7gwQN3ih19Zm4Y"),
+            ],
+        },
+    ];
+
+    #[test]
+    fn round_trip() {
+        for (_, xprv_str, xpub_str) in VECTORS.iter().flat_map(|vector| vector.keys) {
+            let xprv = xprv_str.parse::<ExtendedPrivKey>().unwrap();
+            let xpub = xpub_str.parse::<ExtendedPubKey>().unwrap();
+            assert_eq!(xprv.to_string(), *xprv_str);
+            assert_eq!(xpub.to_string(), *xpub_str);
+            assert_eq!(xprv.network, Network::Bitcoin);
+            assert_eq!(ExtendedPubKey::from_priv(&xprv), xpub);
+        }
+    }
+
+    #[test]
+    fn derivation() {
+        for vector in VECTORS {
+            let master = ExtendedPrivKey::new_master(Network::Bitcoin, &hex(vector.seed)).unwrap();
+            for (path, xprv, _) in vector.keys {
+                let path = path.parse::<DerivationPath>().unwrap();
+                assert_eq!(master.derive_path(&path).unwrap().to_string(), *xprv);
+            }
+        }
+    }
+
+    #[test]
+    fn public_derivation() {
+        let xpub = VECTORS[0].keys[3].2.parse::<ExtendedPubKey>().unwrap();
+        let path = "m/2/1000000000".parse::<DerivationPath>().unwrap();
+        assert_eq!(xpub.derive_path(&path).unwrap().to_string(), VECTORS[0].keys[5].2);
+        let hardened = ChildNumber::from_hardened_idx(0).unwrap();
+        assert_eq!(xpub.derive_child(hardened), Err(Error::HardenedDerivationFromPublicKey));
+    }
+
+    #[test]
+    fn bip84_first_address_key() {
+        // Seed of "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+        let seed = hex("5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4");
+        let master = ExtendedPrivKey::new_master(Network::Bitcoin, &seed).unwrap();
+        let account = master.derive_path(&"m/84'/0'/0'".parse().unwrap()).unwrap();
+        assert_eq!(account.to_string(), "xprv9ybY78BftS5UGANki6oSifuQEjkpyAC8ZmBvBNTshQnCBcxnefjHS7buPMkkqhcRzmoGZ5bokx7GuyDAiktd5HemohAU4wV1ZPMDRmLpBMm");
+        let key = account.derive_path(&"m/0/0".parse().unwrap()).unwrap();
+        // public key published in BIP84
+        assert_eq!(key.private_key.public_key().serialize().to_vec(), hex("0330d54fd0dd420a6e5f8d3624f5f3482cae350f79d5f0753bf5beef9c2d91af3c"));
+    }
+
+    #[test]
+    fn path_syntax() {
+        let apostrophes = "m/84'/0'/0'/0/5".parse::<DerivationPath>().unwrap();
+        let letters = "m/84h/0h/0h/0/5".parse::<DerivationPath>().unwrap();
+        assert_eq!(apostrophes, letters);
+        assert_eq!(letters.to_string(), "m/84'/0'/0'/0/5");
+        let children = apostrophes.iter().map(|child| (child.index(), child.is_hardened())).collect::<Vec<_>>();
+        assert_eq!(children, [(84, true), (0, true), (0, true), (0, false), (5, false)]);
+        assert_eq!("m".parse::<DerivationPath>(), Ok(DerivationPath::master()));
+    }
+
+    #[test]
+    fn path_errors() {
+        assert_eq!("m/2147483648".parse::<DerivationPath>(), Err(Error::ChildNumberOutOfRange));
+        assert_eq!("m/2147483648h".parse::<DerivationPath>(), Err(Error::ChildNumberOutOfRange));
+        assert_eq!("m/4294967296".parse::<DerivationPath>(), Err(Error::ChildNumberOutOfRange));
+        assert_eq!("m/0/".parse::<DerivationPath>(), Err(Error::TrailingSlash));
+        assert_eq!("m//0".parse::<DerivationPath>(), Err(Error::EmptyPathComponent));
+        assert_eq!("m/x".parse::<DerivationPath>(), Err(Error::InvalidChildNumber));
+        assert_eq!("m/+1".parse::<DerivationPath>(), Err(Error::InvalidChildNumber));
+        assert_eq!("m/'".parse::<DerivationPath>(), Err(Error::InvalidChildNumber));
+        assert_eq!("0/1".parse::<DerivationPath>(), Err(Error::InvalidPathPrefix));
+    }
+
+    #[test]
+    fn testnet_round_trip() {
+        let mut xprv = VECTORS[0].keys[0].1.parse::<ExtendedPrivKey>().unwrap();
+        xprv.network = Network::Testnet;
+        let tprv = xprv.to_string();
+        assert!(tprv.starts_with("tprv"));
+        assert_eq!(tprv.parse::<ExtendedPrivKey>().unwrap(), xprv);
+
+        let mut xpub = VECTORS[0].keys[0].2.parse::<ExtendedPubKey>().unwrap();
+        xpub.network = Network::Testnet;
+        let tpub = xpub.to_string();
+        assert!(tpub.starts_with("tpub"));
+        assert_eq!(tpub.parse::<ExtendedPubKey>().unwrap(), xpub);
+    }
+
+    #[test]
+    fn bad_checksum() {
+        let mut data = base58::decode(VECTORS[0].keys[0].1).unwrap();
+        *data.last_mut().unwrap() ^= 1;
+        let corrupted = base58::encode(&data);
+        assert_eq!(corrupted.parse::<ExtendedPrivKey>(), Err(Error::InvalidChecksum));
+    }
+
+    #[test]
+    fn unknown_version() {
+        let (_, xprv, xpub) = VECTORS[0].keys[0];
+        assert_eq!(xpub.parse::<ExtendedPrivKey>(), Err(Error::UnknownVersion([0x04, 0x88, 0xB2, 0x1E])));
+        assert_eq!(xprv.parse::<ExtendedPubKey>(), Err(Error::UnknownVersion([0x04, 0x88, 0xAD, 0xE4])));
+    }
+
+    #[test]
+    fn public_key_in_private_key_slot() {
+        // Take the xpub payload and relabel it as xprv
+        let mut data = base58::decode_check(VECTORS[0].keys[0].2).unwrap();
+        data[0..4].copy_from_slice(&[0x04, 0x88, 0xAD, 0xE4]);
+        let xprv = base58::encode_check(&data);
+        assert_eq!(xprv.parse::<ExtendedPrivKey>(), Err(Error::UnexpectedPublicKey));
+    }
+
+    /// Invalid keys from test vector 5 of BIP32 having depth 0 but a parent
+    #[test]
+    fn master_key_with_parent() {
+        // zero depth with non-zero parent fingerprint
+        assert_eq!("xprv9s2SPatNQ9Vc6GTbVMFPFo7jsaZySyzk7L8n2uqKXJen3KUmvQNTuLh3fhZMBoG3G4ZW1N2kZuHEPY53qmbZzCHshoQnNf4GvELZfqTUrcv".parse::<ExtendedPrivKey>(), Err(Error::InvalidMasterKey));
+        assert_eq!("xpub661no6RGEX3uJkY4bNnPcw4URcQTrSibUZ4NqJEw5eBkv7ovTwgiT91XX27VbEXGENhYRCf7hyEbWrR3FewATdCEebj6znwMfQkhRYHRLpJ".parse::<ExtendedPubKey>(), Err(Error::InvalidMasterKey));
+        // zero depth with non-zero index
+        assert_eq!("xprv9s21ZrQH4r4TsiLvyLXqM9P7k1K3EYhA1kkD6xuquB5i39AU8KF42acDyL3qsDbU9NmZn6MsGSUYZEsuoePmjzsB3eFKSUEh3Gu1N3cqVUN".parse::<ExtendedPrivKey>(), Err(Error::InvalidMasterKey));
+        assert_eq!("xpub661MyMwAuDcm6CRQ5N4qiHKrJ39Xe1R1NyfouMKTTWcguwVcfrZJaNvhpebzGerh7gucBvzEQWRugZDuDXjNDRmXzSZe4c7mnTK97pTvGS8".parse::<ExtendedPubKey>(), Err(Error::InvalidMasterKey));
+    }
+}
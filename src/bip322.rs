@@ -0,0 +1,418 @@
+//! BIP322 generic signed messages, the simple flavor for single-key addresses
+//!
+//! The message is committed to by a virtual transaction `to_spend` whose only input has
+//! `OP_0 PUSH32[message_hash]` as its scriptSig and whose only output pays to the address. The
+//! signature is the witness of another virtual transaction `to_sign` spending that output into
+//! `OP_RETURN`. Both transactions have version 0, lock time 0 and zero sequence numbers and
+//! amounts. The simple signature is the witness stack encoded in base64.
+//!
+//! Only P2WPKH (ECDSA with `SIGHASH_ALL`) and P2TR key path spends (BIP340 with
+//! `SIGHASH_DEFAULT` or `SIGHASH_ALL`) are supported, other addresses are rejected with
+//! `Error::UnsupportedAddress` or `Error::UnsupportedWitnessVersion`.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::address::p2tr_address_to_xonly;
+use crate::ecdsa::{self, Signature};
+use crate::hashes::{hash160, sha256, sha256d};
+use crate::tagged_hash::{TaggedHash, TAP_SIGHASH};
+use crate::{base58, base64, bech32, schnorr, Error, Keypair, Message, PublicKey, SecretKey, XOnlyPoint};
+
+const MESSAGE_TAG: &str = "BIP0322-signed-message";
+const SIGHASH_DEFAULT: u8 = 0x00;
+const SIGHASH_ALL: u8 = 0x01;
+/// The `OP_RETURN` script of the `to_sign` output
+const TO_SIGN_SCRIPT: &[u8] = &[0x6A];
+
+/// Computes the tagged hash of the message that is committed to by `to_spend`
+pub fn message_hash(msg: &str) -> [u8; 32] {
+    TaggedHash::new(MESSAGE_TAG).hash(&[msg.as_bytes()])
+}
+
+fn push_varint(out: &mut Vec<u8>, len: usize) {
+    let len = len as u64;
+    match len {
+        0..=0xFC => out.push(len as u8),
+        0xFD..=0xFFFF => {
+            out.push(0xFD);
+            out.extend_from_slice(&(len as u16).to_le_bytes());
+        },
+        0x10000..=0xFFFF_FFFF => {
+            out.push(0xFE);
+            out.extend_from_slice(&(len as u32).to_le_bytes());
+        },
+        _ => {
+            out.push(0xFF);
+            out.extend_from_slice(&len.to_le_bytes());
+        },
+    }
+}
+
+fn push_var_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    push_varint(out, bytes.len());
+    out.extend_from_slice(bytes);
+}
+
+/// Serializes a transaction without witness that has one input with zero sequence and one
+/// output with zero amount, version and lock time are zero too
+fn serialize_tx(prevout: &[u8; 36], script_sig: &[u8], script_pubkey: &[u8]) -> Vec<u8> {
+    let mut tx = Vec::with_capacity(64 + script_sig.len() + script_pubkey.len());
+    tx.extend_from_slice(&0u32.to_le_bytes());
+    tx.push(1);
+    tx.extend_from_slice(prevout);
+    push_var_bytes(&mut tx, script_sig);
+    tx.extend_from_slice(&0u32.to_le_bytes());
+    tx.push(1);
+    tx.extend_from_slice(&0u64.to_le_bytes());
+    push_var_bytes(&mut tx, script_pubkey);
+    tx.extend_from_slice(&0u32.to_le_bytes());
+    tx
+}
+
+/// Computes the txid of `to_spend` in the internal byte order
+fn to_spend_txid(script_pubkey: &[u8], msg: &str) -> [u8; 32] {
+    let mut prevout = [0; 36];
+    prevout[32..].copy_from_slice(&u32::MAX.to_le_bytes());
+    let mut script_sig = alloc::vec![0x00, 0x20];
+    script_sig.extend_from_slice(&message_hash(msg));
+    sha256d(&serialize_tx(&prevout, &script_sig, script_pubkey))
+}
+
+/// The outpoint spent by `to_sign`
+fn to_sign_prevout(script_pubkey: &[u8], msg: &str) -> [u8; 36] {
+    let mut prevout = [0; 36];
+    prevout[..32].copy_from_slice(&to_spend_txid(script_pubkey, msg));
+    prevout
+}
+
+/// Computes the txid of `to_sign` in the internal byte order, it doesn't commit to the witness
+#[cfg(test)]
+fn to_sign_txid(script_pubkey: &[u8], msg: &str) -> [u8; 32] {
+    sha256d(&serialize_tx(&to_sign_prevout(script_pubkey, msg), &[], TO_SIGN_SCRIPT))
+}
+
+/// The serialized `to_sign` output
+fn to_sign_output() -> Vec<u8> {
+    let mut output = 0u64.to_le_bytes().to_vec();
+    push_var_bytes(&mut output, TO_SIGN_SCRIPT);
+    output
+}
+
+fn p2wpkh_script(hash: &[u8; 20]) -> Vec<u8> {
+    let mut script = alloc::vec![0x00, 0x14];
+    script.extend_from_slice(hash);
+    script
+}
+
+fn p2tr_script(output_key: &XOnlyPoint) -> Vec<u8> {
+    let mut script = alloc::vec![0x51, 0x20];
+    script.extend_from_slice(&output_key.serialize());
+    script
+}
+
+/// BIP143 signature hash of `to_sign` spending P2WPKH output with `SIGHASH_ALL`
+fn p2wpkh_sighash(hash: &[u8; 20], msg: &str) -> [u8; 32] {
+    let prevout = to_sign_prevout(&p2wpkh_script(hash), msg);
+    let sequence = 0u32.to_le_bytes();
+    let mut preimage = Vec::with_capacity(182);
+    preimage.extend_from_slice(&0u32.to_le_bytes());
+    preimage.extend_from_slice(&sha256d(&prevout));
+    preimage.extend_from_slice(&sha256d(&sequence));
+    preimage.extend_from_slice(&prevout);
+    // scriptCode is the P2PKH script
+    preimage.extend_from_slice(&[0x19, 0x76, 0xA9, 0x14]);
+    preimage.extend_from_slice(hash);
+    preimage.extend_from_slice(&[0x88, 0xAC]);
+    preimage.extend_from_slice(&0u64.to_le_bytes());
+    preimage.extend_from_slice(&sequence);
+    preimage.extend_from_slice(&sha256d(&to_sign_output()));
+    preimage.extend_from_slice(&0u32.to_le_bytes());
+    preimage.extend_from_slice(&u32::from(SIGHASH_ALL).to_le_bytes());
+    sha256d(&preimage)
+}
+
+/// BIP341 signature hash of `to_sign` spending P2TR output using the key path
+fn p2tr_sighash(output_key: &XOnlyPoint, hash_type: u8, msg: &str) -> [u8; 32] {
+    let script_pubkey = p2tr_script(output_key);
+    let mut script = Vec::with_capacity(35);
+    push_var_bytes(&mut script, &script_pubkey);
+    let prevout = to_sign_prevout(&script_pubkey, msg);
+    TaggedHash::new(TAP_SIGHASH).hash(&[
+        // epoch
+        &[0x00, hash_type],
+        &0u32.to_le_bytes(),
+        &0u32.to_le_bytes(),
+        &sha256(&prevout),
+        &sha256(&0u64.to_le_bytes()),
+        &sha256(&script),
+        &sha256(&0u32.to_le_bytes()),
+        &sha256(&to_sign_output()),
+        // key path spend without annex
+        &[0x00],
+        &0u32.to_le_bytes(),
+    ])
+}
+
+fn encode_witness(items: &[&[u8]]) -> String {
+    let mut witness = Vec::new();
+    push_varint(&mut witness, items.len());
+    for item in items {
+        push_var_bytes(&mut witness, item);
+    }
+    base64::encode(&witness)
+}
+
+/// Reads a varint, it is not required to be minimal
+fn read_varint(data: &mut &[u8]) -> Result<u64, Error> {
+    let (len, rest) = match data.split_first() {
+        Some((0xFD, rest)) => (2, rest),
+        Some((0xFE, rest)) => (4, rest),
+        Some((0xFF, rest)) => (8, rest),
+        Some((len, rest)) => {
+            *data = rest;
+            return Ok(u64::from(*len));
+        },
+        None => return Err(Error::InvalidSignature),
+    };
+    if rest.len() < len {
+        return Err(Error::InvalidSignature);
+    }
+    let value = rest[..len].iter().rev().fold(0, |acc, byte| acc << 8 | u64::from(*byte));
+    *data = &rest[len..];
+    Ok(value)
+}
+
+fn decode_witness(signature: &str) -> Result<Vec<Vec<u8>>, Error> {
+    let bytes = base64::decode(signature)?;
+    let mut data = &bytes[..];
+    let count = read_varint(&mut data)?;
+    // each item takes at least one byte
+    if count > data.len() as u64 {
+        return Err(Error::InvalidSignature);
+    }
+    let mut items = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let len = read_varint(&mut data)?;
+        if len > data.len() as u64 {
+            return Err(Error::InvalidSignature);
+        }
+        let (item, rest) = data.split_at(len as usize);
+        items.push(item.to_vec());
+        data = rest;
+    }
+    if !data.is_empty() {
+        return Err(Error::InvalidSignature);
+    }
+    Ok(items)
+}
+
+/// Signs the message for the P2WPKH address of the key returning base64-encoded simple signature.
+///
+/// The signature has low R, same as the ones produced by Bitcoin Core.
+pub fn sign_p2wpkh(msg: &str, secret_key: &SecretKey) -> String {
+    let public_key = secret_key.public_key().serialize();
+    let sighash = p2wpkh_sighash(&hash160(&public_key), msg);
+    let mut signature = ecdsa::sign_low_r(&Message::from_digest(sighash), secret_key).to_der();
+    signature.push(SIGHASH_ALL);
+    encode_witness(&[&signature, &public_key])
+}
+
+/// Signs the message for the P2TR address of the key without script tree returning
+/// base64-encoded simple signature.
+///
+/// The address is the one with output key `taproot::output_key(internal, None)` where the
+/// internal key is the x-only public key of `keypair`. The signature uses `SIGHASH_DEFAULT`.
+/// Returns `Error::InvalidTweak` in the same cases as `Keypair::tap_tweak`.
+pub fn sign_p2tr(msg: &str, keypair: &Keypair, aux_rand: &[u8; 32]) -> Result<String, Error> {
+    let tweaked = keypair.tap_tweak(None)?;
+    let (output_key, _) = tweaked.x_only_public_key();
+    let signature = schnorr::sign(&Message::from_digest(p2tr_sighash(&output_key, SIGHASH_DEFAULT, msg)), &tweaked, aux_rand);
+    Ok(encode_witness(&[&signature]))
+}
+
+/// Verifies the base64-encoded simple signature of the message by the address.
+///
+/// Returns `Error::InvalidSignature` if the witness is malformed, uses an unsupported signature
+/// hash type or doesn't satisfy the address. As with Bitcoin Core's standardness rules the
+/// ECDSA signature must be strict DER with low S and the public key must be compressed.
+/// Addresses other than P2WPKH and P2TR return `Error::UnsupportedAddress` or
+/// `Error::UnsupportedWitnessVersion`, errors of decoding the address and base64 are returned
+/// as-is.
+pub fn verify(address: &str, msg: &str, signature: &str) -> Result<(), Error> {
+    let (_, version, program) = match bech32::decode_segwit(address) {
+        Ok(decoded) => decoded,
+        Err(_) if base58::decode_check(address).is_ok() => return Err(Error::UnsupportedAddress),
+        Err(error) => return Err(error),
+    };
+    match (version, program.len()) {
+        (0, 20) => {
+            let mut hash = [0; 20];
+            hash.copy_from_slice(&program);
+            verify_p2wpkh(&hash, msg, &decode_witness(signature)?)
+        },
+        (0, _) => Err(Error::UnsupportedAddress),
+        (1, 32) => {
+            let (_, output_key) = p2tr_address_to_xonly(address)?;
+            verify_p2tr(&output_key, msg, &decode_witness(signature)?)
+        },
+        (1, _) => Err(Error::UnsupportedAddress),
+        (version, _) => Err(Error::UnsupportedWitnessVersion(version)),
+    }
+}
+
+fn verify_p2wpkh(hash: &[u8; 20], msg: &str, witness: &[Vec<u8>]) -> Result<(), Error> {
+    let (signature, public_key) = match witness {
+        [signature, public_key] => (signature, public_key),
+        _ => return Err(Error::InvalidSignature),
+    };
+    if public_key.len() != 33 || hash160(public_key) != *hash {
+        return Err(Error::InvalidSignature);
+    }
+    let public_key = PublicKey::from_sec1_bytes(public_key).map_err(|_| Error::InvalidSignature)?;
+    let der = match signature.split_last() {
+        Some((&SIGHASH_ALL, der)) => der,
+        _ => return Err(Error::InvalidSignature),
+    };
+    let signature = Signature::from_der(der).map_err(|_| Error::InvalidSignature)?;
+    ecdsa::verify_strict(&Message::from_digest(p2wpkh_sighash(hash, msg)), &signature, &public_key).map_err(|_| Error::InvalidSignature)
+}
+
+fn verify_p2tr(output_key: &XOnlyPoint, msg: &str, witness: &[Vec<u8>]) -> Result<(), Error> {
+    let signature = match witness {
+        [signature] => signature,
+        _ => return Err(Error::InvalidSignature),
+    };
+    let hash_type = match signature.len() {
+        64 => SIGHASH_DEFAULT,
+        65 if signature[64] == SIGHASH_ALL => SIGHASH_ALL,
+        _ => return Err(Error::InvalidSignature),
+    };
+    let mut bytes = [0; 64];
+    bytes.copy_from_slice(&signature[..64]);
+    schnorr::verify(&Message::from_digest(p2tr_sighash(output_key, hash_type, msg)), &bytes, output_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{message_hash, p2tr_script, p2wpkh_script, sign_p2tr, sign_p2wpkh, to_sign_txid, to_spend_txid, verify};
+    use crate::hashes::hash160;
+    use crate::test_util::hex32;
+    use crate::test_utils::deterministic_keypair;
+    use crate::{base58, taproot, Error, Keypair, SecretKey};
+
+    /// The key of the test vectors in BIP322
+    fn bip_key() -> SecretKey {
+        let payload = base58::decode_check("L3VFeEujGtevx9w18HD1fhRbCH67Az2dpCymeRE1SoPK6XQtaN2k").unwrap();
+        assert_eq!(payload.len(), 34);
+        SecretKey::from_bytes(crate::array_ref_32(&payload[1..33])).unwrap()
+    }
+
+    /// Reverses the displayed txid to the internal byte order
+    fn txid(string: &str) -> [u8; 32] {
+        let mut txid = hex32(string);
+        txid.reverse();
+        txid
+    }
+
+    const P2WPKH: &str = "bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l";
+    const P2TR: &str = "bc1ppv609nr0vr25u07u95waq5lucwfm6tde4nydujnu8npg4q75mr5sxq8lt3";
+
+    #[test]
+    fn bip_message_hashes() {
+        assert_eq!(message_hash(""), hex32("c90c269c4f8fcbe6880f72a721ddfbf1914268a794cbb21cfafee13770ae19f1"));
+        assert_eq!(message_hash("Hello World"), hex32("f0eb03b1a75ac6d9847f55c624a99169b5dccba2a31f5b23bea77ba270de0a7a"));
+    }
+
+    #[test]
+    fn bip_transactions() {
+        let public_key = bip_key().public_key();
+        assert_eq!(crate::bech32::encode_segwit("bc", 0, &hash160(&public_key.serialize())), P2WPKH);
+        let script = p2wpkh_script(&hash160(&public_key.serialize()));
+        assert_eq!(to_spend_txid(&script, ""), txid("c5680aa69bb8d860bf82d4e9cd3504b55dde018de765a91bb566283c545a99a7"));
+        assert_eq!(to_spend_txid(&script, "Hello World"), txid("b79d196740ad5217771c1098fc4a4b51e0535c32236c71f1ea4d61a2d603352b"));
+        assert_eq!(to_sign_txid(&script, ""), txid("1e9654e951a5ba44c8604c4de6c67fd78a27e81dcadcfe1edf638ba3aaebaed6"));
+        assert_eq!(to_sign_txid(&script, "Hello World"), txid("88737ae86f2077145f93cc4b153ae9a1cb8d56afa511988c149c5c8c9d93bddf"));
+    }
+
+    #[test]
+    fn bip_p2wpkh_signatures() {
+        let vectors = [
+            ("", "AkcwRAIgM2gBAQqvZX15ZiysmKmQpDrG83avLIT492QBzLnQIxYCIBaTpOaD20qRlEylyxFSeEA2ba9YOixpX8z46TSDtS40ASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI="),
+            ("Hello World", "AkcwRAIgZRfIY3p7/DoVTty6YZbWS71bc5Vct9p9Fia83eRmw2QCICK/ENGfwLtptFluMGs2KsqoNSk89pO7F29zJLUx9a/sASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI="),
+        ];
+        for (msg, signature) in &vectors {
+            assert_eq!(verify(P2WPKH, msg, signature), Ok(()), "{:?}", msg);
+        }
+        assert_eq!(verify(P2WPKH, "", vectors[1].1), Err(Error::InvalidSignature));
+        assert_eq!(verify(P2WPKH, "Hello World", vectors[0].1), Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn bip_p2tr_signature() {
+        let keypair = Keypair::from_secret_key(&bip_key());
+        let (internal, _) = keypair.x_only_public_key();
+        let (output_key, _) = taproot::output_key(&internal, None).unwrap();
+        assert_eq!(output_key.p2tr_address("bc"), P2TR);
+        let signature = "AUHd69PrJQEv+oKTfZ8l+WROBHuy9HKrbFCJu7U1iK2iiEy1vMU5EfMtjc+VSHM7aU0SDbak5IUZRVno2P5mjSafAQ==";
+        assert_eq!(verify(P2TR, "Hello World", signature), Ok(()));
+        assert_eq!(verify(P2TR, "", signature), Err(Error::InvalidSignature));
+        assert_eq!(verify(P2WPKH, "Hello World", signature), Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn sign_verify() {
+        for index in 0..4 {
+            let keypair = deterministic_keypair(index);
+            let public_key = keypair.public_key().serialize();
+            let p2wpkh = crate::bech32::encode_segwit("tb", 0, &hash160(&public_key));
+            let (output_key, _) = keypair.tap_tweak(None).unwrap().x_only_public_key();
+            let p2tr = output_key.p2tr_address("tb");
+            assert_eq!(p2tr_script(&output_key)[2..], output_key.serialize()[..]);
+
+            for msg in &["", "Hello World", "\u{1F511}"] {
+                let signature = sign_p2wpkh(msg, keypair.secret_key());
+                assert_eq!(verify(&p2wpkh, msg, &signature), Ok(()));
+                assert_eq!(verify(&p2tr, msg, &signature), Err(Error::InvalidSignature));
+                assert_eq!(verify(&p2wpkh, "other", &signature), Err(Error::InvalidSignature));
+
+                let signature = sign_p2tr(msg, &keypair, &[index as u8; 32]).unwrap();
+                assert_eq!(verify(&p2tr, msg, &signature), Ok(()));
+                assert_eq!(verify(&p2wpkh, msg, &signature), Err(Error::InvalidSignature));
+                assert_eq!(verify(&p2tr, "other", &signature), Err(Error::InvalidSignature));
+            }
+        }
+    }
+
+    #[test]
+    fn bitcoin_core_compatible_signing() {
+        // Bitcoin Core grinds for low R and the BIP vectors were produced by it
+        let signature = sign_p2wpkh("Hello World", &bip_key());
+        assert_eq!(signature, "AkcwRAIgZRfIY3p7/DoVTty6YZbWS71bc5Vct9p9Fia83eRmw2QCICK/ENGfwLtptFluMGs2KsqoNSk89pO7F29zJLUx9a/sASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI=");
+    }
+
+    #[test]
+    fn unsupported_addresses() {
+        let key = bip_key();
+        let signature = sign_p2wpkh("", &key);
+        assert_eq!(verify(&key.public_key().p2pkh_address(crate::Network::Bitcoin), "", &signature), Err(Error::UnsupportedAddress));
+        // P2WSH
+        assert_eq!(verify("bc1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qccfmv3", "", &signature), Err(Error::UnsupportedAddress));
+        let v2 = crate::bech32::encode_segwit("bc", 2, &[0; 32]);
+        assert_eq!(verify(&v2, "", &signature), Err(Error::UnsupportedWitnessVersion(2)));
+        assert!(verify("bc1qinvalid", "", &signature).is_err());
+    }
+
+    #[test]
+    fn malformed_witness() {
+        let key = bip_key();
+        // empty witness, trailing byte, truncated item and other hash types
+        let signature = sign_p2wpkh("", &key);
+        let mut bytes = crate::base64::decode(&signature).unwrap();
+        for malformed in &[vec![0x00], [&bytes[..], &[0]].concat(), bytes[..bytes.len() - 1].to_vec()] {
+            assert_eq!(verify(P2WPKH, "", &crate::base64::encode(malformed)), Err(Error::InvalidSignature));
+        }
+        let hash_type = 1 + 1 + usize::from(bytes[1]) - 1;
+        bytes[hash_type] = 0x81;
+        assert_eq!(verify(P2WPKH, "", &crate::base64::encode(&bytes)), Err(Error::InvalidSignature));
+    }
+}
@@ -0,0 +1,175 @@
+//! BIP38 passphrase-protected secret keys
+//!
+//! Only the non-EC-multiply mode is supported: the owner of the key encrypts it with a
+//! passphrase. The encryption key is derived as `scrypt(passphrase, address_hash, n = 16384,
+//! r = 8, p = 8)` where `address_hash` is the first four bytes of double SHA256 of the P2PKH
+//! address of the key on mainnet. The 64-byte output is split into halves, the secret key is
+//! XOR-ed with the first half and encrypted with AES-256 using the second half as the key. The
+//! encoding is Base58Check of `0x01 0x42 flags address_hash ciphertext` which starts with `6P`.
+//!
+//! Decryption recomputes the address hash from the decrypted key so a wrong passphrase is
+//! detected with probability `1 - 2⁻³²`.
+//!
+//! BIP38 requires the passphrase to be normalized to NFC. This crate has no Unicode tables so the
+//! passphrase is used as is - ASCII passphrases are always normalized but others must be
+//! normalized by the caller, otherwise the result won't match other implementations.
+//!
+//! scrypt with these parameters allocates 16 MiB and is intentionally slow.
+
+use alloc::string::String;
+use crate::aes::Aes256;
+use crate::hashes::sha256d;
+use crate::scrypt::scrypt;
+use crate::zeroize::Zeroizing;
+use crate::{base58, Error, Network, SecretKey};
+
+const PREFIX: [u8; 2] = [0x01, 0x42];
+const FLAGS: u8 = 0xC0;
+const COMPRESSED: u8 = 0x20;
+const LOG_N: u8 = 14;
+const R: usize = 8;
+const P: usize = 8;
+
+fn compute_address_hash(secret_key: &SecretKey, compressed: bool) -> [u8; 4] {
+    let public_key = secret_key.public_key();
+    let address = if compressed {
+        public_key.p2pkh_address(Network::Bitcoin)
+    } else {
+        public_key.p2pkh_address_uncompressed(Network::Bitcoin)
+    };
+    let mut hash = [0; 4];
+    hash.copy_from_slice(&sha256d(address.as_bytes())[..4]);
+    hash
+}
+
+/// Returns the XOR mask and the AES key
+fn derive_keys(passphrase: &str, address_hash: &[u8; 4]) -> (Zeroizing<[u8; 32]>, Aes256) {
+    let mut derived = Zeroizing::new([0; 64]);
+    scrypt(passphrase.as_bytes(), address_hash, LOG_N, R, P, &mut *derived);
+    let mut mask = Zeroizing::new([0; 32]);
+    mask.copy_from_slice(&derived[..32]);
+    let mut key = Zeroizing::new([0; 32]);
+    key.copy_from_slice(&derived[32..]);
+    (mask, Aes256::new(&key))
+}
+
+impl SecretKey {
+    /// Encrypts the key with `passphrase` as defined by BIP38.
+    ///
+    /// `compressed` selects whether the key is used with compressed public key which determines
+    /// the address committed to in the output and is restored by `decrypt_bip38`.
+    pub fn encrypt_bip38(&self, passphrase: &str, compressed: bool) -> String {
+        let address_hash = compute_address_hash(self, compressed);
+        let (mask, aes) = derive_keys(passphrase, &address_hash);
+
+        let mut payload = Zeroizing::new([0; 39]);
+        payload[..2].copy_from_slice(&PREFIX);
+        payload[2] = if compressed { FLAGS | COMPRESSED } else { FLAGS };
+        payload[3..7].copy_from_slice(&address_hash);
+        let secret = Zeroizing::new(self.to_bytes());
+        for (i, chunk) in secret.chunks(16).enumerate() {
+            let mut block = Zeroizing::new([0; 16]);
+            for ((byte, secret), mask) in block.iter_mut().zip(chunk).zip(&mask[(i * 16)..]) {
+                *byte = secret ^ mask;
+            }
+            aes.encrypt_block(&mut block);
+            payload[(7 + i * 16)..(23 + i * 16)].copy_from_slice(&*block);
+        }
+        base58::encode_check(&*payload)
+    }
+
+    /// Decrypts a key encrypted by `encrypt_bip38` returning it together with the flag saying
+    /// whether it's used with compressed public key.
+    ///
+    /// Returns the errors of Base58Check decoding, `Error::InvalidLength` if the decoded data is
+    /// not 39 bytes long, `Error::UnsupportedVersion` if the prefix is not the one of
+    /// non-EC-multiply mode, `Error::InvalidBip38Flags` if the flag byte has unknown bits set and
+    /// `Error::DecryptionFailed` if the passphrase is wrong.
+    pub fn decrypt_bip38(encrypted: &str, passphrase: &str) -> Result<(SecretKey, bool), Error> {
+        let payload = base58::decode_check(encrypted)?;
+        if payload.len() != 39 {
+            return Err(Error::InvalidLength(payload.len()));
+        }
+        if payload[..2] != PREFIX {
+            return Err(Error::UnsupportedVersion);
+        }
+        let compressed = match payload[2] {
+            FLAGS => false,
+            flags if flags == FLAGS | COMPRESSED => true,
+            flags => return Err(Error::InvalidBip38Flags(flags)),
+        };
+        let mut address_hash = [0; 4];
+        address_hash.copy_from_slice(&payload[3..7]);
+        let (mask, aes) = derive_keys(passphrase, &address_hash);
+
+        let mut secret = Zeroizing::new([0; 32]);
+        for (i, chunk) in payload[7..].chunks(16).enumerate() {
+            let mut block = Zeroizing::new([0; 16]);
+            block.copy_from_slice(chunk);
+            aes.decrypt_block(&mut block);
+            for ((secret, byte), mask) in secret[(i * 16)..].iter_mut().zip(&*block).zip(&mask[(i * 16)..]) {
+                *secret = byte ^ mask;
+            }
+        }
+        let secret_key = SecretKey::from_bytes(&secret).map_err(|_| Error::DecryptionFailed)?;
+        if !crate::bytes_eq_ct(&compute_address_hash(&secret_key, compressed), &address_hash) {
+            return Err(Error::DecryptionFailed);
+        }
+        Ok((secret_key, compressed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::hex32;
+    use crate::{Error, SecretKey};
+
+    /// The non-EC-multiply vectors of BIP38
+    #[test]
+    fn vectors() {
+        // (passphrase, encrypted, secret key, compressed)
+        let vectors = [
+            ("TestingOneTwoThree", "6PRVWUbkzzsbcVac2qwfssoUJAN1Xhrg6bNk8J7Nzm5H7kxEbn2Nh2ZoGg", "cbf4b9f70470856bb4f40f80b87edb90865997ffee6df315ab166d713af433a5", false),
+            ("Satoshi", "6PRNFFkZc2NZ6dJqFfhRoFNMR9Lnyj7dYGrzdgXXVMXcxoKTePPX1dWByq", "09c2686880095b1a4c249ee3ac4eea8a014f11e6f986d0b5025ac1f39afbd9ae", false),
+            ("TestingOneTwoThree", "6PYNKZ1EAgYgmQfmNVamxyXVWHzK5s6DGhwP4J5o44cvXdoY7sRzhtpUeo", "cbf4b9f70470856bb4f40f80b87edb90865997ffee6df315ab166d713af433a5", true),
+            ("Satoshi", "6PYLtMnXvfG3oJde97zRyLYFZCYizPU5T3LwgdYJz1fRhh16bU7u6PPmY7", "09c2686880095b1a4c249ee3ac4eea8a014f11e6f986d0b5025ac1f39afbd9ae", true),
+        ];
+        for (passphrase, encrypted, secret_key, compressed) in &vectors {
+            let secret_key = SecretKey::from_bytes(&hex32(secret_key)).unwrap();
+            assert_eq!(secret_key.encrypt_bip38(passphrase, *compressed), *encrypted);
+            assert_eq!(SecretKey::decrypt_bip38(encrypted, passphrase), Ok((secret_key, *compressed)));
+        }
+    }
+
+    /// The passphrase of the vector is `U+03D2 U+0301 U+0000 U+010400 U+01F4A9` which is
+    /// `U+03D3 U+0000 U+010400 U+01F4A9` in NFC
+    #[test]
+    fn unicode_passphrase() {
+        let passphrase = "\u{03D3}\u{0000}\u{10400}\u{1F4A9}";
+        let encrypted = "6PRW5o9FLp4gJDDVqJQKJFTpMvdsSGJxMYHtHaQBF3ooa8mwD69bapcDQn";
+        let (secret_key, compressed) = SecretKey::decrypt_bip38(encrypted, passphrase).unwrap();
+        assert!(!compressed);
+        assert_eq!(secret_key.public_key().p2pkh_address_uncompressed(crate::Network::Bitcoin), "16ktGzmfrurhbhi6JGqsMWf7TyqK9HNAeF");
+        assert_eq!(secret_key.encrypt_bip38(passphrase, false), encrypted);
+
+        // the caller is responsible for the normalization
+        let denormalized = "\u{03D2}\u{0301}\u{0000}\u{10400}\u{1F4A9}";
+        assert_eq!(SecretKey::decrypt_bip38(encrypted, denormalized), Err(Error::DecryptionFailed));
+    }
+
+    #[test]
+    fn invalid() {
+        let encrypted = "6PRNFFkZc2NZ6dJqFfhRoFNMR9Lnyj7dYGrzdgXXVMXcxoKTePPX1dWByq";
+        assert_eq!(SecretKey::decrypt_bip38(encrypted, "satoshi"), Err(Error::DecryptionFailed));
+
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let mut payload = crate::base58::decode_check(&secret_key.encrypt_bip38("", true)).unwrap();
+        payload[2] = 0xE4;
+        assert_eq!(SecretKey::decrypt_bip38(&crate::base58::encode_check(&payload), ""), Err(Error::InvalidBip38Flags(0xE4)));
+        // EC-multiply mode
+        payload[1] = 0x43;
+        assert_eq!(SecretKey::decrypt_bip38(&crate::base58::encode_check(&payload), ""), Err(Error::UnsupportedVersion));
+        payload.pop();
+        assert_eq!(SecretKey::decrypt_bip38(&crate::base58::encode_check(&payload), ""), Err(Error::InvalidLength(38)));
+    }
+}
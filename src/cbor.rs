@@ -0,0 +1,220 @@
+//! Minimal CBOR (RFC 8949) support for COSE keys
+//!
+//! Only definite-length items are supported, indefinite-length ones are rejected as invalid.
+//! Values the caller isn't interested in (arrays, maps, tags, floats) are skipped.
+
+use alloc::vec::Vec;
+use crate::Error;
+
+const UNSIGNED: u8 = 0;
+const NEGATIVE: u8 = 1;
+const BYTES: u8 = 2;
+const TEXT: u8 = 3;
+const ARRAY: u8 = 4;
+const MAP: u8 = 5;
+const TAG: u8 = 6;
+const SIMPLE: u8 = 7;
+
+/// How deep skipped arrays, maps and tags may be nested
+const MAX_DEPTH: usize = 16;
+
+/// Writes the initial byte and the argument in the shortest form
+fn push_head(out: &mut Vec<u8>, major: u8, argument: u64) {
+    let major = major << 5;
+    match argument {
+        0..=23 => out.push(major | argument as u8),
+        24..=0xFF => out.extend_from_slice(&[major | 24, argument as u8]),
+        0x100..=0xFFFF => {
+            out.push(major | 25);
+            out.extend_from_slice(&(argument as u16).to_be_bytes());
+        },
+        0x1_0000..=0xFFFF_FFFF => {
+            out.push(major | 26);
+            out.extend_from_slice(&(argument as u32).to_be_bytes());
+        },
+        _ => {
+            out.push(major | 27);
+            out.extend_from_slice(&argument.to_be_bytes());
+        },
+    }
+}
+
+pub(crate) fn push_map_header(out: &mut Vec<u8>, len: usize) {
+    push_head(out, MAP, len as u64);
+}
+
+pub(crate) fn push_int(out: &mut Vec<u8>, value: i64) {
+    if value < 0 {
+        push_head(out, NEGATIVE, !value as u64);
+    } else {
+        push_head(out, UNSIGNED, value as u64);
+    }
+}
+
+pub(crate) fn push_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    push_head(out, BYTES, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+/// Decoded data item, compound items are skipped and reported as `Other`
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum Value<'a> {
+    /// Integer that fits into `i64`
+    Int(i64),
+    Bytes(&'a [u8]),
+    Text(&'a str),
+    Bool(bool),
+    Other,
+}
+
+pub(crate) struct Decoder<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Decoder { data }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn take(&mut self, len: u64) -> Result<&'a [u8], Error> {
+        if len > self.data.len() as u64 {
+            return Err(Error::InvalidCbor);
+        }
+        let (taken, rest) = self.data.split_at(len as usize);
+        self.data = rest;
+        Ok(taken)
+    }
+
+    /// Reads the major type, the additional information and the argument
+    fn head(&mut self) -> Result<(u8, u8, u64), Error> {
+        let initial = self.take(1)?[0];
+        let info = initial & 0x1F;
+        let argument = match info {
+            info @ 0..=23 => u64::from(info),
+            24 => u64::from(self.take(1)?[0]),
+            25 => self.take(2)?.iter().fold(0, |acc, byte| acc << 8 | u64::from(*byte)),
+            26 => self.take(4)?.iter().fold(0, |acc, byte| acc << 8 | u64::from(*byte)),
+            27 => self.take(8)?.iter().fold(0, |acc, byte| acc << 8 | u64::from(*byte)),
+            // reserved values and indefinite lengths
+            _ => return Err(Error::InvalidCbor),
+        };
+        Ok((initial >> 5, info, argument))
+    }
+
+    /// Reads the header of a map returning the number of entries
+    pub(crate) fn map_header(&mut self) -> Result<u64, Error> {
+        match self.head()? {
+            (MAP, _, len) => Ok(len),
+            _ => Err(Error::InvalidCbor),
+        }
+    }
+
+    pub(crate) fn value(&mut self) -> Result<Value<'a>, Error> {
+        self.value_nested(0)
+    }
+
+    fn value_nested(&mut self, depth: usize) -> Result<Value<'a>, Error> {
+        if depth > MAX_DEPTH {
+            return Err(Error::InvalidCbor);
+        }
+        let value = match self.head()? {
+            (UNSIGNED, _, value) if value <= i64::MAX as u64 => Value::Int(value as i64),
+            (NEGATIVE, _, value) if value <= i64::MAX as u64 => Value::Int(!(value as i64)),
+            (UNSIGNED, _, _) | (NEGATIVE, _, _) => Value::Other,
+            (BYTES, _, len) => Value::Bytes(self.take(len)?),
+            (TEXT, _, len) => Value::Text(core::str::from_utf8(self.take(len)?).map_err(|_| Error::InvalidCbor)?),
+            (ARRAY, _, len) => {
+                for _ in 0..len {
+                    self.value_nested(depth + 1)?;
+                }
+                Value::Other
+            },
+            (MAP, _, len) => {
+                for _ in 0..len {
+                    self.value_nested(depth + 1)?;
+                    self.value_nested(depth + 1)?;
+                }
+                Value::Other
+            },
+            (TAG, _, _) => {
+                self.value_nested(depth + 1)?;
+                Value::Other
+            },
+            (SIMPLE, 20, _) => Value::Bool(false),
+            (SIMPLE, 21, _) => Value::Bool(true),
+            // other simple values and floats, the two-byte form of simple values must not
+            // encode values below 32
+            (SIMPLE, 24, value) if value < 32 => return Err(Error::InvalidCbor),
+            (SIMPLE, _, _) => Value::Other,
+            _ => unreachable!("the major type has 3 bits"),
+        };
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{push_bytes, push_int, push_map_header, Decoder, Value};
+    use crate::test_util::hex;
+    use crate::Error;
+
+    #[test]
+    fn rfc8949_examples() {
+        let ints: [(i64, &str); 10] = [
+            (0, "00"),
+            (23, "17"),
+            (24, "1818"),
+            (1000, "1903e8"),
+            (1000000, "1a000f4240"),
+            (1000000000000, "1b000000e8d4a51000"),
+            (-1, "20"),
+            (-100, "3863"),
+            (-1000, "3903e7"),
+            (i64::MIN, "3b7fffffffffffffff"),
+        ];
+        for (value, encoded) in &ints {
+            let mut out = Vec::new();
+            push_int(&mut out, *value);
+            assert_eq!(out, hex(encoded), "{}", value);
+            assert_eq!(Decoder::new(&out).value(), Ok(Value::Int(*value)));
+        }
+
+        let mut out = Vec::new();
+        push_bytes(&mut out, &[1, 2, 3, 4]);
+        assert_eq!(out, hex("4401020304"));
+        push_map_header(&mut out, 2);
+        assert_eq!(out[5..], hex("a2")[..]);
+
+        let decode = |encoded: &str| {
+            let encoded = hex(encoded);
+            let mut decoder = Decoder::new(&encoded);
+            let value = decoder.value().map(|value| match value {
+                Value::Int(value) => Value::Int(value),
+                Value::Bool(value) => Value::Bool(value),
+                _ => Value::Other,
+            });
+            assert!(decoder.is_empty() || value.is_err(), "{:?} didn't consume everything", encoded);
+            value
+        };
+        assert_eq!(decode("f4"), Ok(Value::Bool(false)));
+        assert_eq!(decode("f5"), Ok(Value::Bool(true)));
+        for skipped in &["f6", "f93c00", "f90014", "fb3ff199999999999a", "c11a514b67b0", "83010203", "a201020304", "826161a161626163", "1bffffffffffffffff", "6449455446"] {
+            assert_eq!(decode(skipped), Ok(Value::Other), "{}", skipped);
+        }
+        // indefinite lengths, reserved values, truncated data, invalid UTF-8, non-minimal
+        // simple value
+        for invalid in &["5f42010243030405ff", "9fff", "1c", "1a000f42", "44010203", "62c328", "f814"] {
+            assert_eq!(decode(invalid), Err(Error::InvalidCbor), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn deep_nesting_is_rejected() {
+        let nested = [0x81; 100];
+        assert_eq!(Decoder::new(&nested).value(), Err(Error::InvalidCbor));
+    }
+}
@@ -0,0 +1,46 @@
+//! ChaCha block function (RFC 8439) with configurable number of rounds
+//!
+//! ChaCha20 is used by the AEAD, ChaCha8 by the deterministic test generator.
+
+fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]); state[d] ^= state[a]; state[d] = state[d].rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]); state[b] ^= state[c]; state[b] = state[b].rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]); state[d] ^= state[a]; state[d] = state[d].rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]); state[b] ^= state[c]; state[b] = state[b].rotate_left(7);
+}
+
+fn le_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+/// Computes one 64-byte keystream block using `rounds` rounds, which must be even
+pub(crate) fn chacha_block(key: &[u8; 32], counter: u32, nonce: &[u8; 12], rounds: usize) -> [u8; 64] {
+    let mut state = [0u32; 16];
+    // "expand 32-byte k"
+    state[..4].copy_from_slice(&[0x61707865, 0x3320646e, 0x79622d32, 0x6b206574]);
+    for (word, chunk) in state[4..12].iter_mut().zip(key.chunks(4)) {
+        *word = le_u32(chunk);
+    }
+    state[12] = counter;
+    for (word, chunk) in state[13..].iter_mut().zip(nonce.chunks(4)) {
+        *word = le_u32(chunk);
+    }
+
+    let mut working = state;
+    for _ in 0..(rounds / 2) {
+        quarter_round(&mut working, 0, 4, 8, 12);
+        quarter_round(&mut working, 1, 5, 9, 13);
+        quarter_round(&mut working, 2, 6, 10, 14);
+        quarter_round(&mut working, 3, 7, 11, 15);
+        quarter_round(&mut working, 0, 5, 10, 15);
+        quarter_round(&mut working, 1, 6, 11, 12);
+        quarter_round(&mut working, 2, 7, 8, 13);
+        quarter_round(&mut working, 3, 4, 9, 14);
+    }
+
+    let mut block = [0; 64];
+    for ((chunk, working), state) in block.chunks_mut(4).zip(&working).zip(&state) {
+        chunk.copy_from_slice(&working.wrapping_add(*state).to_le_bytes());
+    }
+    block
+}
@@ -0,0 +1,147 @@
+//! ChaCha20-Poly1305 AEAD as specified by RFC 8439
+//!
+//! Poly1305 is computed naively using `U256` arithmetic modulo `2^130 - 5`, the same way the rest
+//! of the crate does it.
+
+use crate::chacha::chacha_block;
+use crate::U256;
+use crate::Error;
+
+/// Length of the authentication tag
+pub(crate) const TAG_LEN: usize = 16;
+
+/// XORs `data` with the keystream starting at block `counter`
+fn chacha20_xor(key: &[u8; 32], mut counter: u32, nonce: &[u8; 12], data: &mut [u8]) {
+    for chunk in data.chunks_mut(64) {
+        let block = chacha_block(key, counter, nonce, 20);
+        for (byte, key_byte) in chunk.iter_mut().zip(&block) {
+            *byte ^= key_byte;
+        }
+        counter += 1;
+    }
+}
+
+/// Incremental Poly1305 over 16-byte blocks
+struct Poly1305 {
+    r: U256,
+    s: U256,
+    acc: U256,
+}
+
+impl Poly1305 {
+    fn new(key: &[u8; 32]) -> Self {
+        let mut r = [0; 16];
+        r.copy_from_slice(&key[..16]);
+        for i in &[3, 7, 11, 15] {
+            r[*i] &= 0x0f;
+        }
+        for i in &[4, 8, 12] {
+            r[*i] &= 0xfc;
+        }
+        Poly1305 {
+            r: U256::from_little_endian(&r),
+            s: U256::from_little_endian(&key[16..]),
+            acc: U256::zero(),
+        }
+    }
+
+    /// Processes the message, the last block may be shorter than 16 bytes
+    fn update(&mut self, data: &[u8]) {
+        // (2^130 - 5) doesn't fit into u64 constructors so it's computed
+        let p = (U256::one() << 130) - U256::from(5);
+        for chunk in data.chunks(16) {
+            let mut block = [0; 17];
+            block[..chunk.len()].copy_from_slice(chunk);
+            block[chunk.len()] = 1;
+            // acc < 2^130 and r < 2^124 so the product can't overflow
+            self.acc = (self.acc + U256::from_little_endian(&block)) * self.r % p;
+        }
+    }
+
+    /// Processes the data zero-padded to the multiple of 16 bytes as AEAD construction requires
+    fn update_padded(&mut self, data: &[u8]) {
+        let full_len = data.len() - data.len() % 16;
+        self.update(&data[..full_len]);
+        if full_len < data.len() {
+            let mut block = [0; 16];
+            block[..(data.len() - full_len)].copy_from_slice(&data[full_len..]);
+            self.update(&block);
+        }
+    }
+
+    fn finalize(self) -> [u8; TAG_LEN] {
+        let bytes = (self.acc + self.s).to_le_bytes();
+        let mut tag = [0; TAG_LEN];
+        tag.copy_from_slice(&bytes[..TAG_LEN]);
+        tag
+    }
+}
+
+fn compute_tag(key: &[u8; 32], nonce: &[u8; 12], aad: &[u8], ciphertext: &[u8]) -> [u8; TAG_LEN] {
+    let mut poly_key = [0; 32];
+    poly_key.copy_from_slice(&chacha_block(key, 0, nonce, 20)[..32]);
+    let mut poly = Poly1305::new(&poly_key);
+    poly.update_padded(aad);
+    poly.update_padded(ciphertext);
+    let mut lengths = [0; 16];
+    lengths[..8].copy_from_slice(&(aad.len() as u64).to_le_bytes());
+    lengths[8..].copy_from_slice(&(ciphertext.len() as u64).to_le_bytes());
+    poly.update_padded(&lengths);
+    poly.finalize()
+}
+
+/// Encrypts `buffer` in place and returns the authentication tag
+pub(crate) fn seal(key: &[u8; 32], nonce: &[u8; 12], aad: &[u8], buffer: &mut [u8]) -> [u8; TAG_LEN] {
+    chacha20_xor(key, 1, nonce, buffer);
+    compute_tag(key, nonce, aad, buffer)
+}
+
+/// Checks the tag and decrypts `buffer` in place.
+///
+/// Returns `Error::DecryptionFailed` and leaves `buffer` untouched if the tag doesn't match.
+pub(crate) fn open(key: &[u8; 32], nonce: &[u8; 12], aad: &[u8], buffer: &mut [u8], tag: &[u8; TAG_LEN]) -> Result<(), Error> {
+    let expected = compute_tag(key, nonce, aad, buffer);
+    // the tag is public once the check fails but let's not leak the position of the first
+    // mismatching byte anyway
+    if !crate::bytes_eq_ct(&expected, tag) {
+        return Err(Error::DecryptionFailed);
+    }
+    chacha20_xor(key, 1, nonce, buffer);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{open, seal, Poly1305};
+    use crate::test_util::{hex, hex32};
+    use crate::Error;
+
+    #[test]
+    fn rfc8439_poly1305() {
+        let mut poly = Poly1305::new(&hex32("85d6be7857556d337f4452fe42d506a80103808afb0db2fd4abff6af4149f51b"));
+        poly.update(b"Cryptographic Forum Research Group");
+        assert_eq!(poly.finalize()[..], hex("a8061dc1305136c6c22b8baf0c0127a9")[..]);
+    }
+
+    #[test]
+    fn rfc8439_aead() {
+        let key = hex32("808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f");
+        let mut nonce = [0; 12];
+        nonce.copy_from_slice(&hex("070000004041424344454647"));
+        let aad = hex("50515253c0c1c2c3c4c5c6c7");
+        let plaintext = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+        let expected = hex("d31a8d34648e60db7b86afbc53ef7ec2a4aded51296e08fea9e2b5a736ee62d63dbea45e8ca9671282fafb69da92728b1a71de0a9e060b2905d6a5b67ecd3b3692ddbd7f2d778b8c9803aee328091b58fab324e4fad675945585808b4831d7bc3ff4def08e4b7a9de576d26586cec64b6116");
+
+        let mut buffer = plaintext.to_vec();
+        let tag = seal(&key, &nonce, &aad, &mut buffer);
+        assert_eq!(buffer, expected);
+        assert_eq!(tag[..], hex("1ae10b594f09e26a7e902ecbd0600691")[..]);
+
+        assert_eq!(open(&key, &nonce, &aad, &mut buffer, &tag), Ok(()));
+        assert_eq!(buffer[..], plaintext[..]);
+
+        let mut buffer = expected.clone();
+        assert_eq!(open(&key, &nonce, &aad[1..], &mut buffer, &tag), Err(Error::DecryptionFailed));
+        assert_eq!(buffer, expected);
+    }
+}
@@ -0,0 +1,290 @@
+//! Fixed-size binary encoding shared by the public types
+//!
+//! | Type                   | Length | Layout                                        |
+//! |------------------------|--------|-----------------------------------------------|
+//! | `U256`, `Zp`, `Scalar` | 32     | big-endian                                    |
+//! | `SecretKey`            | 32     | big-endian scalar                             |
+//! | `NonZeroScalar`        | 32     | big-endian                                    |
+//! | `XOnlyPoint`           | 32     | big-endian x coordinate                       |
+//! | `Point`                | 33     | compressed SEC1, all zeros for infinity       |
+//! | `PublicKey`            | 33     | compressed SEC1                               |
+//! | `NonIdentityPoint`     | 33     | compressed SEC1                               |
+//! | `ecdsa::Signature`     | 64     | `r || s`                                      |
+//! | `RecoverableSignature` | 65     | `r || s || recovery id`                       |
+//!
+//! Parsing is strict: out-of-range integers, points not on the curve and invalid recovery ids
+//! are rejected rather than reduced. The wrappers reject the values they exclude.
+
+use core::convert::TryFrom;
+use crate::ecdsa::{RecoverableSignature, RecoveryId, Signature};
+use crate::{Error, NonIdentityPoint, NonZeroScalar, Point, PublicKey, Scalar, SecretKey, XOnlyPoint, Zp, U256};
+
+/// Serialization into a fixed-size array
+pub trait ToBytes {
+    /// Length of the encoding in bytes
+    const LEN: usize;
+
+    /// Always `[u8; Self::LEN]`
+    type Bytes: AsRef<[u8]> + Copy + for<'a> TryFrom<&'a [u8]>;
+
+    fn to_bytes(&self) -> Self::Bytes;
+}
+
+/// Strict parsing of the encoding produced by `ToBytes`
+pub trait FromBytes: ToBytes + Sized {
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error>;
+
+    /// Parses the slice returning `Error::InvalidLength` if it's not `Self::LEN` bytes long
+    fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        let array = Self::Bytes::try_from(bytes).map_err(|_| Error::InvalidLength(bytes.len()))?;
+        Self::from_bytes(&array)
+    }
+}
+
+impl ToBytes for U256 {
+    const LEN: usize = 32;
+    type Bytes = [u8; 32];
+
+    fn to_bytes(&self) -> Self::Bytes {
+        self.to_be_bytes()
+    }
+}
+
+impl FromBytes for U256 {
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error> {
+        Ok(U256::from_be_bytes(bytes))
+    }
+}
+
+impl ToBytes for Zp {
+    const LEN: usize = 32;
+    type Bytes = [u8; 32];
+
+    fn to_bytes(&self) -> Self::Bytes {
+        self.to_be_bytes()
+    }
+}
+
+impl FromBytes for Zp {
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error> {
+        Zp::try_from_u256(U256::from_be_bytes(bytes))
+    }
+}
+
+impl ToBytes for Scalar {
+    const LEN: usize = 32;
+    type Bytes = [u8; 32];
+
+    fn to_bytes(&self) -> Self::Bytes {
+        self.to_be_bytes()
+    }
+}
+
+impl FromBytes for Scalar {
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error> {
+        Scalar::try_from_u256(U256::from_be_bytes(bytes))
+    }
+}
+
+impl ToBytes for SecretKey {
+    const LEN: usize = 32;
+    type Bytes = [u8; 32];
+
+    fn to_bytes(&self) -> Self::Bytes {
+        SecretKey::to_bytes(self)
+    }
+}
+
+impl FromBytes for SecretKey {
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error> {
+        SecretKey::from_bytes(bytes)
+    }
+}
+
+impl ToBytes for NonZeroScalar {
+    const LEN: usize = 32;
+    type Bytes = [u8; 32];
+
+    fn to_bytes(&self) -> Self::Bytes {
+        self.to_be_bytes()
+    }
+}
+
+impl FromBytes for NonZeroScalar {
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error> {
+        NonZeroScalar::from_be_bytes(bytes)
+    }
+}
+
+impl ToBytes for XOnlyPoint {
+    const LEN: usize = 32;
+    type Bytes = [u8; 32];
+
+    fn to_bytes(&self) -> Self::Bytes {
+        self.serialize()
+    }
+}
+
+impl FromBytes for XOnlyPoint {
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error> {
+        XOnlyPoint::from_bytes(bytes)
+    }
+}
+
+impl ToBytes for Point {
+    const LEN: usize = 33;
+    type Bytes = [u8; 33];
+
+    fn to_bytes(&self) -> Self::Bytes {
+        self.serialize_compressed()
+    }
+}
+
+impl FromBytes for Point {
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error> {
+        if *bytes == [0; 33] {
+            Ok(Point::AT_INFINITY)
+        } else {
+            Point::from_sec1_bytes(bytes)
+        }
+    }
+}
+
+impl ToBytes for PublicKey {
+    const LEN: usize = 33;
+    type Bytes = [u8; 33];
+
+    fn to_bytes(&self) -> Self::Bytes {
+        self.serialize()
+    }
+}
+
+impl FromBytes for PublicKey {
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error> {
+        PublicKey::from_sec1_bytes(bytes)
+    }
+}
+
+impl ToBytes for NonIdentityPoint {
+    const LEN: usize = 33;
+    type Bytes = [u8; 33];
+
+    fn to_bytes(&self) -> Self::Bytes {
+        self.serialize_compressed()
+    }
+}
+
+impl FromBytes for NonIdentityPoint {
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error> {
+        NonIdentityPoint::from_sec1_bytes(bytes)
+    }
+}
+
+impl ToBytes for Signature {
+    const LEN: usize = 64;
+    type Bytes = [u8; 64];
+
+    fn to_bytes(&self) -> Self::Bytes {
+        self.serialize_compact()
+    }
+}
+
+impl FromBytes for Signature {
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error> {
+        Signature::from_compact(bytes)
+    }
+}
+
+impl ToBytes for RecoverableSignature {
+    const LEN: usize = 65;
+    type Bytes = [u8; 65];
+
+    fn to_bytes(&self) -> Self::Bytes {
+        let (compact, recovery_id) = self.serialize_compact();
+        let mut bytes = [0; 65];
+        bytes[..64].copy_from_slice(&compact);
+        bytes[64] = recovery_id.to_u8();
+        bytes
+    }
+}
+
+impl FromBytes for RecoverableSignature {
+    fn from_bytes(bytes: &Self::Bytes) -> Result<Self, Error> {
+        let mut compact = [0; 64];
+        compact.copy_from_slice(&bytes[..64]);
+        RecoverableSignature::from_compact(&compact, RecoveryId::from_u8(bytes[64])?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Debug;
+    use rand_core::RngCore;
+    use super::{FromBytes, ToBytes};
+    use crate::ecdsa::{RecoverableSignature, RecoveryId, Signature};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, Message, NonIdentityPoint, NonZeroScalar, Point, PublicKey, Scalar, SecretKey, XOnlyPoint, Zp, U256, G};
+
+    /// Checks the round trip and that corrupting any byte doesn't panic and doesn't decode to
+    /// the same value
+    fn check<T: FromBytes + Eq + Debug>(value: T, rng: &mut DeterministicRng) {
+        let bytes = value.to_bytes();
+        assert_eq!(bytes.as_ref().len(), T::LEN);
+        assert_eq!(T::from_bytes(&bytes).as_ref(), Ok(&value));
+        assert_eq!(T::from_slice(bytes.as_ref()).as_ref(), Ok(&value));
+        assert_eq!(T::from_slice(&bytes.as_ref()[1..]).err(), Some(Error::InvalidLength(T::LEN - 1)));
+
+        for i in 0..T::LEN {
+            for &mask in &[0x01, 0x80, 0xFF, (rng.next_u32() as u8) | 1] {
+                let mut corrupted = bytes.as_ref().to_vec();
+                corrupted[i] ^= mask;
+                if let Ok(decoded) = T::from_slice(&corrupted) {
+                    assert_ne!(decoded, value);
+                    assert_eq!(decoded.to_bytes().as_ref(), &corrupted[..]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut rng = DeterministicRng::new(179);
+        for _ in 0..4 {
+            let scalar = Scalar::random(&mut rng);
+            let secret_key = SecretKey::from_scalar(scalar).unwrap();
+            let signature = crate::ecdsa::sign_recoverable_with(&Message::from_digest([0x42; 32]), &secret_key, &mut crate::nonce::RngNonce(&mut rng));
+            check(scalar.to_u256(), &mut rng);
+            check(scalar, &mut rng);
+            check(Zp::from_be_bytes(&scalar.to_be_bytes()).unwrap(), &mut rng);
+            check(secret_key, &mut rng);
+            check(NonZeroScalar::new(scalar).unwrap(), &mut rng);
+            check(G * scalar, &mut rng);
+            check(NonIdentityPoint::new(G * scalar).unwrap(), &mut rng);
+            check(secret_key.public_key(), &mut rng);
+            check(secret_key.public_key().x_only_public_key().0, &mut rng);
+            check(signature, &mut rng);
+            check(Signature::from_compact(&signature.serialize_compact().0).unwrap(), &mut rng);
+        }
+        check(Point::AT_INFINITY, &mut rng);
+    }
+
+    #[test]
+    fn boundaries() {
+        let minus_one = -Scalar::from(1);
+        assert_eq!(Scalar::from_bytes(&minus_one.to_bytes()), Ok(minus_one));
+        let mut n = minus_one.to_bytes();
+        n[31] += 1;
+        assert_eq!(Scalar::from_bytes(&n), Err(Error::NonCanonicalScalar));
+        assert_eq!(SecretKey::from_bytes(&[0; 32]), Err(Error::InvalidSecretKey));
+        assert_eq!(<Zp as FromBytes>::from_bytes(&[0xFF; 32]), Err(Error::NonCanonicalFieldElement));
+        assert_eq!(U256::from_bytes(&[0xFF; 32]), Ok(U256::max_value()));
+        assert_eq!(<PublicKey as FromBytes>::from_bytes(&[0; 33]), Err(Error::InvalidPrefix(0)));
+        assert_eq!(<XOnlyPoint as FromBytes>::from_bytes(&[0xFF; 32]), Err(Error::NonCanonicalFieldElement));
+
+        let signature = RecoverableSignature::from_compact(&[1; 64], RecoveryId::from_u8(3).unwrap()).unwrap();
+        let mut bytes = signature.to_bytes();
+        assert_eq!(bytes[64], 3);
+        bytes[64] = 4;
+        assert_eq!(RecoverableSignature::from_bytes(&bytes), Err(Error::InvalidRecoveryId));
+    }
+}
@@ -0,0 +1,157 @@
+//! Counters of the field and point operations performed by the current thread
+//!
+//! Meant for teaching and for comparing algorithms without a profiler. The counters are
+//! incremented by the operators of `Zp` and `Point`: field multiplication (`*` of two elements),
+//! squaring (`square`), inversion (`multiplicative_inverse` and `/`), and point addition and
+//! doubling (`+`). Additions involving the point at infinity or a point and its negation are
+//! shortcuts that don't evaluate the formula so they aren't counted. The specialized routines
+//! calling the low-level arithmetic directly (the precomputed tables, `Point::mul_ct`,
+//! `Zp::mul_many` and similar) are not counted either.
+//!
+//! Without the `counters` feature the increments are not compiled at all.
+
+use core::cell::Cell;
+use core::ops::Sub;
+
+/// Numbers of operations performed
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct OpCounts {
+    pub field_multiplications: u64,
+    pub field_squarings: u64,
+    pub field_inversions: u64,
+    pub point_additions: u64,
+    pub point_doublings: u64,
+}
+
+/// Operations performed between two snapshots
+impl Sub for OpCounts {
+    type Output = OpCounts;
+
+    fn sub(self, rhs: OpCounts) -> Self::Output {
+        OpCounts {
+            field_multiplications: self.field_multiplications - rhs.field_multiplications,
+            field_squarings: self.field_squarings - rhs.field_squarings,
+            field_inversions: self.field_inversions - rhs.field_inversions,
+            point_additions: self.point_additions - rhs.point_additions,
+            point_doublings: self.point_doublings - rhs.point_doublings,
+        }
+    }
+}
+
+std::thread_local! {
+    static COUNTS: Cell<OpCounts> = Cell::new(OpCounts::default());
+}
+
+/// Increments the counter of the current thread selected by `field`, used by the `count_op` macro
+pub(crate) fn increment(field: impl FnOnce(&mut OpCounts) -> &mut u64) {
+    COUNTS.with(|counts| {
+        let mut value = counts.get();
+        *field(&mut value) += 1;
+        counts.set(value);
+    });
+}
+
+/// Sets all counters of the current thread to zero
+pub fn reset() {
+    COUNTS.with(|counts| counts.set(OpCounts::default()));
+}
+
+/// Returns the counts of the current thread since it started or since the last `reset`
+pub fn snapshot() -> OpCounts {
+    COUNTS.with(Cell::get)
+}
+
+/// Runs `f` returning its result and the operations it performed.
+///
+/// The counters are not reset so the calls can be nested.
+pub fn count_ops<T>(f: impl FnOnce() -> T) -> (T, OpCounts) {
+    let before = snapshot();
+    let res = f();
+    (res, snapshot() - before)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_ops, reset, snapshot, OpCounts};
+    use crate::{recode, Point, Scalar, G, U256};
+
+    #[test]
+    fn field_operations() {
+        let a = G.x();
+        let b = G.y();
+        let (_, counts) = count_ops(|| (a * b, a.square(), a / b, a + b, a * 7));
+        // division is a multiplication by the inverse, small constants are not counted
+        let expected = OpCounts { field_multiplications: 2, field_squarings: 1, field_inversions: 1, ..OpCounts::default() };
+        assert_eq!(counts, expected);
+    }
+
+    #[test]
+    fn point_addition() {
+        let p = G * 2;
+        let (sum, counts) = count_ops(|| G + p);
+        assert_eq!(sum, G * 3);
+        let expected = OpCounts {
+            field_multiplications: 2,
+            field_squarings: 1,
+            field_inversions: 1,
+            point_additions: 1,
+            point_doublings: 0,
+        };
+        assert_eq!(counts, expected);
+
+        let (_, counts) = count_ops(|| G + G);
+        let expected = OpCounts {
+            field_multiplications: 2,
+            field_squarings: 2,
+            field_inversions: 1,
+            point_additions: 0,
+            point_doublings: 1,
+        };
+        assert_eq!(counts, expected);
+
+        // shortcuts
+        let (_, counts) = count_ops(|| (G + Point::AT_INFINITY, G + -G));
+        assert_eq!(counts, OpCounts::default());
+    }
+
+    /// wNAF with width 4: `P + P` and three additions for the table `P, 3P, 5P, 7P`, then a
+    /// doubling per digit and an addition per non-zero digit except for the first one which
+    /// only sets the point at infinity to the table entry
+    #[test]
+    fn scalar_multiplication() {
+        let k = 0x5A5A_F00F_1234_ABCD;
+        let mut digits = [0; recode::MAX_DIGITS];
+        let len = recode::wnaf_into(U256::from(k), 4, &mut digits);
+        let nonzero = digits[..len].iter().filter(|digit| **digit != 0).count() as u64;
+        let doublings = 1 + (len as u64 - 1);
+        let additions = 3 + (nonzero - 1);
+
+        let (product, counts) = count_ops(|| G * Scalar::from(k));
+        assert_eq!(product, G * U256::from(k));
+        let expected = OpCounts {
+            field_multiplications: 2 * (additions + doublings),
+            field_squarings: additions + 2 * doublings,
+            field_inversions: additions + doublings,
+            point_additions: additions,
+            point_doublings: doublings,
+        };
+        assert_eq!(counts, expected);
+    }
+
+    #[test]
+    fn reset_and_nesting() {
+        let p = G * 2;
+        reset();
+        assert_eq!(snapshot(), OpCounts::default());
+        let ((_, inner), outer) = count_ops(|| {
+            let _ = G + G;
+            count_ops(|| G + p)
+        });
+        assert_eq!(inner.point_additions, 1);
+        assert_eq!(outer.point_doublings, 1);
+        assert_eq!(outer.point_additions, 1);
+        assert_eq!(snapshot(), outer);
+        reset();
+        assert_eq!(snapshot(), OpCounts::default());
+    }
+}
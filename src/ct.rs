@@ -0,0 +1,321 @@
+//! Constant-time selection using the `subtle` crate
+//!
+//! Only the selection is constant time - the arithmetic of this crate is not, so these are
+//! building blocks for experimenting with side-channel resistant algorithms rather than a
+//! guarantee.
+//!
+//! With this feature the multiplications by secret scalars don't index tables by secret digits,
+//! every lookup reads the whole table using `Point::ct_select`:
+//!
+//! * `Point::mul_ct`
+//! * `Point::mul_gen`, used for key generation, tweaking and signing nonces, both with and
+//!   without the `precomputed-tables` feature
+//!
+//! The multiplication operators (`Point * Scalar` and others) and `multiexp` are meant for
+//! public scalars (verification) and keep indexing directly.
+//!
+//! `ConstantTimeEq` is implemented for points, keys and signatures and `ct_eq_bytes` compares
+//! byte strings such as ECDH shared secrets. Use them when comparing a value derived from a
+//! secret with one presented by the other party, `==` leaks the position of the first
+//! difference.
+
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use crate::ecdsa::Signature;
+use crate::field;
+use crate::params::P;
+use crate::{Point, PublicKey, Scalar, XOnlyPoint, Zp, U256};
+
+/// `√-Z = √11` where `Z = -11` is the non-square of the simplified SWU map for secp256k1
+/// (RFC 9380 section 8.7)
+const SQRT_MINUS_Z: Zp = crate::zp!("31fdf302724013e57ad13fb38f842afeec184f00a74789dd286729c8303c4a59");
+
+impl ConditionallySelectable for U256 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut limbs = [0; 4];
+        for (limb, (a, b)) in limbs.iter_mut().zip(a.0.iter().zip(&b.0)) {
+            *limb = u64::conditional_select(a, b, choice);
+        }
+        U256(limbs)
+    }
+}
+
+impl ConditionallySelectable for Zp {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Zp::from_reduced(U256::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl ConstantTimeEq for U256 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConstantTimeEq for Zp {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0 .0.ct_eq(&other.0 .0)
+    }
+}
+
+/// Compares the affine coordinates, which are canonical, the point at infinity is `(0, 0)`
+impl ConstantTimeEq for Point {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y)
+    }
+}
+
+impl ConstantTimeEq for PublicKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.to_point().ct_eq(&other.to_point())
+    }
+}
+
+impl ConstantTimeEq for XOnlyPoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.to_point().ct_eq(&other.to_point())
+    }
+}
+
+impl ConstantTimeEq for Signature {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.r().ct_eq(&other.r()) & self.s().ct_eq(&other.s())
+    }
+}
+
+/// Compares the bytes without exiting at the first difference, only the lengths may leak
+pub fn ct_eq_bytes(a: &[u8], b: &[u8]) -> Choice {
+    Choice::from(u8::from(crate::bytes_eq_ct(a, b)))
+}
+
+impl Zp {
+    /// Computes `√(u/v)` without inverting `v`, `sqrt_ratio_3mod4` from RFC 9380.
+    ///
+    /// Returns `(1, √(u/v))` if `u/v` is a square and `(0, √(Z·u/v))` otherwise where `Z = -11`
+    /// is the non-square used by the simplified SWU map for secp256k1. Either root may be
+    /// returned. If `v` is zero the result is `(1, 0)` for zero `u` and `(0, 0)` otherwise. The
+    /// sequence of operations doesn't depend on the values.
+    pub fn sqrt_ratio(u: Zp, v: Zp) -> (Choice, Zp) {
+        // y₁ = u·v·(u·v³)^((p - 3) / 4), y₁² = u/v if it's a square
+        let uv = field::mul(u.0, v.0);
+        let uv3 = field::mul(field::mul(v.0, v.0), uv);
+        let y1 = Zp::from_reduced(field::mul(field::pow(uv3, P.wrapping_shr(2)), uv));
+        // y₁²·v = ±u and if it's -u then (y₁·√-Z)² = Z·u/v
+        let y2 = Zp::from_reduced(field::mul(y1.0, SQRT_MINUS_Z.0));
+        let is_square = Zp::from_reduced(field::mul(field::mul(y1.0, y1.0), v.0)).ct_eq(&u);
+        (is_square, Zp::conditional_select(&y2, &y1, is_square))
+    }
+}
+
+/// `ConditionallyNegatable` is implemented automatically using this and `Neg for &Point`
+impl ConditionallySelectable for Point {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Point {
+            x: Zp::conditional_select(&a.x, &b.x, choice),
+            y: Zp::conditional_select(&a.y, &b.y, choice),
+        }
+    }
+}
+
+impl Point {
+    /// Returns `table[index]` reading every entry so that memory access doesn't depend on
+    /// `index`.
+    ///
+    /// Returns the point at infinity if `index` is out of range.
+    pub fn ct_select(table: &[Point], index: usize) -> Point {
+        let index = index as u64;
+        table.iter().enumerate().fold(Point::AT_INFINITY, |selected, (i, point)| {
+            Point::conditional_select(&selected, point, (i as u64).ct_eq(&index))
+        })
+    }
+
+    /// Computes `k·self` using fixed 4-bit windows with the whole table read for every digit.
+    ///
+    /// The result is the same as `self * k`, which is faster but indexes its table by the digits
+    /// of `k`.
+    pub fn mul_ct(&self, k: &Scalar) -> Point {
+        // multiples 0 to 15, so that every digit, including zero, is a lookup
+        let mut table = [Point::AT_INFINITY; 16];
+        for i in 1..table.len() {
+            table[i] = table[i - 1] + *self;
+        }
+        let mut res = Point::AT_INFINITY;
+        for byte in &k.to_be_bytes() {
+            for digit in &[byte >> 4, byte & 0x0F] {
+                for _ in 0..4 {
+                    res = res + res;
+                }
+                res += Point::ct_select(&table, usize::from(*digit));
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Point, Scalar, Zp, G, H, U256};
+
+    const SSWU_Z: Zp = crate::zp!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc24");
+
+    /// Scalars whose every digit is `digit`, except the top one which is zero to stay below `n`
+    fn repeated_digit_scalars() -> impl Iterator<Item = Scalar> {
+        (0..16u8).map(|digit| {
+            let mut bytes = [digit * 0x11; 32];
+            bytes[0] = digit;
+            Scalar::from_be_bytes(&bytes).unwrap()
+        })
+    }
+
+    #[test]
+    fn select_and_negate() {
+        let a = G * 3;
+        let b = G * 5;
+        assert_eq!(Point::conditional_select(&a, &b, Choice::from(0)), a);
+        assert_eq!(Point::conditional_select(&a, &b, Choice::from(1)), b);
+        let mut point = a;
+        point.conditional_negate(Choice::from(0));
+        assert_eq!(point, a);
+        point.conditional_negate(Choice::from(1));
+        assert_eq!(point, -a);
+        let mut infinity = Point::AT_INFINITY;
+        infinity.conditional_negate(Choice::from(1));
+        assert!(infinity.is_at_infinity());
+        assert_eq!(Scalar::conditional_select(&Scalar::ONE, &-Scalar::ONE, Choice::from(1)), -Scalar::ONE);
+    }
+
+    #[test]
+    fn table_lookup() {
+        // the multiplication uses 4 odd multiples, check other sizes too
+        for size in 1..=16 {
+            let table = (1..=size).map(|i| G * (2 * i - 1)).collect::<Vec<_>>();
+            for (index, point) in table.iter().enumerate() {
+                assert_eq!(Point::ct_select(&table, index), *point);
+            }
+            assert!(Point::ct_select(&table, table.len()).is_at_infinity());
+        }
+        assert!(Point::ct_select(&[], 0).is_at_infinity());
+    }
+
+    #[test]
+    fn mul_ct_matches_vartime() {
+        let mut rng = DeterministicRng::new(228);
+        let edge_cases = [Scalar::ZERO, Scalar::ONE, -Scalar::ONE, Scalar::from(15), Scalar::from(16)];
+        let random = (0..16).map(|_| Scalar::random(&mut rng));
+        for k in repeated_digit_scalars().chain(edge_cases.iter().copied()).chain(random) {
+            assert_eq!(G.mul_ct(&k), G * k, "{:?}", k.to_be_bytes());
+            assert_eq!(H.mul_ct(&k), H * k, "{:?}", k.to_be_bytes());
+            assert_eq!(Point::mul_gen(&k), G * k, "{:?}", k.to_be_bytes());
+        }
+        assert!(Point::AT_INFINITY.mul_ct(&Scalar::ONE).is_at_infinity());
+    }
+
+    fn random_element(rng: &mut DeterministicRng) -> Zp {
+        use rand_core::RngCore;
+
+        let mut bytes = [0; 32];
+        rng.fill_bytes(&mut bytes);
+        Zp::wrapping_from(U256::from_be_bytes(&bytes))
+    }
+
+    #[test]
+    fn sqrt_ratio_matches_naive() {
+        assert_eq!(SSWU_Z, -Zp::from_reduced(U256::from(11)));
+        assert!(SSWU_Z.sqrt().is_none());
+        let mut rng = DeterministicRng::new(229);
+        let mut squares = 0;
+        for _ in 0..64 {
+            let u = random_element(&mut rng);
+            let v = random_element(&mut rng);
+            let ratio = u / v;
+            let (is_square, root) = Zp::sqrt_ratio(u, v);
+            match ratio.sqrt() {
+                Some(expected) => {
+                    assert!(bool::from(is_square));
+                    assert!(root == expected || root == -expected);
+                    squares += 1;
+                },
+                None => {
+                    // the fallback
+                    assert!(!bool::from(is_square));
+                    assert_eq!(root * root, SSWU_Z * ratio);
+                },
+            }
+        }
+        assert!(squares > 16 && squares < 48, "{}", squares);
+        // u/v = 4
+        let (is_square, root) = Zp::sqrt_ratio(Zp::from_reduced(U256::from(12)), Zp::from_reduced(U256::from(3)));
+        assert!(bool::from(is_square));
+        assert!(root == Zp::from_reduced(U256::from(2)) || root == -Zp::from_reduced(U256::from(2)));
+        // u/v = -1 is not a square, the fallback is √11
+        let (is_square, root) = Zp::sqrt_ratio(-Zp::ONE, Zp::ONE);
+        assert!(!bool::from(is_square));
+        assert_eq!(root * root, Zp::from_reduced(U256::from(11)));
+    }
+
+    #[test]
+    fn sqrt_ratio_zero() {
+        let (is_square, root) = Zp::sqrt_ratio(Zp::ZERO, Zp::ZERO);
+        assert!(bool::from(is_square));
+        assert_eq!(root, Zp::ZERO);
+        let (is_square, root) = Zp::sqrt_ratio(Zp::ONE, Zp::ZERO);
+        assert!(!bool::from(is_square));
+        assert_eq!(root, Zp::ZERO);
+        let (is_square, root) = Zp::sqrt_ratio(Zp::ZERO, Zp::from_reduced(U256::from(5)));
+        assert!(bool::from(is_square));
+        assert_eq!(root, Zp::ZERO);
+    }
+
+    #[test]
+    fn ct_eq_matches_eq() {
+        use subtle::ConstantTimeEq;
+        use crate::ecdsa::Signature;
+        use crate::PublicKey;
+
+        let mut rng = DeterministicRng::new(245);
+        let mut points = (0..6).map(|_| G * Scalar::random(&mut rng)).collect::<Vec<_>>();
+        // -G has the same x-only key as G
+        points.extend_from_slice(&[Point::AT_INFINITY, G, -G, H]);
+        for a in &points {
+            for b in &points {
+                assert_eq!(bool::from(a.ct_eq(b)), a == b);
+                assert_eq!(a.eq_ct(b), a == b);
+                if let (Some(a), Some(b)) = (PublicKey::from_point(*a), PublicKey::from_point(*b)) {
+                    assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+                    let (a, b) = (a.x_only_public_key().0, b.x_only_public_key().0);
+                    assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+                }
+            }
+        }
+
+        let (r, s) = (Scalar::random(&mut rng), Scalar::from(0x100));
+        let signature = Signature::from_scalars(r, s).unwrap();
+        // the final byte differs
+        let others = [signature, Signature::from_scalars(r, s + Scalar::ONE).unwrap(), Signature::from_scalars(r + Scalar::ONE, s).unwrap(), Signature::from_scalars(s, r).unwrap()];
+        for other in &others {
+            assert_eq!(bool::from(signature.ct_eq(other)), signature == *other);
+        }
+        assert!(bool::from(U256::from(7).ct_eq(&U256::from(7))));
+        assert!(!bool::from(U256::from(7).ct_eq(&U256::from(6))));
+        assert!(!bool::from(U256::from(7).ct_eq(&(U256::from(7) + U256::from(1).wrapping_shl(255)))));
+    }
+
+    #[test]
+    fn ct_eq_bytes_matches_eq() {
+        use super::ct_eq_bytes;
+
+        let mut rng = DeterministicRng::new(245);
+        let secret = Scalar::random(&mut rng).to_be_bytes();
+        let mut last = secret;
+        last[31] ^= 1;
+        let mut first = secret;
+        first[0] ^= 0x80;
+        let candidates: [&[u8]; 6] = [&secret, &last, &first, &secret[..31], &[], &[0; 32]];
+        for a in &candidates {
+            for b in &candidates {
+                assert_eq!(bool::from(ct_eq_bytes(a, b)), a == b);
+            }
+        }
+    }
+}
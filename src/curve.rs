@@ -0,0 +1,157 @@
+//! Parameters of short Weierstrass curves `y² = x³ + ax + b` over `F_p`
+//!
+//! The field and point arithmetic is generic over `CurveParams` so that the group laws can be
+//! checked on other curves too, including ones small enough to enumerate. Everything specific to
+//! secp256k1 - scalars, keys, signatures, encodings - uses the `Secp256k1` parameters only.
+
+use core::fmt;
+use core::hash::Hash;
+use crate::params::{N, P};
+use crate::U256;
+
+/// Domain parameters of a curve `y² = x³ + ax + b` over `F_p`.
+///
+/// The arithmetic relies on these properties, which are not checked:
+///
+/// * `P` is a prime with `P ≡ 3 (mod 4)` so that square roots are a single exponentiation
+/// * `A`, `B`, `G_X` and `G_Y` are less than `P`
+/// * `B` is not zero so that `(0, 0)` is not on the curve and can represent the point at infinity
+/// * `(G_X, G_Y)` is on the curve and has order `N`
+///
+/// The implementors are only used as type parameters so they are usually empty enums.
+pub trait CurveParams: Copy + Ord + Hash + fmt::Debug + 'static {
+    /// The field size `p`
+    const P: U256;
+    /// The coefficient `a` of the curve equation
+    const A: U256;
+    /// The coefficient `b` of the curve equation
+    const B: U256;
+    /// The x coordinate of the generator
+    const G_X: U256;
+    /// The y coordinate of the generator
+    const G_Y: U256;
+    /// The order `n` of the generator
+    const N: U256;
+}
+
+/// The secp256k1 curve `y² = x³ + 7`, see the `params` module
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Secp256k1 {}
+
+impl CurveParams for Secp256k1 {
+    const P: U256 = P;
+    const A: U256 = U256([0, 0, 0, 0]);
+    const B: U256 = U256([7, 0, 0, 0]);
+    const G_X: U256 = U256([0x59F2815B_16F81798, 0x029BFCDB_2DCE28D9, 0x55A06295_CE870B07, 0x79BE667E_F9DCBBAC]);
+    const G_Y: U256 = U256([0x9C47D08F_FB10D4B8, 0xFD17B448_A6855419, 0x5DA4FBFC_0E1108A8, 0x483ADA77_26A3C465]);
+    const N: U256 = N;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CurveParams, Secp256k1};
+    use crate::{CurvePoint, FieldElement, U256};
+
+    /// NIST P-256, which has `a = -3` exercising the `ax` terms
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+    enum P256 {}
+
+    impl CurveParams for P256 {
+        const P: U256 = U256([0xFFFFFFFF_FFFFFFFF, 0x00000000_FFFFFFFF, 0x00000000_00000000, 0xFFFFFFFF_00000001]);
+        const A: U256 = U256([0xFFFFFFFF_FFFFFFFC, 0x00000000_FFFFFFFF, 0x00000000_00000000, 0xFFFFFFFF_00000001]);
+        const B: U256 = U256([0x3BCE3C3E_27D2604B, 0x651D06B0_CC53B0F6, 0xB3EBBD55_769886BC, 0x5AC635D8_AA3A93E7]);
+        const G_X: U256 = U256([0xF4A13945_D898C296, 0x77037D81_2DEB33A0, 0xF8BCE6E5_63A440F2, 0x6B17D1F2_E12C4247]);
+        const G_Y: U256 = U256([0xCBB64068_37BF51F5, 0x2BCE3357_6B315ECE, 0x8EE7EB4A_7C0F9E16, 0x4FE342E2_FE1A7F9B]);
+        const N: U256 = U256([0xF3B9CAC2_FC632551, 0xBCE6FAAD_A7179E84, 0xFFFFFFFF_FFFFFFFF, 0xFFFFFFFF_00000000]);
+    }
+
+    /// `y² = x³ + x + 1` over `F_23` - the generator `(3, 10)` spans all 28 points
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+    enum Toy23 {}
+
+    impl CurveParams for Toy23 {
+        const P: U256 = U256([23, 0, 0, 0]);
+        const A: U256 = U256([1, 0, 0, 0]);
+        const B: U256 = U256([1, 0, 0, 0]);
+        const G_X: U256 = U256([3, 0, 0, 0]);
+        const G_Y: U256 = U256([10, 0, 0, 0]);
+        const N: U256 = U256([28, 0, 0, 0]);
+    }
+
+    fn fe<C: CurveParams>(value: u64) -> FieldElement<C> {
+        FieldElement::wrapping_from(U256::from(value))
+    }
+
+    /// Group laws checked on a few multiples of the generator
+    fn check_group_laws<C: CurveParams>() {
+        let g = CurvePoint::<C>::GENERATOR;
+        let infinity = CurvePoint::<C>::AT_INFINITY;
+        assert_eq!(CurvePoint::new(g.x(), g.y()), Some(g));
+        assert!((g * C::N).is_at_infinity());
+        assert_eq!(g * (C::N - U256::one()), -g);
+
+        let points = [infinity, g, g * 2, g * 3, g * 5, g * 11, -g, g * (C::N - U256::from(2))];
+        for &a in &points {
+            assert_eq!(CurvePoint::new(a.x(), a.y()), Some(a));
+            if !a.is_at_infinity() {
+                assert_eq!(CurvePoint::try_from_x(a.x(), a.y().is_odd()), Ok(a));
+            }
+            assert_eq!(a + infinity, a);
+            assert_eq!(infinity + a, a);
+            assert!((a + -a).is_at_infinity());
+            assert_eq!(a + a, a * 2);
+            assert_eq!(a + a + a, a * 3);
+            for &b in &points {
+                assert_eq!(a + b, b + a);
+                for &c in &points {
+                    assert_eq!((a + b) + c, a + (b + c));
+                }
+            }
+        }
+
+        for (x, y) in [(42, 47), (1, 1), (7, 5)].iter() {
+            assert_eq!(g * (x + y), g * *x + g * *y);
+        }
+    }
+
+    #[test]
+    fn group_laws_secp256k1() {
+        check_group_laws::<Secp256k1>();
+    }
+
+    #[test]
+    fn group_laws_p256() {
+        check_group_laws::<P256>();
+    }
+
+    #[test]
+    fn group_laws_toy_curve() {
+        check_group_laws::<Toy23>();
+    }
+
+    #[test]
+    fn p256_vectors() {
+        let g = CurvePoint::<P256>::GENERATOR;
+        let double = CurvePoint::from_sec1_bytes(&crate::test_util::hex("047cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc4766997807775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1")).unwrap();
+        let triple = CurvePoint::from_sec1_bytes(&crate::test_util::hex("045ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c8734640c4998ff7e374b06ce1a64a2ecd82ab036384fb83d9a79b127a27d5032")).unwrap();
+        assert_eq!(g + g, double);
+        assert_eq!(g * 3, triple);
+        assert_eq!((g * 42) * CurvePoint::<P256>::scalar_multiplicative_inverse(U256::from(42)), g);
+    }
+
+    /// The toy curve is small enough to check against all points found by brute force
+    #[test]
+    fn toy_curve_is_cyclic() {
+        let mut points = (0..23)
+            .flat_map(|x| (0..23).filter_map(move |y| CurvePoint::<Toy23>::new(fe(x), fe(y))))
+            .collect::<Vec<_>>();
+        points.sort();
+        // (0, 0) is the point at infinity
+        assert_eq!(points.len(), 28);
+        let mut multiples = (0..28).map(|k| CurvePoint::<Toy23>::GENERATOR * k).collect::<Vec<_>>();
+        multiples.sort();
+        assert_eq!(multiples, points);
+        // doubling with a ≠ 0, the slope is (3·3² + 1) / (2·10) = 28 / 20 ≡ 6
+        assert_eq!(CurvePoint::<Toy23>::GENERATOR * 2, CurvePoint::new(fe(7), fe(12)).unwrap());
+    }
+}
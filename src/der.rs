@@ -0,0 +1,61 @@
+//! Strict DER parsing primitives shared by the signature and key encodings
+
+use crate::DerError;
+
+pub(crate) const INTEGER_TAG: u8 = 0x02;
+pub(crate) const BIT_STRING_TAG: u8 = 0x03;
+pub(crate) const OCTET_STRING_TAG: u8 = 0x04;
+pub(crate) const OBJECT_IDENTIFIER_TAG: u8 = 0x06;
+pub(crate) const SEQUENCE_TAG: u8 = 0x30;
+
+pub(crate) fn split_at(bytes: &[u8], mid: usize) -> Result<(&[u8], &[u8]), DerError> {
+    if bytes.len() < mid {
+        Err(DerError::UnexpectedEnd)
+    } else {
+        Ok(bytes.split_at(mid))
+    }
+}
+
+/// Parses the tag and length returning the content and the remaining bytes
+pub(crate) fn parse_tlv(bytes: &[u8], tag: u8, tag_error: DerError) -> Result<(&[u8], &[u8]), DerError> {
+    let (&actual_tag, bytes) = bytes.split_first().ok_or(DerError::UnexpectedEnd)?;
+    if actual_tag != tag {
+        return Err(tag_error);
+    }
+    let (&first, bytes) = bytes.split_first().ok_or(DerError::UnexpectedEnd)?;
+    if first < 0x80 {
+        return split_at(bytes, first.into());
+    }
+    // long form, 0x80 (indefinite length) is not allowed in DER
+    let (length_bytes, bytes) = split_at(bytes, usize::from(first & 0x7F))?;
+    if length_bytes.is_empty() || length_bytes[0] == 0 {
+        return Err(DerError::NonMinimalLength);
+    }
+    if length_bytes.len() > core::mem::size_of::<usize>() {
+        return Err(DerError::UnexpectedEnd);
+    }
+    let length = length_bytes.iter().fold(0usize, |acc, &b| acc << 8 | usize::from(b));
+    if length < 0x80 {
+        return Err(DerError::NonMinimalLength);
+    }
+    split_at(bytes, length)
+}
+
+/// Parses the tag and length requiring the element to span all of `bytes`
+pub(crate) fn parse_tlv_exact(bytes: &[u8], tag: u8, tag_error: DerError) -> Result<&[u8], DerError> {
+    let (content, rest) = parse_tlv(bytes, tag, tag_error)?;
+    if !rest.is_empty() {
+        return Err(DerError::TrailingData);
+    }
+    Ok(content)
+}
+
+/// Parses the element if it starts with `tag` otherwise returns `bytes` untouched
+pub(crate) fn parse_optional(bytes: &[u8], tag: u8) -> Result<(Option<&[u8]>, &[u8]), DerError> {
+    if bytes.first() == Some(&tag) {
+        let (content, rest) = parse_tlv(bytes, tag, DerError::UnexpectedEnd)?;
+        Ok((Some(content), rest))
+    } else {
+        Ok((None, bytes))
+    }
+}
@@ -0,0 +1,156 @@
+//! Discrete logarithm equality proofs (Chaum–Pedersen)
+//!
+//! Proves that `A = x·G'` and `B = x·H'` for the same `x` and arbitrary bases `G'`, `H'` without
+//! revealing `x`. The prover picks nonce `k`, computes `R₁ = k·G'`, `R₂ = k·H'`, the challenge
+//! `e = Scalar::hash_to_scalar("toy-secp256k1/DLEQ", [G', A, H', B, R₁, R₂])` with the points
+//! compressed (the point at infinity as 33 zero bytes) and `s = k + e·x`.
+//!
+//! The proof is serialized as 64 bytes: `e || s`, both big-endian. The verifier recomputes
+//! `R₁ = s·G' - e·A`, `R₂ = s·H' - e·B` and checks that they hash to `e`.
+
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use crate::nonce::derive_proof_nonce;
+use crate::sigma::SigmaStatement;
+use crate::transcript::Transcript;
+use crate::{array_ref_32, Error, Point, Scalar};
+
+const CHALLENGE_TAG: &str = "toy-secp256k1/DLEQ";
+const NONCE_TAG: &str = "toy-secp256k1/DLEQ/nonce";
+
+/// Proof that two points have the same discrete logarithm with respect to their bases
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DleqProof {
+    e: Scalar,
+    s: Scalar,
+}
+
+impl DleqProof {
+    /// Parses `e || s`
+    ///
+    /// Returns `Error::InvalidSignature` if any scalar is not less than the curve order.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Result<Self, Error> {
+        let e = Scalar::from_be_bytes(array_ref_32(&bytes[..32])).ok_or(Error::InvalidSignature)?;
+        let s = Scalar::from_be_bytes(array_ref_32(&bytes[32..])).ok_or(Error::InvalidSignature)?;
+        Ok(DleqProof { e, s })
+    }
+
+    pub fn serialize(&self) -> [u8; 64] {
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&self.e.to_be_bytes());
+        bytes[32..].copy_from_slice(&self.s.to_be_bytes());
+        bytes
+    }
+}
+
+fn challenge(g: &Point, xg: &Point, h: &Point, xh: &Point, r1: &Point, r2: &Point) -> Scalar {
+    let points = [g, xg, h, xh, r1, r2].map(Point::serialize_compressed);
+    Scalar::hash_to_scalar(CHALLENGE_TAG, &points.each_ref().map(|point| &point[..]))
+}
+
+/// Proves `log_g(x·g) == log_h(x·h)` using the nonce `k`
+fn prove_with_nonce(x: Scalar, g: Point, h: Point, k: Scalar) -> DleqProof {
+    let e = challenge(&g, &(g * x), &h, &(h * x), &(g * k), &(h * k));
+    DleqProof { e, s: k + e * x }
+}
+
+/// Proves that `x·g` and `x·h` have the same discrete logarithm using a random nonce.
+pub fn prove<R: RngCore + CryptoRng>(x: Scalar, g: Point, h: Point, rng: &mut R) -> DleqProof {
+    prove_with_nonce(x, g, h, Scalar::random(rng))
+}
+
+/// Proves the same statement as `prove` but derives the nonce from `x` and the statement using
+/// `derive_proof_nonce`.
+///
+/// The same inputs always produce the same proof.
+pub fn prove_deterministic(x: Scalar, g: Point, h: Point) -> DleqProof {
+    let transcript = [g, g * x, h, h * x]
+        .iter()
+        .flat_map(|point| point.serialize_compressed().to_vec())
+        .collect::<Vec<_>>();
+    prove_with_nonce(x, g, h, derive_proof_nonce(&x, &transcript, NONCE_TAG))
+}
+
+/// Verifies that `log_g(xg) == log_h(xh)`
+pub fn verify(proof: &DleqProof, g: Point, xg: Point, h: Point, xh: Point) -> bool {
+    let r1 = g * proof.s + -(xg * proof.e);
+    let r2 = h * proof.s + -(xh * proof.e);
+    challenge(&g, &xg, &h, &xh, &r1, &r2) == proof.e
+}
+
+/// Equality of the discrete logarithms of `xg` and `xh` with respect to `g` and `h`, for
+/// composing proofs using `sigma`
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Statement {
+    g: Point,
+    xg: Point,
+    h: Point,
+    xh: Point,
+}
+
+impl Statement {
+    pub fn new(g: Point, xg: Point, h: Point, xh: Point) -> Self {
+        Statement { g, xg, h, xh }
+    }
+}
+
+impl SigmaStatement for Statement {
+    fn append_to_transcript(&self, transcript: &mut Transcript) {
+        transcript.append_point("dleq/g", &self.g);
+        transcript.append_point("dleq/xg", &self.xg);
+        transcript.append_point("dleq/h", &self.h);
+        transcript.append_point("dleq/xh", &self.xh);
+    }
+
+    fn commit(&self, nonce: &Scalar) -> Vec<Point> {
+        alloc::vec![self.g * *nonce, self.h * *nonce]
+    }
+
+    fn simulate(&self, challenge: &Scalar, response: &Scalar) -> Vec<Point> {
+        alloc::vec![self.g * *response + -(self.xg * *challenge), self.h * *response + -(self.xh * *challenge)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{prove, prove_deterministic, verify, DleqProof};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, Scalar, G, H};
+
+    #[test]
+    fn valid_proof() {
+        let mut rng = DeterministicRng::new(1);
+        let x = Scalar::random(&mut rng);
+        let h = G * Scalar::random(&mut rng);
+        let proof = prove(x, G, h, &mut rng);
+        assert!(verify(&proof, G, G * x, h, h * x));
+        assert_eq!(DleqProof::from_bytes(&proof.serialize()), Ok(proof));
+        assert_eq!(DleqProof::from_bytes(&[0xFF; 64]), Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn swapped_points_fail() {
+        let mut rng = DeterministicRng::new(2);
+        let x = Scalar::random(&mut rng);
+        let (xg, xh) = (G * x, H * x);
+        let proof = prove(x, G, H, &mut rng);
+        assert!(verify(&proof, G, xg, H, xh));
+        assert!(!verify(&proof, H, xg, G, xh));
+        assert!(!verify(&proof, G, xh, H, xg));
+        assert!(!verify(&proof, xg, G, H, xh));
+        assert!(!verify(&proof, G, xg, xh, H));
+        assert!(!verify(&proof, H, xh, G, xg));
+        // different discrete logarithms
+        assert!(!verify(&proof, G, xg, H, xh + H));
+    }
+
+    #[test]
+    fn deterministic() {
+        let x = Scalar::from(42);
+        let proof = prove_deterministic(x, G, H);
+        assert_eq!(prove_deterministic(x, G, H), proof);
+        assert_ne!(prove_deterministic(x + Scalar::ONE, G, H), proof);
+        assert!(verify(&proof, G, G * x, H, H * x));
+        assert_ne!(prove(x, G, H, &mut DeterministicRng::new(3)), proof);
+    }
+}
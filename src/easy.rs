@@ -0,0 +1,171 @@
+//! One-call signing, verification and key exchange on plain byte arrays
+//!
+//! For applications that just need signatures and shared secrets without choosing nonces,
+//! encodings or hash functions. Every function is a thin wrapper, the exact constructions are
+//! listed here so that the data stays usable after switching to the underlying APIs:
+//!
+//! * Secret keys are 32 big-endian bytes, public keys are 32-byte x-only (BIP340) keys.
+//! * `sign` creates a BIP340 signature of `SHA256(message)` with `aux_rand` drawn from the RNG,
+//!   which is `schnorr::sign_hedged(&Message::from_hashed_data(message), ..)`.
+//! * `verify` is `schnorr::verify(&Message::from_hashed_data(message), ..)`.
+//! * `shared_secret` is `SHA256(x(a·B))` where `B` is the peer key with even y. Only the x
+//!   coordinate is hashed so both parties arrive at the same secret even though x-only keys
+//!   don't encode the parity - unlike `ecdh::shared_secret` which hashes the compressed point.
+//!
+//! Malformed inputs are reported as `Error::InvalidSecretKey` or `Error::InvalidPublicKey`,
+//! nothing panics.
+
+use rand_core::{CryptoRng, RngCore};
+use crate::hashes::sha256;
+use crate::{ecdh, schnorr, Error, Keypair, Message, PublicKey, Scalar, SecretKey, XOnlyPoint};
+
+fn secret_key(bytes: &[u8; 32]) -> Result<SecretKey, Error> {
+    SecretKey::from_bytes(bytes).map_err(|_| Error::InvalidSecretKey)
+}
+
+fn public_key(bytes: &[u8; 32]) -> Result<XOnlyPoint, Error> {
+    XOnlyPoint::from_bytes(bytes).map_err(|_| Error::InvalidPublicKey)
+}
+
+/// Generates a random secret key returning it together with its public key
+pub fn generate_keypair<R: RngCore + CryptoRng>(rng: &mut R) -> ([u8; 32], [u8; 32]) {
+    let secret_key = SecretKey::from_scalar(Scalar::random(rng)).expect("random scalar is never zero");
+    (secret_key.to_bytes(), secret_key.public_key().x_only_public_key().0.serialize())
+}
+
+/// Returns the public key of the secret key
+pub fn public_key_create(secret_key: &[u8; 32]) -> Result<[u8; 32], Error> {
+    Ok(self::secret_key(secret_key)?.public_key().x_only_public_key().0.serialize())
+}
+
+/// Signs the message of any length.
+///
+/// The randomness from `rng` protects against side-channel and fault attacks, a broken RNG
+/// doesn't make the signature insecure.
+pub fn sign<R: RngCore + CryptoRng>(secret_key: &[u8; 32], message: &[u8], rng: &mut R) -> Result<[u8; 64], Error> {
+    let keypair = Keypair::from_secret_key(&self::secret_key(secret_key)?);
+    Ok(schnorr::sign_hedged(&Message::from_hashed_data(message), &keypair, rng))
+}
+
+/// Checks that `signature` of `message` was made by the owner of `public_key`.
+///
+/// Returns `false` for invalid public keys and signatures as well.
+pub fn verify(public_key: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> bool {
+    match self::public_key(public_key) {
+        Ok(public_key) => schnorr::verify(&Message::from_hashed_data(message), signature, &public_key).is_ok(),
+        Err(_) => false,
+    }
+}
+
+/// Computes the secret shared with the owner of `peer_public_key`.
+///
+/// The peer computes the same value from its secret key and our public key.
+pub fn shared_secret(secret_key: &[u8; 32], peer_public_key: &[u8; 32]) -> Result<[u8; 32], Error> {
+    let secret_key = self::secret_key(secret_key)?;
+    let peer_public_key = PublicKey::from_point(public_key(peer_public_key)?.to_point()).ok_or(Error::InvalidPublicKey)?;
+    Ok(sha256(&ecdh::shared_x(&secret_key, &peer_public_key)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_keypair, public_key_create, shared_secret, sign, verify};
+    use crate::test_util::{hex32, ZeroRng};
+    use crate::test_utils::DeterministicRng;
+    use crate::{ecdh, schnorr, Error, Keypair, Message, PublicKey, SecretKey, XOnlyPoint};
+
+    /// Order of the curve, invalid as a secret key
+    fn n() -> [u8; 32] {
+        hex32("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141")
+    }
+
+    /// Prime of the field, invalid as a public key
+    fn p() -> [u8; 32] {
+        hex32("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f")
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut rng = DeterministicRng::new(246);
+        let (secret_key, public_key) = generate_keypair(&mut rng);
+        assert_eq!(public_key_create(&secret_key), Ok(public_key));
+        let signature = sign(&secret_key, b"hello", &mut rng).unwrap();
+        assert!(verify(&public_key, b"hello", &signature));
+        assert!(!verify(&public_key, b"hello!", &signature));
+        assert!(!verify(&generate_keypair(&mut rng).1, b"hello", &signature));
+        // hedged
+        assert_ne!(sign(&secret_key, b"hello", &mut rng), Ok(signature));
+
+        let long = [0x42; 1000];
+        let signature = sign(&secret_key, &long, &mut rng).unwrap();
+        assert!(verify(&public_key, &long, &signature));
+        let signature = sign(&secret_key, b"", &mut rng).unwrap();
+        assert!(verify(&public_key, b"", &signature));
+    }
+
+    #[test]
+    fn matches_underlying_apis() {
+        let mut rng = DeterministicRng::new(246);
+        for _ in 0..4 {
+            let (secret_key, public_key) = generate_keypair(&mut rng);
+            let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&secret_key).unwrap());
+            let x_only = XOnlyPoint::from_bytes(&public_key).unwrap();
+            assert_eq!(keypair.x_only_public_key().0, x_only);
+
+            let msg = Message::from_hashed_data(b"message");
+            let signature = sign(&secret_key, b"message", &mut ZeroRng).unwrap();
+            assert_eq!(signature, schnorr::sign(&msg, &keypair, &[0; 32]));
+            assert_eq!(schnorr::verify(&msg, &signature, &x_only), Ok(()));
+            let other = schnorr::sign(&msg, &keypair, &[1; 32]);
+            assert!(verify(&public_key, b"message", &other));
+
+            let (peer_secret, peer_public) = generate_keypair(&mut rng);
+            let shared = shared_secret(&secret_key, &peer_public).unwrap();
+            assert_eq!(shared_secret(&peer_secret, &public_key), Ok(shared));
+            let peer = PublicKey::from_point(XOnlyPoint::from_bytes(&peer_public).unwrap().to_point()).unwrap();
+            assert_eq!(shared, crate::hashes::sha256(&ecdh::shared_x(keypair.secret_key(), &peer)));
+        }
+    }
+
+    /// About half of the keys have odd y, the shared secret must not depend on it
+    #[test]
+    fn shared_secret_ignores_parity() {
+        let mut rng = DeterministicRng::new(247);
+        let mut odd = 0;
+        for _ in 0..8 {
+            let (alice, alice_public) = generate_keypair(&mut rng);
+            let (bob, bob_public) = generate_keypair(&mut rng);
+            assert_eq!(shared_secret(&alice, &bob_public), shared_secret(&bob, &alice_public));
+            odd += usize::from(SecretKey::from_bytes(&alice).unwrap().public_key().serialize()[0] == 0x03);
+        }
+        assert!(odd > 0 && odd < 8, "{}", odd);
+    }
+
+    #[test]
+    fn malformed_inputs() {
+        let mut rng = DeterministicRng::new(246);
+        let (secret_key, public_key) = generate_keypair(&mut rng);
+        let signature = sign(&secret_key, b"message", &mut rng).unwrap();
+        // x = 5 is not on the curve
+        let mut off_curve = [0; 32];
+        off_curve[31] = 5;
+
+        for invalid in &[[0; 32], n(), [0xFF; 32]] {
+            assert_eq!(public_key_create(invalid), Err(Error::InvalidSecretKey));
+            assert_eq!(sign(invalid, b"message", &mut rng), Err(Error::InvalidSecretKey));
+            assert_eq!(shared_secret(invalid, &public_key), Err(Error::InvalidSecretKey));
+        }
+        for invalid in &[off_curve, p(), [0xFF; 32]] {
+            assert!(!verify(invalid, b"message", &signature));
+            assert_eq!(shared_secret(&secret_key, invalid), Err(Error::InvalidPublicKey));
+        }
+        // r not less than p and s not less than n
+        let mut bad_r = signature;
+        bad_r[..32].copy_from_slice(&p());
+        assert!(!verify(&public_key, b"message", &bad_r));
+        let mut bad_s = signature;
+        bad_s[32..].copy_from_slice(&n());
+        assert!(!verify(&public_key, b"message", &bad_s));
+        assert!(!verify(&public_key, b"message", &[0; 64]));
+        assert!(!verify(&public_key, b"message", &[0xFF; 64]));
+    }
+}
@@ -0,0 +1,115 @@
+//! Elliptic curve Diffie-Hellman key exchange
+//!
+//! Both parties multiply the other party's public key by their own secret key, arriving at the
+//! same point `a·B = b·A = ab·G`. The raw point should be post-processed by a key derivation
+//! function before using it as a key - see `shared_secret_with` for using a custom one.
+
+use crate::{NonIdentityPoint, NonZeroScalar, Point, PublicKey, SecretKey};
+
+/// Computes the shared secret the same way as the default hash function of libsecp256k1.
+///
+/// The result is `SHA256(prefix || x)` where `prefix` is `0x02` or `0x03` depending on the
+/// parity of y, in other words SHA256 of the compressed shared point.
+#[cfg(feature = "hashes")]
+pub fn shared_secret(secret_key: &SecretKey, public_key: &PublicKey) -> [u8; 32] {
+    shared_secret_with(secret_key, public_key, sha256_kdf)
+}
+
+/// Computes the shared point `sk·P` and passes it to `kdf`, returning its result.
+///
+/// This allows protocols to post-process the point in their own way without the point leaking
+/// out of the closure.
+pub fn shared_secret_with<T, F: FnOnce(&Point) -> T>(secret_key: &SecretKey, public_key: &PublicKey, kdf: F) -> T {
+    kdf(&shared_point(secret_key, public_key))
+}
+
+/// Computes the shared point `sk·P`.
+///
+/// The result is never the point at infinity since the secret key is non-zero, the public key
+/// is not at infinity and the curve has prime order.
+pub fn shared_point(secret_key: &SecretKey, public_key: &PublicKey) -> NonIdentityPoint {
+    NonIdentityPoint::from(public_key) * NonZeroScalar::from(secret_key)
+}
+
+/// Computes the big-endian x coordinate of the shared point `sk·P`
+pub fn shared_x(secret_key: &SecretKey, public_key: &PublicKey) -> [u8; 32] {
+    shared_secret_with(secret_key, public_key, raw_x_kdf)
+}
+
+/// KDF returning the big-endian x coordinate as is, for use with `shared_secret_with`
+pub fn raw_x_kdf(point: &Point) -> [u8; 32] {
+    point.x().to_be_bytes()
+}
+
+/// KDF compatible with libsecp256k1 - SHA256 of the compressed point, for use with
+/// `shared_secret_with`
+#[cfg(feature = "hashes")]
+pub fn sha256_kdf(point: &Point) -> [u8; 32] {
+    crate::hashes::sha256(&point.serialize_compressed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{raw_x_kdf, shared_point, shared_secret_with, shared_x};
+    #[cfg(feature = "hashes")]
+    use super::{sha256_kdf, shared_secret};
+    use crate::test_util::{hex, hex32};
+    use crate::test_utils::DeterministicRng;
+    use crate::{PublicKey, Scalar, SecretKey};
+
+    #[test]
+    fn commutative() {
+        let mut rng = DeterministicRng::new(1);
+        // kept small because the arithmetic is slow
+        for _ in 0..3 {
+            let a = SecretKey::from_scalar(Scalar::random(&mut rng)).unwrap();
+            let b = SecretKey::from_scalar(Scalar::random(&mut rng)).unwrap();
+            assert_eq!(shared_x(&a, &b.public_key()), shared_x(&b, &a.public_key()));
+            assert_eq!(shared_point(&a, &b.public_key()), shared_point(&b, &a.public_key()));
+            #[cfg(feature = "hashes")]
+            assert_eq!(shared_secret(&a, &b.public_key()), shared_secret(&b, &a.public_key()));
+        }
+    }
+
+    #[test]
+    fn secp256k1_vector() {
+        // generated by `secp256k1::ecdh::shared_secret_point`
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let public_key = PublicKey::from_sec1_bytes(&hex("02531fe6068134503d2723133227c867ac8fa6c83c537e9a44c3c5bdbdcb1fe337")).unwrap();
+        let expected = hex32("122b36e8269349669a188cdbf9f90d34e149c1fcfc9055f7fc2ba4c4dcbbd114");
+        assert_eq!(shared_x(&secret_key, &public_key), expected);
+        let point = shared_point(&secret_key, &public_key);
+        assert_eq!(point.y().to_be_bytes(), hex32("f78965e5f8811b120d30305d24e380a539e597b202cbacf56bda5d0be86a9071"));
+    }
+
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn libsecp256k1_hashed_vector() {
+        // generated by `secp256k1::ecdh::SharedSecret::new`, the shared point has odd y
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let public_key = SecretKey::from_bytes(&[0x03; 32]).unwrap().public_key();
+        let expected = hex32("a775c084e7916ed7d3626f30db19527661df90c61ed478b585c26dfb3684a6f6");
+        assert_eq!(shared_secret(&secret_key, &public_key), expected);
+        assert_eq!(shared_secret(&SecretKey::from_bytes(&[0x03; 32]).unwrap(), &secret_key.public_key()), expected);
+    }
+
+    #[test]
+    fn custom_kdf() {
+        let a = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let b = SecretKey::from_bytes(&[0x03; 32]).unwrap();
+        let point = shared_point(&a, &b.public_key());
+        let parity = shared_secret_with(&a, &b.public_key(), |point| point.y().is_odd());
+        assert_eq!(parity, point.y().is_odd());
+        let uncompressed = shared_secret_with(&b, &a.public_key(), |point| point.serialize_uncompressed().to_vec());
+        assert_eq!(uncompressed, point.serialize_uncompressed().to_vec());
+    }
+
+    #[test]
+    fn provided_kdfs() {
+        let a = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let public_key = SecretKey::from_bytes(&[0x03; 32]).unwrap().public_key();
+        assert_eq!(shared_secret_with(&a, &public_key, raw_x_kdf), shared_x(&a, &public_key));
+        #[cfg(feature = "hashes")]
+        assert_eq!(shared_secret_with(&a, &public_key, sha256_kdf), shared_secret(&a, &public_key));
+    }
+}
@@ -0,0 +1,774 @@
+//! ECDSA signatures
+//!
+//! `sign_hedged` is the recommended way of signing, use `sign` only if the signatures need to be
+//! reproducible.
+
+use core::fmt;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use crate::{array_ref_32, write_hex, Error, Message, Point, PublicKey, Scalar, SecretKey, G, U256};
+#[cfg(feature = "hashes")]
+use rand_core::{CryptoRng, RngCore};
+#[cfg(feature = "hashes")]
+use crate::{Keypair, SignOptions};
+use crate::nonce::NonceGenerator;
+#[cfg(feature = "dangerous-explicit-nonce")]
+use crate::NonZeroScalar;
+use crate::zeroize::Zeroizing;
+#[cfg(feature = "hashes")]
+use crate::nonce::Rfc6979;
+#[cfg(feature = "hashes")]
+use crate::rfc6979::Drbg;
+
+#[cfg(feature = "hashes")]
+mod adaptor;
+mod batch;
+mod der;
+#[cfg(feature = "digest")]
+mod digest;
+mod recovery;
+
+#[cfg(feature = "hashes")]
+pub use adaptor::{adapt, adaptor_sign, adaptor_verify, extract_secret, AdaptorSignature};
+#[cfg(feature = "alloc")]
+pub use batch::{find_invalid, verify_batch};
+pub use batch::verify_batch_with_scratch;
+pub use der::MAX_LEN as MAX_DER_LEN;
+#[cfg(feature = "digest")]
+pub use self::digest::{sign_digest, verify_digest, DigestSigner, DigestVerifier};
+pub use recovery::{sign_recoverable_with, RecoverableSignature, RecoveryId};
+#[cfg(feature = "hashes")]
+pub use recovery::sign_recoverable;
+
+/// ECDSA signature - pair of non-zero scalars
+///
+/// The scalars are always in range so code using the signature doesn't need to check for zero
+/// or values not less than the curve order.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Signature {
+    r: Scalar,
+    s: Scalar,
+}
+
+impl Signature {
+    /// Returns `None` if any of the scalars is zero
+    pub fn from_scalars(r: Scalar, s: Scalar) -> Option<Self> {
+        if r.is_zero() || s.is_zero() {
+            None
+        } else {
+            Some(Signature { r, s })
+        }
+    }
+
+    pub fn r(&self) -> Scalar {
+        self.r
+    }
+
+    pub fn s(&self) -> Scalar {
+        self.s
+    }
+
+    /// Checks whether `s` is not greater than `n / 2`.
+    ///
+    /// Both `s` and `-s` produce a valid signature, Bitcoin standardness rules require the low one.
+    pub fn is_low_s(&self) -> bool {
+        // n is odd so s can not be equal to -s
+        self.s < -self.s
+    }
+
+    /// Checks whether `r < 2^255` so that its DER encoding doesn't need padding byte
+    pub fn has_low_r(&self) -> bool {
+        self.r.to_be_bytes()[0] < 0x80
+    }
+
+    /// Replaces `s` with `-s` if it's high returning `true` if the signature was changed
+    pub fn normalize_s(&mut self) -> bool {
+        if self.is_low_s() {
+            false
+        } else {
+            self.s = -self.s;
+            true
+        }
+    }
+
+    /// Parses 64-byte big-endian `r || s` rejecting zero or out-of-range values.
+    ///
+    /// Returns `Error::NonCanonicalScalar` if either value is not less than the curve order and
+    /// `Error::ZeroScalar` if either is zero.
+    pub fn from_compact(bytes: &[u8; 64]) -> Result<Self, Error> {
+        let r = Scalar::try_from_u256(U256::from_be_bytes(array_ref_32(&bytes[..32])))?;
+        let s = Scalar::try_from_u256(U256::from_be_bytes(array_ref_32(&bytes[32..])))?;
+        Signature::from_scalars(r, s).ok_or(Error::ZeroScalar)
+    }
+
+    /// Parses strict DER encoding as required by BIP66.
+    ///
+    /// Rejects incorrect tags, non-minimal lengths and integers, negative or out-of-range
+    /// integers and trailing data.
+    pub fn from_der(bytes: &[u8]) -> Result<Self, Error> {
+        der::decode_strict(bytes)
+    }
+
+    /// Parses sloppy DER encodings produced by old software, as accepted by Bitcoin before BIP66.
+    ///
+    /// Compatible with libsecp256k1 `ecdsa_signature_parse_der_lax` except that signatures with
+    /// out-of-range integers are rejected rather than parsed as invalid. Use only for legacy data.
+    pub fn from_der_lax(bytes: &[u8]) -> Result<Self, Error> {
+        der::decode_lax(bytes)
+    }
+
+    /// Serializes the signature using DER
+    #[cfg(feature = "alloc")]
+    pub fn to_der(&self) -> Vec<u8> {
+        let (bytes, len) = der::encode(self);
+        bytes[..len].to_vec()
+    }
+
+    /// Serializes the signature using DER without allocating.
+    ///
+    /// The encoding is stored in the first `len` bytes of the returned array, the rest is zeroed.
+    pub fn to_der_array(&self) -> ([u8; MAX_DER_LEN], usize) {
+        der::encode(self)
+    }
+
+    /// Serializes the signature as 64-byte big-endian `r || s`
+    pub fn serialize_compact(&self) -> [u8; 64] {
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&self.r.to_be_bytes());
+        bytes[32..].copy_from_slice(&self.s.to_be_bytes());
+        bytes
+    }
+}
+
+/// Displays the compact serialization in hex
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, &self.serialize_compact())
+    }
+}
+
+impl fmt::Debug for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Signature(")?;
+        write_hex(f, &self.serialize_compact())?;
+        f.write_str(")")
+    }
+}
+
+/// Converts the message hash to scalar - the hash has the same length as the order so this is
+/// just a reduction.
+pub(crate) fn message_scalar(msg: &Message) -> Scalar {
+    Scalar::from_be_bytes_reduce(msg.as_bytes())
+}
+
+/// Signs the message using deterministic nonce (RFC 6979).
+///
+/// Prefer `sign_hedged` unless reproducible signatures are required - purely deterministic
+/// nonces make fault attacks easier.
+#[cfg(feature = "hashes")]
+pub fn sign(msg: &Message, secret_key: &SecretKey) -> Signature {
+    sign_with(msg, secret_key, &mut Rfc6979::new())
+}
+
+/// Signs the message using RFC 6979 nonce with 32 random bytes as extra data.
+///
+/// This is the recommended way of signing. The nonce stays secure if the RNG is broken since
+/// it's still derived from the key and message, while the randomness makes each signature
+/// different which thwarts fault attacks relying on repeated signing. See
+/// `sign_hedged_with_randomness` for the details.
+#[cfg(feature = "hashes")]
+pub fn sign_hedged<R: RngCore + CryptoRng>(msg: &Message, secret_key: &SecretKey, rng: &mut R) -> Signature {
+    let mut randomness = Zeroizing::new([0; 32]);
+    rng.fill_bytes(&mut *randomness);
+    sign_hedged_with_randomness(msg, secret_key, &randomness)
+}
+
+/// Signs the message using RFC 6979 nonce with `randomness` as extra data.
+///
+/// All-zero randomness is treated as absent so the result equals the one of `sign`, otherwise
+/// the signature is compatible with libsecp256k1 given the same `noncedata`.
+#[cfg(feature = "hashes")]
+pub fn sign_hedged_with_randomness(msg: &Message, secret_key: &SecretKey, randomness: &[u8; 32]) -> Signature {
+    if *randomness == [0; 32] {
+        sign(msg, secret_key)
+    } else {
+        sign_with(msg, secret_key, &mut Rfc6979::with_extra_data(*randomness))
+    }
+}
+
+/// Signs the message using deterministic nonce (RFC 6979) honoring `options`.
+///
+/// Without any options enabled the signature is the same as the one returned by `sign`. Returns
+/// `Error::SelfVerificationFailed` if `verify_after_sign` is enabled and the signature doesn't
+/// verify against the cached public key of the keypair.
+#[cfg(feature = "hashes")]
+pub fn sign_with_options(msg: &Message, keypair: &Keypair, options: &SignOptions) -> Result<Signature, Error> {
+    sign_checked(msg, keypair, options, |_| ())
+}
+
+/// Implements `sign_with_options`, `fault` lets the tests corrupt the signature before it's
+/// checked
+#[cfg(feature = "hashes")]
+fn sign_checked(msg: &Message, keypair: &Keypair, options: &SignOptions, fault: impl FnOnce(&mut Signature)) -> Result<Signature, Error> {
+    let mut signature = sign(msg, keypair.secret_key());
+    fault(&mut signature);
+    if options.verify_after_sign && verify(msg, &signature, keypair.public_key()).is_err() {
+        return Err(Error::SelfVerificationFailed);
+    }
+    Ok(signature)
+}
+
+/// Signs the message grinding the nonce until `r` is low.
+///
+/// This saves a byte in DER encoding so the signature has at most 71 bytes. Compatible with
+/// Bitcoin Core - attempt `i > 0` uses RFC 6979 with extra data being `i` as 32-bit little endian
+/// padded with zeros.
+#[cfg(feature = "hashes")]
+pub fn sign_low_r(msg: &Message, secret_key: &SecretKey) -> Signature {
+    let mut signature = sign(msg, secret_key);
+    let mut counter = 0u32;
+    while !signature.has_low_r() {
+        counter += 1;
+        let mut extra_data = [0; 32];
+        extra_data[..4].copy_from_slice(&counter.to_le_bytes());
+        signature = sign_with(msg, secret_key, &mut Rfc6979::with_extra_data(extra_data));
+    }
+    signature
+}
+
+/// Computes the nonce that `sign` would use for given message and key.
+///
+/// `extra_data` is mixed into the nonce derivation, this is compatible with libsecp256k1.
+/// Returns the first valid candidate, if signing with it fails the following candidates are used.
+#[cfg(feature = "hashes")]
+pub fn rfc6979_nonce(msg: &Message, secret_key: &SecretKey, extra_data: Option<&[u8; 32]>) -> Scalar {
+    Drbg::new(msg.as_bytes(), secret_key, extra_data).next_nonce()
+}
+
+/// Signs the message using nonces produced by `nonce_generator`.
+///
+/// The produced signature is always low-S.
+pub fn sign_with(msg: &Message, secret_key: &SecretKey, nonce_generator: &mut impl NonceGenerator) -> Signature {
+    sign_internal(msg, secret_key, nonce_generator).0
+}
+
+/// Signs the message returning also the point `R` matching the normalized signature
+fn sign_internal(msg: &Message, secret_key: &SecretKey, nonce_generator: &mut impl NonceGenerator) -> (Signature, Point) {
+    let z = message_scalar(msg);
+    let d = Zeroizing::new(secret_key.to_scalar());
+    let mut attempt = 0;
+    loop {
+        let k = Zeroizing::new(nonce_generator.nonce(msg.as_bytes(), secret_key, attempt));
+        attempt += 1;
+        if k.is_zero() {
+            continue;
+        }
+        let big_r = Point::mul_gen(&k);
+        let r = big_r.x().to_scalar_reduce();
+        let k_inverse = Zeroizing::new(k.multiplicative_inverse());
+        let s = (z + r * *d) * *k_inverse;
+        if let Some(mut signature) = Signature::from_scalars(r, s) {
+            // -s corresponds to nonce -k
+            let big_r = if signature.normalize_s() { -big_r } else { big_r };
+            break (signature, big_r);
+        }
+    }
+}
+
+/// Signs the message using exactly the nonce `k`.
+///
+/// **DANGER: this is unsafe to use with real keys!** Anyone who learns `k` or sees two signatures
+/// with the same `k` (even for the same message if the keys differ, or for different messages
+/// with the same key) can compute the secret key. Even slightly biased nonces leak the key given
+/// enough signatures. This exists only for demonstrating such attacks, use `sign` otherwise.
+///
+/// The signature is not normalized to low-S since that would effectively use `-k`. Returns
+/// `Error::InvalidNonce` if `k` results in zero `r` or `s`.
+#[cfg(feature = "dangerous-explicit-nonce")]
+pub fn sign_with_explicit_nonce(msg: &Message, secret_key: &SecretKey, k: NonZeroScalar) -> Result<Signature, Error> {
+    let r = (G * *k).x().to_scalar_reduce();
+    let d = Zeroizing::new(secret_key.to_scalar());
+    let s = (message_scalar(msg) + r * *d) * *k.invert();
+    Signature::from_scalars(r, s).ok_or(Error::InvalidNonce)
+}
+
+/// Verifies the signature of the message.
+///
+/// Returns `Error::InvalidSignature` if the signature is not valid for the key and message. Both
+/// low-S and high-S signatures are accepted, see `verify_strict`.
+pub fn verify(msg: &Message, signature: &Signature, public_key: &PublicKey) -> Result<(), Error> {
+    let z = message_scalar(msg);
+    let s_inv = signature.s.multiplicative_inverse();
+    let u1 = z * s_inv;
+    let u2 = signature.r * s_inv;
+    let big_r = G * u1 + public_key.to_point() * u2;
+    if big_r.is_at_infinity() {
+        return Err(Error::InvalidSignature);
+    }
+    if big_r.x().to_scalar_reduce() == signature.r {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
+
+/// Verifies the signature rejecting high-S with `Error::HighS`
+pub fn verify_strict(msg: &Message, signature: &Signature, public_key: &PublicKey) -> Result<(), Error> {
+    if !signature.is_low_s() {
+        return Err(Error::HighS);
+    }
+    verify(msg, signature, public_key)
+}
+
+/// Signs SHA256 of the message using RFC 6979 nonce
+#[cfg(feature = "hashes")]
+pub fn sign_message_sha256(msg: &[u8], secret_key: &SecretKey) -> Signature {
+    sign(&Message::from_hashed_data(msg), secret_key)
+}
+
+/// Verifies signature of SHA256 of the message
+#[cfg(feature = "hashes")]
+pub fn verify_message_sha256(msg: &[u8], signature: &Signature, public_key: &PublicKey) -> Result<(), Error> {
+    verify(&Message::from_hashed_data(msg), signature, public_key)
+}
+
+/// Signs double SHA256 of the message as used by Bitcoin, using RFC 6979 nonce
+#[cfg(feature = "hashes")]
+pub fn sign_message_sha256d(msg: &[u8], secret_key: &SecretKey) -> Signature {
+    sign(&Message::from_double_sha256(msg), secret_key)
+}
+
+/// Verifies signature of double SHA256 of the message
+#[cfg(feature = "hashes")]
+pub fn verify_message_sha256d(msg: &[u8], signature: &Signature, public_key: &PublicKey) -> Result<(), Error> {
+    verify(&Message::from_double_sha256(msg), signature, public_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sign_with, verify, verify_strict, Signature};
+    use crate::nonce::{NonceGenerator, RngNonce};
+    use crate::test_utils::DeterministicRng;
+    #[cfg(feature = "hashes")]
+    use crate::nonce::Rfc6979;
+    #[cfg(feature = "hashes")]
+    use super::{rfc6979_nonce, sign, sign_low_r, sign_message_sha256, sign_message_sha256d, verify_message_sha256, verify_message_sha256d};
+    use crate::{Error, Message, PublicKey, Scalar, SecretKey};
+    use crate::test_util::{hex, hex32};
+
+    /// Returns the nonce at index `attempt`
+    struct Fixed<'a>(&'a [Scalar]);
+
+    impl NonceGenerator for Fixed<'_> {
+        fn nonce(&mut self, _msg32: &[u8; 32], _secret_key: &SecretKey, attempt: u32) -> Scalar {
+            self.0[attempt as usize]
+        }
+    }
+
+    fn signature(r: &str, s: &str) -> Signature {
+        let r = Scalar::from_be_bytes(&hex32(r)).unwrap();
+        let s = Scalar::from_be_bytes(&hex32(s)).unwrap();
+        Signature::from_scalars(r, s).unwrap()
+    }
+
+    /// Computed by an independent implementation and checked with OpenSSL
+    #[test]
+    fn fixed_nonce() {
+        let secret_key = SecretKey::from_bytes(&hex32("1e99423a4ed27608a15a2616a2b0e9e52ced330ac530edcc32c8ffc6a526aedd")).unwrap();
+        // SHA256("toy-secp256k1")
+        let msg = Message::from_digest(hex32("9d17a4ec82c8d8161cbd0df88b71fef9a65b6bc6ec064d2db57af463c96f8a1f"));
+        let k = Scalar::from_be_bytes(&hex32("2ba9ef2d1ce1a5c0fcb9e58ee19f0c47c3b1c0b1ab44b0c12a564d6b1f7ec2a4")).unwrap();
+
+        let signature = sign_with(&msg, &secret_key, &mut Fixed(&[k]));
+        assert_eq!(signature.r().to_be_bytes(), hex32("a29a620e10006b3ad1060ac518bed36b89332f289acb1b8add594f68d52afae5"));
+        assert_eq!(signature.s().to_be_bytes(), hex32("5dd1cd900639856fa2fe3b5ad761956faf04de256ddee87752d95284782c9ac7"));
+    }
+
+    #[test]
+    fn zero_nonce_is_skipped() {
+        let secret_key = SecretKey::from_bytes(&hex32("1e99423a4ed27608a15a2616a2b0e9e52ced330ac530edcc32c8ffc6a526aedd")).unwrap();
+        let signature = sign_with(&Message::from_digest([0; 32]), &secret_key, &mut Fixed(&[Scalar::ZERO, Scalar::from(42)]));
+        assert_eq!(signature, sign_with(&Message::from_digest([0; 32]), &secret_key, &mut Fixed(&[Scalar::from(42)])));
+    }
+
+    #[test]
+    fn sign_verify() {
+        let secret_key = SecretKey::from_bytes(&hex32("1e99423a4ed27608a15a2616a2b0e9e52ced330ac530edcc32c8ffc6a526aedd")).unwrap();
+        let public_key = secret_key.public_key();
+        let msg = Message::from_digest([42; 32]);
+        let k = Scalar::from(0xDEADBEEF);
+        let signature = sign_with(&msg, &secret_key, &mut Fixed(&[k]));
+        assert_eq!(verify(&msg, &signature, &public_key), Ok(()));
+
+        for &bit in &[0, 1, 128, 255] {
+            let mut r = signature.r().to_be_bytes();
+            r[31 - bit / 8] ^= 1 << (bit % 8);
+            if let Some(r) = Scalar::from_be_bytes(&r) {
+                let modified = Signature::from_scalars(r, signature.s()).unwrap();
+                assert_eq!(verify(&msg, &modified, &public_key), Err(Error::InvalidSignature));
+            }
+
+            let mut s = signature.s().to_be_bytes();
+            s[31 - bit / 8] ^= 1 << (bit % 8);
+            if let Some(s) = Scalar::from_be_bytes(&s) {
+                let modified = Signature::from_scalars(signature.r(), s).unwrap();
+                assert_eq!(verify(&msg, &modified, &public_key), Err(Error::InvalidSignature));
+            }
+
+            let mut modified_msg = msg.to_bytes();
+            modified_msg[31 - bit / 8] ^= 1 << (bit % 8);
+            assert_eq!(verify(&Message::from_digest(modified_msg), &signature, &public_key), Err(Error::InvalidSignature));
+
+            let mut key = public_key.serialize();
+            key[32 - bit / 8] ^= 1 << (bit % 8);
+            if let Ok(key) = PublicKey::from_sec1_bytes(&key) {
+                assert_eq!(verify(&msg, &signature, &key), Err(Error::InvalidSignature));
+            }
+        }
+    }
+
+    /// Produced by OpenSSL, some of them have high S
+    #[test]
+    fn external_signatures() {
+        let public_key = PublicKey::from_sec1_bytes(&hex("03b9ebdc566c4002d0d9228581eb1f77b33c1b74d24714dadeed58291d7ef15697")).unwrap();
+        let vectors = [
+            (
+                "56ae0588cd161158395388d03f774650bbb78c7da47e0f6a71a87b4607bec222",
+                "98fdfc1157d68cca0d888df3504d3efc8fbf37dac5c35db117caaefdb2481c4d",
+                "b73c47b73604b3d4c3078fc634d205855b14b365cd81b61a8816ba6cfc5ba254",
+            ),
+            (
+                "c9ec9ef0d034440119ae8b4636be90b7c1fcca2e5a602ac0967e5d6c6f01c762",
+                "8eda1c025879287a156b9d74d040650b5dde7ab092030a749997e8d8e11a3f51",
+                "15136d91089defc764dbfc9cca81923174ace4e128ae045ce6b0275fe34e0bd7",
+            ),
+            (
+                "28ec3c2583722559ea06d26aa5e435ecb417034782cabe4168bc4526a60c91c4",
+                "57cae5a374a1bd31781a2d3b610fbdbc41da8a41da81ec3ee8042cefb9a3a620",
+                "e413f01ff8d13abffdafe485bc98fb024e1bd9303a41945496128634966a2888",
+            ),
+        ];
+        for (msg, r, s) in &vectors {
+            assert_eq!(verify(&Message::from_digest(hex32(msg)), &signature(r, s), &public_key), Ok(()));
+        }
+        let (_, r, s) = &vectors[0];
+        assert_eq!(verify(&Message::from_digest(hex32(vectors[1].0)), &signature(r, s), &public_key), Err(Error::InvalidSignature));
+    }
+
+    /// Nonces from python-ecdsa test suite, signatures checked against libsecp256k1
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn rfc6979_vectors() {
+        let vectors = [
+            (
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                // SHA256("Satoshi Nakamoto")
+                "a0dc65ffca799873cbea0ac274015b9526505daaaed385155425f7337704883e",
+                "8f8a276c19f4149656b280621e358cce24f5f52542772691ee69063b74f15d15",
+                "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8",
+                "2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+            ),
+            (
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                // SHA256("All those moments will be lost in time, like tears in rain. Time to die...")
+                "7d1833f54854ac51659521afcd0ec6dca2ce2351429614bfa28a756b1b3c637f",
+                "38aa22d72376b4dbc472e06c3ba403ee0a394da63fc58d88686c611aba98d6b3",
+                "8600dbd41e348fe5c9465ab92d23e3db8b98b873beecd930736488696438cb6b",
+                "547fe64427496db33bf66019dacbf0039c04199abb0122918601db38a72cfc21",
+            ),
+            (
+                "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+                // SHA256("Satoshi Nakamoto")
+                "a0dc65ffca799873cbea0ac274015b9526505daaaed385155425f7337704883e",
+                "33a19b60e25fb6f4435af53a3d42d493644827367e6453928554f43e49aa6f90",
+                "fd567d121db66e382991534ada77a6bd3106f0a1098c231e47993447cd6af2d0",
+                "6b39cd0eb1bc8603e159ef5c20a5c8ad685a45b06ce9bebed3f153d10d93bed5",
+            ),
+            (
+                "f8b8af8ce3c7cca5e300d33939540c10d45ce001b8f252bfbc57ba0342904181",
+                // SHA256("Alan Turing")
+                "4ba38d48a60f1b29e9eb726eaff08b2e83d8d81e031666fee50e85900d7dc1ef",
+                "525a82b70e67874398067543fd84c83d30c175fdc45fdeee082fe13b1d7cfdf1",
+                "7063ae83e7f62bbb171798131b4a0564b956930092b33b07b395615d9ec7e15c",
+                "58dfcc1e00a35e1572f366ffe34ba0fc47db1e7189759b9fb233c5b05ab388ea",
+            ),
+        ];
+        for (key, msg, k, r, s) in &vectors {
+            let secret_key = SecretKey::from_bytes(&hex32(key)).unwrap();
+            let msg = Message::from_digest(hex32(msg));
+            assert_eq!(rfc6979_nonce(&msg, &secret_key, None).to_be_bytes(), hex32(k));
+            let signature = sign(&msg, &secret_key);
+            let expected = self::signature(r, s);
+            assert_eq!(signature.r(), expected.r());
+            assert_eq!(signature, expected);
+            assert_eq!(verify(&msg, &signature, &secret_key.public_key()), Ok(()));
+        }
+    }
+
+    /// Checked against libsecp256k1
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn rfc6979_extra_data() {
+        let secret_key = SecretKey::from_bytes(&hex32("0000000000000000000000000000000000000000000000000000000000000001")).unwrap();
+        let msg = Message::from_digest(hex32("a0dc65ffca799873cbea0ac274015b9526505daaaed385155425f7337704883e"));
+        let k = rfc6979_nonce(&msg, &secret_key, Some(&[1; 32]));
+        assert_eq!(k.to_be_bytes(), hex32("ff49282725ee554d481ee92230ebf201d5137cdc427fcda67210387e20a1b90b"));
+        let signature = sign_with(&msg, &secret_key, &mut Fixed(&[k]));
+        assert_eq!(signature.r().to_be_bytes(), hex32("bb6cf569458d507451271380d2863dad30355387836d5c3287a4efbd5ed1ad8e"));
+    }
+
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn extra_data_changes_signature() {
+        let secret_key = SecretKey::from_bytes(&hex32("1e99423a4ed27608a15a2616a2b0e9e52ced330ac530edcc32c8ffc6a526aedd")).unwrap();
+        let msg = Message::from_digest([42; 32]);
+        let plain = sign_with(&msg, &secret_key, &mut Rfc6979::new());
+        let extra = sign_with(&msg, &secret_key, &mut Rfc6979::with_extra_data([1; 32]));
+        assert_eq!(plain, sign(&msg, &secret_key));
+        assert_ne!(plain, extra);
+        assert_eq!(verify(&msg, &plain, &secret_key.public_key()), Ok(()));
+        assert_eq!(verify(&msg, &extra, &secret_key.public_key()), Ok(()));
+        assert_eq!(Rfc6979::new().nonce(msg.as_bytes(), &secret_key, 0), rfc6979_nonce(&msg, &secret_key, None));
+        assert_ne!(Rfc6979::new().nonce(msg.as_bytes(), &secret_key, 1), rfc6979_nonce(&msg, &secret_key, None));
+    }
+
+    #[test]
+    fn random_nonces() {
+        let secret_key = SecretKey::from_bytes(&hex32("1e99423a4ed27608a15a2616a2b0e9e52ced330ac530edcc32c8ffc6a526aedd")).unwrap();
+        let mut nonces = RngNonce(DeterministicRng::new(42));
+        let first = sign_with(&Message::from_digest([42; 32]), &secret_key, &mut nonces);
+        let second = sign_with(&Message::from_digest([42; 32]), &secret_key, &mut nonces);
+        assert_ne!(first, second);
+        assert_eq!(verify(&Message::from_digest([42; 32]), &first, &secret_key.public_key()), Ok(()));
+        assert_eq!(verify(&Message::from_digest([42; 32]), &second, &secret_key.public_key()), Ok(()));
+    }
+
+    #[test]
+    fn compact_round_trip() {
+        let sig = signature("a29a620e10006b3ad1060ac518bed36b89332f289acb1b8add594f68d52afae5", "5dd1cd900639856fa2fe3b5ad761956faf04de256ddee87752d95284782c9ac7");
+        let compact = sig.serialize_compact();
+        assert_eq!(compact[..32], hex32("a29a620e10006b3ad1060ac518bed36b89332f289acb1b8add594f68d52afae5"));
+        assert_eq!(Signature::from_compact(&compact), Ok(sig));
+        let formatted = "a29a620e10006b3ad1060ac518bed36b89332f289acb1b8add594f68d52afae55dd1cd900639856fa2fe3b5ad761956faf04de256ddee87752d95284782c9ac7";
+        assert_eq!(sig.to_string(), formatted);
+        assert_eq!(format!("{:?}", sig), format!("Signature({})", formatted));
+    }
+
+    #[test]
+    fn compact_out_of_range() {
+        let valid = hex32("5dd1cd900639856fa2fe3b5ad761956faf04de256ddee87752d95284782c9ac7");
+        // the curve order
+        let n = hex32("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+        let cases = [
+            ([0; 32], valid, Error::ZeroScalar),
+            (valid, [0; 32], Error::ZeroScalar),
+            (n, valid, Error::NonCanonicalScalar),
+            (valid, n, Error::NonCanonicalScalar),
+            ([0xFF; 32], valid, Error::NonCanonicalScalar),
+        ];
+        for (r, s, error) in &cases {
+            let mut compact = [0; 64];
+            compact[..32].copy_from_slice(r);
+            compact[32..].copy_from_slice(s);
+            assert_eq!(Signature::from_compact(&compact), Err(*error));
+        }
+    }
+
+    /// Compact signatures produced by libsecp256k1 for private key 1
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn compact_matches_libsecp256k1() {
+        let secret_key = SecretKey::from_bytes(&hex32("0000000000000000000000000000000000000000000000000000000000000001")).unwrap();
+        let vectors = [
+            (
+                // SHA256("toy-secp256k1 compact 1")
+                "6e6b1a386a918033d662a7ed60ce518ad7db8419ee06c514eb4ed71b0b39b465",
+                "524b97d9cf6aea86253778a8fe672a294563571bfee75e42d924ec71da43394501beb5e5448d9dd43e6f5f7417014f30c797f0f628c962af0d6e85946e100ec8",
+            ),
+            (
+                // SHA256("toy-secp256k1 compact 2")
+                "d383ab665707c3c9b68b2a5988fb0bcd7d5e6b7cb1649cb4854958065c4937ea",
+                "a2de00877d5f5da03fb4c102bc38d48f37cedb0144c1f4b882d57ae429bfc05c668af46e77b30e302b5b4ca7f3d904e9ebd46bde160b2b34d935c013dadafdff",
+            ),
+        ];
+        for (msg, compact) in &vectors {
+            let signature = sign(&Message::from_digest(hex32(msg)), &secret_key);
+            assert_eq!(signature.serialize_compact()[..], hex(compact)[..]);
+        }
+    }
+
+    #[test]
+    fn low_s() {
+        let public_key = PublicKey::from_sec1_bytes(&hex("03b9ebdc566c4002d0d9228581eb1f77b33c1b74d24714dadeed58291d7ef15697")).unwrap();
+        // produced by OpenSSL
+        let msg = Message::from_digest(hex32("56ae0588cd161158395388d03f774650bbb78c7da47e0f6a71a87b4607bec222"));
+        let high = signature("98fdfc1157d68cca0d888df3504d3efc8fbf37dac5c35db117caaefdb2481c4d", "b73c47b73604b3d4c3078fc634d205855b14b365cd81b61a8816ba6cfc5ba254");
+        assert!(!high.is_low_s());
+
+        let mut low = high;
+        assert!(low.normalize_s());
+        assert!(low.is_low_s());
+        assert_eq!(low.s(), -high.s());
+        assert!(!low.clone().normalize_s());
+
+        assert_eq!(verify(&msg, &high, &public_key), Ok(()));
+        assert_eq!(verify(&msg, &low, &public_key), Ok(()));
+        assert_eq!(verify_strict(&msg, &high, &public_key), Err(Error::HighS));
+        assert_eq!(verify_strict(&msg, &low, &public_key), Ok(()));
+    }
+
+    #[test]
+    fn signer_produces_low_s() {
+        let secret_key = SecretKey::from_bytes(&hex32("1e99423a4ed27608a15a2616a2b0e9e52ced330ac530edcc32c8ffc6a526aedd")).unwrap();
+        let mut nonces = RngNonce(DeterministicRng::new(1));
+        for i in 0..16 {
+            let signature = sign_with(&Message::from_digest([i; 32]), &secret_key, &mut nonces);
+            assert!(signature.is_low_s());
+            assert_eq!(verify_strict(&Message::from_digest([i; 32]), &signature, &secret_key.public_key()), Ok(()));
+        }
+    }
+
+    /// Checked against libsecp256k1 `sign_ecdsa_low_r`, all but 1 and 7 needed grinding
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn low_r_vectors() {
+        let secret_key = SecretKey::from_bytes(&hex32("0000000000000000000000000000000000000000000000000000000000000001")).unwrap();
+        let vectors = [
+            "060067d74aea6a4f1a91427070c41e8f7a04cf55f0653b97b1188e93a1445bf308c8f87359415e2081e45308117362ed16d55b774f5dfdf89d1b686acfe2b44c",
+            "747a6c6bd9a8ff0b77a99b8f274f5df5198b5dddc33edd4bc89e459d13dfa6177431bfa016325b8f27491781d1c462999f1dde760560373a517d29161c2df139",
+            "45c8f8265c2f7eff00192804a8c9bfc27dccd8196fd4f817e3b21deb21a4caaa12bb835c1fce7b03c186abb1e9f1886b94c84c9e3a8e003ac462f986246ccdc6",
+            "2b5ca98ecf62cf6c0ddc07172341b5220ff909a45d4328928883496ea5b9600d6cfedee5de55325a76cce03ea64688bf3bee96675769c6880068d16a6f11bf75",
+            "0a770bfa72ce16cdf9c7388bdda9600542d88ee6a507750db16e9a6563a1bca4528c54e41e2e3fc1fc5d70d87b326392b0a1deca8659ffaac888db00cd6a27d3",
+            "34985d8a7ba9352087485c181c1ffc4c7e4539aacb403bb5c97edc2ef8c495c93ae2c8683f2490fc9333e0efbaaa2d3da805e993df9658c3df6ed9b0a54d73f0",
+            "7b2b6952e92e627342eedf78bef85e288a95fad4600ecce4abe1cd248004a19b63da59b784f350f7f47bdcfc336794e4035489ef6a8e7e5c29d4f8996c95b386",
+            "73a5c857e5738b8ea9bab7e4fb0de0bdd0d73030aca7e7e3f62ae97b6b4629e3729bad61cbeba1b0b9a0b9526dc6f359c9a5052616843e391a4a388f23607d15",
+        ];
+        for (i, expected) in vectors.iter().enumerate() {
+            let msg = Message::from_digest([i as u8; 32]);
+            let signature = sign_low_r(&msg, &secret_key);
+            assert_eq!(signature.serialize_compact()[..], hex(expected)[..]);
+            assert_eq!(signature == sign(&msg, &secret_key), i == 1 || i == 7);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn low_r_random_messages() {
+        let secret_key = SecretKey::from_bytes(&hex32("1e99423a4ed27608a15a2616a2b0e9e52ced330ac530edcc32c8ffc6a526aedd")).unwrap();
+        let public_key = secret_key.public_key();
+        let mut rng = DeterministicRng::new(42);
+        // kept small because the arithmetic is slow
+        for _ in 0..64 {
+            let msg = Message::from_digest(Scalar::random(&mut rng).to_be_bytes());
+            let signature = sign_low_r(&msg, &secret_key);
+            assert!(signature.has_low_r());
+            assert!(signature.r().to_be_bytes()[0] < 0x80);
+            assert!(signature.to_der_array().1 <= 71);
+            assert_eq!(verify_strict(&msg, &signature, &public_key), Ok(()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn message_hashing() {
+        let secret_key = SecretKey::from_bytes(&hex32("1e99423a4ed27608a15a2616a2b0e9e52ced330ac530edcc32c8ffc6a526aedd")).unwrap();
+        let public_key = secret_key.public_key();
+        let msg = b"toy-secp256k1";
+        let signature = sign_message_sha256(msg, &secret_key);
+        assert_eq!(signature, sign(&Message::from_hashed_data(msg), &secret_key));
+        assert_eq!(verify_message_sha256(msg, &signature, &public_key), Ok(()));
+        assert_eq!(verify_message_sha256d(msg, &signature, &public_key), Err(Error::InvalidSignature));
+
+        let signature = sign_message_sha256d(msg, &secret_key);
+        assert_eq!(signature, sign(&Message::from_double_sha256(msg), &secret_key));
+        assert_eq!(verify_message_sha256d(msg, &signature, &public_key), Ok(()));
+        assert_eq!(verify_message_sha256(msg, &signature, &public_key), Err(Error::InvalidSignature));
+    }
+
+    /// `openssl dgst -sha256 -sign`
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn openssl_message() {
+        let public_key = PublicKey::from_sec1_bytes(&hex("03b9ebdc566c4002d0d9228581eb1f77b33c1b74d24714dadeed58291d7ef15697")).unwrap();
+        let signature = Signature::from_der(&hex("3045022057cae5a374a1bd31781a2d3b610fbdbc41da8a41da81ec3ee8042cefb9a3a620022100e413f01ff8d13abffdafe485bc98fb024e1bd9303a41945496128634966a2888")).unwrap();
+        assert_eq!(verify_message_sha256(b"openssl signed", &signature, &public_key), Ok(()));
+    }
+
+    /// The classic attack - signing two different messages with the same nonce reveals the key
+    #[test]
+    #[cfg(feature = "dangerous-explicit-nonce")]
+    fn nonce_reuse_leaks_key() {
+        use super::sign_with_explicit_nonce;
+        use crate::NonZeroScalar;
+
+        let secret_key = SecretKey::from_bytes(&hex32("1e99423a4ed27608a15a2616a2b0e9e52ced330ac530edcc32c8ffc6a526aedd")).unwrap();
+        let k = NonZeroScalar::from_be_bytes(&hex32("2ba9ef2d1ce1a5c0fcb9e58ee19f0c47c3b1c0b1ab44b0c12a564d6b1f7ec2a4")).unwrap();
+        let (msg1, msg2) = (Message::from_digest([1; 32]), Message::from_digest([2; 32]));
+        let sig1 = sign_with_explicit_nonce(&msg1, &secret_key, k).unwrap();
+        let sig2 = sign_with_explicit_nonce(&msg2, &secret_key, k).unwrap();
+        assert_eq!(verify(&msg1, &sig1, &secret_key.public_key()), Ok(()));
+        assert_eq!(verify(&msg2, &sig2, &secret_key.public_key()), Ok(()));
+        assert_eq!(sig1.r(), sig2.r());
+
+        // s₁ - s₂ = k⁻¹(z₁ - z₂)
+        let (z1, z2) = (Scalar::from_be_bytes_reduce(msg1.as_bytes()), Scalar::from_be_bytes_reduce(msg2.as_bytes()));
+        let recovered_k = (z1 - z2) / (sig1.s() - sig2.s());
+        assert_eq!(recovered_k, *k);
+        // s₁ = k⁻¹(z₁ + r·d)
+        let recovered_key = (sig1.s() * recovered_k - z1) / sig1.r();
+        assert_eq!(recovered_key, secret_key.to_scalar());
+
+        // the zero nonce can't even be passed in
+        assert_eq!(NonZeroScalar::new(Scalar::ZERO), Err(Error::ZeroScalar));
+        // matches the independently computed vector
+        let msg = Message::from_digest(hex32("9d17a4ec82c8d8161cbd0df88b71fef9a65b6bc6ec064d2db57af463c96f8a1f"));
+        assert_eq!(sign_with_explicit_nonce(&msg, &secret_key, k).unwrap().r().to_be_bytes(), hex32("a29a620e10006b3ad1060ac518bed36b89332f289acb1b8add594f68d52afae5"));
+    }
+
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn verify_after_sign() {
+        use super::{sign_checked, sign_with_options};
+        use crate::{Keypair, SignOptions};
+
+        let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap());
+        let options = SignOptions { verify_after_sign: true };
+        let msg = Message::from_digest([0x01; 32]);
+        let expected = sign(&msg, keypair.secret_key());
+        assert_eq!(sign_with_options(&msg, &keypair, &options), Ok(expected));
+        assert_eq!(sign_with_options(&msg, &keypair, &SignOptions::default()), Ok(expected));
+
+        let fault = |signature: &mut Signature| *signature = Signature::from_scalars(signature.r(), signature.s() + Scalar::ONE).unwrap();
+        assert_eq!(sign_checked(&msg, &keypair, &options, fault), Err(Error::SelfVerificationFailed));
+        // the faulty signature is released without the check
+        let faulty = sign_checked(&msg, &keypair, &SignOptions::default(), fault).unwrap();
+        assert_ne!(faulty, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn hedged() {
+        use super::{sign_hedged, sign_hedged_with_randomness};
+        use crate::test_util::ZeroRng;
+
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let public_key = secret_key.public_key();
+        let msg = Message::from_digest([0x01; 32]);
+        let deterministic = sign(&msg, &secret_key);
+        assert_eq!(sign_hedged(&msg, &secret_key, &mut ZeroRng), deterministic);
+        assert_eq!(sign_hedged_with_randomness(&msg, &secret_key, &[0; 32]), deterministic);
+
+        let first = sign_hedged(&msg, &secret_key, &mut DeterministicRng::new(236));
+        assert_eq!(sign_hedged(&msg, &secret_key, &mut DeterministicRng::new(236)), first);
+        let second = sign_hedged(&msg, &secret_key, &mut DeterministicRng::new(237));
+        assert_ne!(first, deterministic);
+        assert_ne!(first, second);
+        for signature in &[first, second] {
+            assert_eq!(verify_strict(&msg, signature, &public_key), Ok(()));
+        }
+
+        let randomness = [0x55; 32];
+        let expected = sign_with(&msg, &secret_key, &mut Rfc6979::with_extra_data(randomness));
+        assert_eq!(sign_hedged_with_randomness(&msg, &secret_key, &randomness), expected);
+    }
+}
@@ -0,0 +1,275 @@
+//! ECDSA adaptor signatures compatible with libsecp256k1-zkp
+//!
+//! An adaptor signature is a signature "encrypted" to an adaptor point `Y = y·G`: anyone can
+//! check it's valid but it turns into a valid ECDSA signature only with the knowledge of `y`.
+//! Publishing the completed signature in turn reveals `y` to the holder of the adaptor
+//! signature. The nonce is `R = k·Y` and a DLEQ proof shows that `R' = k·G` uses the same `k`.
+
+use core::fmt;
+use super::{message_scalar, Signature};
+use crate::hashes::sha256;
+use crate::tagged_hash::TaggedHash;
+use crate::{array_ref_32, write_hex, Error, Message, Point, PublicKey, Scalar, SecretKey, G};
+
+const NONCE_TAG: &str = "ECDSAadaptor/non";
+const DLEQ_TAG: &str = "DLEQ";
+
+/// Adaptor signature together with the proof that it's encrypted to the adaptor point
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct AdaptorSignature {
+    big_r: Point,
+    big_r_prime: Point,
+    s_prime: Scalar,
+    dleq_e: Scalar,
+    dleq_s: Scalar,
+}
+
+impl AdaptorSignature {
+    /// Parses the 162-byte format used by libsecp256k1-zkp: `R || R' || s' || e || s`
+    ///
+    /// Returns `Error::InvalidSignature` if any of the values is invalid.
+    pub fn from_bytes(bytes: &[u8; 162]) -> Result<Self, Error> {
+        let big_r = Point::from_sec1_bytes(&bytes[..33]).map_err(|_| Error::InvalidSignature)?;
+        let big_r_prime = Point::from_sec1_bytes(&bytes[33..66]).map_err(|_| Error::InvalidSignature)?;
+        let s_prime = Scalar::from_be_bytes(array_ref_32(&bytes[66..98])).ok_or(Error::InvalidSignature)?;
+        let dleq_e = Scalar::from_be_bytes_reduce(array_ref_32(&bytes[98..130]));
+        let dleq_s = Scalar::from_be_bytes(array_ref_32(&bytes[130..])).ok_or(Error::InvalidSignature)?;
+        if s_prime.is_zero() || x_scalar(big_r).is_zero() {
+            return Err(Error::InvalidSignature);
+        }
+        Ok(AdaptorSignature { big_r, big_r_prime, s_prime, dleq_e, dleq_s })
+    }
+
+    pub fn serialize(&self) -> [u8; 162] {
+        let mut bytes = [0; 162];
+        bytes[..33].copy_from_slice(&self.big_r.serialize_compressed());
+        bytes[33..66].copy_from_slice(&self.big_r_prime.serialize_compressed());
+        bytes[66..98].copy_from_slice(&self.s_prime.to_be_bytes());
+        bytes[98..130].copy_from_slice(&self.dleq_e.to_be_bytes());
+        bytes[130..].copy_from_slice(&self.dleq_s.to_be_bytes());
+        bytes
+    }
+
+    /// The `r` value the completed signature will have
+    fn r(&self) -> Scalar {
+        x_scalar(self.big_r)
+    }
+}
+
+impl fmt::Debug for AdaptorSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AdaptorSignature(")?;
+        write_hex(f, &self.serialize())?;
+        f.write_str(")")
+    }
+}
+
+fn x_scalar(point: Point) -> Scalar {
+    point.x().to_scalar_reduce()
+}
+
+/// The nonce function of libsecp256k1-zkp without auxiliary randomness
+fn nonce(tag: &str, key: &Scalar, point: &Point, msg32: &[u8; 32]) -> Scalar {
+    let hash = TaggedHash::new(tag).hash(&[&key.to_be_bytes(), &point.serialize_compressed(), msg32]);
+    let k = Scalar::from_be_bytes_reduce(&hash);
+    assert!(!k.is_zero(), "the probability of zero nonce is negligible");
+    k
+}
+
+fn dleq_challenge(y: &Point, p1: &Point, p2: &Point, r1: &Point, r2: &Point) -> Scalar {
+    let points = [p1, y, p2, r1, r2].map(Point::serialize_compressed);
+    let chunks = [&points[0][..], &points[1], &points[2], &points[3], &points[4]];
+    Scalar::from_be_bytes_reduce(&TaggedHash::new(DLEQ_TAG).hash(&chunks))
+}
+
+/// Proves that `p1 = x·G` and `p2 = x·y` have the same discrete logarithm
+fn dleq_prove(x: Scalar, y: &Point, p1: &Point, p2: &Point) -> (Scalar, Scalar) {
+    let mut points = [0; 66];
+    points[..33].copy_from_slice(&p1.serialize_compressed());
+    points[33..].copy_from_slice(&p2.serialize_compressed());
+    let k = nonce(DLEQ_TAG, &x, y, &sha256(&points));
+    let e = dleq_challenge(y, p1, p2, &(G * k), &(*y * k));
+    (e, k + e * x)
+}
+
+fn dleq_verify(e: Scalar, s: Scalar, y: &Point, p1: &Point, p2: &Point) -> bool {
+    let r1 = G * s + *p1 * -e;
+    let r2 = *y * s + *p2 * -e;
+    dleq_challenge(y, p1, p2, &r1, &r2) == e
+}
+
+/// Creates an adaptor signature of 32-byte message hash encrypted to `adaptor_point`.
+///
+/// The nonce is derived deterministically the same way as libsecp256k1-zkp does it when no
+/// auxiliary randomness is provided.
+///
+/// # Panics
+///
+/// If `adaptor_point` is the point at infinity.
+pub fn adaptor_sign(msg32: &[u8; 32], secret_key: &SecretKey, adaptor_point: Point) -> AdaptorSignature {
+    assert!(!adaptor_point.is_at_infinity(), "adaptor point must not be at infinity");
+    let d = secret_key.to_scalar();
+    let k = nonce(NONCE_TAG, &d, &adaptor_point, msg32);
+    let big_r_prime = G * k;
+    let big_r = adaptor_point * k;
+    let (dleq_e, dleq_s) = dleq_prove(k, &adaptor_point, &big_r_prime, &big_r);
+    let s_prime = (message_scalar(&Message::from_digest(*msg32)) + x_scalar(big_r) * d) / k;
+    AdaptorSignature { big_r, big_r_prime, s_prime, dleq_e, dleq_s }
+}
+
+/// Checks that the adaptor signature turns into a valid signature once decrypted with the
+/// discrete logarithm of `adaptor_point`.
+pub fn adaptor_verify(msg32: &[u8; 32], public_key: &PublicKey, adaptor_point: Point, signature: &AdaptorSignature) -> bool {
+    if adaptor_point.is_at_infinity() {
+        return false;
+    }
+    if !dleq_verify(signature.dleq_e, signature.dleq_s, &adaptor_point, &signature.big_r_prime, &signature.big_r) {
+        return false;
+    }
+    let s_inv = signature.s_prime.multiplicative_inverse();
+    let derived = G * (message_scalar(&Message::from_digest(*msg32)) * s_inv) + public_key.to_point() * (signature.r() * s_inv);
+    !derived.is_at_infinity() && derived == signature.big_r_prime
+}
+
+/// Decrypts the adaptor signature using the discrete logarithm of the adaptor point.
+///
+/// The result is low-S. The adaptor signature should be verified first, otherwise the result
+/// may be invalid.
+///
+/// # Panics
+///
+/// If `secret` is zero.
+pub fn adapt(signature: &AdaptorSignature, secret: Scalar) -> Signature {
+    assert!(!secret.is_zero(), "adaptor secret must not be zero");
+    let mut signature = Signature::from_scalars(signature.r(), signature.s_prime / secret).expect("non-zero values");
+    signature.normalize_s();
+    signature
+}
+
+/// Extracts the discrete logarithm of `adaptor_point` from the decrypted signature.
+///
+/// Returns `Error::InvalidSignature` if `signature` is not a decryption of `adaptor_signature`
+/// for `adaptor_point`.
+pub fn extract_secret(adaptor_signature: &AdaptorSignature, signature: &Signature, adaptor_point: Point) -> Result<Scalar, Error> {
+    if signature.r() != adaptor_signature.r() {
+        return Err(Error::InvalidSignature);
+    }
+    let secret = adaptor_signature.s_prime / signature.s();
+    let implied = G * secret;
+    if implied.x() != adaptor_point.x() {
+        return Err(Error::InvalidSignature);
+    }
+    // s might have been negated during decryption
+    if implied == adaptor_point {
+        Ok(secret)
+    } else {
+        Ok(-secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{adapt, adaptor_sign, adaptor_verify, extract_secret, AdaptorSignature};
+    use crate::ecdsa::{verify_strict, Signature};
+    use crate::test_util::{hex, hex32};
+    use crate::{Error, Message, Point, PublicKey, Scalar, SecretKey, G};
+
+    fn adaptor_bytes(s: &str) -> [u8; 162] {
+        let mut bytes = [0; 162];
+        bytes.copy_from_slice(&hex(s));
+        bytes
+    }
+
+    /// Produced by `EcdsaAdaptorSignature::encrypt_no_aux_rand` of secp256k1-zkp
+    #[test]
+    fn matches_libsecp256k1_zkp() {
+        let secret_key = SecretKey::from_bytes(&[0x11; 32]).unwrap();
+        let secret = Scalar::from_be_bytes(&[0x22; 32]).unwrap();
+        let msg32 = [0x33; 32];
+        let expected = adaptor_bytes("02b6c69c37896a383ec436560cb36c88709856981fd89ef3a3fb2a7845a63ae8b80346a71997cd7985a4e1c43579a1f9a21ebad62c1adb4f1d39fc497d2276c0a308b4ff71256af7f2fddb246cfff9615dbc84af8533215ce3f0f87b738304c4b623aa8e37634faac9f9e0078d3300e12cd6b9772254ab4cb7749a9f665db3b6ba21b949566edd49389510d7bc3c6c00e9bf13495613ebc8b39856b28e28289717c7");
+        let signature = adaptor_sign(&msg32, &secret_key, G * secret);
+        assert_eq!(signature.serialize(), expected);
+        assert_eq!(AdaptorSignature::from_bytes(&expected), Ok(signature));
+
+        let decrypted = adapt(&signature, secret);
+        let expected = hex("b6c69c37896a383ec436560cb36c88709856981fd89ef3a3fb2a7845a63ae8b87d902671fe31c973d53d8a3e33569459df192857dee97d42ecd74e531f147888");
+        assert_eq!(decrypted.serialize_compact()[..], expected[..]);
+    }
+
+    /// Test vector 0 from libsecp256k1-zkp (taken from the DLC specification)
+    #[test]
+    fn spec_vector() {
+        let signature = AdaptorSignature::from_bytes(&adaptor_bytes("03424d14a5471c048ab87b3b83f6085d125d5864249ae4297a57c84e74710bb6730223f325042fce535d040fee52ec13231bf709ccd84233c6944b90317e62528b2527dff9d659a96db4c99f9750168308633c1867b70f3a18fb0f4539a1aecedcd1fc0148fc22f36b6303083ece3f872b18e35d368b3958efe5fb081f7716736ccb598d269aa3084d57e1855e1ea9a45efc10463bbf32ae378029f5763ceb40173f")).unwrap();
+        let msg32 = hex32("8131e6f4b45754f2c90bd06688ceeabc0c45055460729928b4eecf11026a9e2d");
+        let public_key = PublicKey::from_sec1_bytes(&hex("035be5e9478209674a96e60f1f037f6176540fd001fa1d64694770c56a7709c42c")).unwrap();
+        let adaptor_point = Point::from_sec1_bytes(&hex("02c2662c97488b07b6e819124b8989849206334a4c2fbdf691f7b34d2b16e9c293")).unwrap();
+        let secret = Scalar::from_be_bytes(&hex32("0b2aba63b885a0f0e96fa0f303920c7fb7431ddfa94376ad94d969fbf4109dc8")).unwrap();
+        let mut compact = [0; 64];
+        compact.copy_from_slice(&hex("424d14a5471c048ab87b3b83f6085d125d5864249ae4297a57c84e74710bb67329e80e0ee60e57af3e625bbae1672b1ecaa58effe613426b024fa1621d903394"));
+        let expected = Signature::from_compact(&compact).unwrap();
+
+        assert!(adaptor_verify(&msg32, &public_key, adaptor_point, &signature));
+        assert_eq!(adapt(&signature, secret), expected);
+        assert_eq!(extract_secret(&signature, &expected, adaptor_point), Ok(secret));
+    }
+
+    #[test]
+    fn round_trip() {
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let public_key = secret_key.public_key();
+        // kept small because the arithmetic is slow
+        for i in 1..=3u8 {
+            let msg32 = [i; 32];
+            let secret = Scalar::from(u64::from(i) * 0x1234_5678_9abc);
+            let adaptor_point = G * secret;
+            let adaptor_signature = adaptor_sign(&msg32, &secret_key, adaptor_point);
+            assert!(adaptor_verify(&msg32, &public_key, adaptor_point, &adaptor_signature));
+
+            let signature = adapt(&adaptor_signature, secret);
+            assert_eq!(verify_strict(&Message::from_digest(msg32), &signature, &public_key), Ok(()));
+            assert_eq!(extract_secret(&adaptor_signature, &signature, adaptor_point), Ok(secret));
+            // the high-S variant is valid too and must give the same secret
+            let high = Signature::from_scalars(signature.r(), -signature.s()).unwrap();
+            assert_eq!(extract_secret(&adaptor_signature, &high, adaptor_point), Ok(secret));
+        }
+    }
+
+    #[test]
+    fn wrong_adaptor_point() {
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let public_key = secret_key.public_key();
+        let msg32 = [0x07; 32];
+        let secret = Scalar::from(0xdead_beef);
+        let adaptor_signature = adaptor_sign(&msg32, &secret_key, G * secret);
+        let wrong_point = G * (secret + Scalar::ONE);
+
+        assert!(!adaptor_verify(&msg32, &public_key, wrong_point, &adaptor_signature));
+        assert!(!adaptor_verify(&msg32, &public_key, Point::AT_INFINITY, &adaptor_signature));
+        assert!(!adaptor_verify(&[0x08; 32], &public_key, G * secret, &adaptor_signature));
+        let signature = adapt(&adaptor_signature, secret);
+        assert_eq!(extract_secret(&adaptor_signature, &signature, wrong_point), Err(Error::InvalidSignature));
+        // unrelated signature
+        let other = crate::ecdsa::sign(&Message::from_digest(msg32), &secret_key);
+        assert_eq!(extract_secret(&adaptor_signature, &other, G * secret), Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn invalid_encoding() {
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let bytes = adaptor_sign(&[0x07; 32], &secret_key, G * Scalar::from(5)).serialize();
+
+        let mut invalid = bytes;
+        invalid[0] = 0x04;
+        assert_eq!(AdaptorSignature::from_bytes(&invalid), Err(Error::InvalidSignature));
+        let mut invalid = bytes;
+        invalid[66..98].copy_from_slice(&[0; 32]);
+        assert_eq!(AdaptorSignature::from_bytes(&invalid), Err(Error::InvalidSignature));
+        let mut invalid = bytes;
+        invalid[130..].copy_from_slice(&[0xff; 32]);
+        assert_eq!(AdaptorSignature::from_bytes(&invalid), Err(Error::InvalidSignature));
+        // e is reduced rather than rejected
+        let mut reduced = bytes;
+        reduced[98..130].copy_from_slice(&[0xff; 32]);
+        assert!(AdaptorSignature::from_bytes(&reduced).is_ok());
+    }
+}
@@ -0,0 +1,181 @@
+//! Batch verification of ECDSA signatures using random linear combination
+//!
+//! Verification of a single signature only checks the x coordinate of `R = u₁·G + u₂·P`. To
+//! combine the equations the whole point `R` is needed which can not be computed from a plain
+//! signature. Thus batch verification works with recoverable signatures only - the recovery id
+//! identifies `R` exactly. Plain signatures have to be verified one by one using `verify`.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use super::{message_scalar, verify, RecoverableSignature};
+use crate::{multiexp, Error, Message, Point, PublicKey, Scalar, G, U256};
+
+/// Verifies all signatures at once.
+///
+/// Checks `Σ aᵢ·(u₁ᵢ·G + u₂ᵢ·Pᵢ - Rᵢ) = 0` for random `aᵢ` which is much faster than verifying
+/// the signatures separately. Apart from validity of the signature this also checks that the
+/// recovery id matches. If `R` can not be reconstructed from the recovery id the signature is
+/// verified separately and the recovery id is ignored.
+///
+/// Returns `Error::InvalidSignature` if any signature is invalid, use `find_invalid` to find out
+/// which one.
+#[cfg(feature = "alloc")]
+pub fn verify_batch<R: RngCore + CryptoRng>(items: &[([u8; 32], RecoverableSignature, PublicKey)], rng: &mut R) -> Result<(), Error> {
+    let mut scratch = Vec::new();
+    scratch.resize(items.len() * 2 + 1, (U256::zero(), G));
+    verify_batch_with_scratch(items, rng, &mut scratch)
+}
+
+/// Verifies all signatures like `verify_batch` using `scratch` for the terms of the equation.
+///
+/// Each signature needs two entries of `scratch` and one more is needed for the whole batch. If
+/// `scratch` is shorter than `2 * items.len() + 1` the signatures are verified in as many smaller
+/// batches as needed. Nothing is allocated.
+///
+/// Returns `Error::InvalidLength` with the length of `scratch` if it can't hold a single
+/// signature (and `items` is not empty), `Error::InvalidSignature` if any signature is invalid.
+pub fn verify_batch_with_scratch<R: RngCore + CryptoRng>(items: &[([u8; 32], RecoverableSignature, PublicKey)], rng: &mut R, scratch: &mut [(U256, Point)]) -> Result<(), Error> {
+    let chunk_len = scratch.len().saturating_sub(1) / 2;
+    if chunk_len == 0 && !items.is_empty() {
+        return Err(Error::InvalidLength(scratch.len()));
+    }
+    for chunk in items.chunks(chunk_len.max(1)) {
+        let mut g_scalar = Scalar::ZERO;
+        let mut len = 0;
+        for (msg32, signature, public_key) in chunk {
+            let big_r = match signature.nonce_point() {
+                Ok(big_r) => big_r,
+                Err(_) => {
+                    verify(&Message::from_digest(*msg32), &signature.to_standard(), public_key)?;
+                    continue;
+                },
+            };
+            let standard = signature.to_standard();
+            let s_inv = standard.s().multiplicative_inverse();
+            let a = Scalar::random(rng);
+            g_scalar += a * message_scalar(&Message::from_digest(*msg32)) * s_inv;
+            scratch[len] = ((a * standard.r() * s_inv).to_u256(), public_key.to_point());
+            scratch[len + 1] = ((-a).to_u256(), big_r);
+            len += 2;
+        }
+        scratch[len] = (g_scalar.to_u256(), G);
+
+        if multiexp(&scratch[..len + 1]) != Point::AT_INFINITY {
+            return Err(Error::InvalidSignature);
+        }
+    }
+    Ok(())
+}
+
+/// Finds the index of an invalid signature by bisecting the batch.
+///
+/// Returns `None` if all signatures are valid. This is faster than verifying all signatures
+/// separately if there are few invalid ones.
+#[cfg(feature = "alloc")]
+pub fn find_invalid<R: RngCore + CryptoRng>(items: &[([u8; 32], RecoverableSignature, PublicKey)], rng: &mut R) -> Option<usize> {
+    if verify_batch(items, rng).is_ok() {
+        return None;
+    }
+    let mut offset = 0;
+    let mut items = items;
+    // invariant: `items` contains an invalid signature
+    while items.len() > 1 {
+        let (left, right) = items.split_at(items.len() / 2);
+        if verify_batch(left, rng).is_err() {
+            items = left;
+        } else {
+            offset += left.len();
+            items = right;
+        }
+    }
+    Some(offset)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{find_invalid, verify_batch, verify_batch_with_scratch};
+    use crate::ecdsa::{sign_recoverable_with, RecoverableSignature, RecoveryId, Signature};
+    use crate::nonce::RngNonce;
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, Message, PublicKey, Scalar, SecretKey, G, U256};
+
+    fn batch(rng: &mut DeterministicRng, len: usize) -> Vec<([u8; 32], RecoverableSignature, PublicKey)> {
+        (0..len)
+            .map(|_| {
+                let secret_key = SecretKey::from_scalar(Scalar::random(rng)).unwrap();
+                let msg = Scalar::random(rng).to_be_bytes();
+                let signature = sign_recoverable_with(&Message::from_digest(msg), &secret_key, &mut RngNonce(&mut *rng));
+                (msg, signature, secret_key.public_key())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid_batch() {
+        let mut rng = DeterministicRng::new(42);
+        let items = batch(&mut rng, 6);
+        assert_eq!(verify_batch(&items, &mut rng), Ok(()));
+        assert_eq!(verify_batch(&[], &mut rng), Ok(()));
+        assert_eq!(find_invalid(&items, &mut rng), None);
+    }
+
+    #[test]
+    fn bounded_scratch() {
+        let mut rng = DeterministicRng::new(222);
+        let items = batch(&mut rng, 4);
+        let mut scratch = [(U256::zero(), G); 5];
+        for &len in &[3, 4, 5] {
+            assert_eq!(verify_batch_with_scratch(&items, &mut rng, &mut scratch[..len]), Ok(()), "scratch length {}", len);
+        }
+        assert_eq!(verify_batch_with_scratch(&items, &mut rng, &mut scratch[..2]), Err(Error::InvalidLength(2)));
+        assert_eq!(verify_batch_with_scratch(&[], &mut rng, &mut []), Ok(()));
+        for i in 0..items.len() {
+            let mut corrupted = items.clone();
+            corrupted[i].0[0] ^= 1;
+            assert_eq!(verify_batch_with_scratch(&corrupted, &mut rng, &mut scratch), Err(Error::InvalidSignature), "item {}", i);
+        }
+    }
+
+    #[test]
+    fn corrupted_signature() {
+        let mut rng = DeterministicRng::new(42);
+        let items = batch(&mut rng, 4);
+        for i in 0..items.len() {
+            let mut corrupted = items.clone();
+            let (compact, id) = corrupted[i].1.serialize_compact();
+            let s = Scalar::from_be_bytes_reduce(&[compact[63]; 32]);
+            let signature = Signature::from_scalars(corrupted[i].1.to_standard().r(), s).unwrap();
+            corrupted[i].1 = RecoverableSignature::new(signature, id);
+            assert_eq!(verify_batch(&corrupted, &mut rng), Err(Error::InvalidSignature));
+            assert_eq!(find_invalid(&corrupted, &mut rng), Some(i));
+
+            let mut corrupted = items.clone();
+            corrupted[i].0[0] ^= 1;
+            assert_eq!(find_invalid(&corrupted, &mut rng), Some(i));
+        }
+    }
+
+    #[test]
+    fn wrong_recovery_id() {
+        let mut rng = DeterministicRng::new(42);
+        let mut items = batch(&mut rng, 2);
+        let id = items[1].1.recovery_id().to_u8() ^ 1;
+        items[1].1 = RecoverableSignature::new(items[1].1.to_standard(), RecoveryId::from_u8(id).unwrap());
+        assert_eq!(verify_batch(&items, &mut rng), Err(Error::InvalidSignature));
+    }
+
+    /// `R` can not be reconstructed so the signature is verified separately
+    #[test]
+    fn unreconstructible_nonce_point() {
+        let mut rng = DeterministicRng::new(42);
+        let mut items = batch(&mut rng, 2);
+        // r + n overflows
+        assert!(items[1].1.to_standard().r().to_be_bytes()[0] > 0);
+        let id = items[1].1.recovery_id().to_u8() | 2;
+        items[1].1 = RecoverableSignature::new(items[1].1.to_standard(), RecoveryId::from_u8(id).unwrap());
+        assert_eq!(verify_batch(&items, &mut rng), Ok(()));
+        items[1].0[0] ^= 1;
+        assert_eq!(verify_batch(&items, &mut rng), Err(Error::InvalidSignature));
+    }
+}
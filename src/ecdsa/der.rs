@@ -0,0 +1,271 @@
+//! DER encoding of signatures - `SEQUENCE { r INTEGER, s INTEGER }`
+
+use super::Signature;
+use crate::der::{parse_tlv, INTEGER_TAG, SEQUENCE_TAG};
+use crate::{DerError, Error, Scalar};
+
+/// The length of the longest encoding - both integers padded to 33 bytes
+pub const MAX_LEN: usize = 2 + 2 * (2 + 33);
+
+/// Writes unsigned integer in minimal two's complement encoding into zeroed `out` returning the
+/// number of bytes written
+fn encode_integer(out: &mut [u8], value: Scalar) -> usize {
+    let bytes = value.to_be_bytes();
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(31);
+    let pad = usize::from(bytes[start] & 0x80 != 0);
+    let len = 32 - start + pad;
+    out[0] = INTEGER_TAG;
+    out[1] = len as u8;
+    out[(2 + pad)..(2 + len)].copy_from_slice(&bytes[start..]);
+    2 + len
+}
+
+pub(super) fn encode(signature: &Signature) -> ([u8; MAX_LEN], usize) {
+    let mut out = [0; MAX_LEN];
+    let r_len = encode_integer(&mut out[2..], signature.r);
+    let s_len = encode_integer(&mut out[(2 + r_len)..], signature.s);
+    out[0] = SEQUENCE_TAG;
+    out[1] = (r_len + s_len) as u8;
+    (out, 2 + r_len + s_len)
+}
+
+fn parse_integer(bytes: &[u8]) -> Result<(Scalar, &[u8]), DerError> {
+    let (integer, rest) = parse_tlv(bytes, INTEGER_TAG, DerError::InvalidIntegerTag)?;
+    match integer {
+        [] => return Err(DerError::EmptyInteger),
+        [first, ..] if first & 0x80 != 0 => return Err(DerError::NegativeInteger),
+        [0, second, ..] if second & 0x80 == 0 => return Err(DerError::NonMinimalInteger),
+        _ => (),
+    }
+    let integer = if integer[0] == 0 { &integer[1..] } else { integer };
+    if integer.len() > 32 {
+        return Err(DerError::IntegerOutOfRange);
+    }
+    let mut bytes = [0; 32];
+    bytes[(32 - integer.len())..].copy_from_slice(integer);
+    match Scalar::from_be_bytes(&bytes) {
+        Some(scalar) if !scalar.is_zero() => Ok((scalar, rest)),
+        _ => Err(DerError::IntegerOutOfRange),
+    }
+}
+
+pub(super) fn decode_strict(bytes: &[u8]) -> Result<Signature, Error> {
+    let (content, rest) = parse_tlv(bytes, SEQUENCE_TAG, DerError::InvalidSequenceTag)?;
+    let (r, content) = parse_integer(content)?;
+    let (s, content) = parse_integer(content)?;
+    if !content.is_empty() || !rest.is_empty() {
+        return Err(DerError::TrailingData.into());
+    }
+    Ok(Signature { r, s })
+}
+
+/// Reads the length of lax-encoded integer, long form may contain leading zeros
+fn parse_lax_length(bytes: &[u8], pos: &mut usize) -> Result<usize, DerError> {
+    let first = *bytes.get(*pos).ok_or(DerError::UnexpectedEnd)?;
+    *pos += 1;
+    if first & 0x80 == 0 {
+        return Ok(first.into());
+    }
+    let mut length_bytes = bytes[*pos..].get(..usize::from(first & 0x7F)).ok_or(DerError::UnexpectedEnd)?;
+    *pos += length_bytes.len();
+    while let [0, rest @ ..] = length_bytes {
+        length_bytes = rest;
+    }
+    if length_bytes.len() >= core::mem::size_of::<usize>() {
+        return Err(DerError::UnexpectedEnd);
+    }
+    Ok(length_bytes.iter().fold(0usize, |acc, &b| acc << 8 | usize::from(b)))
+}
+
+/// Reads a lax-encoded integer returning its bytes without leading zeros
+fn parse_lax_integer<'a>(bytes: &'a [u8], pos: &mut usize) -> Result<&'a [u8], DerError> {
+    if bytes.get(*pos) != Some(&INTEGER_TAG) {
+        return Err(if *pos == bytes.len() { DerError::UnexpectedEnd } else { DerError::InvalidIntegerTag });
+    }
+    *pos += 1;
+    let length = parse_lax_length(bytes, pos)?;
+    let mut integer = bytes[*pos..].get(..length).ok_or(DerError::UnexpectedEnd)?;
+    *pos += length;
+    while let [0, rest @ ..] = integer {
+        integer = rest;
+    }
+    Ok(integer)
+}
+
+fn lax_scalar(integer: &[u8]) -> Result<Scalar, DerError> {
+    if integer.len() > 32 {
+        return Err(DerError::IntegerOutOfRange);
+    }
+    let mut bytes = [0; 32];
+    bytes[(32 - integer.len())..].copy_from_slice(integer);
+    match Scalar::from_be_bytes(&bytes) {
+        Some(scalar) if !scalar.is_zero() => Ok(scalar),
+        _ => Err(DerError::IntegerOutOfRange),
+    }
+}
+
+/// Port of `ecdsa_signature_parse_der_lax` from libsecp256k1 contrib.
+///
+/// The sequence length is skipped, integers are treated as unsigned with arbitrary leading zeros
+/// and anything after `s` is ignored. libsecp256k1 returns an unverifiable all-zero signature for
+/// out-of-range integers, we return an error instead.
+pub(super) fn decode_lax(bytes: &[u8]) -> Result<Signature, Error> {
+    let mut pos = 0;
+    match bytes.first() {
+        Some(&SEQUENCE_TAG) => pos += 1,
+        Some(_) => return Err(DerError::InvalidSequenceTag.into()),
+        None => return Err(DerError::UnexpectedEnd.into()),
+    }
+    let length = *bytes.get(pos).ok_or(DerError::UnexpectedEnd)?;
+    pos += 1;
+    if length & 0x80 != 0 {
+        let skip = usize::from(length & 0x7F);
+        if skip > bytes.len() - pos {
+            return Err(DerError::UnexpectedEnd.into());
+        }
+        pos += skip;
+    }
+    let r = parse_lax_integer(bytes, &mut pos)?;
+    let s = parse_lax_integer(bytes, &mut pos)?;
+    Ok(Signature {
+        r: lax_scalar(r)?,
+        s: lax_scalar(s)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Signature;
+    use crate::{DerError, Error, Scalar};
+    use crate::test_util::hex;
+    use crate::test_utils::DeterministicRng;
+
+    fn to_der(signature: &Signature) -> Vec<u8> {
+        let (bytes, len) = signature.to_der_array();
+        assert!(bytes[len..].iter().all(|&b| b == 0));
+        bytes[..len].to_vec()
+    }
+
+    /// Produced by OpenSSL, `s` needs padding
+    const OPENSSL: &str = "3045022057cae5a374a1bd31781a2d3b610fbdbc41da8a41da81ec3ee8042cefb9a3a620022100e413f01ff8d13abffdafe485bc98fb024e1bd9303a41945496128634966a2888";
+
+    #[test]
+    fn external_round_trip() {
+        let der = hex(OPENSSL);
+        let signature = Signature::from_der(&der).unwrap();
+        assert_eq!(signature.r().to_be_bytes()[..], der[4..36]);
+        assert_eq!(signature.s().to_be_bytes()[..], der[39..]);
+        assert_eq!(to_der(&signature), der);
+    }
+
+    #[test]
+    fn random_round_trip() {
+        let mut rng = DeterministicRng::new(42);
+        for i in 0..256 {
+            let mut r = Scalar::random(&mut rng);
+            // exercise short integers too
+            if i % 4 == 0 {
+                r = Scalar::from(u64::from(i as u8) + 1);
+            }
+            let signature = Signature::from_scalars(r, Scalar::random(&mut rng)).unwrap();
+            let der = to_der(&signature);
+            assert!(der.len() <= 72);
+            assert_eq!(Signature::from_der(&der), Ok(signature));
+        }
+    }
+
+    #[test]
+    fn smallest_signature() {
+        let signature = Signature::from_scalars(Scalar::ONE, Scalar::from(0x80)).unwrap();
+        assert_eq!(to_der(&signature), hex("300702010102020080"));
+    }
+
+    #[test]
+    fn strict_violations() {
+        let valid = hex(OPENSSL);
+        let with = |f: &dyn Fn(&mut Vec<u8>)| {
+            let mut der = valid.clone();
+            f(&mut der);
+            der
+        };
+        let cases: Vec<(Vec<u8>, DerError)> = vec![
+            (Vec::new(), DerError::UnexpectedEnd),
+            (valid[..(valid.len() - 1)].to_vec(), DerError::UnexpectedEnd),
+            (with(&|der| der[0] = 0x31), DerError::InvalidSequenceTag),
+            (with(&|der| der[2] = 0x03), DerError::InvalidIntegerTag),
+            (with(&|der| der[36] = 0x03), DerError::InvalidIntegerTag),
+            // garbage after the sequence
+            (with(&|der| der.push(0x00)), DerError::TrailingData),
+            // garbage inside the sequence
+            (with(&|der| { der[1] += 1; der.push(0x00) }), DerError::TrailingData),
+            // overlong sequence length
+            (with(&|der| { der.insert(1, 0x81); }), DerError::NonMinimalLength),
+            (with(&|der| { der[1] = 0x80; }), DerError::NonMinimalLength),
+            // s without the padding byte is negative
+            (with(&|der| { der[1] -= 1; der[37] -= 1; der.remove(38); }), DerError::NegativeInteger),
+            // r with an extra padding byte
+            (with(&|der| { der[1] += 1; der[3] += 1; der.insert(4, 0x00); }), DerError::NonMinimalInteger),
+            // s with two padding bytes
+            (with(&|der| { der[1] += 1; der[37] += 1; der.insert(38, 0x00); }), DerError::NonMinimalInteger),
+            (hex("3006020002020080"), DerError::EmptyInteger),
+            (hex("3006020101020180"), DerError::NegativeInteger),
+            // zero
+            (hex("300702010002020080"), DerError::IntegerOutOfRange),
+            // the curve order
+            (hex("3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141020101"), DerError::IntegerOutOfRange),
+            // 2^256
+            (hex("30260221010000000000000000000000000000000000000000000000000000000000000000020101"), DerError::IntegerOutOfRange),
+        ];
+        for (der, error) in cases {
+            assert_eq!(Signature::from_der(&der), Err(Error::InvalidDer(error)), "{:02x?}", der);
+        }
+    }
+
+    /// Encodings of the kinds found in transactions before BIP66
+    #[test]
+    fn lax_accepts_legacy_encodings() {
+        let valid = hex(OPENSSL);
+        let expected = Signature::from_der(&valid).unwrap();
+        let with = |f: &dyn Fn(&mut Vec<u8>)| {
+            let mut der = valid.clone();
+            f(&mut der);
+            der
+        };
+        let cases = [
+            // sighash type byte appended
+            with(&|der| der.push(0x01)),
+            // overlong sequence length
+            with(&|der| der.insert(1, 0x81)),
+            // wrong sequence length
+            with(&|der| der[1] = 0x44),
+            // extra padding of r
+            with(&|der| { der[1] += 2; der[3] += 2; der.insert(4, 0x00); der.insert(4, 0x00); }),
+            // s not padded so it looks negative
+            with(&|der| { der[1] -= 1; der[37] -= 1; der.remove(38); }),
+            // long form length of r with leading zero
+            with(&|der| { der[1] += 2; der.splice(3..4, [0x82, 0x00, 0x20].iter().copied()); }),
+        ];
+        for der in &cases {
+            assert!(Signature::from_der(der).is_err(), "{:02x?}", der);
+            assert_eq!(Signature::from_der_lax(der), Ok(expected), "{:02x?}", der);
+        }
+        assert_eq!(Signature::from_der_lax(&valid), Ok(expected));
+    }
+
+    #[test]
+    fn lax_rejects_garbage() {
+        let cases: &[(&[u8], DerError)] = &[
+            (&[], DerError::UnexpectedEnd),
+            (&[0x30], DerError::UnexpectedEnd),
+            (&[0x31, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01], DerError::InvalidSequenceTag),
+            (&[0x30, 0x06, 0x03, 0x01, 0x01, 0x02, 0x01, 0x01], DerError::InvalidIntegerTag),
+            (&[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x02, 0x01], DerError::UnexpectedEnd),
+            (&[0x30, 0x06, 0x02, 0x01, 0x01], DerError::UnexpectedEnd),
+            (&[0x30, 0x85, 0x02, 0x01, 0x01], DerError::UnexpectedEnd),
+            (&[0x30, 0x06, 0x02, 0x01, 0x00, 0x02, 0x01, 0x01], DerError::IntegerOutOfRange),
+        ];
+        for (der, error) in cases {
+            assert_eq!(Signature::from_der_lax(der), Err(Error::InvalidDer(*error)), "{:02x?}", der);
+        }
+    }
+}
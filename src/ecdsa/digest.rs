@@ -0,0 +1,81 @@
+//! Signing and verification of messages hashed by any hash function with 32-byte output
+//!
+//! The caller feeds the message into the hasher and the finalized output is the 32-byte message
+//! hash passed to `sign` or `verify`, so signing through `sha2::Sha256` is identical to
+//! `sign_message_sha256`. The RFC 6979 nonce is derived from the output using HMAC-SHA256 the
+//! same way libsecp256k1 does for any 32-byte message hash.
+
+use digest::consts::U32;
+use digest::Digest;
+use super::{sign, verify, Signature};
+use crate::{Error, Message, PublicKey, SecretKey};
+
+/// Signs a message fed into a hasher, the counterpart of `signature::DigestSigner`
+pub trait DigestSigner<D, S> {
+    /// Finalizes `digest` and signs the output
+    fn sign_digest(&self, digest: D) -> S;
+}
+
+/// Verifies a signature of a message fed into a hasher, the counterpart of
+/// `signature::DigestVerifier`
+pub trait DigestVerifier<D, S> {
+    /// Finalizes `digest` and verifies the signature of the output
+    fn verify_digest(&self, digest: D, signature: &S) -> Result<(), Error>;
+}
+
+/// Finalizes `digest` and signs the output using RFC 6979 nonce
+pub fn sign_digest<D: Digest<OutputSize = U32>>(digest: D, secret_key: &SecretKey) -> Signature {
+    sign(&Message::from_digest(digest.finalize().into()), secret_key)
+}
+
+/// Finalizes `digest` and verifies the signature of the output, see `verify`
+pub fn verify_digest<D: Digest<OutputSize = U32>>(digest: D, signature: &Signature, public_key: &PublicKey) -> Result<(), Error> {
+    verify(&Message::from_digest(digest.finalize().into()), signature, public_key)
+}
+
+impl<D: Digest<OutputSize = U32>> DigestSigner<D, Signature> for SecretKey {
+    fn sign_digest(&self, digest: D) -> Signature {
+        sign_digest(digest, self)
+    }
+}
+
+impl<D: Digest<OutputSize = U32>> DigestVerifier<D, Signature> for PublicKey {
+    fn verify_digest(&self, digest: D, signature: &Signature) -> Result<(), Error> {
+        verify_digest(digest, signature, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::Sha256;
+    use sha3::Sha3_256;
+    use digest::Digest;
+    use super::{sign_digest, verify_digest, DigestSigner, DigestVerifier};
+    use crate::ecdsa::{sign, sign_message_sha256, verify_message_sha256, Signature};
+    use crate::{Message, SecretKey};
+
+    #[test]
+    fn sha256_matches_concrete() {
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let hasher = Sha256::new().chain_update(b"hello ").chain_update(b"world");
+        let signature = sign_digest(hasher.clone(), &secret_key);
+        assert_eq!(signature, sign_message_sha256(b"hello world", &secret_key));
+        assert_eq!(DigestSigner::<_, Signature>::sign_digest(&secret_key, hasher.clone()), signature);
+        assert_eq!(verify_message_sha256(b"hello world", &signature, &secret_key.public_key()), Ok(()));
+        assert_eq!(verify_digest(hasher, &signature, &secret_key.public_key()), Ok(()));
+    }
+
+    #[test]
+    fn sha3() {
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let public_key = secret_key.public_key();
+        let hasher = Sha3_256::new().chain_update(b"hello world");
+        let signature: Signature = secret_key.sign_digest(hasher.clone());
+        assert_eq!(signature, sign(&Message::from_digest(Sha3_256::digest(b"hello world").into()), &secret_key));
+        assert_eq!(public_key.verify_digest(hasher, &signature), Ok(()));
+        let other = Sha3_256::new().chain_update(b"hello world!");
+        assert!(public_key.verify_digest(other, &signature).is_err());
+        // the same message hashed by a different function
+        assert!(verify_digest(Sha256::new().chain_update(b"hello world"), &signature, &public_key).is_err());
+    }
+}
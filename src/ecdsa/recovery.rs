@@ -0,0 +1,380 @@
+//! Recoverable signatures - signatures carrying enough information to compute the public key
+
+use super::{message_scalar, sign_internal, Signature};
+use crate::nonce::NonceGenerator;
+#[cfg(feature = "hashes")]
+use crate::nonce::Rfc6979;
+use crate::params::N;
+use crate::{Error, Message, Point, PublicKey, SecretKey, Zp, G};
+
+/// Identifies which of the (up to four) points `R` matches `r` of the signature.
+///
+/// The lowest bit is the parity of `R.y`, the second bit is set if `R.x` was not less than the
+/// curve order (this is extremely rare).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct RecoveryId(u8);
+
+impl RecoveryId {
+    /// Returns `Error::InvalidRecoveryId` if `id` is greater than 3
+    pub fn from_u8(id: u8) -> Result<Self, Error> {
+        if id > 3 {
+            Err(Error::InvalidRecoveryId)
+        } else {
+            Ok(RecoveryId(id))
+        }
+    }
+
+    /// Converts Ethereum `v` value.
+    ///
+    /// Accepts raw ids 0 and 1, legacy 27 and 28 and EIP-155 values `chain_id * 2 + 35 + id`.
+    pub fn from_ethereum_v(v: u64) -> Result<Self, Error> {
+        match v {
+            0 | 1 => Ok(RecoveryId(v as u8)),
+            27 | 28 => Ok(RecoveryId((v - 27) as u8)),
+            35..=u64::MAX => Ok(RecoveryId(((v - 35) % 2) as u8)),
+            _ => Err(Error::InvalidRecoveryId),
+        }
+    }
+
+    pub fn to_u8(self) -> u8 {
+        self.0
+    }
+
+    /// Is `R.y` odd?
+    pub fn is_y_odd(self) -> bool {
+        self.0 & 1 != 0
+    }
+
+    /// Was `R.x` reduced modulo the curve order?
+    pub fn is_x_reduced(self) -> bool {
+        self.0 & 2 != 0
+    }
+
+    fn from_nonce_point(big_r: Point) -> Self {
+        let (_, overflow) = big_r.x().to_scalar_overflowing();
+        RecoveryId(u8::from(overflow) << 1 | u8::from(big_r.y().is_odd()))
+    }
+}
+
+/// ECDSA signature together with its recovery id
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct RecoverableSignature {
+    signature: Signature,
+    recovery_id: RecoveryId,
+}
+
+impl RecoverableSignature {
+    pub fn new(signature: Signature, recovery_id: RecoveryId) -> Self {
+        RecoverableSignature {
+            signature,
+            recovery_id,
+        }
+    }
+
+    /// Parses 64-byte compact signature with separately stored recovery id
+    pub fn from_compact(bytes: &[u8; 64], recovery_id: RecoveryId) -> Result<Self, Error> {
+        Signature::from_compact(bytes).map(|signature| Self::new(signature, recovery_id))
+    }
+
+    /// Serializes the signature as 64-byte `r || s` returning the recovery id separately
+    pub fn serialize_compact(&self) -> ([u8; 64], RecoveryId) {
+        (self.signature.serialize_compact(), self.recovery_id)
+    }
+
+    pub fn recovery_id(&self) -> RecoveryId {
+        self.recovery_id
+    }
+
+    /// Computes the public key that created this signature of `msg`.
+    ///
+    /// Returns `Error::InvalidSignature` if no such key exists.
+    pub fn recover(&self, msg: &Message) -> Result<PublicKey, Error> {
+        let r = self.signature.r;
+        let big_r = self.nonce_point()?;
+        let z = message_scalar(msg);
+        let r_inv = r.multiplicative_inverse();
+        let point = big_r * (self.signature.s * r_inv) + G * (-z * r_inv);
+        PublicKey::from_point(point).ok_or(Error::InvalidSignature)
+    }
+
+    /// Reconstructs the point `R` from `r` and the recovery id
+    pub(crate) fn nonce_point(&self) -> Result<Point, Error> {
+        let r = self.signature.r.to_u256();
+        let x = if self.recovery_id.is_x_reduced() {
+            let (x, overflow) = r.overflowing_add(N);
+            if overflow {
+                return Err(Error::InvalidSignature);
+            }
+            x
+        } else {
+            r
+        };
+        let x = Zp::checked_from(x).ok_or(Error::InvalidSignature)?;
+        Point::from_x(x, self.recovery_id.is_y_odd()).ok_or(Error::InvalidSignature)
+    }
+
+    /// Serializes the signature in the EIP-2098 compact form `r || yParityAndS`.
+    ///
+    /// The parity of `R.y` is stored in the top bit of `s` which is free only if `s` is low, so a
+    /// high `s` is negated and the parity flipped first - the result encodes an equivalent
+    /// signature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the recovery id says `R.x` was reduced, EIP-2098 can't represent it. Honestly
+    /// generated signatures have it with negligible probability.
+    pub fn to_eip2098(&self) -> [u8; 64] {
+        assert!(!self.recovery_id.is_x_reduced(), "EIP-2098 can't represent reduced R.x");
+        let mut signature = self.signature;
+        let flipped = signature.normalize_s();
+        let mut bytes = signature.serialize_compact();
+        if self.recovery_id.is_y_odd() != flipped {
+            bytes[32] |= 0x80;
+        }
+        bytes
+    }
+
+    /// Parses the EIP-2098 compact form `r || yParityAndS`.
+    ///
+    /// Returns `Error::HighS` if `s` is not low - the encoding of such signature would be
+    /// ambiguous - and the same errors as `Signature::from_compact` for invalid `r` or `s`.
+    pub fn from_eip2098(bytes: &[u8; 64]) -> Result<Self, Error> {
+        let mut compact = *bytes;
+        let odd = compact[32] & 0x80 != 0;
+        compact[32] &= 0x7F;
+        let signature = Signature::from_compact(&compact)?;
+        if !signature.is_low_s() {
+            return Err(Error::HighS);
+        }
+        Ok(Self::new(signature, RecoveryId(u8::from(odd))))
+    }
+
+    /// Drops the recovery id
+    pub fn to_standard(&self) -> Signature {
+        self.signature
+    }
+}
+
+impl From<RecoverableSignature> for Signature {
+    fn from(signature: RecoverableSignature) -> Self {
+        signature.signature
+    }
+}
+
+/// Signs the 32-byte message hash using RFC 6979 nonce, computing the recovery id
+#[cfg(feature = "hashes")]
+pub fn sign_recoverable(msg: &Message, secret_key: &SecretKey) -> RecoverableSignature {
+    sign_recoverable_with(msg, secret_key, &mut Rfc6979::new())
+}
+
+/// Signs the 32-byte message hash using nonces produced by `nonce_generator`, computing the
+/// recovery id
+pub fn sign_recoverable_with(msg: &Message, secret_key: &SecretKey, nonce_generator: &mut impl NonceGenerator) -> RecoverableSignature {
+    let (signature, big_r) = sign_internal(msg, secret_key, nonce_generator);
+    RecoverableSignature::new(signature, RecoveryId::from_nonce_point(big_r))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sign_recoverable_with, RecoverableSignature, RecoveryId};
+    use crate::ecdsa::{sign_with, verify, Signature};
+    use crate::nonce::RngNonce;
+    use crate::test_util::hex32;
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, Message, Point, PublicKey, Scalar, SecretKey, Zp, G};
+
+    #[test]
+    fn recovery_id_range() {
+        for id in 0..4 {
+            assert_eq!(RecoveryId::from_u8(id).unwrap().to_u8(), id);
+        }
+        assert_eq!(RecoveryId::from_u8(4), Err(Error::InvalidRecoveryId));
+        assert_eq!(RecoveryId::from_u8(27), Err(Error::InvalidRecoveryId));
+    }
+
+    #[test]
+    fn recovery_id_from_point() {
+        assert_eq!(RecoveryId::from_nonce_point(G).to_u8(), 0);
+        assert_eq!(RecoveryId::from_nonce_point(-G).to_u8(), 1);
+
+        // n + 2 is the smallest x greater than n on the curve
+        let x = Zp::from_be_bytes(&hex32("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364143")).unwrap();
+        let even = Point::from_x(x, false).unwrap();
+        assert_eq!(RecoveryId::from_nonce_point(even).to_u8(), 2);
+        assert_eq!(RecoveryId::from_nonce_point(-even).to_u8(), 3);
+        assert!(RecoveryId::from_u8(3).unwrap().is_x_reduced());
+        assert!(RecoveryId::from_u8(3).unwrap().is_y_odd());
+    }
+
+    #[test]
+    fn sign_and_serialize() {
+        let secret_key = SecretKey::from_bytes(&hex32("1e99423a4ed27608a15a2616a2b0e9e52ced330ac530edcc32c8ffc6a526aedd")).unwrap();
+        let mut ids = [false; 2];
+        for i in 0..8 {
+            let signature = sign_recoverable_with(&Message::from_digest([i; 32]), &secret_key, &mut RngNonce(DeterministicRng::new(i.into())));
+            assert_eq!(Signature::from(signature), sign_with(&Message::from_digest([i; 32]), &secret_key, &mut RngNonce(DeterministicRng::new(i.into()))));
+            let (compact, id) = signature.serialize_compact();
+            assert_eq!(RecoverableSignature::from_compact(&compact, id), Ok(signature));
+            assert!(id.to_u8() < 2);
+            ids[usize::from(id.to_u8())] = true;
+        }
+        // both parities happen
+        assert_eq!(ids, [true, true]);
+    }
+
+    #[test]
+    fn recover_own_signatures() {
+        let mut rng = DeterministicRng::new(42);
+        for i in 0..8 {
+            let secret_key = SecretKey::from_scalar(Scalar::random(&mut rng)).unwrap();
+            let msg = Message::from_digest(Scalar::random(&mut rng).to_be_bytes());
+            let signature = sign_recoverable_with(&msg, &secret_key, &mut RngNonce(DeterministicRng::new(i)));
+            assert_eq!(signature.recover(&msg), Ok(secret_key.public_key()));
+            let mut other_msg = msg.to_bytes();
+            other_msg[0] ^= 1;
+            assert_ne!(signature.recover(&Message::from_digest(other_msg)), Ok(secret_key.public_key()));
+        }
+    }
+
+    /// Nobody knows the nonce for `R.x >= n`, the key is computed from an arbitrary signature
+    #[test]
+    fn recover_reduced_x() {
+        let x = Zp::from_be_bytes(&hex32("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364143")).unwrap();
+        let r = Scalar::from(2);
+        let s = Scalar::from(42);
+        let msg = Message::from_digest([1; 32]);
+        let z = Scalar::from_be_bytes_reduce(msg.as_bytes());
+        for &odd in &[false, true] {
+            let big_r = Point::from_x(x, odd).unwrap();
+            let public_key = PublicKey::from_point((big_r * s + G * -z) * r.multiplicative_inverse()).unwrap();
+            let signature = Signature::from_scalars(r, s).unwrap();
+            assert_eq!(verify(&msg, &signature, &public_key), Ok(()));
+
+            let id = RecoveryId::from_u8(2 + u8::from(odd)).unwrap();
+            assert_eq!(RecoverableSignature::new(signature, id).recover(&msg), Ok(public_key));
+        }
+    }
+
+    #[test]
+    fn recover_invalid() {
+        // r + n overflows 256 bits or is not less than p
+        for r in &["fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140", "000000000000000000000000000000014551231950b75fc4402da17300000000"] {
+            let r = Scalar::from_be_bytes(&hex32(r)).unwrap();
+            let signature = Signature::from_scalars(r, Scalar::ONE).unwrap();
+            let signature = RecoverableSignature::new(signature, RecoveryId::from_u8(2).unwrap());
+            assert_eq!(signature.recover(&Message::from_digest([1; 32])), Err(Error::InvalidSignature));
+        }
+
+        // x = 5 is not on the curve
+        let signature = Signature::from_scalars(Scalar::from(5), Scalar::ONE).unwrap();
+        let signature = RecoverableSignature::new(signature, RecoveryId::from_u8(0).unwrap());
+        assert_eq!(signature.recover(&Message::from_digest([1; 32])), Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn ethereum_v() {
+        assert_eq!(RecoveryId::from_ethereum_v(27).unwrap().to_u8(), 0);
+        assert_eq!(RecoveryId::from_ethereum_v(28).unwrap().to_u8(), 1);
+        // chain id 1
+        assert_eq!(RecoveryId::from_ethereum_v(37).unwrap().to_u8(), 0);
+        assert_eq!(RecoveryId::from_ethereum_v(38).unwrap().to_u8(), 1);
+        assert_eq!(RecoveryId::from_ethereum_v(2), Err(Error::InvalidRecoveryId));
+        assert_eq!(RecoveryId::from_ethereum_v(29), Err(Error::InvalidRecoveryId));
+        assert_eq!(RecoveryId::from_ethereum_v(34), Err(Error::InvalidRecoveryId));
+    }
+
+    /// The example transaction from EIP-155
+    #[test]
+    fn eip155_transaction() {
+        let msg = Message::from_digest(hex32("daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53"));
+        let mut compact = [0; 64];
+        compact[..32].copy_from_slice(&hex32("28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276"));
+        compact[32..].copy_from_slice(&hex32("67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"));
+        let signature = RecoverableSignature::from_compact(&compact, RecoveryId::from_ethereum_v(37).unwrap()).unwrap();
+        let public_key = signature.recover(&msg).unwrap();
+        assert_eq!(public_key, SecretKey::from_bytes(&[0x46; 32]).unwrap().public_key());
+        #[cfg(all(feature = "keccak", feature = "alloc"))]
+        assert_eq!(public_key.to_eth_address_string(), "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F");
+    }
+
+    /// The examples from EIP-2098
+    #[test]
+    fn eip2098_vectors() {
+        let vectors = [
+            ("Hello World", "68a020a209d3d56c46f38cc50a33f704f4a9a10a59377f8dd762ac66910e9b90", "7e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea52064", 27, "7e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea52064"),
+            ("It's a small(er) world", "9328da16089fcba9bececa81663203989f2df5fe1faa6291a45381c81bd17f76", "139c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793", 28, "939c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793"),
+        ];
+        for (msg, r, s, v, y_parity_and_s) in &vectors {
+            let mut compact = [0; 64];
+            compact[..32].copy_from_slice(&hex32(r));
+            compact[32..].copy_from_slice(&hex32(s));
+            let signature = RecoverableSignature::from_compact(&compact, RecoveryId::from_ethereum_v(*v).unwrap()).unwrap();
+            let mut expected = [0; 64];
+            expected[..32].copy_from_slice(&hex32(r));
+            expected[32..].copy_from_slice(&hex32(y_parity_and_s));
+            assert_eq!(signature.to_eip2098(), expected, "{}", msg);
+            assert_eq!(RecoverableSignature::from_eip2098(&expected), Ok(signature));
+
+            // EIP-191 personal message
+            #[cfg(feature = "keccak")]
+            {
+                use sha3::{Digest, Keccak256};
+                let secret_key = SecretKey::from_bytes(&hex32("1234567890123456789012345678901234567890123456789012345678901234")).unwrap();
+                let mut hasher = Keccak256::new();
+                hasher.update(format!("\x19Ethereum Signed Message:\n{}{}", msg.len(), msg));
+                let hash = Message::from_digest(hasher.finalize().into());
+                assert_eq!(signature.recover(&hash), Ok(secret_key.public_key()));
+            }
+        }
+    }
+
+    #[test]
+    fn eip2098_round_trip() {
+        let mut rng = DeterministicRng::new(210);
+        let mut flipped = 0;
+        for i in 0..64 {
+            let secret_key = SecretKey::from_scalar(Scalar::random(&mut rng)).unwrap();
+            let msg = Message::from_digest(Scalar::random(&mut rng).to_be_bytes());
+            let signature = sign_recoverable_with(&msg, &secret_key, &mut RngNonce(DeterministicRng::new(i)));
+            let compact = signature.to_eip2098();
+            assert_eq!(RecoverableSignature::from_eip2098(&compact), Ok(signature));
+            assert_eq!(compact[32] & 0x80 != 0, signature.recovery_id().is_y_odd());
+
+            // the high-S twin has the opposite parity and encodes to the same bytes
+            let low = signature.to_standard();
+            let high = Signature::from_scalars(low.r(), -low.s()).unwrap();
+            let twin = RecoverableSignature::new(high, RecoveryId::from_u8(signature.recovery_id().to_u8() ^ 1).unwrap());
+            assert_eq!(twin.recover(&msg), Ok(secret_key.public_key()));
+            assert_eq!(twin.to_eip2098(), compact);
+            flipped += usize::from(signature.recovery_id().is_y_odd());
+        }
+        // both parities happen
+        assert!(flipped > 0 && flipped < 64);
+    }
+
+    #[test]
+    fn eip2098_invalid() {
+        // s = (n - 1) / 2 + 1 is the smallest high value
+        let mut bytes = [0; 64];
+        bytes[31] = 1;
+        bytes[32..].copy_from_slice(&hex32("7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a1"));
+        assert_eq!(RecoverableSignature::from_eip2098(&bytes), Err(Error::HighS));
+        bytes[32] |= 0x80;
+        assert_eq!(RecoverableSignature::from_eip2098(&bytes), Err(Error::HighS));
+        bytes[63] = 0xA0;
+        assert!(RecoverableSignature::from_eip2098(&bytes).unwrap().recovery_id().is_y_odd());
+        // zero r or s
+        assert_eq!(RecoverableSignature::from_eip2098(&[0; 64]), Err(Error::ZeroScalar));
+        let mut bytes = [0; 64];
+        bytes[31] = 1;
+        bytes[32] = 0x80;
+        assert_eq!(RecoverableSignature::from_eip2098(&bytes), Err(Error::ZeroScalar));
+    }
+
+    #[test]
+    #[should_panic(expected = "reduced R.x")]
+    fn eip2098_reduced_x() {
+        let signature = Signature::from_scalars(Scalar::from(2), Scalar::ONE).unwrap();
+        RecoverableSignature::new(signature, RecoveryId::from_u8(2).unwrap()).to_eip2098();
+    }
+}
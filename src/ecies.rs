@@ -0,0 +1,117 @@
+//! Hybrid public key encryption (ECIES)
+//!
+//! The sender generates an ephemeral keypair, performs ECDH with the recipient's public key and
+//! derives a symmetric key used to encrypt the payload with ChaCha20-Poly1305. The output is
+//! `ephemeral_public_key (33 bytes, compressed) || ciphertext || tag (16 bytes)`.
+//!
+//! The symmetric key is derived as
+//! `HKDF-SHA256(salt = "toy-secp256k1/ecies", ikm = x, info = E || P)` where `x` is the
+//! big-endian x coordinate of the shared point, `E` is the compressed ephemeral public key and
+//! `P` is the compressed public key of the recipient. Since every key is used only once the
+//! ChaCha20-Poly1305 nonce is 12 zero bytes.
+
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use crate::chacha20poly1305::{self, TAG_LEN};
+use crate::hashes::hkdf_sha256;
+use crate::{ecdh, Error, PublicKey, Scalar, SecretKey};
+
+/// HKDF salt identifying this scheme
+pub const KDF_SALT: &[u8] = b"toy-secp256k1/ecies";
+
+const PUBLIC_KEY_LEN: usize = 33;
+const NONCE: [u8; 12] = [0; 12];
+
+/// Length of the encrypted message minus the length of the plaintext
+pub const OVERHEAD: usize = PUBLIC_KEY_LEN + TAG_LEN;
+
+fn derive_key(secret_key: &SecretKey, public_key: &PublicKey, ephemeral: &PublicKey, recipient: &PublicKey) -> [u8; 32] {
+    let x = ecdh::shared_x(secret_key, public_key);
+    let mut info = [0; 2 * PUBLIC_KEY_LEN];
+    info[..PUBLIC_KEY_LEN].copy_from_slice(&ephemeral.serialize());
+    info[PUBLIC_KEY_LEN..].copy_from_slice(&recipient.serialize());
+    let mut key = [0; 32];
+    hkdf_sha256(KDF_SALT, &x, &[&info], &mut key);
+    key
+}
+
+/// Encrypts `plaintext` to `recipient`, authenticating also `aad` which is not included in the
+/// output.
+pub fn encrypt<R: RngCore + CryptoRng>(recipient: &PublicKey, plaintext: &[u8], aad: &[u8], rng: &mut R) -> Vec<u8> {
+    let ephemeral_secret = SecretKey::from_scalar(Scalar::random(rng)).expect("random scalar is never zero");
+    let ephemeral = ephemeral_secret.public_key();
+    let key = derive_key(&ephemeral_secret, recipient, &ephemeral, recipient);
+
+    let mut blob = Vec::with_capacity(OVERHEAD + plaintext.len());
+    blob.extend_from_slice(&ephemeral.serialize());
+    blob.extend_from_slice(plaintext);
+    let tag = chacha20poly1305::seal(&key, &NONCE, aad, &mut blob[PUBLIC_KEY_LEN..]);
+    blob.extend_from_slice(&tag);
+    blob
+}
+
+/// Decrypts the output of `encrypt` using the secret key of the recipient.
+///
+/// Returns `Error::InvalidLength` if the blob is too short to contain the ephemeral key and the
+/// tag, the error of `PublicKey::from_sec1_bytes` if the ephemeral key is invalid and
+/// `Error::DecryptionFailed` if the ciphertext, the tag or `aad` were modified or the key is wrong.
+pub fn decrypt(secret_key: &SecretKey, blob: &[u8], aad: &[u8]) -> Result<Vec<u8>, Error> {
+    if blob.len() < OVERHEAD {
+        return Err(Error::InvalidLength(blob.len()));
+    }
+    let (ephemeral, rest) = blob.split_at(PUBLIC_KEY_LEN);
+    let (ciphertext, tag) = rest.split_at(rest.len() - TAG_LEN);
+    let ephemeral = PublicKey::from_sec1_bytes(ephemeral)?;
+    let key = derive_key(secret_key, &ephemeral, &ephemeral, &secret_key.public_key());
+
+    let mut tag_array = [0; TAG_LEN];
+    tag_array.copy_from_slice(tag);
+    let mut plaintext = ciphertext.to_vec();
+    chacha20poly1305::open(&key, &NONCE, aad, &mut plaintext, &tag_array)?;
+    Ok(plaintext)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decrypt, encrypt, OVERHEAD};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, SecretKey};
+
+    #[test]
+    fn round_trip() {
+        let mut rng = DeterministicRng::new(1);
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let message = (0..200).map(|i| i as u8).collect::<Vec<_>>();
+        for len in &[0, 1, 16, 63, 64, 65, 200] {
+            let blob = encrypt(&secret_key.public_key(), &message[..*len], b"aad", &mut rng);
+            assert_eq!(blob.len(), len + OVERHEAD);
+            assert_eq!(decrypt(&secret_key, &blob, b"aad"), Ok(message[..*len].to_vec()), "length {}", len);
+        }
+    }
+
+    #[test]
+    fn tampering_fails() {
+        let mut rng = DeterministicRng::new(2);
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let blob = encrypt(&secret_key.public_key(), b"attack at dawn", b"header", &mut rng);
+        // covers the ephemeral key, the ciphertext and the tag
+        for i in 0..blob.len() {
+            let mut corrupted = blob.clone();
+            corrupted[i] ^= 0x01;
+            let result = decrypt(&secret_key, &corrupted, b"header");
+            assert!(result == Err(Error::DecryptionFailed) || result == Err(Error::NotOnCurve), "byte {}: {:?}", i, result);
+        }
+        assert_eq!(decrypt(&secret_key, &blob, b"Header"), Err(Error::DecryptionFailed));
+        assert_eq!(decrypt(&secret_key, &blob[..(OVERHEAD - 1)], b"header"), Err(Error::InvalidLength(OVERHEAD - 1)));
+        assert_eq!(decrypt(&secret_key, &blob[..(blob.len() - 1)], b"header"), Err(Error::DecryptionFailed));
+    }
+
+    #[test]
+    fn wrong_key_fails() {
+        let mut rng = DeterministicRng::new(3);
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let other = SecretKey::from_bytes(&[0x43; 32]).unwrap();
+        let blob = encrypt(&secret_key.public_key(), b"attack at dawn", &[], &mut rng);
+        assert_eq!(decrypt(&other, &blob, &[]), Err(Error::DecryptionFailed));
+    }
+}
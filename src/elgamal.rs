@@ -0,0 +1,191 @@
+//! ElGamal encryption over the curve
+//!
+//! A point `M` is encrypted to public key `P` as `(r·G, M + r·P)` for random `r`. The scheme is
+//! additively homomorphic: adding ciphertexts component-wise yields an encryption of the sum of
+//! the plaintext points. Small integers can be encrypted as `m·G`, decrypting them then requires
+//! solving the discrete logarithm which is only feasible for small `m`.
+
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+use core::ops::{Add, AddAssign, Neg, Sub};
+use rand_core::{CryptoRng, RngCore};
+use crate::{Point, PublicKey, Scalar, SecretKey, G};
+
+/// Encrypted point
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Ciphertext {
+    c1: Point,
+    c2: Point,
+}
+
+impl Ciphertext {
+    pub fn from_points(c1: Point, c2: Point) -> Self {
+        Ciphertext { c1, c2 }
+    }
+
+    /// The ephemeral point `r·G`
+    pub fn c1(&self) -> Point {
+        self.c1
+    }
+
+    /// The masked message `M + r·P`
+    pub fn c2(&self) -> Point {
+        self.c2
+    }
+}
+
+/// Computes the encryption of the sum of the plaintexts
+impl Add for Ciphertext {
+    type Output = Ciphertext;
+
+    fn add(self, rhs: Ciphertext) -> Self::Output {
+        Ciphertext {
+            c1: self.c1 + rhs.c1,
+            c2: self.c2 + rhs.c2,
+        }
+    }
+}
+
+impl AddAssign for Ciphertext {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Neg for Ciphertext {
+    type Output = Ciphertext;
+
+    fn neg(self) -> Self::Output {
+        Ciphertext {
+            c1: -self.c1,
+            c2: -self.c2,
+        }
+    }
+}
+
+impl Sub for Ciphertext {
+    type Output = Ciphertext;
+
+    fn sub(self, rhs: Ciphertext) -> Self::Output {
+        self + -rhs
+    }
+}
+
+/// Encrypts the point to the public key using fresh randomness.
+pub fn encrypt<R: RngCore + CryptoRng>(public_key: &PublicKey, msg: Point, rng: &mut R) -> Ciphertext {
+    let r = Scalar::random(rng);
+    Ciphertext {
+        c1: G * r,
+        c2: msg + public_key.to_point() * r,
+    }
+}
+
+/// Decrypts the point, computing `c2 - sk·c1`.
+///
+/// There's no integrity protection so decrypting a ciphertext not created for the key results
+/// in a random point.
+pub fn decrypt(secret_key: &SecretKey, ciphertext: &Ciphertext) -> Point {
+    ciphertext.c2 + -(ciphertext.c1 * secret_key.to_scalar())
+}
+
+/// Encrypts `m·G`.
+pub fn encrypt_scalar<R: RngCore + CryptoRng>(public_key: &PublicKey, m: u64, rng: &mut R) -> Ciphertext {
+    encrypt(public_key, G * m, rng)
+}
+
+/// Decrypts the ciphertext and recovers `m` from `m·G` if `m <= max`.
+///
+/// Returns `None` if the decrypted point is not `m·G` for any `m` in the range. The running time
+/// is proportional to the square root of `max`.
+#[cfg(feature = "alloc")]
+pub fn decrypt_scalar(secret_key: &SecretKey, ciphertext: &Ciphertext, max: u64) -> Option<u64> {
+    discrete_log(&decrypt(secret_key, ciphertext), max)
+}
+
+/// Finds `m <= max` such that `point == m·G` using baby-step giant-step algorithm.
+///
+/// Uses `O(√max)` time and memory.
+#[cfg(feature = "alloc")]
+pub fn discrete_log(point: &Point, max: u64) -> Option<u64> {
+    // m = ceil(sqrt(max + 1)) so that m² covers the whole range
+    let mut m = max.isqrt();
+    while m.saturating_mul(m) <= max {
+        m += 1;
+    }
+
+    // baby steps: j·G for j in 0..m
+    let mut baby_steps = BTreeMap::new();
+    let mut current = Point::AT_INFINITY;
+    for j in 0..m {
+        baby_steps.entry(current).or_insert(j);
+        current += G;
+    }
+
+    // giant steps: point - i·m·G
+    let giant_step = -(G * m);
+    let mut current = *point;
+    for i in 0..m {
+        if let Some(j) = baby_steps.get(&current) {
+            let value = i * m + j;
+            return if value <= max { Some(value) } else { None };
+        }
+        current += giant_step;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decrypt, encrypt};
+    #[cfg(feature = "alloc")]
+    use super::{decrypt_scalar, discrete_log, encrypt_scalar};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Scalar, SecretKey, G};
+
+    #[test]
+    fn round_trip() {
+        let mut rng = DeterministicRng::new(1);
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let msg = G * Scalar::random(&mut rng);
+        let ciphertext = encrypt(&secret_key.public_key(), msg, &mut rng);
+        assert_eq!(decrypt(&secret_key, &ciphertext), msg);
+        // randomized
+        assert_ne!(encrypt(&secret_key.public_key(), msg, &mut rng), ciphertext);
+        let other = SecretKey::from_bytes(&[0x43; 32]).unwrap();
+        assert_ne!(decrypt(&other, &ciphertext), msg);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn homomorphism() {
+        let mut rng = DeterministicRng::new(2);
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let public_key = secret_key.public_key();
+        let a = encrypt_scalar(&public_key, 1234, &mut rng);
+        let b = encrypt_scalar(&public_key, 4321, &mut rng);
+        assert_eq!(decrypt(&secret_key, &(a + b)), G * 5555);
+        assert_eq!(decrypt_scalar(&secret_key, &(a + b), 10_000), Some(5555));
+        assert_eq!(decrypt_scalar(&secret_key, &(b - a), 10_000), Some(3087));
+
+        let mut sum = encrypt(&public_key, G * 7, &mut rng);
+        sum += encrypt(&public_key, -(G * 7), &mut rng);
+        assert!(decrypt(&secret_key, &sum).is_at_infinity());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn scalar_recovery() {
+        let mut rng = DeterministicRng::new(3);
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let max = 1 << 20;
+        for m in &[0, 1, 1023, 1025, 777_777, max - 1, max] {
+            let ciphertext = encrypt_scalar(&secret_key.public_key(), *m, &mut rng);
+            assert_eq!(decrypt_scalar(&secret_key, &ciphertext, max), Some(*m), "value {}", m);
+        }
+        let ciphertext = encrypt_scalar(&secret_key.public_key(), max + 1, &mut rng);
+        assert_eq!(decrypt_scalar(&secret_key, &ciphertext, max), None);
+        assert_eq!(discrete_log(&-G, 1000), None);
+        assert_eq!(discrete_log(&G, 0), None);
+        assert_eq!(discrete_log(&G, 1), Some(1));
+    }
+}
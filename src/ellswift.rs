@@ -0,0 +1,333 @@
+//! ElligatorSwift encoding of public keys and x-only ECDH as used by BIP324
+//!
+//! A point is encoded as two field elements `u || t` (64 bytes) which look uniformly random.
+//! Decoding maps any 64 bytes to a point: `u` and `t` are reduced modulo `p`, `xswiftec(u, t)`
+//! computes the x coordinate and the y coordinate has the parity of `t`. `xswiftec` computes
+//! (after replacing zero `u` and `t` with one and doubling `t` if `u³ + t² + 7 = 0`)
+//!
+//! * `X = (u³ + 7 - t²) / (2·t)`
+//! * `Y = (X + t) / (√-3·u)`
+//!
+//! and returns the first of `u + 4·Y²`, `(-X/Y - u) / 2`, `(X/Y - u) / 2` that is on the curve.
+//! Either exactly one or all three of them are so the choice of the square root of `-3` doesn't
+//! matter.
+//!
+//! Encoding picks random `u` and inverts `xswiftec` for it. Each `u` has up to eight preimages
+//! `t`, one of them is selected by a random case, trying again if it doesn't exist. The inverse
+//! is described at `xswiftec_inv`. This is **NOT CONSTANT TIME!!!**
+
+use rand_core::{CryptoRng, RngCore};
+use crate::field;
+use crate::params::B;
+use crate::tagged_hash::TaggedHash;
+use crate::{array_ref_32, Point, PublicKey, SecretKey, Zp, U256};
+
+const ECDH_TAG: &str = "bip324_ellswift_xonly_ecdh";
+
+/// Multiplies using the reduction specialized for secp256k1, the operator is much slower
+fn mul(a: Zp, b: Zp) -> Zp {
+    Zp::from_reduced(field::mul(a.0, b.0))
+}
+
+fn square(a: Zp) -> Zp {
+    mul(a, a)
+}
+
+fn sqrt(a: Zp) -> Option<Zp> {
+    field::sqrt(a)
+}
+
+/// `x³ + 7`
+fn rhs(x: Zp) -> Zp {
+    mul(square(x), x) + B
+}
+
+fn is_valid_x(x: Zp) -> bool {
+    sqrt(rhs(x)).is_some()
+}
+
+fn half(a: Zp) -> Zp {
+    mul(a, Zp::from_reduced(U256::from(2)).multiplicative_inverse())
+}
+
+/// The square root of `-3` chosen by BIP324, `(-3)^((p + 1) / 4) = 2·β² + 1` where `β` is the
+/// cube root of unity, so `(1 - √-3) / 2 = -β²` and `(1 + √-3) / 2 = -β`
+fn minus_3_sqrt() -> Zp {
+    let beta2 = square(Zp::CUBE_ROOT_OF_UNITY);
+    beta2 + beta2 + Zp::ONE
+}
+
+/// Computes the x coordinate encoded by `u` and `t`
+fn xswiftec(u: Zp, t: Zp) -> Zp {
+    let u = if u.is_zero() { Zp::ONE } else { u };
+    let t = if t.is_zero() { Zp::ONE } else { t };
+    let t = if (rhs(u) + square(t)).is_zero() { t + t } else { t };
+    let x = mul(rhs(u) - square(t), (t + t).multiplicative_inverse());
+    let y = mul(x + t, mul(minus_3_sqrt(), u).multiplicative_inverse());
+    let x_over_y = mul(x, y.multiplicative_inverse());
+    let candidates = [u + square(y + y), half(-x_over_y - u), half(x_over_y - u)];
+    let x = candidates.iter().copied().find(|x| is_valid_x(*x));
+    x.expect("one of the candidates is always on the curve")
+}
+
+/// Finds `t` such that `xswiftec(u, t) == x`, one of up to eight selected by `case` (0 to 7)
+/// numbered as in BIP324.
+///
+/// * Bit 1 of `case` is clear: `x` is `(∓X/Y - u) / 2` so `v = x`. This requires `-x - u` (the
+///   other one) not to be on the curve, otherwise all three candidates are and `u + 4·Y²` would
+///   be decoded. Then `s = 4·Y² = -(u³ + 7) / (u² + u·x + x²)`.
+/// * Bit 1 of `case` is set: `x = u + 4·Y²` so `s = 4·Y² = x - u` and `v = (r/s - u) / 2`
+///   where `r = √(-s·(4·(u³ + 7) + 3·s·u²))`. If `r` is zero both choices of bit 0 give the
+///   same `t` so only one of them is returned.
+///
+/// With `w = √s` case `0` is `-w·(u·(1 - √-3) / 2 + v)`, `1` is `w·(u·(1 + √-3) / 2 + v)` and
+/// setting bit 2 negates the result. Returns `None` if the selected preimage doesn't exist.
+fn xswiftec_inv(x: Zp, u: Zp, case: u8) -> Option<Zp> {
+    let (v, s) = if case & 2 == 0 {
+        if is_valid_x(-x - u) {
+            return None;
+        }
+        let denominator = square(u) + mul(u, x) + square(x);
+        if denominator.is_zero() {
+            return None;
+        }
+        (x, -mul(rhs(u), denominator.multiplicative_inverse()))
+    } else {
+        let s = x - u;
+        if s.is_zero() {
+            return None;
+        }
+        let g = rhs(u);
+        let three_s_u2 = mul(Zp::from_reduced(U256::from(3)), mul(s, square(u)));
+        let r = sqrt(-mul(s, g + g + g + g + three_s_u2))?;
+        if case & 1 == 1 && r.is_zero() {
+            return None;
+        }
+        (half(mul(r, s.multiplicative_inverse()) - u), s)
+    };
+    let w = sqrt(s)?;
+    let t = if case & 1 == 0 {
+        -mul(w, mul(u, half(Zp::ONE - minus_3_sqrt())) + v)
+    } else {
+        mul(w, mul(u, half(Zp::ONE + minus_3_sqrt())) + v)
+    };
+    if case & 4 == 0 {
+        Some(t)
+    } else {
+        Some(-t)
+    }
+}
+
+fn decode_elements(u: Zp, t: Zp) -> Point {
+    let x = xswiftec(u, t);
+    let y = sqrt(rhs(x)).expect("xswiftec returns valid x coordinates");
+    let y = if y.is_odd() == t.is_odd() { y } else { -y };
+    Point::from_coordinates_unchecked(x, y)
+}
+
+/// Decodes the point, any 64 bytes are a valid encoding of some point.
+pub fn decode(bytes: &[u8; 64]) -> Point {
+    let u = Zp::wrapping_from(U256::from_be_bytes(array_ref_32(&bytes[..32])));
+    let t = Zp::wrapping_from(U256::from_be_bytes(array_ref_32(&bytes[32..])));
+    decode_elements(u, t)
+}
+
+/// Encodes the public key as 64 bytes indistinguishable from random ones.
+///
+/// Every call returns a different encoding, all of them decode to `public_key`.
+pub fn encode<R: RngCore + CryptoRng>(public_key: &PublicKey, rng: &mut R) -> [u8; 64] {
+    let point = public_key.to_point();
+    loop {
+        let mut bytes = [0; 32];
+        rng.fill_bytes(&mut bytes);
+        let u = match Zp::from_be_bytes(&bytes) {
+            Some(u) if !u.is_zero() => u,
+            _ => continue,
+        };
+        let case = (rng.next_u32() % 8) as u8;
+        let t = match xswiftec_inv(point.x(), u, case) {
+            Some(t) => t,
+            None => continue,
+        };
+        // negating `t` negates the decoded y coordinate
+        let t = if t.is_odd() == point.y().is_odd() { t } else { -t };
+        // the special cases of `xswiftec` for zero `t` and `u³ + t² + 7 = 0` don't invert
+        if decode_elements(u, t) != point {
+            continue;
+        }
+        let mut encoded = [0; 64];
+        encoded[..32].copy_from_slice(&u.to_be_bytes());
+        encoded[32..].copy_from_slice(&t.to_be_bytes());
+        return encoded;
+    }
+}
+
+/// Computes the BIP324 shared secret.
+///
+/// This is `TaggedHash("bip324_ellswift_xonly_ecdh")(initiator || responder || x)` where
+/// `initiator` and `responder` are the encodings of the public keys of the parties and `x` is
+/// the x coordinate of the shared point. `initiating` tells whether we are the initiator.
+pub fn ecdh(our_secret_key: &SecretKey, our_ellswift: &[u8; 64], their_ellswift: &[u8; 64], initiating: bool) -> [u8; 32] {
+    let shared = decode(their_ellswift) * our_secret_key.to_scalar();
+    let x = shared.x().to_be_bytes();
+    let (initiator, responder) = if initiating { (our_ellswift, their_ellswift) } else { (their_ellswift, our_ellswift) };
+    TaggedHash::new(ECDH_TAG).hash(&[initiator, responder, &x])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, ecdh, encode, is_valid_x, minus_3_sqrt, sqrt, xswiftec, xswiftec_inv};
+    use crate::test_util::{hex, hex32};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Scalar, SecretKey, Zp};
+    use rand_core::RngCore;
+
+    fn random_element(rng: &mut DeterministicRng) -> Zp {
+        loop {
+            let mut bytes = [0; 32];
+            rng.fill_bytes(&mut bytes);
+            if let Some(element) = Zp::from_be_bytes(&bytes) {
+                return element;
+            }
+        }
+    }
+
+    /// Both `u` and `t` are zero and get replaced by one
+    #[test]
+    fn zero_encoding() {
+        let point = decode(&[0; 64]);
+        assert_eq!(point.x().to_be_bytes(), hex32("edd1fd3e327ce90cc7a3542614289aee9682003e9cf7dcc9cf2ca9743be5aa0c"));
+        assert!(!point.y().is_odd());
+    }
+
+    /// Rows of `ellswift_decode_test_vectors.csv` from BIP324
+    #[test]
+    fn decode_vectors() {
+        let vectors = [
+            ("000000000000000000000000000000000000000000000000000000000000000001d3475bf7655b0fb2d852921035b2ef607f49069b97454e6795251062741771", "b5da00b73cd6560520e7c364086e7cd23a34bf60d0e707be9fc34d4cd5fdfa2c"),
+            ("000000000000000000000000000000000000000000000000000000000000000082277c4a71f9d22e66ece523f8fa08741a7c0912c66a69ce68514bfd3515b49f", "f482f2e241753ad0fb89150d8491dc1e34ff0b8acfbb442cfe999e2e5e6fd1d2"),
+        ];
+        for (encoded, x) in &vectors {
+            let mut bytes = [0; 64];
+            bytes.copy_from_slice(&hex(encoded));
+            assert_eq!(decode(&bytes).x().to_be_bytes(), hex32(x), "{}", encoded);
+        }
+    }
+
+    /// Rows of `xswiftec_inv_test_vectors.csv` from BIP324, empty strings are missing preimages
+    #[test]
+    fn xswiftec_inv_vectors() {
+        let vectors = [
+            ("05ff6bdad900fc3261bc7fe34e2fb0f569f06e091ae437d3a52e9da0cbfb9590", "80cdf63774ec7022c89a5a8558e373a279170285e0ab27412dbce510bdfe23fc", [
+                "",
+                "",
+                "45654798ece071ba79286d04f7f3eb1c3f1d17dd883610f2ad2efd82a287466b",
+                "0aeaa886f6b76c7158452418cbf5033adc5747e9e9b5d3b2303db96936528557",
+                "",
+                "",
+                "ba9ab867131f8e4586d792fb080c14e3c0e2e82277c9ef0d52d1027c5d78b5c4",
+                "f51557790948938ea7badbe7340afcc523a8b816164a2c4dcfc24695c9ad76d8",
+            ]),
+            ("1737a85f4c8d146cec96e3ffdca76d9903dcf3bd53061868d478c78c63c2aa9e", "39e48dd150d2f429be088dfd5b61882e7e8407483702ae9a5ab35927b15f85ea", [
+                "1be8cc0b04be0c681d0c6a68f733f82c6c896e0c8a262fcd392918e303a7abf4",
+                "605b5814bf9b8cb066667c9e5480d22dc5b6c92f14b4af3ee0a9eb83b03685e3",
+                "",
+                "",
+                "e41733f4fb41f397e2f3959708cc07d3937691f375d9d032c6d6e71bfc58503b",
+                "9fa4a7eb4064734f99998361ab7f2dd23a4936d0eb4b50c11f56147b4fc9764c",
+                "",
+                "",
+            ]),
+        ];
+        for (u, x, preimages) in &vectors {
+            let u = Zp::from_be_bytes(&hex32(u)).unwrap();
+            let x = Zp::from_be_bytes(&hex32(x)).unwrap();
+            for (case, t) in preimages.iter().enumerate() {
+                let expected = if t.is_empty() { None } else { Zp::from_be_bytes(&hex32(t)) };
+                assert_eq!(xswiftec_inv(x, u, case as u8), expected, "case {}", case);
+            }
+        }
+    }
+
+    #[test]
+    fn bip324_minus_3_sqrt() {
+        assert_eq!(sqrt(-Zp::from_reduced(3u64.into())), Some(minus_3_sqrt()));
+    }
+
+    /// Values of `u` and `t` at least `p` are reduced
+    #[test]
+    fn unreduced_encoding() {
+        let mut encoded = [0; 64];
+        encoded.copy_from_slice(&hex(concat!(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30",
+        )));
+        let mut reduced = [0; 64];
+        reduced[63] = 1;
+        assert_eq!(decode(&encoded), decode(&reduced));
+        assert_eq!(decode(&[0xFF; 64]), decode(&{
+            let mut reduced = [0; 64];
+            reduced[..32].copy_from_slice(&hex32("00000000000000000000000000000000000000000000000000000001000003d0"));
+            reduced[32..].copy_from_slice(&hex32("00000000000000000000000000000000000000000000000000000001000003d0"));
+            reduced
+        }));
+    }
+
+    #[test]
+    fn inverse_cases() {
+        let mut rng = DeterministicRng::new(225);
+        let mut found = [0; 8];
+        for _ in 0..40 {
+            let x = loop {
+                let x = random_element(&mut rng);
+                if is_valid_x(x) {
+                    break x;
+                }
+            };
+            let u = random_element(&mut rng);
+            for case in 0..8 {
+                if let Some(t) = xswiftec_inv(x, u, case) {
+                    assert_eq!(xswiftec(u, t), x, "case {}", case);
+                    found[case as usize] += 1;
+                }
+            }
+        }
+        assert!(found.iter().all(|count| *count > 0), "{:?}", found);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut rng = DeterministicRng::new(225);
+        for _ in 0..10 {
+            let public_key = SecretKey::from_scalar(Scalar::random(&mut rng)).unwrap().public_key();
+            let encoded = encode(&public_key, &mut rng);
+            assert_eq!(decode(&encoded), public_key.to_point());
+            assert_ne!(encode(&public_key, &mut rng), encoded);
+        }
+    }
+
+    #[test]
+    fn shared_secret() {
+        let mut rng = DeterministicRng::new(225);
+        let alice = SecretKey::from_scalar(Scalar::random(&mut rng)).unwrap();
+        let bob = SecretKey::from_scalar(Scalar::random(&mut rng)).unwrap();
+        let alice_encoded = encode(&alice.public_key(), &mut rng);
+        let bob_encoded = encode(&bob.public_key(), &mut rng);
+        let initiator = ecdh(&alice, &alice_encoded, &bob_encoded, true);
+        assert_eq!(ecdh(&bob, &bob_encoded, &alice_encoded, false), initiator);
+        assert_ne!(ecdh(&bob, &bob_encoded, &alice_encoded, true), initiator);
+    }
+
+    /// The first row of `packet_encoding_test_vectors.csv` from BIP324
+    #[test]
+    fn shared_secret_vector() {
+        let secret_key = SecretKey::from_bytes(&hex32("61062ea5071d800bbfd59e2e8b53d47d194b095ae5a4df04936b49772ef0d4d7")).unwrap();
+        let mut ours = [0; 64];
+        ours.copy_from_slice(&hex("ec0adff257bbfe500c188c80b4fdd640f6b45a482bbc15fc7cef5931deff0aa186f6eb9bba7b85dc4dcc28b28722de1e3d9108b985e2967045668f66098e475b"));
+        let mut theirs = [0; 64];
+        theirs.copy_from_slice(&hex("a4a94dfce69b4a2a0a099313d10f9f7e7d649d60501c9e1d274c300e0d89aafaffffffffffffffffffffffffffffffffffffffffffffffffffffffff8faf88d5"));
+        assert_eq!(decode(&ours).x(), secret_key.public_key().to_point().x());
+        assert_eq!(decode(&theirs).x().to_be_bytes(), hex32("0c71defa3fafd74cb835102acd81490963f6b72d889495e06561375bd65f6ffc"));
+        assert_eq!(ecdh(&secret_key, &ours, &theirs, true), hex32("c6992a117f5edbea70c3f511d32d26b9798be4b81a62eaee1a5acaa8459a3592"));
+    }
+}
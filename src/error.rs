@@ -0,0 +1,377 @@
+//! Error type used by fallible operations of this crate
+
+use core::fmt;
+
+/// Error returned when parsing or validating data fails.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Error {
+    /// The data has unexpected length (contained)
+    InvalidLength(usize),
+    /// The bytes don't encode a valid secret key - zero or not less than the curve order
+    InvalidSecretKey,
+    /// The bytes don't encode a valid point on the curve
+    InvalidPublicKey,
+    /// The first byte of a SEC1 point is not a valid prefix for its length (contained)
+    InvalidPrefix(u8),
+    /// The string contains a character that is not in the Base58 alphabet
+    InvalidBase58Character(char),
+    /// The string contains a character that is not in the Base64 alphabet
+    InvalidBase64Character(char),
+    /// Base58Check or BIP39 checksum doesn't match the data
+    InvalidChecksum,
+    /// Version bytes of an extended key are not known
+    UnknownVersion([u8; 4]),
+    /// A private key was expected but the key data looks like a public key
+    UnexpectedPublicKey,
+    /// BIP32 child number is not less than 2^31 (excluding the hardened bit)
+    ChildNumberOutOfRange,
+    /// BIP32 child number is not a decimal number optionally followed by a hardened marker
+    InvalidChildNumber,
+    /// Derivation path doesn't start with `m`
+    InvalidPathPrefix,
+    /// Derivation path contains an empty component (`//`)
+    EmptyPathComponent,
+    /// Derivation path ends with a slash
+    TrailingSlash,
+    /// BIP32 derivation resulted in an invalid key, the next index should be used
+    InvalidChildKey,
+    /// BIP32 derivation would exceed the maximum depth of 255
+    MaxDepthExceeded,
+    /// Extended key of depth 0 has a non-zero parent fingerprint or child number
+    InvalidMasterKey,
+    /// Attempted to derive a hardened child from an extended public key
+    HardenedDerivationFromPublicKey,
+    /// Version byte of a Base58Check address is not known (contained)
+    UnknownAddressVersion(u8),
+    /// Bech32 string contains both lower-case and upper-case characters
+    MixedCase,
+    /// Bech32 string doesn't contain the `1` separator
+    MissingSeparator,
+    /// Human-readable part of a bech32 string is empty or contains invalid characters
+    InvalidHrp,
+    /// Bech32 data part contains a character that is not in the bech32 alphabet
+    InvalidBech32Character(char),
+    /// Segwit version is not supported by the operation (contained)
+    UnsupportedWitnessVersion(u8),
+    /// Bech32 data has non-zero or excessive padding
+    InvalidPadding,
+    /// Signature verification failed
+    InvalidSignature,
+    /// Signature has high S which is rejected by strict verification
+    HighS,
+    /// The nonce is zero or produced invalid signature
+    InvalidNonce,
+    /// Recovery id is not in range 0..=3
+    InvalidRecoveryId,
+    /// Tweak is not less than the curve order or the tweaked key is the point at infinity
+    InvalidTweak,
+    /// Signature share of the participant (contained) is invalid or missing
+    InvalidShare(u16),
+    /// DER encoding of a signature or a key is invalid (contained)
+    InvalidDer(DerError),
+    /// Secret sharing threshold is zero or greater than the number of shares
+    InvalidThreshold,
+    /// Two shares have the same index (contained)
+    DuplicateIndex(u16),
+    /// There are fewer shares (contained) than the threshold
+    NotEnoughShares(usize),
+    /// Authentication of the ciphertext failed - it was corrupted or the key is wrong
+    DecryptionFailed,
+    /// The value doesn't fit into the range
+    ValueOutOfRange,
+    /// The key algorithm is not `id-ecPublicKey` or the JWS algorithm is not `ES256K`
+    UnsupportedAlgorithm,
+    /// The key belongs to a curve other than secp256k1
+    UnsupportedCurve,
+    /// The encoding requires uncompressed point but it's compressed
+    CompressedPoint,
+    /// Version of the key structure is not supported
+    UnsupportedVersion,
+    /// The public key stored along with the secret key doesn't match it
+    PublicKeyMismatch,
+    /// PEM `BEGIN` or `END` line is missing or malformed
+    InvalidPemBoundary,
+    /// PEM `END` label differs from the `BEGIN` label
+    PemLabelMismatch,
+    /// PEM label doesn't match the expected kind of key
+    UnexpectedPemLabel,
+    /// JSON is malformed or a member has unexpected type
+    InvalidJson,
+    /// Required JSON member is missing
+    MissingJsonMember,
+    /// Compact JWS doesn't consist of three dot-separated parts
+    InvalidJwsFormat,
+    /// JWS header marks an extension as critical but no extensions are supported
+    UnsupportedCriticalHeader,
+    /// The coordinates don't satisfy the curve equation
+    NotOnCurve,
+    /// The integer is not less than the field size `P`
+    NonCanonicalFieldElement,
+    /// The integer is not less than the curve order `N`
+    NonCanonicalScalar,
+    /// The scalar is zero where a non-zero one is required
+    ZeroScalar,
+    /// The point is the point at infinity where a finite one is required
+    PointAtInfinity,
+    /// BIP39 entropy length in bits (contained) is not one of 128, 160, 192, 224 and 256
+    InvalidEntropyLength(usize),
+    /// BIP39 mnemonic has word count (contained) other than 12, 15, 18, 21 and 24
+    InvalidWordCount(usize),
+    /// The word at the (contained) position is not in the BIP39 wordlist
+    UnknownWord(usize),
+    /// The id of a Nostr event doesn't match its contents
+    InvalidEventId,
+    /// CBOR is malformed, uses indefinite lengths, has trailing data or duplicate map keys
+    InvalidCbor,
+    /// Required COSE_Key parameter is missing
+    MissingCoseParameter,
+    /// COSE_Key contains parameter with the (contained) label which is not understood
+    UnsupportedCoseParameter(i64),
+    /// The address type is not supported by the operation
+    UnsupportedAddress,
+    /// Message from a participant with invalid index (contained) or intended for another one
+    InvalidParticipant(u16),
+    /// Revealed value doesn't match the commitment to it
+    CommitmentMismatch,
+    /// The field element is zero so it can not be inverted
+    ZeroFieldElement,
+    /// Taproot leaf version (contained) is odd so it would collide with the parity bit
+    InvalidLeafVersion(u8),
+    /// Taproot script tree is deeper than 128 levels
+    TapTreeTooDeep,
+    /// The freshly produced signature doesn't verify, the signing computation was faulty
+    SelfVerificationFailed,
+    /// Domain separation tag is empty
+    EmptyTag,
+    /// BIP38 flag byte (contained) has unknown bits set or is missing the mandatory ones
+    InvalidBip38Flags(u8),
+}
+
+/// Specific violation of strict DER encoding of a signature or a key
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum DerError {
+    /// The data ended prematurely
+    UnexpectedEnd,
+    /// The signature doesn't start with SEQUENCE tag
+    InvalidSequenceTag,
+    /// Expected INTEGER tag
+    InvalidIntegerTag,
+    /// Length is encoded using more bytes than necessary
+    NonMinimalLength,
+    /// Integer has zero length
+    EmptyInteger,
+    /// Integer has the sign bit set
+    NegativeInteger,
+    /// Integer has unnecessary leading zero byte
+    NonMinimalInteger,
+    /// Integer is zero or not less than the curve order
+    IntegerOutOfRange,
+    /// Expected BIT STRING tag
+    InvalidBitStringTag,
+    /// Expected OCTET STRING tag
+    InvalidOctetStringTag,
+    /// Expected OBJECT IDENTIFIER tag
+    InvalidObjectIdentifierTag,
+    /// BIT STRING is empty or has unused bits
+    InvalidBitString,
+    /// There are bytes after the encoded data
+    TrailingData,
+}
+
+/// Failure of batch verification
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum BatchError {
+    /// At least one signature in the batch is invalid, which one wasn't searched for
+    Invalid,
+    /// The signature at the (contained) index is invalid, there may be more invalid ones after it
+    InvalidEntry(usize),
+}
+
+/// Error returned by the `bindings` functions
+///
+/// The variants carry no data so that they map directly to an error enum of the foreign
+/// language.
+#[cfg(feature = "bindings")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum BindingsError {
+    /// An input doesn't have the required length
+    InvalidLength,
+    /// The secret key is zero or not less than the curve order
+    InvalidSecretKey,
+    /// The public key is malformed or not on the curve
+    InvalidPublicKey,
+    /// The signature is malformed
+    InvalidSignature,
+    /// The tweak is not less than the curve order or the tweaked key is invalid
+    InvalidTweak,
+}
+
+/// Component whose known-answer test failed, returned by `selftest`
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum SelfTestFailure {
+    /// Multiplication or inversion of field elements
+    Field,
+    /// Multiplication of the generator by a scalar
+    ScalarMultiplication,
+    /// ECDSA signing or verification
+    Ecdsa,
+    /// BIP340 signing or verification
+    Schnorr,
+    /// SEC1 serialization or parsing of points
+    Sec1,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidLength(len) => write!(f, "invalid length {}", len),
+            Error::InvalidSecretKey => write!(f, "invalid secret key"),
+            Error::InvalidPublicKey => write!(f, "invalid public key"),
+            Error::InvalidPrefix(prefix) => write!(f, "invalid SEC1 prefix {:#04x}", prefix),
+            Error::InvalidBase58Character(c) => write!(f, "invalid base58 character {:?}", c),
+            Error::InvalidBase64Character(c) => write!(f, "invalid base64 character {:?}", c),
+            Error::InvalidChecksum => write!(f, "invalid checksum"),
+            Error::UnknownVersion(version) => write!(f, "unknown version {:02x}{:02x}{:02x}{:02x}", version[0], version[1], version[2], version[3]),
+            Error::UnexpectedPublicKey => write!(f, "expected private key, found public key"),
+            Error::ChildNumberOutOfRange => write!(f, "child number out of range"),
+            Error::InvalidChildNumber => write!(f, "invalid child number"),
+            Error::InvalidPathPrefix => write!(f, "derivation path doesn't start with 'm'"),
+            Error::EmptyPathComponent => write!(f, "empty derivation path component"),
+            Error::TrailingSlash => write!(f, "trailing slash in derivation path"),
+            Error::InvalidChildKey => write!(f, "derived key is invalid"),
+            Error::MaxDepthExceeded => write!(f, "maximum derivation depth exceeded"),
+            Error::InvalidMasterKey => write!(f, "master key has a parent fingerprint or child number"),
+            Error::HardenedDerivationFromPublicKey => write!(f, "can not derive hardened key from public key"),
+            Error::UnknownAddressVersion(version) => write!(f, "unknown address version {:#04x}", version),
+            Error::MixedCase => write!(f, "mixed-case bech32 string"),
+            Error::MissingSeparator => write!(f, "missing bech32 separator"),
+            Error::InvalidHrp => write!(f, "invalid human-readable part"),
+            Error::InvalidBech32Character(c) => write!(f, "invalid bech32 character {:?}", c),
+            Error::UnsupportedWitnessVersion(version) => write!(f, "unsupported witness version {}", version),
+            Error::InvalidPadding => write!(f, "invalid padding"),
+            Error::InvalidSignature => write!(f, "invalid signature"),
+            Error::HighS => write!(f, "signature has high S"),
+            Error::InvalidNonce => write!(f, "invalid nonce"),
+            Error::InvalidRecoveryId => write!(f, "invalid recovery id"),
+            Error::InvalidTweak => write!(f, "invalid tweak"),
+            Error::InvalidShare(index) => write!(f, "invalid signature share of participant {}", index),
+            Error::InvalidDer(error) => write!(f, "invalid DER: {}", error),
+            Error::InvalidThreshold => write!(f, "invalid threshold"),
+            Error::DuplicateIndex(index) => write!(f, "duplicate share index {}", index),
+            Error::NotEnoughShares(count) => write!(f, "not enough shares ({})", count),
+            Error::DecryptionFailed => write!(f, "decryption failed"),
+            Error::ValueOutOfRange => write!(f, "value out of range"),
+            Error::UnsupportedAlgorithm => write!(f, "unsupported algorithm"),
+            Error::UnsupportedCurve => write!(f, "unsupported curve"),
+            Error::CompressedPoint => write!(f, "expected uncompressed point"),
+            Error::UnsupportedVersion => write!(f, "unsupported version"),
+            Error::PublicKeyMismatch => write!(f, "public key doesn't match the secret key"),
+            Error::InvalidPemBoundary => write!(f, "missing or malformed PEM boundary"),
+            Error::PemLabelMismatch => write!(f, "PEM END label doesn't match BEGIN label"),
+            Error::UnexpectedPemLabel => write!(f, "unexpected PEM label"),
+            Error::InvalidJson => write!(f, "invalid JSON"),
+            Error::MissingJsonMember => write!(f, "missing JSON member"),
+            Error::InvalidJwsFormat => write!(f, "invalid JWS compact serialization"),
+            Error::UnsupportedCriticalHeader => write!(f, "unsupported critical JWS header"),
+            Error::NotOnCurve => write!(f, "point is not on the curve"),
+            Error::NonCanonicalFieldElement => write!(f, "field element is not less than the field size"),
+            Error::NonCanonicalScalar => write!(f, "scalar is not less than the curve order"),
+            Error::ZeroScalar => write!(f, "scalar is zero"),
+            Error::PointAtInfinity => write!(f, "point at infinity"),
+            Error::InvalidEntropyLength(bits) => write!(f, "invalid entropy length of {} bits", bits),
+            Error::InvalidWordCount(count) => write!(f, "invalid mnemonic word count {}", count),
+            Error::UnknownWord(position) => write!(f, "unknown mnemonic word at position {}", position),
+            Error::InvalidEventId => write!(f, "event id doesn't match the event"),
+            Error::InvalidCbor => write!(f, "invalid CBOR"),
+            Error::MissingCoseParameter => write!(f, "missing COSE_Key parameter"),
+            Error::UnsupportedCoseParameter(label) => write!(f, "unsupported COSE_Key parameter {}", label),
+            Error::UnsupportedAddress => write!(f, "unsupported address type"),
+            Error::InvalidParticipant(index) => write!(f, "invalid participant {}", index),
+            Error::CommitmentMismatch => write!(f, "revealed value doesn't match the commitment"),
+            Error::ZeroFieldElement => write!(f, "field element is zero"),
+            Error::InvalidLeafVersion(version) => write!(f, "invalid leaf version {:#04x}", version),
+            Error::TapTreeTooDeep => write!(f, "taproot script tree is deeper than 128 levels"),
+            Error::SelfVerificationFailed => write!(f, "the produced signature failed verification"),
+            Error::EmptyTag => write!(f, "empty domain separation tag"),
+            Error::InvalidBip38Flags(flags) => write!(f, "invalid BIP38 flags {:#04x}", flags),
+        }
+    }
+}
+
+impl fmt::Display for DerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DerError::UnexpectedEnd => write!(f, "unexpected end of data"),
+            DerError::InvalidSequenceTag => write!(f, "expected SEQUENCE tag"),
+            DerError::InvalidIntegerTag => write!(f, "expected INTEGER tag"),
+            DerError::NonMinimalLength => write!(f, "non-minimal length encoding"),
+            DerError::EmptyInteger => write!(f, "empty integer"),
+            DerError::NegativeInteger => write!(f, "negative integer"),
+            DerError::NonMinimalInteger => write!(f, "integer has unnecessary leading zero"),
+            DerError::IntegerOutOfRange => write!(f, "integer out of range"),
+            DerError::InvalidBitStringTag => write!(f, "expected BIT STRING tag"),
+            DerError::InvalidOctetStringTag => write!(f, "expected OCTET STRING tag"),
+            DerError::InvalidObjectIdentifierTag => write!(f, "expected OBJECT IDENTIFIER tag"),
+            DerError::InvalidBitString => write!(f, "invalid BIT STRING"),
+            DerError::TrailingData => write!(f, "trailing data"),
+        }
+    }
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchError::Invalid => write!(f, "the batch contains an invalid signature"),
+            BatchError::InvalidEntry(index) => write!(f, "the signature at index {} is invalid", index),
+        }
+    }
+}
+
+#[cfg(feature = "bindings")]
+impl fmt::Display for BindingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BindingsError::InvalidLength => write!(f, "invalid length"),
+            BindingsError::InvalidSecretKey => write!(f, "invalid secret key"),
+            BindingsError::InvalidPublicKey => write!(f, "invalid public key"),
+            BindingsError::InvalidSignature => write!(f, "invalid signature"),
+            BindingsError::InvalidTweak => write!(f, "invalid tweak"),
+        }
+    }
+}
+
+impl fmt::Display for SelfTestFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let component = match self {
+            SelfTestFailure::Field => "field arithmetic",
+            SelfTestFailure::ScalarMultiplication => "scalar multiplication",
+            SelfTestFailure::Ecdsa => "ECDSA",
+            SelfTestFailure::Schnorr => "BIP340",
+            SelfTestFailure::Sec1 => "SEC1 encoding",
+        };
+        write!(f, "self-test of {} failed", component)
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl core::error::Error for DerError {}
+
+impl core::error::Error for BatchError {}
+
+#[cfg(feature = "bindings")]
+impl core::error::Error for BindingsError {}
+
+impl core::error::Error for SelfTestFailure {}
+
+impl From<DerError> for Error {
+    fn from(error: DerError) -> Self {
+        Error::InvalidDer(error)
+    }
+}
+
+impl From<BatchError> for Error {
+    fn from(_: BatchError) -> Self {
+        Error::InvalidSignature
+    }
+}
@@ -0,0 +1,81 @@
+//! Ethereum addresses
+
+use alloc::{string::String, vec, vec::Vec};
+use sha3::{Digest, Keccak256};
+use crate::PublicKey;
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+impl PublicKey {
+    /// Computes the Ethereum address - last 20 bytes of Keccak-256 of the raw key.
+    ///
+    /// `PublicKey` is never the point at infinity so the address is always well-defined.
+    pub fn ethereum_address(&self) -> [u8; 20] {
+        let hash = keccak256(&self.serialize_raw());
+        let mut address = [0; 20];
+        address.copy_from_slice(&hash[12..]);
+        address
+    }
+
+    /// Formats the Ethereum address as `0x`-prefixed hex string with EIP-55 checksum
+    pub fn to_eth_address_string(&self) -> String {
+        eip55_encode(&self.ethereum_address())
+    }
+}
+
+/// Letters are upper-cased if the corresponding nibble of the hash of lower-case hex is >= 8
+fn eip55_encode(address: &[u8; 20]) -> String {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+
+    let lower = address
+        .iter()
+        .flat_map(|byte| vec![HEX[usize::from(byte >> 4)], HEX[usize::from(byte & 0x0F)]])
+        .collect::<Vec<_>>();
+    let hash = keccak256(&lower);
+
+    let mut result = String::with_capacity(42);
+    result.push_str("0x");
+    for (i, &c) in lower.iter().enumerate() {
+        let nibble = if i % 2 == 0 { hash[i / 2] >> 4 } else { hash[i / 2] & 0x0F };
+        result.push(char::from(if nibble >= 8 { c.to_ascii_uppercase() } else { c }));
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::eip55_encode;
+    use crate::SecretKey;
+
+    fn secret_key(last_byte: u8) -> SecretKey {
+        let mut bytes = [0; 32];
+        bytes[31] = last_byte;
+        SecretKey::from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    fn small_private_keys() {
+        assert_eq!(secret_key(1).public_key().to_eth_address_string(), "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf");
+        assert_eq!(secret_key(2).public_key().to_eth_address_string(), "0x2B5AD5c4795c026514f8317c7a215E218DcCD6cF");
+        assert_eq!(secret_key(1).public_key().ethereum_address()[..4], [0x7E, 0x5F, 0x45, 0x52]);
+    }
+
+    #[test]
+    fn eip55_vectors() {
+        let addresses = [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ];
+        for address in &addresses {
+            let mut bytes = [0; 20];
+            for (i, byte) in bytes.iter_mut().enumerate() {
+                *byte = u8::from_str_radix(&address[(2 + 2 * i)..(4 + 2 * i)], 16).unwrap();
+            }
+            assert_eq!(eip55_encode(&bytes), *address);
+        }
+    }
+}
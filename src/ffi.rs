@@ -0,0 +1,503 @@
+//! C interface mirroring the core entry points of libsecp256k1
+//!
+//! The functions have the same names and signatures as in `secp256k1.h` so that C code written
+//! against libsecp256k1 can be linked against this crate instead. Build the library with
+//! `cargo rustc --release --features ffi --crate-type cdylib` (or `staticlib`).
+//!
+//! Deviations from libsecp256k1:
+//!
+//! * The context is a static handle, `flags` are ignored and `ctx` may be `NULL`.
+//! * The opaque `secp256k1_pubkey` holds `x || y` and `secp256k1_ecdsa_signature` holds `r || s`,
+//!   all big-endian. Treat them as opaque anyway.
+//! * Illegal arguments such as `NULL` pointers or too short output buffers make the functions
+//!   return 0 instead of calling the illegal callback.
+//! * `ecdsa_sign` only supports the default RFC6979 nonce function, `noncefp` must be `NULL`.
+//! * Signatures with zero `r` or `s` are rejected when parsing rather than when verifying.
+//! * Nothing is constant time!
+//!
+//! All pointers must be either `NULL` or valid for reads or writes of the documented size, just
+//! like in libsecp256k1.
+
+#![allow(clippy::missing_safety_doc)]
+
+use core::ffi::{c_int, c_uchar, c_uint, c_void};
+use crate::ecdsa::{self, Signature};
+use crate::nonce::Rfc6979;
+use crate::{Message, PublicKey, Scalar, SecretKey, G};
+
+/// `SECP256K1_EC_COMPRESSED` flag of `ec_pubkey_serialize`
+pub const EC_COMPRESSED: c_uint = (1 << 1) | (1 << 8);
+/// `SECP256K1_EC_UNCOMPRESSED` flag of `ec_pubkey_serialize`
+pub const EC_UNCOMPRESSED: c_uint = 1 << 1;
+
+/// Opaque context, there's only one static instance
+#[repr(C)]
+pub struct Context {
+    _private: u8,
+}
+
+static CONTEXT: Context = Context { _private: 0 };
+
+/// `secp256k1_pubkey`
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Pubkey {
+    pub data: [c_uchar; 64],
+}
+
+/// `secp256k1_ecdsa_signature`
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct EcdsaSignature {
+    pub data: [c_uchar; 64],
+}
+
+impl Pubkey {
+    fn new(public_key: &PublicKey) -> Self {
+        let mut data = [0; 64];
+        data.copy_from_slice(&public_key.serialize_uncompressed()[1..]);
+        Pubkey { data }
+    }
+
+    fn load(&self) -> Option<PublicKey> {
+        let mut uncompressed = [0x04; 65];
+        uncompressed[1..].copy_from_slice(&self.data);
+        PublicKey::from_sec1_bytes(&uncompressed).ok()
+    }
+}
+
+impl EcdsaSignature {
+    fn new(signature: &Signature) -> Self {
+        EcdsaSignature { data: signature.serialize_compact() }
+    }
+
+    fn load(&self) -> Option<Signature> {
+        Signature::from_compact(&self.data).ok()
+    }
+}
+
+fn bool_to_int(value: bool) -> c_int {
+    c_int::from(value)
+}
+
+unsafe fn read_32<'a>(ptr: *const c_uchar) -> Option<&'a [u8; 32]> {
+    ptr.cast::<[u8; 32]>().as_ref()
+}
+
+unsafe fn read_secret_key(ptr: *const c_uchar) -> Option<SecretKey> {
+    SecretKey::from_bytes(read_32(ptr)?).ok()
+}
+
+unsafe fn read_tweak(ptr: *const c_uchar) -> Option<Scalar> {
+    Scalar::from_be_bytes(read_32(ptr)?)
+}
+
+/// Stores the result of `f` in the secret key in place, zeroing it on failure
+unsafe fn update_secret_key(seckey: *mut c_uchar, f: impl FnOnce(SecretKey) -> Option<SecretKey>) -> c_int {
+    let seckey = match seckey.cast::<[u8; 32]>().as_mut() {
+        Some(seckey) => seckey,
+        None => return 0,
+    };
+    match SecretKey::from_bytes(seckey).ok().and_then(f) {
+        Some(result) => {
+            *seckey = result.to_bytes();
+            1
+        },
+        None => {
+            *seckey = [0; 32];
+            0
+        },
+    }
+}
+
+/// Stores the result of `f` in the public key in place, zeroing it on failure
+unsafe fn update_pubkey(pubkey: *mut Pubkey, f: impl FnOnce(PublicKey) -> Option<PublicKey>) -> c_int {
+    let pubkey = match pubkey.as_mut() {
+        Some(pubkey) => pubkey,
+        None => return 0,
+    };
+    match pubkey.load().and_then(f) {
+        Some(result) => {
+            *pubkey = Pubkey::new(&result);
+            1
+        },
+        None => {
+            pubkey.data = [0; 64];
+            0
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn secp256k1_context_create(_flags: c_uint) -> *mut Context {
+    &CONTEXT as *const Context as *mut Context
+}
+
+#[no_mangle]
+pub extern "C" fn secp256k1_context_destroy(_ctx: *mut Context) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ec_seckey_verify(_ctx: *const Context, seckey: *const c_uchar) -> c_int {
+    bool_to_int(read_secret_key(seckey).is_some())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ec_pubkey_create(_ctx: *const Context, pubkey: *mut Pubkey, seckey: *const c_uchar) -> c_int {
+    let pubkey = match pubkey.as_mut() {
+        Some(pubkey) => pubkey,
+        None => return 0,
+    };
+    match read_secret_key(seckey) {
+        Some(secret_key) => {
+            *pubkey = Pubkey::new(&secret_key.public_key());
+            1
+        },
+        None => {
+            pubkey.data = [0; 64];
+            0
+        },
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ec_pubkey_parse(_ctx: *const Context, pubkey: *mut Pubkey, input: *const c_uchar, inputlen: usize) -> c_int {
+    let pubkey = match pubkey.as_mut() {
+        Some(pubkey) => pubkey,
+        None => return 0,
+    };
+    pubkey.data = [0; 64];
+    if input.is_null() {
+        return 0;
+    }
+    match PublicKey::from_sec1_bytes(core::slice::from_raw_parts(input, inputlen)) {
+        Ok(public_key) => {
+            *pubkey = Pubkey::new(&public_key);
+            1
+        },
+        Err(_) => 0,
+    }
+}
+
+/// Writes compressed or uncompressed key depending on `flags`, `*outputlen` must be at least 33
+/// or 65 respectively and is set to the written length.
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ec_pubkey_serialize(_ctx: *const Context, output: *mut c_uchar, outputlen: *mut usize, pubkey: *const Pubkey, flags: c_uint) -> c_int {
+    let (outputlen, public_key) = match (outputlen.as_mut(), pubkey.as_ref().and_then(Pubkey::load)) {
+        (Some(outputlen), Some(public_key)) if !output.is_null() => (outputlen, public_key),
+        _ => return 0,
+    };
+    let compressed;
+    let uncompressed;
+    let bytes = match flags {
+        EC_COMPRESSED => {
+            compressed = public_key.serialize();
+            &compressed[..]
+        },
+        EC_UNCOMPRESSED => {
+            uncompressed = public_key.serialize_uncompressed();
+            &uncompressed[..]
+        },
+        _ => return 0,
+    };
+    if *outputlen < bytes.len() {
+        return 0;
+    }
+    core::ptr::copy_nonoverlapping(bytes.as_ptr(), output, bytes.len());
+    *outputlen = bytes.len();
+    1
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ec_pubkey_negate(_ctx: *const Context, pubkey: *mut Pubkey) -> c_int {
+    update_pubkey(pubkey, |public_key| PublicKey::from_point(-public_key.to_point()))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ec_seckey_negate(_ctx: *const Context, seckey: *mut c_uchar) -> c_int {
+    update_secret_key(seckey, |secret_key| SecretKey::from_scalar(-secret_key.to_scalar()))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ec_seckey_tweak_add(_ctx: *const Context, seckey: *mut c_uchar, tweak32: *const c_uchar) -> c_int {
+    let tweak = read_tweak(tweak32);
+    update_secret_key(seckey, |secret_key| SecretKey::from_scalar(secret_key.to_scalar() + tweak?))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ec_pubkey_tweak_add(_ctx: *const Context, pubkey: *mut Pubkey, tweak32: *const c_uchar) -> c_int {
+    let tweak = read_tweak(tweak32);
+    update_pubkey(pubkey, |public_key| PublicKey::from_point(public_key.to_point() + G * tweak?))
+}
+
+/// Fails if the tweak is zero
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ec_seckey_tweak_mul(_ctx: *const Context, seckey: *mut c_uchar, tweak32: *const c_uchar) -> c_int {
+    let tweak = read_tweak(tweak32);
+    update_secret_key(seckey, |secret_key| SecretKey::from_scalar(secret_key.to_scalar() * tweak?))
+}
+
+/// Fails if the tweak is zero
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ec_pubkey_tweak_mul(_ctx: *const Context, pubkey: *mut Pubkey, tweak32: *const c_uchar) -> c_int {
+    let tweak = read_tweak(tweak32);
+    update_pubkey(pubkey, |public_key| PublicKey::from_point(public_key.to_point() * tweak?))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ecdsa_signature_parse_compact(_ctx: *const Context, sig: *mut EcdsaSignature, input64: *const c_uchar) -> c_int {
+    let sig = match sig.as_mut() {
+        Some(sig) => sig,
+        None => return 0,
+    };
+    match input64.cast::<[u8; 64]>().as_ref().and_then(|input| Signature::from_compact(input).ok()) {
+        Some(signature) => {
+            *sig = EcdsaSignature::new(&signature);
+            1
+        },
+        None => {
+            sig.data = [0; 64];
+            0
+        },
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ecdsa_signature_parse_der(_ctx: *const Context, sig: *mut EcdsaSignature, input: *const c_uchar, inputlen: usize) -> c_int {
+    let sig = match sig.as_mut() {
+        Some(sig) => sig,
+        None => return 0,
+    };
+    sig.data = [0; 64];
+    if input.is_null() {
+        return 0;
+    }
+    match Signature::from_der(core::slice::from_raw_parts(input, inputlen)) {
+        Ok(signature) => {
+            *sig = EcdsaSignature::new(&signature);
+            1
+        },
+        Err(_) => 0,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ecdsa_signature_serialize_compact(_ctx: *const Context, output64: *mut c_uchar, sig: *const EcdsaSignature) -> c_int {
+    match (output64.cast::<[u8; 64]>().as_mut(), sig.as_ref().and_then(EcdsaSignature::load)) {
+        (Some(output), Some(signature)) => {
+            *output = signature.serialize_compact();
+            1
+        },
+        _ => 0,
+    }
+}
+
+/// `*outputlen` must be at least the length of the encoding (at most 72) and is set to it
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ecdsa_signature_serialize_der(_ctx: *const Context, output: *mut c_uchar, outputlen: *mut usize, sig: *const EcdsaSignature) -> c_int {
+    let (outputlen, signature) = match (outputlen.as_mut(), sig.as_ref().and_then(EcdsaSignature::load)) {
+        (Some(outputlen), Some(signature)) if !output.is_null() => (outputlen, signature),
+        _ => return 0,
+    };
+    let (der, len) = signature.to_der_array();
+    if *outputlen < len {
+        return 0;
+    }
+    core::ptr::copy_nonoverlapping(der.as_ptr(), output, len);
+    *outputlen = len;
+    1
+}
+
+/// Returns 1 if the input was high-S; `sigout` may be `NULL` to only check
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ecdsa_signature_normalize(_ctx: *const Context, sigout: *mut EcdsaSignature, sigin: *const EcdsaSignature) -> c_int {
+    let mut signature = match sigin.as_ref().and_then(EcdsaSignature::load) {
+        Some(signature) => signature,
+        None => return 0,
+    };
+    let was_high = signature.normalize_s();
+    if let Some(sigout) = sigout.as_mut() {
+        *sigout = EcdsaSignature::new(&signature);
+    }
+    bool_to_int(was_high)
+}
+
+/// Signs using RFC6979 with `ndata`, if not `NULL`, as 32 bytes of extra data
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ecdsa_sign(_ctx: *const Context, sig: *mut EcdsaSignature, msghash32: *const c_uchar, seckey: *const c_uchar, noncefp: *const c_void, ndata: *const c_void) -> c_int {
+    let sig = match sig.as_mut() {
+        Some(sig) => sig,
+        None => return 0,
+    };
+    sig.data = [0; 64];
+    let (msg32, secret_key) = match (read_32(msghash32), read_secret_key(seckey)) {
+        (Some(msg32), Some(secret_key)) if noncefp.is_null() => (msg32, secret_key),
+        _ => return 0,
+    };
+    let mut nonce_generator = match read_32(ndata.cast()) {
+        Some(extra_data) => Rfc6979::with_extra_data(*extra_data),
+        None => Rfc6979::new(),
+    };
+    *sig = EcdsaSignature::new(&ecdsa::sign_with(&Message::from_digest(*msg32), &secret_key, &mut nonce_generator));
+    1
+}
+
+/// Rejects high-S signatures like libsecp256k1
+#[no_mangle]
+pub unsafe extern "C" fn secp256k1_ecdsa_verify(_ctx: *const Context, sig: *const EcdsaSignature, msghash32: *const c_uchar, pubkey: *const Pubkey) -> c_int {
+    let signature = sig.as_ref().and_then(EcdsaSignature::load);
+    let public_key = pubkey.as_ref().and_then(Pubkey::load);
+    match (signature, read_32(msghash32), public_key) {
+        (Some(signature), Some(msg32), Some(public_key)) => bool_to_int(ecdsa::verify_strict(&Message::from_digest(*msg32), &signature, &public_key).is_ok()),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ptr::{null, null_mut};
+    use super::*;
+    use crate::ecdsa;
+    use crate::test_util::hex;
+
+    #[test]
+    fn keys() {
+        unsafe {
+            let ctx = secp256k1_context_create(0x0301);
+            assert!(!ctx.is_null());
+            let seckey = [0x42; 32];
+            assert_eq!(secp256k1_ec_seckey_verify(ctx, seckey.as_ptr()), 1);
+            assert_eq!(secp256k1_ec_seckey_verify(ctx, [0xFF; 32].as_ptr()), 0);
+            assert_eq!(secp256k1_ec_seckey_verify(ctx, null()), 0);
+
+            let mut pubkey = Pubkey { data: [0xAA; 64] };
+            assert_eq!(secp256k1_ec_pubkey_create(ctx, &mut pubkey, [0; 32].as_ptr()), 0);
+            assert_eq!(pubkey.data, [0; 64]);
+            assert_eq!(secp256k1_ec_pubkey_create(ctx, null_mut(), seckey.as_ptr()), 0);
+            assert_eq!(secp256k1_ec_pubkey_create(ctx, &mut pubkey, seckey.as_ptr()), 1);
+
+            let expected = SecretKey::from_bytes(&seckey).unwrap().public_key();
+            let mut output = [0; 65];
+            let mut len = 65;
+            assert_eq!(secp256k1_ec_pubkey_serialize(ctx, output.as_mut_ptr(), &mut len, &pubkey, EC_COMPRESSED), 1);
+            assert_eq!(output[..len], expected.serialize());
+            len = 65;
+            assert_eq!(secp256k1_ec_pubkey_serialize(ctx, output.as_mut_ptr(), &mut len, &pubkey, EC_UNCOMPRESSED), 1);
+            assert_eq!(output[..len], expected.serialize_uncompressed());
+            len = 64;
+            assert_eq!(secp256k1_ec_pubkey_serialize(ctx, output.as_mut_ptr(), &mut len, &pubkey, EC_UNCOMPRESSED), 0);
+            assert_eq!(secp256k1_ec_pubkey_serialize(ctx, output.as_mut_ptr(), &mut len, &pubkey, 0), 0);
+            assert_eq!(secp256k1_ec_pubkey_serialize(ctx, output.as_mut_ptr(), null_mut(), &pubkey, EC_COMPRESSED), 0);
+            assert_eq!(secp256k1_ec_pubkey_serialize(ctx, output.as_mut_ptr(), &mut len, &Pubkey { data: [0; 64] }, EC_COMPRESSED), 0);
+
+            let mut parsed = Pubkey { data: [0; 64] };
+            let compressed = expected.serialize();
+            assert_eq!(secp256k1_ec_pubkey_parse(ctx, &mut parsed, compressed.as_ptr(), 33), 1);
+            assert_eq!(parsed.data, pubkey.data);
+            assert_eq!(secp256k1_ec_pubkey_parse(ctx, &mut parsed, compressed.as_ptr(), 32), 0);
+            assert_eq!(parsed.data, [0; 64]);
+            assert_eq!(secp256k1_ec_pubkey_parse(ctx, &mut parsed, null(), 33), 0);
+            secp256k1_context_destroy(ctx);
+        }
+    }
+
+    #[test]
+    fn signatures() {
+        unsafe {
+            let ctx = null();
+            let seckey = [0x42; 32];
+            let msg = [0x07; 32];
+            let mut pubkey = Pubkey { data: [0; 64] };
+            assert_eq!(secp256k1_ec_pubkey_create(ctx, &mut pubkey, seckey.as_ptr()), 1);
+
+            let mut sig = EcdsaSignature { data: [0; 64] };
+            assert_eq!(secp256k1_ecdsa_sign(ctx, &mut sig, msg.as_ptr(), seckey.as_ptr(), null(), null()), 1);
+            let secret_key = SecretKey::from_bytes(&seckey).unwrap();
+            assert_eq!(sig.data, ecdsa::sign(&Message::from_digest(msg), &secret_key).serialize_compact());
+            assert_eq!(secp256k1_ecdsa_verify(ctx, &sig, msg.as_ptr(), &pubkey), 1);
+            assert_eq!(secp256k1_ecdsa_verify(ctx, &sig, [0x08; 32].as_ptr(), &pubkey), 0);
+            assert_eq!(secp256k1_ecdsa_verify(ctx, &sig, msg.as_ptr(), null()), 0);
+
+            let extra = [0x01; 32];
+            let mut with_extra = EcdsaSignature { data: [0; 64] };
+            assert_eq!(secp256k1_ecdsa_sign(ctx, &mut with_extra, msg.as_ptr(), seckey.as_ptr(), null(), extra.as_ptr().cast()), 1);
+            assert_ne!(with_extra.data, sig.data);
+            assert_eq!(secp256k1_ecdsa_verify(ctx, &with_extra, msg.as_ptr(), &pubkey), 1);
+            let noncefp = core::ptr::dangling::<c_void>();
+            assert_eq!(secp256k1_ecdsa_sign(ctx, &mut with_extra, msg.as_ptr(), seckey.as_ptr(), noncefp, null()), 0);
+            assert_eq!(with_extra.data, [0; 64]);
+
+            // high-S is rejected until normalized
+            let mut high = EcdsaSignature { data: [0; 64] };
+            let mut signature = ecdsa::Signature::from_compact(&sig.data).unwrap();
+            signature = ecdsa::Signature::from_scalars(signature.r(), -signature.s()).unwrap();
+            let compact = signature.serialize_compact();
+            assert_eq!(secp256k1_ecdsa_signature_parse_compact(ctx, &mut high, compact.as_ptr()), 1);
+            assert_eq!(secp256k1_ecdsa_verify(ctx, &high, msg.as_ptr(), &pubkey), 0);
+            let mut normalized = EcdsaSignature { data: [0; 64] };
+            assert_eq!(secp256k1_ecdsa_signature_normalize(ctx, &mut normalized, &high), 1);
+            assert_eq!(secp256k1_ecdsa_signature_normalize(ctx, null_mut(), &normalized), 0);
+            assert_eq!(normalized.data, sig.data);
+
+            let mut der = [0; 72];
+            let mut len = der.len();
+            assert_eq!(secp256k1_ecdsa_signature_serialize_der(ctx, der.as_mut_ptr(), &mut len, &sig), 1);
+            let mut parsed = EcdsaSignature { data: [0; 64] };
+            assert_eq!(secp256k1_ecdsa_signature_parse_der(ctx, &mut parsed, der.as_ptr(), len), 1);
+            assert_eq!(parsed.data, sig.data);
+            assert_eq!(secp256k1_ecdsa_signature_parse_der(ctx, &mut parsed, der.as_ptr(), len - 1), 0);
+            let mut short = len - 1;
+            assert_eq!(secp256k1_ecdsa_signature_serialize_der(ctx, der.as_mut_ptr(), &mut short, &sig), 0);
+            let mut compact = [0; 64];
+            assert_eq!(secp256k1_ecdsa_signature_serialize_compact(ctx, compact.as_mut_ptr(), &sig), 1);
+            assert_eq!(compact, sig.data);
+            assert_eq!(secp256k1_ecdsa_signature_serialize_compact(ctx, null_mut(), &sig), 0);
+            assert_eq!(secp256k1_ecdsa_signature_parse_compact(ctx, &mut parsed, [0xFF; 64].as_ptr()), 0);
+        }
+    }
+
+    #[test]
+    fn tweaks() {
+        unsafe {
+            let ctx = secp256k1_context_create(0);
+            let mut seckey = [0x42; 32];
+            let mut pubkey = Pubkey { data: [0; 64] };
+            assert_eq!(secp256k1_ec_pubkey_create(ctx, &mut pubkey, seckey.as_ptr()), 1);
+            let tweak = [0x03; 32];
+            let check = |seckey: &[u8; 32], pubkey: &Pubkey| {
+                let mut expected = Pubkey { data: [0; 64] };
+                assert_eq!(secp256k1_ec_pubkey_create(ctx, &mut expected, seckey.as_ptr()), 1);
+                assert_eq!(expected.data, pubkey.data);
+            };
+
+            assert_eq!(secp256k1_ec_seckey_tweak_add(ctx, seckey.as_mut_ptr(), tweak.as_ptr()), 1);
+            assert_eq!(secp256k1_ec_pubkey_tweak_add(ctx, &mut pubkey, tweak.as_ptr()), 1);
+            check(&seckey, &pubkey);
+            assert_eq!(secp256k1_ec_seckey_tweak_mul(ctx, seckey.as_mut_ptr(), tweak.as_ptr()), 1);
+            assert_eq!(secp256k1_ec_pubkey_tweak_mul(ctx, &mut pubkey, tweak.as_ptr()), 1);
+            check(&seckey, &pubkey);
+            assert_eq!(secp256k1_ec_seckey_negate(ctx, seckey.as_mut_ptr()), 1);
+            assert_eq!(secp256k1_ec_pubkey_negate(ctx, &mut pubkey), 1);
+            check(&seckey, &pubkey);
+
+            // invalid tweaks zero the key
+            let mut copy = seckey;
+            assert_eq!(secp256k1_ec_seckey_tweak_mul(ctx, copy.as_mut_ptr(), [0; 32].as_ptr()), 0);
+            assert_eq!(copy, [0; 32]);
+            let mut copy = pubkey;
+            assert_eq!(secp256k1_ec_pubkey_tweak_add(ctx, &mut copy, [0xFF; 32].as_ptr()), 0);
+            assert_eq!(copy.data, [0; 64]);
+            assert_eq!(secp256k1_ec_pubkey_tweak_add(ctx, &mut pubkey, null()), 0);
+            assert_eq!(secp256k1_ec_seckey_tweak_add(ctx, null_mut(), tweak.as_ptr()), 0);
+
+            // adding the negated key gives zero
+            let mut negated = seckey;
+            assert_eq!(secp256k1_ec_seckey_negate(ctx, negated.as_mut_ptr()), 1);
+            assert_eq!(secp256k1_ec_seckey_tweak_add(ctx, seckey.as_mut_ptr(), negated.as_ptr()), 0);
+            assert_eq!(seckey, [0; 32]);
+
+            let uncompressed = hex("0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8");
+            assert_eq!(secp256k1_ec_pubkey_parse(ctx, &mut pubkey, uncompressed.as_ptr(), uncompressed.len()), 1);
+            assert_eq!(pubkey.data[..], uncompressed[1..]);
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Fast arithmetic in the secp256k1 field on reduced `U256` values
+//!
+//! Unlike the generic operators of `FieldElement` this uses the special form `p = 2²⁵⁶ - c` of
+//! the field size to reduce full products and all functions except `sqrt` are `const`. It's used
+//! to evaluate constants at compile time and in batch operations. This is **NOT CONSTANT
+//! TIME!!!**
+
+use core::cmp::Ordering;
+use crate::params::P;
+use crate::U256;
+
+/// `2²⁵⁶ - p`
+const C: U256 = U256([0x00000001_000003D1, 0, 0, 0]);
+
+pub(crate) const fn eq(a: U256, b: U256) -> bool {
+    matches!(a.const_cmp(&b), Ordering::Equal)
+}
+
+/// Subtracts `p` once if needed, `carry` is the 257th bit of the value
+pub(crate) const fn reduce_once(value: U256, carry: bool) -> U256 {
+    if carry || !matches!(value.const_cmp(&P), Ordering::Less) {
+        value.wrapping_sub(P)
+    } else {
+        value
+    }
+}
+
+pub(crate) const fn add(a: U256, b: U256) -> U256 {
+    let (sum, carry) = a.overflowing_add(b);
+    reduce_once(sum, carry)
+}
+
+pub(crate) const fn mul(a: U256, b: U256) -> U256 {
+    let (low, high) = a.widening_mul(b);
+    reduce_wide(low, high)
+}
+
+/// Reduces `high·2²⁵⁶ + low` modulo `p`
+pub(crate) const fn reduce_wide(low: U256, high: U256) -> U256 {
+    // high·2²⁵⁶ ≡ high·c, the product has at most 289 bits
+    let (low2, high2) = high.widening_mul(C);
+    let (sum, carry) = low.overflowing_add(low2);
+    // high2 < 2³³ so this fits into 67 bits
+    let extra = high2.wrapping_add(U256([carry as u64, 0, 0, 0])).overflowing_mul(C).0;
+    let (sum, carry) = sum.overflowing_add(extra);
+    if carry {
+        // the sum wrapped so it's less than `extra` and adding c doesn't overflow
+        reduce_once(sum.wrapping_add(C), false)
+    } else {
+        reduce_once(sum, false)
+    }
+}
+
+pub(crate) const fn pow(base: U256, exp: U256) -> U256 {
+    let mut res = U256::one();
+    let mut bit = 256;
+    while bit > 0 {
+        bit -= 1;
+        res = mul(res, res);
+        if exp.bit(bit) {
+            res = mul(res, base);
+        }
+    }
+    res
+}
+
+/// Multiplies the elements pairwise, four at a time using AVX2 if it's enabled and available.
+///
+/// # Panics
+///
+/// If the slices have different lengths.
+#[cfg(feature = "alloc")]
+pub(crate) fn mul_many(a: &[crate::Zp], b: &[crate::Zp]) -> alloc::vec::Vec<crate::Zp> {
+    assert_eq!(a.len(), b.len(), "the slices must have the same length");
+    let mut res = alloc::vec::Vec::with_capacity(a.len());
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    for (a, b) in a.chunks_exact(4).zip(b.chunks_exact(4)) {
+        let a = [a[0].0, a[1].0, a[2].0, a[3].0];
+        let b = [b[0].0, b[1].0, b[2].0, b[3].0];
+        match crate::simd::mul4(&a, &b) {
+            Some(products) => res.extend(products.iter().map(|product| crate::Zp::from_reduced(*product))),
+            None => break,
+        }
+    }
+    let done = res.len();
+    res.extend(a[done..].iter().zip(&b[done..]).map(|(a, b)| crate::Zp::from_reduced(mul(a.0, b.0))));
+    res
+}
+
+/// Squares the value `count` times
+const fn square_n(value: U256, count: usize) -> U256 {
+    let mut res = value;
+    let mut i = 0;
+    while i < count {
+        res = mul(res, res);
+        i += 1;
+    }
+    res
+}
+
+/// Computes the square root if it exists using an addition chain for `(p + 1) / 4`.
+///
+/// The chain (the same as libsecp256k1 uses) needs 253 squarings and 13 multiplications
+/// compared to 256 and about 250 of square-and-multiply. `FieldElement::sqrt` uses it for the
+/// secp256k1 field.
+pub(crate) fn sqrt(value: crate::Zp) -> Option<crate::Zp> {
+    let x = U256::from_be_bytes(&value.to_be_bytes());
+    // xₖ = x^(2ᵏ - 1)
+    let x2 = mul(mul(x, x), x);
+    let x3 = mul(mul(x2, x2), x);
+    let x6 = mul(square_n(x3, 3), x3);
+    let x9 = mul(square_n(x6, 3), x3);
+    let x11 = mul(square_n(x9, 2), x2);
+    let x22 = mul(square_n(x11, 11), x11);
+    let x44 = mul(square_n(x22, 22), x22);
+    let x88 = mul(square_n(x44, 44), x44);
+    let x176 = mul(square_n(x88, 88), x88);
+    let x220 = mul(square_n(x176, 44), x44);
+    let x223 = mul(square_n(x220, 3), x3);
+    // (p + 1) / 4 = (2²²³ - 1)·2³¹ + (2²² - 1)·2⁸ + (2² - 1)·2²
+    let root = mul(square_n(x223, 23), x22);
+    let root = mul(square_n(root, 6), x2);
+    let root = square_n(root, 2);
+    if eq(mul(root, root), x) {
+        Some(crate::Zp::from_reduced(root))
+    } else {
+        None
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{mul, pow, sqrt};
+    use crate::params::SQRT_EXPONENT;
+    use crate::test_utils::DeterministicRng;
+    use crate::{Scalar, Zp, G, U256};
+
+    #[test]
+    fn mul_many_matches_operator() {
+        let mut rng = DeterministicRng::new(219);
+        let edge = [Zp::ZERO, Zp::ONE, -Zp::ONE, -Zp::wrapping_from(U256::from(2)), Zp::wrapping_from(U256::one() << 255)];
+        let mut a = edge.iter().flat_map(|a| edge.iter().map(move |_| *a)).collect::<Vec<_>>();
+        let mut b = edge.iter().flat_map(|_| edge.iter().copied()).collect::<Vec<_>>();
+        for _ in 0..100 {
+            a.push((G * Scalar::random(&mut rng)).x());
+            b.push((G * Scalar::random(&mut rng)).y());
+        }
+        // lengths that are not multiples of four exercise the scalar tail
+        for len in (0..10).chain(Some(a.len())) {
+            let products = Zp::mul_many(&a[..len], &b[..len]);
+            assert_eq!(products.len(), len);
+            for ((a, b), product) in a.iter().zip(&b).zip(&products) {
+                assert_eq!(*product, *a * *b, "{:?} · {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn mul_many_different_lengths() {
+        Zp::mul_many(&[Zp::ONE], &[]);
+    }
+
+    #[test]
+    fn sqrt_matches_square_and_multiply() {
+        let mut rng = DeterministicRng::new(213);
+        let mut values = vec![Zp::ZERO, Zp::ONE, -Zp::ONE, Zp::wrapping_from(U256::from(5))];
+        for _ in 0..50 {
+            values.push((G * Scalar::random(&mut rng)).x());
+        }
+        let mut squares = 0;
+        for value in &values {
+            let root = pow(value.0, SQRT_EXPONENT);
+            let expected = if mul(root, root) == value.0 { Some(Zp::from_reduced(root)) } else { None };
+            assert_eq!(sqrt(*value), expected, "{:?}", value);
+            squares += usize::from(expected.is_some());
+        }
+        // both cases are covered
+        assert!(squares > 10 && squares < values.len() - 10);
+    }
+}
@@ -0,0 +1,203 @@
+//! FROST threshold Schnorr signatures
+//!
+//! Keys are generated by a trusted dealer: the dealer picks the group secret, splits it using
+//! Shamir's secret sharing and publishes commitments to the polynomial so that each participant
+//! can check the share it received. The `dkg` module generates the keys without a dealer.
+//! Signing produces BIP340 signatures.
+
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use crate::{Parity, Point, PublicKey, Scalar, XOnlyPoint, G};
+
+pub mod dkg;
+#[cfg(feature = "hashes")]
+mod sign;
+
+#[cfg(feature = "hashes")]
+pub use sign::{aggregate, commit, sign, SignatureShare, SigningCommitments, SigningNonces, SigningPackage};
+
+/// The public key corresponding to the shared secret
+///
+/// It also keeps the dealer's commitments so that the public shares of the participants can be
+/// computed.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GroupPublicKey {
+    public_key: PublicKey,
+    commitments: Vec<VerifiableCommitment>,
+}
+
+impl GroupPublicKey {
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Returns the BIP340 group key and the parity of the full key
+    pub fn x_only_public_key(&self) -> (XOnlyPoint, Parity) {
+        self.public_key.x_only_public_key()
+    }
+
+    /// Computes the public share of the participant
+    pub fn public_share(&self, index: u16) -> Point {
+        public_share(index, &self.commitments)
+    }
+}
+
+/// Share of the group secret held by one participant
+///
+/// The share is the value of the dealer's polynomial at the (non-zero) index of the
+/// participant.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SecretShare {
+    index: u16,
+    value: Scalar,
+}
+
+impl SecretShare {
+    /// Index of the participant, starting from 1
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    pub fn to_scalar(&self) -> Scalar {
+        self.value
+    }
+
+    /// The public counterpart of the share which other participants can compute from the
+    /// commitments
+    pub fn public_share(&self) -> Point {
+        G * self.value
+    }
+
+    /// Checks that the share lies on the polynomial committed to by the dealer.
+    pub fn verify(&self, commitments: &[VerifiableCommitment]) -> bool {
+        public_share(self.index, commitments) == self.public_share()
+    }
+}
+
+/// Doesn't print the share so that it doesn't leak into logs
+impl core::fmt::Debug for SecretShare {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SecretShare {{ index: {}, .. }}", self.index)
+    }
+}
+
+/// Commitment `C_j = a_j·G` to one coefficient of the dealer's polynomial
+///
+/// The first commitment is the group public key.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct VerifiableCommitment(Point);
+
+impl VerifiableCommitment {
+    pub fn to_point(&self) -> Point {
+        self.0
+    }
+}
+
+/// Splits a random group secret into `participants` shares, any `threshold` of which can sign.
+///
+/// # Panics
+///
+/// If `threshold` is zero or greater than `participants`.
+pub fn dealer_keygen<R: RngCore + CryptoRng>(threshold: u16, participants: u16, rng: &mut R) -> (GroupPublicKey, Vec<SecretShare>, Vec<VerifiableCommitment>) {
+    assert!(threshold > 0, "threshold must not be zero");
+    assert!(threshold <= participants, "threshold must not exceed the number of participants");
+
+    let coefficients = (0..threshold).map(|_| Scalar::random(rng)).collect::<Vec<_>>();
+    let shares = (1..=participants)
+        .map(|index| {
+            // Horner's method
+            let x = Scalar::from(u64::from(index));
+            let value = coefficients.iter().rev().fold(Scalar::ZERO, |acc, coefficient| acc * x + *coefficient);
+            SecretShare { index, value }
+        })
+        .collect();
+    let commitments = coefficients.iter().map(|coefficient| VerifiableCommitment(G * *coefficient)).collect::<Vec<_>>();
+    let public_key = PublicKey::from_point(commitments[0].0).expect("random scalar is not zero");
+    (GroupPublicKey { public_key, commitments: commitments.clone() }, shares, commitments)
+}
+
+/// Computes the public share of the participant from the dealer's commitments
+pub fn public_share(index: u16, commitments: &[VerifiableCommitment]) -> Point {
+    let x = Scalar::from(u64::from(index));
+    commitments.iter().rev().fold(Point::AT_INFINITY, |acc, commitment| acc * x + commitment.0)
+}
+
+/// Computes the Lagrange coefficient of `index` for interpolating at zero from the `indices`
+///
+/// `indices` must contain `index` and must not contain duplicates or zero.
+pub(crate) fn lagrange_coefficient(index: u16, indices: &[u16]) -> Scalar {
+    let x = Scalar::from(u64::from(index));
+    let (numerator, denominator) = indices
+        .iter()
+        .filter(|other| **other != index)
+        .map(|other| Scalar::from(u64::from(*other)))
+        .fold((Scalar::ONE, Scalar::ONE), |(numerator, denominator), other| (numerator * other, denominator * (other - x)));
+    numerator / denominator
+}
+
+/// Reconstructs the group secret by interpolating the shares.
+///
+/// This defeats the purpose of threshold signatures and is only useful for testing. The result
+/// is meaningless if there are fewer shares than the threshold.
+pub fn reconstruct_secret(shares: &[SecretShare]) -> Scalar {
+    let indices = shares.iter().map(SecretShare::index).collect::<Vec<_>>();
+    shares
+        .iter()
+        .map(|share| lagrange_coefficient(share.index, &indices) * share.value)
+        .fold(Scalar::ZERO, |acc, value| acc + value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dealer_keygen, public_share, reconstruct_secret};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Scalar, G};
+
+    #[test]
+    fn shares_verify() {
+        let (group_key, shares, commitments) = dealer_keygen(3, 5, &mut DeterministicRng::new(1));
+        assert_eq!(shares.len(), 5);
+        assert_eq!(commitments.len(), 3);
+        assert_eq!(group_key.public_key().to_point(), commitments[0].to_point());
+        for share in &shares {
+            assert!(share.verify(&commitments), "share {}", share.index());
+            assert_eq!(public_share(share.index(), &commitments), share.public_share());
+            assert_eq!(group_key.public_share(share.index()), share.public_share());
+        }
+        let mut corrupted = shares[0];
+        corrupted.value += Scalar::ONE;
+        assert!(!corrupted.verify(&commitments));
+        // a valid share presented under another index
+        corrupted = shares[0];
+        corrupted.index = 2;
+        assert!(!corrupted.verify(&commitments));
+    }
+
+    #[test]
+    fn threshold_subsets() {
+        let (group_key, shares, _) = dealer_keygen(3, 5, &mut DeterministicRng::new(2));
+        let secret = reconstruct_secret(&shares[..3]);
+        assert_eq!(G * secret, group_key.public_key().to_point());
+        for subset in &[[0, 1, 4], [2, 3, 4], [4, 0, 2]] {
+            let subset = subset.iter().map(|i| shares[*i]).collect::<Vec<_>>();
+            assert_eq!(reconstruct_secret(&subset), secret);
+        }
+        // more shares than needed still work
+        assert_eq!(reconstruct_secret(&shares), secret);
+        assert_ne!(reconstruct_secret(&shares[..2]), secret);
+        assert_ne!(reconstruct_secret(&shares[1..3]), secret);
+    }
+
+    #[test]
+    fn single_signer() {
+        let (group_key, shares, commitments) = dealer_keygen(1, 1, &mut DeterministicRng::new(3));
+        assert_eq!(G * shares[0].to_scalar(), group_key.public_key().to_point());
+        assert!(shares[0].verify(&commitments));
+    }
+
+    #[test]
+    #[should_panic]
+    fn threshold_too_high() {
+        dealer_keygen(4, 3, &mut DeterministicRng::new(4));
+    }
+}
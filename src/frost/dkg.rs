@@ -0,0 +1,472 @@
+//! Pedersen distributed key generation (Joint-Feldman with complaints)
+//!
+//! Generates FROST keys without a trusted dealer. Every participant deals a Feldman sharing of
+//! its own random secret (see the `vss` module), the group secret is the sum of the secrets of
+//! the qualified dealers and the share of each participant is the sum of the shares it received
+//! from them. Nobody learns the group secret.
+//!
+//! The protocol is driven by `Participant` and runs in these rounds:
+//!
+//! 1. `Participant::new` returns the `Commitments` to broadcast and a `SharePackage` for each
+//!    other participant which must be sent over a private authenticated channel.
+//! 2. Once the commitments and shares of all others are received each participant broadcasts
+//!    its `Complaints` against the dealers whose share didn't verify or didn't arrive.
+//! 3. Each accused dealer broadcasts `Justification`s - the disputed shares revealed to
+//!    everyone.
+//! 4. `Participant::finish` disqualifies the dealers with invalid commitments or without valid
+//!    justifications and returns the `KeyPackage`.
+//!
+//! Transport is up to the caller, the messages are plain values and the shares are not
+//! encrypted. The broadcast must be reliable - all honest participants have to see the same
+//! commitments, complaints and justifications, otherwise they end up with different keys. Note
+//! that Joint-Feldman allows the last dealer to bias the group key (Gennaro et al., "Secure
+//! Distributed Key Generation for Discrete-Log Based Cryptosystems") which doesn't matter for
+//! Schnorr signatures.
+
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use super::{GroupPublicKey, SecretShare, VerifiableCommitment};
+use crate::sss::{evaluate, random_polynomial};
+use crate::{Error, Point, PublicKey, Scalar, G};
+
+/// Broadcast commitments to the polynomial of the dealer
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Commitments {
+    sender: u16,
+    commitments: Vec<VerifiableCommitment>,
+}
+
+impl Commitments {
+    pub fn sender(&self) -> u16 {
+        self.sender
+    }
+
+    pub fn commitments(&self) -> &[VerifiableCommitment] {
+        &self.commitments
+    }
+}
+
+/// Share of the dealer's secret sent privately to one participant
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SharePackage {
+    sender: u16,
+    recipient: u16,
+    share: Scalar,
+}
+
+impl SharePackage {
+    pub fn sender(&self) -> u16 {
+        self.sender
+    }
+
+    pub fn recipient(&self) -> u16 {
+        self.recipient
+    }
+}
+
+/// Doesn't print the share so that it doesn't leak into logs
+impl core::fmt::Debug for SharePackage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SharePackage {{ sender: {}, recipient: {}, .. }}", self.sender, self.recipient)
+    }
+}
+
+/// Broadcast list of the dealers whose shares the sender didn't accept
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Complaints {
+    sender: u16,
+    accused: Vec<u16>,
+}
+
+impl Complaints {
+    pub fn sender(&self) -> u16 {
+        self.sender
+    }
+
+    pub fn accused(&self) -> &[u16] {
+        &self.accused
+    }
+}
+
+/// Broadcast answer to a complaint revealing the disputed share
+///
+/// The share is public after this but a single share reveals nothing about the secret.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Justification {
+    sender: u16,
+    accuser: u16,
+    share: Scalar,
+}
+
+impl Justification {
+    pub fn sender(&self) -> u16 {
+        self.sender
+    }
+
+    pub fn accuser(&self) -> u16 {
+        self.accuser
+    }
+}
+
+/// The result of the key generation
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct KeyPackage {
+    group_key: GroupPublicKey,
+    share: SecretShare,
+    qualified: Vec<u16>,
+}
+
+impl KeyPackage {
+    pub fn group_key(&self) -> &GroupPublicKey {
+        &self.group_key
+    }
+
+    pub fn share(&self) -> &SecretShare {
+        &self.share
+    }
+
+    /// Indices of the dealers that contributed to the group key in ascending order
+    pub fn qualified(&self) -> &[u16] {
+        &self.qualified
+    }
+}
+
+/// State of one participant of the key generation
+pub struct Participant {
+    index: u16,
+    threshold: u16,
+    /// commitments received from each dealer, indexed by `dealer - 1`
+    commitments: Vec<Option<Vec<VerifiableCommitment>>>,
+    /// shares received from each dealer, indexed by `dealer - 1`
+    shares: Vec<Option<Scalar>>,
+    /// (accuser, accused)
+    complaints: Vec<(u16, u16)>,
+    /// the dealer's polynomial, kept to answer complaints
+    coefficients: Vec<Scalar>,
+}
+
+/// Doesn't print the state so that the secrets don't leak into logs
+impl core::fmt::Debug for Participant {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Participant {{ index: {}, threshold: {}, .. }}", self.index, self.threshold)
+    }
+}
+
+impl Participant {
+    /// Starts the key generation for participant `index` (starting from 1) of `participants`.
+    ///
+    /// Returns the commitments to broadcast and the shares to send to the other participants.
+    ///
+    /// Returns `Error::InvalidThreshold` if `threshold` is zero or greater than `participants`
+    /// and `Error::InvalidParticipant` if `index` is zero or greater than `participants`.
+    pub fn new<R: RngCore + CryptoRng>(index: u16, threshold: u16, participants: u16, rng: &mut R) -> Result<(Self, Commitments, Vec<SharePackage>), Error> {
+        if threshold == 0 || threshold > participants {
+            return Err(Error::InvalidThreshold);
+        }
+        if index == 0 || index > participants {
+            return Err(Error::InvalidParticipant(index));
+        }
+        let coefficients = random_polynomial(Scalar::random(rng), threshold, rng);
+        let commitments = coefficients.iter().map(|coefficient| VerifiableCommitment(G * *coefficient)).collect::<Vec<_>>();
+        let packages = (1..=participants)
+            .filter(|recipient| *recipient != index)
+            .map(|recipient| SharePackage { sender: index, recipient, share: evaluate(&coefficients, recipient) })
+            .collect();
+
+        let mut participant = Participant {
+            index,
+            threshold,
+            commitments: alloc::vec![None; usize::from(participants)],
+            shares: alloc::vec![None; usize::from(participants)],
+            complaints: Vec::new(),
+            coefficients,
+        };
+        participant.commitments[usize::from(index - 1)] = Some(commitments.clone());
+        participant.shares[usize::from(index - 1)] = Some(evaluate(&participant.coefficients, index));
+        Ok((participant, Commitments { sender: index, commitments }, packages))
+    }
+
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the position of the sender in the state or `Error::InvalidParticipant`
+    fn slot(&self, sender: u16) -> Result<usize, Error> {
+        if sender == 0 || usize::from(sender) > self.shares.len() {
+            return Err(Error::InvalidParticipant(sender));
+        }
+        Ok(usize::from(sender - 1))
+    }
+
+    /// Stores the broadcast commitments of another participant.
+    ///
+    /// Returns `Error::InvalidParticipant` if the sender is unknown and `Error::DuplicateIndex`
+    /// if the commitments of the sender were already received.
+    pub fn receive_commitments(&mut self, commitments: &Commitments) -> Result<(), Error> {
+        let slot = self.slot(commitments.sender)?;
+        if self.commitments[slot].is_some() {
+            return Err(Error::DuplicateIndex(commitments.sender));
+        }
+        self.commitments[slot] = Some(commitments.commitments.clone());
+        Ok(())
+    }
+
+    /// Stores the share sent by another participant, it's verified when complaining.
+    ///
+    /// Returns `Error::InvalidParticipant` if the sender is unknown or the share is intended for
+    /// another participant and `Error::DuplicateIndex` if a share from the sender was already
+    /// received.
+    pub fn receive_share(&mut self, package: &SharePackage) -> Result<(), Error> {
+        if package.recipient != self.index {
+            return Err(Error::InvalidParticipant(package.recipient));
+        }
+        let slot = self.slot(package.sender)?;
+        if self.shares[slot].is_some() {
+            return Err(Error::DuplicateIndex(package.sender));
+        }
+        self.shares[slot] = Some(package.share);
+        Ok(())
+    }
+
+    /// Checks whether the share from the dealer is consistent with the dealer's commitments
+    fn verify_share(&self, dealer: u16, recipient: u16, share: Scalar) -> bool {
+        match &self.commitments[usize::from(dealer - 1)] {
+            Some(commitments) if commitments.len() == usize::from(self.threshold) => SecretShare { index: recipient, value: share }.verify(commitments),
+            _ => false,
+        }
+    }
+
+    /// Returns the complaints against the dealers whose shares are missing or don't match their
+    /// commitments.
+    ///
+    /// The complaints have to be broadcast even if empty and also passed to
+    /// `receive_complaints` of this participant.
+    pub fn complaints(&self) -> Complaints {
+        let accused = (1..=self.shares.len() as u16)
+            .filter(|dealer| match self.shares[usize::from(dealer - 1)] {
+                Some(share) => !self.verify_share(*dealer, self.index, share),
+                None => true,
+            })
+            .collect();
+        Complaints { sender: self.index, accused }
+    }
+
+    /// Stores the broadcast complaints of a participant.
+    ///
+    /// Returns `Error::InvalidParticipant` if the sender or an accused dealer is unknown.
+    pub fn receive_complaints(&mut self, complaints: &Complaints) -> Result<(), Error> {
+        self.slot(complaints.sender)?;
+        for accused in &complaints.accused {
+            self.slot(*accused)?;
+        }
+        self.complaints.extend(complaints.accused.iter().map(|accused| (complaints.sender, *accused)));
+        Ok(())
+    }
+
+    /// Answers the complaints against this participant by revealing the disputed shares.
+    pub fn justifications(&self) -> Vec<Justification> {
+        self.complaints
+            .iter()
+            .filter(|(_, accused)| *accused == self.index)
+            .map(|(accuser, _)| Justification { sender: self.index, accuser: *accuser, share: evaluate(&self.coefficients, *accuser) })
+            .collect()
+    }
+
+    /// Finishes the key generation using the broadcast justifications of all participants.
+    ///
+    /// A dealer is qualified if its commitments were received and have the right length and each
+    /// complaint against it is answered by a justification matching the commitments. The
+    /// participant uses the revealed shares in place of the ones it complained about.
+    ///
+    /// Returns `Error::NotEnoughShares` if fewer than `threshold` dealers are qualified since
+    /// then there's no guarantee an honest one contributed and `Error::PointAtInfinity` in the
+    /// negligible case the group key is the point at infinity.
+    pub fn finish(self, justifications: &[Justification]) -> Result<KeyPackage, Error> {
+        let justified = |dealer: u16, accuser: u16| {
+            justifications
+                .iter()
+                .find(|justification| justification.sender == dealer && justification.accuser == accuser && self.verify_share(dealer, accuser, justification.share))
+                .map(|justification| justification.share)
+        };
+        let mut qualified = Vec::new();
+        let mut value = Scalar::ZERO;
+        let mut commitments = alloc::vec![Point::AT_INFINITY; usize::from(self.threshold)];
+        for dealer in 1..=self.shares.len() as u16 {
+            let dealer_commitments = match &self.commitments[usize::from(dealer - 1)] {
+                Some(dealer_commitments) if dealer_commitments.len() == usize::from(self.threshold) => dealer_commitments,
+                _ => continue,
+            };
+            if self.complaints.iter().any(|(accuser, accused)| *accused == dealer && justified(dealer, *accuser).is_none()) {
+                continue;
+            }
+            let share = match self.shares[usize::from(dealer - 1)] {
+                Some(share) if self.verify_share(dealer, self.index, share) => share,
+                // the complaint of this participant was answered
+                _ => justified(dealer, self.index).ok_or(Error::InvalidShare(dealer))?,
+            };
+            qualified.push(dealer);
+            value += share;
+            for (sum, commitment) in commitments.iter_mut().zip(dealer_commitments) {
+                *sum += commitment.0;
+            }
+        }
+        if qualified.len() < usize::from(self.threshold) {
+            return Err(Error::NotEnoughShares(qualified.len()));
+        }
+        let public_key = PublicKey::from_point(commitments[0]).ok_or(Error::PointAtInfinity)?;
+        let commitments = commitments.into_iter().map(VerifiableCommitment).collect();
+        Ok(KeyPackage {
+            group_key: GroupPublicKey { public_key, commitments },
+            share: SecretShare { index: self.index, value },
+            qualified,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Commitments, Complaints, Justification, KeyPackage, Participant, SharePackage};
+    use crate::frost::{reconstruct_secret, SecretShare};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, Scalar, G};
+
+    /// Runs the protocol letting `tamper` modify the shares in transit, `complain` the complaints
+    /// and `answer` modify or drop the justifications, returns the key packages and the secrets
+    /// of the dealers
+    fn run(tamper: impl Fn(&mut SharePackage), complain: impl Fn(&mut Complaints), answer: impl Fn(&mut Justification) -> bool) -> (Vec<KeyPackage>, Vec<Scalar>) {
+        let (threshold, n) = (3, 5);
+        let mut rng = DeterministicRng::new(215);
+        let mut participants = Vec::new();
+        let mut broadcast = Vec::<Commitments>::new();
+        let mut packages = Vec::new();
+        for index in 1..=n {
+            let (participant, commitments, shares) = Participant::new(index, threshold, n, &mut rng).unwrap();
+            assert_eq!(shares.len(), usize::from(n - 1));
+            participants.push(participant);
+            broadcast.push(commitments);
+            packages.extend(shares);
+        }
+        let secrets = participants.iter().map(|participant| participant.coefficients[0]).collect::<Vec<_>>();
+
+        for participant in &mut participants {
+            let index = participant.index();
+            for commitments in broadcast.iter().filter(|commitments| commitments.sender() != index) {
+                participant.receive_commitments(commitments).unwrap();
+            }
+            for package in packages.iter().filter(|package| package.recipient() == index) {
+                let mut package = *package;
+                tamper(&mut package);
+                participant.receive_share(&package).unwrap();
+            }
+        }
+
+        let complaints = participants
+            .iter()
+            .map(|participant| {
+                let mut complaints = participant.complaints();
+                complain(&mut complaints);
+                complaints
+            })
+            .collect::<Vec<_>>();
+        for participant in &mut participants {
+            for complaints in &complaints {
+                participant.receive_complaints(complaints).unwrap();
+            }
+        }
+        let justifications = participants.iter().flat_map(Participant::justifications).filter_map(|mut justification| if answer(&mut justification) { Some(justification) } else { None }).collect::<Vec<_>>();
+        let packages = participants.into_iter().map(|participant| participant.finish(&justifications).unwrap()).collect();
+        (packages, secrets)
+    }
+
+    fn check_keys(packages: &[KeyPackage], secrets: &[Scalar], qualified: &[u16]) {
+        let group_key = packages[0].group_key();
+        let secret = qualified.iter().map(|dealer| secrets[usize::from(dealer - 1)]).fold(Scalar::ZERO, |acc, secret| acc + secret);
+        assert_eq!(group_key.public_key().to_point(), G * secret);
+        for package in packages {
+            assert_eq!(package.group_key(), group_key);
+            assert_eq!(package.qualified(), qualified);
+            assert_eq!(group_key.public_share(package.share().index()), package.share().public_share());
+        }
+        let shares = packages.iter().map(|package| *package.share()).collect::<Vec<SecretShare>>();
+        assert_eq!(reconstruct_secret(&shares[..3]), secret);
+        assert_eq!(reconstruct_secret(&shares[2..]), secret);
+        assert_eq!(reconstruct_secret(&[shares[4], shares[0], shares[2]]), secret);
+        assert_ne!(reconstruct_secret(&shares[..2]), secret);
+    }
+
+    #[test]
+    fn honest_run() {
+        let (packages, secrets) = run(|_| (), |_| (), |_| true);
+        check_keys(&packages, &secrets, &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn bad_dealer_is_excluded() {
+        // dealer 2 sends a bad share to participant 4 and can't justify it
+        let tamper = |package: &mut SharePackage| {
+            if package.sender() == 2 && package.recipient() == 4 {
+                package.share += Scalar::ONE;
+            }
+        };
+        let (packages, secrets) = run(tamper, |_| (), |justification| justification.sender() != 2);
+        check_keys(&packages, &secrets, &[1, 3, 4, 5]);
+
+        // revealing the bad share doesn't help
+        let reveal_bad = |justification: &mut Justification| {
+            if justification.sender() == 2 {
+                justification.share += Scalar::ONE;
+            }
+            true
+        };
+        let (packages, secrets) = run(tamper, |_| (), reveal_bad);
+        check_keys(&packages, &secrets, &[1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn justified_dealer_stays() {
+        // the share of dealer 1 is lost in transit, it reveals the share so participant 5 can use it
+        let tamper = |package: &mut SharePackage| {
+            if package.sender() == 1 && package.recipient() == 5 {
+                package.share = Scalar::ZERO;
+            }
+        };
+        let (packages, secrets) = run(tamper, |_| (), |_| true);
+        check_keys(&packages, &secrets, &[1, 2, 3, 4, 5]);
+
+        // participant 3 falsely accuses dealer 4
+        let complain = |complaints: &mut Complaints| {
+            if complaints.sender() == 3 {
+                complaints.accused.push(4);
+            }
+        };
+        let (packages, secrets) = run(|_| (), complain, |_| true);
+        check_keys(&packages, &secrets, &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn invalid_messages() {
+        let mut rng = DeterministicRng::new(215);
+        assert_eq!(Participant::new(1, 4, 3, &mut rng).map(|_| ()), Err(Error::InvalidThreshold));
+        assert_eq!(Participant::new(1, 0, 3, &mut rng).map(|_| ()), Err(Error::InvalidThreshold));
+        assert_eq!(Participant::new(4, 2, 3, &mut rng).map(|_| ()), Err(Error::InvalidParticipant(4)));
+        assert_eq!(Participant::new(0, 2, 3, &mut rng).map(|_| ()), Err(Error::InvalidParticipant(0)));
+
+        let (mut first, first_commitments, _) = Participant::new(1, 2, 3, &mut rng).unwrap();
+        let (_, second_commitments, second_shares) = Participant::new(2, 2, 3, &mut rng).unwrap();
+        assert_eq!(first.receive_commitments(&first_commitments), Err(Error::DuplicateIndex(1)));
+        first.receive_commitments(&second_commitments).unwrap();
+        assert_eq!(first.receive_commitments(&second_commitments), Err(Error::DuplicateIndex(2)));
+        // second_shares[0] is for participant 1, second_shares[1] for participant 3
+        assert_eq!(first.receive_share(&second_shares[1]), Err(Error::InvalidParticipant(3)));
+        first.receive_share(&second_shares[0]).unwrap();
+        assert_eq!(first.receive_share(&second_shares[0]), Err(Error::DuplicateIndex(2)));
+        assert_eq!(first.receive_complaints(&Complaints { sender: 2, accused: vec![7] }), Err(Error::InvalidParticipant(7)));
+
+        // participant 3 never sent anything
+        assert_eq!(first.complaints().accused(), [3]);
+        first.receive_complaints(&first.complaints()).unwrap();
+        assert_eq!(first.finish(&[]).unwrap().qualified(), [1, 2]);
+        let (alone, _, _) = Participant::new(1, 2, 3, &mut rng).unwrap();
+        assert_eq!(alone.finish(&[]), Err(Error::NotEnoughShares(1)));
+    }
+}
@@ -0,0 +1,258 @@
+//! Two-round FROST signing producing BIP340 signatures
+//!
+//! The binding factors are computed using a BIP340-style tagged hash rather than the
+//! hash-to-field of RFC 9591 so the shares are not interoperable with RFC implementations. The
+//! final signature is an ordinary BIP340 signature: the signers negate their nonces if the group
+//! commitment has odd y coordinate and their shares if the group key does.
+
+use alloc::vec::Vec;
+use core::fmt;
+use rand_core::{CryptoRng, RngCore};
+use super::{lagrange_coefficient, GroupPublicKey, SecretShare};
+use crate::schnorr::challenge;
+use crate::tagged_hash::TaggedHash;
+use crate::{Error, Parity, Point, Scalar, XOnlyPoint, G};
+
+const BINDING_TAG: &str = "FROST/binding";
+
+/// Secret nonces of one signer for a single signing session
+///
+/// Intentionally neither `Copy` nor `Clone` so that the nonces aren't accidentally reused.
+pub struct SigningNonces {
+    hiding: Scalar,
+    binding: Scalar,
+}
+
+/// Doesn't print the nonces so that they don't leak into logs
+impl fmt::Debug for SigningNonces {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SigningNonces(..)")
+    }
+}
+
+/// Public commitments to the nonces sent to the coordinator in the first round
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SigningCommitments {
+    hiding: Point,
+    binding: Point,
+}
+
+/// Everything the signers need for the second round: the message, commitments of all
+/// participating signers and the values derived from them
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SigningPackage {
+    msg: [u8; 32],
+    /// commitments sorted by index together with the binding factors
+    commitments: Vec<(u16, SigningCommitments, Scalar)>,
+    r: XOnlyPoint,
+    r_parity: Parity,
+    key_parity: Parity,
+    challenge: Scalar,
+}
+
+impl SigningPackage {
+    /// Computes the binding factors, the group commitment and the challenge.
+    ///
+    /// # Panics
+    ///
+    /// If there are fewer commitments than the threshold or the indices are zero or duplicated.
+    pub fn new(group_key: &GroupPublicKey, msg: &[u8; 32], commitments: &[(u16, SigningCommitments)]) -> Self {
+        assert!(commitments.len() >= group_key.commitments.len(), "not enough signers");
+        let mut commitments = commitments.to_vec();
+        commitments.sort_by_key(|(index, _)| *index);
+        assert!(commitments[0].0 != 0, "index must not be zero");
+        assert!(commitments.windows(2).all(|pair| pair[0].0 != pair[1].0), "duplicate index");
+
+        let (public_key, key_parity) = group_key.x_only_public_key();
+        let mut encoded = Vec::with_capacity(commitments.len() * 68);
+        for (index, commitment) in &commitments {
+            encoded.extend_from_slice(&index.to_be_bytes());
+            encoded.extend_from_slice(&commitment.hiding.serialize_compressed());
+            encoded.extend_from_slice(&commitment.binding.serialize_compressed());
+        }
+        let hasher = TaggedHash::new(BINDING_TAG);
+        let commitments = commitments
+            .into_iter()
+            .map(|(index, commitment)| {
+                let hash = hasher.hash(&[&public_key.serialize(), msg, &encoded, &index.to_be_bytes()]);
+                (index, commitment, Scalar::from_be_bytes_reduce(&hash))
+            })
+            .collect::<Vec<_>>();
+
+        let r = commitments
+            .iter()
+            .fold(Point::AT_INFINITY, |acc, (_, commitment, rho)| acc + commitment.hiding + commitment.binding * *rho);
+        let (r, r_parity) = XOnlyPoint::from_point(r).expect("the probability of the group commitment being at infinity is negligible");
+        let challenge = challenge(&r.serialize(), &public_key, msg);
+        SigningPackage {
+            msg: *msg,
+            commitments,
+            r,
+            r_parity,
+            key_parity,
+            challenge,
+        }
+    }
+
+    pub fn msg(&self) -> &[u8; 32] {
+        &self.msg
+    }
+
+    fn indices(&self) -> Vec<u16> {
+        self.commitments.iter().map(|(index, _, _)| *index).collect()
+    }
+
+    fn find(&self, index: u16) -> Option<(SigningCommitments, Scalar)> {
+        self.commitments
+            .iter()
+            .find(|(other, _, _)| *other == index)
+            .map(|(_, commitment, rho)| (*commitment, *rho))
+    }
+}
+
+/// Response of one signer computed in the second round
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SignatureShare {
+    index: u16,
+    z: Scalar,
+}
+
+impl SignatureShare {
+    /// Returns `Error::InvalidSignature` if the value is not less than the curve order
+    pub fn from_bytes(index: u16, bytes: &[u8; 32]) -> Result<Self, Error> {
+        let z = Scalar::from_be_bytes(bytes).ok_or(Error::InvalidSignature)?;
+        Ok(SignatureShare { index, z })
+    }
+
+    /// Index of the signer that created the share
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    pub fn serialize(&self) -> [u8; 32] {
+        self.z.to_be_bytes()
+    }
+}
+
+/// Generates the nonces for the first round.
+pub fn commit<R: RngCore + CryptoRng>(rng: &mut R) -> (SigningNonces, SigningCommitments) {
+    let nonces = SigningNonces {
+        hiding: Scalar::random(rng),
+        binding: Scalar::random(rng),
+    };
+    let commitments = SigningCommitments {
+        hiding: G * nonces.hiding,
+        binding: G * nonces.binding,
+    };
+    (nonces, commitments)
+}
+
+/// Computes the signature share of the signer in the second round.
+///
+/// Returns `Error::InvalidNonce` if the package doesn't contain the commitments to `nonces`
+/// under the index of `share`.
+pub fn sign(package: &SigningPackage, nonces: &SigningNonces, share: &SecretShare) -> Result<SignatureShare, Error> {
+    let (commitment, rho) = package.find(share.index()).ok_or(Error::InvalidNonce)?;
+    if commitment.hiding != G * nonces.hiding || commitment.binding != G * nonces.binding {
+        return Err(Error::InvalidNonce);
+    }
+    let k = nonces.hiding + nonces.binding * rho;
+    let k = if package.r_parity == Parity::Odd { -k } else { k };
+    let secret = share.to_scalar();
+    let secret = if package.key_parity == Parity::Odd { -secret } else { secret };
+    let lambda = lagrange_coefficient(share.index(), &package.indices());
+    Ok(SignatureShare {
+        index: share.index(),
+        z: k + package.challenge * lambda * secret,
+    })
+}
+
+/// Verifies the shares and combines them into a BIP340 signature.
+///
+/// Returns `Error::InvalidShare` with the index of the first signer whose share is invalid,
+/// missing or who is not among the signers of the package.
+pub fn aggregate(package: &SigningPackage, shares: &[SignatureShare], group_key: &GroupPublicKey) -> Result<[u8; 64], Error> {
+    let indices = package.indices();
+    if let Some(share) = shares.iter().find(|share| !indices.contains(&share.index)) {
+        return Err(Error::InvalidShare(share.index));
+    }
+    let mut s = Scalar::ZERO;
+    for (index, commitment, rho) in &package.commitments {
+        let share = shares.iter().find(|share| share.index == *index).ok_or(Error::InvalidShare(*index))?;
+        let r = commitment.hiding + commitment.binding * *rho;
+        let r = if package.r_parity == Parity::Odd { -r } else { r };
+        let public_share = group_key.public_share(*index);
+        let public_share = if package.key_parity == Parity::Odd { -public_share } else { public_share };
+        let lambda = lagrange_coefficient(*index, &indices);
+        if G * share.z != r + public_share * (package.challenge * lambda) {
+            return Err(Error::InvalidShare(*index));
+        }
+        s += share.z;
+    }
+    let mut signature = [0; 64];
+    signature[..32].copy_from_slice(&package.r.serialize());
+    signature[32..].copy_from_slice(&s.to_be_bytes());
+    Ok(signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{aggregate, commit, sign, SigningPackage};
+    use crate::frost::{dealer_keygen, SecretShare};
+    use crate::schnorr::verify;
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, Message, Scalar};
+
+    fn run(threshold: u16, participants: u16, signers: &[usize], seed: u64) {
+        let mut rng = DeterministicRng::new(seed);
+        let (group_key, shares, _) = dealer_keygen(threshold, participants, &mut rng);
+        let signers = signers.iter().map(|i| shares[*i]).collect::<Vec<_>>();
+        let msg = [seed as u8; 32];
+        let (nonces, commitments): (Vec<_>, Vec<_>) = signers.iter().map(|_| commit(&mut rng)).unzip();
+        let indexed = signers.iter().map(SecretShare::index).zip(commitments).collect::<Vec<_>>();
+        let package = SigningPackage::new(&group_key, &msg, &indexed);
+        let signature_shares = nonces
+            .iter()
+            .zip(&signers)
+            .map(|(nonces, share)| sign(&package, nonces, share).unwrap())
+            .collect::<Vec<_>>();
+        let signature = aggregate(&package, &signature_shares, &group_key).unwrap();
+        assert_eq!(verify(&Message::from_digest(msg), &signature, &group_key.x_only_public_key().0), Ok(()));
+    }
+
+    #[test]
+    fn two_of_three() {
+        run(2, 3, &[0, 1], 1);
+        run(2, 3, &[2, 0], 2);
+    }
+
+    #[test]
+    fn three_of_five() {
+        run(3, 5, &[0, 2, 4], 3);
+        // more signers than the threshold
+        run(3, 5, &[4, 3, 1, 0], 4);
+    }
+
+    #[test]
+    fn corrupted_share_identified() {
+        let mut rng = DeterministicRng::new(5);
+        let (group_key, shares, _) = dealer_keygen(2, 3, &mut rng);
+        let msg = [0x42; 32];
+        let (nonces_1, commitments_1) = commit(&mut rng);
+        let (nonces_3, commitments_3) = commit(&mut rng);
+        let package = SigningPackage::new(&group_key, &msg, &[(1, commitments_1), (3, commitments_3)]);
+        let share_1 = sign(&package, &nonces_1, &shares[0]).unwrap();
+        let mut share_3 = sign(&package, &nonces_3, &shares[2]).unwrap();
+        share_3.z += Scalar::ONE;
+        assert_eq!(aggregate(&package, &[share_1, share_3], &group_key), Err(Error::InvalidShare(3)));
+        assert_eq!(aggregate(&package, &[share_1], &group_key), Err(Error::InvalidShare(3)));
+
+        // the nonces don't belong to the signer's index
+        assert_eq!(sign(&package, &nonces_1, &shares[2]), Err(Error::InvalidNonce));
+        assert_eq!(sign(&package, &nonces_1, &shares[1]), Err(Error::InvalidNonce));
+        // a valid share claimed by another signer
+        let mut unrelated = sign(&package, &nonces_3, &shares[2]).unwrap();
+        unrelated.index = 2;
+        assert_eq!(aggregate(&package, &[share_1, unrelated], &group_key), Err(Error::InvalidShare(2)));
+    }
+}
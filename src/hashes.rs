@@ -0,0 +1,231 @@
+//! Thin wrappers around the hash implementations
+//!
+//! SHA256 and HMAC-SHA256 come from the built-in implementation unless the `sha2` feature
+//! selects the `sha2` crate. SHA512 and RIPEMD160 always come from external crates and are only
+//! available with the `bitcoin-hashes` feature.
+
+#[cfg(any(not(feature = "sha2"), test))]
+mod builtin;
+
+#[cfg(not(feature = "sha2"))]
+pub(crate) use builtin::{HmacSha256, Sha256};
+#[cfg(all(feature = "bitcoin-hashes", feature = "alloc"))]
+use hmac::{Hmac, Mac};
+#[cfg(all(feature = "bitcoin-hashes", feature = "alloc"))]
+use sha2::{Digest, Sha512};
+#[cfg(all(feature = "bitcoin-hashes", feature = "alloc"))]
+use ripemd::Ripemd160;
+
+/// Incremental SHA256 hasher backed by the `sha2` crate
+#[cfg(feature = "sha2")]
+#[derive(Clone, Default)]
+pub(crate) struct Sha256(sha2::Sha256);
+
+#[cfg(feature = "sha2")]
+impl Sha256 {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        sha2::Digest::update(&mut self.0, data);
+    }
+
+    pub(crate) fn finalize(self) -> [u8; 32] {
+        sha2::Digest::finalize(self.0).into()
+    }
+}
+
+/// Incremental HMAC-SHA256 backed by the `sha2` and `hmac` crates
+#[cfg(feature = "sha2")]
+#[derive(Clone)]
+pub(crate) struct HmacSha256(hmac::Hmac<sha2::Sha256>);
+
+#[cfg(feature = "sha2")]
+impl HmacSha256 {
+    pub(crate) fn new(key: &[u8]) -> Self {
+        HmacSha256(hmac::Mac::new_from_slice(key).expect("HMAC accepts keys of any length"))
+    }
+
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        hmac::Mac::update(&mut self.0, data);
+    }
+
+    pub(crate) fn finalize(self) -> [u8; 32] {
+        hmac::Mac::finalize(self.0).into_bytes().into()
+    }
+}
+
+pub(crate) fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hasher.finalize()
+}
+
+/// SHA256 over concatenation of `chunks`
+pub(crate) fn sha256_chunks(chunks: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for chunk in chunks {
+        hasher.update(chunk);
+    }
+    hasher.finalize()
+}
+
+/// SHA256(SHA256(data)) as used by Bitcoin
+pub(crate) fn sha256d(data: &[u8]) -> [u8; 32] {
+    sha256(&sha256(data))
+}
+
+/// RIPEMD160(SHA256(data)) as used by Bitcoin
+#[cfg(all(feature = "bitcoin-hashes", feature = "alloc"))]
+pub(crate) fn hash160(data: &[u8]) -> [u8; 20] {
+    Ripemd160::digest(sha256(data)).into()
+}
+
+/// HMAC-SHA256 over concatenation of `chunks`
+pub(crate) fn hmac_sha256(key: &[u8], chunks: &[&[u8]]) -> [u8; 32] {
+    let mut mac = HmacSha256::new(key);
+    for chunk in chunks {
+        mac.update(chunk);
+    }
+    mac.finalize()
+}
+
+/// HMAC-SHA512 over concatenation of `chunks`
+#[cfg(all(feature = "bitcoin-hashes", feature = "alloc"))]
+pub(crate) fn hmac_sha512(key: &[u8], chunks: &[&[u8]]) -> [u8; 64] {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    for chunk in chunks {
+        mac.update(chunk);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+/// PBKDF2-HMAC-SHA512 (RFC 8018) producing a single 64-byte block
+#[cfg(feature = "bip39")]
+pub(crate) fn pbkdf2_hmac_sha512(password: &[u8], salt: &[&[u8]], iterations: u32) -> [u8; 64] {
+    let keyed = Hmac::<Sha512>::new_from_slice(password).expect("HMAC accepts keys of any length");
+    let mut mac = keyed.clone();
+    for chunk in salt {
+        mac.update(chunk);
+    }
+    mac.update(&1u32.to_be_bytes());
+    let mut block: [u8; 64] = mac.finalize().into_bytes().into();
+    let mut result = block;
+    for _ in 1..iterations {
+        block = keyed.clone().chain_update(block).finalize().into_bytes().into();
+        for (result, byte) in result.iter_mut().zip(&block) {
+            *result ^= byte;
+        }
+    }
+    result
+}
+
+/// PBKDF2-HMAC-SHA256 (RFC 8018) filling `output` with the derived key
+#[cfg(feature = "bip38")]
+pub(crate) fn pbkdf2_hmac_sha256(password: &[u8], salt: &[u8], iterations: u32, output: &mut [u8]) {
+    let keyed = HmacSha256::new(password);
+    for (i, chunk) in output.chunks_mut(32).enumerate() {
+        let mut mac = keyed.clone();
+        mac.update(salt);
+        mac.update(&(i as u32 + 1).to_be_bytes());
+        let mut block = mac.finalize();
+        let mut result = block;
+        for _ in 1..iterations {
+            let mut mac = keyed.clone();
+            mac.update(&block);
+            block = mac.finalize();
+            for (result, byte) in result.iter_mut().zip(&block) {
+                *result ^= byte;
+            }
+        }
+        chunk.copy_from_slice(&result[..chunk.len()]);
+    }
+}
+
+/// HKDF-SHA256 (RFC 5869) filling `okm` with the derived key material.
+///
+/// `info` is the concatenation of the chunks. Panics if `okm` is longer than 255 * 32 bytes.
+pub(crate) fn hkdf_sha256(salt: &[u8], ikm: &[u8], info: &[&[u8]], okm: &mut [u8]) {
+    assert!(okm.len() <= 255 * 32, "HKDF output too long");
+    let prk = hmac_sha256(salt, &[ikm]);
+    let mut block = [0u8; 32];
+    for (i, chunk) in okm.chunks_mut(32).enumerate() {
+        let previous = if i == 0 { &[][..] } else { &block[..] };
+        let mut mac = HmacSha256::new(&prk);
+        mac.update(previous);
+        for part in info {
+            mac.update(part);
+        }
+        mac.update(&[i as u8 + 1]);
+        block = mac.finalize();
+        chunk.copy_from_slice(&block[..chunk.len()]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hkdf_sha256, hmac_sha256, sha256, Sha256};
+    use crate::test_util::{hex, hex32};
+
+    /// NIST FIPS 180-4 examples and the long message from the NESSIE set
+    #[test]
+    fn sha256_vectors() {
+        let vectors: [(&[u8], &str); 4] = [
+            (b"", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+            (b"abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+            (b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq", "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"),
+            (
+                b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+                "cf5b16a778af8380036ce59e7b0492370b249b11e8f07a51afac45037afee9d1",
+            ),
+        ];
+        for (msg, expected) in &vectors {
+            assert_eq!(sha256(msg), hex32(expected));
+        }
+        let mut hasher = Sha256::new();
+        for _ in 0..1000 {
+            hasher.update(&[b'a'; 1000]);
+        }
+        assert_eq!(hasher.finalize(), hex32("cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"));
+    }
+
+    /// RFC 4231 test cases except the truncated one (5)
+    #[test]
+    fn hmac_sha256_vectors() {
+        let long_key = [0xaa; 131];
+        let vectors: [(&[u8], &[u8], &str); 6] = [
+            (&[0x0b; 20], b"Hi There", "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7"),
+            (b"Jefe", b"what do ya want for nothing?", "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"),
+            (&[0xaa; 20], &[0xdd; 50], "773ea91e36800e46854db8ebd09181a72959098b3ef8c122d9635514ced565fe"),
+            (&hex("0102030405060708090a0b0c0d0e0f10111213141516171819"), &[0xcd; 50], "82558a389a443c0ea4cc819899f2083a85f0faa3e578f8077a2e3ff46729665b"),
+            (&long_key, b"Test Using Larger Than Block-Size Key - Hash Key First", "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"),
+            (
+                &long_key,
+                b"This is a test using a larger than block-size key and a larger than block-size data. The key needs to be hashed before being used by the HMAC algorithm.",
+                "9b09ffa71b942fcb27635fbcd5b0e944bfdc63644f0713938a7f51535c3a35e2",
+            ),
+        ];
+        for (key, data, expected) in &vectors {
+            assert_eq!(hmac_sha256(key, &[data]), hex32(expected));
+        }
+    }
+
+    /// RFC 7914 section 11
+    #[test]
+    #[cfg(feature = "bip38")]
+    fn pbkdf2_hmac_sha256_vectors() {
+        let mut output = [0; 64];
+        super::pbkdf2_hmac_sha256(b"passwd", b"salt", 1, &mut output);
+        assert_eq!(output[..], hex("55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783")[..]);
+        super::pbkdf2_hmac_sha256(b"Password", b"NaCl", 80000, &mut output);
+        assert_eq!(output[..], hex("4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56a1d425a1225833549adb841b51c9b3176a272bdebba1d078478f62b397f33c8d")[..]);
+    }
+
+    #[test]
+    fn rfc5869_case_1() {
+        let mut okm = [0; 42];
+        hkdf_sha256(&hex("000102030405060708090a0b0c"), &[0x0b; 22], &[&hex("f0f1f2f3f4f5")[..], &hex("f6f7f8f9")[..]], &mut okm);
+        assert_eq!(okm[..], hex("3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865")[..]);
+    }
+}
@@ -0,0 +1,218 @@
+//! SHA256 (FIPS 180-4) and HMAC-SHA256 (RFC 2104) without dependencies
+//!
+//! Straightforward implementation processing one 64-byte block at a time, the only state is the
+//! chaining value and a buffer of one block. The `sha2` feature replaces it with the optimized
+//! implementation from the `sha2` crate.
+//!
+//! The state is wiped on drop since it's keyed with secrets by RFC 6979 and BIP340.
+
+use crate::zeroize::{Wipe, Zeroizing};
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const INITIAL_STATE: [u32; 8] = [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+
+const BLOCK_LEN: usize = 64;
+
+/// Incremental SHA256 hasher
+#[derive(Clone)]
+pub(crate) struct Sha256 {
+    state: [u32; 8],
+    buffer: [u8; BLOCK_LEN],
+    buffer_len: usize,
+    /// Number of bytes processed so far including the buffered ones
+    len: u64,
+}
+
+impl Default for Sha256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sha256 {
+    pub(crate) fn new() -> Self {
+        Sha256 {
+            state: INITIAL_STATE,
+            buffer: [0; BLOCK_LEN],
+            buffer_len: 0,
+            len: 0,
+        }
+    }
+
+    pub(crate) fn update(&mut self, mut data: &[u8]) {
+        self.len = self.len.wrapping_add(data.len() as u64);
+        if self.buffer_len > 0 {
+            let take = (BLOCK_LEN - self.buffer_len).min(data.len());
+            self.buffer[self.buffer_len..(self.buffer_len + take)].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            data = &data[take..];
+            if self.buffer_len < BLOCK_LEN {
+                return;
+            }
+            compress(&mut self.state, &self.buffer);
+            self.buffer_len = 0;
+        }
+        let mut blocks = data.chunks_exact(BLOCK_LEN);
+        for block in &mut blocks {
+            compress(&mut self.state, block);
+        }
+        let rest = blocks.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffer_len = rest.len();
+    }
+
+    pub(crate) fn finalize(mut self) -> [u8; 32] {
+        let bit_len = self.len.wrapping_mul(8);
+        // padding: 0x80, zeros up to 56 bytes modulo the block length and 64-bit length
+        let padding_len = if self.buffer_len < 56 { 56 - self.buffer_len } else { 120 - self.buffer_len };
+        let mut padding = [0; BLOCK_LEN + 8];
+        padding[0] = 0x80;
+        padding[padding_len..(padding_len + 8)].copy_from_slice(&bit_len.to_be_bytes());
+        self.update(&padding[..(padding_len + 8)]);
+        debug_assert_eq!(self.buffer_len, 0);
+
+        let mut output = [0; 32];
+        for (chunk, word) in output.chunks_exact_mut(4).zip(&self.state) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        output
+    }
+}
+
+impl Drop for Sha256 {
+    fn drop(&mut self) {
+        self.state.wipe();
+        self.buffer.wipe();
+    }
+}
+
+/// Processes one 64-byte block
+fn compress(state: &mut [u32; 8], block: &[u8]) {
+    let mut w = [0u32; 64];
+    for (word, bytes) in w.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+    for i in 16..64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for (k, w) in K.iter().zip(&w) {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(*k).wrapping_add(*w);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = s0.wrapping_add(maj);
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+    }
+    for (state, word) in state.iter_mut().zip(&[a, b, c, d, e, f, g, h]) {
+        *state = state.wrapping_add(*word);
+    }
+}
+
+/// Incremental HMAC-SHA256
+///
+/// Both the inner and outer hashers have the padded key already processed so cloning a keyed
+/// instance avoids hashing the key again.
+#[derive(Clone)]
+pub(crate) struct HmacSha256 {
+    inner: Sha256,
+    outer: Sha256,
+}
+
+impl HmacSha256 {
+    /// Keys longer than the block are hashed first
+    pub(crate) fn new(key: &[u8]) -> Self {
+        let mut padded_key = Zeroizing::new([0; BLOCK_LEN]);
+        if key.len() > BLOCK_LEN {
+            let mut hasher = Sha256::new();
+            hasher.update(key);
+            padded_key[..32].copy_from_slice(&Zeroizing::new(hasher.finalize())[..]);
+        } else {
+            padded_key[..key.len()].copy_from_slice(key);
+        }
+        let mut inner = Sha256::new();
+        inner.update(&Zeroizing::new(padded_key.map(|byte| byte ^ 0x36))[..]);
+        let mut outer = Sha256::new();
+        outer.update(&Zeroizing::new(padded_key.map(|byte| byte ^ 0x5c))[..]);
+        HmacSha256 { inner, outer }
+    }
+
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    pub(crate) fn finalize(self) -> [u8; 32] {
+        let mut outer = self.outer;
+        outer.update(&Zeroizing::new(self.inner.finalize())[..]);
+        outer.finalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::ManuallyDrop;
+    use super::Sha256;
+
+    /// Lengths around the block and padding boundaries, split at various points
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn matches_sha2_crate() {
+        use hmac::{Hmac, Mac};
+        use rand_core::RngCore;
+        use sha2::Digest;
+        use super::HmacSha256;
+        use crate::test_utils::DeterministicRng;
+
+        let mut rng = DeterministicRng::new(227);
+        let mut data = [0; 300];
+        rng.fill_bytes(&mut data);
+        for len in (0..140).chain([191, 192, 193, 255, 256, 300].iter().copied()) {
+            let expected: [u8; 32] = sha2::Sha256::digest(&data[..len]).into();
+            for split in [0, len.min(1), len / 3, len / 2, len].iter().copied() {
+                let mut hasher = Sha256::new();
+                hasher.update(&data[..split]);
+                hasher.update(&data[split..len]);
+                assert_eq!(hasher.finalize(), expected, "len {} split {}", len, split);
+            }
+        }
+        for key_len in [0, 1, 32, 63, 64, 65, 200].iter().copied() {
+            let mut mac = HmacSha256::new(&data[..key_len]);
+            mac.update(&data[100..]);
+            let expected: [u8; 32] = Hmac::<sha2::Sha256>::new_from_slice(&data[..key_len]).unwrap().chain_update(&data[100..]).finalize().into_bytes().into();
+            assert_eq!(mac.finalize(), expected, "key length {}", key_len);
+        }
+    }
+
+    #[test]
+    fn wiped_on_drop() {
+        let mut hasher = ManuallyDrop::new(Sha256::new());
+        hasher.update(&[0x42; 100]);
+        // SAFETY: only the plain arrays are read after the destructor ran
+        unsafe {
+            ManuallyDrop::drop(&mut hasher);
+        }
+        assert_eq!(hasher.state, [0; 8]);
+        assert_eq!(hasher.buffer, [0; 64]);
+    }
+}
@@ -0,0 +1,171 @@
+//! Interactive Schnorr identification protocol
+//!
+//! The prover convinces the verifier that it knows the secret key `x` of `P = x·G` in three
+//! moves:
+//!
+//! 1. The prover picks random `k` and sends the commitment `R = k·G`.
+//! 2. The verifier replies with random challenge `e`.
+//! 3. The prover responds with `s = k + e·x` and the verifier checks `s·G == R + e·P`.
+//!
+//! Responding to two different challenges with the same commitment reveals the secret key so
+//! `ProverState` is consumed by `respond`.
+
+use rand_core::{CryptoRng, RngCore};
+use crate::zeroize::Wipe;
+use crate::{Error, Point, PublicKey, Scalar, SecretKey, G};
+
+/// The first message sent by the prover
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Commitment(Point);
+
+/// The random challenge sent by the verifier
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Challenge(Scalar);
+
+/// The final message sent by the prover
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Response(Scalar);
+
+impl Commitment {
+    /// Parses the compressed point rejecting the point at infinity
+    pub fn from_bytes(bytes: &[u8; 33]) -> Result<Self, Error> {
+        Point::from_sec1_bytes(bytes).map(Commitment)
+    }
+
+    pub fn serialize(&self) -> [u8; 33] {
+        self.0.serialize_compressed()
+    }
+}
+
+macro_rules! impl_scalar_encoding {
+    ($type:ident) => {
+        impl $type {
+            /// Parses 32 big-endian bytes
+            ///
+            /// Returns `Error::InvalidSignature` if the value is not less than the curve order.
+            pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, Error> {
+                Scalar::from_be_bytes(bytes).map($type).ok_or(Error::InvalidSignature)
+            }
+
+            pub fn serialize(&self) -> [u8; 32] {
+                self.0.to_be_bytes()
+            }
+        }
+    }
+}
+
+impl_scalar_encoding!(Challenge);
+impl_scalar_encoding!(Response);
+
+/// The party proving the knowledge of its secret key
+#[derive(Debug)]
+pub struct Prover {
+    secret_key: SecretKey,
+}
+
+impl Prover {
+    pub fn new(secret_key: SecretKey) -> Self {
+        Prover { secret_key }
+    }
+
+    /// Starts a new run of the protocol with a fresh nonce
+    pub fn commit<R: RngCore + CryptoRng>(&self, rng: &mut R) -> (ProverState, Commitment) {
+        let nonce = Scalar::random(rng);
+        let state = ProverState { secret: self.secret_key.to_scalar(), nonce };
+        (state, Commitment(G * nonce))
+    }
+}
+
+/// The prover waiting for the challenge
+///
+/// The nonce is overwritten with zero when the state is dropped.
+pub struct ProverState {
+    secret: Scalar,
+    nonce: Scalar,
+}
+
+impl ProverState {
+    /// Computes the response, consuming the state so that the nonce can't be reused
+    pub fn respond(self, challenge: &Challenge) -> Response {
+        Response(self.nonce + challenge.0 * self.secret)
+    }
+}
+
+impl Drop for ProverState {
+    fn drop(&mut self) {
+        self.nonce.wipe();
+        self.secret.wipe();
+    }
+}
+
+/// Doesn't print the state so that it doesn't leak into logs
+impl core::fmt::Debug for ProverState {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("ProverState(..)")
+    }
+}
+
+/// The party checking the prover
+///
+/// The verifier is stateless, it only has to remember the challenge it sent.
+#[derive(Copy, Clone, Debug)]
+pub struct Verifier;
+
+impl Verifier {
+    /// Picks a random challenge, it must be fresh for each run
+    pub fn challenge<R: RngCore + CryptoRng>(rng: &mut R) -> Challenge {
+        Challenge(Scalar::random(rng))
+    }
+
+    /// Checks that `s·G == R + e·P`
+    pub fn check(public_key: &PublicKey, commitment: &Commitment, challenge: &Challenge, response: &Response) -> bool {
+        G * response.0 == commitment.0 + public_key.to_point() * challenge.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Challenge, Commitment, Prover, ProverState, Response, Verifier};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, Scalar, SecretKey, G};
+
+    #[test]
+    fn honest_run() {
+        let mut rng = DeterministicRng::new(1);
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let public_key = secret_key.public_key();
+        let prover = Prover::new(secret_key);
+        let (state, commitment) = prover.commit(&mut rng);
+        let commitment = Commitment::from_bytes(&commitment.serialize()).unwrap();
+        let challenge = Challenge::from_bytes(&Verifier::challenge(&mut rng).serialize()).unwrap();
+        let response = Response::from_bytes(&state.respond(&challenge).serialize()).unwrap();
+        assert!(Verifier::check(&public_key, &commitment, &challenge, &response));
+
+        let other = SecretKey::from_bytes(&[0x43; 32]).unwrap().public_key();
+        assert!(!Verifier::check(&other, &commitment, &challenge, &response));
+        let other_challenge = Verifier::challenge(&mut rng);
+        assert!(!Verifier::check(&public_key, &commitment, &other_challenge, &response));
+        assert_eq!(Response::from_bytes(&[0xFF; 32]), Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn special_soundness() {
+        // `respond` consumes the state so an honest prover can't be tricked into this but two
+        // states with the same nonce can be constructed here
+        let mut rng = DeterministicRng::new(2);
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let public_key = secret_key.public_key();
+        let nonce = Scalar::random(&mut rng);
+        let commitment = Commitment(G * nonce);
+        let first_challenge = Verifier::challenge(&mut rng);
+        let second_challenge = Verifier::challenge(&mut rng);
+        let first = ProverState { secret: secret_key.to_scalar(), nonce }.respond(&first_challenge);
+        let second = ProverState { secret: secret_key.to_scalar(), nonce }.respond(&second_challenge);
+        assert!(Verifier::check(&public_key, &commitment, &first_challenge, &first));
+        assert!(Verifier::check(&public_key, &commitment, &second_challenge, &second));
+
+        // x = (s₁ - s₂) / (e₁ - e₂)
+        let extracted = (first.0 - second.0) * (first_challenge.0 - second_challenge.0).multiplicative_inverse();
+        assert_eq!(extracted, secret_key.to_scalar());
+    }
+}
@@ -0,0 +1,293 @@
+//! Minimal JSON support needed by the JOSE formats
+//!
+//! Only the members of the top-level object are collected, values other than strings are
+//! validated and skipped.
+
+use alloc::{string::String, vec::Vec};
+use crate::Error;
+
+/// Limits recursion when skipping nested values
+const MAX_DEPTH: usize = 32;
+
+/// Members of a JSON object
+pub(crate) struct Object {
+    /// The value is `None` if it's not a string
+    members: Vec<(String, Option<String>)>,
+}
+
+impl Object {
+    /// Parses the object rejecting duplicate members
+    pub(crate) fn parse(json: &str) -> Result<Self, Error> {
+        let mut parser = Parser { bytes: json.as_bytes(), pos: 0 };
+        let mut members = Vec::new();
+        parser.expect(b'{')?;
+        if !parser.eat(b'}') {
+            loop {
+                let name = parser.string()?;
+                if members.iter().any(|(existing, _)| *existing == name) {
+                    return Err(Error::InvalidJson);
+                }
+                parser.expect(b':')?;
+                let value = if parser.peek() == Some(b'"') {
+                    Some(parser.string()?)
+                } else {
+                    parser.skip_value(0)?;
+                    None
+                };
+                members.push((name, value));
+                if parser.eat(b'}') {
+                    break;
+                }
+                parser.expect(b',')?;
+            }
+        }
+        if parser.peek().is_some() {
+            return Err(Error::InvalidJson);
+        }
+        Ok(Object { members })
+    }
+
+    /// Returns the string value of the member, `Error::InvalidJson` if it's not a string
+    pub(crate) fn get(&self, name: &str) -> Result<Option<&str>, Error> {
+        match self.members.iter().find(|(existing, _)| existing == name) {
+            Some((_, Some(value))) => Ok(Some(value)),
+            Some((_, None)) => Err(Error::InvalidJson),
+            None => Ok(None),
+        }
+    }
+
+    #[cfg(feature = "hashes")]
+    pub(crate) fn contains(&self, name: &str) -> bool {
+        self.members.iter().any(|(existing, _)| existing == name)
+    }
+
+    /// Returns the string value of the member, `Error::MissingJsonMember` if it's not present
+    pub(crate) fn require(&self, name: &str) -> Result<&str, Error> {
+        self.get(name)?.ok_or(Error::MissingJsonMember)
+    }
+}
+
+/// Appends the quoted and escaped string
+pub(crate) fn push_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                out.push_str("\\u00");
+                out.push(char::from(b"0123456789abcdef"[(c as usize) >> 4]));
+                out.push(char::from(b"0123456789abcdef"[(c as usize) & 0xF]));
+            },
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.bytes.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), Error> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(Error::InvalidJson)
+        }
+    }
+
+    fn next_byte(&mut self) -> Result<u8, Error> {
+        let byte = *self.bytes.get(self.pos).ok_or(Error::InvalidJson)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn hex4(&mut self) -> Result<u32, Error> {
+        let digits = self.bytes.get(self.pos..(self.pos + 4)).ok_or(Error::InvalidJson)?;
+        self.pos += 4;
+        let digits = core::str::from_utf8(digits).map_err(|_| Error::InvalidJson)?;
+        // from_str_radix would accept a sign
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(Error::InvalidJson);
+        }
+        u32::from_str_radix(digits, 16).map_err(|_| Error::InvalidJson)
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        self.expect(b'"')?;
+        let mut string = String::new();
+        loop {
+            let start = self.pos;
+            while let Some(&byte) = self.bytes.get(self.pos) {
+                if byte == b'"' || byte == b'\\' || byte < 0x20 {
+                    break;
+                }
+                self.pos += 1;
+            }
+            // the input is str and we only split at ASCII characters
+            string.push_str(core::str::from_utf8(&self.bytes[start..self.pos]).expect("valid UTF-8"));
+            match self.next_byte()? {
+                b'"' => return Ok(string),
+                b'\\' => (),
+                _ => return Err(Error::InvalidJson),
+            }
+            let c = match self.next_byte()? {
+                b'"' => '"',
+                b'\\' => '\\',
+                b'/' => '/',
+                b'b' => '\u{8}',
+                b'f' => '\u{c}',
+                b'n' => '\n',
+                b'r' => '\r',
+                b't' => '\t',
+                b'u' => {
+                    let first = self.hex4()?;
+                    let code = if (0xD800..0xDC00).contains(&first) {
+                        if self.next_byte()? != b'\\' || self.next_byte()? != b'u' {
+                            return Err(Error::InvalidJson);
+                        }
+                        let second = self.hex4()?;
+                        if !(0xDC00..0xE000).contains(&second) {
+                            return Err(Error::InvalidJson);
+                        }
+                        0x10000 + ((first - 0xD800) << 10) + (second - 0xDC00)
+                    } else {
+                        first
+                    };
+                    char::from_u32(code).ok_or(Error::InvalidJson)?
+                },
+                _ => return Err(Error::InvalidJson),
+            };
+            string.push(c);
+        }
+    }
+
+    fn skip_literal(&mut self, literal: &[u8]) -> Result<(), Error> {
+        if self.bytes[self.pos..].starts_with(literal) {
+            self.pos += literal.len();
+            Ok(())
+        } else {
+            Err(Error::InvalidJson)
+        }
+    }
+
+    fn skip_number(&mut self) -> Result<(), Error> {
+        let start = self.pos;
+        while let Some(b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E') = self.bytes.get(self.pos) {
+            self.pos += 1;
+        }
+        let number = core::str::from_utf8(&self.bytes[start..self.pos]).expect("ASCII");
+        // close enough to JSON grammar for skipping
+        match number.parse::<f64>() {
+            Ok(_) if !number.starts_with('+') && !number.starts_with('.') => Ok(()),
+            _ => Err(Error::InvalidJson),
+        }
+    }
+
+    fn skip_value(&mut self, depth: usize) -> Result<(), Error> {
+        if depth > MAX_DEPTH {
+            return Err(Error::InvalidJson);
+        }
+        match self.peek().ok_or(Error::InvalidJson)? {
+            b'"' => self.string().map(drop),
+            b'{' => {
+                self.pos += 1;
+                if self.eat(b'}') {
+                    return Ok(());
+                }
+                loop {
+                    self.string()?;
+                    self.expect(b':')?;
+                    self.skip_value(depth + 1)?;
+                    if self.eat(b'}') {
+                        return Ok(());
+                    }
+                    self.expect(b',')?;
+                }
+            },
+            b'[' => {
+                self.pos += 1;
+                if self.eat(b']') {
+                    return Ok(());
+                }
+                loop {
+                    self.skip_value(depth + 1)?;
+                    if self.eat(b']') {
+                        return Ok(());
+                    }
+                    self.expect(b',')?;
+                }
+            },
+            b't' => self.skip_literal(b"true"),
+            b'f' => self.skip_literal(b"false"),
+            b'n' => self.skip_literal(b"null"),
+            _ => self.skip_number(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use super::{push_string, Object};
+    use crate::Error;
+
+    #[test]
+    fn members() {
+        let object = Object::parse(r#" { "a" : "x\"é😀", "b": [1, -2.5e3, {"c": null}], "d": true } "#).unwrap();
+        assert_eq!(object.get("a"), Ok(Some("x\"é😀")));
+        assert_eq!(object.get("b"), Err(Error::InvalidJson));
+        assert_eq!(object.get("d"), Err(Error::InvalidJson));
+        assert_eq!(object.get("e"), Ok(None));
+        assert_eq!(object.require("e"), Err(Error::MissingJsonMember));
+        assert!(Object::parse("{}").is_ok());
+    }
+
+    #[test]
+    fn invalid() {
+        let invalid = [
+            "", "[]", "{", r#"{"a":"b",}"#, r#"{"a":"b"} x"#, r#"{"a":"b","a":"c"}"#, r#"{"a":tru}"#,
+            r#"{"a":"\x"}"#, r#"{"a":"\ud83d"}"#, "{\"a\":\"\n\"}", r#"{"a":+1}"#, r#"{"a":[1,]}"#,
+        ];
+        for json in &invalid {
+            assert!(Object::parse(json).is_err(), "{}", json);
+        }
+        let deep = "{\"a\":".to_owned() + &"[".repeat(100) + &"]".repeat(100) + "}";
+        assert_eq!(Object::parse(&deep).err(), Some(Error::InvalidJson));
+    }
+
+    #[test]
+    fn escaping() {
+        let mut out = String::new();
+        push_string(&mut out, "a\"b\\c\n\u{1}é");
+        assert_eq!(out, r#""a\"b\\c\n\u0001é""#);
+        let object = Object::parse(&format!("{{\"k\":{}}}", out)).unwrap();
+        assert_eq!(object.get("k"), Ok(Some("a\"b\\c\n\u{1}é")));
+    }
+}
@@ -0,0 +1,458 @@
+//! Secret and public keys
+
+use core::cmp::Ordering;
+use core::fmt;
+use crate::{array_ref_32, Error, Point, Scalar, Zp, U256};
+
+#[cfg(feature = "hashes")]
+mod contract;
+#[cfg(feature = "hashes")]
+mod derive;
+mod spki;
+mod pkcs8;
+#[cfg(feature = "alloc")]
+mod pem;
+#[cfg(feature = "alloc")]
+mod jwk;
+#[cfg(feature = "cose")]
+mod cose;
+
+#[cfg(feature = "hashes")]
+pub use contract::verify_contract;
+
+/// Secret key - a non-zero scalar
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SecretKey(Scalar);
+
+impl SecretKey {
+    /// Returns `None` if the scalar is zero
+    pub fn from_scalar(scalar: Scalar) -> Option<Self> {
+        Self::try_from_scalar(scalar).ok()
+    }
+
+    /// Returns `Error::ZeroScalar` if the scalar is zero
+    pub fn try_from_scalar(scalar: Scalar) -> Result<Self, Error> {
+        if scalar.is_zero() {
+            Err(Error::ZeroScalar)
+        } else {
+            Ok(SecretKey(scalar))
+        }
+    }
+
+    /// Parses 32 big-endian bytes, rejecting zero and values not less than the curve order
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, Error> {
+        Scalar::from_be_bytes(bytes)
+            .and_then(Self::from_scalar)
+            .ok_or(Error::InvalidSecretKey)
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_be_bytes()
+    }
+
+    pub fn to_scalar(&self) -> Scalar {
+        self.0
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from_secret_key(self)
+    }
+}
+
+/// Doesn't print the key so that it doesn't leak into logs
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretKey(..)")
+    }
+}
+
+/// Public key - a point on the curve other than the point at infinity
+///
+/// Intentionally doesn't implement `Ord` - ordering of the internal representation is
+/// meaningless.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PublicKey(Point);
+
+impl PublicKey {
+    pub fn from_secret_key(secret_key: &SecretKey) -> Self {
+        PublicKey(Point::mul_gen(&secret_key.0))
+    }
+
+    /// Returns `None` if the point is at infinity
+    pub fn from_point(point: Point) -> Option<Self> {
+        Self::try_from_point(point).ok()
+    }
+
+    /// Returns `Error::PointAtInfinity` if the point is at infinity
+    pub fn try_from_point(point: Point) -> Result<Self, Error> {
+        if point.is_at_infinity() {
+            Err(Error::PointAtInfinity)
+        } else {
+            Ok(PublicKey(point))
+        }
+    }
+
+    /// Parses the key in compressed or uncompressed SEC1 format
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Point::from_sec1_bytes(bytes).map(PublicKey)
+    }
+
+    /// Parses the raw 64-byte `x || y` encoding used by Ethereum - uncompressed SEC1 without the
+    /// `0x04` prefix.
+    ///
+    /// The errors are the same as in `from_sec1_bytes`.
+    pub fn from_raw(bytes: &[u8; 64]) -> Result<Self, Error> {
+        let mut uncompressed = [0x04; 65];
+        uncompressed[1..].copy_from_slice(bytes);
+        Self::from_sec1_bytes(&uncompressed)
+    }
+
+    /// Parses the raw 64-byte encoding or the 65-byte uncompressed SEC1 encoding.
+    ///
+    /// Convenient for Ethereum tooling which uses both. Returns `Error::InvalidLength` for other
+    /// lengths (compressed keys are not accepted) and `Error::InvalidPrefix` if the 65-byte
+    /// encoding doesn't start with `0x04`.
+    pub fn from_raw_lenient(bytes: &[u8]) -> Result<Self, Error> {
+        match bytes.len() {
+            64 => {
+                let mut raw = [0; 64];
+                raw.copy_from_slice(bytes);
+                Self::from_raw(&raw)
+            },
+            65 => Self::from_sec1_bytes(bytes),
+            len => Err(Error::InvalidLength(len)),
+        }
+    }
+
+    pub fn to_point(&self) -> Point {
+        self.0
+    }
+
+    /// Serializes the key in the raw 64-byte `x || y` encoding used by Ethereum
+    pub fn serialize_raw(&self) -> [u8; 64] {
+        let mut raw = [0; 64];
+        raw.copy_from_slice(&self.serialize_uncompressed()[1..]);
+        raw
+    }
+
+    /// Serializes the key in compressed SEC1 format
+    pub fn serialize(&self) -> [u8; 33] {
+        self.0.serialize_compressed()
+    }
+
+    /// Serializes the key in uncompressed SEC1 format
+    pub fn serialize_uncompressed(&self) -> [u8; 65] {
+        self.0.serialize_uncompressed()
+    }
+
+    /// Compares the keys by their compressed serialization as required by BIP67
+    pub fn cmp_bip67(&self, other: &Self) -> Ordering {
+        self.serialize().cmp(&other.serialize())
+    }
+
+    /// Sorts the keys for use in multisig scripts (BIP67)
+    pub fn sort_lexicographic(keys: &mut [PublicKey]) {
+        keys.sort_unstable_by(PublicKey::cmp_bip67);
+    }
+
+    /// Drops the y coordinate returning its parity separately
+    pub fn x_only_public_key(&self) -> (XOnlyPoint, Parity) {
+        XOnlyPoint::from_point(self.0).expect("public key is never at infinity")
+    }
+
+    /// Adds the keys together.
+    ///
+    /// If the keys come from untrusted parties the sum is vulnerable to rogue-key attacks unless
+    /// the parties prove possession of their secret keys first (see the `pop` module).
+    ///
+    /// Returns `Error::InvalidPublicKey` if there are no keys or the sum is the point at
+    /// infinity.
+    pub fn combine(keys: &[PublicKey]) -> Result<PublicKey, Error> {
+        let sum = keys.iter().fold(Point::AT_INFINITY, |sum, key| sum + key.0);
+        PublicKey::from_point(sum).ok_or(Error::InvalidPublicKey)
+    }
+}
+
+/// Secret key together with its public key so that it doesn't have to be recomputed
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Keypair {
+    secret_key: SecretKey,
+    public_key: PublicKey,
+}
+
+impl Keypair {
+    pub fn from_secret_key(secret_key: &SecretKey) -> Self {
+        Keypair {
+            secret_key: *secret_key,
+            public_key: secret_key.public_key(),
+        }
+    }
+
+    pub fn secret_key(&self) -> &SecretKey {
+        &self.secret_key
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Returns the BIP340 public key and the parity of the full public key
+    pub fn x_only_public_key(&self) -> (XOnlyPoint, Parity) {
+        self.public_key.x_only_public_key()
+    }
+}
+
+/// Parity of the y coordinate
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Parity {
+    Even,
+    Odd,
+}
+
+impl Parity {
+    fn of(value: Zp) -> Self {
+        if value.is_odd() {
+            Parity::Odd
+        } else {
+            Parity::Even
+        }
+    }
+}
+
+/// Point identified only by its x coordinate, the y coordinate is implicitly even (BIP340)
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct XOnlyPoint(Point);
+
+impl XOnlyPoint {
+    /// Returns the x-only point and the parity of the y coordinate that was dropped.
+    ///
+    /// Returns `None` if the point is at infinity.
+    pub fn from_point(point: Point) -> Option<(Self, Parity)> {
+        if point.is_at_infinity() {
+            return None;
+        }
+        let parity = Parity::of(point.y());
+        let point = if parity == Parity::Odd { -point } else { point };
+        Some((XOnlyPoint(point), parity))
+    }
+
+    /// Parses 32-byte big-endian x coordinate rejecting values that are not on the curve.
+    ///
+    /// Returns `Error::NonCanonicalFieldElement` if the value is not less than `P` and
+    /// `Error::NotOnCurve` if there's no point with such x coordinate.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, Error> {
+        let x = Zp::try_from_u256(U256::from_be_bytes(bytes))?;
+        Point::try_from_x(x, false).map(XOnlyPoint)
+    }
+
+    /// Parses the key from a slice, checking its length
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != 32 {
+            return Err(Error::InvalidLength(bytes.len()));
+        }
+        Self::from_bytes(array_ref_32(bytes))
+    }
+
+    /// Serializes the x coordinate as 32 big-endian bytes
+    pub fn serialize(&self) -> [u8; 32] {
+        self.0.x().to_be_bytes()
+    }
+
+    /// Returns the point with even y coordinate
+    pub fn to_point(&self) -> Point {
+        self.0
+    }
+
+    /// Checks that `tweaked` with `tweaked_parity` is `self + tweak·G`, like
+    /// `secp256k1_xonly_pubkey_tweak_add_check`.
+    ///
+    /// Only public data is needed so this allows e.g. verifying that a Taproot output key commits
+    /// to the claimed internal key. Returns `false` if the sum is the point at infinity.
+    pub fn tweak_add_check(&self, tweaked: &XOnlyPoint, tweaked_parity: Parity, tweak: &Scalar) -> bool {
+        match XOnlyPoint::from_point(self.0 + Point::mul_gen(tweak)) {
+            Some((expected, parity)) => expected == *tweaked && parity == tweaked_parity,
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Parity, PublicKey, SecretKey, XOnlyPoint};
+    use crate::{Error, Point, Scalar, G};
+    use crate::test_util::hex;
+    use crate::test_utils::DeterministicRng;
+
+    #[test]
+    fn sec1_round_trip() {
+        let public_key = PublicKey::from_point(G * 42).unwrap();
+        assert_eq!(PublicKey::from_sec1_bytes(&public_key.serialize()), Ok(public_key));
+        assert_eq!(PublicKey::from_sec1_bytes(&public_key.serialize_uncompressed()), Ok(public_key));
+        let negated = PublicKey::from_point(-(G * 42)).unwrap();
+        assert_eq!(PublicKey::from_sec1_bytes(&negated.serialize()), Ok(negated));
+    }
+
+    #[test]
+    fn raw_encoding() {
+        let mut rng = DeterministicRng::new(216);
+        for _ in 0..8 {
+            let public_key = PublicKey::from_point(G * Scalar::random(&mut rng)).unwrap();
+            let raw = public_key.serialize_raw();
+            assert_eq!(raw[..], public_key.serialize_uncompressed()[1..]);
+            assert_eq!(PublicKey::from_raw(&raw), Ok(public_key));
+            assert_eq!(PublicKey::from_raw_lenient(&raw), Ok(public_key));
+            assert_eq!(PublicKey::from_raw_lenient(&public_key.serialize_uncompressed()), Ok(public_key));
+        }
+
+        let public_key = PublicKey::from_point(G).unwrap();
+        assert_eq!(PublicKey::from_raw_lenient(&public_key.serialize()), Err(Error::InvalidLength(33)));
+        assert_eq!(PublicKey::from_raw_lenient(&public_key.serialize_raw()[1..]), Err(Error::InvalidLength(63)));
+        let mut hybrid = public_key.serialize_uncompressed();
+        hybrid[0] = 0x06;
+        assert_eq!(PublicKey::from_raw_lenient(&hybrid), Err(Error::InvalidPrefix(0x06)));
+
+        // off-curve coordinates, the point at infinity and a coordinate not less than p
+        let mut off_curve = public_key.serialize_raw();
+        off_curve[63] ^= 1;
+        assert_eq!(PublicKey::from_raw(&off_curve), Err(Error::NotOnCurve));
+        assert_eq!(PublicKey::from_raw_lenient(&off_curve), Err(Error::NotOnCurve));
+        let mut swapped = [0; 64];
+        swapped[..32].copy_from_slice(&off_curve[32..]);
+        swapped[32..].copy_from_slice(&off_curve[..32]);
+        assert_eq!(PublicKey::from_raw(&swapped), Err(Error::NotOnCurve));
+        assert_eq!(PublicKey::from_raw(&[0; 64]), Err(Error::PointAtInfinity));
+        assert_eq!(PublicKey::from_raw(&[0xFF; 64]), Err(Error::NonCanonicalFieldElement));
+    }
+
+    #[test]
+    fn secret_key_range() {
+        assert_eq!(SecretKey::from_bytes(&[0; 32]), Err(Error::InvalidSecretKey));
+        assert_eq!(SecretKey::from_bytes(&[0xFF; 32]), Err(Error::InvalidSecretKey));
+        let mut one = [0; 32];
+        one[31] = 1;
+        assert_eq!(SecretKey::from_bytes(&one).unwrap().public_key().to_point(), G);
+        assert_eq!(SecretKey::try_from_scalar(Scalar::ZERO), Err(Error::ZeroScalar));
+        assert_eq!(PublicKey::try_from_point(Point::AT_INFINITY), Err(Error::PointAtInfinity));
+        assert_eq!(PublicKey::try_from_point(G).map(|key| key.to_point()), Ok(G));
+    }
+
+    #[test]
+    fn bip67_vectors() {
+        let vectors: &[(&[&str], &[&str])] = &[
+            (
+                &[
+                    "02ff12471208c14bd580709cb2358d98975247d8765f92bc25eab3b2763ed605f8",
+                    "02fe6f0a5a297eb38c391581c4413e084773ea23954d93f7753db7dc0adc188b2f",
+                ],
+                &[
+                    "02fe6f0a5a297eb38c391581c4413e084773ea23954d93f7753db7dc0adc188b2f",
+                    "02ff12471208c14bd580709cb2358d98975247d8765f92bc25eab3b2763ed605f8",
+                ],
+            ),
+            (
+                &[
+                    "02632b12f4ac5b1d1b72b2a3b508c19172de44f6f46bcee50ba33f3f9291e47ed0",
+                    "027735a29bae7780a9755fae7a1c4374c656ac6a69ea9f3697fda61bb99a4f3e77",
+                    "02e2cc6bd5f45edd43bebe7cb9b675f0ce9ed3efe613b177588290ad188d11b404",
+                ],
+                &[
+                    "02632b12f4ac5b1d1b72b2a3b508c19172de44f6f46bcee50ba33f3f9291e47ed0",
+                    "027735a29bae7780a9755fae7a1c4374c656ac6a69ea9f3697fda61bb99a4f3e77",
+                    "02e2cc6bd5f45edd43bebe7cb9b675f0ce9ed3efe613b177588290ad188d11b404",
+                ],
+            ),
+            (
+                &[
+                    "030000000000000000000000000000000000004141414141414141414141414141",
+                    "020000000000000000000000000000000000004141414141414141414141414141",
+                    "020000000000000000000000000000000000004141414141414141414141414140",
+                    "030000000000000000000000000000000000004141414141414141414141414140",
+                ],
+                &[
+                    "020000000000000000000000000000000000004141414141414141414141414140",
+                    "020000000000000000000000000000000000004141414141414141414141414141",
+                    "030000000000000000000000000000000000004141414141414141414141414140",
+                    "030000000000000000000000000000000000004141414141414141414141414141",
+                ],
+            ),
+            (
+                &[
+                    "022df8750480ad5b26950b25c7ba79d3e37d75f640f8e5d9bcd5b150a0f85014da",
+                    "03e3818b65bcc73a7d64064106a859cc1a5a728c4345ff0b641209fba0d90de6e9",
+                    "021f2f6e1e50cb6a953935c3601284925decd3fd21bc445712576873fb8c6ebc18",
+                ],
+                &[
+                    "021f2f6e1e50cb6a953935c3601284925decd3fd21bc445712576873fb8c6ebc18",
+                    "022df8750480ad5b26950b25c7ba79d3e37d75f640f8e5d9bcd5b150a0f85014da",
+                    "03e3818b65bcc73a7d64064106a859cc1a5a728c4345ff0b641209fba0d90de6e9",
+                ],
+            ),
+        ];
+
+        let parse = |keys: &[&str]| keys.iter().map(|key| PublicKey::from_sec1_bytes(&hex(key)).unwrap()).collect::<Vec<_>>();
+        for (input, expected) in vectors {
+            let expected = parse(expected);
+            let mut keys = parse(input);
+            PublicKey::sort_lexicographic(&mut keys);
+            assert_eq!(keys, expected);
+
+            let mut keys = parse(input);
+            keys.sort_by(PublicKey::cmp_bip67);
+            assert_eq!(keys, expected);
+        }
+    }
+
+    #[test]
+    fn x_only_drops_parity() {
+        let (x_only, parity) = XOnlyPoint::from_point(G * 3).unwrap();
+        let (negated, negated_parity) = XOnlyPoint::from_point(-(G * 3)).unwrap();
+        assert_eq!(x_only, negated);
+        assert_ne!(parity, negated_parity);
+        assert!(!x_only.to_point().y().is_odd());
+        assert_eq!(XOnlyPoint::from_bytes(&x_only.serialize()), Ok(x_only));
+        assert_eq!(PublicKey::from_point(G).unwrap().x_only_public_key(), (XOnlyPoint::from_point(G).unwrap().0, Parity::Even));
+    }
+
+    #[test]
+    fn x_only_rejects_invalid() {
+        // x = 5 is not on the curve, neither is P
+        let mut five = [0; 32];
+        five[31] = 5;
+        assert_eq!(XOnlyPoint::from_bytes(&five), Err(Error::NotOnCurve));
+        assert_eq!(XOnlyPoint::from_bytes(&[0xFF; 32]), Err(Error::NonCanonicalFieldElement));
+        assert_eq!(XOnlyPoint::from_slice(&[0; 31]), Err(Error::InvalidLength(31)));
+    }
+
+    #[test]
+    fn tweak_add_check() {
+        let mut rng = DeterministicRng::new(200);
+        let mut parities = [0; 2];
+        for _ in 0..16 {
+            let (internal, _) = XOnlyPoint::from_point(G * Scalar::random(&mut rng)).unwrap();
+            let tweak = Scalar::random(&mut rng);
+            let (tweaked, parity) = XOnlyPoint::from_point(internal.to_point() + G * tweak).unwrap();
+            let wrong_parity = match parity {
+                Parity::Even => { parities[0] += 1; Parity::Odd },
+                Parity::Odd => { parities[1] += 1; Parity::Even },
+            };
+            let (other, _) = XOnlyPoint::from_point(tweaked.to_point() + G).unwrap();
+
+            assert!(internal.tweak_add_check(&tweaked, parity, &tweak));
+            assert!(!internal.tweak_add_check(&tweaked, wrong_parity, &tweak));
+            assert!(!internal.tweak_add_check(&tweaked, parity, &(tweak + Scalar::ONE)));
+            assert!(!internal.tweak_add_check(&other, parity, &tweak));
+        }
+        // both parities were exercised
+        assert!(parities.iter().all(|count| *count > 0), "{:?}", parities);
+
+        // zero tweak keeps the key which has even y
+        let (internal, _) = XOnlyPoint::from_point(G * 7).unwrap();
+        assert!(internal.tweak_add_check(&internal, Parity::Even, &Scalar::ZERO));
+        assert!(!internal.tweak_add_check(&internal, Parity::Odd, &Scalar::ZERO));
+        // the tweak cancelling the key results in the point at infinity
+        let secret = if (G * 7).y().is_odd() { -Scalar::from(7) } else { Scalar::from(7) };
+        assert!(!internal.tweak_add_check(&internal, Parity::Even, &-secret));
+        assert!(!internal.tweak_add_check(&internal, Parity::Odd, &-secret));
+    }
+
+}
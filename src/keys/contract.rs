@@ -0,0 +1,90 @@
+//! Pay-to-contract commitments
+//!
+//! Commits to arbitrary data inside a public key: `P' = P + t·G` where
+//! `t = TaggedHash("toy-secp256k1/pay-to-contract")(P || data)` with `P` compressed. Hashing the
+//! untweaked key prevents replaying the commitment with a different key. The owner of `P` can
+//! spend from `P'` using the secret key `x + t`.
+
+use crate::tagged_hash::TaggedHash;
+use crate::{Scalar, G};
+use super::{PublicKey, SecretKey};
+
+const TWEAK_TAG: &str = "toy-secp256k1/pay-to-contract";
+
+fn contract_tweak(public_key: &PublicKey, data: &[u8]) -> Scalar {
+    Scalar::from_be_bytes_reduce(&TaggedHash::new(TWEAK_TAG).hash(&[&public_key.serialize(), data]))
+}
+
+impl PublicKey {
+    /// Commits to `data` returning the tweaked key and the tweak.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tweaked key is the point at infinity which happens with negligible
+    /// probability.
+    pub fn pay_to_contract(&self, data: &[u8]) -> (PublicKey, Scalar) {
+        let tweak = contract_tweak(self, data);
+        let tweaked = PublicKey::from_point(self.0 + G * tweak)
+            .expect("the probability of the tweak being the negated secret key is negligible");
+        (tweaked, tweak)
+    }
+}
+
+impl SecretKey {
+    /// Computes the secret key of `self.public_key().pay_to_contract(data)`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tweaked key is zero which happens with negligible probability.
+    pub fn pay_to_contract_tweak(&self, data: &[u8]) -> SecretKey {
+        let tweak = contract_tweak(&self.public_key(), data);
+        SecretKey::from_scalar(self.0 + tweak)
+            .expect("the probability of the tweak being the negated secret key is negligible")
+    }
+}
+
+/// Checks that `tweaked` commits to `data` on top of `original`
+pub fn verify_contract(original: &PublicKey, tweaked: &PublicKey, data: &[u8]) -> bool {
+    PublicKey::from_point(original.0 + G * contract_tweak(original, data)).as_ref() == Some(tweaked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verify_contract;
+    use crate::{SecretKey, G};
+
+    #[test]
+    fn secret_matches_public() {
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let public_key = secret_key.public_key();
+        let (tweaked, tweak) = public_key.pay_to_contract(b"contract");
+        assert_eq!(secret_key.pay_to_contract_tweak(b"contract").public_key(), tweaked);
+        assert_eq!(tweaked.to_point(), public_key.to_point() + G * tweak);
+        assert!(verify_contract(&public_key, &tweaked, b"contract"));
+        assert!(!verify_contract(&public_key, &tweaked, b"Contract"));
+        assert!(!verify_contract(&public_key, &tweaked, b""));
+
+        // the tweak depends on the key so the commitment can't be moved to another key
+        let other = SecretKey::from_bytes(&[0x43; 32]).unwrap().public_key();
+        let (other_tweaked, other_tweak) = other.pay_to_contract(b"contract");
+        assert_ne!(other_tweak, tweak);
+        assert!(!verify_contract(&other, &tweaked, b"contract"));
+        assert!(!verify_contract(&public_key, &other_tweaked, b"contract"));
+    }
+
+    #[test]
+    fn nested() {
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let public_key = secret_key.public_key();
+        let (inner, inner_tweak) = public_key.pay_to_contract(b"inner");
+        let (outer, outer_tweak) = inner.pay_to_contract(b"outer");
+        assert_eq!(outer.to_point(), public_key.to_point() + G * (inner_tweak + outer_tweak));
+        let outer_secret = secret_key.pay_to_contract_tweak(b"inner").pay_to_contract_tweak(b"outer");
+        assert_eq!(outer_secret.public_key(), outer);
+        assert!(verify_contract(&inner, &outer, b"outer"));
+        assert!(!verify_contract(&public_key, &outer, b"inner"));
+        assert!(!verify_contract(&public_key, &outer, b"outer"));
+        // order matters
+        assert_ne!(public_key.pay_to_contract(b"outer").0.pay_to_contract(b"inner").0, outer);
+    }
+}
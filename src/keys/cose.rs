@@ -0,0 +1,287 @@
+//! COSE_Key encoding of secp256k1 keys (RFC 9052, RFC 9053, RFC 8812)
+//!
+//! The key is a CBOR map `{1: 2, -1: 8, -2: x, -3: y}` - key type EC2, curve secp256k1 and the
+//! coordinates as 32-byte big-endian byte strings. Secret keys additionally contain `-4: d`.
+//! Encoding uses the deterministic (sorted, shortest-form) CBOR encoding.
+//!
+//! When parsing, `kid` (2), `key_ops` (4), `Base IV` (5) and text labels are ignored and `alg`
+//! (3) must be ES256K (-47) if present. Any other integer label is treated as critical and
+//! rejected with `Error::UnsupportedCoseParameter`. The y coordinate must be present as a byte
+//! string - compressed (boolean y) and x-only keys are rejected with `Error::CompressedPoint`.
+
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use crate::cbor::{self, Decoder, Value};
+use crate::Error;
+use super::{PublicKey, SecretKey};
+
+const KTY: i64 = 1;
+const KID: i64 = 2;
+const ALG: i64 = 3;
+const KEY_OPS: i64 = 4;
+const BASE_IV: i64 = 5;
+const CRV: i64 = -1;
+const X: i64 = -2;
+const Y: i64 = -3;
+const D: i64 = -4;
+
+const KTY_EC2: i64 = 2;
+const CRV_SECP256K1: i64 = 8;
+const ALG_ES256K: i64 = -47;
+
+/// The key parameters relevant to us
+struct CoseKey<'a> {
+    x: Option<Value<'a>>,
+    y: Option<Value<'a>>,
+    d: Option<Value<'a>>,
+}
+
+impl<'a> CoseKey<'a> {
+    fn parse(bytes: &'a [u8]) -> Result<Self, Error> {
+        let mut decoder = Decoder::new(bytes);
+        let len = decoder.map_header()?;
+        let mut labels = Vec::new();
+        let (mut kty, mut crv, mut alg) = (None, None, None);
+        let mut key = CoseKey { x: None, y: None, d: None };
+        for _ in 0..len {
+            let label = match decoder.value()? {
+                Value::Int(label) => label,
+                Value::Text(_) => {
+                    decoder.value()?;
+                    continue;
+                },
+                _ => return Err(Error::InvalidCbor),
+            };
+            if labels.contains(&label) {
+                return Err(Error::InvalidCbor);
+            }
+            labels.push(label);
+            let value = decoder.value()?;
+            match label {
+                KTY => kty = Some(value),
+                CRV => crv = Some(value),
+                ALG => alg = Some(value),
+                X => key.x = Some(value),
+                Y => key.y = Some(value),
+                D => key.d = Some(value),
+                KID | KEY_OPS | BASE_IV => (),
+                label => return Err(Error::UnsupportedCoseParameter(label)),
+            }
+        }
+        if !decoder.is_empty() {
+            return Err(Error::InvalidCbor);
+        }
+
+        match kty.ok_or(Error::MissingCoseParameter)? {
+            Value::Int(KTY_EC2) => (),
+            _ => return Err(Error::UnsupportedAlgorithm),
+        }
+        match crv.ok_or(Error::MissingCoseParameter)? {
+            Value::Int(CRV_SECP256K1) => (),
+            _ => return Err(Error::UnsupportedCurve),
+        }
+        match alg {
+            None | Some(Value::Int(ALG_ES256K)) => (),
+            Some(_) => return Err(Error::UnsupportedAlgorithm),
+        }
+        Ok(key)
+    }
+
+    /// Parses the public key if the coordinates are present
+    fn public_key(&self) -> Result<Option<PublicKey>, Error> {
+        let (x, y) = match (self.x, self.y) {
+            (None, None) => return Ok(None),
+            (None, Some(_)) => return Err(Error::MissingCoseParameter),
+            (Some(_), None) | (Some(_), Some(Value::Bool(_))) => return Err(Error::CompressedPoint),
+            (Some(x), Some(y)) => (x, y),
+        };
+        let mut uncompressed = [0x04; 65];
+        uncompressed[1..33].copy_from_slice(&bytes_32(x)?);
+        uncompressed[33..].copy_from_slice(&bytes_32(y)?);
+        PublicKey::from_sec1_bytes(&uncompressed).map(Some)
+    }
+}
+
+/// Extracts byte string that must be exactly 32 bytes long
+fn bytes_32(value: Value<'_>) -> Result<[u8; 32], Error> {
+    match value {
+        Value::Bytes(bytes) => bytes.try_into().map_err(|_| Error::InvalidLength(bytes.len())),
+        _ => Err(Error::InvalidCbor),
+    }
+}
+
+/// Writes the map header and the public parameters
+fn write_public(out: &mut Vec<u8>, public_key: &PublicKey, len: usize) {
+    let uncompressed = public_key.serialize_uncompressed();
+    cbor::push_map_header(out, len);
+    cbor::push_int(out, KTY);
+    cbor::push_int(out, KTY_EC2);
+    cbor::push_int(out, CRV);
+    cbor::push_int(out, CRV_SECP256K1);
+    cbor::push_int(out, X);
+    cbor::push_bytes(out, &uncompressed[1..33]);
+    cbor::push_int(out, Y);
+    cbor::push_bytes(out, &uncompressed[33..]);
+}
+
+impl PublicKey {
+    /// Encodes the key as EC2 COSE_Key
+    pub fn to_cose_key(&self) -> Vec<u8> {
+        let mut key = Vec::with_capacity(77);
+        write_public(&mut key, self, 4);
+        key
+    }
+
+    /// Parses EC2 COSE_Key, the secret key parameter is ignored if present.
+    pub fn from_cose_key(bytes: &[u8]) -> Result<Self, Error> {
+        CoseKey::parse(bytes)?.public_key()?.ok_or(Error::MissingCoseParameter)
+    }
+}
+
+impl SecretKey {
+    /// Encodes the key as EC2 COSE_Key including the public key
+    pub fn to_cose_key(&self) -> Vec<u8> {
+        let mut key = Vec::with_capacity(112);
+        write_public(&mut key, &self.public_key(), 5);
+        cbor::push_int(&mut key, D);
+        cbor::push_bytes(&mut key, &self.to_bytes());
+        key
+    }
+
+    /// Parses EC2 COSE_Key containing `d`.
+    ///
+    /// The public key parameters are optional but if present they must match the secret key.
+    pub fn from_cose_key(bytes: &[u8]) -> Result<Self, Error> {
+        let key = CoseKey::parse(bytes)?;
+        let public_key = key.public_key()?;
+        let secret_key = SecretKey::from_bytes(&bytes_32(key.d.ok_or(Error::MissingCoseParameter)?)?)?;
+        match public_key {
+            Some(public_key) if public_key != secret_key.public_key() => Err(Error::PublicKeyMismatch),
+            _ => Ok(secret_key),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::hex;
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, PublicKey, Scalar, SecretKey};
+
+    const X_3: &str = "f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+    const Y_3: &str = "388f7b0f632de8140fe337e62a37f3566500a99934c2231b6cb9fd7584b8e672";
+    const D_3: &str = "0000000000000000000000000000000000000000000000000000000000000003";
+
+    fn key_3() -> SecretKey {
+        SecretKey::from_bytes(&crate::test_util::hex32(D_3)).unwrap()
+    }
+
+    fn concat_hex(parts: &[&str]) -> Vec<u8> {
+        hex(&parts.concat())
+    }
+
+    /// Replaces the first occurrence of the pattern
+    fn replace(bytes: &[u8], from: &str, to: &str) -> Vec<u8> {
+        let (from, to) = (hex(from), hex(to));
+        let position = bytes.windows(from.len()).position(|window| window == &from[..]).expect("pattern present");
+        [&bytes[..position], &to[..], &bytes[position + from.len()..]].concat()
+    }
+
+    #[test]
+    fn encoding() {
+        assert_eq!(key_3().public_key().to_cose_key(), concat_hex(&["a4", "0102", "2008", "215820", X_3, "225820", Y_3]));
+        assert_eq!(key_3().to_cose_key(), concat_hex(&["a5", "0102", "2008", "215820", X_3, "225820", Y_3, "235820", D_3]));
+    }
+
+    #[test]
+    fn fixture() {
+        // key 3 with the parameters in non-canonical order, `kid`, `alg`, `key_ops`, a text
+        // label and non-minimal encodings of `crv` and of the length of `y`
+        let key = concat_hex(&[
+            "a8",
+            "02426b31", // kid: h'6b31'
+            "215820", X_3,
+            "0102", // kty: EC2
+            "03382e", // alg: ES256K
+            "201808", // crv: secp256k1
+            "04820102", // key_ops: [sign, verify]
+            "22590020", Y_3,
+            "63757365f6", // "use": null
+        ]);
+        assert_eq!(PublicKey::from_cose_key(&key), Ok(key_3().public_key()));
+        assert_eq!(SecretKey::from_cose_key(&key), Err(Error::MissingCoseParameter));
+    }
+
+    #[test]
+    fn rfc9052_example_key() {
+        // public key of meriadoc.brandybuck@buckland.example from RFC 9052 appendix C.7.1 -
+        // well-formed but P-256
+        let kid = "5824".to_owned() + &b"meriadoc.brandybuck@buckland.example".iter().map(|byte| format!("{:02x}", byte)).collect::<String>();
+        let key = concat_hex(&[
+            "a5",
+            "0102",
+            "02", &kid,
+            "2001",
+            "215820", "65eda5a12577c2bae829437fe338701a10aaa375e1bb5b5de108de439c08551d",
+            "225820", "1e52ed75701163f7f9e40ddf9f341b3dc9ba860af7e0ca7ca7e9eecd0084d19c",
+        ]);
+        assert_eq!(PublicKey::from_cose_key(&key), Err(Error::UnsupportedCurve));
+        let key = concat_hex(&["a5", "0102", "02", &kid, "2008", "215820", X_3, "225820", Y_3]);
+        assert_eq!(PublicKey::from_cose_key(&key), Ok(key_3().public_key()));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut rng = DeterministicRng::new(207);
+        for _ in 0..16 {
+            let secret_key = SecretKey::from_scalar(Scalar::random(&mut rng)).unwrap();
+            let public_key = secret_key.public_key();
+            assert_eq!(SecretKey::from_cose_key(&secret_key.to_cose_key()), Ok(secret_key));
+            assert_eq!(PublicKey::from_cose_key(&secret_key.to_cose_key()), Ok(public_key));
+            assert_eq!(PublicKey::from_cose_key(&public_key.to_cose_key()), Ok(public_key));
+        }
+    }
+
+    #[test]
+    fn compressed_and_x_only_are_rejected() {
+        for y in &["f4", "f5"] {
+            let compressed = concat_hex(&["a4", "0102", "2008", "215820", X_3, "22", y]);
+            assert_eq!(PublicKey::from_cose_key(&compressed), Err(Error::CompressedPoint));
+        }
+        let x_only = concat_hex(&["a3", "0102", "2008", "215820", X_3]);
+        assert_eq!(PublicKey::from_cose_key(&x_only), Err(Error::CompressedPoint));
+        let secret = concat_hex(&["a4", "0102", "2008", "215820", X_3, "235820", D_3]);
+        assert_eq!(SecretKey::from_cose_key(&secret), Err(Error::CompressedPoint));
+    }
+
+    #[test]
+    fn invalid() {
+        let public = key_3().public_key().to_cose_key();
+        let secret = key_3().to_cose_key();
+
+        assert_eq!(PublicKey::from_cose_key(&replace(&public, "0102", "0101")), Err(Error::UnsupportedAlgorithm));
+        assert_eq!(PublicKey::from_cose_key(&replace(&public, "2008", "2001")), Err(Error::UnsupportedCurve));
+        // alg ES256
+        assert_eq!(PublicKey::from_cose_key(&replace(&public, "a40102", "a501020326")), Err(Error::UnsupportedAlgorithm));
+        // unknown critical parameters
+        assert_eq!(PublicKey::from_cose_key(&replace(&public, "a40102", "a5010224f5")), Err(Error::UnsupportedCoseParameter(-5)));
+        assert_eq!(PublicKey::from_cose_key(&replace(&public, "a40102", "a501020a00")), Err(Error::UnsupportedCoseParameter(10)));
+        // duplicate label
+        assert_eq!(PublicKey::from_cose_key(&replace(&public, "a40102", "a501020102")), Err(Error::InvalidCbor));
+        assert_eq!(PublicKey::from_cose_key(&replace(&public, "a40102", "a3")), Err(Error::MissingCoseParameter));
+        assert_eq!(PublicKey::from_cose_key(&replace(&public, "2008", "")[1..]), Err(Error::InvalidCbor));
+        let short_y = replace(&public, "225820", "22581f");
+        assert_eq!(PublicKey::from_cose_key(&short_y[..short_y.len() - 1]), Err(Error::InvalidLength(31)));
+        assert_eq!(PublicKey::from_cose_key(&replace(&public, "225820388f", "225820388e")), Err(Error::NotOnCurve));
+        assert_eq!(PublicKey::from_cose_key(&public[..public.len() - 1]), Err(Error::InvalidCbor));
+        assert_eq!(PublicKey::from_cose_key(&[&public[..], &[0]].concat()), Err(Error::InvalidCbor));
+
+        assert_eq!(SecretKey::from_cose_key(&public), Err(Error::MissingCoseParameter));
+        let mismatched = [&secret[..secret.len() - 1], &[4]].concat();
+        assert_eq!(SecretKey::from_cose_key(&mismatched), Err(Error::PublicKeyMismatch));
+        let zero = [&secret[..secret.len() - 1], &[0]].concat();
+        assert_eq!(SecretKey::from_cose_key(&zero), Err(Error::InvalidSecretKey));
+        // the public key is optional
+        assert_eq!(SecretKey::from_cose_key(&concat_hex(&["a3", "0102", "2008", "235820", D_3])), Ok(key_3()));
+    }
+}
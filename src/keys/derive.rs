@@ -0,0 +1,77 @@
+//! Derivation of secret keys from a master secret and a label
+//!
+//! Version 1 of the construction, which will never change - a different one would get a new salt:
+//!
+//! ```text
+//! okm = HKDF-SHA256(salt = "toy-secp256k1/derive-key/v1", ikm = seed, info = counter || label, L = 64)
+//! key = okm as big-endian integer mod n
+//! ```
+//!
+//! `counter` is a 4-byte big-endian integer starting at zero and incremented only if the key
+//! would be zero, which has negligible probability. It has fixed length and precedes the label so
+//! that distinct `(counter, label)` pairs give distinct `info`. Reducing 64 bytes makes the bias
+//! negligible.
+
+use crate::hashes::hkdf_sha256;
+use crate::zeroize::Zeroizing;
+use crate::Scalar;
+use super::SecretKey;
+
+const SALT: &[u8] = b"toy-secp256k1/derive-key/v1";
+
+impl SecretKey {
+    /// Derives a secret key from the master secret `seed` and `label`, see the module
+    /// documentation for the exact construction.
+    ///
+    /// Different labels give unrelated keys. The seed should have at least 32 bytes of entropy.
+    pub fn derive_from_seed(seed: &[u8], label: &[u8]) -> SecretKey {
+        let mut okm = Zeroizing::new([0; 64]);
+        for counter in 0..=u32::MAX {
+            hkdf_sha256(SALT, seed, &[&counter.to_be_bytes(), label], &mut *okm);
+            if let Some(key) = SecretKey::from_scalar(Scalar::from_be_bytes_wide_reduce(&okm)) {
+                return key;
+            }
+        }
+        unreachable!("2³² zero scalars in a row")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::hex;
+    use crate::SecretKey;
+
+    /// Computed independently from the construction in the module documentation, these must never
+    /// change
+    #[test]
+    fn pinned_vectors() {
+        let vectors: &[(&[u8], &[u8], &str)] = &[
+            (&[0; 32], b"", "920c371b870a30106976973f40d53ac39592f1370ae6fc35811747548cce016e"),
+            (&hex("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"), b"signing", "f54c61f6340dd96cfddff1e07f59af5c5c5d9e3518c0e6bc0c867f532b082cd6"),
+            (&hex("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"), b"encryption", "c02aa488126c512909d81f9caa7d5cc8c39d185a78c23ab490d83f1110edbda7"),
+            (b"master secret", b"toy-secp256k1 test", "53e4e2d817341f38f706011b246d50b81c023f090f83e37d659c1980e21d6b9a"),
+        ];
+        for (seed, label, expected) in vectors {
+            assert_eq!(SecretKey::derive_from_seed(seed, label).to_bytes()[..], hex(expected)[..], "{:?}", label);
+        }
+    }
+
+    #[test]
+    fn labels_give_unrelated_keys() {
+        let seed = [0x42; 32];
+        let labels: &[&[u8]] = &[b"", b"a", b"a\0", b"\0a", b"b", b"ab", b"signing", b"signing "];
+        let keys = labels.iter().map(|label| SecretKey::derive_from_seed(&seed, label).to_scalar()).collect::<Vec<_>>();
+        // a simple relation such as keys differing by a constant would show up as a repeated
+        // difference, distinct differences also imply distinct keys
+        let mut differences = Vec::new();
+        for (i, a) in keys.iter().enumerate() {
+            for (j, b) in keys.iter().enumerate().filter(|(j, _)| *j != i) {
+                let difference = *a - *b;
+                assert!(!difference.is_zero(), "{:?} {:?}", labels[i], labels[j]);
+                assert!(!differences.contains(&difference), "{:?} {:?}", labels[i], labels[j]);
+                differences.push(difference);
+            }
+        }
+        assert_ne!(SecretKey::derive_from_seed(&[0x43; 32], b"a"), SecretKey::derive_from_seed(&seed, b"a"));
+    }
+}
@@ -0,0 +1,148 @@
+//! JSON Web Key encoding of secp256k1 keys (RFC 7517, RFC 7518, RFC 8812)
+//!
+//! `{"kty":"EC","crv":"secp256k1","x":...,"y":...}` with the coordinates encoded as unpadded
+//! base64url of 32 big-endian bytes. Secret keys additionally contain `d`.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use crate::json::{self, Object};
+use crate::{base64, Error};
+use super::{PublicKey, SecretKey};
+
+/// Decodes base64url member that must be exactly 32 bytes long
+fn decode_32(value: &str) -> Result<[u8; 32], Error> {
+    let bytes: Vec<u8> = base64::decode_url(value)?;
+    bytes.as_slice().try_into().map_err(|_| Error::InvalidLength(bytes.len()))
+}
+
+fn push_member(out: &mut String, name: &str, value: &str) {
+    out.push(',');
+    json::push_string(out, name);
+    out.push(':');
+    json::push_string(out, value);
+}
+
+/// Writes the public members without the closing brace
+fn write_public(out: &mut String, public_key: &PublicKey) {
+    let uncompressed = public_key.serialize_uncompressed();
+    out.push_str(r#"{"kty":"EC","crv":"secp256k1""#);
+    push_member(out, "x", &base64::encode_url(&uncompressed[1..33]));
+    push_member(out, "y", &base64::encode_url(&uncompressed[33..]));
+}
+
+/// Checks `kty` and `crv` and parses the public key if `x` and `y` are present
+fn parse_public(object: &Object) -> Result<Option<PublicKey>, Error> {
+    if object.require("kty")? != "EC" {
+        return Err(Error::UnsupportedAlgorithm);
+    }
+    if object.require("crv")? != "secp256k1" {
+        return Err(Error::UnsupportedCurve);
+    }
+    let (x, y) = match (object.get("x")?, object.get("y")?) {
+        (Some(x), Some(y)) => (x, y),
+        (None, None) => return Ok(None),
+        _ => return Err(Error::MissingJsonMember),
+    };
+    let mut uncompressed = [0x04; 65];
+    uncompressed[1..33].copy_from_slice(&decode_32(x)?);
+    uncompressed[33..].copy_from_slice(&decode_32(y)?);
+    PublicKey::from_sec1_bytes(&uncompressed).map(Some)
+}
+
+impl PublicKey {
+    /// Encodes the key as EC JWK
+    pub fn to_jwk(&self) -> String {
+        let mut jwk = String::with_capacity(128);
+        write_public(&mut jwk, self);
+        jwk.push('}');
+        jwk
+    }
+
+    /// Parses EC JWK, the members other than `kty`, `crv`, `x` and `y` are ignored.
+    pub fn from_jwk(jwk: &str) -> Result<Self, Error> {
+        parse_public(&Object::parse(jwk)?)?.ok_or(Error::MissingJsonMember)
+    }
+}
+
+impl SecretKey {
+    /// Encodes the key as EC JWK including the public key
+    pub fn to_jwk(&self) -> String {
+        let mut jwk = String::with_capacity(176);
+        write_public(&mut jwk, &self.public_key());
+        push_member(&mut jwk, "d", &base64::encode_url(&self.to_bytes()));
+        jwk.push('}');
+        jwk
+    }
+
+    /// Parses EC JWK containing `d`.
+    ///
+    /// The public key members are optional but if present they must match the secret key.
+    pub fn from_jwk(jwk: &str) -> Result<Self, Error> {
+        let object = Object::parse(jwk)?;
+        let public_key = parse_public(&object)?;
+        let secret_key = SecretKey::from_bytes(&decode_32(object.require("d")?)?)?;
+        match public_key {
+            Some(public_key) if public_key != secret_key.public_key() => Err(Error::PublicKeyMismatch),
+            _ => Ok(secret_key),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, PublicKey, SecretKey};
+    use crate::test_utils::DeterministicRng;
+
+    /// Secret key 3 with coordinates taken from OpenSSL output, reordered and with extra members
+    const FIXTURE: &str = r#"{
+        "use": "sig",
+        "key_ops": ["sign", "verify"],
+        "crv": "secp256k1",
+        "x": "-TCKAZJYwxBJNE-F-J1SKbUxyEWDb5mwhgHxE7zgNvk",
+        "y": "OI97D2Mt6BQP4zfmKjfzVmUAqZk0wiMbbLn9dYS45nI",
+        "d": "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAM",
+        "kty": "EC"
+    }"#;
+
+    fn key_3() -> SecretKey {
+        let mut bytes = [0; 32];
+        bytes[31] = 3;
+        SecretKey::from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    fn fixture() {
+        assert_eq!(SecretKey::from_jwk(FIXTURE), Ok(key_3()));
+        assert_eq!(PublicKey::from_jwk(FIXTURE), Ok(key_3().public_key()));
+        assert_eq!(key_3().public_key().to_jwk(), r#"{"kty":"EC","crv":"secp256k1","x":"-TCKAZJYwxBJNE-F-J1SKbUxyEWDb5mwhgHxE7zgNvk","y":"OI97D2Mt6BQP4zfmKjfzVmUAqZk0wiMbbLn9dYS45nI"}"#);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut rng = DeterministicRng::new(175);
+        for _ in 0..16 {
+            let secret_key = SecretKey::from_scalar(crate::Scalar::random(&mut rng)).unwrap();
+            assert_eq!(SecretKey::from_jwk(&secret_key.to_jwk()), Ok(secret_key));
+            assert_eq!(PublicKey::from_jwk(&secret_key.to_jwk()), Ok(secret_key.public_key()));
+            assert_eq!(PublicKey::from_jwk(&secret_key.public_key().to_jwk()), Ok(secret_key.public_key()));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let public_jwk = key_3().public_key().to_jwk();
+        assert_eq!(SecretKey::from_jwk(&public_jwk), Err(Error::MissingJsonMember));
+        assert_eq!(PublicKey::from_jwk(&public_jwk.replace("secp256k1", "P-256")), Err(Error::UnsupportedCurve));
+        assert_eq!(PublicKey::from_jwk(&public_jwk.replace("EC", "OKP")), Err(Error::UnsupportedAlgorithm));
+        assert_eq!(PublicKey::from_jwk(&public_jwk.replace("-TCK", "+TCK")), Err(Error::InvalidBase64Character('+')));
+        assert_eq!(PublicKey::from_jwk(&public_jwk.replace("-TCK", "")), Err(Error::InvalidLength(29)));
+        assert_eq!(PublicKey::from_jwk(&public_jwk.replace("OI97", "OI98")), Err(Error::NotOnCurve));
+        assert_eq!(PublicKey::from_jwk(&public_jwk.replace("\"y\"", "\"z\"")), Err(Error::MissingJsonMember));
+
+        let mismatched = FIXTURE.replace("AAAAM", "AAAAQ");
+        assert_eq!(SecretKey::from_jwk(&mismatched), Err(Error::PublicKeyMismatch));
+        let zero = FIXTURE.replace("AAAAM", "AAAAA");
+        assert_eq!(SecretKey::from_jwk(&zero), Err(Error::InvalidSecretKey));
+    }
+}
@@ -0,0 +1,211 @@
+//! PKCS#8 encoding of secret keys (RFC 5208, RFC 5958) wrapping ECPrivateKey (RFC 5915)
+//!
+//! ```text
+//! SEQUENCE {
+//!     INTEGER version (0, or 1 if the public key is present)
+//!     SEQUENCE { OID id-ecPublicKey, OID secp256k1 }
+//!     OCTET STRING ECPrivateKey
+//!     [0] IMPLICIT attributes OPTIONAL
+//!     [1] IMPLICIT BIT STRING public key OPTIONAL
+//! }
+//!
+//! ECPrivateKey ::= SEQUENCE {
+//!     INTEGER 1
+//!     OCTET STRING secret key
+//!     [0] EXPLICIT OID secp256k1 OPTIONAL
+//!     [1] EXPLICIT BIT STRING public key OPTIONAL
+//! }
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use crate::der::{parse_optional, parse_tlv, parse_tlv_exact, BIT_STRING_TAG, INTEGER_TAG, OCTET_STRING_TAG, SEQUENCE_TAG};
+use crate::{DerError, Error};
+use super::spki;
+use super::SecretKey;
+
+const PARAMETERS_TAG: u8 = 0xA0;
+const EC_PUBLIC_KEY_TAG: u8 = 0xA1;
+const ATTRIBUTES_TAG: u8 = 0xA0;
+const PKCS8_PUBLIC_KEY_TAG: u8 = 0x81;
+
+/// Length of the encoding produced by `to_pkcs8_der` - the same as the one produced by OpenSSL
+#[cfg(feature = "alloc")]
+const PKCS8_LEN: usize = 135;
+
+/// Checks that the content of a BIT STRING is the public key of `secret_key`
+fn check_public_key(secret_key: &SecretKey, bits: &[u8]) -> Result<(), Error> {
+    let point = match bits {
+        [0, point @ ..] => point,
+        _ => return Err(DerError::InvalidBitString.into()),
+    };
+    if super::PublicKey::from_sec1_bytes(point)? != secret_key.public_key() {
+        return Err(Error::PublicKeyMismatch);
+    }
+    Ok(())
+}
+
+/// Parses the fields of ECPrivateKey following the version
+fn parse_ec_private_key_fields(content: &[u8]) -> Result<SecretKey, Error> {
+    let (secret, rest) = parse_tlv(content, OCTET_STRING_TAG, DerError::InvalidOctetStringTag)?;
+    let secret = secret.try_into().map_err(|_| Error::InvalidLength(secret.len()))?;
+    let secret_key = SecretKey::from_bytes(secret)?;
+    let (parameters, rest) = parse_optional(rest, PARAMETERS_TAG)?;
+    if let Some(parameters) = parameters {
+        spki::parse_curve(parameters)?;
+    }
+    let (public_key, rest) = parse_optional(rest, EC_PUBLIC_KEY_TAG)?;
+    if let Some(public_key) = public_key {
+        let bits = parse_tlv_exact(public_key, BIT_STRING_TAG, DerError::InvalidBitStringTag)?;
+        check_public_key(&secret_key, bits)?;
+    }
+    if !rest.is_empty() {
+        return Err(DerError::TrailingData.into());
+    }
+    Ok(secret_key)
+}
+
+fn parse_ec_private_key(bytes: &[u8]) -> Result<SecretKey, Error> {
+    let content = parse_tlv_exact(bytes, SEQUENCE_TAG, DerError::InvalidSequenceTag)?;
+    let (version, rest) = parse_tlv(content, INTEGER_TAG, DerError::InvalidIntegerTag)?;
+    if version != [1] {
+        return Err(Error::UnsupportedVersion);
+    }
+    parse_ec_private_key_fields(rest)
+}
+
+impl SecretKey {
+    /// Encodes the key as DER PKCS#8 `PrivateKeyInfo` including the public key in ECPrivateKey
+    #[cfg(feature = "alloc")]
+    pub fn to_pkcs8_der(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(PKCS8_LEN);
+        out.extend_from_slice(&[SEQUENCE_TAG, 0x81, (PKCS8_LEN - 3) as u8, INTEGER_TAG, 1, 0]);
+        let mut algorithm = [0; 18];
+        let algorithm_len = spki::write_algorithm(&mut algorithm);
+        out.extend_from_slice(&algorithm[..algorithm_len]);
+        out.extend_from_slice(&[OCTET_STRING_TAG, 0x6d, SEQUENCE_TAG, 0x6b, INTEGER_TAG, 1, 1, OCTET_STRING_TAG, 32]);
+        out.extend_from_slice(&self.to_bytes());
+        out.extend_from_slice(&[EC_PUBLIC_KEY_TAG, 0x44, BIT_STRING_TAG, 0x42, 0]);
+        out.extend_from_slice(&self.public_key().serialize_uncompressed());
+        debug_assert_eq!(out.len(), PKCS8_LEN);
+        out
+    }
+
+    /// Parses DER PKCS#8 `PrivateKeyInfo`/`OneAsymmetricKey` or a bare SEC1 `ECPrivateKey`.
+    ///
+    /// Attributes are ignored. The curve parameters and the public key are optional but if
+    /// present they must be secp256k1 and the public key of the secret key respectively.
+    pub fn from_pkcs8_der(bytes: &[u8]) -> Result<Self, Error> {
+        let content = parse_tlv_exact(bytes, SEQUENCE_TAG, DerError::InvalidSequenceTag)?;
+        let (version, rest) = parse_tlv(content, INTEGER_TAG, DerError::InvalidIntegerTag)?;
+        // ECPrivateKey has version 1 too but OCTET STRING follows instead of algorithm
+        if version == [1] && rest.first() == Some(&OCTET_STRING_TAG) {
+            return parse_ec_private_key_fields(rest);
+        }
+        let has_public_key = match version {
+            [0] => false,
+            [1] => true,
+            _ => return Err(Error::UnsupportedVersion),
+        };
+        let (algorithm, rest) = parse_tlv(rest, SEQUENCE_TAG, DerError::InvalidSequenceTag)?;
+        spki::parse_algorithm(algorithm)?;
+        let (private_key, rest) = parse_tlv(rest, OCTET_STRING_TAG, DerError::InvalidOctetStringTag)?;
+        let secret_key = parse_ec_private_key(private_key)?;
+        let (_attributes, mut rest) = parse_optional(rest, ATTRIBUTES_TAG)?;
+        if has_public_key {
+            let (public_key, remaining) = parse_optional(rest, PKCS8_PUBLIC_KEY_TAG)?;
+            if let Some(bits) = public_key {
+                check_public_key(&secret_key, bits)?;
+            }
+            rest = remaining;
+        }
+        if !rest.is_empty() {
+            return Err(DerError::TrailingData.into());
+        }
+        Ok(secret_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DerError, Error, PublicKey, SecretKey};
+    use crate::test_util::hex;
+
+    /// `openssl pkcs8 -topk8 -nocrypt -outform DER` for secret key 3
+    const OPENSSL_PKCS8: &str = "308184020100301006072a8648ce3d020106052b8104000a046d306b02010104200000000000000000000000000000000000000000000000000000000000000003a14403420004f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9388f7b0f632de8140fe337e62a37f3566500a99934c2231b6cb9fd7584b8e672";
+    /// `openssl genpkey -algorithm EC -pkeyopt ec_paramgen_curve:secp256k1 -outform DER`
+    const OPENSSL_SEC1: &str = "30740201010420a3172f4f0b21a1690690b6b3c22fed5aa4c329d309a94d3302b68e13fb4cfadaa00706052b8104000aa144034200049ea75b1f8bdba90bb87485c0dc9411cdfc335d815082fb70d6dc1a19372626c290836bbd020191f7ee6e26b5390248523656e19969f8602b11f0f6dac01e612e";
+    /// `openssl pkey -pubout -outform DER` of the above
+    const OPENSSL_SEC1_SPKI: &str = "3056301006072a8648ce3d020106052b8104000a034200049ea75b1f8bdba90bb87485c0dc9411cdfc335d815082fb70d6dc1a19372626c290836bbd020191f7ee6e26b5390248523656e19969f8602b11f0f6dac01e612e";
+
+    fn key_3() -> SecretKey {
+        let mut bytes = [0; 32];
+        bytes[31] = 3;
+        SecretKey::from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    fn openssl_fixtures() {
+        let der = hex(OPENSSL_PKCS8);
+        assert_eq!(SecretKey::from_pkcs8_der(&der), Ok(key_3()));
+        #[cfg(feature = "alloc")]
+        assert_eq!(key_3().to_pkcs8_der(), der);
+
+        let secret_key = SecretKey::from_pkcs8_der(&hex(OPENSSL_SEC1)).unwrap();
+        assert_eq!(Ok(secret_key.public_key()), PublicKey::from_spki_der(&hex(OPENSSL_SEC1_SPKI)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn round_trip() {
+        let mut rng = crate::test_utils::DeterministicRng::new(173);
+        for _ in 0..16 {
+            let secret_key = SecretKey::from_scalar(crate::Scalar::random(&mut rng)).unwrap();
+            assert_eq!(SecretKey::from_pkcs8_der(&secret_key.to_pkcs8_der()), Ok(secret_key));
+        }
+    }
+
+    #[test]
+    fn optional_fields() {
+        // v1 without the public key inside ECPrivateKey
+        let mut minimal = hex("3041020100301306072a8648ce3d020106082a8648ce3d030107042730250201010420");
+        minimal.extend_from_slice(&key_3().to_bytes());
+        // the curve is P-256
+        assert_eq!(SecretKey::from_pkcs8_der(&minimal), Err(Error::UnsupportedCurve));
+
+        let mut minimal = hex("303e020100301006072a8648ce3d020106052b8104000a042730250201010420");
+        minimal.extend_from_slice(&key_3().to_bytes());
+        assert_eq!(SecretKey::from_pkcs8_der(&minimal), Ok(key_3()));
+
+        // attributes and v2 public key
+        let mut full = hex(OPENSSL_PKCS8);
+        full[2] += 2 + 3 + 65;
+        full[5] = 1;
+        full.extend_from_slice(&hex("a000814200"));
+        full.extend_from_slice(&key_3().public_key().serialize_uncompressed());
+        assert_eq!(SecretKey::from_pkcs8_der(&full), Ok(key_3()));
+        // v1 must not contain the public key
+        full[5] = 0;
+        assert_eq!(SecretKey::from_pkcs8_der(&full), Err(Error::InvalidDer(DerError::TrailingData)));
+        full[5] = 2;
+        assert_eq!(SecretKey::from_pkcs8_der(&full), Err(Error::UnsupportedVersion));
+    }
+
+    #[test]
+    fn rejects_mismatched_public_key() {
+        let mut der = hex(OPENSSL_PKCS8);
+        let last = der.len() - 1;
+        der[last] ^= 1;
+        assert_eq!(SecretKey::from_pkcs8_der(&der), Err(Error::NotOnCurve));
+
+        let mut sec1 = hex(OPENSSL_SEC1);
+        sec1[8] ^= 1;
+        assert_eq!(SecretKey::from_pkcs8_der(&sec1), Err(Error::PublicKeyMismatch));
+
+        let mut trailing = hex(OPENSSL_SEC1);
+        trailing[1] += 2;
+        trailing.extend_from_slice(&[0x05, 0x00]);
+        assert_eq!(SecretKey::from_pkcs8_der(&trailing), Err(Error::InvalidDer(DerError::TrailingData)));
+    }
+}
@@ -0,0 +1,141 @@
+//! SubjectPublicKeyInfo encoding of public keys (RFC 5480)
+//!
+//! ```text
+//! SEQUENCE {
+//!     SEQUENCE { OID id-ecPublicKey, OID secp256k1 }
+//!     BIT STRING uncompressed point
+//! }
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use crate::der::{parse_tlv, parse_tlv_exact, BIT_STRING_TAG, OBJECT_IDENTIFIER_TAG, SEQUENCE_TAG};
+use crate::{DerError, Error};
+use super::PublicKey;
+
+/// DER of `id-ecPublicKey` (1.2.840.10045.2.1)
+const EC_PUBLIC_KEY_OID: [u8; 9] = [0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+/// DER of `secp256k1` (1.3.132.0.10)
+const SECP256K1_OID: [u8; 7] = [0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x0a];
+
+/// Length of the SPKI encoding - the point is always uncompressed
+#[cfg(feature = "alloc")]
+const SPKI_LEN: usize = 88;
+
+/// Checks the content of `AlgorithmIdentifier` sequence
+pub(super) fn parse_algorithm(content: &[u8]) -> Result<(), Error> {
+    let (oid, rest) = parse_tlv(content, OBJECT_IDENTIFIER_TAG, DerError::InvalidObjectIdentifierTag)?;
+    if *oid != EC_PUBLIC_KEY_OID[2..] {
+        return Err(Error::UnsupportedAlgorithm);
+    }
+    parse_curve(rest)
+}
+
+/// Checks that `bytes` contain exactly the secp256k1 OID
+pub(super) fn parse_curve(bytes: &[u8]) -> Result<(), Error> {
+    let oid = parse_tlv_exact(bytes, OBJECT_IDENTIFIER_TAG, DerError::InvalidObjectIdentifierTag)?;
+    if *oid != SECP256K1_OID[2..] {
+        return Err(Error::UnsupportedCurve);
+    }
+    Ok(())
+}
+
+/// Parses the content of a BIT STRING containing an uncompressed point
+pub(super) fn parse_point(bits: &[u8]) -> Result<PublicKey, Error> {
+    match bits {
+        [0, 0x02, ..] | [0, 0x03, ..] => Err(Error::CompressedPoint),
+        [0, point @ ..] if point.len() == 65 => PublicKey::from_sec1_bytes(point),
+        [0, point @ ..] => Err(Error::InvalidLength(point.len())),
+        _ => Err(DerError::InvalidBitString.into()),
+    }
+}
+
+/// Writes the DER of `AlgorithmIdentifier` into `out` returning the number of bytes written
+#[cfg(feature = "alloc")]
+pub(super) fn write_algorithm(out: &mut [u8]) -> usize {
+    let len = EC_PUBLIC_KEY_OID.len() + SECP256K1_OID.len();
+    out[0] = SEQUENCE_TAG;
+    out[1] = len as u8;
+    out[2..][..EC_PUBLIC_KEY_OID.len()].copy_from_slice(&EC_PUBLIC_KEY_OID);
+    out[(2 + EC_PUBLIC_KEY_OID.len())..][..SECP256K1_OID.len()].copy_from_slice(&SECP256K1_OID);
+    2 + len
+}
+
+impl PublicKey {
+    /// Encodes the key as DER `SubjectPublicKeyInfo` with uncompressed point
+    #[cfg(feature = "alloc")]
+    pub fn to_spki_der(&self) -> Vec<u8> {
+        let mut out = [0; SPKI_LEN];
+        out[0] = SEQUENCE_TAG;
+        out[1] = (SPKI_LEN - 2) as u8;
+        let algorithm_len = write_algorithm(&mut out[2..]);
+        let bits = &mut out[(2 + algorithm_len)..];
+        bits[0] = BIT_STRING_TAG;
+        bits[1] = 66;
+        bits[2] = 0;
+        bits[3..].copy_from_slice(&self.serialize_uncompressed());
+        out.to_vec()
+    }
+
+    /// Parses DER `SubjectPublicKeyInfo`.
+    ///
+    /// The parsing is strict: the algorithm must be `id-ecPublicKey` with the secp256k1 named
+    /// curve, the point must be uncompressed and there must be no trailing data.
+    pub fn from_spki_der(bytes: &[u8]) -> Result<Self, Error> {
+        let content = parse_tlv_exact(bytes, SEQUENCE_TAG, DerError::InvalidSequenceTag)?;
+        let (algorithm, content) = parse_tlv(content, SEQUENCE_TAG, DerError::InvalidSequenceTag)?;
+        parse_algorithm(algorithm)?;
+        let bits = parse_tlv_exact(content, BIT_STRING_TAG, DerError::InvalidBitStringTag)?;
+        parse_point(bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DerError, Error, PublicKey, SecretKey};
+    use crate::test_util::hex;
+
+    /// `openssl ec -pubout -outform DER` for secret key 3
+    const OPENSSL: &str = "3056301006072a8648ce3d020106052b8104000a03420004f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9388f7b0f632de8140fe337e62a37f3566500a99934c2231b6cb9fd7584b8e672";
+    /// The same with `-conv_form compressed`
+    const OPENSSL_COMPRESSED: &str = "3036301006072a8648ce3d020106052b8104000a03220002f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+
+    fn key_3() -> PublicKey {
+        let mut bytes = [0; 32];
+        bytes[31] = 3;
+        SecretKey::from_bytes(&bytes).unwrap().public_key()
+    }
+
+    #[test]
+    fn openssl_fixture() {
+        let der = hex(OPENSSL);
+        assert_eq!(PublicKey::from_spki_der(&der), Ok(key_3()));
+        #[cfg(feature = "alloc")]
+        assert_eq!(key_3().to_spki_der(), der);
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        let der = hex(OPENSSL);
+        assert_eq!(PublicKey::from_spki_der(&hex(OPENSSL_COMPRESSED)), Err(Error::CompressedPoint));
+
+        let mut wrong_algorithm = der.clone();
+        wrong_algorithm[12] = 0x02;
+        assert_eq!(PublicKey::from_spki_der(&wrong_algorithm), Err(Error::UnsupportedAlgorithm));
+        let mut wrong_curve = der.clone();
+        wrong_curve[19] = 0x22;
+        assert_eq!(PublicKey::from_spki_der(&wrong_curve), Err(Error::UnsupportedCurve));
+
+        let mut trailing = der.clone();
+        trailing.push(0);
+        assert_eq!(PublicKey::from_spki_der(&trailing), Err(Error::InvalidDer(DerError::TrailingData)));
+        assert_eq!(PublicKey::from_spki_der(&der[..87]), Err(Error::InvalidDer(DerError::UnexpectedEnd)));
+
+        let mut unused_bits = der.clone();
+        unused_bits[22] = 1;
+        assert_eq!(PublicKey::from_spki_der(&unused_bits), Err(Error::InvalidDer(DerError::InvalidBitString)));
+        let mut not_on_curve = der;
+        not_on_curve[87] ^= 1;
+        assert_eq!(PublicKey::from_spki_der(&not_on_curve), Err(Error::NotOnCurve));
+    }
+}
@@ -1,197 +1,665 @@
-use bigint::uint::U256;
-use std::ops::{Add, AddAssign, Sub, SubAssign, Neg, Mul, MulAssign, Div, DivAssign};
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Sub, SubAssign, Neg, Mul, MulAssign, Div, DivAssign};
+
+/// Increments the operation counter `$field`, expands to nothing without the `counters` feature
+macro_rules! count_op {
+    ($field:ident) => {
+        #[cfg(feature = "counters")]
+        crate::counters::increment(|counts| &mut counts.$field);
+    };
+}
 
-const P: U256 = U256([0xFFFFFFFE_FFFFFC2F, 0xFFFFFFFF_FFFFFFFF, 0xFFFFFFFF_FFFFFFFF, 0xFFFFFFFF_FFFFFFFF]);
+pub mod error;
+#[doc(hidden)]
+pub mod macros;
+pub mod params;
+pub mod curve;
+pub mod uint;
+pub mod scalar;
+pub mod keys;
+pub mod validate;
+pub mod codec;
+pub mod message;
+#[cfg(all(feature = "bitcoin-hashes", feature = "alloc"))]
+pub mod bip32;
+#[cfg(feature = "bip39")]
+pub mod bip39;
+#[cfg(feature = "bip38")]
+mod bip38;
+#[cfg(feature = "alloc")]
+pub mod address;
+#[cfg(all(feature = "bitcoin-hashes", feature = "alloc"))]
+pub mod signed_message;
+#[cfg(all(feature = "bitcoin-hashes", feature = "alloc"))]
+pub mod bip322;
+#[cfg(feature = "hashes")]
+pub mod taproot;
+#[cfg(feature = "bindings")]
+pub mod bindings;
+#[cfg(feature = "hashes")]
+pub mod easy;
+pub mod ecdsa;
+pub mod ecdh;
+pub mod elgamal;
+pub mod pedersen;
+#[cfg(all(feature = "hashes", feature = "alloc"))]
+pub mod pedersen_hash;
+pub mod ot;
+#[cfg(feature = "alloc")]
+pub mod sss;
+#[cfg(feature = "alloc")]
+pub mod vss;
+pub mod ident;
+#[cfg(all(feature = "hashes", feature = "alloc"))]
+pub mod dleq;
+#[cfg(all(feature = "hashes", feature = "alloc"))]
+pub mod pok;
+#[cfg(all(feature = "hashes", feature = "alloc"))]
+pub mod sigma;
+#[cfg(feature = "hashes")]
+pub mod pop;
+#[cfg(all(feature = "hashes", feature = "alloc"))]
+pub mod vrf;
+#[cfg(all(feature = "hashes", feature = "alloc"))]
+pub mod ring;
+#[cfg(all(feature = "hashes", feature = "alloc"))]
+pub mod oprf;
+#[cfg(feature = "hashes")]
+pub mod bdhke;
+#[cfg(feature = "hashes")]
+pub mod ellswift;
+#[cfg(all(feature = "hashes", feature = "alloc"))]
+pub mod silentpayments;
+#[cfg(feature = "hashes")]
+pub mod stealth;
+#[cfg(all(feature = "hashes", feature = "alloc"))]
+pub mod rangeproof;
+#[cfg(feature = "ecies")]
+pub mod ecies;
+#[cfg(all(feature = "hashes", feature = "alloc"))]
+pub mod jws;
+#[cfg(all(feature = "hashes", feature = "alloc"))]
+pub mod nostr;
+#[cfg(all(feature = "hashes", feature = "alloc"))]
+pub mod batch_sign;
+#[cfg(all(feature = "hashes", feature = "alloc"))]
+pub mod batch;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod nonce;
+pub mod vanity;
+mod selftest;
+mod nonzero;
+pub mod multiexp;
+#[cfg(feature = "precomputed-tables")]
+mod precomputed;
+pub mod recode;
+pub mod trace;
+#[cfg(feature = "counters")]
+pub mod counters;
+#[cfg(feature = "hashes")]
+pub mod schnorr;
+#[cfg(feature = "hashes")]
+pub mod antiexfil;
+#[cfg(all(feature = "hashes", feature = "alloc"))]
+pub mod musig;
+#[cfg(feature = "alloc")]
+pub mod frost;
+#[cfg(feature = "hashes")]
+pub mod tagged_hash;
+#[cfg(feature = "hashes")]
+pub mod streaming;
+#[cfg(feature = "hashes")]
+pub mod transcript;
+#[cfg(all(feature = "keccak", feature = "alloc"))]
+mod ethereum;
+#[cfg(feature = "hashes")]
+mod hashes;
+#[cfg(all(feature = "bitcoin-hashes", feature = "alloc"))]
+mod base58;
+#[cfg(feature = "alloc")]
+mod base64;
+#[cfg(feature = "hashes")]
+mod rfc6979;
+#[cfg(any(feature = "ecies", feature = "test-utils", test))]
+mod chacha;
+#[cfg(feature = "ecies")]
+mod chacha20poly1305;
+#[cfg(feature = "bip38")]
+mod aes;
+#[cfg(feature = "bip38")]
+mod scrypt;
+#[cfg(feature = "alloc")]
+mod bech32;
+#[cfg(feature = "alloc")]
+mod json;
+#[cfg(feature = "cose")]
+mod cbor;
+mod der;
+mod field;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd;
+mod zeroize;
+#[cfg(feature = "subtle")]
+mod ct;
+#[cfg(any(feature = "test-utils", test))]
+pub mod test_utils;
+#[cfg(test)]
+mod test_util;
+#[cfg(all(test, feature = "timing-tests"))]
+mod timing;
+
+pub use error::{BatchError, DerError, Error, SelfTestFailure};
+#[cfg(feature = "bindings")]
+pub use error::BindingsError;
+pub use scalar::Scalar;
+pub use nonzero::{NonIdentityPoint, NonZeroScalar};
+pub use uint::U256;
+pub use keys::{SecretKey, PublicKey, Keypair, XOnlyPoint, Parity};
+pub use multiexp::{multiexp, multiexp_into};
+pub use codec::{FromBytes, ToBytes};
+pub use message::Message;
+pub use params::G;
+pub use selftest::selftest;
+pub use curve::{CurveParams, Secp256k1};
+#[cfg(feature = "subtle")]
+pub use ct::ct_eq_bytes;
+
+/// Bitcoin network selecting the version bytes of serialized keys
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Network {
+    Bitcoin,
+    Testnet,
+}
 
-// Convenience methods
-trait U256Ext {
-    fn wrapping_add(self, rhs: Self) -> Self;
-    fn wrapping_sub(self, rhs: Self) -> Self;
-    fn wrapping_shl(self, rhs: usize) -> Self;
+/// Options of `ecdsa::sign_with_options` and `schnorr::sign_with_options`
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct SignOptions {
+    /// Verifies the signature before returning it.
+    ///
+    /// A fault injected during signing (e.g. by glitching the power supply) can produce a
+    /// signature that leaks the secret key. Verification catches it at the cost of roughly
+    /// doubling the time of signing.
+    pub verify_after_sign: bool,
 }
 
-impl U256Ext for U256 {
-    fn wrapping_add(self, rhs: Self) -> Self {
-        self.overflowing_add(rhs).0
+/// Implementation of `Z_p` cyclic group where `p` is the size of the field of the curve `C`.
+///
+/// Usually used through the `Zp` alias for secp256k1.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct FieldElement<C: CurveParams>(U256, PhantomData<C>);
+
+/// Element of the field used in secp256k1 - see the `P` constant in this library.
+pub type Zp = FieldElement<Secp256k1>;
+
+impl<C: CurveParams> FieldElement<C> {
+    pub const ZERO: Self = FieldElement(U256([0, 0, 0, 0]), PhantomData);
+    pub const ONE: Self = FieldElement(U256([1, 0, 0, 0]), PhantomData);
+
+    /// Wraps the value without reducing it, the caller must guarantee it's less than `C::P`
+    pub(crate) const fn from_reduced(value: U256) -> Self {
+        FieldElement(value, PhantomData)
     }
 
-    fn wrapping_sub(self, rhs: Self) -> Self {
-        self.overflowing_sub(rhs).0
+    /// Converts the value % P to Self
+    pub const fn wrapping_from(value: U256) -> Self {
+        match value.const_cmp(&C::P) {
+            Ordering::Less => FieldElement(value, PhantomData),
+            _ => FieldElement(value.wrapping_sub(C::P), PhantomData),
+        }
     }
 
-    fn wrapping_shl(self, rhs: usize) -> Self {
-        self << rhs
+    pub const fn checked_from(value: U256) -> Option<Self> {
+        match value.const_cmp(&C::P) {
+            Ordering::Less => Some(FieldElement(value, PhantomData)),
+            _ => None,
+        }
     }
-}
 
-/// Implementation of `Z_p` cyclic group where `p` is the size of the field used in secp256k1 - se
-/// the `P` constant in this library.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-pub struct Zp(U256);
+    /// Returns `Error::NonCanonicalFieldElement` if the value is not less than `P`
+    pub const fn try_from_u256(value: U256) -> Result<Self, Error> {
+        match Self::checked_from(value) {
+            Some(value) => Ok(value),
+            None => Err(Error::NonCanonicalFieldElement),
+        }
+    }
 
-impl Zp {
-    pub const ZERO: Self = Zp(U256([0, 0, 0, 0]));
+    /// Parses big-endian bytes.
+    ///
+    /// Returns `None` if the value is not less than `P`
+    pub const fn from_be_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        Self::checked_from(U256::from_be_bytes(bytes))
+    }
 
-    /// Converts the value % P to Self
-    pub fn wrapping_from(value: U256) -> Self {
-        if value >= P {
-            Zp(value.wrapping_sub(P))
-        } else {
-            Zp(value)
+    /// Serializes the value as 32 big-endian bytes
+    pub const fn to_be_bytes(&self) -> [u8; 32] {
+        self.0.to_be_bytes()
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub const fn is_odd(&self) -> bool {
+        !self.0.is_even()
+    }
+
+    pub fn multiplicative_inverse(self) -> Self {
+        count_op!(field_inversions);
+        FieldElement(self.0.mod_inverse(C::P), PhantomData)
+    }
+
+    /// Computes `self²`
+    pub fn square(self) -> Self {
+        count_op!(field_squarings);
+        self * self.0
+    }
+
+    /// Square-and-multiply exponentiation
+    pub fn pow(self, exp: U256) -> Self {
+        let mut res = Self::ONE;
+
+        for bit in (0..256).rev() {
+            res = res.square();
+            if exp.bit(bit) {
+                res *= self;
+            }
         }
+
+        res
     }
 
-    pub fn checked_from(value: U256) -> Option<Self> {
-        if value >= P {
+    /// Computes the square root if it exists.
+    ///
+    /// Since `P % 4 == 3` (required by `CurveParams`) the root is simply `self^((P + 1) / 4)`, we
+    /// only need to check that it actually squares back to `self`. Which one of the two roots is
+    /// returned is unspecified.
+    pub fn sqrt(self) -> Option<Self> {
+        // the field depends only on `P` and the secp256k1 one has a much faster addition chain
+        if C::P == crate::params::P {
+            return field::sqrt(Zp::from_reduced(self.0)).map(|root| FieldElement(root.0, PhantomData));
+        }
+        let root = self.pow(C::P.wrapping_shr(2).wrapping_add(U256::one()));
+        if root.square() == self {
+            Some(root)
+        } else {
             None
+        }
+    }
+}
+
+impl Zp {
+    /// The primitive cube root of unity `β`, `β³ = 1`.
+    ///
+    /// This is the `β` of the GLV endomorphism `(x, y) ↦ (βx, y) = λ·(x, y)`, also available as
+    /// `params::BETA`, see also `params::LAMBDA`.
+    pub const CUBE_ROOT_OF_UNITY: Zp = Zp::from_reduced(U256([0xC1396C28_719501EE, 0x9CF04975_12F58995, 0x6E64479E_AC3434E9, 0x7AE96A2B_657C0710]));
+
+    /// Reduces the value modulo the curve order, as ECDSA does with `R.x`.
+    pub const fn to_scalar_reduce(&self) -> Scalar {
+        self.to_scalar_overflowing().0
+    }
+
+    /// Reduces the value modulo the curve order returning also whether it wrapped around.
+    ///
+    /// The value wraps only if it's in `[n, p)` which happens with probability about 2⁻¹²⁸ for a
+    /// random x coordinate. ECDSA recovery needs to know because `n + r` has to be tried instead
+    /// of `r`.
+    pub const fn to_scalar_overflowing(&self) -> (Scalar, bool) {
+        match Scalar::checked_from(self.0) {
+            Some(scalar) => (scalar, false),
+            None => (Scalar::wrapping_from(self.0), true),
+        }
+    }
+
+    /// Computes a cube root if it exists.
+    ///
+    /// Since `p ≡ 1 (mod 3)` only a third of the nonzero elements are cubes and each of them has
+    /// three roots, differing by a factor of `CUBE_ROOT_OF_UNITY`. `3` divides `p - 1` only once,
+    /// so no adjustment by the root of unity is needed to find a root: `self^((p + 2) / 9)` is one
+    /// whenever `self` is a cube, which is checked by cubing it back. Which root is returned is
+    /// unspecified.
+    pub fn cube_root(self) -> Option<Self> {
+        let root = self.pow(params::CUBE_ROOT_EXPONENT);
+        if root * root * root == self {
+            Some(root)
         } else {
-            Some(Zp(value))
+            None
         }
     }
 
-    pub fn is_zero(&self) -> bool {
-        self.0.is_zero()
+    /// Multiplies the elements pairwise.
+    ///
+    /// This uses the multiplication specialized for secp256k1 which is much faster than the
+    /// operator. With the `simd` feature on x86_64 CPUs supporting AVX2 (detected at runtime)
+    /// four pairs are multiplied at once. The results are the same either way.
+    ///
+    /// # Panics
+    ///
+    /// If the slices have different lengths.
+    #[cfg(feature = "alloc")]
+    pub fn mul_many(a: &[Zp], b: &[Zp]) -> alloc::vec::Vec<Zp> {
+        field::mul_many(a, b)
     }
 
-    pub fn multiplicative_inverse(self) -> Self {
-        Zp(self.0.mod_inverse(P))
+    /// Inverts all elements in place using a single inversion (Montgomery's trick), storing the
+    /// intermediate products in `scratch`.
+    ///
+    /// Works the same way as `Scalar::batch_invert_with_scratch`, with the multiplication
+    /// specialized for secp256k1. Returns `Error::InvalidLength` with the length of `scratch` if
+    /// it's shorter than `elements`, `Error::ZeroFieldElement` if any of the elements is zero.
+    /// `elements` is not modified in either case.
+    pub fn batch_invert_with_scratch(elements: &mut [Zp], scratch: &mut [Zp]) -> Result<(), Error> {
+        if scratch.len() < elements.len() {
+            return Err(Error::InvalidLength(scratch.len()));
+        }
+        if elements.iter().any(Zp::is_zero) {
+            return Err(Error::ZeroFieldElement);
+        }
+        // prefixes[i] = elements[0]·…·elements[i - 1]
+        let prefixes = &mut scratch[..elements.len()];
+        let mut product = Zp::ONE;
+        for (element, prefix) in elements.iter().zip(prefixes.iter_mut()) {
+            *prefix = product;
+            product = Zp::from_reduced(field::mul(product.0, element.0));
+        }
+        // the inverse of the product of the elements not yet processed
+        let mut inverse = product.multiplicative_inverse();
+        for (element, prefix) in elements.iter_mut().zip(prefixes.iter()).rev() {
+            let next = field::mul(inverse.0, element.0);
+            *element = Zp::from_reduced(field::mul(inverse.0, prefix.0));
+            inverse = Zp::from_reduced(next);
+        }
+        Ok(())
+    }
+
+    /// Inverts all elements of the array in place, the intermediate products are stored on the
+    /// stack.
+    ///
+    /// Returns `Error::ZeroFieldElement` if any of the elements is zero, the array is not
+    /// modified in that case.
+    pub fn batch_invert_in_place<const LEN: usize>(elements: &mut [Zp; LEN]) -> Result<(), Error> {
+        let mut scratch = [Zp::ZERO; LEN];
+        Self::batch_invert_with_scratch(elements, &mut scratch)
+    }
+}
+
+/// Prints big-endian hex, e.g. `Zp(0x0000…0007)`
+impl<C: CurveParams> fmt::Debug for FieldElement<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Zp(0x")?;
+        write_hex(f, &self.to_be_bytes())?;
+        f.write_str(")")
     }
 }
 
 // We use simple subtraction instead of modulo as it should be more efficient
-impl Add for Zp {
+impl<C: CurveParams> Add for FieldElement<C> {
     type Output = Self;
 
-    fn add(self, rhs: Zp) -> Self::Output {
+    fn add(self, rhs: Self) -> Self::Output {
         let (res, overflow) = self.0.overflowing_add(rhs.0);
-        Zp(if overflow || res >= P {
-            res.wrapping_sub(P)
+        FieldElement(if overflow || res >= C::P {
+            res.wrapping_sub(C::P)
         } else {
             res
-        })
+        }, PhantomData)
     }
 }
 
-impl AddAssign for Zp {
+impl<C: CurveParams> AddAssign for FieldElement<C> {
     fn add_assign(&mut self, rhs: Self) {
         *self = *self + rhs;
     }
 }
 
-impl Sub for Zp {
+impl<C: CurveParams> Sub for FieldElement<C> {
     type Output = Self;
 
-    fn sub(self, rhs: Zp) -> Self::Output {
+    fn sub(self, rhs: Self) -> Self::Output {
         let (res, overflow) = self.0.overflowing_sub(rhs.0);
-        Zp(if overflow || res >= P {
-            res.wrapping_add(P)
+        FieldElement(if overflow || res >= C::P {
+            res.wrapping_add(C::P)
         } else {
             res
-        })
+        }, PhantomData)
     }
 }
 
-impl SubAssign for Zp {
+impl<C: CurveParams> SubAssign for FieldElement<C> {
     fn sub_assign(&mut self, rhs: Self) {
         *self = *self - rhs;
     }
 }
 
-impl Mul<U256> for Zp {
-    type Output = Zp;
+impl<C: CurveParams> Mul<U256> for FieldElement<C> {
+    type Output = Self;
 
     /// Double-and-add algorithm
-    fn mul(self, mut rhs: U256) -> Self::Output {
-        let mut res = Zp::ZERO;
+    fn mul(self, rhs: U256) -> Self::Output {
+        let mut res = Self::ZERO;
 
-        for _ in 0..256 {
+        for bit in (0..256).rev() {
             // Can't use *= 2 - that would cause infinite recursion.
             // Don't ask how I know.
             res += res;
-            if rhs & U256([0, 0, 0, 1 << 63]) != U256::zero() {
+            if rhs.bit(bit) {
                 res += self;
             }
-            rhs = rhs.wrapping_shl(1);
         }
 
         res
     }
 }
 
-impl Mul<u64> for Zp {
-    type Output = Zp;
+impl<C: CurveParams> Mul<u64> for FieldElement<C> {
+    type Output = Self;
 
     fn mul(self, rhs: u64) -> Self::Output {
         self * U256::from(rhs)
     }
 }
 
-impl MulAssign<u64> for Zp {
+impl<C: CurveParams> MulAssign<u64> for FieldElement<C> {
     fn mul_assign(&mut self, rhs: u64) {
         *self = *self * rhs;
     }
 }
 
-impl Mul for Zp {
-    type Output = Zp;
+impl<C: CurveParams> Mul for FieldElement<C> {
+    type Output = Self;
 
-    fn mul(self, rhs: Zp) -> Self::Output {
+    fn mul(self, rhs: Self) -> Self::Output {
+        count_op!(field_multiplications);
         self * rhs.0
     }
 }
 
-impl MulAssign for Zp {
+impl<C: CurveParams> MulAssign for FieldElement<C> {
     fn mul_assign(&mut self, rhs: Self) {
         *self = *self * rhs;
     }
 }
 
-impl Div for Zp {
-    type Output = Zp;
+impl<C: CurveParams> Div for FieldElement<C> {
+    type Output = Self;
 
-    fn div(self, rhs: Zp) -> Self::Output {
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
         self * rhs.multiplicative_inverse()
     }
 }
 
-impl DivAssign for Zp {
+impl<C: CurveParams> DivAssign for FieldElement<C> {
     fn div_assign(&mut self, rhs: Self) {
         *self = *self / rhs;
     }
 }
 
-impl Neg for Zp {
-    type Output = Zp;
+impl<C: CurveParams> Neg for FieldElement<C> {
+    type Output = Self;
 
     fn neg(self) -> Self::Output {
         if self.is_zero() {
             self
         } else {
-            Zp(P - self.0)
+            FieldElement(C::P - self.0, PhantomData)
         }
     }
 }
 
-/// Secp256k1 curve point
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-pub struct Point {
-    x: Zp,
-    y: Zp,
+/// Point on the curve `C`.
+///
+/// Usually used through the `Point` alias for secp256k1.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct CurvePoint<C: CurveParams> {
+    x: FieldElement<C>,
+    y: FieldElement<C>,
 }
 
-impl Point {
+/// Secp256k1 curve point
+pub type Point = CurvePoint<Secp256k1>;
+
+impl<C: CurveParams> CurvePoint<C> {
     /// Point at infinity - neutral element, ironically denoted as 0
-    pub const AT_INFINITY: Point = Point { x: Zp::ZERO, y: Zp::ZERO, };
+    pub const AT_INFINITY: Self = CurvePoint { x: FieldElement::ZERO, y: FieldElement::ZERO, };
+
+    /// The generator of the curve - `(C::G_X, C::G_Y)`
+    pub const GENERATOR: Self = CurvePoint { x: FieldElement::from_reduced(C::G_X), y: FieldElement::from_reduced(C::G_Y), };
 
     /// Constructs the point from coordinates.
     ///
     /// Returns `None` if the point is not on the curve
-    pub fn new(x: Zp, y: Zp) -> Option<Self> {
-        if (x.is_zero() && y.is_zero()) || y * y == x * x * x + B {
-            Some(Point { x, y })
+    pub fn new(x: FieldElement<C>, y: FieldElement<C>) -> Option<Self> {
+        Self::try_new(x, y).ok()
+    }
+
+    /// Constructs the point from coordinates.
+    ///
+    /// Returns `Error::NotOnCurve` if the point is not on the curve
+    pub fn try_new(x: FieldElement<C>, y: FieldElement<C>) -> Result<Self, Error> {
+        if Self::is_on_curve(x, y) {
+            Ok(CurvePoint { x, y })
         } else {
-            None
+            Err(Error::NotOnCurve)
+        }
+    }
+
+    /// Constructs the point from coordinates without checking the curve equation.
+    ///
+    /// Intended for reloading points that were already validated, e.g. precomputed tables. The
+    /// caller must guarantee that the point is on the curve (or is `(0, 0)` representing the
+    /// point at infinity). This is checked in debug builds only, in release builds an invalid
+    /// point silently produces garbage results of all following operations.
+    pub fn from_coordinates_unchecked(x: FieldElement<C>, y: FieldElement<C>) -> Self {
+        debug_assert!(Self::is_on_curve(x, y), "the point is not on the curve");
+        CurvePoint { x, y }
+    }
+
+    /// Computes the right-hand side of the curve equation, `x³ + ax + b`
+    fn rhs(x: FieldElement<C>) -> FieldElement<C> {
+        let cubic = x * x * x + FieldElement::from_reduced(C::B);
+        // skips the multiplication for secp256k1
+        if C::A.is_zero() {
+            cubic
+        } else {
+            cubic + x * FieldElement::from_reduced(C::A)
+        }
+    }
+
+    fn is_on_curve(x: FieldElement<C>, y: FieldElement<C>) -> bool {
+        (x.is_zero() && y.is_zero()) || y * y == Self::rhs(x)
+    }
+
+    /// Constructs the point from x coordinate and parity of y coordinate.
+    ///
+    /// Returns `None` if there's no point with such x coordinate.
+    pub fn from_x(x: FieldElement<C>, odd_y: bool) -> Option<Self> {
+        Self::try_from_x(x, odd_y).ok()
+    }
+
+    /// Constructs the point from x coordinate and parity of y coordinate.
+    ///
+    /// Returns `Error::NotOnCurve` if there's no point with such x coordinate.
+    pub fn try_from_x(x: FieldElement<C>, odd_y: bool) -> Result<Self, Error> {
+        let y = Self::rhs(x).sqrt().ok_or(Error::NotOnCurve)?;
+        let y = if y.is_odd() == odd_y { y } else { -y };
+        Ok(CurvePoint { x, y })
+    }
+
+    /// Constructs the point with even y coordinate from x coordinate as defined by BIP340.
+    ///
+    /// Returns `None` if there's no point with such x coordinate.
+    pub fn lift_x(x: FieldElement<C>) -> Option<Self> {
+        Self::from_x(x, false)
+    }
+
+    /// Parses SEC1-encoded point - either compressed (33 bytes) or uncompressed (65 bytes).
+    ///
+    /// The point at infinity is not accepted. The error pinpoints the failure:
+    /// `Error::InvalidLength` for other lengths, `Error::InvalidPrefix` if the first byte doesn't
+    /// match the length, `Error::NonCanonicalFieldElement` for a coordinate not less than `P`,
+    /// `Error::NotOnCurve` for coordinates not satisfying the curve equation and
+    /// `Error::PointAtInfinity` for all-zero uncompressed coordinates.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        match (bytes.len(), bytes.first()) {
+            (33, Some(&prefix @ (0x02 | 0x03))) => {
+                let x = FieldElement::try_from_u256(U256::from_be_bytes(array_ref_32(&bytes[1..33])))?;
+                Self::try_from_x(x, prefix == 0x03)
+            },
+            (65, Some(0x04)) => {
+                let x = FieldElement::try_from_u256(U256::from_be_bytes(array_ref_32(&bytes[1..33])))?;
+                let y = FieldElement::try_from_u256(U256::from_be_bytes(array_ref_32(&bytes[33..65])))?;
+                let point = Self::try_new(x, y)?;
+                if point.is_at_infinity() {
+                    Err(Error::PointAtInfinity)
+                } else {
+                    Ok(point)
+                }
+            },
+            (33, Some(&prefix)) | (65, Some(&prefix)) => Err(Error::InvalidPrefix(prefix)),
+            (len, _) => Err(Error::InvalidLength(len)),
+        }
+    }
+
+    /// Serializes the point in SEC1 compressed form.
+    ///
+    /// The point at infinity has no such encoding so it's serialized as all zeros.
+    pub fn serialize_compressed(&self) -> [u8; 33] {
+        let mut bytes = [0; 33];
+        if !self.is_at_infinity() {
+            bytes[0] = if self.y.is_odd() { 0x03 } else { 0x02 };
+            bytes[1..].copy_from_slice(&self.x.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Serializes the point in SEC1 uncompressed form.
+    ///
+    /// The point at infinity has no such encoding so it's serialized as all zeros.
+    pub fn serialize_uncompressed(&self) -> [u8; 65] {
+        let mut bytes = [0; 65];
+        if !self.is_at_infinity() {
+            bytes[0] = 0x04;
+            bytes[1..33].copy_from_slice(&self.x.to_be_bytes());
+            bytes[33..].copy_from_slice(&self.y.to_be_bytes());
         }
+        bytes
+    }
+
+    pub fn x(&self) -> FieldElement<C> {
+        self.x
+    }
+
+    pub fn y(&self) -> FieldElement<C> {
+        self.y
     }
 
     /// Checks if the point is neutral element
@@ -199,25 +667,110 @@ impl Point {
         self.x.is_zero() && self.y.is_zero()
     }
 
+    /// Compares the canonical coordinates without exiting at the first difference, for points
+    /// derived from secrets
+    pub(crate) fn eq_ct(&self, other: &Self) -> bool {
+        let limbs = self.x.0 .0.iter().chain(&self.y.0 .0);
+        let other_limbs = other.x.0 .0.iter().chain(&other.y.0 .0);
+        core::hint::black_box(limbs.zip(other_limbs).fold(0, |diff, (a, b)| diff | (a ^ b))) == 0
+    }
+
     /// Computes multiplicative inverse for scalar multiplication.
     /// 
     /// For each scalar `x`, curve point `P`: `P*x*scalar_multiplicative_inverse(x) == P`.
     /// Or in other words `x*scalar_multiplicative_inverse(x) % curve order == 1`.
     pub fn scalar_multiplicative_inverse(scalar: U256) -> U256 {
-        scalar.mod_inverse(SECP256K1_GROUP_ORDER)
+        scalar.mod_inverse(C::N)
     }
 }
 
-pub const G: Point = Point { x: Zp(U256([0x59F2815B_16F81798, 0x029BFCDB_2DCE28D9, 0x55A06295_CE870B07, 0x79BE667E_F9DCBBAC])), y: Zp(U256([0x9C47D08F_FB10D4B8, 0xFD17B448_A6855419, 0x5DA4FBFC_0E1108A8, 0x483ADA77_26A3C465])), };
-const B: Zp = Zp(U256([7, 0, 0, 0]));
+impl Point {
+    /// Computes `k·G`.
+    ///
+    /// With the `precomputed-tables` feature this uses a table of multiples of `G` compiled into
+    /// the binary, which is several times faster than `G * k`. The result is the same either way.
+    /// With the `subtle` feature the table lookups read whole tables instead of indexing them by
+    /// the digits of `k` (see `G.mul_ct(k)`), the arithmetic is still **NOT CONSTANT TIME!!!**
+    pub fn mul_gen(k: &Scalar) -> Point {
+        #[cfg(feature = "precomputed-tables")]
+        {
+            precomputed::mul_gen(k)
+        }
+        #[cfg(all(not(feature = "precomputed-tables"), feature = "subtle"))]
+        {
+            G.mul_ct(k)
+        }
+        #[cfg(all(not(feature = "precomputed-tables"), not(feature = "subtle")))]
+        {
+            G * *k
+        }
+    }
+
+    /// Generates uniformly random point other than the point at infinity without knowing its
+    /// discrete logarithm.
+    ///
+    /// Draws uniform x coordinates (rejecting values not less than `p`) until one is on the curve
+    /// and picks the parity of y by a random bit. Each of the `(n - 1) / 2` valid x coordinates
+    /// has exactly two points so the result is uniform over all `n - 1` points. About half of the
+    /// field elements are valid so the expected number of iterations is 2 and the probability
+    /// of needing more than `k` is about `2⁻ᵏ`. Nobody learns the discrete logarithm of the
+    /// point, use `random_with_dlog` if it's not needed, it's cheaper. **NOT CONSTANT TIME!!!**
+    pub fn random<R: rand_core::RngCore + rand_core::CryptoRng>(rng: &mut R) -> Point {
+        let mut bytes = [0; 32];
+        loop {
+            rng.fill_bytes(&mut bytes);
+            let x = match Zp::from_be_bytes(&bytes) {
+                Some(x) => x,
+                None => continue,
+            };
+            let odd_y = rng.next_u32() & 1 == 1;
+            if let Some(point) = Point::from_x(x, odd_y) {
+                break point;
+            }
+        }
+    }
+
+    /// Generates uniformly random point other than the point at infinity together with its
+    /// discrete logarithm with respect to `G`.
+    ///
+    /// This is just `(k, k·G)` for uniformly random non-zero `k` so it's cheap and the point has
+    /// the same distribution as the one returned by `random`.
+    pub fn random_with_dlog<R: rand_core::RngCore + rand_core::CryptoRng>(rng: &mut R) -> (Scalar, Point) {
+        let k = Scalar::random(rng);
+        (k, Point::mul_gen(&k))
+    }
+
+    /// Returns `[x, βx, β²x]` - the x-coordinates of `self`, `λ·self` and `λ²·self`.
+    ///
+    /// Multiplying by `params::LAMBDA` permutes the points with these x-coordinates (and their
+    /// negations) so the orbit identifies the point up to the endomorphism and negation. The
+    /// point at infinity gives three zeros.
+    pub fn x_orbit(&self) -> [Zp; 3] {
+        let beta_x = self.x * params::BETA;
+        [self.x, beta_x, beta_x * params::BETA]
+    }
+
+    /// Lifts many x coordinates to points with even y, the same as calling `lift_x` on each of
+    /// them.
+    ///
+    /// This is only a convenience wrapper: the cost is dominated by the square roots which can't
+    /// share work and lifting to affine coordinates has no inversions to batch.
+    #[cfg(feature = "alloc")]
+    pub fn lift_x_batch(xs: &[Zp]) -> alloc::vec::Vec<Option<Point>> {
+        xs.iter().map(|x| Point::lift_x(*x)).collect()
+    }
+}
 
-/// Curve order of SECP256K1
-const SECP256K1_GROUP_ORDER: U256 = U256([0xBFD25E8C_D0364141, 0xBAAEDCE6_AF48A03B, 0xFFFFFFFF_FFFFFFFE, 0xFFFFFFFF_FFFFFFFF]);
+/// Nothing-up-my-sleeve generator whose discrete logarithm with respect to `G` is unknown.
+///
+/// It's `lift_x(SHA256(G serialized uncompressed))`, the same point as used by BIP341 and
+/// secp256k1-zkp.
+pub const H: Point = crate::point!("0250929B74C1A04954B78B4B6035E97A5E078A5A0F28EC96D547BFEE9ACE803AC0");
 
-impl Add for Point {
+impl<C: CurveParams> Add for CurvePoint<C> {
     type Output = Self;
 
-    fn add(self, rhs: Point) -> Self::Output {
+    fn add(self, rhs: Self) -> Self::Output {
         if self.is_at_infinity() {
             return rhs;
         }
@@ -225,7 +778,7 @@ impl Add for Point {
             return self;
         }
         if self == -rhs {
-            return Point::AT_INFINITY;
+            return Self::AT_INFINITY;
         }
 
         // Made it easier to copy from Wikipedia :)
@@ -233,84 +786,231 @@ impl Add for Point {
         let p = rhs;
 
         let lambda = if p == q {
-            // point doubling
-            p.x * p.x * 3 /* + a, which is 0 for secp256k1 */ / (p.y * 2)
+            count_op!(point_doublings);
+            (p.x.square() * 3 + FieldElement::from_reduced(C::A)) / (p.y * 2)
         } else {
+            count_op!(point_additions);
             (q.y - p.y) / (q.x - p.x)
         };
 
-        let x = lambda * lambda - p.x - q.x;
+        let x = lambda.square() - p.x - q.x;
         // Note that there's `x` in the parentheses not `something.x`, this is correct, the font at
         // Wikipedia is awful.
         let y = lambda * (p.x - x) - p.y;
 
-        Point { x, y, }
+        CurvePoint { x, y, }
     }
 }
 
-impl AddAssign for Point {
+impl<C: CurveParams> AddAssign for CurvePoint<C> {
     fn add_assign(&mut self, rhs: Self) {
         *self = *self + rhs;
     }
 }
 
-impl Mul<U256> for Point {
-    type Output = Point;
+const WNAF_WIDTH: u32 = 4;
+
+impl<C: CurveParams> Mul<U256> for CurvePoint<C> {
+    type Output = Self;
 
-    // Double and add algorithm - that means **NOT CONSTANT TIME!!!**
-    fn mul(self, mut rhs: U256) -> Self::Output {
-        let mut res = Point::AT_INFINITY;
+    // Double and add algorithm with width-4 NAF - that means **NOT CONSTANT TIME!!!**
+    fn mul(self, rhs: U256) -> Self::Output {
+        let mut digits = [0; recode::MAX_DIGITS];
+        let len = recode::wnaf_into(rhs, WNAF_WIDTH, &mut digits);
 
-        for _ in 0..256 {
+        // odd multiples P, 3P, 5P, 7P
+        let double = self + self;
+        let mut table = [self; 1 << (WNAF_WIDTH - 2)];
+        for i in 1..table.len() {
+            table[i] = table[i - 1] + double;
+        }
+
+        let mut res = Self::AT_INFINITY;
+        for &digit in digits[..len].iter().rev() {
             res = res + res;
-            if rhs & U256([0, 0, 0, 1 << 63]) != U256::zero() {
-                res += self;
+            match digit {
+                0 => (),
+                1..=i8::MAX => res += table[(digit / 2) as usize],
+                _ => res += -table[(-digit / 2) as usize],
             }
-            rhs = rhs.wrapping_shl(1);
         }
 
         res
     }
 }
 
-impl Mul<u64> for Point {
-    type Output = Point;
+impl<C: CurveParams> Mul<u64> for CurvePoint<C> {
+    type Output = Self;
 
     fn mul(self, rhs: u64) -> Self::Output {
         self * U256::from(rhs)
     }
 }
 
-impl Neg for Point {
+impl Mul<Scalar> for Point {
     type Output = Point;
 
+    fn mul(self, rhs: Scalar) -> Self::Output {
+        self * rhs.to_u256()
+    }
+}
+
+impl<C: CurveParams> Neg for CurvePoint<C> {
+    type Output = Self;
+
     fn neg(self) -> Self::Output {
-        Point {
+        CurvePoint {
             x: self.x,
             y: -self.y,
         }
     }
 }
 
+impl<C: CurveParams> Neg for &CurvePoint<C> {
+    type Output = CurvePoint<C>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+/// Prints the coordinates as big-endian hex or `Point(infinity)`
+impl<C: CurveParams> fmt::Debug for CurvePoint<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_at_infinity() {
+            return f.write_str("Point(infinity)");
+        }
+        f.write_str("Point { x: 0x")?;
+        write_hex(f, &self.x.to_be_bytes())?;
+        f.write_str(", y: 0x")?;
+        write_hex(f, &self.y.to_be_bytes())?;
+        f.write_str(" }")
+    }
+}
+
+/// Converts a slice of known length to array reference.
+///
+/// Panics if the length is not 32.
+pub(crate) fn array_ref_32(slice: &[u8]) -> &[u8; 32] {
+    use core::convert::TryInto;
+
+    slice.try_into().expect("the caller must pass exactly 32 bytes")
+}
+
+/// Compares the bytes without exiting at the first difference, only the lengths may leak.
+///
+/// Used internally for secret-derived values regardless of the `subtle` feature which exposes
+/// the same comparison as `ct_eq_bytes`.
+#[cfg(any(feature = "subtle", feature = "ecies", feature = "bip38"))]
+pub(crate) fn bytes_eq_ct(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && core::hint::black_box(a.iter().zip(b).fold(0, |diff, (a, b)| diff | (a ^ b))) == 0
+}
+
+/// Writes the bytes as lower-case hex
+pub(crate) fn write_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    for byte in bytes {
+        write!(f, "{:02x}", byte)?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Point, G, Zp};
-    use bigint::U256;
-
-    macro_rules! be_point {
-        ($xa:expr, $xb:expr, $xc:expr, $xd:expr; $ya:expr, $yb:expr, $yc:expr, $yd:expr) => {
-            Point {
-                x: Zp(U256([$xd, $xc, $xb, $xa])),
-                y: Zp(U256([$yd, $yc, $yb, $ya])),
+    use super::{Error, Point, G, Zp, U256};
+    use crate::point;
+
+    const P: Point = point!("0479BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8");
+
+    #[test]
+    fn g_is_on_curve() {
+        assert_eq!(Point::new(G.x, G.y), Some(G));
+    }
+
+    #[test]
+    fn unchecked_construction() {
+        assert_eq!(Point::from_coordinates_unchecked(G.x, G.y), G);
+        assert_eq!(Point::from_coordinates_unchecked(Zp::ZERO, Zp::ZERO), Point::AT_INFINITY);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "the point is not on the curve")]
+    fn unchecked_construction_off_curve() {
+        Point::from_coordinates_unchecked(G.x, G.y + Zp::ONE);
+    }
+
+    #[test]
+    fn sec1_corruption_matrix() {
+        let compressed = G.serialize_compressed();
+        let uncompressed = G.serialize_uncompressed();
+        let p = crate::params::P.to_be_bytes();
+
+        for len in (0..=66).filter(|len| *len != 33 && *len != 65) {
+            let mut bytes = [0x02; 66];
+            bytes[0] = if len > 33 { 0x04 } else { 0x02 };
+            assert_eq!(Point::from_sec1_bytes(&bytes[..len]), Err(Error::InvalidLength(len)), "length {}", len);
+        }
+
+        for prefix in 0..=0xFF {
+            let mut short = compressed;
+            short[0] = prefix;
+            let mut long = uncompressed;
+            long[0] = prefix;
+            if prefix != 0x02 && prefix != 0x03 {
+                assert_eq!(Point::from_sec1_bytes(&short), Err(Error::InvalidPrefix(prefix)), "prefix {:#04x}", prefix);
+            }
+            if prefix != 0x04 {
+                assert_eq!(Point::from_sec1_bytes(&long), Err(Error::InvalidPrefix(prefix)), "prefix {:#04x}", prefix);
             }
         }
+
+        let mut x_out_of_range = compressed;
+        x_out_of_range[1..].copy_from_slice(&p);
+        assert_eq!(Point::from_sec1_bytes(&x_out_of_range), Err(Error::NonCanonicalFieldElement));
+        for range in &[1..33, 33..65] {
+            let mut out_of_range = uncompressed;
+            out_of_range[range.clone()].copy_from_slice(&p);
+            assert_eq!(Point::from_sec1_bytes(&out_of_range), Err(Error::NonCanonicalFieldElement), "{:?}", range);
+            out_of_range[range.clone()].copy_from_slice(&[0xFF; 32]);
+            assert_eq!(Point::from_sec1_bytes(&out_of_range), Err(Error::NonCanonicalFieldElement), "{:?}", range);
+        }
+
+        // x = 5 has no y
+        let mut not_on_curve = [0; 33];
+        not_on_curve[0] = 0x02;
+        not_on_curve[32] = 5;
+        assert_eq!(Point::from_sec1_bytes(&not_on_curve), Err(Error::NotOnCurve));
+        for i in 1..65 {
+            let mut corrupted = uncompressed;
+            corrupted[i] ^= 1;
+            assert_eq!(Point::from_sec1_bytes(&corrupted), Err(Error::NotOnCurve), "byte {}", i);
+        }
+
+        let mut infinity = [0; 65];
+        infinity[0] = 0x04;
+        assert_eq!(Point::from_sec1_bytes(&infinity), Err(Error::PointAtInfinity));
+
+        assert_eq!(Point::from_sec1_bytes(&compressed), Ok(G));
+        assert_eq!(Point::from_sec1_bytes(&uncompressed), Ok(G));
     }
 
-    const P: Point = be_point!(0x79BE667EF9DCBBAC, 0x55A06295CE870B07, 0x029BFCDB2DCE28D9, 0x59F2815B16F81798; 0x483ADA7726A3C465, 0x5DA4FBFC0E1108A8, 0xFD17B448A6855419, 0x9C47D08FFB10D4B8);
+    #[test]
+    fn debug_output() {
+        assert_eq!(format!("{:?}", Zp::ZERO), format!("Zp(0x{})", "0".repeat(64)));
+        assert_eq!(format!("{:?}", G.x), "Zp(0x79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798)");
+        assert_eq!(format!("{:?}", G), "Point { x: 0x79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798, y: 0x483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8 }");
+        assert_eq!(format!("{:?}", Point::AT_INFINITY), "Point(infinity)");
+    }
 
     #[test]
-    fn g_is_on_curve() {
-        assert_eq!(Point::new(G.x, G.y), Some(G));
+    fn specific_errors() {
+        assert_eq!(Point::try_new(G.x, G.y + Zp::ONE), Err(Error::NotOnCurve));
+        assert_eq!(Point::try_from_x(G.x, G.y.is_odd()), Ok(G));
+        // x³ + 7 = 132 has no square root modulo P
+        assert_eq!(Point::try_from_x(Zp::from_reduced(U256::from(5)), false), Err(Error::NotOnCurve));
+        assert_eq!(Zp::try_from_u256(crate::params::P - U256::one()), Ok(-Zp::ONE));
+        assert_eq!(Zp::try_from_u256(crate::params::P), Err(Error::NonCanonicalFieldElement));
+        assert_eq!(Zp::try_from_u256(U256::max_value()), Err(Error::NonCanonicalFieldElement));
     }
 
     #[test]
@@ -320,7 +1020,7 @@ mod tests {
 
     #[test]
     fn curve_order() {
-        assert!((G * super::SECP256K1_GROUP_ORDER).is_at_infinity());
+        assert!((G * crate::params::N).is_at_infinity());
     }
 
     #[test]
@@ -340,16 +1040,152 @@ mod tests {
 
     #[test]
     fn p_times_2() {
-        assert_eq!(P * 2, be_point!(0xC6047F9441ED7D6D, 0x3045406E95C07CD8, 0x5C778E4B8CEF3CA7, 0xABAC09B95C709EE5; 0x1AE168FEA63DC339, 0xA3C58419466CEAEE, 0xF7F632653266D0E1, 0x236431A950CFE52A));
+        assert_eq!(P * 2, point!("04C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE51AE168FEA63DC339A3C58419466CEAEEF7F632653266D0E1236431A950CFE52A"));
     }
 
     #[test]
     fn p_times_3() {
-        assert_eq!(P * 3, be_point!(0xF9308A019258C310, 0x49344F85F89D5229, 0xB531C845836F99B0, 0x8601F113BCE036F9; 0x388F7B0F632DE814, 0x0FE337E62A37F356, 0x6500A99934C2231B, 0x6CB9FD7584B8E672));
+        assert_eq!(P * 3, point!("04F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9388F7B0F632DE8140FE337E62A37F3566500A99934C2231B6CB9FD7584B8E672"));
+    }
+
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn nums_h() {
+        let x = Zp::from_be_bytes(&crate::hashes::sha256(&G.serialize_uncompressed())).unwrap();
+        assert_eq!(Point::lift_x(x), Some(super::H));
+    }
+
+    #[test]
+    fn random_points() {
+        use crate::test_utils::DeterministicRng;
+
+        let mut rng = DeterministicRng::new(241);
+        let points = [(); 16].map(|_| Point::random(&mut rng));
+        let mut odd = 0;
+        for point in &points {
+            assert!(!point.is_at_infinity());
+            assert_eq!(Point::new(point.x(), point.y()), Some(*point));
+            odd += usize::from(point.y().is_odd());
+        }
+        // both parities show up, the chance of this failing is 2⁻¹⁵
+        assert!(odd > 0 && odd < points.len());
+        assert_ne!(points[0], points[1]);
+
+        let mut rng = DeterministicRng::new(241);
+        assert_eq!([(); 16].map(|_| Point::random(&mut rng)), points);
+
+        for _ in 0..4 {
+            let (k, point) = Point::random_with_dlog(&mut rng);
+            assert!(!point.is_at_infinity());
+            assert_eq!(Point::new(point.x(), point.y()), Some(point));
+            assert_eq!(G * k, point);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn lift_x_batch() {
+        use rand_core::RngCore;
+
+        let mut rng = crate::test_utils::DeterministicRng::new(213);
+        let mut xs = vec![Zp::ZERO, Zp::ONE, -Zp::ONE, G.x(), super::H.x()];
+        for _ in 0..200 {
+            let mut bytes = [0; 32];
+            rng.fill_bytes(&mut bytes);
+            xs.push(Zp::wrapping_from(U256::from_be_bytes(&bytes)));
+        }
+        let lifted = Point::lift_x_batch(&xs);
+        assert_eq!(lifted.len(), xs.len());
+        for (x, point) in xs.iter().zip(&lifted) {
+            assert_eq!(*point, Point::lift_x(*x), "{:?}", x);
+        }
+        // about half of the values are not on the curve
+        let missing = lifted.iter().filter(|point| point.is_none()).count();
+        assert!(missing > 60 && missing < 140, "{}", missing);
+        assert!(Point::lift_x_batch(&[]).is_empty());
     }
 
     #[test]
     fn multiplicative_inverse() {
         assert_eq!((G * 42) * Point::scalar_multiplicative_inverse(42.into()), G);
     }
+
+    #[test]
+    fn batch_invert_field_elements() {
+        let elements = [G.x(), G.y(), Zp::ONE, -Zp::ONE, (G * 3).x()];
+        let mut inverses = elements;
+        Zp::batch_invert_in_place(&mut inverses).unwrap();
+        for (element, inverse) in elements.iter().zip(&inverses) {
+            assert_eq!(*inverse, element.multiplicative_inverse());
+        }
+        Zp::batch_invert_in_place(&mut []).unwrap();
+
+        let mut with_scratch = elements;
+        let mut scratch = [Zp::ZERO; 5];
+        assert_eq!(Zp::batch_invert_with_scratch(&mut with_scratch, &mut scratch[..4]), Err(Error::InvalidLength(4)));
+        assert_eq!(with_scratch, elements);
+        Zp::batch_invert_with_scratch(&mut with_scratch, &mut scratch).unwrap();
+        assert_eq!(with_scratch, inverses);
+
+        let mut with_zero = [G.x(), Zp::ZERO];
+        assert_eq!(Zp::batch_invert_in_place(&mut with_zero), Err(Error::ZeroFieldElement));
+        assert_eq!(with_zero, [G.x(), Zp::ZERO]);
+    }
+
+    #[test]
+    fn cube_root() {
+        use rand_core::RngCore;
+
+        let beta = Zp::CUBE_ROOT_OF_UNITY;
+        assert_eq!(beta * beta * beta, Zp::ONE);
+        assert_eq!(Zp::ZERO.cube_root(), Some(Zp::ZERO));
+        assert_eq!(Zp::ONE.cube_root().map(|root| root * root * root), Some(Zp::ONE));
+
+        let mut rng = crate::test_utils::DeterministicRng::new(202);
+        let mut cubes = 0;
+        for _ in 0..600 {
+            let mut bytes = [0; 32];
+            rng.fill_bytes(&mut bytes);
+            let x = Zp::wrapping_from(U256::from_be_bytes(&bytes));
+            if let Some(root) = x.cube_root() {
+                cubes += 1;
+                assert_eq!(root * root * root, x);
+                // the other two roots
+                assert_eq!((root * beta).pow(U256::from(3)), x);
+                assert_eq!((root * beta * beta).pow(U256::from(3)), x);
+            } else {
+                assert_eq!((x * x * x).cube_root().map(|root| root * root * root), Some(x * x * x));
+            }
+        }
+        // a third of the elements are cubes, the standard deviation is about 12
+        assert!((150..250).contains(&cubes), "{} cubes out of 600", cubes);
+    }
+
+    /// `[n, p)` is where the x coordinate of `R` wraps and recovery ids 2 and 3 are needed
+    #[test]
+    fn scalar_conversions() {
+        use crate::params::{N, P};
+        use crate::Scalar;
+
+        let n = Zp::from_reduced(N);
+        assert_eq!(n.to_scalar_overflowing(), (Scalar::ZERO, true));
+        assert_eq!((n - Zp::ONE).to_scalar_overflowing(), (-Scalar::ONE, false));
+        assert_eq!((-Zp::ONE).to_scalar_overflowing(), (Scalar::wrapping_from(P - N - U256::one()), true));
+        assert_eq!((n + Zp::from_reduced(U256::from(5))).to_scalar_reduce(), Scalar::from(5));
+        // a point whose x coordinate wraps
+        let point = (0..)
+            .find_map(|i| Point::lift_x(n + Zp::from_reduced(U256::from(i))))
+            .unwrap();
+        let (r, overflow) = point.x().to_scalar_overflowing();
+        assert!(overflow);
+        assert_eq!(r.to_zp() + n, point.x());
+
+        let mut rng = crate::test_utils::DeterministicRng::new(230);
+        let edge_cases = [Scalar::ZERO, Scalar::ONE, -Scalar::ONE];
+        for scalar in edge_cases.iter().copied().chain((0..20).map(|_| Scalar::random(&mut rng))) {
+            assert_eq!(scalar.to_zp().to_be_bytes(), scalar.to_be_bytes());
+            assert_eq!(scalar.to_zp().to_scalar_overflowing(), (scalar, false));
+        }
+        assert_eq!(G.x().to_scalar_reduce(), Scalar::from_be_bytes(&G.x().to_be_bytes()).unwrap());
+    }
 }
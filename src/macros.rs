@@ -0,0 +1,196 @@
+//! Compile-time parsing of constants used by the `zp!` and `point!` macros
+//!
+//! The functions are public only because the macros expand to calls to them, they are not part
+//! of the API. They panic on invalid input which is a compile error when they are evaluated in
+//! a constant, the macros make sure they always are.
+//!
+//! The arithmetic uses the `const` functions of the `field` module since the operators are not
+//! `const`.
+
+use crate::field::{add, eq, mul, pow};
+use crate::params::{B, P, SQRT_EXPONENT};
+use crate::{Point, Zp, U256};
+
+/// Parses a field element from big-endian hex with at most 64 digits.
+///
+/// # Panics
+///
+/// Panics if the string is empty, too long, contains a non-hex character or the value is not
+/// less than `p`.
+pub const fn zp_from_hex(hex: &str) -> Zp {
+    let hex = hex.as_bytes();
+    assert!(!hex.is_empty() && hex.len() <= 64, "field element must have 1 to 64 hex digits");
+    parse_zp(hex, 0, hex.len())
+}
+
+/// Parses a point from the hex of its compressed or uncompressed SEC1 encoding.
+///
+/// # Panics
+///
+/// Panics if the string is not valid hex of a canonical SEC1 encoding of a point on the curve.
+pub const fn point_from_hex(hex: &str) -> Point {
+    let hex = hex.as_bytes();
+    assert!(hex.len() == 66 || hex.len() == 130, "point must have 33 or 65 bytes");
+    let prefix = parse_u256(hex, 0, 2).low_u64();
+    let x = parse_zp(hex, 2, 66);
+    let rhs = add(mul(mul(x.0, x.0), x.0), B.0);
+    let y = match (prefix, hex.len()) {
+        (0x02, 66) | (0x03, 66) => {
+            let y = pow(rhs, SQRT_EXPONENT);
+            assert!(eq(mul(y, y), rhs), "point is not on the curve");
+            if y.is_even() == (prefix == 0x02) {
+                y
+            } else {
+                // y is not zero since zero is even
+                P.wrapping_sub(y)
+            }
+        },
+        (0x04, 130) => {
+            let y = parse_zp(hex, 66, 130).0;
+            assert!(eq(mul(y, y), rhs), "point is not on the curve");
+            y
+        },
+        _ => panic!("invalid prefix of the point"),
+    };
+    Point { x, y: Zp::from_reduced(y) }
+}
+
+const fn hex_digit(digit: u8) -> u64 {
+    match digit {
+        b'0'..=b'9' => (digit - b'0') as u64,
+        b'a'..=b'f' => (digit - b'a' + 10) as u64,
+        b'A'..=b'F' => (digit - b'A' + 10) as u64,
+        _ => panic!("invalid hex digit"),
+    }
+}
+
+/// Parses big-endian hex `hex[start..end]` with at most 64 digits
+const fn parse_u256(hex: &[u8], start: usize, end: usize) -> U256 {
+    let mut limbs = [0; 4];
+    let mut i = 0;
+    while start + i < end {
+        let digit = hex_digit(hex[end - 1 - i]);
+        limbs[i / 16] |= digit << (i % 16 * 4);
+        i += 1;
+    }
+    U256(limbs)
+}
+
+const fn parse_zp(hex: &[u8], start: usize, end: usize) -> Zp {
+    match Zp::checked_from(parse_u256(hex, start, end)) {
+        Some(value) => value,
+        None => panic!("field element is not less than p"),
+    }
+}
+
+/// Defines a field element constant from big-endian hex with at most 64 digits.
+///
+/// The hex is parsed and checked to be less than `p` at compile time so the result can be used
+/// in constants.
+///
+/// ```
+/// const SEVEN: toy_secp256k1::Zp = toy_secp256k1::zp!("7");
+/// assert_eq!(SEVEN, toy_secp256k1::params::B);
+/// ```
+///
+/// Values that are not less than `p` don't compile:
+///
+/// ```compile_fail
+/// const P: toy_secp256k1::Zp = toy_secp256k1::zp!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+/// ```
+#[macro_export]
+macro_rules! zp {
+    ($hex:expr) => {{
+        const VALUE: $crate::Zp = $crate::macros::zp_from_hex($hex);
+        VALUE
+    }};
+}
+
+/// Defines a point constant from the hex of its compressed or uncompressed SEC1 encoding.
+///
+/// The hex is parsed and the point is checked to be on the curve at compile time (and
+/// decompressed if needed) so the result can be used in constants.
+///
+/// ```
+/// const G: toy_secp256k1::Point = toy_secp256k1::point!("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+/// assert_eq!(G, toy_secp256k1::G);
+/// ```
+///
+/// Points not on the curve don't compile:
+///
+/// ```compile_fail
+/// const G: toy_secp256k1::Point = toy_secp256k1::point!("0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b9");
+/// ```
+#[macro_export]
+macro_rules! point {
+    ($hex:expr) => {{
+        const VALUE: $crate::Point = $crate::macros::point_from_hex($hex);
+        VALUE
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{point_from_hex, zp_from_hex};
+    use crate::field::{add, mul, pow};
+    use crate::test_utils::DeterministicRng;
+    use crate::{params, Point, Zp, G, U256};
+    use rand_core::RngCore;
+
+    #[test]
+    fn matches_runtime() {
+        const G_COMPRESSED: Point = point!("0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798");
+        const G_UNCOMPRESSED: Point = point!("0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8");
+        assert_eq!(G_COMPRESSED, Point::GENERATOR);
+        assert_eq!(G_UNCOMPRESSED, Point::GENERATOR);
+        assert_eq!(zp!("7"), Zp::from_reduced(U256::from(7)));
+        assert_eq!(zp!("0"), Zp::ZERO);
+
+        let mut rng = DeterministicRng::new(209);
+        for _ in 0..50 {
+            let point = G * U256::from(rng.next_u64());
+            let hex = |bytes: &[u8]| bytes.iter().map(|byte| format!("{:02x}", byte)).collect::<String>();
+            assert_eq!(point_from_hex(&hex(&point.serialize_compressed())), point);
+            assert_eq!(point_from_hex(&hex(&point.serialize_uncompressed())), point);
+            assert_eq!(zp_from_hex(&hex(&point.x().to_be_bytes())), point.x());
+
+            let (a, b) = (point.x(), point.y());
+            assert_eq!(Zp::from_reduced(mul(a.0, b.0)), a * b);
+            assert_eq!(Zp::from_reduced(add(a.0, b.0)), a + b);
+            let minus_one = -Zp::ONE;
+            assert_eq!(Zp::from_reduced(mul(minus_one.0, minus_one.0)), Zp::ONE);
+            assert_eq!(Zp::from_reduced(pow(a.0, params::SQRT_EXPONENT)), a.pow(params::SQRT_EXPONENT));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not on the curve")]
+    fn off_curve() {
+        point_from_hex("0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b9");
+    }
+
+    #[test]
+    #[should_panic(expected = "not on the curve")]
+    fn no_square_root() {
+        // 5³ + 7 is not a square
+        point_from_hex("020000000000000000000000000000000000000000000000000000000000000005");
+    }
+
+    #[test]
+    #[should_panic(expected = "not less than p")]
+    fn non_canonical() {
+        zp_from_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid hex digit")]
+    fn invalid_digit() {
+        zp_from_hex("0x7");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid prefix")]
+    fn invalid_prefix() {
+        point_from_hex("0579be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+    }
+}
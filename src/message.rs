@@ -0,0 +1,111 @@
+//! The 32-byte message signed by ECDSA and BIP340
+//!
+//! Signing a bare `[u8; 32]` makes it too easy to sign data that wasn't hashed or was hashed
+//! differently than the verifier expects. `Message` can only be constructed through named
+//! constructors that state how the digest was obtained, there's intentionally no
+//! `From<[u8; 32]>`.
+
+use core::fmt;
+#[cfg(feature = "hashes")]
+use crate::hashes::{sha256, sha256d};
+#[cfg(feature = "hashes")]
+use crate::tagged_hash::TaggedHash;
+use crate::write_hex;
+
+/// Digest of the signed data
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Message([u8; 32]);
+
+impl Message {
+    /// Wraps the bytes claiming they are already an output of a cryptographic hash function.
+    ///
+    /// Use it for digests computed elsewhere, e.g. Bitcoin sighashes. Never pass data that
+    /// wasn't hashed - for ECDSA anyone could then forge signatures of related messages.
+    pub const fn from_digest(digest: [u8; 32]) -> Self {
+        Message(digest)
+    }
+
+    /// Hashes the data using SHA256
+    #[cfg(feature = "hashes")]
+    pub fn from_hashed_data(data: &[u8]) -> Self {
+        Message(sha256(data))
+    }
+
+    /// Hashes the data using double SHA256 as used by Bitcoin
+    #[cfg(feature = "hashes")]
+    pub fn from_double_sha256(data: &[u8]) -> Self {
+        Message(sha256d(data))
+    }
+
+    /// Hashes the data using BIP340 tagged hash with `tag`
+    #[cfg(feature = "hashes")]
+    pub fn from_tagged(tag: &str, data: &[u8]) -> Self {
+        Message(TaggedHash::new(tag).hash(&[data]))
+    }
+
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    pub const fn to_bytes(self) -> [u8; 32] {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for Message {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Displays the digest in hex
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, &self.0)
+    }
+}
+
+impl fmt::Debug for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Message(")?;
+        write_hex(f, &self.0)?;
+        f.write_str(")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Message;
+
+    #[test]
+    fn from_digest() {
+        let message = Message::from_digest([0x42; 32]);
+        assert_eq!(message.to_bytes(), [0x42; 32]);
+        assert_eq!(*message.as_bytes(), [0x42; 32]);
+        assert_eq!(message.as_ref(), &[0x42; 32][..]);
+    }
+
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn hashing_constructors() {
+        use crate::test_util::hex32;
+
+        assert_eq!(Message::from_hashed_data(b"abc").to_bytes(), hex32("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"));
+        assert_eq!(Message::from_double_sha256(b"hello").to_bytes(), hex32("9595c9df90075148eb06860365df33584b75bff782a510c6cd4883a419833d50"));
+        // SHA256(SHA256(tag) || SHA256(tag) || data)
+        let tag = crate::hashes::sha256(b"BIP0340/challenge");
+        let mut preimage = [0; 67];
+        preimage[..32].copy_from_slice(&tag);
+        preimage[32..64].copy_from_slice(&tag);
+        preimage[64..].copy_from_slice(b"abc");
+        assert_eq!(Message::from_tagged("BIP0340/challenge", b"abc"), Message::from_hashed_data(&preimage));
+    }
+
+    #[test]
+    fn formatting() {
+        let message = Message::from_digest([0xAB; 32]);
+        let hex = "ab".repeat(32);
+        assert_eq!(format!("{}", message), hex);
+        assert_eq!(format!("{:?}", message), format!("Message({})", hex));
+    }
+}
@@ -0,0 +1,94 @@
+//! Multi-scalar multiplication - computing `Σ kᵢ·Pᵢ` faster than multiplying each point separately
+
+use crate::{Point, U256};
+
+/// Computes the sum of products of all pairs.
+///
+/// Uses interleaved double-and-add (Straus) so the doublings are shared by all points. Just like
+/// `Point * U256` this is **NOT CONSTANT TIME!!!**
+pub fn multiexp(pairs: &[(U256, Point)]) -> Point {
+    let mut res = Point::AT_INFINITY;
+    for bit in (0..256).rev() {
+        res = res + res;
+        for (scalar, point) in pairs {
+            if scalar.bit(bit) {
+                res += *point;
+            }
+        }
+    }
+
+    res
+}
+
+/// Number of points of scratch space `multiexp_into` needs per pair to process it with windows.
+pub const SCRATCH_PER_POINT: usize = 15;
+
+/// Computes the sum of products of all pairs into `dst` using `scratch` for precomputed tables.
+///
+/// The scalars are split into 4-bit windows and the multiples `1·P … 15·P` of each point are
+/// stored in `scratch`, which replaces about 128 additions per point with 78. The pairs are
+/// processed in chunks of `scratch.len() / SCRATCH_PER_POINT` so any length works, the doublings
+/// are repeated for each chunk though. If `scratch` can't hold a single table this falls back to
+/// `multiexp`. The result is the same as the one of `multiexp` and nothing is allocated. This is
+/// **NOT CONSTANT TIME!!!** either.
+pub fn multiexp_into(dst: &mut Point, pairs: &[(U256, Point)], scratch: &mut [Point]) {
+    let chunk_len = scratch.len() / SCRATCH_PER_POINT;
+    if chunk_len == 0 {
+        *dst = multiexp(pairs);
+        return;
+    }
+    let mut res = Point::AT_INFINITY;
+    for chunk in pairs.chunks(chunk_len) {
+        for ((_, point), table) in chunk.iter().zip(scratch.chunks_exact_mut(SCRATCH_PER_POINT)) {
+            table[0] = *point;
+            for i in 1..SCRATCH_PER_POINT {
+                table[i] = table[i - 1] + *point;
+            }
+        }
+        let mut sum = Point::AT_INFINITY;
+        for window in (0..64).rev() {
+            for _ in 0..4 {
+                sum = sum + sum;
+            }
+            for ((scalar, _), table) in chunk.iter().zip(scratch.chunks_exact(SCRATCH_PER_POINT)) {
+                let digit = (0..4).fold(0, |digit, i| digit | usize::from(scalar.bit(window * 4 + i)) << i);
+                if digit != 0 {
+                    sum += table[digit - 1];
+                }
+            }
+        }
+        res += sum;
+    }
+    *dst = res;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{multiexp, multiexp_into, SCRATCH_PER_POINT};
+    use crate::{Point, G, U256};
+
+    #[test]
+    fn matches_naive() {
+        let pairs = [(U256::from(3), G), (U256::from(5), G * 7), (U256::max_value(), -(G * 2)), (U256::zero(), G)];
+        let expected = pairs.iter().fold(Point::AT_INFINITY, |acc, (scalar, point)| acc + *point * *scalar);
+        assert_eq!(multiexp(&pairs), expected);
+        assert_eq!(multiexp(&[]), Point::AT_INFINITY);
+        assert_eq!(multiexp(&[(U256::from(1), G), (U256::from(1), -G)]), Point::AT_INFINITY);
+    }
+
+    #[test]
+    fn into_matches_multiexp() {
+        let pairs = [(U256::from(3), G), (U256::from(5), G * 7), (U256::max_value(), -(G * 2)), (U256::zero(), G), (U256::one() << 255, G * 3)];
+        let expected = multiexp(&pairs);
+        let mut scratch = [Point::AT_INFINITY; SCRATCH_PER_POINT * 3];
+        // one chunk, several chunks, falling back to `multiexp`
+        for &len in &[SCRATCH_PER_POINT * 3, SCRATCH_PER_POINT * 2, SCRATCH_PER_POINT + 1, SCRATCH_PER_POINT - 1, 0] {
+            let mut res = G;
+            multiexp_into(&mut res, &pairs, &mut scratch[..len]);
+            assert_eq!(res, expected, "scratch length {}", len);
+        }
+        let mut res = G;
+        multiexp_into(&mut res, &[], &mut scratch);
+        assert_eq!(res, Point::AT_INFINITY);
+    }
+}
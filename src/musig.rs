@@ -0,0 +1,12 @@
+//! MuSig2 multi-signatures (BIP327)
+//!
+//! The signers aggregate their keys into a single BIP340 key and cooperatively produce a
+//! signature which is indistinguishable from a single-signer one.
+
+mod key_agg;
+mod nonce;
+mod session;
+
+pub use key_agg::KeyAggContext;
+pub use nonce::{AggNonce, PubNonce, SecNonce};
+pub use session::{aggregate_partials, deterministic_sign, partial_sign, partial_verify, PartialSig, Session};
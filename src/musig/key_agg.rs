@@ -0,0 +1,177 @@
+use alloc::vec::Vec;
+use crate::tagged_hash::{TaggedHash, KEYAGG_COEFFICIENT, KEYAGG_LIST};
+use crate::{Error, Parity, Point, PublicKey, Scalar, XOnlyPoint, G};
+
+/// Aggregated key together with the information needed for signing
+///
+/// Besides the aggregate it keeps the sign `gacc` and the sum `tacc` accumulated by tweaking so
+/// that the signers can adjust their partial signatures.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct KeyAggContext {
+    pubkeys: Vec<PublicKey>,
+    list_hash: [u8; 32],
+    second_key: Option<PublicKey>,
+    q: PublicKey,
+    pub(super) gacc: Scalar,
+    pub(super) tacc: Scalar,
+}
+
+impl KeyAggContext {
+    /// Aggregates the keys in the given order.
+    ///
+    /// The order matters, signers usually sort the keys first using
+    /// `PublicKey::sort_lexicographic`. Returns `Error::InvalidPublicKey` if the aggregate is
+    /// the point at infinity (including an empty list).
+    pub fn new(pubkeys: &[PublicKey]) -> Result<Self, Error> {
+        let serialized = pubkeys.iter().map(PublicKey::serialize).collect::<Vec<_>>();
+        let chunks = serialized.iter().map(|key| &key[..]).collect::<Vec<_>>();
+        let list_hash = TaggedHash::new(KEYAGG_LIST).hash(&chunks);
+        let second_key = pubkeys.iter().copied().find(|key| *key != pubkeys[0]);
+
+        let mut context = KeyAggContext {
+            pubkeys: pubkeys.to_vec(),
+            list_hash,
+            second_key,
+            q: PublicKey::from_point(G).expect("G is not at infinity"),
+            gacc: Scalar::ONE,
+            tacc: Scalar::ZERO,
+        };
+        let q = pubkeys
+            .iter()
+            .map(|key| key.to_point() * context.coefficient(key))
+            .fold(Point::AT_INFINITY, |acc, point| acc + point);
+        context.q = PublicKey::from_point(q).ok_or(Error::InvalidPublicKey)?;
+        Ok(context)
+    }
+
+    /// Applies a plain (BIP32-style) or x-only (taproot) tweak to the aggregate key.
+    ///
+    /// Returns `Error::InvalidTweak` if the tweak is not less than the curve order or the
+    /// tweaked key would be the point at infinity.
+    pub fn with_tweak(mut self, tweak: &[u8; 32], is_xonly: bool) -> Result<Self, Error> {
+        let t = Scalar::from_be_bytes(tweak).ok_or(Error::InvalidTweak)?;
+        let g = if is_xonly && self.x_only_public_key().1 == Parity::Odd { -Scalar::ONE } else { Scalar::ONE };
+        let q = self.q.to_point() * g + G * t;
+        self.q = PublicKey::from_point(q).ok_or(Error::InvalidTweak)?;
+        self.gacc = g * self.gacc;
+        self.tacc = t + g * self.tacc;
+        Ok(self)
+    }
+
+    /// Returns the aggregate key including tweaks
+    pub fn public_key(&self) -> &PublicKey {
+        &self.q
+    }
+
+    /// Returns the BIP340 aggregate key and the parity of the full key
+    pub fn x_only_public_key(&self) -> (XOnlyPoint, Parity) {
+        self.q.x_only_public_key()
+    }
+
+    /// The keys in the order they were aggregated
+    pub fn pubkeys(&self) -> &[PublicKey] {
+        &self.pubkeys
+    }
+
+    /// Returns the coefficient `a_i` of the key or `None` if the key wasn't aggregated
+    pub fn key_agg_coefficient(&self, pubkey: &PublicKey) -> Option<Scalar> {
+        if self.pubkeys.contains(pubkey) {
+            Some(self.coefficient(pubkey))
+        } else {
+            None
+        }
+    }
+
+    fn coefficient(&self, pubkey: &PublicKey) -> Scalar {
+        // keys equal to the second distinct key get coefficient 1 which saves one scalar
+        // multiplication, it's secure as long as at least one key has a hash coefficient
+        if Some(*pubkey) == self.second_key {
+            return Scalar::ONE;
+        }
+        let hash = TaggedHash::new(KEYAGG_COEFFICIENT).hash(&[&self.list_hash, &pubkey.serialize()]);
+        Scalar::from_be_bytes_reduce(&hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyAggContext;
+    use crate::test_util::{hex, hex32};
+    use crate::{Error, Parity, PublicKey, Scalar, XOnlyPoint, G};
+
+    /// Keys from the BIP327 key aggregation vectors, the ones at indices 3, 4 and 5 are invalid
+    const PUBKEYS: [&str; 7] = [
+        "02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+        "03DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+        "023590A94E768F8E1815C2F24B4D80A8E3149316C3518CE7B7AD338368D038CA66",
+        "020000000000000000000000000000000000000000000000000000000000000005",
+        "02FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30",
+        "04F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+        "03935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9",
+    ];
+
+    const TWEAKS: [&str; 2] = [
+        "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+        "252E4BD67410A76CDF933D30EAA1608214037F1B105A013ECCD3C5C184A6110B",
+    ];
+
+    fn parse(indices: &[usize]) -> Result<Vec<PublicKey>, Error> {
+        indices.iter().map(|i| PublicKey::from_sec1_bytes(&hex(PUBKEYS[*i]))).collect()
+    }
+
+    #[test]
+    fn bip327_valid() {
+        let vectors: [(&[usize], &str); 4] = [
+            (&[0, 1, 2], "90539EEDE565F5D054F32CC0C220126889ED1E5D193BAF15AEF344FE59D4610C"),
+            (&[2, 1, 0], "6204DE8B083426DC6EAF9502D27024D53FC826BF7D2012148A0575435DF54B2B"),
+            (&[0, 0, 0], "B436E3BAD62B8CD409969A224731C193D051162D8C5AE8B109306127DA3AA935"),
+            (&[0, 0, 1, 1], "69BC22BFA5D106306E48A20679DE1D7389386124D07571D0D872686028C26A3E"),
+        ];
+        for (indices, expected) in &vectors {
+            let context = KeyAggContext::new(&parse(indices).unwrap()).unwrap();
+            assert_eq!(context.x_only_public_key().0.serialize(), hex32(expected), "keys {:?}", indices);
+        }
+    }
+
+    #[test]
+    fn bip327_invalid_pubkeys() {
+        let cases = [
+            ([0, 3], Error::NotOnCurve),
+            ([0, 4], Error::NonCanonicalFieldElement),
+            ([5, 0], Error::InvalidPrefix(0x04)),
+        ];
+        for (indices, error) in &cases {
+            assert_eq!(parse(indices), Err(*error), "keys {:?}", indices);
+        }
+    }
+
+    #[test]
+    fn bip327_invalid_tweaks() {
+        let context = KeyAggContext::new(&parse(&[0, 1]).unwrap()).unwrap();
+        assert_eq!(context.with_tweak(&hex32(TWEAKS[0]), true), Err(Error::InvalidTweak));
+        // the tweak moves the aggregate key to the point at infinity
+        let context = KeyAggContext::new(&parse(&[6]).unwrap()).unwrap();
+        assert_eq!(context.with_tweak(&hex32(TWEAKS[1]), false), Err(Error::InvalidTweak));
+    }
+
+    #[test]
+    fn tweak_accumulation() {
+        let keys = parse(&[0, 1, 2]).unwrap();
+        let untweaked = KeyAggContext::new(&keys).unwrap();
+        let base = untweaked.public_key().to_point();
+        let mut context = untweaked.clone();
+        let mut seen_odd = false;
+        for (i, is_xonly) in [true, false, true, true].iter().enumerate() {
+            seen_odd |= *is_xonly && context.x_only_public_key().1 == Parity::Odd;
+            let tweak = Scalar::from(i as u64 + 1);
+            let previous = context.public_key().to_point();
+            context = context.with_tweak(&tweak.to_be_bytes(), *is_xonly).unwrap();
+            let expected = if *is_xonly { XOnlyPoint::from_point(previous).unwrap().0.to_point() } else { previous };
+            assert_eq!(context.public_key().to_point(), expected + G * tweak);
+            assert_eq!(context.public_key().to_point(), base * context.gacc + G * context.tacc);
+        }
+        assert!(seen_odd, "the vectors should exercise negation");
+        assert_eq!(untweaked.key_agg_coefficient(&keys[1]), Some(Scalar::ONE));
+        assert_eq!(untweaked.key_agg_coefficient(&PublicKey::from_point(G).unwrap()), None);
+    }
+}
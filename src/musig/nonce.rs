@@ -0,0 +1,290 @@
+use core::fmt;
+use crate::tagged_hash::{TaggedHash, MUSIG_AUX, MUSIG_DETERMINISTIC_NONCE, MUSIG_NONCE};
+use crate::{write_hex, Error, Point, PublicKey, Scalar, SecretKey, XOnlyPoint, G};
+
+/// Secret nonce of one signer
+///
+/// Intentionally neither `Copy` nor `Clone` - signing consumes the nonce because using it twice
+/// reveals the secret key.
+pub struct SecNonce {
+    pub(super) k1: Scalar,
+    pub(super) k2: Scalar,
+    pub(super) public_key: PublicKey,
+}
+
+impl SecNonce {
+    /// Derives the nonce as defined in BIP327.
+    ///
+    /// `session_rand` must be fresh randomness that is never reused. The optional arguments
+    /// make the nonce depend on more of the session so a broken random number generator is less
+    /// likely to cause nonce reuse; the secret key additionally masks `session_rand`.
+    pub fn generate(
+        session_rand: &[u8; 32],
+        secret_key: Option<&SecretKey>,
+        public_key: &PublicKey,
+        aggregate_key: Option<&XOnlyPoint>,
+        msg: Option<&[u8]>,
+        extra_in: &[u8],
+    ) -> Self {
+        let mut rand = *session_rand;
+        if let Some(secret_key) = secret_key {
+            let mask = TaggedHash::new(MUSIG_AUX).hash(&[session_rand]);
+            rand = secret_key.to_bytes();
+            for (rand, mask) in rand.iter_mut().zip(&mask) {
+                *rand ^= mask;
+            }
+        }
+        let public_key_bytes = public_key.serialize();
+        let aggregate_key = aggregate_key.map(XOnlyPoint::serialize);
+        let aggregate_key: &[u8] = aggregate_key.as_ref().map_or(&[], |key| &key[..]);
+        let (msg_prefix, msg_len, msg) = match msg {
+            Some(msg) => (1u8, (msg.len() as u64).to_be_bytes(), msg),
+            None => (0, [0; 8], &[][..]),
+        };
+        let msg_len: &[u8] = if msg_prefix == 1 { &msg_len } else { &[] };
+
+        let hasher = TaggedHash::new(MUSIG_NONCE);
+        let k = |i: u8| {
+            let hash = hasher.hash(&[
+                &rand,
+                &[public_key_bytes.len() as u8],
+                &public_key_bytes,
+                &[aggregate_key.len() as u8],
+                aggregate_key,
+                &[msg_prefix],
+                msg_len,
+                msg,
+                &(extra_in.len() as u32).to_be_bytes(),
+                extra_in,
+                &[i],
+            ]);
+            let k = Scalar::from_be_bytes_reduce(&hash);
+            assert!(!k.is_zero(), "the probability of zero nonce is negligible");
+            k
+        };
+        SecNonce {
+            k1: k(0),
+            k2: k(1),
+            public_key: *public_key,
+        }
+    }
+
+    /// Derives the nonce of the deterministic signer from the other nonces, the message and the
+    /// keys, see `deterministic_sign`.
+    pub(super) fn deterministic(
+        secret_key: &SecretKey,
+        agg_other_nonce: &AggNonce,
+        aggregate_key: &XOnlyPoint,
+        msg: &[u8],
+        rand: Option<&[u8; 32]>,
+    ) -> Self {
+        let mut secret = secret_key.to_bytes();
+        if let Some(rand) = rand {
+            let mask = TaggedHash::new(MUSIG_AUX).hash(&[rand]);
+            for (secret, mask) in secret.iter_mut().zip(&mask) {
+                *secret ^= mask;
+            }
+        }
+        let hasher = TaggedHash::new(MUSIG_DETERMINISTIC_NONCE);
+        let k = |i: u8| {
+            let hash = hasher.hash(&[
+                &secret,
+                &agg_other_nonce.serialize(),
+                &aggregate_key.serialize(),
+                &(msg.len() as u64).to_be_bytes(),
+                msg,
+                &[i],
+            ]);
+            let k = Scalar::from_be_bytes_reduce(&hash);
+            assert!(!k.is_zero(), "the probability of zero nonce is negligible");
+            k
+        };
+        SecNonce {
+            k1: k(0),
+            k2: k(1),
+            public_key: secret_key.public_key(),
+        }
+    }
+
+    /// The key of the signer the nonce was generated for
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Computes the nonce that is sent to the other signers
+    pub fn public_nonce(&self) -> PubNonce {
+        PubNonce {
+            r1: G * self.k1,
+            r2: G * self.k2,
+        }
+    }
+}
+
+/// Doesn't print the nonce so that it doesn't leak into logs
+impl fmt::Debug for SecNonce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecNonce(..)")
+    }
+}
+
+/// Public nonce of one signer - a pair of points which are never at infinity
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct PubNonce {
+    pub(super) r1: Point,
+    pub(super) r2: Point,
+}
+
+impl PubNonce {
+    /// Parses two compressed points
+    ///
+    /// Returns `Error::InvalidNonce` if any of them is invalid.
+    pub fn from_bytes(bytes: &[u8; 66]) -> Result<Self, Error> {
+        let r1 = Point::from_sec1_bytes(&bytes[..33]).map_err(|_| Error::InvalidNonce)?;
+        let r2 = Point::from_sec1_bytes(&bytes[33..]).map_err(|_| Error::InvalidNonce)?;
+        Ok(PubNonce { r1, r2 })
+    }
+
+    pub fn serialize(&self) -> [u8; 66] {
+        serialize_points(self.r1, self.r2)
+    }
+}
+
+impl fmt::Debug for PubNonce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PubNonce(")?;
+        write_hex(f, &self.serialize())?;
+        f.write_str(")")
+    }
+}
+
+/// Sum of the public nonces of all signers
+///
+/// Unlike `PubNonce` the points may be at infinity, in which case they are encoded as 33 zero
+/// bytes.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct AggNonce {
+    pub(super) r1: Point,
+    pub(super) r2: Point,
+}
+
+impl AggNonce {
+    pub fn aggregate(nonces: &[PubNonce]) -> Self {
+        nonces.iter().fold(AggNonce { r1: Point::AT_INFINITY, r2: Point::AT_INFINITY }, |acc, nonce| AggNonce {
+            r1: acc.r1 + nonce.r1,
+            r2: acc.r2 + nonce.r2,
+        })
+    }
+
+    /// Parses two compressed points or zero encodings of the point at infinity
+    ///
+    /// Returns `Error::InvalidNonce` if any of them is invalid.
+    pub fn from_bytes(bytes: &[u8; 66]) -> Result<Self, Error> {
+        let parse = |bytes: &[u8]| if bytes.iter().all(|byte| *byte == 0) {
+            Ok(Point::AT_INFINITY)
+        } else {
+            Point::from_sec1_bytes(bytes).map_err(|_| Error::InvalidNonce)
+        };
+        Ok(AggNonce { r1: parse(&bytes[..33])?, r2: parse(&bytes[33..])? })
+    }
+
+    pub fn serialize(&self) -> [u8; 66] {
+        serialize_points(self.r1, self.r2)
+    }
+}
+
+impl fmt::Debug for AggNonce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AggNonce(")?;
+        write_hex(f, &self.serialize())?;
+        f.write_str(")")
+    }
+}
+
+fn serialize_points(r1: Point, r2: Point) -> [u8; 66] {
+    let mut bytes = [0; 66];
+    bytes[..33].copy_from_slice(&r1.serialize_compressed());
+    bytes[33..].copy_from_slice(&r2.serialize_compressed());
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AggNonce, PubNonce, SecNonce};
+    use crate::test_util::hex;
+    use crate::{Error, PublicKey, SecretKey, XOnlyPoint};
+    use core::convert::TryInto;
+
+    fn hex66(s: &str) -> [u8; 66] {
+        hex(s).try_into().expect("expected 66 bytes")
+    }
+
+    fn check_secnonce(nonce: &SecNonce, expected: &str) {
+        let expected = hex(expected);
+        assert_eq!(nonce.k1.to_be_bytes()[..], expected[..32]);
+        assert_eq!(nonce.k2.to_be_bytes()[..], expected[32..64]);
+        assert_eq!(nonce.public_key.serialize()[..], expected[64..]);
+    }
+
+    #[test]
+    fn bip327_nonce_gen() {
+        let rand = [0x0F; 32];
+        let secret_key = SecretKey::from_bytes(&[0x02; 32]).unwrap();
+        let public_key = PublicKey::from_sec1_bytes(&hex("024D4B6CD1361032CA9BD2AEB9D900AA4D45D9EAD80AC9423374C451A7254D0766")).unwrap();
+        assert_eq!(secret_key.public_key(), public_key);
+        let aggregate_key = XOnlyPoint::from_bytes(&[0x07; 32]).unwrap();
+        let nonce = SecNonce::generate(&rand, Some(&secret_key), &public_key, Some(&aggregate_key), Some(&[0x01; 32]), &[0x08; 32]);
+        check_secnonce(&nonce, "B114E502BEAA4E301DD08A50264172C84E41650E6CB726B410C0694D59EFFB6495B5CAF28D045B973D63E3C99A44B807BDE375FD6CB39E46DC4A511708D0E9D2024D4B6CD1361032CA9BD2AEB9D900AA4D45D9EAD80AC9423374C451A7254D0766");
+        assert_eq!(nonce.public_nonce().serialize(), hex66("02F7BE7089E8376EB355272368766B17E88E7DB72047D05E56AA881EA52B3B35DF02C29C8046FDD0DED4C7E55869137200FBDBFE2EB654267B6D7013602CAED3115A"));
+
+        // all optional inputs missing
+        let public_key = PublicKey::from_sec1_bytes(&hex("02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9")).unwrap();
+        let nonce = SecNonce::generate(&rand, None, &public_key, None, None, &[]);
+        check_secnonce(&nonce, "89BDD787D0284E5E4D5FC572E49E316BAB7E21E3B1830DE37DFE80156FA41A6D0B17AE8D024C53679699A6FD7944D9C4A366B514BAF43088E0708B1023DD289702F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9");
+        assert_eq!(nonce.public_nonce().serialize(), hex66("02C96E7CB1E8AA5DAC64D872947914198F607D90ECDE5200DE52978AD5DED63C000299EC5117C2D29EDEE8A2092587C3909BE694D5CFF0667D6C02EA4059F7CD9786"));
+    }
+
+    #[test]
+    fn empty_message_differs_from_missing() {
+        let public_key = SecretKey::from_bytes(&[0x02; 32]).unwrap().public_key();
+        let missing = SecNonce::generate(&[0; 32], None, &public_key, None, None, &[]);
+        let empty = SecNonce::generate(&[0; 32], None, &public_key, None, Some(&[]), &[]);
+        assert_ne!(missing.public_nonce(), empty.public_nonce());
+    }
+
+    const PUBNONCES: [&str; 7] = [
+        "020151C80F435648DF67A22B749CD798CE54E0321D034B92B709B567D60A42E66603BA47FBC1834437B3212E89A84D8425E7BF12E0245D98262268EBDCB385D50641",
+        "03FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A60248C264CDD57D3C24D79990B0F865674EB62A0F9018277A95011B41BFC193B833",
+        "020151C80F435648DF67A22B749CD798CE54E0321D034B92B709B567D60A42E6660279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+        "03FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A60379BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+        "04FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A60248C264CDD57D3C24D79990B0F865674EB62A0F9018277A95011B41BFC193B833",
+        "03FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A60248C264CDD57D3C24D79990B0F865674EB62A0F9018277A95011B41BFC193B831",
+        "03FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A602FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30",
+    ];
+
+    #[test]
+    fn bip327_nonce_agg() {
+        let vectors = [
+            ([0, 1], "035FE1873B4F2967F52FEA4A06AD5A8ECCBE9D0FD73068012C894E2E87CCB5804B024725377345BDE0E9C33AF3C43C0A29A9249F2F2956FA8CFEB55C8573D0262DC8"),
+            // the second points sum to infinity
+            ([2, 3], "035FE1873B4F2967F52FEA4A06AD5A8ECCBE9D0FD73068012C894E2E87CCB5804B000000000000000000000000000000000000000000000000000000000000000000"),
+        ];
+        for (indices, expected) in &vectors {
+            let nonces = indices.iter().map(|i| PubNonce::from_bytes(&hex66(PUBNONCES[*i])).unwrap()).collect::<Vec<_>>();
+            let aggregate = AggNonce::aggregate(&nonces);
+            assert_eq!(aggregate.serialize(), hex66(expected), "nonces {:?}", indices);
+            assert_eq!(AggNonce::from_bytes(&aggregate.serialize()), Ok(aggregate));
+        }
+    }
+
+    #[test]
+    fn bip327_nonce_agg_invalid() {
+        // invalid prefix, x not on the curve, x not less than p
+        for i in &[4, 5, 6] {
+            assert_eq!(PubNonce::from_bytes(&hex66(PUBNONCES[*i])), Err(Error::InvalidNonce), "nonce {}", i);
+        }
+        assert_eq!(PubNonce::from_bytes(&[0; 66]), Err(Error::InvalidNonce));
+        let mut bytes = [0; 66];
+        bytes[..33].copy_from_slice(&hex(&PUBNONCES[0][..66]));
+        assert_eq!(AggNonce::from_bytes(&bytes).map(|nonce| nonce.serialize()), Ok(bytes));
+    }
+}
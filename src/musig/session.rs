@@ -0,0 +1,464 @@
+use core::fmt;
+use super::{AggNonce, KeyAggContext, PubNonce, SecNonce};
+use crate::schnorr::challenge;
+use crate::tagged_hash::{TaggedHash, MUSIG_NONCE_COEFFICIENT};
+use crate::{write_hex, Error, Keypair, Parity, PublicKey, Scalar, XOnlyPoint, G};
+
+/// Signing session - everything the signers need to know once the nonces are exchanged
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Session {
+    key_agg: KeyAggContext,
+    /// nonce coefficient
+    b: Scalar,
+    /// final nonce with even y coordinate and the parity of the original point
+    r: XOnlyPoint,
+    r_parity: Parity,
+    /// challenge
+    e: Scalar,
+}
+
+impl Session {
+    /// Computes the final nonce and the challenge for signing `msg`.
+    pub fn new(key_agg: &KeyAggContext, agg_nonce: &AggNonce, msg: &[u8]) -> Self {
+        let q = key_agg.x_only_public_key().0;
+        let hash = TaggedHash::new(MUSIG_NONCE_COEFFICIENT).hash(&[&agg_nonce.serialize(), &q.serialize(), msg]);
+        let b = Scalar::from_be_bytes_reduce(&hash);
+        let r = agg_nonce.r1 + agg_nonce.r2 * b;
+        // the point at infinity can only be created by a malicious signer, replacing it with
+        // G allows honest signers to finish and identify the culprit
+        let r = if r.is_at_infinity() { G } else { r };
+        let (r, r_parity) = XOnlyPoint::from_point(r).expect("r is not at infinity");
+        let e = challenge(&r.serialize(), &q, msg);
+        Session {
+            key_agg: key_agg.clone(),
+            b,
+            r,
+            r_parity,
+            e,
+        }
+    }
+
+    /// Returns `-1` if the aggregate key has odd y coordinate, `1` otherwise.
+    fn key_sign(&self) -> Scalar {
+        match self.key_agg.x_only_public_key().1 {
+            Parity::Even => Scalar::ONE,
+            Parity::Odd => -Scalar::ONE,
+        }
+    }
+
+    /// Applies the nonce coefficient and the parity of the final nonce to a pair of nonces
+    fn effective_nonce<T: core::ops::Add<Output = T> + core::ops::Mul<Scalar, Output = T> + core::ops::Neg<Output = T>>(&self, first: T, second: T) -> T {
+        let nonce = first + second * self.b;
+        match self.r_parity {
+            Parity::Even => nonce,
+            Parity::Odd => -nonce,
+        }
+    }
+}
+
+/// Signature share of one signer
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct PartialSig(Scalar);
+
+impl PartialSig {
+    /// Returns `Error::InvalidSignature` if the value is not less than the curve order
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, Error> {
+        Scalar::from_be_bytes(bytes).map(PartialSig).ok_or(Error::InvalidSignature)
+    }
+
+    pub fn serialize(&self) -> [u8; 32] {
+        self.0.to_be_bytes()
+    }
+}
+
+impl fmt::Debug for PartialSig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PartialSig(")?;
+        write_hex(f, &self.serialize())?;
+        f.write_str(")")
+    }
+}
+
+/// Creates the signature share of the signer, consuming the nonce.
+///
+/// Returns `Error::InvalidNonce` if the nonce was generated for a different key and
+/// `Error::InvalidPublicKey` if the key of the signer wasn't aggregated.
+pub fn partial_sign(session: &Session, nonce: SecNonce, keypair: &Keypair) -> Result<PartialSig, Error> {
+    if nonce.public_key != *keypair.public_key() {
+        return Err(Error::InvalidNonce);
+    }
+    let a = session.key_agg.key_agg_coefficient(keypair.public_key()).ok_or(Error::InvalidPublicKey)?;
+    let d = session.key_sign() * session.key_agg.gacc * keypair.secret_key().to_scalar();
+    let k = session.effective_nonce(nonce.k1, nonce.k2);
+    Ok(PartialSig(k + session.e * a * d))
+}
+
+/// Creates the nonce and the signature share of a signer that doesn't keep any state between
+/// the rounds (BIP327 `DeterministicSign`).
+///
+/// The nonce is derived from the secret key, `agg_other_nonce` - the aggregate of the nonces of
+/// **all other** signers - the aggregate key and the message, optionally masked by `rand`.
+///
+/// **This is only safe for the last signer**: the other nonces must be final before this is
+/// called. If another signer could still choose its nonce after seeing the returned one, it
+/// could make the session differ from the one the nonce was derived for and obtain two shares
+/// with the same nonce, revealing the secret key. Signing with the same inputs repeatedly is
+/// fine, it produces the same output.
+///
+/// Returns the same errors as `partial_sign`.
+pub fn deterministic_sign(keypair: &Keypair, agg_other_nonce: &AggNonce, key_agg: &KeyAggContext, msg: &[u8], rand: Option<&[u8; 32]>) -> Result<(PubNonce, PartialSig), Error> {
+    let aggregate_key = key_agg.x_only_public_key().0;
+    let nonce = SecNonce::deterministic(keypair.secret_key(), agg_other_nonce, &aggregate_key, msg, rand);
+    let public_nonce = nonce.public_nonce();
+    let agg_nonce = AggNonce {
+        r1: agg_other_nonce.r1 + public_nonce.r1,
+        r2: agg_other_nonce.r2 + public_nonce.r2,
+    };
+    let session = Session::new(key_agg, &agg_nonce, msg);
+    let signature = partial_sign(&session, nonce, keypair)?;
+    Ok((public_nonce, signature))
+}
+
+/// Checks the signature share of the signer with the given nonce and key.
+pub fn partial_verify(session: &Session, nonce: &PubNonce, public_key: &PublicKey, signature: &PartialSig) -> bool {
+    let a = match session.key_agg.key_agg_coefficient(public_key) {
+        Some(a) => a,
+        None => return false,
+    };
+    let g = session.key_sign() * session.key_agg.gacc;
+    let r = session.effective_nonce(nonce.r1, nonce.r2);
+    G * signature.0 == r + public_key.to_point() * (session.e * a * g)
+}
+
+/// Sums the signature shares into a BIP340 signature valid under the aggregate key.
+///
+/// The shares should be verified first, otherwise the result may be invalid.
+pub fn aggregate_partials(session: &Session, signatures: &[PartialSig]) -> [u8; 64] {
+    let s = signatures.iter().fold(Scalar::ZERO, |acc, signature| acc + signature.0);
+    let s = s + session.e * session.key_sign() * session.key_agg.tacc;
+    let mut bytes = [0; 64];
+    bytes[..32].copy_from_slice(&session.r.serialize());
+    bytes[32..].copy_from_slice(&s.to_be_bytes());
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{aggregate_partials, deterministic_sign, partial_sign, partial_verify, PartialSig, Session};
+    use crate::musig::{AggNonce, KeyAggContext, PubNonce, SecNonce};
+    use crate::schnorr::verify;
+    use crate::test_util::{hex, hex32};
+    use crate::{Error, Keypair, Message, PublicKey, Scalar, SecretKey};
+    use core::convert::TryInto;
+
+    fn hex66(s: &str) -> [u8; 66] {
+        hex(s).try_into().expect("expected 66 bytes")
+    }
+
+    fn keys(pubkeys: &[&str], indices: &[usize]) -> Result<Vec<PublicKey>, Error> {
+        indices.iter().map(|i| PublicKey::from_sec1_bytes(&hex(pubkeys[*i]))).collect()
+    }
+
+    fn secnonce(bytes: &str) -> SecNonce {
+        let bytes = hex(bytes);
+        SecNonce {
+            k1: Scalar::from_be_bytes(&bytes[..32].try_into().unwrap()).unwrap(),
+            k2: Scalar::from_be_bytes(&bytes[32..64].try_into().unwrap()).unwrap(),
+            public_key: PublicKey::from_sec1_bytes(&bytes[64..]).unwrap(),
+        }
+    }
+
+    fn keypair() -> Keypair {
+        Keypair::from_secret_key(&SecretKey::from_bytes(&hex32("7FB9E0E687ADA1EEBF7ECFE2F21E73EBDB51A7D450948DFE8D76D7F2D1007671")).unwrap())
+    }
+
+    /// Vectors from the BIP327 sign/verify test vectors, the key at index 3 and the nonce at
+    /// index 4 are invalid
+    const PUBKEYS: [&str; 4] = [
+        "03935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9",
+        "02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+        "02DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA661",
+        "020000000000000000000000000000000000000000000000000000000000000007",
+    ];
+    const SECNONCE: &str = "508B81A611F100A6B2B6B29656590898AF488BCF2E1F55CF22E5CFB84421FE61FA27FD49B1D50085B481285E1CA205D55C82CC1B31FF5CD54A489829355901F703935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9";
+    const PUBNONCES: [&str; 5] = [
+        "0337C87821AFD50A8644D820A8F3E02E499C931865C2360FB43D0A0D20DAFE07EA0287BF891D2A6DEAEBADC909352AA9405D1428C15F4B75F04DAE642A95C2548480",
+        "0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F817980279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+        "032DE2662628C90B03F5E720284EB52FF7D71F4284F627B68A853D78C78E1FFE9303E4C5524E83FFE1493B9077CF1CA6BEB2090C93D930321071AD40B2F44E599046",
+        "0237C87821AFD50A8644D820A8F3E02E499C931865C2360FB43D0A0D20DAFE07EA0387BF891D2A6DEAEBADC909352AA9405D1428C15F4B75F04DAE642A95C2548480",
+        "0200000000000000000000000000000000000000000000000000000000000000090287BF891D2A6DEAEBADC909352AA9405D1428C15F4B75F04DAE642A95C2548480",
+    ];
+    const AGGNONCES: [&str; 5] = [
+        "028465FCF0BBDBCF443AABCCE533D42B4B5A10966AC09A49655E8C42DAAB8FCD61037496A3CC86926D452CAFCFD55D25972CA1675D549310DE296BFF42F72EEEA8C9",
+        "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+        "048465FCF0BBDBCF443AABCCE533D42B4B5A10966AC09A49655E8C42DAAB8FCD61037496A3CC86926D452CAFCFD55D25972CA1675D549310DE296BFF42F72EEEA8C9",
+        "028465FCF0BBDBCF443AABCCE533D42B4B5A10966AC09A49655E8C42DAAB8FCD61020000000000000000000000000000000000000000000000000000000000000009",
+        "028465FCF0BBDBCF443AABCCE533D42B4B5A10966AC09A49655E8C42DAAB8FCD6102FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30",
+    ];
+    const MSG: &str = "F95466D086770E689964664219266FE5ED215C92AE20BAB5C9D79ADDDDF3C0CF";
+
+    fn pubnonce(i: usize) -> Result<PubNonce, Error> {
+        PubNonce::from_bytes(&hex66(PUBNONCES[i]))
+    }
+
+    #[test]
+    fn bip327_sign_verify() {
+        let keypair = keypair();
+        assert_eq!(secnonce(SECNONCE).public_nonce(), pubnonce(0).unwrap());
+        let vectors: [(&[usize], &[usize], usize, &str); 4] = [
+            (&[0, 1, 2], &[0, 1, 2], 0, "012ABBCB52B3016AC03AD82395A1A415C48B93DEF78718E62A7A90052FE224FB"),
+            (&[1, 0, 2], &[1, 0, 2], 0, "9FF2F7AAA856150CC8819254218D3ADEEB0535269051897724F9DB3789513A52"),
+            (&[1, 2, 0], &[1, 2, 0], 0, "FA23C359F6FAC4E7796BB93BC9F0532A95468C539BA20FF86D7C76ED92227900"),
+            // both halves of the aggregate nonce are at infinity
+            (&[0, 1], &[0, 3], 1, "AE386064B26105404798F75DE2EB9AF5EDA5387B064B83D049CB7C5E08879531"),
+        ];
+        for (key_indices, nonce_indices, agg_nonce, expected) in &vectors {
+            let key_agg = KeyAggContext::new(&keys(&PUBKEYS, key_indices).unwrap()).unwrap();
+            let nonces = nonce_indices.iter().map(|i| pubnonce(*i).unwrap()).collect::<Vec<_>>();
+            let agg_nonce = AggNonce::from_bytes(&hex66(AGGNONCES[*agg_nonce])).unwrap();
+            assert_eq!(AggNonce::aggregate(&nonces), agg_nonce);
+            let session = Session::new(&key_agg, &agg_nonce, &hex(MSG));
+            let signature = partial_sign(&session, secnonce(SECNONCE), &keypair).unwrap();
+            assert_eq!(signature.serialize(), hex32(expected), "keys {:?}", key_indices);
+            assert!(partial_verify(&session, &pubnonce(0).unwrap(), keypair.public_key(), &signature));
+        }
+    }
+
+    #[test]
+    fn bip327_sign_errors() {
+        let keypair = keypair();
+        let agg_nonce = AggNonce::from_bytes(&hex66(AGGNONCES[0])).unwrap();
+        // the signer's key is not among the aggregated keys
+        let key_agg = KeyAggContext::new(&keys(&PUBKEYS, &[1, 2]).unwrap()).unwrap();
+        let session = Session::new(&key_agg, &agg_nonce, &hex(MSG));
+        assert_eq!(partial_sign(&session, secnonce(SECNONCE), &keypair), Err(Error::InvalidPublicKey));
+        assert_eq!(keys(&PUBKEYS, &[1, 0, 3]), Err(Error::NotOnCurve));
+        for agg_nonce in &AGGNONCES[2..] {
+            assert_eq!(AggNonce::from_bytes(&hex66(agg_nonce)), Err(Error::InvalidNonce), "aggregate nonce {}", agg_nonce);
+        }
+        // the nonce belongs to another signer
+        let key_agg = KeyAggContext::new(&keys(&PUBKEYS, &[0, 1, 2]).unwrap()).unwrap();
+        let session = Session::new(&key_agg, &agg_nonce, &hex(MSG));
+        let other = Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap());
+        assert_eq!(partial_sign(&session, secnonce(SECNONCE), &other), Err(Error::InvalidNonce));
+    }
+
+    #[test]
+    fn bip327_verify_fail() {
+        let key_agg = KeyAggContext::new(&keys(&PUBKEYS, &[0, 1, 2]).unwrap()).unwrap();
+        let agg_nonce = AggNonce::from_bytes(&hex66(AGGNONCES[0])).unwrap();
+        let session = Session::new(&key_agg, &agg_nonce, &hex(MSG));
+        let public_key = |i: usize| PublicKey::from_sec1_bytes(&hex(PUBKEYS[i])).unwrap();
+
+        let wrong = PartialSig::from_bytes(&hex32("97AC833ADCB1AFA42EBF9E0725616F3C9A0D5B614F6FE283CEAAA37A8FFAF406")).unwrap();
+        assert!(!partial_verify(&session, &pubnonce(0).unwrap(), &public_key(0), &wrong));
+        // valid signature of the wrong signer
+        let other = PartialSig::from_bytes(&hex32("68537CC5234E505BD14061F8DA9E90C220A181855FD8BDB7F127BB12403B4D3B")).unwrap();
+        assert!(!partial_verify(&session, &pubnonce(1).unwrap(), &public_key(1), &other));
+        assert_eq!(PartialSig::from_bytes(&hex32("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141")), Err(Error::InvalidSignature));
+        assert_eq!(pubnonce(4), Err(Error::InvalidNonce));
+    }
+
+    #[test]
+    fn bip327_tweaks() {
+        let pubkeys = [
+            "03935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9",
+            "02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+            "02DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+        ];
+        let tweaks = [
+            "E8F791FF9225A2AF0102AFFF4A9A723D9612A682A25EBE79802B263CDFCD83BB",
+            "AE2EA797CC0FE72AC5B97B97F3C6957D7E4199A167A58EB08BCAFFDA70AC0455",
+            "F52ECBC565B3D8BEA2DFD5B75A4F457E54369809322E4120831626F290FA87E0",
+            "1969AD73CC177FA0B4FCED6DF1F7BF9907E665FDE9BA196A74FED0A3CF5AEF9D",
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+        ];
+        let vectors: [(&[usize], &[bool], &str); 5] = [
+            (&[0], &[true], "E28A5C66E61E178C2BA19DB77B6CF9F7E2F0F56C17918CD13135E60CC848FE91"),
+            (&[0], &[false], "38B0767798252F21BF5702C48028B095428320F73A4B14DB1E25DE58543D2D2D"),
+            (&[0, 1], &[false, true], "408A0A21C4A0F5DACAF9646AD6EB6FECD7F7A11F03ED1F48DFFF2185BC2C2408"),
+            (&[0, 1, 2, 3], &[false, false, true, true], "45ABD206E61E3DF2EC9E264A6FEC8292141A633C28586388235541F9ADE75435"),
+            (&[0, 1, 2, 3], &[true, false, true, false], "B255FDCAC27B40C7CE7848E2D3B7BF5EA0ED756DA81565AC804CCCA3E1D5D239"),
+        ];
+        let keypair = keypair();
+        let key_agg = KeyAggContext::new(&keys(&pubkeys, &[1, 2, 0]).unwrap()).unwrap();
+        let agg_nonce = AggNonce::from_bytes(&hex66(AGGNONCES[0])).unwrap();
+        for (tweak_indices, is_xonly, expected) in &vectors {
+            let key_agg = tweak_indices.iter().zip(is_xonly.iter()).fold(key_agg.clone(), |key_agg, (i, is_xonly)| {
+                key_agg.with_tweak(&hex32(tweaks[*i]), *is_xonly).unwrap()
+            });
+            let session = Session::new(&key_agg, &agg_nonce, &hex(MSG));
+            let signature = partial_sign(&session, secnonce(SECNONCE), &keypair).unwrap();
+            assert_eq!(signature.serialize(), hex32(expected), "tweaks {:?}", tweak_indices);
+            assert!(partial_verify(&session, &pubnonce(0).unwrap(), keypair.public_key(), &signature));
+        }
+        assert_eq!(key_agg.with_tweak(&hex32(tweaks[4]), false), Err(Error::InvalidTweak));
+    }
+
+    #[test]
+    fn bip327_sig_agg() {
+        let pubkeys = [
+            "03935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9",
+            "02D2DC6F5DF7C56ACF38C7FA0AE7A759AE30E19B37359DFDE015872324C7EF6E05",
+            "03C7FB101D97FF930ACD0C6760852EF64E69083DE0B06AC6335724754BB4B0522C",
+            "02352433B21E7E05D3B452B81CAE566E06D2E003ECE16D1074AABA4289E0E3D581",
+        ];
+        let tweaks = [
+            "B511DA492182A91B0FFB9A98020D55F260AE86D7ECBD0399C7383D59A5F2AF7C",
+            "A815FE049EE3C5AAB66310477FBC8BCCCAC2F3395F59F921C364ACD78A2F48DC",
+            "75448A87274B056468B977BE06EB1E9F657577B7320B0A3376EA51FD420D18A8",
+        ];
+        let psigs = [
+            "B15D2CD3C3D22B04DAE438CE653F6B4ECF042F42CFDED7C41B64AAF9B4AF53FB",
+            "6193D6AC61B354E9105BBDC8937A3454A6D705B6D57322A5A472A02CE99FCB64",
+            "9A87D3B79EC67228CB97878B76049B15DBD05B8158D17B5B9114D3C226887505",
+            "66F82EA90923689B855D36C6B7E032FB9970301481B99E01CDB4D6AC7C347A15",
+            "4F5AEE41510848A6447DCD1BBC78457EF69024944C87F40250D3EF2C25D33EFE",
+            "DDEF427BBB847CC027BEFF4EDB01038148917832253EBC355FC33F4A8E2FCCE4",
+            "97B890A26C981DA8102D3BC294159D171D72810FDF7C6A691DEF02F0F7AF3FDC",
+            "53FA9E08BA5243CBCB0D797C5EE83BC6728E539EB76C2D0BF0F971EE4E909971",
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+        ];
+        let msg = hex32("599C67EA410D005B9DA90817CF03ED3B1C868E4DA4EDF00A5880B0082C237869");
+        // keys, tweaks, tweak types, aggregate nonce, partial signatures, expected signature
+        type Vector = (&'static [usize], &'static [usize], &'static [bool], &'static str, &'static [usize], &'static str);
+        let vectors: [Vector; 4] = [
+            (
+                &[0, 1], &[], &[],
+                "0341432722C5CD0268D829C702CF0D1CBCE57033EED201FD335191385227C3210C03D377F2D258B64AADC0E16F26462323D701D286046A2EA93365656AFD9875982B",
+                &[0, 1],
+                "041DA22223CE65C92C9A0D6C2CAC828AAF1EEE56304FEC371DDF91EBB2B9EF0912F1038025857FEDEB3FF696F8B99FA4BB2C5812F6095A2E0004EC99CE18DE1E",
+            ),
+            (
+                &[0, 2], &[], &[],
+                "0224AFD36C902084058B51B5D36676BBA4DC97C775873768E58822F87FE437D792028CB15929099EEE2F5DAE404CD39357591BA32E9AF4E162B8D3E7CB5EFE31CB20",
+                &[2, 3],
+                "1069B67EC3D2F3C7C08291ACCB17A9C9B8F2819A52EB5DF8726E17E7D6B52E9F01800260A7E9DAC450F4BE522DE4CE12BA91AEAF2B4279219EF74BE1D286ADD9",
+            ),
+            (
+                &[0, 2], &[0], &[false],
+                "0208C5C438C710F4F96A61E9FF3C37758814B8C3AE12BFEA0ED2C87FF6954FF186020B1816EA104B4FCA2D304D733E0E19CEAD51303FF6420BFD222335CAA402916D",
+                &[4, 5],
+                "5C558E1DCADE86DA0B2F02626A512E30A22CF5255CAEA7EE32C38E9A71A0E9148BA6C0E6EC7683B64220F0298696F1B878CD47B107B81F7188812D593971E0CC",
+            ),
+            (
+                &[0, 3], &[0, 1, 2], &[true, false, true],
+                "02B5AD07AFCD99B6D92CB433FBD2A28FDEB98EAE2EB09B6014EF0F8197CD58403302E8616910F9293CF692C49F351DB86B25E352901F0E237BAFDA11F1C1CEF29FFD",
+                &[6, 7],
+                "839B08820B681DBA8DAF4CC7B104E8F2638F9388F8D7A555DC17B6E6971D7426CE07BF6AB01F1DB50E4E33719295F4094572B79868E440FB3DEFD3FAC1DB589E",
+            ),
+        ];
+        for (key_indices, tweak_indices, is_xonly, agg_nonce, psig_indices, expected) in &vectors {
+            let key_agg = KeyAggContext::new(&keys(&pubkeys, key_indices).unwrap()).unwrap();
+            let key_agg = tweak_indices.iter().zip(is_xonly.iter()).fold(key_agg, |key_agg, (i, is_xonly)| {
+                key_agg.with_tweak(&hex32(tweaks[*i]), *is_xonly).unwrap()
+            });
+            let session = Session::new(&key_agg, &AggNonce::from_bytes(&hex66(agg_nonce)).unwrap(), &msg);
+            let signatures = psig_indices.iter().map(|i| PartialSig::from_bytes(&hex32(psigs[*i])).unwrap()).collect::<Vec<_>>();
+            let signature = aggregate_partials(&session, &signatures);
+            assert_eq!(signature[..], hex(expected)[..], "keys {:?}", key_indices);
+            assert_eq!(verify(&Message::from_digest(msg), &signature, &key_agg.x_only_public_key().0), Ok(()));
+        }
+        assert_eq!(PartialSig::from_bytes(&hex32(psigs[8])), Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn deterministic_sign_vectors() {
+        // Produced by an independent implementation of BIP327 `DeterministicSign` since the
+        // vectors from the BIP aren't available here. The signer is the key at index 0 of
+        // `PUBKEYS`, the other nonce is `PUBNONCES[0]`.
+        let tweak = "E8F791FF9225A2AF0102AFFF4A9A723D9612A682A25EBE79802B263CDFCD83BB";
+        type Vector = (&'static [usize], Option<bool>, &'static str, Option<[u8; 32]>, &'static str, &'static str);
+        let vectors: [Vector; 7] = [
+            (&[0, 1, 2], None, MSG, Some([0; 32]), "03BB10EAA2003EC427E26A2A978BEE6C636C8AA8FA23191C67EF75B612B2E5AAEE03DA76509344D723B6A04F1B7D078387B39BFFF74FC2B18867CE86FA2A34C5D1E3", "E586B6FB2268B2F3B5007149C7F6670748ADBE5B1E97473F70E6C0E7418C8680"),
+            (&[1, 0, 2], None, MSG, None, "0280AE0C4B5059DB4D3EA132C58A3CA7438066CAE768C03C684841677A1E68246702E499A7DB9778CB7123BD28CA1165D6AC12CC2BDAE3CB4F220B1A8A568AA6D9B9", "BA15CD0E038E79DD6B3E2DD8104F0522061444FA1761F1AA6BEB8B958F210648"),
+            (&[0, 1, 2], None, MSG, Some([1; 32]), "0263922ADA17515B29A59E9B3FE6AC3B22A94BAC7278794CB9CD592A25B5F2E76F02CABB2D897A0B94841534FAB0FF8A2014334AA7AA58CC9E6EC61DEC0FC39AB689", "C43CBAD5151C41CD7AD7FB2D6E4014FF8E6CC5DB27EA52BE005AD21AA235040D"),
+            (&[0, 1, 2], None, "", Some([0; 32]), "03C8495B6BB8D0CD585D4C35CA68477682C635290E55D608A2BDE2D760D3C475C5030FF55ED1D67852931FEC64821C0FF19C147C11573742418028DB5F9EB44F7550", "40CB8E6655656DE2EC95721AB81E2204FEC7A67E795ADE026F937BE320DA37E7"),
+            (&[0, 1, 2], None, "2626262626262626262626262626262626262626262626262626262626262626262626262626", Some([0; 32]), "03C340CDE6E32B28C8A250EB2A2D724F90F3F62DFFAE2B04654F88AE28AC8AB47D036B734EF4F43B5F85EA15D53D26B3BCA6D4E7F9BE3F60018D17B9D698842EC180", "E283162D72E7415719C181508A51B2711D8BE7091C30299B81D2A7D86D0FD38C"),
+            (&[0, 1, 2], Some(true), MSG, None, "03A3DF01299F879EBF998B29C6DEA07A060D4E770943E3A8C5BC9448BA941D301D0249A75E07F8DD89921D6EA36857FFDECB4DD4DFB2C0D67FD44347101EB31E4ADD", "5C62FCFEF1461650C579895C3D5414E6FCC7F8AE07FC682E6678C560C8A076E8"),
+            (&[0, 1, 2], Some(false), MSG, Some([0; 32]), "02673B94A032C7E02F8B7627BEA89956F2DB8CA7F0ED3F9ACFB369C1EF0B98D9FC0376E948480721946243876482DBDEA8FA92093D2AD7915EB94B1E96C860F4D8EC", "E62F7CEB335C479594966D4B8E1835DC6ABC71B31839215400A558CAFDF06CF4"),
+        ];
+        let keypair = keypair();
+        let agg_other_nonce = AggNonce::aggregate(&[pubnonce(0).unwrap()]);
+        for (key_indices, is_xonly, msg, rand, expected_nonce, expected_signature) in &vectors {
+            let key_agg = KeyAggContext::new(&keys(&PUBKEYS, key_indices).unwrap()).unwrap();
+            let key_agg = match is_xonly {
+                Some(is_xonly) => key_agg.with_tweak(&hex32(tweak), *is_xonly).unwrap(),
+                None => key_agg,
+            };
+            let (nonce, signature) = deterministic_sign(&keypair, &agg_other_nonce, &key_agg, &hex(msg), rand.as_ref()).unwrap();
+            assert_eq!(nonce.serialize(), hex66(expected_nonce), "keys {:?}, rand {:?}", key_indices, rand);
+            assert_eq!(signature.serialize(), hex32(expected_signature), "keys {:?}, rand {:?}", key_indices, rand);
+
+            let session = Session::new(&key_agg, &AggNonce::aggregate(&[pubnonce(0).unwrap(), nonce]), &hex(msg));
+            assert!(partial_verify(&session, &nonce, keypair.public_key(), &signature));
+        }
+
+        let key_agg = KeyAggContext::new(&keys(&PUBKEYS, &[1, 2]).unwrap()).unwrap();
+        assert_eq!(deterministic_sign(&keypair, &agg_other_nonce, &key_agg, &hex(MSG), None), Err(Error::InvalidPublicKey));
+    }
+
+    #[test]
+    fn deterministic_last_signer() {
+        let keypairs = [1u8, 2, 3].iter().map(|i| Keypair::from_secret_key(&SecretKey::from_bytes(&[*i; 32]).unwrap())).collect::<Vec<_>>();
+        let pubkeys = keypairs.iter().map(|keypair| *keypair.public_key()).collect::<Vec<_>>();
+        let key_agg = KeyAggContext::new(&pubkeys).unwrap().with_tweak(&[0x55; 32], true).unwrap();
+        let aggregate_key = key_agg.x_only_public_key().0;
+        let msg = [0x44; 32];
+
+        let nonces = keypairs[..2]
+            .iter()
+            .map(|keypair| SecNonce::generate(&[0x0F; 32], Some(keypair.secret_key()), keypair.public_key(), Some(&aggregate_key), Some(&msg), &[]))
+            .collect::<Vec<_>>();
+        let mut public_nonces = nonces.iter().map(SecNonce::public_nonce).collect::<Vec<_>>();
+        let agg_other_nonce = AggNonce::aggregate(&public_nonces);
+        let (last_nonce, last_signature) = deterministic_sign(&keypairs[2], &agg_other_nonce, &key_agg, &msg, Some(&[0x99; 32])).unwrap();
+        // stateless - the same inputs give the same output
+        assert_eq!(deterministic_sign(&keypairs[2], &agg_other_nonce, &key_agg, &msg, Some(&[0x99; 32])), Ok((last_nonce, last_signature)));
+        let (other_nonce, _) = deterministic_sign(&keypairs[2], &AggNonce::aggregate(&public_nonces[..1]), &key_agg, &msg, Some(&[0x99; 32])).unwrap();
+        assert_ne!(other_nonce, last_nonce);
+
+        public_nonces.push(last_nonce);
+        let session = Session::new(&key_agg, &AggNonce::aggregate(&public_nonces), &msg);
+        let mut signatures = nonces
+            .into_iter()
+            .zip(&keypairs)
+            .map(|(nonce, keypair)| partial_sign(&session, nonce, keypair).unwrap())
+            .collect::<Vec<_>>();
+        signatures.push(last_signature);
+        for ((signature, nonce), keypair) in signatures.iter().zip(&public_nonces).zip(&keypairs) {
+            assert!(partial_verify(&session, nonce, keypair.public_key(), signature));
+        }
+        assert_eq!(verify(&Message::from_digest(msg), &aggregate_partials(&session, &signatures), &aggregate_key), Ok(()));
+    }
+
+    #[test]
+    fn three_signers() {
+        let keypairs = [1u8, 2, 3].iter().map(|i| Keypair::from_secret_key(&SecretKey::from_bytes(&[*i; 32]).unwrap())).collect::<Vec<_>>();
+        let mut pubkeys = keypairs.iter().map(|keypair| *keypair.public_key()).collect::<Vec<_>>();
+        PublicKey::sort_lexicographic(&mut pubkeys);
+        let untweaked = KeyAggContext::new(&pubkeys).unwrap();
+        let tweaked = untweaked.clone().with_tweak(&[0x55; 32], true).unwrap();
+        let msg = [0x33; 32];
+
+        for (i, key_agg) in [untweaked, tweaked].iter().enumerate() {
+            let aggregate_key = key_agg.x_only_public_key().0;
+            let nonces = keypairs
+                .iter()
+                .map(|keypair| SecNonce::generate(&[i as u8; 32], Some(keypair.secret_key()), keypair.public_key(), Some(&aggregate_key), Some(&msg), &[]))
+                .collect::<Vec<_>>();
+            let public_nonces = nonces.iter().map(SecNonce::public_nonce).collect::<Vec<_>>();
+            let session = Session::new(key_agg, &AggNonce::aggregate(&public_nonces), &msg);
+            let signatures = nonces
+                .into_iter()
+                .zip(&keypairs)
+                .map(|(nonce, keypair)| partial_sign(&session, nonce, keypair).unwrap())
+                .collect::<Vec<_>>();
+            for ((signature, nonce), keypair) in signatures.iter().zip(&public_nonces).zip(&keypairs) {
+                assert!(partial_verify(&session, nonce, keypair.public_key(), signature));
+            }
+            let signature = aggregate_partials(&session, &signatures);
+            assert_eq!(verify(&Message::from_digest(msg), &signature, &aggregate_key), Ok(()));
+        }
+    }
+}
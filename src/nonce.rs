@@ -0,0 +1,98 @@
+//! Nonce generation policies for signing
+
+use rand_core::{CryptoRng, RngCore};
+use crate::{Scalar, SecretKey};
+#[cfg(feature = "hashes")]
+use crate::rfc6979::Drbg;
+#[cfg(feature = "hashes")]
+use crate::tagged_hash::TaggedHash;
+#[cfg(feature = "hashes")]
+use crate::zeroize::Zeroizing;
+
+/// Source of secret signing nonces.
+///
+/// The signer calls `nonce` with `attempt` starting at zero and increments it each time the
+/// returned nonce produces an invalid signature. Implementations must never return the same
+/// nonce for different messages and the nonce must be unpredictable to anyone not knowing the
+/// secret key.
+pub trait NonceGenerator {
+    fn nonce(&mut self, msg32: &[u8; 32], secret_key: &SecretKey, attempt: u32) -> Scalar;
+}
+
+/// Deterministic nonces as specified by RFC 6979 (HMAC-SHA256)
+///
+/// This is the default used by `ecdsa::sign`.
+#[cfg(feature = "hashes")]
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Rfc6979 {
+    extra_data: Option<[u8; 32]>,
+}
+
+#[cfg(feature = "hashes")]
+impl Rfc6979 {
+    /// Plain RFC 6979 without additional data
+    pub fn new() -> Self {
+        Rfc6979 { extra_data: None }
+    }
+
+    /// Mixes additional data into the derivation (section 3.6 of the RFC).
+    ///
+    /// This is compatible with libsecp256k1 `noncedata` and can be used to add entropy or to
+    /// grind signatures.
+    pub fn with_extra_data(extra_data: [u8; 32]) -> Self {
+        Rfc6979 {
+            extra_data: Some(extra_data),
+        }
+    }
+}
+
+#[cfg(feature = "hashes")]
+impl NonceGenerator for Rfc6979 {
+    /// Attempt `n` returns the `n`-th valid candidate of the generator
+    fn nonce(&mut self, msg32: &[u8; 32], secret_key: &SecretKey, attempt: u32) -> Scalar {
+        let mut drbg = Drbg::new(msg32, secret_key, self.extra_data.as_ref());
+        for _ in 0..attempt {
+            drbg.next_nonce();
+        }
+        drbg.next_nonce()
+    }
+}
+
+/// Uniformly random nonces drawn from cryptographically secure RNG
+#[derive(Debug)]
+pub struct RngNonce<R>(pub R);
+
+impl<R: RngCore + CryptoRng> NonceGenerator for RngNonce<R> {
+    fn nonce(&mut self, _msg32: &[u8; 32], _secret_key: &SecretKey, _attempt: u32) -> Scalar {
+        Scalar::random(&mut self.0)
+    }
+}
+
+/// Derives a deterministic nonce for a zero-knowledge proof.
+///
+/// The transcript is hashed with the tagged hash of `domain` and the result is used as the
+/// message of the RFC 6979 generator keyed by `secret`. The transcript must contain the whole
+/// public statement so that proving different statements never reuses the nonce.
+#[cfg(feature = "hashes")]
+pub fn derive_proof_nonce(secret: &Scalar, transcript_bytes: &[u8], domain: &str) -> Scalar {
+    let msg32 = TaggedHash::new(domain).hash(&[transcript_bytes]);
+    Drbg::with_key_bytes(&msg32, &Zeroizing::new(secret.to_be_bytes()), None).next_nonce()
+}
+
+#[cfg(all(test, feature = "hashes"))]
+mod tests {
+    use super::derive_proof_nonce;
+    use crate::test_util::hex32;
+    use crate::Scalar;
+
+    /// The expected nonce was computed by an independent implementation
+    #[test]
+    fn proof_nonce() {
+        let secret = Scalar::from(42);
+        let nonce = derive_proof_nonce(&secret, b"statement", "domain");
+        assert_eq!(nonce.to_be_bytes(), hex32("0591b3bae27b0895f987dc99f1aaa22e7787302d3159e4e51a6ec1f56572298f"));
+        assert_ne!(derive_proof_nonce(&secret, b"statement", "other domain"), nonce);
+        assert_ne!(derive_proof_nonce(&secret, b"other statement", "domain"), nonce);
+        assert_ne!(derive_proof_nonce(&(secret + Scalar::ONE), b"statement", "domain"), nonce);
+    }
+}
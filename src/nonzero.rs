@@ -0,0 +1,243 @@
+//! Scalars and points with the zero and the identity excluded by construction
+//!
+//! The group has prime order so the product of a non-zero scalar and a point other than the
+//! point at infinity is never the point at infinity. Encoding this in the types removes error
+//! paths from the code that relies on it. Addition is not closed - `a + (-a)` is zero - so only
+//! multiplication, inversion and negation are provided.
+
+use core::convert::TryFrom;
+use core::ops::{Deref, Mul, Neg};
+use rand_core::{CryptoRng, RngCore};
+use crate::{Error, Point, PublicKey, Scalar, SecretKey};
+
+/// Scalar other than zero
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct NonZeroScalar(Scalar);
+
+impl NonZeroScalar {
+    pub const ONE: Self = NonZeroScalar(Scalar::ONE);
+
+    /// Returns `Error::ZeroScalar` if the scalar is zero
+    pub fn new(scalar: Scalar) -> Result<Self, Error> {
+        if scalar.is_zero() {
+            Err(Error::ZeroScalar)
+        } else {
+            Ok(NonZeroScalar(scalar))
+        }
+    }
+
+    /// Parses big-endian bytes.
+    ///
+    /// Returns `Error::NonCanonicalScalar` if the value is not less than `N` and
+    /// `Error::ZeroScalar` if it's zero.
+    pub fn from_be_bytes(bytes: &[u8; 32]) -> Result<Self, Error> {
+        Scalar::from_be_bytes(bytes).ok_or(Error::NonCanonicalScalar).and_then(Self::new)
+    }
+
+    /// Generates uniformly random non-zero scalar
+    pub fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        NonZeroScalar(Scalar::random(rng))
+    }
+
+    pub fn to_scalar(self) -> Scalar {
+        self.0
+    }
+
+    /// Inverts the scalar, the inverse always exists
+    pub fn invert(self) -> Self {
+        NonZeroScalar(self.0.multiplicative_inverse())
+    }
+}
+
+impl Deref for NonZeroScalar {
+    type Target = Scalar;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<NonZeroScalar> for Scalar {
+    fn from(scalar: NonZeroScalar) -> Self {
+        scalar.0
+    }
+}
+
+impl TryFrom<Scalar> for NonZeroScalar {
+    type Error = Error;
+
+    fn try_from(scalar: Scalar) -> Result<Self, Self::Error> {
+        Self::new(scalar)
+    }
+}
+
+impl From<&SecretKey> for NonZeroScalar {
+    fn from(secret_key: &SecretKey) -> Self {
+        NonZeroScalar(secret_key.to_scalar())
+    }
+}
+
+impl From<NonZeroScalar> for SecretKey {
+    fn from(scalar: NonZeroScalar) -> Self {
+        SecretKey::from_scalar(scalar.0).expect("the scalar is non-zero")
+    }
+}
+
+impl Mul for NonZeroScalar {
+    type Output = NonZeroScalar;
+
+    /// The product is non-zero because `N` is prime
+    fn mul(self, rhs: NonZeroScalar) -> Self::Output {
+        NonZeroScalar(self.0 * rhs.0)
+    }
+}
+
+impl Neg for NonZeroScalar {
+    type Output = NonZeroScalar;
+
+    fn neg(self) -> Self::Output {
+        NonZeroScalar(-self.0)
+    }
+}
+
+/// Point on the curve other than the point at infinity
+///
+/// Unlike `PublicKey` this doesn't imply the point is a key, it may be any intermediate value.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct NonIdentityPoint(Point);
+
+impl NonIdentityPoint {
+    /// Returns `Error::PointAtInfinity` if the point is at infinity
+    pub fn new(point: Point) -> Result<Self, Error> {
+        if point.is_at_infinity() {
+            Err(Error::PointAtInfinity)
+        } else {
+            Ok(NonIdentityPoint(point))
+        }
+    }
+
+    /// Parses SEC1-encoded point, the errors are the same as in `Point::from_sec1_bytes`
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Point::from_sec1_bytes(bytes).and_then(Self::new)
+    }
+
+    pub fn to_point(self) -> Point {
+        self.0
+    }
+}
+
+impl Deref for NonIdentityPoint {
+    type Target = Point;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<NonIdentityPoint> for Point {
+    fn from(point: NonIdentityPoint) -> Self {
+        point.0
+    }
+}
+
+impl TryFrom<Point> for NonIdentityPoint {
+    type Error = Error;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Self::new(point)
+    }
+}
+
+impl From<&PublicKey> for NonIdentityPoint {
+    fn from(public_key: &PublicKey) -> Self {
+        NonIdentityPoint(public_key.to_point())
+    }
+}
+
+impl From<NonIdentityPoint> for PublicKey {
+    fn from(point: NonIdentityPoint) -> Self {
+        PublicKey::from_point(point.0).expect("the point is not at infinity")
+    }
+}
+
+impl Mul<NonZeroScalar> for NonIdentityPoint {
+    type Output = NonIdentityPoint;
+
+    /// The product is not at infinity because the group has prime order
+    fn mul(self, rhs: NonZeroScalar) -> Self::Output {
+        NonIdentityPoint(self.0 * rhs.0)
+    }
+}
+
+impl Neg for NonIdentityPoint {
+    type Output = NonIdentityPoint;
+
+    fn neg(self) -> Self::Output {
+        NonIdentityPoint(-self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+    use super::{NonIdentityPoint, NonZeroScalar};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, FromBytes, Point, PublicKey, Scalar, SecretKey, ToBytes, G};
+
+    #[test]
+    fn constructors() {
+        assert_eq!(NonZeroScalar::new(Scalar::ZERO), Err(Error::ZeroScalar));
+        assert_eq!(NonZeroScalar::try_from(Scalar::ONE), Ok(NonZeroScalar::ONE));
+        assert_eq!(*NonZeroScalar::new(-Scalar::ONE).unwrap(), -Scalar::ONE);
+        assert_eq!(NonIdentityPoint::new(Point::AT_INFINITY), Err(Error::PointAtInfinity));
+        assert_eq!(Point::from(NonIdentityPoint::try_from(G).unwrap()), G);
+        assert_eq!(NonIdentityPoint::new(G).unwrap().x(), G.x());
+
+        let mut rng = DeterministicRng::new(234);
+        let secret_key = SecretKey::from_scalar(Scalar::random(&mut rng)).unwrap();
+        let scalar = NonZeroScalar::from(&secret_key);
+        assert_eq!(SecretKey::from(scalar), secret_key);
+        let point = NonIdentityPoint::from(&secret_key.public_key());
+        assert_eq!(PublicKey::from(point), secret_key.public_key());
+    }
+
+    #[test]
+    fn arithmetic_closure() {
+        let mut rng = DeterministicRng::new(234);
+        let a = NonZeroScalar::random(&mut rng);
+        let b = NonZeroScalar::random(&mut rng);
+        assert_eq!((a * b).to_scalar(), *a * *b);
+        assert_eq!(a * a.invert(), NonZeroScalar::ONE);
+        assert_eq!(NonZeroScalar::ONE.invert(), NonZeroScalar::ONE);
+        assert_eq!((-a).to_scalar(), -*a);
+        let minus_one = -NonZeroScalar::ONE;
+        assert_eq!(minus_one * minus_one, NonZeroScalar::ONE);
+        assert_eq!(minus_one.invert(), minus_one);
+
+        let point = NonIdentityPoint::new(G).unwrap() * a;
+        assert_eq!(point.to_point(), G * *a);
+        assert_eq!((point * a.invert()).to_point(), G);
+        assert_eq!((-point).to_point(), -(G * *a));
+        assert_eq!((NonIdentityPoint::new(G).unwrap() * minus_one).to_point(), -G);
+    }
+
+    #[test]
+    fn parsing_rejects_excluded_values() {
+        assert_eq!(NonZeroScalar::from_be_bytes(&[0; 32]), Err(Error::ZeroScalar));
+        assert_eq!(NonZeroScalar::from_be_bytes(&[0xFF; 32]), Err(Error::NonCanonicalScalar));
+        assert_eq!(<NonZeroScalar as FromBytes>::from_bytes(&[0; 32]), Err(Error::ZeroScalar));
+        assert_eq!(NonZeroScalar::from_slice(&[0; 32]), Err(Error::ZeroScalar));
+        let one = NonZeroScalar::ONE.to_bytes();
+        assert_eq!(NonZeroScalar::from_slice(&one), Ok(NonZeroScalar::ONE));
+
+        // `Point` encodes infinity as zeros but the wrapper must not accept it
+        assert_eq!(Point::AT_INFINITY.to_bytes(), [0; 33]);
+        assert_eq!(<NonIdentityPoint as FromBytes>::from_bytes(&[0; 33]), Err(Error::InvalidPrefix(0)));
+        let mut uncompressed_infinity = [0; 65];
+        uncompressed_infinity[0] = 0x04;
+        assert_eq!(NonIdentityPoint::from_sec1_bytes(&uncompressed_infinity), Err(Error::PointAtInfinity));
+        let g = NonIdentityPoint::new(G).unwrap();
+        assert_eq!(NonIdentityPoint::from_slice(&g.to_bytes()), Ok(g));
+        assert_eq!(NonIdentityPoint::from_sec1_bytes(&G.serialize_uncompressed()), Ok(g));
+    }
+}
@@ -0,0 +1,208 @@
+//! Nostr events (NIP-01)
+//!
+//! The id of an event is SHA256 of the JSON array `[0, pubkey, created_at, kind, tags, content]`
+//! serialized without whitespace, with the public key as lower-case hex and strings escaped
+//! minimally - only `\n`, `"`, `\`, `\r`, `\t`, backspace and form feed, everything else
+//! (including other control characters) is included verbatim. The signature is BIP340 over the
+//! 32-byte id.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+use crate::hashes::sha256;
+use crate::{schnorr, Error, Keypair, Message, XOnlyPoint};
+
+/// Signed Nostr event
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Event {
+    pub id: [u8; 32],
+    pub pubkey: XOnlyPoint,
+    /// Unix timestamp in seconds
+    pub created_at: u64,
+    pub kind: u16,
+    pub tags: Vec<Vec<String>>,
+    pub content: String,
+    pub sig: [u8; 64],
+}
+
+fn push_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '\n' => out.push_str("\\n"),
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0C}' => out.push_str("\\f"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Returns the canonical serialization whose hash is the event id
+pub fn serialize(pubkey: &XOnlyPoint, created_at: u64, kind: u16, tags: &[Vec<String>], content: &str) -> String {
+    let mut out = String::from("[0,\"");
+    for byte in &pubkey.serialize() {
+        write!(out, "{:02x}", byte).expect("writing to String doesn't fail");
+    }
+    write!(out, "\",{},{},[", created_at, kind).expect("writing to String doesn't fail");
+    for (i, tag) in tags.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push('[');
+        for (j, value) in tag.iter().enumerate() {
+            if j > 0 {
+                out.push(',');
+            }
+            push_string(&mut out, value);
+        }
+        out.push(']');
+    }
+    out.push_str("],");
+    push_string(&mut out, content);
+    out.push(']');
+    out
+}
+
+/// Computes the event id - SHA256 of the canonical serialization
+pub fn event_id(pubkey: &XOnlyPoint, created_at: u64, kind: u16, tags: &[Vec<String>], content: &str) -> [u8; 32] {
+    sha256(serialize(pubkey, created_at, kind, tags, content).as_bytes())
+}
+
+/// Creates the event signed by `keypair`.
+///
+/// `aux_rand` is passed to BIP340 signing, see `schnorr::sign`.
+pub fn sign_event(keypair: &Keypair, created_at: u64, kind: u16, tags: Vec<Vec<String>>, content: String, aux_rand: &[u8; 32]) -> Event {
+    let (pubkey, _) = keypair.x_only_public_key();
+    let id = event_id(&pubkey, created_at, kind, &tags, &content);
+    let sig = schnorr::sign(&Message::from_digest(id), keypair, aux_rand);
+    Event { id, pubkey, created_at, kind, tags, content, sig }
+}
+
+/// Checks that the id matches the contents and that the signature of the id is valid.
+///
+/// Returns `Error::InvalidEventId` or `Error::InvalidSignature` respectively.
+pub fn verify_event(event: &Event) -> Result<(), Error> {
+    if event_id(&event.pubkey, event.created_at, event.kind, &event.tags, &event.content) != event.id {
+        return Err(Error::InvalidEventId);
+    }
+    schnorr::verify(&Message::from_digest(event.id), &event.sig, &event.pubkey)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::{String, ToString}, vec::Vec};
+    use super::{event_id, serialize, sign_event, verify_event, Event};
+    use crate::test_util::{hex, hex32};
+    use crate::{Error, Keypair, SecretKey};
+
+    struct Fixture {
+        secret_key: &'static str,
+        created_at: u64,
+        kind: u16,
+        tags: &'static [&'static [&'static str]],
+        content: &'static str,
+        serialized: &'static str,
+        id: &'static str,
+        sig: &'static str,
+    }
+
+    // Produced by an independent implementation of NIP-01 and BIP340 (with zero aux_rand)
+    const FIXTURES: &[Fixture] = &[
+        Fixture {
+            secret_key: "cce2ab0984327307fc87e52b6a3e78ac8497f7f604f01c78411c7beb754d1a6b",
+            created_at: 1700000000,
+            kind: 1,
+            tags: &[],
+            content: "hello nostr",
+            serialized: r#"[0,"ae5cb7dc00bc1fc21b50af32f9ba3c88b57a87d50022e2c30937fa60bfdd94ba",1700000000,1,[],"hello nostr"]"#,
+            id: "9111e1894cfd996b54517b1c452422da6f2e3ffe5333df0def555808d7657bb8",
+            sig: "d7a4e08ba8b452963e15dc0c663d8fde07ef60fdbb00be74c77f0e67617de007e42c6a91eeda5e00fb83fca0f28b6aa4fb672bbc659a2f5834545af2353299bf",
+        },
+        Fixture {
+            secret_key: "fdaeceb76dd5c316318034ea6675f04bdaf30b203527bc3c7812ae950c5a8865",
+            created_at: 1672531200,
+            kind: 1,
+            tags: &[
+                &["e", "5c83da77af1dec6d7289834998ad7aafbd9e2191396d75ec3cc27f5a77226f36", "wss://relay.example.com"],
+                &["p", "f7234bd4c1394dda46d09f35bd384dd30cc552ad5541990f98844fb06676e9ca"],
+                &["t", "test"],
+            ],
+            content: "quote \" backslash \\ newline \n cr \r tab \t backspace \u{08} formfeed \u{0C} bell \u{07} unicode é中 emoji 😀 slash / del \u{7F}",
+            serialized: "[0,\"3906aab5abf026de797a7cc6a9d8acbff98c62a8df1acf1de96f5cc7cc8f3c26\",1672531200,1,[[\"e\",\"5c83da77af1dec6d7289834998ad7aafbd9e2191396d75ec3cc27f5a77226f36\",\"wss://relay.example.com\"],[\"p\",\"f7234bd4c1394dda46d09f35bd384dd30cc552ad5541990f98844fb06676e9ca\"],[\"t\",\"test\"]],\"quote \\\" backslash \\\\ newline \\n cr \\r tab \\t backspace \\b formfeed \\f bell \u{07} unicode é中 emoji 😀 slash / del \u{7F}\"]",
+            id: "45ade2a7f926a3a9608e54f1ae97758341f759d81d7dae23b145b04e38e1eb16",
+            sig: "7faa467ffd4fd9b7bbb58d9174c0788fd21d31c4bcdffceac25e0ee3271215685c58a20da187cce0899b5b26397da18049e5a45590a8ae393b8ff46937ba6e53",
+        },
+        Fixture {
+            secret_key: "92d047d9ccaf270fe459980aa15793afe69bafdf51e04fd9dc7472295399d305",
+            created_at: 0,
+            kind: 0,
+            tags: &[&[""], &["a", "b\nc"]],
+            content: r#"{"name":"toy","about":"secp256k1"}"#,
+            serialized: r#"[0,"fc35eb9e8ab4bfa347df907a5179fbec20e80d1d0fe971c4b1989496b493d5ac",0,0,[[""],["a","b\nc"]],"{\"name\":\"toy\",\"about\":\"secp256k1\"}"]"#,
+            id: "fd3585849201a143395718d2fa9ca18183233ec3943fcbca07123ac70dc7ce61",
+            sig: "6d3de4fb818327894363d59c239a53ff48f6128145a17979441aa95bc952acec1a34a33b56c6605c6ee6d71c27b865df34fd953c14b54b54a0356356debd0846",
+        },
+        Fixture {
+            secret_key: "fbc3d954de3406cd14881c8dd891daa6fec7ef1586c0b1f1bae77499fb87b095",
+            created_at: 4294967296,
+            kind: 30023,
+            tags: &[&["d", "article"], &["title", "Título"]],
+            content: "",
+            serialized: r#"[0,"11f67233d5ea061aecc56066867f5f5b193671ca7bb05d3c26e779abf9d57c20",4294967296,30023,[["d","article"],["title","Título"]],""]"#,
+            id: "495178a85059644c4c9cfbeb47c464da1b7851a54cf91eec4dcf7eb4ace34793",
+            sig: "8558ce514db536ef1aa655335e7ef6403b980f0ecbc767de8f98dc5cca07a24170cd43a00d1702780837ecd866f2f04e5497be2095cc74cbe3066ad653a9caae",
+        },
+    ];
+
+    fn tags(fixture: &Fixture) -> Vec<Vec<String>> {
+        fixture.tags.iter().map(|tag| tag.iter().map(|value| value.to_string()).collect()).collect()
+    }
+
+    fn sign_fixture(fixture: &Fixture) -> Event {
+        let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&hex32(fixture.secret_key)).unwrap());
+        sign_event(&keypair, fixture.created_at, fixture.kind, tags(fixture), fixture.content.to_string(), &[0; 32])
+    }
+
+    #[test]
+    fn fixtures() {
+        for fixture in FIXTURES {
+            let event = sign_fixture(fixture);
+            assert_eq!(serialize(&event.pubkey, event.created_at, event.kind, &event.tags, &event.content), fixture.serialized);
+            assert_eq!(event.id, hex32(fixture.id));
+            assert_eq!(event_id(&event.pubkey, fixture.created_at, fixture.kind, &tags(fixture), fixture.content), hex32(fixture.id));
+            assert_eq!(event.sig[..], hex(fixture.sig)[..]);
+            assert_eq!(verify_event(&event), Ok(()));
+        }
+    }
+
+    #[test]
+    fn tampering_is_detected() {
+        let event = sign_fixture(&FIXTURES[1]);
+
+        let mut content = event.clone();
+        content.content.push(' ');
+        assert_eq!(verify_event(&content), Err(Error::InvalidEventId));
+        let mut tags = event.clone();
+        tags.tags.pop();
+        assert_eq!(verify_event(&tags), Err(Error::InvalidEventId));
+        let mut kind = event.clone();
+        kind.kind = 7;
+        assert_eq!(verify_event(&kind), Err(Error::InvalidEventId));
+
+        let mut sig = event.clone();
+        sig.sig[63] ^= 1;
+        assert_eq!(verify_event(&sig), Err(Error::InvalidSignature));
+        // consistent id and contents signed by someone else
+        let mut pubkey = sign_fixture(&FIXTURES[0]);
+        pubkey.created_at = event.created_at;
+        pubkey.kind = event.kind;
+        pubkey.tags = event.tags.clone();
+        pubkey.content = event.content.clone();
+        pubkey.id = event_id(&pubkey.pubkey, pubkey.created_at, pubkey.kind, &pubkey.tags, &pubkey.content);
+        assert_eq!(verify_event(&pubkey), Err(Error::InvalidSignature));
+    }
+}
@@ -0,0 +1,170 @@
+//! Oblivious pseudo-random function (2HashDH)
+//!
+//! The PRF is `F(k, x) = H2(x, k·H1(x))`. The client hashes its input to a point, blinds it
+//! with random `r` and sends `r·H1(x)` to the server which multiplies it by its key. The client
+//! then unblinds the result by multiplying it by `r⁻¹` and hashes it together with the input.
+//! The server learns nothing about `x` and the client learns nothing about `k`.
+//!
+//! `H1` is `TaggedHash("toy-secp256k1/OPRF/hash_to_curve")` hashed to curve from `x`, `H2` is
+//! `TaggedHash("toy-secp256k1/OPRF/finalize")(len(x) || x || N)` where `len(x)` is 8 bytes
+//! big-endian and `N` is the compressed unblinded point.
+
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use crate::tagged_hash::TaggedHash;
+use crate::zeroize::Wipe;
+use crate::{Error, Point, PublicKey, Scalar, SecretKey};
+
+const HASH_TO_CURVE_TAG: &str = "toy-secp256k1/OPRF/hash_to_curve";
+const FINALIZE_TAG: &str = "toy-secp256k1/OPRF/finalize";
+
+/// The blinded input sent by the client to the server
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct BlindedElement(Point);
+
+/// The blinded input multiplied by the key of the server
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct EvaluatedElement(Point);
+
+macro_rules! impl_element_encoding {
+    ($type:ident) => {
+        impl $type {
+            /// Parses the compressed point rejecting invalid points and the point at infinity
+            pub fn from_bytes(bytes: &[u8; 33]) -> Result<Self, Error> {
+                Point::from_sec1_bytes(bytes).map($type)
+            }
+
+            pub fn serialize(&self) -> [u8; 33] {
+                self.0.serialize_compressed()
+            }
+        }
+    }
+}
+
+impl_element_encoding!(BlindedElement);
+impl_element_encoding!(EvaluatedElement);
+
+fn hash_to_curve(input: &[u8]) -> Point {
+    TaggedHash::new(HASH_TO_CURVE_TAG).hash_to_curve(&[input])
+}
+
+fn finalize_hash(input: &[u8], unblinded: &Point) -> [u8; 32] {
+    TaggedHash::new(FINALIZE_TAG).hash(&[&(input.len() as u64).to_be_bytes(), input, &unblinded.serialize_compressed()])
+}
+
+/// Client state between blinding the input and receiving the evaluation
+///
+/// The blinding factor is overwritten with zero when the state is dropped.
+pub struct Client {
+    input: Vec<u8>,
+    blinding: Scalar,
+}
+
+impl Client {
+    /// Blinds the input with a fresh random factor.
+    pub fn blind<R: RngCore + CryptoRng>(input: &[u8], rng: &mut R) -> (Self, BlindedElement) {
+        let blinding = Scalar::random(rng);
+        let blinded = blind_point(hash_to_curve(input), blinding);
+        (Client { input: input.to_vec(), blinding }, blinded)
+    }
+
+    /// Unblinds the evaluation returned by the server and computes the PRF output.
+    ///
+    /// Returns `Error::InvalidPublicKey` if the unblinded point is at infinity which can only
+    /// happen if the server misbehaves.
+    pub fn finalize(self, evaluated: &EvaluatedElement) -> Result<[u8; 32], Error> {
+        let unblinded = evaluated.0 * self.blinding.multiplicative_inverse();
+        if unblinded.is_at_infinity() {
+            return Err(Error::InvalidPublicKey);
+        }
+        Ok(finalize_hash(&self.input, &unblinded))
+    }
+}
+
+fn blind_point(point: Point, blinding: Scalar) -> BlindedElement {
+    BlindedElement(point * blinding)
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        self.blinding.wipe();
+    }
+}
+
+/// Doesn't print the state so that it doesn't leak into logs
+impl core::fmt::Debug for Client {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Client(..)")
+    }
+}
+
+/// The server holding the PRF key
+#[derive(Debug)]
+pub struct Server {
+    key: SecretKey,
+}
+
+impl Server {
+    pub fn new(key: SecretKey) -> Self {
+        Server { key }
+    }
+
+    /// The public key corresponding to the PRF key
+    pub fn public_key(&self) -> PublicKey {
+        self.key.public_key()
+    }
+
+    /// Multiplies the blinded element by the key
+    pub fn evaluate(&self, blinded: &BlindedElement) -> EvaluatedElement {
+        EvaluatedElement(blinded.0 * self.key.to_scalar())
+    }
+}
+
+/// Computes the PRF directly from the key and the input, without any blinding
+pub fn evaluate_direct(key: &SecretKey, input: &[u8]) -> [u8; 32] {
+    finalize_hash(input, &(hash_to_curve(input) * key.to_scalar()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blind_point, evaluate_direct, BlindedElement, Client, EvaluatedElement, Server};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Scalar, SecretKey, G};
+
+    #[test]
+    fn oblivious_matches_direct() {
+        let mut rng = DeterministicRng::new(1);
+        let key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let server = Server::new(key);
+        let (client, blinded) = Client::blind(b"input", &mut rng);
+        let blinded = BlindedElement::from_bytes(&blinded.serialize()).unwrap();
+        let evaluated = EvaluatedElement::from_bytes(&server.evaluate(&blinded).serialize()).unwrap();
+        assert_eq!(client.finalize(&evaluated), Ok(evaluate_direct(&key, b"input")));
+
+        // fresh blinding each time
+        let (_, blinded_again) = Client::blind(b"input", &mut rng);
+        assert_ne!(blinded, blinded_again);
+        assert_ne!(evaluate_direct(&key, b"input"), evaluate_direct(&key, b"other input"));
+    }
+
+    #[test]
+    fn key_dependent() {
+        let mut rng = DeterministicRng::new(2);
+        let key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let other = SecretKey::from_bytes(&[0x43; 32]).unwrap();
+        let (client, blinded) = Client::blind(b"input", &mut rng);
+        let output = client.finalize(&Server::new(other).evaluate(&blinded)).unwrap();
+        assert_eq!(output, evaluate_direct(&other, b"input"));
+        assert_ne!(output, evaluate_direct(&key, b"input"));
+    }
+
+    #[test]
+    fn blinded_element_hides_input() {
+        // the hashes of the inputs are modelled as 3·G and 5·G so that suitable blinding factors
+        // can be computed
+        let t = Scalar::from(7);
+        let first = blind_point(G * 3, Scalar::from(5) * t);
+        let second = blind_point(G * 5, Scalar::from(3) * t);
+        assert_eq!(first, second);
+    }
+}
@@ -0,0 +1,148 @@
+//! The simplest 1-out-of-2 oblivious transfer (Chou–Orlandi)
+//!
+//! The sender publishes `S = a·G`, the receiver with choice bit `c` responds with
+//! `R = c·S + b·G`. The sender then derives two keys `k₀ = H(a·R)` and `k₁ = H(a·R - a·S)` and
+//! the receiver derives `H(b·S)` which equals `k_c`. The sender learns nothing about `c` and the
+//! receiver can't compute the other key. The sender then encrypts each message with its key.
+//!
+//! The hash `H` is supplied by the caller, real protocols should also bind `S` and `R` into it.
+
+use rand_core::{CryptoRng, RngCore};
+use crate::{Error, Point, Scalar, G};
+
+/// The first message, sent by the sender to the receiver
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SenderSetup {
+    big_s: Point,
+}
+
+impl SenderSetup {
+    /// Parses the compressed point rejecting invalid points and the point at infinity
+    pub fn from_bytes(bytes: &[u8; 33]) -> Result<Self, Error> {
+        Point::from_sec1_bytes(bytes).map(|big_s| SenderSetup { big_s })
+    }
+
+    pub fn serialize(&self) -> [u8; 33] {
+        self.big_s.serialize_compressed()
+    }
+}
+
+/// The response of the receiver encoding the choice
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ReceiverResponse {
+    big_r: Point,
+}
+
+impl ReceiverResponse {
+    /// Parses the compressed point rejecting invalid points and the point at infinity
+    pub fn from_bytes(bytes: &[u8; 33]) -> Result<Self, Error> {
+        Point::from_sec1_bytes(bytes).map(|big_r| ReceiverResponse { big_r })
+    }
+
+    pub fn serialize(&self) -> [u8; 33] {
+        self.big_r.serialize_compressed()
+    }
+}
+
+/// State of the sender between sending the setup and receiving the response
+pub struct Sender {
+    a: Scalar,
+    big_s: Point,
+}
+
+impl Sender {
+    /// Generates the secret `a` returning the state and the message for the receiver
+    pub fn new<R: RngCore + CryptoRng>(rng: &mut R) -> (Self, SenderSetup) {
+        let a = Scalar::random(rng);
+        let big_s = G * a;
+        (Sender { a, big_s }, SenderSetup { big_s })
+    }
+
+    /// Derives the keys `(k₀, k₁)` for both messages by passing the points to `kdf`.
+    pub fn derive_keys<T, F: Fn(&Point) -> T>(self, response: &ReceiverResponse, kdf: F) -> (T, T) {
+        let k0 = response.big_r * self.a;
+        let k1 = k0 + -(self.big_s * self.a);
+        (kdf(&k0), kdf(&k1))
+    }
+}
+
+/// Doesn't print the secret so that it doesn't leak into logs
+impl core::fmt::Debug for Sender {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Sender(..)")
+    }
+}
+
+/// State of the receiver after choosing
+pub struct Receiver {
+    b: Scalar,
+    big_s: Point,
+}
+
+impl Receiver {
+    /// Encodes `choice` into the response for the sender.
+    ///
+    /// The choice is **NOT** hidden from timing side channels.
+    pub fn new<R: RngCore + CryptoRng>(setup: &SenderSetup, choice: bool, rng: &mut R) -> (Self, ReceiverResponse) {
+        let b = Scalar::random(rng);
+        let big_r = if choice { setup.big_s + G * b } else { G * b };
+        (Receiver { b, big_s: setup.big_s }, ReceiverResponse { big_r })
+    }
+
+    /// Derives the key of the chosen message by passing the point to `kdf`.
+    pub fn derive_key<T, F: FnOnce(&Point) -> T>(self, kdf: F) -> T {
+        kdf(&(self.big_s * self.b))
+    }
+}
+
+/// Doesn't print the secret so that it doesn't leak into logs
+impl core::fmt::Debug for Receiver {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Receiver(..)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Receiver, ReceiverResponse, Sender, SenderSetup};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, Point};
+
+    fn kdf(point: &Point) -> [u8; 33] {
+        point.serialize_compressed()
+    }
+
+    #[test]
+    fn receiver_gets_chosen_key() {
+        let mut rng = DeterministicRng::new(1);
+        for &choice in &[false, true] {
+            let (sender, setup) = Sender::new(&mut rng);
+            let setup = SenderSetup::from_bytes(&setup.serialize()).unwrap();
+            let (receiver, response) = Receiver::new(&setup, choice, &mut rng);
+            let response = ReceiverResponse::from_bytes(&response.serialize()).unwrap();
+            let (k0, k1) = sender.derive_keys(&response, kdf);
+            let key = receiver.derive_key(kdf);
+            assert_ne!(k0, k1);
+            if choice {
+                assert_eq!(key, k1);
+                assert_ne!(key, k0);
+            } else {
+                assert_eq!(key, k0);
+                assert_ne!(key, k1);
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_points_rejected() {
+        // x = 5 is not on the curve
+        let mut bytes = [0; 33];
+        bytes[0] = 0x02;
+        bytes[32] = 5;
+        assert_eq!(ReceiverResponse::from_bytes(&bytes), Err(Error::NotOnCurve));
+        assert_eq!(SenderSetup::from_bytes(&bytes), Err(Error::NotOnCurve));
+        assert_eq!(ReceiverResponse::from_bytes(&[0; 33]), Err(Error::InvalidPrefix(0)));
+        bytes[0] = 0x04;
+        assert_eq!(ReceiverResponse::from_bytes(&bytes), Err(Error::InvalidPrefix(0x04)));
+    }
+}
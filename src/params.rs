@@ -0,0 +1,91 @@
+//! Parameters of the secp256k1 curve `y² = x³ + 7` over `F_p`
+//!
+//! The values are taken from SEC 2: Recommended Elliptic Curve Domain Parameters, version 2.0,
+//! section 2.4.1.
+
+use crate::{Point, Scalar, Zp, U256};
+
+/// The field size `p = 2²⁵⁶ - 2³² - 977`
+pub const P: U256 = U256([0xFFFFFFFE_FFFFFC2F, 0xFFFFFFFF_FFFFFFFF, 0xFFFFFFFF_FFFFFFFF, 0xFFFFFFFF_FFFFFFFF]);
+
+/// The order `n` of the generator, since the cofactor is 1 it's also the order of the curve
+pub const N: U256 = U256([0xBFD25E8C_D0364141, 0xBAAEDCE6_AF48A03B, 0xFFFFFFFF_FFFFFFFE, 0xFFFFFFFF_FFFFFFFF]);
+
+/// The coefficient `b` of the curve equation, `a` is zero
+pub const B: Zp = crate::zp!("7");
+
+/// The cofactor `h` - the curve has prime order
+pub const COFACTOR: u64 = 1;
+
+/// The base point `G`
+pub const G: Point = crate::point!("0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798");
+
+/// `(n - 1) / 2` - the largest low-S value of ECDSA signatures
+pub const HALF_N: U256 = N.wrapping_shr(1);
+
+/// `(p + 1) / 4` - raising a square to this power yields its root since `p ≡ 3 (mod 4)`
+pub const SQRT_EXPONENT: U256 = P.wrapping_shr(2).wrapping_add(U256::one());
+
+/// `(p + 2) / 9` - raising a cube to this power yields one of its roots, see `Zp::cube_root`
+pub const CUBE_ROOT_EXPONENT: U256 = U256([0x1C71C71C_555554E9, 0x71C71C71_C71C71C7, 0xC71C71C7_1C71C71C, 0x1C71C71C_71C71C71]);
+
+/// `β` of the GLV endomorphism, the same as `Zp::CUBE_ROOT_OF_UNITY`
+///
+/// It's a primitive cube root of unity in `F_p` (`β³ = 1`, `β ≠ 1`) and `(βx, y) = λ·(x, y)` for
+/// every point `(x, y)`, see `LAMBDA`.
+pub const BETA: Zp = Zp::CUBE_ROOT_OF_UNITY;
+
+/// `λ` of the GLV endomorphism
+///
+/// It's a primitive cube root of unity modulo `n` (`λ³ = 1`, `λ ≠ 1`) such that
+/// `λ·(x, y) = (βx, y)` for every point `(x, y)`, see `BETA`. Multiplying by `λ` is therefore
+/// as cheap as one field multiplication.
+pub const LAMBDA: Scalar = Scalar::wrapping_from(U256([0xDF02967C_1B23BD72, 0x122E22EA_20816678, 0xA5261C02_8812645A, 0x5363AD4C_C05C30E0]));
+
+#[cfg(test)]
+mod tests {
+    use super::{B, BETA, COFACTOR, CUBE_ROOT_EXPONENT, G, HALF_N, LAMBDA, N, P, SQRT_EXPONENT};
+    use crate::{Point, Scalar, Zp, U256};
+
+    #[test]
+    fn consistency() {
+        assert_eq!(Point::new(G.x(), G.y()), Some(G));
+        assert_eq!(G.y() * G.y(), G.x() * G.x() * G.x() + B);
+        assert!((G * N).is_at_infinity());
+        assert!(!(G * (N - U256::one())).is_at_infinity());
+        assert_eq!(HALF_N * U256::from(2) + U256::one(), N);
+        assert_eq!(SQRT_EXPONENT * U256::from(4), P + U256::one());
+        assert_eq!(COFACTOR, 1);
+        // p ≡ 3 (mod 4) is what makes the simple square root work
+        assert_eq!(P.low_u64() % 4, 3);
+        assert_eq!(Zp::checked_from(P), None);
+        assert_eq!(Zp::checked_from(P - U256::one()), Some(-Zp::ONE));
+    }
+
+    #[test]
+    fn cube_roots() {
+        assert_eq!(CUBE_ROOT_EXPONENT * U256::from(9), P + U256::from(2));
+        assert_eq!(BETA * BETA * BETA, Zp::ONE);
+        assert_ne!(BETA, Zp::ONE);
+    }
+
+    #[test]
+    fn endomorphism() {
+        assert_eq!(LAMBDA * LAMBDA * LAMBDA, Scalar::ONE);
+        assert_ne!(LAMBDA, Scalar::ONE);
+        // the constant is canonical, not reduced by `wrapping_from`
+        assert!(LAMBDA.to_u256() < N);
+
+        let lambda_g = G * LAMBDA;
+        let lambda2_g = lambda_g * LAMBDA;
+        assert_eq!(lambda_g.x(), G.x() * BETA);
+        assert_eq!(lambda_g.y(), G.y());
+        let orbit = G.x_orbit();
+        assert_eq!(orbit, [G.x(), lambda_g.x(), lambda2_g.x()]);
+        assert_eq!(lambda_g.x_orbit(), [lambda_g.x(), lambda2_g.x(), G.x()]);
+
+        let point = G * U256::from(12345);
+        assert_eq!(point * LAMBDA, Point::new(BETA * point.x(), point.y()).unwrap());
+        assert_eq!(Point::AT_INFINITY.x_orbit(), [Zp::ZERO; 3]);
+    }
+}
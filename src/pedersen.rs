@@ -0,0 +1,284 @@
+//! Pedersen commitments
+//!
+//! A commitment to `value` is `value·H + blinding·G`. It's perfectly hiding since any value can
+//! be opened with a suitable blinding factor and computationally binding since finding two
+//! openings reveals the discrete logarithm of `H` with respect to `G`.
+//!
+//! Commitments are additively homomorphic: `Commit(a, r) + Commit(b, s) == Commit(a + b, r + s)`
+//! which allows checking that the sum of committed values balances without revealing them.
+
+#[cfg(feature = "hashes")]
+#[cfg(feature = "alloc")]
+mod vector;
+
+#[cfg(feature = "hashes")]
+#[cfg(feature = "alloc")]
+pub use vector::{vector_commit, Generators};
+
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use crate::{multiexp, Error, Point, Scalar, G, H};
+
+/// Commitment to a scalar value
+///
+/// The underlying point is only accessible via `to_point` to avoid mixing commitments with
+/// public keys by accident.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Commitment(Point);
+
+impl Commitment {
+    /// Commits to `value` using the NUMS generator `H` for the value and `G` for the blinding
+    pub fn commit(value: Scalar, blinding: Scalar) -> Self {
+        Self::commit_with_generators(value, blinding, H, G)
+    }
+
+    /// Computes `value·value_generator + blinding·blinding_generator`.
+    ///
+    /// The commitment is only binding if the discrete logarithm between the generators is
+    /// unknown.
+    pub fn commit_with_generators(value: Scalar, blinding: Scalar, value_generator: Point, blinding_generator: Point) -> Self {
+        Commitment(multiexp(&[(value.to_u256(), value_generator), (blinding.to_u256(), blinding_generator)]))
+    }
+
+    /// Checks that the commitment opens to `value` with `blinding`
+    pub fn verify_opening(&self, value: Scalar, blinding: Scalar) -> bool {
+        self.0.eq_ct(&Self::commit(value, blinding).0)
+    }
+
+    /// Checks the opening of a commitment created by `commit_with_generators`
+    pub fn verify_opening_with_generators(&self, value: Scalar, blinding: Scalar, value_generator: Point, blinding_generator: Point) -> bool {
+        self.0.eq_ct(&Self::commit_with_generators(value, blinding, value_generator, blinding_generator).0)
+    }
+
+    /// Sums the commitments, the result commits to the sum of values and blinding factors
+    pub fn sum<I: IntoIterator<Item = Commitment>>(commitments: I) -> Self {
+        commitments.into_iter().fold(Commitment(Point::AT_INFINITY), Add::add)
+    }
+
+    /// Computes `Σ inputs - Σ outputs`.
+    ///
+    /// If the values balance the result is `r·G` where `r` is the difference of the blinding
+    /// factors so the party knowing them can prove it by signing with `r` as the secret key.
+    pub fn commit_balance(inputs: &[Commitment], outputs: &[Commitment]) -> Point {
+        (Self::sum(inputs.iter().copied()) - Self::sum(outputs.iter().copied())).0
+    }
+
+    /// Computes `Σ positive - Σ negative`, e.g. the excess commitment of a transaction.
+    ///
+    /// Returns `Error::PointAtInfinity` if the sum is the point at infinity, in which case
+    /// `verify_commit_sum` would succeed. This mirrors `secp256k1_pedersen_commit_sum` of
+    /// libsecp256k1-zkp.
+    pub fn commit_sum(positive: &[Commitment], negative: &[Commitment]) -> Result<Self, Error> {
+        let sum = Commitment(Self::commit_balance(positive, negative));
+        if sum.0.is_at_infinity() {
+            Err(Error::PointAtInfinity)
+        } else {
+            Ok(sum)
+        }
+    }
+
+    /// Wraps a point obtained from elsewhere, e.g. from the output of other protocol
+    pub fn from_point(point: Point) -> Self {
+        Commitment(point)
+    }
+
+    /// Explicitly returns the point
+    pub fn to_point(&self) -> Point {
+        self.0
+    }
+
+    /// Parses the compressed SEC1 point, 33 zero bytes encode the point at infinity.
+    pub fn from_bytes(bytes: &[u8; 33]) -> Result<Self, Error> {
+        if *bytes == [0; 33] {
+            return Ok(Commitment(Point::AT_INFINITY));
+        }
+        Point::from_sec1_bytes(bytes).map(Commitment)
+    }
+
+    /// Serializes the point in SEC1 compressed form, the point at infinity is 33 zero bytes.
+    pub fn serialize(&self) -> [u8; 33] {
+        self.0.serialize_compressed()
+    }
+}
+
+/// Checks that `Σ positive == Σ negative`.
+///
+/// This holds if both the values and the blinding factors sum to the same values. Include the
+/// excess commitment among `negative` to check `Σ inputs == Σ outputs + excess`. Empty slices
+/// sum to the point at infinity so two empty slices balance.
+pub fn verify_commit_sum(positive: &[Commitment], negative: &[Commitment]) -> bool {
+    Commitment::commit_balance(positive, negative).is_at_infinity()
+}
+
+impl Add for Commitment {
+    type Output = Commitment;
+
+    fn add(self, rhs: Commitment) -> Self::Output {
+        Commitment(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Commitment {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Neg for Commitment {
+    type Output = Commitment;
+
+    fn neg(self) -> Self::Output {
+        Commitment(-self.0)
+    }
+}
+
+impl Sub for Commitment {
+    type Output = Commitment;
+
+    fn sub(self, rhs: Commitment) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl SubAssign for Commitment {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Multiplies both the value and the blinding factor by a public scalar
+impl Mul<Scalar> for Commitment {
+    type Output = Commitment;
+
+    fn mul(self, rhs: Scalar) -> Self::Output {
+        Commitment(self.0 * rhs)
+    }
+}
+
+impl Mul<u64> for Commitment {
+    type Output = Commitment;
+
+    fn mul(self, rhs: u64) -> Self::Output {
+        Commitment(self.0 * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{verify_commit_sum, Commitment};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, Point, Scalar, G, H};
+
+    #[test]
+    fn hiding_and_binding() {
+        let mut rng = DeterministicRng::new(1);
+        let value = Scalar::from(42);
+        let blinding = Scalar::random(&mut rng);
+        let other_blinding = Scalar::random(&mut rng);
+        let commitment = Commitment::commit(value, blinding);
+        assert_ne!(commitment, Commitment::commit(value, other_blinding));
+        assert!(commitment.verify_opening(value, blinding));
+        assert!(!commitment.verify_opening(value + Scalar::ONE, blinding));
+        assert!(!commitment.verify_opening(value, other_blinding));
+        assert_eq!(commitment.to_point(), H * value + G * blinding);
+    }
+
+    #[test]
+    fn custom_generators() {
+        let value_generator = G * 3;
+        let blinding_generator = G * 5;
+        let commitment = Commitment::commit_with_generators(Scalar::from(2), Scalar::from(7), value_generator, blinding_generator);
+        assert_eq!(commitment.to_point(), G * 41);
+        assert!(commitment.verify_opening_with_generators(Scalar::from(2), Scalar::from(7), value_generator, blinding_generator));
+        assert!(!commitment.verify_opening(Scalar::from(2), Scalar::from(7)));
+    }
+
+    #[test]
+    fn serialization_round_trip() {
+        let mut rng = DeterministicRng::new(2);
+        let commitment = Commitment::commit(Scalar::from(1000), Scalar::random(&mut rng));
+        assert_eq!(Commitment::from_bytes(&commitment.serialize()), Ok(commitment));
+        let zero = Commitment::commit(Scalar::ZERO, Scalar::ZERO);
+        assert_eq!(zero.serialize(), [0; 33]);
+        assert_eq!(Commitment::from_bytes(&zero.serialize()), Ok(zero));
+        let mut invalid = [0; 33];
+        invalid[0] = 0x04;
+        assert_eq!(Commitment::from_bytes(&invalid), Err(Error::InvalidPrefix(0x04)));
+    }
+
+    #[test]
+    fn homomorphism() {
+        let mut rng = DeterministicRng::new(3);
+        // kept small because the arithmetic is slow
+        for _ in 0..3 {
+            let (a, r) = (Scalar::random(&mut rng), Scalar::random(&mut rng));
+            let (b, s) = (Scalar::random(&mut rng), Scalar::random(&mut rng));
+            let sum = Commitment::commit(a, r) + Commitment::commit(b, s);
+            assert_eq!(sum, Commitment::commit(a + b, r + s));
+            assert_eq!(Commitment::commit(a, r) - Commitment::commit(b, s), Commitment::commit(a - b, r - s));
+            assert_eq!(-Commitment::commit(a, r), Commitment::commit(-a, -r));
+        }
+
+        // wrap around the curve order
+        let minus_one = -Scalar::ONE;
+        let sum = Commitment::commit(minus_one, minus_one) + Commitment::commit(Scalar::from(2), Scalar::from(3));
+        assert_eq!(sum, Commitment::commit(Scalar::ONE, Scalar::from(2)));
+        assert!(sum.verify_opening(Scalar::ONE, Scalar::from(2)));
+        let mut sum = Commitment::commit(minus_one, Scalar::ONE);
+        sum += Commitment::commit(Scalar::ONE, minus_one);
+        assert_eq!(sum.to_point(), Point::AT_INFINITY);
+        sum -= Commitment::commit(Scalar::ONE, Scalar::ONE);
+        assert!(sum.verify_opening(minus_one, minus_one));
+    }
+
+    #[test]
+    fn scalar_multiplication() {
+        let commitment = Commitment::commit(Scalar::from(5), Scalar::from(11));
+        assert_eq!(commitment * 3, Commitment::commit(Scalar::from(15), Scalar::from(33)));
+        assert_eq!(commitment * -Scalar::ONE, -commitment);
+    }
+
+    #[test]
+    fn balance() {
+        let mut rng = DeterministicRng::new(4);
+        let blindings = (0..4).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+        let inputs = [Commitment::commit(Scalar::from(10), blindings[0]), Commitment::commit(Scalar::from(5), blindings[1])];
+        let outputs = [Commitment::commit(Scalar::from(12), blindings[2]), Commitment::commit(Scalar::from(3), blindings[3])];
+        let excess = blindings[0] + blindings[1] - blindings[2] - blindings[3];
+        assert_eq!(Commitment::commit_balance(&inputs, &outputs), G * excess);
+        assert_eq!(Commitment::sum(inputs.iter().copied()), inputs[0] + inputs[1]);
+        assert_eq!(Commitment::sum(Vec::new()).to_point(), Point::AT_INFINITY);
+
+        let inflated = [outputs[0], Commitment::commit(Scalar::from(4), blindings[3])];
+        assert_ne!(Commitment::commit_balance(&inputs, &inflated), G * excess);
+    }
+
+    #[test]
+    fn commit_sum() {
+        let mut rng = DeterministicRng::new(239);
+        let blindings = (0..3).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+        let excess_blinding = blindings[0] - blindings[1] - blindings[2];
+        let inputs = [Commitment::commit(Scalar::from(10), blindings[0])];
+        let outputs = [Commitment::commit(Scalar::from(7), blindings[1]), Commitment::commit(Scalar::from(3), blindings[2])];
+
+        let excess = Commitment::commit_sum(&inputs, &outputs).unwrap();
+        assert_eq!(excess, Commitment::commit(Scalar::ZERO, excess_blinding));
+        assert_eq!(excess, inputs[0] - outputs[0] - outputs[1]);
+        let with_excess = [outputs[0], outputs[1], excess];
+        assert!(verify_commit_sum(&inputs, &with_excess));
+        assert!(verify_commit_sum(&with_excess, &inputs));
+        assert!(!verify_commit_sum(&inputs, &outputs));
+
+        // off by one in the value or the blinding factor
+        let value_off = [Commitment::commit(Scalar::from(8), blindings[1]), outputs[1], excess];
+        assert!(!verify_commit_sum(&inputs, &value_off));
+        let blinding_off = [Commitment::commit(Scalar::from(7), blindings[1] + Scalar::ONE), outputs[1], excess];
+        assert!(!verify_commit_sum(&inputs, &blinding_off));
+
+        assert!(verify_commit_sum(&[], &[]));
+        assert!(!verify_commit_sum(&inputs, &[]));
+        assert_eq!(Commitment::commit_sum(&inputs, &[]), Ok(inputs[0]));
+        assert_eq!(Commitment::commit_sum(&[], &inputs), Ok(-inputs[0]));
+        assert_eq!(Commitment::commit_sum(&[], &[]), Err(Error::PointAtInfinity));
+        assert_eq!(Commitment::commit_sum(&inputs, &with_excess), Err(Error::PointAtInfinity));
+    }
+}
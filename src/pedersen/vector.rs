@@ -0,0 +1,123 @@
+//! Vector Pedersen commitments
+//!
+//! Generators are derived by try-and-increment: the candidate x coordinate for the `i`-th
+//! generator of kind `k` (`"G"` or `"H"`) is `TaggedHash(domain_tag)(k || i || counter)` with
+//! `i` encoded as 4 big-endian bytes and `counter` as a single byte, starting from zero and
+//! incremented until the candidate is on the curve. The point with even y is used. Nobody
+//! knows the discrete logarithms between the generators.
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use super::Commitment;
+use crate::tagged_hash::TaggedHash;
+use crate::{multiexp, Point, Scalar, H};
+
+/// Deterministically derived generators `G₁..Gₙ` and `H₁..Hₙ`
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Generators {
+    g: Vec<Point>,
+    h: Vec<Point>,
+}
+
+impl Generators {
+    /// Derives `n` generators of each kind for the domain.
+    ///
+    /// Different domain tags produce unrelated generators.
+    pub fn new(n: usize, domain_tag: &str) -> Self {
+        let hasher = TaggedHash::new(domain_tag);
+        Generators {
+            g: (0..n).map(|i| derive_generator(&hasher, b"G", i)).collect(),
+            h: (0..n).map(|i| derive_generator(&hasher, b"H", i)).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.g.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.g.is_empty()
+    }
+
+    pub fn g(&self) -> &[Point] {
+        &self.g
+    }
+
+    pub fn h(&self) -> &[Point] {
+        &self.h
+    }
+}
+
+fn derive_generator(hasher: &TaggedHash, kind: &[u8], index: usize) -> Point {
+    let index = u32::try_from(index).expect("the number of generators fits into u32").to_be_bytes();
+    hasher.hash_to_curve(&[kind, &index])
+}
+
+/// Computes `Σ vᵢ·Gᵢ + blinding·H` where `H` is the NUMS generator of the crate.
+///
+/// # Panics
+///
+/// If there are more values than generators.
+pub fn vector_commit(values: &[Scalar], blinding: Scalar, generators: &Generators) -> Commitment {
+    assert!(values.len() <= generators.len(), "not enough generators for the values");
+    let mut pairs = values.iter().zip(&generators.g).map(|(value, generator)| (value.to_u256(), *generator)).collect::<Vec<_>>();
+    pairs.push((blinding.to_u256(), H));
+    Commitment(multiexp(&pairs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{vector_commit, Generators};
+    use crate::test_util::hex;
+    use crate::test_utils::DeterministicRng;
+    use crate::{Scalar, H};
+
+    #[test]
+    fn pinned_generators() {
+        let generators = Generators::new(3, "toy-secp256k1/test");
+        let expected_g = [
+            "026dff4e1d60a60bcb2ca2bf001c5bdea1c771ce02e205962e030834964f3e2b98",
+            "021f336ea7dea3cc906ee1cb648d588ee89e065c7ffdc05deb5414a127202f9e42",
+            "02991b020bf5285a8dc284e404d7daf7dbc3184a7c459eb5747340d7bb11cab539",
+        ];
+        let expected_h = [
+            "02e2577c0a30d83c1e7263bf0ba388e9b583abd1a9822be0ad140af37ba534c54b",
+            "026cdd5e747d1d7df0da8e43add7dd5fe140a23bba0d7d3368f5eb2099b51c3060",
+            "02b160ad746f64946ea0c0828ade4f9a65005a5b445534c36f990b3a142a028854",
+        ];
+        for (generator, expected) in generators.g().iter().zip(&expected_g) {
+            assert_eq!(generator.serialize_compressed()[..], hex(expected)[..]);
+        }
+        for (generator, expected) in generators.h().iter().zip(&expected_h) {
+            assert_eq!(generator.serialize_compressed()[..], hex(expected)[..]);
+        }
+        // the prefix doesn't depend on the count
+        assert_eq!(Generators::new(2, "toy-secp256k1/test").g(), &generators.g()[..2]);
+    }
+
+    #[test]
+    fn disjoint_domains() {
+        let a = Generators::new(4, "domain A");
+        let b = Generators::new(4, "domain B");
+        let all = a.g().iter().chain(a.h()).chain(b.g()).chain(b.h()).collect::<std::collections::HashSet<_>>();
+        assert_eq!(all.len(), 16);
+    }
+
+    #[test]
+    fn single_position_changes() {
+        let mut rng = DeterministicRng::new(1);
+        let generators = Generators::new(4, "toy-secp256k1/test");
+        let values = (0..4).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+        let blinding = Scalar::random(&mut rng);
+        let commitment = vector_commit(&values, blinding, &generators);
+        for i in 0..values.len() {
+            let mut changed = values.clone();
+            changed[i] += Scalar::ONE;
+            assert_ne!(vector_commit(&changed, blinding, &generators), commitment, "position {}", i);
+        }
+        let expected = generators.g().iter().zip(&values).fold(H * blinding, |acc, (generator, value)| acc + *generator * *value);
+        assert_eq!(commitment.to_point(), expected);
+        // missing values are zero
+        assert_eq!(vector_commit(&values[..2], blinding, &generators), vector_commit(&[values[0], values[1], Scalar::ZERO], blinding, &generators));
+    }
+}
@@ -0,0 +1,187 @@
+//! Windowed Pedersen hash - a hash to the curve that is collision resistant under ECDLP
+//!
+//! The input is split into segments of 31 bytes (the last one may be shorter, empty input is a
+//! single empty segment). Segment `j` is encoded as the scalar `sⱼ = 2^(8·len) + int(bytes)` -
+//! the marker bit makes the encoding injective including the length - and the hash is
+//! `Σ sⱼ·Pⱼ` where `Pⱼ = hash_to_curve(TaggedHash("toy-secp256k1/pedersen-hash")(j))` with `j`
+//! encoded as 4 big-endian bytes. A collision gives a non-trivial linear relation between
+//! the generators so finding one is as hard as computing their discrete logarithms.
+//!
+//! Each `sⱼ` has at most 249 bits so it's split into 63 4-bit chunks and `sⱼ·Pⱼ` is the sum of
+//! one entry of a per-position table `(v + 1)·16ⁱ·Pⱼ` for each non-zero chunk, just like
+//! `Point::mul_gen` with the `precomputed-tables` feature. With the `std` feature the tables
+//! are computed once and cached for the first 128 segments (3968 bytes of input), the rest
+//! and everything without `std` uses `multiexp`.
+//!
+//! The hash is **NOT CONSTANT TIME!!!** and it's not a random oracle - it's linear.
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use crate::tagged_hash::TaggedHash;
+use crate::{multiexp, Point, Zp, U256};
+
+const GENERATOR_TAG: &str = "toy-secp256k1/pedersen-hash";
+/// Input bytes in one segment, with the marker the scalar fits into 249 bits
+const SEGMENT_LEN: usize = 31;
+/// Number of 4-bit chunks of a segment scalar
+#[cfg(feature = "std")]
+const WINDOWS: usize = 63;
+/// Non-zero values of a chunk
+#[cfg(feature = "std")]
+const ENTRIES: usize = 15;
+/// Number of segments whose tables are cached, about 60 KiB each
+#[cfg(feature = "std")]
+const CACHED_SEGMENTS: usize = 128;
+
+#[cfg(feature = "std")]
+type Table = Vec<[Point; ENTRIES]>;
+
+#[cfg(feature = "std")]
+static TABLES: std::sync::RwLock<Vec<Table>> = std::sync::RwLock::new(Vec::new());
+
+/// Hashes the input to a curve point.
+///
+/// The result is the point at infinity only if a discrete logarithm relation between the
+/// generators was found, which is infeasible.
+pub fn hash(input: &[u8]) -> Point {
+    let segments = segments(input);
+    #[cfg(feature = "std")]
+    {
+        let cached = segments.len().min(CACHED_SEGMENTS);
+        if TABLES.read().expect("not poisoned").len() < cached {
+            let mut tables = TABLES.write().expect("not poisoned");
+            while tables.len() < cached {
+                let generator = generator(tables.len());
+                tables.push(table(generator));
+            }
+        }
+        let tables = TABLES.read().expect("not poisoned");
+        let res = segments[..cached].iter().zip(tables.iter()).fold(Point::AT_INFINITY, |acc, (segment, table)| acc + lookup(table, segment));
+        let rest = (cached..segments.len()).map(|j| (U256::from_be_bytes(&segments[j]), generator(j))).collect::<Vec<_>>();
+        res + multiexp(&rest)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let pairs = segments.iter().enumerate().map(|(j, segment)| (U256::from_be_bytes(segment), generator(j))).collect::<Vec<_>>();
+        multiexp(&pairs)
+    }
+}
+
+/// Finalizes the hash to a field element by taking the x coordinate.
+///
+/// The point at infinity (which `hash` doesn't return in practice) maps to zero.
+pub fn to_zp(hash: &Point) -> Zp {
+    hash.x()
+}
+
+/// Encodes the segments as big-endian scalars with the marker
+fn segments(input: &[u8]) -> Vec<[u8; 32]> {
+    let encode = |segment: &[u8]| {
+        let mut bytes = [0; 32];
+        bytes[32 - segment.len()..].copy_from_slice(segment);
+        bytes[31 - segment.len()] = 1;
+        bytes
+    };
+    if input.is_empty() {
+        return alloc::vec![encode(&[])];
+    }
+    input.chunks(SEGMENT_LEN).map(encode).collect()
+}
+
+/// Derives the generator of the segment at `index`
+fn generator(index: usize) -> Point {
+    let index = u32::try_from(index).expect("the number of segments fits into u32").to_be_bytes();
+    TaggedHash::new(GENERATOR_TAG).hash_to_curve(&[&index])
+}
+
+/// Computes `table[i][v] = (v + 1)·16ⁱ·base`
+#[cfg(feature = "std")]
+fn table(base: Point) -> Table {
+    let mut base = base;
+    (0..WINDOWS)
+        .map(|_| {
+            let mut row = [base; ENTRIES];
+            for j in 1..ENTRIES {
+                row[j] = row[j - 1] + base;
+            }
+            base = row[ENTRIES - 1] + base;
+            row
+        })
+        .collect()
+}
+
+/// Multiplies the base of the table by the big-endian scalar
+#[cfg(feature = "std")]
+fn lookup(table: &[[Point; ENTRIES]], scalar: &[u8; 32]) -> Point {
+    let mut res = Point::AT_INFINITY;
+    for (i, byte) in scalar.iter().rev().enumerate() {
+        for (j, chunk) in [byte & 0x0F, byte >> 4].iter().enumerate() {
+            if *chunk != 0 {
+                res += table[i * 2 + j][usize::from(*chunk) - 1];
+            }
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generator, hash, segments, to_zp, SEGMENT_LEN};
+    use crate::test_util::hex;
+    use crate::{multiexp, Point, U256};
+
+    fn naive(input: &[u8]) -> Point {
+        let pairs = segments(input).iter().enumerate().map(|(j, segment)| (U256::from_be_bytes(segment), generator(j))).collect::<Vec<_>>();
+        multiexp(&pairs)
+    }
+
+    fn long_input() -> Vec<u8> {
+        (0..3000u32).map(|i| (i * 7 + i / 256) as u8).collect()
+    }
+
+    #[test]
+    fn pinned_vectors() {
+        // computed by an independent implementation of the definition in the module docs
+        let vectors: [(Vec<u8>, &str); 4] = [
+            (Vec::new(), "0286e3bdd3f96c15361e2dedeb81d163e15c17c1c221f1f4c3f2f7aed09d511b27"),
+            (vec![0x61], "03991b07a2fb4ed8ca1f2f39257781b583b837ba3f8295d82f5d2e18fc59760cb9"),
+            (vec![0x00], "03f7c4d1eec52d1e2805b44330773e5daa7d0c9f358cb89e96315b9d567d7b7b1f"),
+            (long_input(), "034ef99330005cf81685678abfeb36354865350a66c2bf9dd4b924efdbddf9c9aa"),
+        ];
+        for (input, expected) in &vectors {
+            let point = hash(input);
+            assert_eq!(point.serialize_compressed()[..], hex(expected)[..], "input of {} bytes", input.len());
+            assert_eq!(to_zp(&point), point.x());
+        }
+    }
+
+    #[test]
+    fn matches_definition() {
+        #[cfg(feature = "std")]
+        assert_eq!(super::WINDOWS * 4, 252);
+        for len in &[0, 1, SEGMENT_LEN - 1, SEGMENT_LEN, SEGMENT_LEN + 1, 100] {
+            let input = (0..*len).map(|i| i as u8 ^ 0xA5).collect::<Vec<_>>();
+            assert_eq!(hash(&input), naive(&input), "length {}", len);
+        }
+        let input = long_input();
+        assert_eq!(hash(&input), naive(&input));
+        // more segments than cached
+        let input = input.iter().chain(&input).copied().collect::<Vec<_>>();
+        assert_eq!(hash(&input), naive(&input));
+        // zero bytes and the length are significant
+        assert_ne!(hash(&[]), hash(&[0]));
+        assert_ne!(hash(&[0]), hash(&[0, 0]));
+        assert_ne!(hash(&[0; SEGMENT_LEN]), hash(&[0; SEGMENT_LEN + 1]));
+    }
+
+    #[test]
+    fn single_bit_flips_change_output() {
+        let input = (0..70).map(|i| i as u8).collect::<Vec<_>>();
+        let original = hash(&input);
+        for bit in 0..input.len() * 8 {
+            let mut flipped = input.clone();
+            flipped[bit / 8] ^= 1 << (bit % 8);
+            assert_ne!(hash(&flipped), original, "bit {}", bit);
+        }
+    }
+}
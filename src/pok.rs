@@ -0,0 +1,154 @@
+//! Non-interactive proofs of knowledge of a secret key
+//!
+//! The proof is a Schnorr signature of the context string with a challenge hash separate from
+//! BIP340 so it can't be replayed as a signature of a message and vice versa. The prover picks
+//! nonce `k`, computes `R = k·G`, the challenge
+//! `e = Scalar::hash_to_scalar("toy-secp256k1/PoK", [P, R, context])` with the points compressed
+//! and `s = k + e·x`. The proof is serialized as 64 bytes `e || s`.
+
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use crate::nonce::derive_proof_nonce;
+use crate::sigma::SigmaStatement;
+use crate::transcript::Transcript;
+use crate::{array_ref_32, Error, Keypair, Point, PublicKey, Scalar, G};
+
+const CHALLENGE_TAG: &str = "toy-secp256k1/PoK";
+const NONCE_TAG: &str = "toy-secp256k1/PoK/nonce";
+
+/// Proof of knowledge of the secret key bound to a context
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PokProof {
+    e: Scalar,
+    s: Scalar,
+}
+
+impl PokProof {
+    /// Parses `e || s`
+    ///
+    /// Returns `Error::InvalidSignature` if any scalar is not less than the curve order.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Result<Self, Error> {
+        let e = Scalar::from_be_bytes(array_ref_32(&bytes[..32])).ok_or(Error::InvalidSignature)?;
+        let s = Scalar::from_be_bytes(array_ref_32(&bytes[32..])).ok_or(Error::InvalidSignature)?;
+        Ok(PokProof { e, s })
+    }
+
+    pub fn serialize(&self) -> [u8; 64] {
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&self.e.to_be_bytes());
+        bytes[32..].copy_from_slice(&self.s.to_be_bytes());
+        bytes
+    }
+}
+
+fn challenge(public_key: &PublicKey, big_r: &Point, context: &[u8]) -> Scalar {
+    Scalar::hash_to_scalar(CHALLENGE_TAG, &[&public_key.serialize(), &big_r.serialize_compressed(), context])
+}
+
+/// Proves the knowledge of the secret key of `keypair`.
+///
+/// `context` should identify the protocol session so that the proof can't be reused elsewhere.
+pub fn prove<R: RngCore + CryptoRng>(keypair: &Keypair, context: &[u8], rng: &mut R) -> PokProof {
+    prove_with_nonce(keypair, context, Scalar::random(rng))
+}
+
+/// Proves the same statement as `prove` but derives the nonce from the secret key, the public
+/// key and `context` using `derive_proof_nonce`.
+///
+/// The same inputs always produce the same proof.
+pub fn prove_deterministic(keypair: &Keypair, context: &[u8]) -> PokProof {
+    let x = keypair.secret_key().to_scalar();
+    let mut transcript = keypair.public_key().serialize().to_vec();
+    transcript.extend_from_slice(context);
+    prove_with_nonce(keypair, context, derive_proof_nonce(&x, &transcript, NONCE_TAG))
+}
+
+fn prove_with_nonce(keypair: &Keypair, context: &[u8], k: Scalar) -> PokProof {
+    let e = challenge(keypair.public_key(), &(G * k), context);
+    PokProof { e, s: k + e * keypair.secret_key().to_scalar() }
+}
+
+/// Checks that the prover knows the secret key of `public_key`
+pub fn verify(public_key: &PublicKey, context: &[u8], proof: &PokProof) -> bool {
+    let big_r = G * proof.s + -(public_key.to_point() * proof.e);
+    challenge(public_key, &big_r, context) == proof.e
+}
+
+/// Knowledge of `x` such that `public = x·base`, for composing proofs using `sigma`
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Statement {
+    base: Point,
+    public: Point,
+}
+
+impl Statement {
+    /// Knowledge of the secret key of `public_key`
+    pub fn new(public_key: &PublicKey) -> Self {
+        Self::with_base(G, public_key.to_point())
+    }
+
+    pub fn with_base(base: Point, public: Point) -> Self {
+        Statement { base, public }
+    }
+}
+
+impl SigmaStatement for Statement {
+    fn append_to_transcript(&self, transcript: &mut Transcript) {
+        transcript.append_point("pok/base", &self.base);
+        transcript.append_point("pok/public", &self.public);
+    }
+
+    fn commit(&self, nonce: &Scalar) -> Vec<Point> {
+        alloc::vec![self.base * *nonce]
+    }
+
+    fn simulate(&self, challenge: &Scalar, response: &Scalar) -> Vec<Point> {
+        alloc::vec![self.base * *response + -(self.public * *challenge)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{prove, prove_deterministic, verify, PokProof};
+    use crate::test_utils::DeterministicRng;
+    use crate::{schnorr, Error, Keypair, Message, SecretKey};
+
+    #[test]
+    fn binds_key_and_context() {
+        let mut rng = DeterministicRng::new(1);
+        let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap());
+        let other = SecretKey::from_bytes(&[0x43; 32]).unwrap().public_key();
+        let proof = prove(&keypair, b"session 1", &mut rng);
+        assert!(verify(keypair.public_key(), b"session 1", &proof));
+        assert!(!verify(keypair.public_key(), b"session 2", &proof));
+        assert!(!verify(&other, b"session 1", &proof));
+        assert_eq!(PokProof::from_bytes(&proof.serialize()), Ok(proof));
+        assert_eq!(PokProof::from_bytes(&[0xFF; 64]), Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn not_a_signature() {
+        let mut rng = DeterministicRng::new(2);
+        let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap());
+        let (x_only, _) = keypair.x_only_public_key();
+        let context = [0x07; 32];
+        let proof = prove(&keypair, &context, &mut rng);
+        assert_eq!(schnorr::verify(&Message::from_digest(context), &proof.serialize(), &x_only), Err(Error::InvalidSignature));
+
+        // and a signature is not a proof
+        let signature = schnorr::sign(&Message::from_digest(context), &keypair, &[0; 32]);
+        let proof = PokProof::from_bytes(&signature).unwrap();
+        assert!(!verify(keypair.public_key(), &context, &proof));
+    }
+
+    #[test]
+    fn deterministic() {
+        let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap());
+        let proof = prove_deterministic(&keypair, b"session 1");
+        assert_eq!(prove_deterministic(&keypair, b"session 1"), proof);
+        assert!(verify(keypair.public_key(), b"session 1", &proof));
+        let other = prove_deterministic(&keypair, b"session 2");
+        assert_ne!(other.serialize()[32..], proof.serialize()[32..]);
+        assert!(verify(keypair.public_key(), b"session 2", &other));
+    }
+}
@@ -0,0 +1,122 @@
+//! Proofs of possession of secret keys
+//!
+//! Naive key aggregation `P = Σ Pᵢ` is vulnerable to rogue-key attacks: the last participant can
+//! pick `P' - Σ Pᵢ` as its key and control the aggregate alone. Requiring every participant to
+//! prove possession of the secret key of its own key prevents this.
+//!
+//! The proof is a Schnorr signature of the public key with a dedicated challenge hash so it
+//! can't be confused with signatures of messages: the prover derives nonce `k` using
+//! `derive_proof_nonce`, computes `R = k·G`, the challenge `e` from a `Transcript` with domain
+//! `toy-secp256k1/PoP` and `P` and `R` appended under labels `P` and `R` and `s = k + e·x`. The
+//! proof is serialized as 64 bytes `e || s`.
+
+use crate::nonce::derive_proof_nonce;
+use crate::transcript::Transcript;
+use crate::{array_ref_32, Error, Keypair, Point, PublicKey, Scalar, G};
+
+const CHALLENGE_TAG: &str = "toy-secp256k1/PoP";
+const NONCE_TAG: &str = "toy-secp256k1/PoP/nonce";
+
+/// Proof of possession of the secret key of a public key
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PopProof {
+    e: Scalar,
+    s: Scalar,
+}
+
+impl PopProof {
+    /// Parses `e || s`
+    ///
+    /// Returns `Error::InvalidSignature` if any scalar is not less than the curve order.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Result<Self, Error> {
+        let e = Scalar::from_be_bytes(array_ref_32(&bytes[..32])).ok_or(Error::InvalidSignature)?;
+        let s = Scalar::from_be_bytes(array_ref_32(&bytes[32..])).ok_or(Error::InvalidSignature)?;
+        Ok(PopProof { e, s })
+    }
+
+    pub fn serialize(&self) -> [u8; 64] {
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&self.e.to_be_bytes());
+        bytes[32..].copy_from_slice(&self.s.to_be_bytes());
+        bytes
+    }
+}
+
+fn challenge(public_key: &PublicKey, big_r: &Point) -> Scalar {
+    let mut transcript = Transcript::new(CHALLENGE_TAG);
+    transcript.append_point("P", &public_key.to_point());
+    transcript.append_point("R", big_r);
+    transcript.challenge_scalar("e")
+}
+
+/// Proves possession of the secret key of `keypair`.
+///
+/// The proof is deterministic.
+pub fn prove(keypair: &Keypair) -> PopProof {
+    let x = keypair.secret_key().to_scalar();
+    let k = derive_proof_nonce(&x, &keypair.public_key().serialize(), NONCE_TAG);
+    let e = challenge(keypair.public_key(), &(G * k));
+    PopProof { e, s: k + e * x }
+}
+
+/// Checks that the proof was made by the owner of `public_key`
+pub fn verify(public_key: &PublicKey, proof: &PopProof) -> bool {
+    let big_r = G * proof.s + -(public_key.to_point() * proof.e);
+    challenge(public_key, &big_r) == proof.e
+}
+
+/// Finds the index of the first key with invalid proof.
+///
+/// Returns `None` if all proofs are valid and the keys can be safely passed to
+/// `PublicKey::combine`.
+pub fn find_invalid(items: &[(PublicKey, PopProof)]) -> Option<usize> {
+    items.iter().position(|(public_key, proof)| !verify(public_key, proof))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_invalid, prove, verify, PopProof};
+    use crate::{schnorr, Error, Keypair, Message, PublicKey, SecretKey};
+
+    fn keypair(byte: u8) -> Keypair {
+        Keypair::from_secret_key(&SecretKey::from_bytes(&[byte; 32]).unwrap())
+    }
+
+    #[test]
+    fn bound_to_key() {
+        let keypair = keypair(0x42);
+        let proof = prove(&keypair);
+        assert_eq!(prove(&keypair), proof);
+        assert!(verify(keypair.public_key(), &proof));
+        assert!(!verify(self::keypair(0x43).public_key(), &proof));
+        assert_eq!(PopProof::from_bytes(&proof.serialize()), Ok(proof));
+        assert_eq!(PopProof::from_bytes(&[0xFF; 64]), Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn not_a_signature() {
+        let keypair = keypair(0x42);
+        let (x_only, _) = keypair.x_only_public_key();
+        let msg = Message::from_digest(x_only.serialize());
+        assert_eq!(schnorr::verify(&msg, &prove(&keypair).serialize(), &x_only), Err(Error::InvalidSignature));
+
+        let signature = schnorr::sign(&msg, &keypair, &[0; 32]);
+        assert!(!verify(keypair.public_key(), &PopProof::from_bytes(&signature).unwrap()));
+    }
+
+    #[test]
+    fn rogue_key() {
+        let honest = [keypair(0x42), keypair(0x43)];
+        let mut items = honest.iter().map(|keypair| (*keypair.public_key(), prove(keypair))).collect::<Vec<_>>();
+        assert_eq!(find_invalid(&items), None);
+
+        // the attacker wants the aggregate to be its own key but can't prove possession
+        let attacker = keypair(0x44);
+        let honest_sum = PublicKey::combine(&[*honest[0].public_key(), *honest[1].public_key()]).unwrap();
+        let rogue = PublicKey::from_point(attacker.public_key().to_point() + -honest_sum.to_point()).unwrap();
+        items.insert(1, (rogue, prove(&attacker)));
+        let keys = items.iter().map(|(public_key, _)| *public_key).collect::<Vec<_>>();
+        assert_eq!(PublicKey::combine(&keys), Ok(*attacker.public_key()));
+        assert_eq!(find_invalid(&items), Some(1));
+    }
+}
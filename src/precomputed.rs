@@ -0,0 +1,128 @@
+//! Fixed-base multiplication of `G` using a table compiled into the binary
+//!
+//! `G_TABLE[i][j]` is `(j + 1)·16ⁱ·G` so `k·G` is the sum of one entry per 4-bit digit of `k` -
+//! 64 additions and no doublings. The table (60 KiB) lives in `table.rs` which is generated by
+//! `cargo test --features precomputed-tables -- --ignored regenerate_g_table` and checked against
+//! the runtime computation by the tests.
+//!
+//! With the `subtle` feature every lookup reads the whole row so that memory access doesn't depend
+//! on the digits of the (secret) scalar.
+
+mod table;
+
+use crate::{Point, Scalar};
+use table::G_TABLE;
+
+/// Number of 4-bit digits of a scalar
+const WINDOWS: usize = 64;
+/// Non-zero values of a digit
+const ENTRIES: usize = 15;
+
+/// Computes `k·G` using the table. **NOT CONSTANT TIME!!!**
+pub(crate) fn mul_gen(k: &Scalar) -> Point {
+    let bytes = k.to_be_bytes();
+    let mut res = Point::AT_INFINITY;
+    for (i, byte) in bytes.iter().rev().enumerate() {
+        for (j, digit) in [byte & 0x0F, byte >> 4].iter().enumerate() {
+            res += lookup(&G_TABLE[i * 2 + j], *digit);
+        }
+    }
+    res
+}
+
+/// Returns `row[digit - 1]`, the point at infinity if `digit` is zero
+#[cfg(feature = "subtle")]
+fn lookup(row: &[Point; ENTRIES], digit: u8) -> Point {
+    // zero wraps around to an index out of range
+    Point::ct_select(row, usize::from(digit).wrapping_sub(1))
+}
+
+/// Returns `row[digit - 1]`, the point at infinity if `digit` is zero
+#[cfg(not(feature = "subtle"))]
+fn lookup(row: &[Point; ENTRIES], digit: u8) -> Point {
+    match digit {
+        0 => Point::AT_INFINITY,
+        _ => row[usize::from(digit) - 1],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Write;
+    use super::{mul_gen, ENTRIES, G_TABLE, WINDOWS};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Point, Scalar, G, U256};
+
+    fn compute_table() -> Vec<[Point; ENTRIES]> {
+        let mut base = G;
+        (0..WINDOWS)
+            .map(|_| {
+                let mut row = [base; ENTRIES];
+                for j in 1..ENTRIES {
+                    row[j] = row[j - 1] + base;
+                }
+                base = row[ENTRIES - 1] + base;
+                row
+            })
+            .collect()
+    }
+
+    fn limbs(value: &U256) -> String {
+        value.0.iter().map(|limb| format!("0x{:08X}_{:08X}", limb >> 32, limb & 0xFFFF_FFFF)).collect::<Vec<_>>().join(", ")
+    }
+
+    #[test]
+    fn embedded_table_matches_runtime() {
+        let table = compute_table();
+        for (i, (embedded, computed)) in G_TABLE.iter().zip(&table).enumerate() {
+            for (j, (embedded, computed)) in embedded.iter().zip(computed).enumerate() {
+                assert_eq!(embedded.serialize_uncompressed(), computed.serialize_uncompressed(), "entry [{}][{}]", i, j);
+            }
+        }
+    }
+
+    #[test]
+    fn agrees_with_generic_multiplication() {
+        let mut rng = DeterministicRng::new(195);
+        let edge_cases = [Scalar::ZERO, Scalar::ONE, -Scalar::ONE, Scalar::from(15), Scalar::from(16), Scalar::from(u64::MAX)];
+        let random = (0..256).map(|_| Scalar::random(&mut rng));
+        for k in edge_cases.iter().copied().chain(random) {
+            assert_eq!(mul_gen(&k), G * k, "{:?}", k.to_be_bytes());
+        }
+    }
+
+    /// Every digit value in every window
+    #[test]
+    fn all_digit_values() {
+        for digit in 0..16u8 {
+            let mut bytes = [digit * 0x11; 32];
+            // stays below the order
+            bytes[0] = digit;
+            let k = Scalar::from_be_bytes(&bytes).unwrap();
+            assert_eq!(mul_gen(&k), G * k, "digit {}", digit);
+        }
+    }
+
+    /// Rewrites `table.rs`, run this after changing the table layout
+    #[test]
+    #[ignore]
+    fn regenerate_g_table() {
+        let mut source = String::from("//! Generated by `cargo test --features precomputed-tables -- --ignored regenerate_g_table`, DO NOT EDIT\n\n");
+        source.push_str("use crate::{Point, Zp, U256};\n");
+        source.push_str("use super::{ENTRIES, WINDOWS};\n\n");
+        source.push_str("macro_rules! point {\n");
+        source.push_str("    ($x0:expr, $x1:expr, $x2:expr, $x3:expr; $y0:expr, $y1:expr, $y2:expr, $y3:expr) => {\n");
+        source.push_str("        Point { x: Zp::from_reduced(U256([$x0, $x1, $x2, $x3])), y: Zp::from_reduced(U256([$y0, $y1, $y2, $y3])) }\n");
+        source.push_str("    }\n}\n\n");
+        source.push_str("pub(super) static G_TABLE: [[Point; ENTRIES]; WINDOWS] = [\n");
+        for row in compute_table() {
+            source.push_str("    [\n");
+            for point in &row {
+                writeln!(source, "        point!({}; {}),", limbs(&point.x.0), limbs(&point.y.0)).unwrap();
+            }
+            source.push_str("    ],\n");
+        }
+        source.push_str("];\n");
+        std::fs::write(concat!(env!("CARGO_MANIFEST_DIR"), "/src/precomputed/table.rs"), source).unwrap();
+    }
+}
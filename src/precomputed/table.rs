@@ -0,0 +1,1101 @@
+//! Generated by `cargo test --features precomputed-tables -- --ignored regenerate_g_table`, DO NOT EDIT
+
+use crate::{Point, Zp, U256};
+use super::{ENTRIES, WINDOWS};
+
+macro_rules! point {
+    ($x0:expr, $x1:expr, $x2:expr, $x3:expr; $y0:expr, $y1:expr, $y2:expr, $y3:expr) => {
+        Point { x: Zp::from_reduced(U256([$x0, $x1, $x2, $x3])), y: Zp::from_reduced(U256([$y0, $y1, $y2, $y3])) }
+    }
+}
+
+pub(super) static G_TABLE: [[Point; ENTRIES]; WINDOWS] = [
+    [
+        point!(0x59F2815B_16F81798, 0x029BFCDB_2DCE28D9, 0x55A06295_CE870B07, 0x79BE667E_F9DCBBAC; 0x9C47D08F_FB10D4B8, 0xFD17B448_A6855419, 0x5DA4FBFC_0E1108A8, 0x483ADA77_26A3C465),
+        point!(0xABAC09B9_5C709EE5, 0x5C778E4B_8CEF3CA7, 0x3045406E_95C07CD8, 0xC6047F94_41ED7D6D; 0x236431A9_50CFE52A, 0xF7F63265_3266D0E1, 0xA3C58419_466CEAEE, 0x1AE168FE_A63DC339),
+        point!(0x8601F113_BCE036F9, 0xB531C845_836F99B0, 0x49344F85_F89D5229, 0xF9308A01_9258C310; 0x6CB9FD75_84B8E672, 0x6500A999_34C2231B, 0x0FE337E6_2A37F356, 0x388F7B0F_632DE814),
+        point!(0x74FA94AB_E8C4CD13, 0xCC6C1390_0EE07584, 0x581E4904_930B1404, 0xE493DBF1_C10D80F3; 0xCFE97BDC_47739922, 0xD967AE33_BFBDFE40, 0x5642E209_8EA51448, 0x51ED993E_A0D455B7),
+        point!(0xCBA8D569_B240EFE4, 0xE88B84BD_DC619AB7, 0x55B4A725_0A5C5128, 0x2F8BDE4D_1A072093; 0xDCA87D3A_A6AC62D6, 0xF788271B_AB0D6840, 0xD4DBA9DD_A6C9C426, 0xD8AC2226_36E5E3D6),
+        point!(0x2F057A14_60297556, 0x82F6472F_8568A18B, 0x20453A14_355235D3, 0xFFF97BD5_755EEEA4; 0x3C870C36_B075F297, 0xDE80F0F6_518FE4A0, 0xF3BE9601_7F45C560, 0xAE12777A_ACFBB620),
+        point!(0xE92BDDED_CAC4F9BC, 0x3D419B7E_0330E39C, 0xA398F365_F2EA7A0E, 0x5CBDF064_6E5DB4EA; 0xA5082628_087264DA, 0xA813D0B8_13FDE7B5, 0xA3178D6D_861A54DB, 0x6AEBCA40_BA255960),
+        point!(0x67784EF3_E10A2A01, 0x0A1BDD05_E5AF888A, 0xAFF3843F_B70F3C2F, 0x2F01E5E1_5CCA351D; 0xB5DA2CB7_6CBDE904, 0xC2E213D6_BA5B7617, 0x293D082A_132D13B4, 0x5C4DA8A7_41539949),
+        point!(0xC35F110D_FC27CCBE, 0xE0979697_4C57E714, 0x09AD178A_9F559ABD, 0xACD484E2_F0C7F653; 0x05CC262A_C64F9C37, 0xADD888A4_375F8E0F, 0x64380971_763B61E9, 0xCC338921_B0A7D9FD),
+        point!(0x52A68E2A_47E247C7, 0x3442D49B_1943C2B7, 0x35477C7B_1AE6AE5D, 0xA0434D9E_47F3C862; 0x3CBEE53B_037368D7, 0x6F794C2E_D877A159, 0xA3B6C7E6_93A24C69, 0x893ABA42_5419BC27),
+        point!(0xBBEC1789_5DA008CB, 0x5649980B_E5C17891, 0x5EF4246B_70C65AAC, 0x774AE7F8_58A9411E; 0x301D74C9_C953C61B, 0x372DB1E2_DFF9D6A8, 0x0243DD56_D7B7B365, 0xD984A032_EB6B5E19),
+        point!(0xC5B0F470_70AFE85A, 0x687CF441_9620095B, 0x15C38F00_4D734633, 0xD01115D5_48E7561B; 0x6B051B13_F4062327, 0x79238C5D_D9A86D52, 0xA8B64537_E17BD815, 0xA9F34FFD_C815E0D7),
+        point!(0xDEEDDF8F_19405AA8, 0xB075FBC6_610E58CD, 0xC7D1D205_C3748651, 0xF28773C2_D975288B; 0x29B5CB52_DB03ED81, 0x3A1A06DA_521FA91F, 0x758212EB_65CDAF47, 0x0AB0902E_8D880A89),
+        point!(0xE49B241A_60E823E4, 0x26AA7B63_678949E6, 0xFD64E67F_07D38E32, 0x499FDF9E_895E719C; 0xC65F40D4_03A13F5B, 0x464279C2_7A3F95BC, 0x90F044E4_A7B3D464, 0xCAC2F6C4_B54E8551),
+        point!(0x44ADBCF8_E27E080E, 0x31E5946F_3C85F79E, 0x5A465AE3_095FF411, 0xD7924D4F_7D43EA96; 0xC504DC9F_F6A26B58, 0xEA40AF2B_D896D3A5, 0x83842EC2_28CC6DEF, 0x581E2872_A86C72A6),
+    ],
+    [
+        point!(0xC44EE89E_2A6DEC0A, 0xB2A31369_B87A5AE9, 0x3011AABC_21C23E97, 0xE60FCE93_B59E9EC5; 0xE1F32CCE_69616821, 0x1296891E_44D23F0B, 0x9DB99F34_F5793710, 0xF7E35073_99E59592),
+        point!(0x75D0DBD4_07143E65, 0xDACFFCB8_9904A61D, 0x47B6E054_E2F378CE, 0xD30199D7_4FB5A22D; 0x05B3FF1F_24106AB9, 0x1F760CC3_64ED8196, 0xB3D6DEC9_E9838065, 0x95038D9D_0AE3D5C3),
+        point!(0x9BD870AA_1118E5C3, 0xFC579B27_452BEBC1, 0xB441656E_F4E65B4B, 0x6ECA335D_9645307D; 0x498A2F78_05A08668, 0x3A496A3A_3BF8EC34, 0x592F5790_74B875A0, 0xD50123B5_7A7A0710),
+        point!(0xE37918E6_F874EF8B, 0xFC4C6F1D_CDBAFD81, 0x0B1051EA_F832823C, 0xBF23C154_2D16EAB7; 0x4DC37EFE_66831D9F, 0xC522FC54_811E2F78, 0x7AD928A0_BA5392E4, 0x5CB3866F_C3300373),
+        point!(0x0ECD31E1_4F87F62E, 0x10E6E638_63716127, 0x0D7C744E_D34659F0, 0xE9623BBE_F1BF90EC; 0x53013EAF_A44EE737, 0xFE6043C9_DD68844E, 0xE0FE953A_8EDAA929, 0x38A9743B_4BC299E9),
+        point!(0x43933ACA_7F8CB0E3, 0xA22EB53F_E1EFE3A4, 0x8FA64E04_4B2EB72E, 0x3F0E80E5_74456D8F; 0xCB0289E2_EA5F404F, 0x9501253A_A65B53A4, 0xE90B9C08_485D01B3, 0xCB66D7D7_296CBC91),
+        point!(0xEB0AADF8_2A8D733C, 0xFFC274BF_62FCA8F9, 0x0884A36F_2080D682, 0xBC82DD73_E5161DBA; 0x1E786104_F47797F0, 0xAE93A0BA_E7389730, 0x54A9B4BF_719F02DF, 0xE5F28C3A_044B1CAC),
+        point!(0x64707745_6769A24E, 0xBCF55CD7_00535655, 0x696C3D09_F7D1671C, 0x34FF3BE4_033F7A06; 0x8491067A_73CC2F1A, 0x55DF16C3_E8F8B681, 0x3F6619D8_9832098C, 0x5D9D1162_3A236C55),
+        point!(0xD7EFE231_5FBC7671, 0x743F1BC8_52858E32, 0xD20291CE_1798F490, 0x8E3D1248_C7657211; 0x7EF1DC64_18717DEC, 0xB9352BAA_A63E144A, 0xF64480E1_9393E90E, 0x099A48E1_0ECFCB81),
+        point!(0x05EC32AD_51B03F6C, 0xA4D04712_2A9B184B, 0x2BC77683_8F73F576, 0x308913A2_7A52D922; 0x60AB5EFC_E8FE4C67, 0xA8333FEA_82BD1F12, 0x91E3531F_66C0375D, 0xF4A5B095_43FEBE5F),
+        point!(0xF3287432_BEB31DB2, 0x8FCAE827_88F506A0, 0x896A193E_D088A2B6, 0x78A891AA_2234A498; 0x3069D623_B9FA4343, 0x54379BCD_D800B82D, 0xFCF5F255_27302DF6, 0x6912A35B_EB5035CB),
+        point!(0xC7B750F7_33CE1752, 0xE783C797_D7CD204E, 0x812DDF64_D99C9AEA, 0xD7A0DA58_D01DC635; 0xBBC02738_0762CEF4, 0x0BE040A8_C062B742, 0xF6F29283_40E28465, 0x912770E0_68008032),
+        point!(0x65348F77_8DB0E595, 0xA7163CB9_FBA082BB, 0xD7CE3765_816076EB, 0x7D867818_55DB1B17; 0x99951E3A_BC733DE8, 0x2937844E_0E25D532, 0x2E562E2B_ED4F8838, 0xE2B99ADF_EC86F877),
+        point!(0x609C4570_6CE6B514, 0x890905C7_9B357322, 0x8885C356_00844D49, 0x08BC89C2_F919ED15; 0x6F63F4CE_A8C95157, 0x172D3056_112776F0, 0xDE776FEC_3B5892C1, 0xD313F3CD_D7CDCC16),
+        point!(0x008FEF85_16060DFC, 0x76545F84_205E6A2A, 0x48494B9D_C41AB086, 0xDDC5310F_00582AC8; 0xFB5F8AB6_E7820CA8, 0x41DBAFC6_ABD04730, 0x0191AB6D_CC8F0E90, 0xBA0D2F3A_F20D9692),
+    ],
+    [
+        point!(0x0646E23F_D5F51508, 0xD8C39CAB_D5AC1CA1, 0xEA2A6E3E_172DE238, 0x82822632_12C609D9; 0xD31B6EAF_F6E26CAF, 0x62D613AC_2F7B17BE, 0x5E8256E8_30B60ACE, 0x11F8A809_8557DFE4),
+        point!(0x926E2C00_EF34A24D, 0x0ADBC968_D9E159D0, 0x905A857A_9CF918D5, 0x465370B2_87A79FF3; 0xA2F8FB20_B33887F4, 0x588E09B2_15D37A10, 0xA4AF8BDA_FDEEC2C1, 0x35E531B3_8368C082),
+        point!(0xFCFC0CB9_E57E8DFA, 0x09809191_A3C7E184, 0x0D9A30F8_ACA98CA0, 0x8262CF2F_F0799C4C; 0x35CFF8D8_FBAC376A, 0x57B6ED33_2B14C478, 0x66FEE22E_C5B34F34, 0x83FD95E2_09109E4E),
+        point!(0xD5B901B2_E285131F, 0xAAEC6ECD_C813B088, 0xD664A18F_66AD6240, 0x241FEBB8_E23CBD77; 0xABB3E66F_2750026D, 0xCD50FD0F_BD0CB5AF, 0xD6C420BD_13981DF8, 0x513378D9_FF94F8D3),
+        point!(0x9052E48B_026BDB6F, 0x7CA41BD4_7B734B94, 0x168105B2_4CE99C87, 0x19825C8B_1DA0DDD5; 0xB5DF7084_C49CFC9B, 0xEBE9EECC_8CF6D3A6, 0x320261CC_94F59F6C, 0x6294310F_0D4C878F),
+        point!(0xD17CC1F2_7C70620C, 0x4998C4BE_ABC288D9, 0xC60DD31A_2B671780, 0x1653A8A4_8D2C236D; 0x6CA2E81D_315B32CD, 0x012AF748_DFD3DC52, 0xEAFA9947_4EFA701C, 0x03382909_35AF7F7A),
+        point!(0x50ED0952_3D82824C, 0xDFA58E34_5E1534E6, 0x43C5F56E_C6C2999E, 0x6F12D86C_11601914; 0x8579C348_06EB34D0, 0x391C9241_0854BC5B, 0x875994F3_FD623769, 0x5C4FF7F4_4AB3BFA0),
+        point!(0xEDCB6306_9B920471, 0xFC318B85_F423DE0D, 0xFCE4CC29_83D8F8D9, 0x5D1BDB4E_A172FA79; 0x70330666_F7B83103, 0x79EB1E99_96C56E7B, 0x794BB994_38A22656, 0x28438267_79379E2E),
+        point!(0x076DDC02_DB453629, 0x45CFDCDC_0F59E603, 0x14262716_A23ABEF6, 0x203A8C6F_9A0AAA5D; 0x5971C785_3FF89F84, 0x686DEB25_C5285ED4, 0x36CC76D1_5F410612, 0x3B0F0B53_DE5DD9B9),
+        point!(0xB9446557_B147F3D6, 0x55319921_7AB8CB45, 0x19E6A363_24655103, 0x474A4732_C294B1E1; 0xDCEE8528_28628BA4, 0x11FA7F17_010E1300, 0xED2CC30C_C3A48AA3, 0x94625E23_1206F04E),
+        point!(0x783130DD_E46C7ECB, 0x9D3AED3F_E5BB4CE9, 0xC81EF733_4866E1EC, 0x6E2ACAEB_3D034181; 0x8449201E_EEBC8720, 0xFAC706B9_1B67512C, 0x9D80C4DA_F0FB643A, 0x9E61A467_97EFEE14),
+        point!(0x47D42BA6_E71DABCD, 0x54D3FE49_89E5CB4F, 0x6098AE32_60B5373F, 0xD49EE4FB_6B63F43C; 0xE66A90CF_016603C2, 0x129C5093_12FF7031, 0xD7C87EA7_A61BF356, 0x0531E392_09A5490D),
+        point!(0xEB7018F3_F0A4147E, 0x2747DD8B_F775F201, 0xAF61717E_8A490A58, 0xD5A70492_E9E9156B; 0x8E4B3CB8_33FB65FF, 0xF4C4D984_7967F57F, 0xFC490990_765E0532, 0x9DB526F5_DBAB89C6),
+        point!(0x63DA8F68_B43841C6, 0x12AA3CC0_D343EFBD, 0x49BB6B52_DCEBF4C2, 0x6CC1109E_03DF0899; 0xB9733A46_B954E94D, 0xD79B433E_2D906CF5, 0x0FFD3D6B_0A29FFEC, 0xAF561AFE_9C094B76),
+        point!(0x4EDDB46B_D8EDCEC6, 0xA1061CC2_D3634F45, 0x0523EFC6_302CDAC6, 0x38C5119A_ABE18BA8; 0x456E0BFB_1933DB08, 0x28A16C82_2B5530A6, 0x668CB2DA_275F282F, 0xE649DD22_85D9732A),
+    ],
+    [
+        point!(0x73FCE5B5_51E5B739, 0xE0B93833_FD2222ED, 0x72F99CC6_C6FC846D, 0x175E159F_728B865A; 0x6EFA6FFE_E9FED695, 0xACB5955A_DD24345C, 0xA4EF97A5_1FF71F5E, 0xD3506E0D_9E3C79EB),
+        point!(0xCE78049E_46BC47D6, 0x30FDFEB5_C6DA121B, 0xA5FFBCC8_E139C621, 0x423A013F_03FF32D7; 0xD1236E6D_8B548A34, 0x720D8EC3_524F009E, 0xA1179F7B_BAF6B3C7, 0xB91AE00F_E1E1D970),
+        point!(0x65B7F8F1_C5041216, 0x3F7335F6_842B836A, 0x128B59EF_DC2FED52, 0xDA75317B_21F7ACF4; 0xDAED3298_6E708572, 0xE9AAC07A_E77ACEDA, 0xDF19E21B_342D7FC6, 0x73F8A046_BF72D5F0),
+        point!(0x302DF6F7_8416824A, 0x49DF662F_3B3E2741, 0x08907A7A_BCD68776, 0x111D6A45_AC1FB905; 0xA9A111D4_2108E9D0, 0xF0700089_96DACA4C, 0xB90D48DB_FF065952, 0x0696911C_478EAFFB),
+        point!(0xB0143E71_E465A930, 0x2587F1C1_6B1352FD, 0x0573C58C_4A82EB1E, 0x1C71C5B4_8E9749D7; 0x1D873F6C_C34638B5, 0x79345E3F_B7174D47, 0x2713F1F2_824BB68C, 0x4A91C334_E8F5FA0C),
+        point!(0x9505324F_3C62BAC0, 0x19150DDF_51F0AB06, 0x1364B7D2_C3E8B70E, 0x9530F0F9_023F469C; 0x478ABDA9_7618E309, 0xE25B3285_2F1FDC68, 0x34DD2F7F_59B333E0, 0x8F3C305A_8F9F21E2),
+        point!(0xE318DAE5_BADB6EE7, 0x7850DD43_2744A077, 0x936E837A_909B4C9C, 0xD84E4AFC_1F31A566; 0x82D556E6_D42EBED2, 0xFDD8AC97_4AA3E649, 0x12A38D58_F565DE4D, 0xE525809A_7C7B79CE),
+        point!(0xAB5EDDE0_1BCED775, 0x7290B68A_5EF74E56, 0xAD795DBE_BCB9DCFF, 0x4A4A6DC9_7AC7C8B8; 0xB2BF8F68_A78DD66D, 0x1DE90CDB_424742AC, 0x943EF9F7_39C0F457, 0x529911B0_16631E72),
+        point!(0x394E9ECF_A49E6D10, 0xF60ADFF3_AEAC99D2, 0x41B22B92_85FF6ED3, 0xF3D4444B_DE66814D; 0x0A038CC0_5347DA3F, 0xFC1ADA50_CE9D6C63, 0x4BAB95AC_198A4B5B, 0x0A4324DF_A6F0163D),
+        point!(0xF5E91B77_A547B4B3, 0x356929D1_FD665411, 0x1DF82EFE_E099522C, 0xF006C42F_1D8E4F75; 0xB5EF449F_A86AA74B, 0x75C933EE_D6BE7AE1, 0x5C47848A_4E4F7D16, 0xF68154D4_A666520A),
+        point!(0x48CB3406_E9D33A07, 0x4E72EE08_AA39BCAB, 0xF9085520_CDBDF404, 0xAE30652C_9D9C1D89; 0x74870C0F_60A0B2A6, 0x1C9C37F5_DFD7E95C, 0xEFCCE7D3_3DB8D5CF, 0x6CB9D9C3_8D63FE57),
+        point!(0x06704385_DC3C9C8F, 0xF2816FEE_3E4367B2, 0x6F09FF43_AAA332B0, 0x67BE02DC_BE4298FD; 0x55384998_593652D9, 0xCD993BF6_0B88C2BE, 0xA2C945B6_08291693, 0x7A9B55A7_3E4DEF84),
+        point!(0x759654B9_0C28CACA, 0x1869C7BB_CC8281DA, 0x15E17E75_83388C9A, 0xD8DC1B2A_5BD5E1C8; 0xCDB676EA_23B3EC7A, 0x7D315D2C_EC8128F6, 0x8C6D8DA4_20247D94, 0x8CEC0AD9_27CEC7D5),
+        point!(0xC5C2F974_518B01B5, 0xDDCCC88B_8710BADA, 0xC1A50C45_352405B1, 0x0E69B346_403DE885; 0x418EE368_78D965C5, 0xCEDF595F_3D00683E, 0x61DED70A_B7D7DD37, 0x0B4EFAC5_FCD7E452),
+        point!(0x8921FB66_3BC4416F, 0xA7AEFD23_F30B0402, 0xD59425A8_D032F31D, 0x2749E292_C5F84ECC; 0x7E98BDE2_FC6BBD8E, 0xF59E9FB6_5EE96105, 0x403D9499_0FCADB3E, 0x50CC2D4E_37672BC4),
+    ],
+    [
+        point!(0x526BAD8F_83FF4640, 0x53441C7E_55552FFE, 0x99CEAC05_B6262EE0, 0x363D90D4_47B00C9C; 0x62003C7F_3BEE9DE9, 0x45B9A890_08199ECB, 0x953B4453_97F33631, 0x04E273AD_FC732221),
+        point!(0xD2712A5C_AF92C541, 0x0B62FB01_2EDFB59D, 0x553973C6_C93B02BF, 0x4C1B9866_ED9A7E9B; 0x72C4F3FD_C68FE020, 0xC652EAD7_E43EB1AD, 0x7FBCB753_CE56E69C, 0xC1F792D3_20BE8A0F),
+        point!(0x4B891216_F6E55DC8, 0x6FF95AB6_EACA0439, 0xBA84A440_C0509442, 0x44314047_90C5FFB2; 0x31D944AE_DBE323B3, 0xA66A29B7_9EAA2E50, 0xFE99837F_5642FED7, 0x96B0C142_E65366F8),
+        point!(0xE0C8F28C_BEAAF3D1, 0x3233EDBC_1A28F135, 0x529A2F3C_0780B54E, 0xA4083877_BA83B12B; 0xB12B534D_F0B254B9, 0x2001E757_6ED1EF90, 0xB8BF83D6_9361B3E2, 0x40E9F612_FEEFBC79),
+        point!(0xDFE9485D_701B23A8, 0xAB7B7D47_0A87EE0A, 0x126243D5_B921089D, 0x9E22FE8D_866CA87C; 0xF7A413C5_0884EDAE, 0xC0F7C949_FB511CEC, 0x177F3F02_099C1533, 0xFD2FF0E9_CA122D10),
+        point!(0x3EB5E196_33F0E9AA, 0x68112776_B11BD34B, 0xB7924AE0_D58138D2, 0xE5380FE8_575F26AD; 0xC4BA4136_4082720F, 0x6FB94E5D_F468318E, 0x5B691363_924C8E01, 0xB97FD873_9087B41D),
+        point!(0xDD7408BF_071A70E4, 0xCD5EE51F_5F5CF475, 0x2EDD69E6_4705306D, 0x508DF6D5_03CE2A8D; 0xDF2E5BF7_29950984, 0x4EC03228_EE8AADFE, 0x777304AA_733E49C5, 0x154C439B_933BC42D),
+        point!(0x8CD3DB5A_7940D33A, 0x6E0D880A_454203B9, 0x3A4E3E1A_2F56C86F, 0xA804C641_D28CC0B5; 0x43CE95FA_6D46967A, 0x1AF18CA8_9CF736A9, 0x3DEC2842_C16047E8, 0x95BE8325_2B2FA6D0),
+        point!(0x6EAFDA76_CE1ABE11, 0xDAB921C2_59CB220F, 0x338DF581_930125B2, 0xE3DBFF84_55109763; 0x4B49AD54_9FA8DE63, 0xD3C97D90_3C6B5C41, 0x6438FA75_DB8A8EF3, 0x06F2F909_9A341421),
+        point!(0xE6929378_B63968B6, 0xF4026165_ED9E3AB2, 0x43C5A960_0890893A, 0xF7A4BE3D_FC6579FB; 0x494DDA32_D0C34882, 0x99702FB8_D110105E, 0x21D29030_38CA52D2, 0xD6DDEF6C_E823C615),
+        point!(0xDCF1B236_03593449, 0xF61B7C12_E42A46FF, 0x878D9C13_A07D014A, 0x19ACE064_C7DE940D; 0x3A414B93_ADF83631, 0xAC710B9F_4084068A, 0xBFEBD739_D9402C46, 0xE3799203_5268A333),
+        point!(0x1AED361B_EEBC61D6, 0x8A8FD3A7_D9FF42DE, 0xC395F0D1_5D6B1F51, 0x939FF3E4_A3ED9AF0; 0xE75EA466_A3F5CB70, 0x980BF26E_B78C7F82, 0x9D46FC4E_EF016C04, 0xDEAB3BCF_08B7A90E),
+        point!(0xEE3E9892_CCBA6B63, 0x0A0FE674_FD0B3BB5, 0x108C3322_8EA88E31, 0xD8740CEC_20F87DAA; 0xBEEA36C0_6934C5F3, 0x40104F5C_C381694A, 0x378A6EE9_903AC37D, 0x6472C133_C6B932BF),
+        point!(0xA34ED6E8_8A8FF2CF, 0x38EA755C_7B062094, 0x797A98DB_A24051A4, 0x019A9E5F_1C4CAE30; 0x3A77E766_DB0E4352, 0x0CE7EBEE_88486D35, 0xFA906292_5012B730, 0x1E661C67_EA85AF5E),
+        point!(0x6194FF2C_1B3EC038, 0xD9FD516A_E60C6B27, 0x64883E76_2F290A50, 0x58AC3339_1B506083; 0x16EAA3F1_10246279, 0x0219043C_0B4FBBCA, 0x78779249_E991FE97, 0x9163D706_D55C92D9),
+    ],
+    [
+        point!(0x69F79A55_DFFDF80C, 0x43E4A781_A15BCD1B, 0x8C6244B5_B7456388, 0x8B4B5F16_5DF3C2BE; 0xB3EFF0C6_5FD4FD36, 0xF9E33654_6162EE56, 0xB3FBD781_3AB0DA04, 0x4AAD0A6F_68D308B4),
+        point!(0xBB3F2671_4755E4BE, 0x71AF64EE_417C997A, 0x8CE17C7E_C83C6110, 0xED0C5CE4_E1329171; 0x63F9FA6E_A07BF42F, 0x49D93925_763DDAB1, 0xBF3DAD7F_5A7EA680, 0x221A9FC7_BC2345BD),
+        point!(0xA0A2A582_F55812DD, 0x3D446723_552D30E2, 0x0B6ABED6_C058F78E, 0x7029BD7A_92FF352F; 0x721CC66B_1A2D2927, 0x47DAE842_43B2C73C, 0x7DD6544A_E30683AC, 0xB0EEFADA_FDE8B3D2),
+        point!(0xD4B6E6C8_07CEC8AB, 0xE5325456_6E0552CE, 0xB3B15C3F_83F1FAE8, 0xFAECB013_C44CE694; 0x8981DFD9_AB155070, 0x9C32B286_B85E2E2E, 0xFC2E02C6_EC2FB13D, 0xCC09B5E9_0E9ECB57),
+        point!(0x49CE5683_BD486ED1, 0x5745BEBA_49565B6A, 0x009D4109_D8CF7560, 0x9CCFEDCA_EAE65C99; 0x4F6D59ED_75E95D8D, 0x24216759_69592AA2, 0xB6122481_200B3411, 0x7C2F4D71_3D6A32CF),
+        point!(0xDCDABFF9_B181FDC2, 0xDD2F62BB_5CC62364, 0x4AA264B8_18A34E7E, 0xF42C102A_F47E6E47; 0x81F00093_A485D7FD, 0x4C15502D_9A2ACF26, 0x78FAD05C_B86FE22A, 0x57503AB4_6CFE806C),
+        point!(0xA206B1A7_5BD0EACA, 0xD0C74576_BA2D4AB7, 0x35A8FDE3_3CA4DF19, 0xCD9A4B87_63414143; 0x6E6FAFB5_ABFF4ACC, 0x0127B38E_ED6F634F, 0x815488AE_933EA08B, 0xF0455879_A1E8F23E),
+        point!(0x07F64EAE_9AD1B1F7, 0xB3B2DD82_4F23CD3E, 0xC8731A0B_37CBCAFD, 0x09BB8A13_2DCAD2F2; 0x64130627_C3811C80, 0x0F54A840_F4752D53, 0xB6F9DD28_4F863E85, 0x945BB2B2_AFEEE3B9),
+        point!(0xF20953A3_C7BC57C6, 0x4903D7A2_3EB276CB, 0x884F2D2E_93D63094, 0xAD09882F_88ED9BEA; 0xCA32C0ED_AE4A0AB8, 0x7B311D37_3590E2D3, 0xA28186C7_29DABE5F, 0x7243C08C_42FBA52B),
+        point!(0x9E2DAB64_4F927DC7, 0x37A7A40D_63D418A4, 0xE669ED95_366A1423, 0xE260A0CC_082BA6BB; 0xA45F36F4_46AEF35D, 0xEDBCC0B4_462CB82D, 0xF073CB3D_725BF440, 0x37EE6744_B0DA9D67),
+        point!(0xEA830C72_ABA56302, 0xF506A937_AF0F1862, 0x1EADA2D2_A9FAD2E0, 0xD9D1290A_EB3CA41F; 0xEAADDAFA_88291C29, 0x1739AECD_9FA74F2A, 0x029715C6_726386E4, 0x7EB53113_EC2D3EED),
+        point!(0x3866D47D_EEDD7DD6, 0x49376FE2_65E1968C, 0xCA5A7840_EE7CFDEC, 0x32CFCF6A_24C7524B; 0x25FD44AE_FE08E330, 0x7A0D8CD2_0349A08B, 0x6208096A_409F561E, 0x21846A34_976A7748),
+        point!(0xBACD3704_F4D1243A, 0x02B12602_3F54A5FD, 0x91CD21AC_063F5584, 0xBC5079DE_539D6CEF; 0xF5C95168_855DB68A, 0x5F7EE4F5_B1F47169, 0x8EEC72B9_4DFFC8D7, 0x65062A3B_3A705CBB),
+        point!(0x696D03FD_F6ABF83D, 0xB8F94058_FB2D0596, 0x3ECBE68F_10D22C02, 0x235CAADD_E85AC49F; 0x7F4EC9E9_0D33E191, 0x3F0518D6_323610AE, 0xEE4232A5_BC93AD89, 0xDC12F9C7_45FD47EB),
+        point!(0x08D244E6_B74A3F9F, 0xC287A665_DC098169, 0x913DEAAE_7DB2625A, 0x4D31A77E_505FC7E8; 0x4FCB0241_301E0BA7, 0xCBF6FB51_B6E00A75, 0x06B18F38_C7802753, 0x22241EC9_6098575B),
+    ],
+    [
+        point!(0xCB611592_5232FCDA, 0xB700DBFF_A6C0E77B, 0x6BF771C0_0BD548C7, 0x723CBAA6_E5DB996D; 0x01DC069D_9EB39F5F, 0x2660A065_37794948, 0xA9211374_88824D6E, 0x96E867B5_595CC498),
+        point!(0x1FD383D6_0CA030D5, 0x2D240A43_07984907, 0x343D7DC4_5773A3C6, 0x57EFA786_437B744D; 0xB07AB442_74B02F9E, 0x689B6D2A_E5E9974A, 0x893627C9_28DE03EC, 0xD712DB0B_D1B48518),
+        point!(0x2567E09E_80633CB1, 0x575A224B_69D02113, 0x00C62732_12181FCB, 0x6DDE9CF3_17AACAD4; 0x57DD49AA_67CE6B34, 0x80B27FDA_CF859EF3, 0x5C99EF86_A1BA66A8, 0x9188FBE7_A707E41D),
+        point!(0x7FAFC777_0C584DD5, 0x1080577E_327B012A, 0xA2DF7E9C_D5226CB9, 0x264BBD43_6A28BC42; 0xE6122793_7704AB11, 0x6A118243_717B8D8D, 0xD4F75CE2_4C33BE22, 0xD87C6FA9_4EE093B4),
+        point!(0x419A518D_2933F3C5, 0x085A0F71_15F12522, 0x13C4BB7F_8E47B850, 0x486FA72C_D5B5CDE8; 0x9AD4A71A_CAFB0F53, 0x62D9B783_CF0F23B7, 0xE48C48BA_ECC8F19F, 0x62E12319_F56BDD43),
+        point!(0x4D0BD76A_44E5467D, 0x40908AB8_19BBFACE, 0x2C21F62E_0EC970E9, 0x97D064F0_FC69A122; 0x797300FD_1E9CB3FA, 0xDA5FB3B8_54F17CCD, 0x03F7C66F_A850861F, 0x89974F2E_D33402CC),
+        point!(0xEBD59422_5E99F728, 0x677375FB_E6F12204, 0xB664FF27_B76A5303, 0x24796974_A894AF4F; 0x37A00516_EBAAEBFF, 0x5ADBF3C0_9575A2D8, 0xEC52E87E_7D8D664A, 0xE3D78D44_688F3001),
+        point!(0x2BF26BD8_4B2438E8, 0xA78BC61F_D5BDEC9D, 0xDAC85C05_6236A79D, 0xA94C6524_BD40D2BB; 0xC2E2C8DB_F18661F4, 0x7E5D3C60_A0E39B2B, 0xFD792195_05019E3A, 0xB5201FD9_92F96280),
+        point!(0xC9E0C6D4_2FB0079A, 0x916F9EF7_23926049, 0x631A59EE_76B38324, 0x2F39CBDA_A3D55FF0; 0xFE229730_2C5690BA, 0x9BFB3FBC_882A0230, 0x4DA936D0_278DA58B, 0xABEADBDE_13863983),
+        point!(0x5F46F289_B98086C8, 0x40C10E34_B62663FF, 0x289263B7_0AA8E6FC, 0x3E842E5B_AA802BDD; 0xF994B513_8EE939A9, 0x37B8E576_B75E7D4E, 0x1B937046_31B077C0, 0x89F24FF5_FC92E72E),
+        point!(0x793300B2_E4F7AB73, 0x62E3D4EA_9D4443A7, 0x56A39E86_C534AED2, 0xE5A31D6C_327D61BA; 0x3913A3FC_70561F42, 0xBF8C4449_A80CBA21, 0x0C118A97_7A4ECE5D, 0x37788C3D_8D1E9D7A),
+        point!(0xCCA81CB9_13613BEC, 0x8BB5FC9D_101CFE67, 0xEDF1B33D_C74F972A, 0x2DCFCAB8_C93937BD; 0x3E730924_9A039215, 0x3732CFBA_D33F5F38, 0x65F088B7_D6F6C6F4, 0x46DBC4DD_9474A412),
+        point!(0x88F929A3_C14DCD86, 0x1FC5D420_96BF71B5, 0xDF50DD98_2051DCF1, 0xCC389D4A_0D153447; 0x68EB6DD9_C8F0A873, 0x05C57E18_FE7E7874, 0x9B568BDB_BEA910CA, 0x93AE4FD6_60F6BBED),
+        point!(0x8443D764_DFB4F6BE, 0xB567D5FF_4FA3EB64, 0x8EC4BB04_28F0E8B6, 0xA2AE53D1_AE758228; 0xC0422567_4AEEB9A2, 0x4C0EFA67_C3D40436, 0x7C95679C_7C1B547C, 0x6933594D_44B3A098),
+        point!(0x24F9C6EB_78819311, 0x0B3E1EE2_10877147, 0x3750A17E_0CF5ED1F, 0x7F9291C8_9D71E902; 0xAA9F9B57_AC2EB125, 0x120AAA40_9C86A174, 0xF5D0DE8C_452415B6, 0x9DA00D10_63EC3EF0),
+    ],
+    [
+        point!(0x9A533ECA_0E7DD7FA, 0x09479612_37A91983, 0xBA5FEEC8_12C2D3B5, 0xEEBFA4D4_93BEBF98; 0xDDFD4FDA_E1DE8999, 0x9AE4CDC3_A711F712, 0x69EE7EDA_F178089D, 0x5D9A8CA3_970EF0F2),
+        point!(0x76BEE908_47D297FD, 0xC4EA4BC0_8F6766D6, 0xA61C6031_C118495F, 0x381C4AD7_A7A97BFD; 0x93ACE318_7D493FC5, 0xF0451032_DB939C00, 0x8F3E5FA7_09915ECC, 0x936AF53B_238EEEE4),
+        point!(0xF3F678FF_BB7CECEB, 0x73A59F93_8897FAF0, 0x36FFB812_6F6E6814, 0x437A8620_4276D450; 0x7363BCC3_56C181E1, 0x87220FCF_DC8F9782, 0x69B8FEB6_99D297FF, 0x0B916BA1_3EEAC32F),
+        point!(0x448D7042_2EDE454C, 0x9CF1D05F_EFDD08B2, 0xCCE10831_D9538C47, 0xE1EFB9CD_05ADC63B; 0xAD9FD233_A8913797, 0x464E3244_A7A2D4C6, 0xB0154C1F_FE477123, 0x0ECB4530_D8AF9BE7),
+        point!(0xD58D729E_097F96F2, 0x35823529_D2C8735C, 0x83CB7E3B_9A3CC273, 0xA9EF9F13_E2A489BC; 0xC03D55B0_56C04BE4, 0xB74F89AF_5A9B4702, 0xD956EE16_0EBB613D, 0xE814CCE5_94559D7C),
+        point!(0x4C9D9D87_DCBF00EB, 0x41B4E98B_C18D0227, 0x49BE16F6_A1A30BC2, 0xB89070AE_96EAD4DC; 0x1B7F1BCD_1B0E664E, 0xCB0D8B06_B6B96A67, 0x472294E4_C1C4A766, 0x6F24C8C2_C8A2D88F),
+        point!(0xBC0AB5A1_350CF77E, 0x07DBF574_54A8AB0D, 0x553827D6_9FAA0642, 0x66D80541_EE1D35BE; 0x2A5F97AF_A0EAA3A6, 0x9444B43A_B7B1B76D, 0xC3F1D420_535613F6, 0x51CFDFE7_32FFFB42),
+        point!(0xD33FEEB3_29EB99A4, 0xC7E5419F_33D47B18, 0xC5AC235E_9AF475A8, 0x5318F9B1_A2697010; 0x2C726EEE_FE91F92D, 0xA41F2B40_D1E3EC65, 0x5772D93A_EBB405E8, 0xF44CCFEB_4BEDA419),
+        point!(0x93548628_87213A5A, 0x0B73204A_785FA62B, 0xBF3508A1_C64CC3C8, 0x62AC05E1_36503FA2; 0xC10D21C0_F46A9E45, 0xE5590901_817994EF, 0xD395E79E_07A92579, 0x236FBDF3_A0D1A6E4),
+        point!(0xD6B21B3C_EFC76AC1, 0x6124C395_CA422C05, 0x37E1859A_7D53E899, 0x188EF3BF_AB103784; 0x3516032A_528F00BC, 0xE4D66DE8_3C763875, 0xD005FB18_CF398E38, 0xE6FC997A_052FBA99),
+        point!(0xC30FD498_67BAD12B, 0x85D5EA00_EAF66E1E, 0x5BF613AF_5C6F2DB3, 0xCA13C449_72448F5B; 0x89699F46_9723B0F2, 0x6370AB71_11A57566, 0xAC15FA64_EAADA3D6, 0x83AA0983_61C287C8),
+        point!(0xB9D6FF9A_B6FBE7B2, 0x5EADEDC1_458D65A3, 0xF336BBB1_B2A88460, 0x26488766_9CB441F8; 0x932A78BC_21BC2A34, 0x5638D981_6A0EB603, 0x8F2F2DCA_D02DDF18, 0x9E15DAB4_B2014498),
+        point!(0xDDAEE5F9_5C80414E, 0x14EF8A86_2D66CF7F, 0x5AC11C4F_44609E49, 0x1CECB101_A7052AE8; 0xDCAE9492_D2169A3B, 0x907557E5_447231D0, 0x4E5AA328_35EDAD16, 0xF3436066_96097CFB),
+        point!(0x50D1E4D1_8B20907D, 0x3B15B2D6_1607851C, 0xB002F458_94850FF4, 0xF98B5DBC_3E54C227; 0x8351319B_5CC926AF, 0x41FCD9E0_25A90E35, 0x42939B70_6BA9CC9E, 0xE2D508BD_4F4A13FA),
+        point!(0xC4F5BC8C_4ED810A9, 0x4F889D3F_3C700155, 0xC950DAF5_F1DF2392, 0x2A699075_04D78F9C; 0xCF86B388_FA9B4728, 0x667D47E9_7EC145C7, 0xC3EC5869_54FB04FC, 0x54F9039B_6C3DFFDA),
+    ],
+    [
+        point!(0xEFD7835B_39A48DB0, 0x9F1215A2_9B3C03BF, 0x2791D0A0_9B7BDE45, 0x100F44DA_696E7167; 0x0FBD5CD6_2BC65A09, 0xB7FF4A18_FF5195AC, 0x2EC8F330_0C090666, 0xCDD9E131_92A00B77),
+        point!(0x4B05284F_1E4DF706, 0xD8D9C8F9_237D0808, 0xA8415DFF_2B4C4199, 0x8C0989F2_CEB5C771; 0x736AC5A3_5D72FA98, 0x60DE6BF5_156511AA, 0xFFD2172C_B9DC966C, 0xFB4DBD04_4F432034),
+        point!(0x9CB9A134_95BC15B4, 0x9275028E_465A2EE6, 0xED858EE9_CED7CA8D, 0x10E90E2E_51EEADC9; 0x34EBE609_58AA258D, 0x4CA58963_02BB6A88, 0x4D57A8C6_16AD1F75, 0xC68A3703_80D5E042),
+        point!(0xB1E63C33_DC47BFFD, 0x9C528539_BC95BC1B, 0xC4A48174_3262C025, 0xFB8F153C_5E266704; 0x090A45DD_D949B095, 0x1DDE1389_AC542613, 0x16FA11D9_B4BCCD53, 0x6CA27A9D_C5E06218),
+        point!(0x2DD3FC30_3FE75269, 0xA377A3CC_053D3318, 0x4575B90B_714B7DCD, 0xF7422F42_DA541638; 0x18980E87_17E49BD5, 0x7FB3A237_F4A398E0, 0xD18CE7DC_B9F63597, 0x406C2F1A_3313093F),
+        point!(0x0F62ABC8_7A1C0A80, 0x4D625158_C65A9C74, 0xB17C9BE7_002FF9C3, 0xB6B15A68_A614CCA5; 0xB6CD0110_41CE0A03, 0x9C9A12B3_082E16EE, 0xA54E223E_EF6536D4, 0xFAE62E14_D6CDB61E),
+        point!(0x653B6696_F5A7175F, 0xEDB8E771_D31CF42A, 0x72879A55_82D5DEBB, 0x2D8CAD04_17D43CFF; 0xCF37BB91_BB9D592A, 0x7A846BFD_9CB5E5E0, 0x7BB232FA_612C9D37, 0xC73F3B83_318CA94A),
+        point!(0xB8FA1B8B_4BB2629A, 0x65A02C58_7737A7B8, 0x5A0CC9F0_A7287084, 0xE747333F_D75D5175; 0x9F8D961A_6946F6D6, 0xC88376AA_6E1A969A, 0x4CC43603_804C2581, 0xF2AFFE01_45070C11),
+        point!(0xE34C9BC3_94B51045, 0xBBC6C896_F31C25B3, 0x8AE73D4E_B1E8CF73, 0x1ECBFD1D_B98A6EA5; 0x53A67101_02C70026, 0xB1900646_B436422D, 0x447D0BB1_849A9B38, 0x1CF6E230_8B99C3A6),
+        point!(0x4A35B56C_6413E439, 0xF92F69EF_DDD5EF67, 0xAF8B1BFE_B7EDB64A, 0x99741BB8_B477E296; 0x3E657D82_6832CD5B, 0xB17C15C9_697466E4, 0xE735AA56_258E3E7A, 0xDE47E9B0_7BD9C9A5),
+        point!(0xF7ACD766_E9358533, 0x10A933F9_D4FB4B9D, 0x83E955A2_91D32A8C, 0x9A0894C5_FE577528; 0xFB3E1C5D_C360BA08, 0x65A6E5BD_BB80DDAD, 0x1F917D5F_954FC321, 0xA79883C4_201B8FC3),
+        point!(0xA7CAA50A_92B062D4, 0x7A5CE7E5_9BB6A141, 0x06FB1712_83EA227A, 0x35963EA4_3256EACA; 0xA10AA4D1_BBB25302, 0xD04082B9_64DE59B1, 0xBFCCE196_F9C08A96, 0xF65BE145_4951E5C9),
+        point!(0x694405D6_198EF7F6, 0x5923F3F7_7A078F9F, 0x5BD9C852_73B8AEA6, 0x664DD849_DB4FD2E3; 0xE7496FF3_5D1EAC94, 0xFC3D3AB3_1B8E6ECE, 0xA1448CE5_DD0458CF, 0xAD512017_17F27932),
+        point!(0x0D0B66F3_119334F0, 0x74728EFB_3A0AD1B6, 0xC6A0E824_85C29E41, 0xCEBB7B7B_02CFC321; 0x16750BD2_51E45191, 0x4765A29D_B9E036CB, 0xBA1EC0F7_4DD2E370, 0xFE3DF243_633B4A32),
+        point!(0xE0C0A6B7_C3C934B3, 0x2B31F580_5B0AE2C4, 0x8231D966_9811A702, 0x82113A93_77D0B863; 0x77E5E62A_C42C6A0F, 0xAA1F7C26_9A446803, 0x08466CF2_B5A0C628, 0x8DA1B8DA_C9AE3666),
+    ],
+    [
+        point!(0x2C8ACDE6_E534FD2D, 0xA77F8D44_64F3B385, 0x1DC9227A_4A04C017, 0xE1031BE2_62C7ED1B; 0xA44F18F2_9456A00D, 0xF292DD41_9E1CED79, 0x6BB6A417_6597535A, 0x9D706192_8940405E),
+        point!(0x84E27E36_A95C8356, 0x028A6AC5_DE94D857, 0xB9F95DCD_0F29B2C9, 0xF4B93F22_4C8089EA; 0xBD5861B9_BE001FD3, 0xC37EF134_4915609A, 0xB0E5F6A7_A40EEE90, 0xA67A92EC_062962DF),
+        point!(0x579623AE_EF028D83, 0x6195926D_BA743961, 0x6A5ABE5A_15DE69DB, 0xA7EBF7C4_E3C785EC; 0x9640392B_99D0BED1, 0x47A38927_4B053919, 0xCFD9C737_7044804B, 0x6205152F_BFE362D5),
+        point!(0x20D62FE1_7B160E8A, 0xD51E8512_F97E696C, 0xB19622EA_025B08B0, 0x09D1ACA1_FCE55236; 0x34E050C5_7CA04C44, 0x6FE9E0EE_9212B5E5, 0x3E56692C_E0D8C27E, 0x1153188F_5101F0C6),
+        point!(0xBAE0E402_27DD5CFA, 0x6A89C513_7F09D4B5, 0x1CDC6A53_42634633, 0x5B5CA08D_CB024F4C; 0xE664A6F9_9E48E98C, 0xBFD067CC_AF3269D3, 0xE991F0CE_A8869094, 0x3ECCB6F7_0AA15825),
+        point!(0xF2884413_7BB61EE5, 0xDA4F04E2_FB1F0C13, 0x662638CD_8974AE6E, 0xD4933230_CC8721B8; 0x662DA4D0_E5D694A8, 0x1AD12C8C_5A438DDC, 0xEDCC5E9D_1ECAFB5E, 0x021C09AB_F51A9D23),
+        point!(0x2B9528E3_23531F82, 0xFF51326B_B10C2C9F, 0xCCEF3E74_89C22C29, 0x046F26AC_C1114BB5; 0xA505FC8B_0BCEDA07, 0xD025945C_DAB55C7F, 0xCE2370AC_2FC32579, 0x6B804B31_635B82EA),
+        point!(0xE97D7D04_75BA7FC2, 0x518B3A93_BFC39562, 0x18A2AD79_3821CDE7, 0xC66C59CC_454C2B9E; 0x7EC3C69C_F75F5956, 0xF00A60DD_B1595597, 0xBEA4F3CE_AAC10CB2, 0xD9592FE2_BFB30FCF),
+        point!(0x60B15437_10432711, 0xCF140B2F_DD916B90, 0x35F96F0F_0899645A, 0xC11926D9_31E0EFB5; 0xE49261E2_EFE2610C, 0x83AB922C_A0F34055, 0x18494BF1_86CCC6CF, 0x8BE1F8CC_7D25B689),
+        point!(0xF836C6CD_22BE316C, 0x6BF4043F_278E7AD0, 0x2B17CEF5_B25EF5F1, 0x559CD5AA_4A0B37B3; 0x1A6AB9C9_9FE04013, 0x8A389A1D_360780E7, 0xF2DBA929_874C692F, 0xAAD5449C_08D44076),
+        point!(0xC179874F_A1257963, 0x5B018D52_C34AA861, 0x6CDBB54B_538979C4, 0x690846E9_EB688F2E; 0x3A576599_466F9835, 0x6CBC3CA8_21C92B25, 0xB4017024_F92FBB7F, 0xE2485FCB_7F3FEBC5),
+        point!(0x530DDCBC_EC04554A, 0xAADCFFBB_4688CFFE, 0x474652C2_7A10A2EC, 0x896F37C8_9873D1A0; 0xD68F9FE1_929138DF, 0xE6085B61_ACC417DC, 0xDA622BB0_4E811BF1, 0x380423E7_224AC4AC),
+        point!(0x2D11394B_DEE23ACE, 0xDEC87C65_B54DD2FD, 0xBBA7552D_F4A32915, 0xFB3DF7FB_8C9220BA; 0xE09DEF4C_9722E8DE, 0x9E727142_FED27F5E, 0x52A25154_0DBAB58A, 0x510E29BC_11C73C56),
+        point!(0x5F90F130_5CD36F89, 0x1E4754C6_E700CB92, 0x651D68B8_72900EE3, 0x482B6F17_69155239; 0x467CA372_70A4F423, 0x19EE0734_42D49A68, 0xCB18C23E_6D7FE92E, 0xE8C3C11D_F9A5CC5A),
+        point!(0x8607CFEF_50272351, 0x70734B3D_F99FF109, 0x3EB8A3EB_3AB1CD41, 0x6DD85EC2_4F5C518B; 0x9B07FA2B_7FC7664B, 0x877C6FF7_C3E0817C, 0x09D407F9_AF5C41B7, 0x16EA67F4_121F427E),
+    ],
+    [
+        point!(0x2D5E688D_9094696D, 0x5CF8B266_A41D6AF5, 0x0AC2839F_143BD7EC, 0xFEEA6CAE_46D55B53; 0x3155DEBF_18090088, 0x981C8957_CC41442D, 0xB06E4E12_BF3ECD5C, 0xE57C6B6C_97DCE1BA),
+        point!(0x0AA4040A_EE752B08, 0x141ECAE0_B331A187, 0xC53261AF_9DB2E179, 0x4D000B62_1ADB87E1; 0x3E72851F_48302CEA, 0xCB7DF5F1_19C7293A, 0xCB6D8255_82D972CC, 0x6A0D5B8F_18E0D255),
+        point!(0x079361BB_48DFD587, 0x5EC4BA38_C9B02656, 0x34867AAA_2CF5A12D, 0x5084B41B_ACF4508B; 0x6E79E97F_91470E89, 0x5DB6F560_6891F560, 0x619AA6C8_55292747, 0x34A9631A_1D980D31),
+        point!(0xF89962FD_475C58EF, 0xD657A040_3E1F1B77, 0xD6AA2621_14717128, 0x71F570CA_203DA05D; 0xF8649A72_D35D420E, 0xF2445D00_C3363E7D, 0xD2555734_5BC95B8D, 0xEB42415B_95DC880D),
+        point!(0xD0823261_7AB34CC6, 0xC80C2976_7CF30A12, 0x53FB3F1F_D18D7128, 0x4F14C03E_0642D5EA; 0x25EC252F_987E681F, 0xB9DE3CCE_8E81DD02, 0xC653A70F_43A62540, 0x7B53D0A8_CAA4E894),
+        point!(0x4D05956D_6C953FA9, 0x28AB2629_F0B8C3DB, 0x3A5F485D_4BD18C06, 0xA49ED10E_AAAB9323; 0x67B2BD22_46FB4C72, 0x5AE87534_968E181B, 0xE03476C0_A0DFDDFB, 0xCC72B894_660F5398),
+        point!(0x34277101_1241D90D, 0xE81CF141_DB2444F8, 0xD4143609_5EDDD363, 0xA74DB87E_49C79ED1; 0xF32518B8_3F7ADAD4, 0xA9EE5093_44A0A313, 0x27FDD08A_588171C8, 0xF78691CD_AF23EEF3),
+        point!(0x6048B060_43FF8359, 0x46B4821D_C65E7651, 0xB7D282B5_C21DA014, 0xA2B7B362_9F7BD253; 0xA2397FEC_FE86FEC2, 0x10D10835_046F3835, 0x57A937A3_F71E29C9, 0x69303894_1695122D),
+        point!(0xA5ECCA22_31C1AE1F, 0x456E58C6_8ACD40B2, 0x9ACDCD44_EBA35658, 0x6901FA57_44BAA2CF; 0x0F833065_D22838B0, 0xD79F3BA5_E5C77A93, 0x461B5380_C29C900D, 0x35DE5C88_2273C212),
+        point!(0x34F09013_99F1F366, 0x4A79057A_3D0076BE, 0xA495F216_B4994D90, 0x5D134E5C_5DA47F7B; 0xE2B0D3E9_C4990F2C, 0xCF02D616_20A88B39, 0x2DBE2A4A_E3A82B73, 0x03B1EF4D_3E084504),
+        point!(0x4B031081_B27A4BDB, 0x046A6D04_35494CAC, 0xA244B643_F1BBA35D, 0x8D3CD82D_1D438127; 0xC3D34C7C_E69A8A2C, 0xD4E3807D_370AD296, 0x70CFBF9D_0076236E, 0x9BD42561_80EE41F4),
+        point!(0x3C096D3F_E7A1D916, 0x315B0A39_5D1F1F3A, 0xBAC6158A_65DBB070, 0x650EF9AC_B6D59D0C; 0x85849CA6_7068387B, 0x58BB4608_A76C26BA, 0x70E9C0B9_279B82C5, 0x085D11F2_1162B118),
+        point!(0x440CD3C6_B4EC1D2D, 0xD9099F5C_6E7D8F45, 0xAFE9D672_967B691F, 0xEAF98363_D6064739; 0xFCFE7560_4C838452, 0xA9F52D54_57E84B53, 0x775228A0_E6B199AA, 0xE518183A_7FE78D22),
+        point!(0xD42C8E80_8BD98EE1, 0x579DF37A_908992BE, 0xFEA7C924_A8FBA197, 0x1D770ACE_0CEB2183; 0xF9FB8179_1D38FFCA, 0x37F61CA4_0E3D57EC, 0x78963038_B6552A86, 0x278F8805_F46EF5DE),
+        point!(0xBCB35BF7_045AE767, 0xC273A9CB_9E89771C, 0x66F40E05_2C06E5D8, 0xFB95BD16_3AEDB634; 0x1853EA0A_FBBF0E11, 0xB816F471_8A59F134, 0x52004D9D_3682275B, 0x664C14D8_11A8DDBC),
+    ],
+    [
+        point!(0x33FA978B_C1EC6CB1, 0xFEED657D_808583DE, 0xB367BE4B_E6FFCA3C, 0xDA67A91D_91049CDC; 0x7E9EA8E2_7A68BE1D, 0xDEC7ADC5_08F740A1, 0x41F463F7_EC9780E5, 0x9BACAA35_481642BC),
+        point!(0x5EE3CC82_1FE741C9, 0x8BBD9F35_CCEA5A83, 0x7C0C0CFA_AF00D871, 0x4DBACD36_5FA1EF58; 0x60BE10F8_338EB623, 0x0CC384A0_9FC0535F, 0x7FDCFD59_E838299D, 0x16C3540E_8A51892E),
+        point!(0x9EFDD065_15BC8A44, 0x68410177_CBE151A1, 0xD38565A4_BA5A5FC7, 0x4D018058_3CFCEDA3; 0x2F1F94C9_1ADBC09E, 0x96942046_8582DA36, 0x67E9BA80_07D63813, 0x3A33C6C1_8CB4F5D3),
+        point!(0x87EDA8BA_B4E218DA, 0x0F4C85F1_52686050, 0xE68F17D8_FF41C259, 0x13D1FFC4_81509BEE; 0xE0DB419D_DB191C19, 0xA4AD0120_6D5BD127, 0xCECB9337_B1B758BD, 0x6008391F_A991961D),
+        point!(0xBC1AB528_65DAEB00, 0x5923EB24_CE645F76, 0x082CB6A2_73B6E9D1, 0x2F661507_DF5CF957; 0x12276789_833992C0, 0x6ECDEE27_195D308C, 0x6EF9537A_8200ADD0, 0xFD5C1213_6F52B33F),
+        point!(0xF52213E4_935B4EB8, 0x07F557F1_AB8B5A3C, 0xF0FF54A3_CCAF2DCE, 0xC11968E4_3ADF2256; 0x7044996D_6F911ADD, 0x42F8B494_12A7149B, 0x8379D81F_E6766F82, 0xBFF5E693_7786458F),
+        point!(0x182A90A0_916AA6D9, 0x3662C8B6_47702DCF, 0x254F174D_A1835A38, 0xF594117D_05FE47D2; 0xC7D0696E_BF2E50CF, 0x7A927788_3E6B5B86, 0xCF65DBDA_B094BCEB, 0xCAA761A5_6E971B12),
+        point!(0x133B40CA_A2E96DB8, 0x3CC9D916_CE29DBFF, 0x7659C79C_45B0533B, 0x219B4F9C_EF6C6000; 0x27DF01A7_8D3B6BC7, 0x394F8AC5_3E905765, 0xF5A44180_C0372A6E, 0x24D9C605_D959EFEA),
+        point!(0xCAAE0395_7AC6F4C0, 0xDA4E8DE8_CC4BDC4C, 0xC4E1C38B_E696711D, 0x0F2D4D7F_1F4D9CDE; 0xE7E7BBAD_72339B58, 0x8F6A10A7_90242656, 0x17FB27FD_F381E71E, 0x3EC89F85_7E93DE4D),
+        point!(0xC56D1F9A_F4284327, 0xAC4020B0_383A6FAC, 0xDD43EBDD_806A3417, 0x0F6840BD_CD64B45C; 0x400D275A_45E2AB72, 0xB68DABA7_712B9EB7, 0x495E8BFF_4B6114AF, 0xF304D37F_100F1A77),
+        point!(0xD855053F_DF428CB2, 0x233822FD_66A22B55, 0x1EB7A181_5958D0F1, 0x1D5DCEC2_E2A14BBD; 0x1E5ED100_895B189B, 0xBD3E912F_876044A5, 0x0D078782_486F0B88, 0x6E5C4083_B142C8C5),
+        point!(0x295BA109_59DFFC96, 0x9228CDA5_AFAC5857, 0xF6F9B395_C817EB01, 0x161C6CBE_E1483DEA; 0xC93BCF61_1B492DE4, 0x9B9634C9_2EAF8621, 0x5BD25939_1CB18305, 0x8A26CA92_A6B9FF98),
+        point!(0xFB0EA88F_A8381273, 0xD8437696_0693DAE5, 0x9FDEA9B2_CC4126CE, 0x89D9A2FD_30096743; 0xA03EB3EE_D309773D, 0x8F61D680_AD127324, 0x377AAF20_76CF62F5, 0xDFCA25B4_51D66F50),
+        point!(0x4CF6155F_810A683F, 0x5248BD31_BF6EB1BE, 0xFE6C7285_F189AA8C, 0x783D0ECC_3F64D4A0; 0xDFD66CE2_E0F54C4C, 0xB5DE9288_72395EE9, 0x016B7457_27F2D51E, 0x200A6D02_9073F4A3),
+        point!(0x1CC32B2C_B5C71D91, 0x23945E2E_2A894FD2, 0xC52A1C13_115AFECE, 0x83191B87_83A50AE1; 0x70B9EC08_E2148A61, 0x17A11F51_750445EE, 0x9E06DE13_D17CAE8A, 0xE0AC7F15_73801B70),
+    ],
+    [
+        point!(0x57545CCC_1A37B7C0, 0xEC08D0F7_BB11069F, 0xA6E00093_5EF22151, 0x53904FAA_0B334CDD; 0x9DCB096B_022771C8, 0x13999981_E1443469, 0x88C9ECCA_C20D3C1C, 0x5BC087D0_BC80106D),
+        point!(0x57CD0F1F_38A47CA9, 0x2A6EE7AA_AD0F85AD, 0x3CF99119_6316995D, 0x01A575AF_9D414675; 0x67C5DF2E_77EBCDB7, 0xBDB93C5D_9F4D7EA6, 0xCC55FC52_E1BB8698, 0x3038F1CB_8AB20DC3),
+        point!(0x71AC42FE_48A2050E, 0x742EF557_615F8A67, 0x96B769CC_6E479B89, 0x673724FD_24BC7318; 0xB90C9A49_061D3D70, 0xBE6BACFD_43349CC2, 0x203482C0_9A886B6D, 0xE4CF8257_896A4A20),
+        point!(0xE309D755_E315565B, 0xD3A61A83_D3C20C6E, 0xCA71F5C1_B76155D6, 0xF5F0E043_7621D439; 0x678430AF_DD2ECC82, 0xA5BF61BF_3ED7E40A, 0xF6218916_0DF7101A, 0x6B9F4E62_BE5A052B),
+        point!(0xCBF6E483_82DE63BF, 0xE03AF532_87261C66, 0x9E598A63_1F6166A8, 0x4366EFA4_72DF4C30; 0x02C6A408_E17924CD, 0xF33B0C52_5AAA6D6B, 0x2EE2537E_130268EA, 0x2E7DD909_BEE2D7CE),
+        point!(0x34CF601F_A9A78179, 0x6B50E905_260D6AFE, 0x905A4733_6209ED9F, 0x995CA7F3_7081B8DC; 0x931FBB4C_AFEC1F47, 0xBBCC8556_9AB64E52, 0xB5E34D88_0492863A, 0xD9A059E9_5553573A),
+        point!(0xF10527FF_06F96190, 0xD1F02DE9_07C9525E, 0x97BE5569_667AA75F, 0x7BD75362_7991AB1F; 0xA3D17204_ABDA00F6, 0xCE0FCC5C_1E0EA695, 0xD5ED6474_943827D6, 0x8336F2B3_DBBA6309),
+        point!(0xCBE781D9_F5362D33, 0x47CBC921_46227642, 0x57A7F36D_970CA4E3, 0x8F506F0B_6C0B6E9A; 0x30487D0C_87FA243F, 0x48CF925D_43BB8EAF, 0x9530C542_4F1C3368, 0x469F955D_2AFA6171),
+        point!(0x33B15315_56AD41ED, 0x0308AC35_F4F03524, 0xAFA3419E_8918DC92, 0x4F7E927B_DDAAD5C1; 0xDF435786_7E642D57, 0x2E731471_4EAF775B, 0x552980DF_53A8F6A6, 0xDFE77451_56A88B10),
+        point!(0xC1843A1A_3E5E6618, 0x166D65F5_9D8FFCB4, 0x87E01714_1E412463, 0xFBC21073_38B97DBF; 0x0ED54DA0_2230C860, 0x7F3C4F3F_1DCBCCF2, 0xB4960C1A_6F39B11D, 0x9F9B0859_185C9414),
+        point!(0xCFA60512_67748690, 0x7D57904D_D8630B01, 0x94B452B1_EDD33D00, 0x2355CB86_7D291CCC; 0x47C47531_4C89582B, 0x58286858_CD1C9391, 0xF55B85EC_15AA7C58, 0x21C2F18A_5A71E1F8),
+        point!(0x13D8D85D_849135D3, 0x74203CD0_12E431FC, 0x65C13429_4130B16A, 0x05940BC7_EF82A85A; 0xD02D7451_962255D2, 0xF1286328_C9902391, 0x2F75B0C7_AAB6687A, 0x90537985_EB4FAB61),
+        point!(0x6DAEA216_0ADE7F16, 0xAE9CBAE9_3CBE028C, 0x013592AB_6C275D24, 0x0B66825B_9B3DA56C; 0xD9444268_48C56217, 0xB7243C0D_F3E7CD2B, 0xEF3A76BA_8B57A97F, 0xA1FBA0B8_18E09B3F),
+        point!(0xC6330701_64D2374D, 0xDCF38A96_84CFEB2B, 0x5DA5A9F7_1B3FF6A4, 0x16EE8ED8_B5E5C9D2; 0x80E5BCB4_FC865998, 0x0755130E_E6D4DDD9, 0x8A26B843_E5B6FF62, 0xF500E7F0_546FF110),
+        point!(0x2906B022_99060D5B, 0x904613B1_1C3A3A7C, 0xB6109B2F_D9F951CB, 0xF602043C_F0BD022C; 0x76F6F50B_1AF88F13, 0x37AA56D2_B7DD1A3F, 0x881A8F64_AC4F7BEA, 0xF036B706_C1F0CF19),
+    ],
+    [
+        point!(0xEFF959F4_3AD86047, 0x79B53A04_3A9B8BCA, 0x719CCA77_64CA9067, 0x8E7BCD0B_D35983A7; 0xEA10047E_8460372A, 0x79E88E2E_47FD68B3, 0x94031042_0CA95145, 0x10B7770B_2A3DA4B3),
+        point!(0x77D2808B_F13F0351, 0x3BC15B8D_3D0389E7, 0xC350F319_996950DF, 0x33B35BAA_195E729D; 0x2BC503CC_CB8D7418, 0xAA6560EF_BC889B70, 0xF9464036_248D52BC, 0xA58A0185_640ABF87),
+        point!(0xFFE8879A_041EAD4B, 0x3A75EDFB_691B03C1, 0xC714734E_FAFE76BE, 0xBFC90C0C_8C8F337E; 0x7452C6F0_86FEDAED, 0xFB468EFF_32E0AE3E, 0x4DBA718D_D5042D36, 0x7A9481B1_E09CDED2),
+        point!(0xD71DC7B2_4414BB36, 0x56F6E109_CAD7BCA6, 0x5CB83AD2_071F7E22, 0x374DEEAE_22C93F95; 0x7875BEA9_8DAF734A, 0x3828D663_00E54321, 0x16032C06_F806F729, 0x171165B6_4FCD4F99),
+        point!(0x26E7BD07_75BB3B3E, 0x50753617_EF9F73CB, 0xDCA5993E_8C2D3F5B, 0x732DF11C_BE3FAAA4; 0xCC577E1E_D7366693, 0xE69DAD6D_64C58436, 0xCB7E2558_40253916, 0x7F41903E_DE8F9977),
+        point!(0x694C856E_E5B7AD0D, 0x76EE767C_A9D0D1D3, 0xC2D0D8A4_96D16C44, 0x3A55690D_ABB5E00D; 0xD6C98790_B2E8C407, 0x01CACB8A_CAC31218, 0xFB21FBAC_97EF99F3, 0xC3E28E19_75A0657B),
+        point!(0xC5F5B3C1_888DC3B9, 0x19A66924_E9774C99, 0x28DA8840_CC97EF60, 0x4CE094B9_603947B4; 0x2C14F7B6_E5C0DE52, 0x16205B20_C9EA0650, 0xD57B4D80_CA76ACA2, 0x05390FBA_BF1A9B3E),
+        point!(0x5E8A64AD_6AE7D616, 0x944DBAF2_B62A9F0C, 0x7C46E073_95AEB0DC, 0x2380C09C_7F3AEAE5; 0x0099BE48_161BBC1A, 0x93AF9214_8F846756, 0xF1598AEF_D509B09A, 0x6F8E8619_3464956A),
+        point!(0x4C375E0B_0AAAFE5A, 0xE25470B3_A509AEDD, 0x96D8A6A9_5B023FD0, 0x9A968EB7_6FC667DE; 0xCE6BFD42_4ED975C0, 0xE7B5880A_65E70CC0, 0x54FC66D0_3D861D0E, 0xABF6FB07_A6D3BA29),
+        point!(0x8D962D14_EFA32402, 0x5E659C61_3C66A534, 0xE0F7DB3B_B038034E, 0x180A4ECE_D74CEAAB; 0x3203780D_C4894B4E, 0xA602C593_7FDCD18B, 0xADBBFC2F_24FEBE85, 0x498A4D57_47BAD7E2),
+        point!(0x3751CBF9_F34C6397, 0x07DD3DB5_0074CD09, 0x4F0AEF80_2536B221, 0xD3C6FBED_01716195; 0xB1FC8D05_68CB3F9C, 0x8A0C7708_905E0E00, 0x9458394D_92EAB646, 0x4A0DD2C5_5E5E2AF1),
+        point!(0x927A47B0_99B2DF82, 0xEC5E7A16_47104FB1, 0xCC92D18E_CCE32C04, 0x405A645C_62BFB92C; 0x5DA2834A_09307DBF, 0x2FFA9DB2_CEDC462B, 0xF5CACA61_E237E712, 0xBC3CB414_F44D96E0),
+        point!(0x19B82593_0AC3137E, 0xFE925B62_A5AF6446, 0xE02F06A7_6AAB3953, 0x4CBDE398_129243B8; 0xC008F15C_EEC02FE6, 0xD193A7AE_588EAD60, 0x50092A13_4B94C370, 0x6CE55460_8F136159),
+        point!(0xC7119E23_E50CE56E, 0xC8293AF0_D77914A3, 0xE68590FC_ACBE7816, 0xAA6DC4D3_CE531AF3; 0xB9DF3A34_7DEDD739, 0xC11984DA_F8F710A8, 0x6E1F6EDC_5E65E663, 0xEBC7998F_A241E529),
+        point!(0x47A2306C_E0FBF84B, 0x03C48FCB_BC3F0B8A, 0xB8BD9C73_0C14CEBB, 0x4B9D333C_82B16247; 0x239EE4B4_A36C3C48, 0x6766D543_E2F5B5E6, 0x55313D98_2679B7EB, 0xFD7FC7FB_E24CC152),
+    ],
+    [
+        point!(0x0A841E15_99C43862, 0x71A7F4F1_8397E669, 0xE6D08186_89B81BDE, 0x385EED34_C1CDFF21; 0xC0458FE5_542E5453, 0x6B304EEC_2086DC8C, 0x6701DE19_E9EBF457, 0x283BEBC3_E8EA23F5),
+        point!(0xDC83A270_78F2827C, 0x47C82642_BEFC1CE2, 0x0456BE13_4D5F67D1, 0xF6F62208_3DAF5480; 0xD15B20B5_20AAA102, 0xE657CA74_48321BF6, 0xAF2C5715_B367CEE7, 0x1BCD4E81_7DE73A0F),
+        point!(0x14BD306A_B6E2D9B3, 0x41DB9283_1B38D635, 0x5E12EA61_39CF8456, 0x19A314F3_97C705E7; 0xD552EE25_CBAAAF33, 0xA5021D1D_2404BE56, 0x234965F8_87F528B3, 0x6CACD8F5_DAC728DD),
+        point!(0x9D893209_715ADCB6, 0xCD91C3CE_7D8C6F36, 0xBD70CB3C_3D1FC255, 0xFB26E518_8F953DE2; 0x49DBCA3B_58BA68F3, 0x16D2CB31_B8B7AB54, 0x58E846A7_19D01769, 0xF3E12881_1012A34D),
+        point!(0x7D8587EB_12F00480, 0x20358804_A100DCEE, 0x55DC9863_64F67219, 0x5840ED4B_95A8DAA3; 0x1592D5E2_BE22CF9E, 0xBA752254_52AE3872, 0x07968DEA_A15DD8DA, 0x670CDA6B_220BF141),
+        point!(0x52E737D9_63A52264, 0xD0ADFC03_15400B37, 0xA806E2A9_AAE51CFF, 0x85FFDC0D_E8187FE9; 0x98C7D29A_82DA2082, 0x678182C9_2B179D04, 0x0BE568F5_0554706C, 0x3FEE3018_7AE2948D),
+        point!(0xE484DEE8_23F54C42, 0x45DC1A3C_269A3DC8, 0x1DC58C1F_4ECE5325, 0x9F5701A5_346918FB; 0x860E1C49_2FEB6A21, 0x89EE784A_B219E527, 0xBFB95B6B_5729BFDD, 0xCE7B8FB8_801D9E57),
+        point!(0xED44DB75_60788C1E, 0xD18C3706_0E8BD1D7, 0x28F5C6BC_763CEAB7, 0x89912259_11B9132D; 0x635C4222_8E8F0EF1, 0x36969C84_FDEF9E11, 0x27B87635_59B136FA, 0xDA8B4D98_7CC9AC9B),
+        point!(0x7E941332_9522461A, 0xBCB30A26_5BC71832, 0x01BB8701_067EF9A0, 0x27F61169_235A8CFC; 0x6AA4CAF9_C7301A2D, 0xE4981B20_DA863944, 0xEA48C561_27428EE8, 0xE512F1A9_900A6AD2),
+        point!(0x6DBD9895_E6DDD3AC, 0x7BF8F633_A2F00651, 0x5852D90D_6CE2D5A3, 0x96EE53E6_732A2E09; 0xFBF25B36_729B6FDE, 0xEF0D0797_6C66DC06, 0xD8C4C971_1EBF1E0E, 0x39B9CAEA_E4B629F2),
+        point!(0xCCE2C9B2_D14F36B9, 0x2598CABB_63B9F390, 0x261BF2F4_35F4A981, 0x64077985_6CEEF941; 0x894B1EE9_E4B4B50A, 0x8CDA0870_499CEEB2, 0xEF90D75F_D85A7B6C, 0xDA61928F_44E56EFA),
+        point!(0x9C1FF5A4_B6A88C9C, 0xA37B6866_3149DF06, 0x8B21D9AC_972CAA2A, 0x78659081_E5479139; 0x5E059345_75A7D19B, 0x12CF919B_03AB6A15, 0x69E8FA72_25E81961, 0x59A23077_92A30D2B),
+        point!(0xCEF63DF9_94D6B76F, 0x7FE5A9FB_B4C815DB, 0x452F708B_C09DFEAE, 0xA23750E3_1C85669F; 0x41D92CCC_8DCCA8DA, 0xE7707C11_FDB848D1, 0xAC32FA3E_4D411113, 0xF7339B14_A6E7DE6D),
+        point!(0xD4FCDB13_D0C13079, 0x6F3E5A01_54DD1688, 0x809FEA76_EDAF3016, 0x5E52B50B_6E1F0EBB; 0xC103C48E_70E1296F, 0x4A3829D1_3D63CE1C, 0x9B698785_34A03EAC, 0x199EDEE7_3A354B0C),
+        point!(0x12EE9C64_FCEEE475, 0xAB6AC828_40ED782B, 0x5ED3C021_F3CAFF41, 0xBBF1AC07_A3F2378D; 0x84622A00_87FE5067, 0x24EB9D89_438B41D6, 0x10883CC8_36B15FE3, 0xB4BFB8DE_EAD460E6),
+    ],
+    [
+        point!(0xC606ED86_C3FAC3A7, 0x0FDDF84A_5947FBC9, 0x637C73A4_413DFA18, 0x06F9D9B8_03ECF191; 0xD8689060_3A842160, 0x7EA4DD2F_5C281002, 0x69B8E2A3_0E45C4D4, 0x7C80C68E_603059BA),
+        point!(0x5C9D2274_4B7FD72D, 0xDA1745E5_A7E4DA17, 0x1CDC36C2_84482939, 0xAE86EEEA_252B411C; 0x2334CB7A_4EEE38BC, 0x8D921155_1472F728, 0x62AB0ACE_589FF0E9, 0x19E993C9_707302F9),
+        point!(0x24ED75E8_D21CE204, 0xB2A7258E_426763D5, 0xB8374D85_9CA6F72F, 0x43CA41D1_62B3C64F; 0xE525044E_934A8F6B, 0x9AB6C7B3_3EA4A468, 0x1C650F92_18DBA31F, 0xDCEA5A82_E37023FA),
+        point!(0x7A147426_7C169290, 0x8718BE75_CF36F2EE, 0xE61D2F8C_56DC2C48, 0x2248C9F9_0BBFFF55; 0x369D8A12_883EA257, 0xE1637502_35DA2BE2, 0xA506BB55_B435BA18, 0xFA059469_2D21EED7),
+        point!(0x64FCE92C_EBE6EFDA, 0x922D4FF3_F8728059, 0xB666F723_785A506D, 0x9C3E06EF_22892BF5; 0xDF140F32_A7AEFC7D, 0xD43BC868_7B36FDF7, 0x8AF0B2D4_4CE26FD5, 0xA7B709E5_E762923D),
+        point!(0x5B3A4320_14978583, 0xCDD6E9AE_F061613F, 0x49784199_90F92214, 0x30ABF89B_9CF23137; 0x44618035_1DC75777, 0x63120EA2_3BB584AC, 0xCDF7B4F2_D0E1AB80, 0x4B035115_477F7498),
+        point!(0x79127AB5_C6C88BE2, 0xEA2C7820_D06EE5E2, 0x3BB72759_D830775B, 0x5D6F8AA3_13E20F03; 0x892E553F_0D7AD75D, 0xEDAB6F8E_A6BF92C2, 0xC71AAF33_AB08BC20, 0xADC4B18D_8D56D4E8),
+        point!(0xFD0DAC4B_B50964E3, 0xA99F0877_DD1C6F76, 0x4AC11B48_D94085D0, 0xE11A6E16_E05C4407; 0x767FBF8B_0682BFC8, 0x17ADC6E1_38318C6F, 0xE1AD5E25_96F0AF24, 0x87D6065B_87A2D430),
+        point!(0xF289351B_92D1B844, 0xC69C0382_C173E484, 0xC86B15C3_C1388BC5, 0xF57D35C3_04A60D5F; 0x464BABBD_61266837, 0x70069720_30915C6A, 0x7904D3F5_A9E4634C, 0x707F3D9E_A98EF4D9),
+        point!(0x73971647_B21F51D3, 0x11F80D46_2C39FDF8, 0xCE8D419B_9DA10BA8, 0x6A73FB21_6726A955; 0x01824DD9_A5224B17, 0x2DB48246_8B60B774, 0x7FEA7D29_49721132, 0x4F3CEEFC_A1EDF3C2),
+        point!(0x234D007F_3CC7CB09, 0x3BC7EB68_A1D1B435, 0xF596E1FE_E3D87146, 0x13E7607A_3A6594A5; 0x1BE75A8A_08079160, 0x65EAE2BB_66B57641, 0xE325FDD8_5B34299D, 0x284DC88D_E8FBB8B1),
+        point!(0x39A37E63_EC288015, 0x4673F853_EEDC99CC, 0x681FAC61_A05B6AA6, 0xAA014EAA_D936DE6F; 0xDF814E66_46C1006F, 0xEF4B9B07_43CECAD9, 0xC3F2A7E4_3F7B0F63, 0x3DE913C0_4636BE9A),
+        point!(0x0060CE12_58CDEE05, 0xA8761FB2_172EC3B9, 0x2400250B_1B06901D, 0x29BEA322_568182E6; 0x9E4A0359_A62651C8, 0xF9FC31B6_C8C698E3, 0xA9009B3F_B8705EC7, 0x7C40D9A2_690E9A0E),
+        point!(0x4CF85578_F11D45F9, 0xBA4C1CDE_6C796614, 0x4EA2EF2C_77DB796E, 0x36AEE3D3_5337512B; 0x9D3BAB3F_7CFEBAD4, 0x004B35D0_AC473606, 0x79CB9547_07C98F54, 0xCAADDA35_CD69609C),
+        point!(0x11B6C756_12F18ADA, 0xD460BD93_BBDBDBCC, 0xD9C36FAF_F33D4A59, 0xF521786D_238288E7; 0x1DD68AFD_23953516, 0xAA5EF4A3_6C5BF098, 0xECC26102_BCD1D1D6, 0xE0686FBF_6038DB57),
+    ],
+    [
+        point!(0x13B7E0E7_42D0E6BD, 0xF774D163_DB0F5E53, 0x82A2147C_104D6ECB, 0x3322D401_243C4E25; 0x24F3A2E9_6C28B2A0, 0x2805F63E_A2873AF6, 0xBFB019BC_4DDAF9B7, 0x56E70797_E9664EF5),
+        point!(0xFC696D32_C0ADE462, 0x0D4CDDC8_EADBCF29, 0x120EF31B_04C80CD5, 0x8D262002_50CEBDAE; 0xD8E0A8B9_0F26470C, 0x1D4AFB4E_72678B3A, 0xD31F6F2D_C3EE36BA, 0xEBED3BB4_715BF437),
+        point!(0xABD9D3F2_059AB499, 0x0B13299C_6E73C330, 0x5D2196B3_C67F01BC, 0x78BAAFF3_015C05BA; 0x681D2318_FEE097FD, 0x91632EEE_8D125199, 0xAFCA84E0_ED82082E, 0xAD4BDCDB_DB06C0AF),
+        point!(0x9164643E_1516E633, 0x8ED4930D_072D9C8B, 0xCE4068A1_F594D03B, 0x1238C076_6EAEBEA9; 0x05CDB728_C77B7805, 0x0946252D_CC740228, 0xD6C979E2_D1C3DC17, 0x8A9DB02D_BB271359),
+        point!(0x4493E16C_FD06ACE6, 0x23709B36_F83A20CA, 0xC20B8498_4929AB1A, 0x6F70F211_A14AE3D4; 0x048BED34_B602D5DE, 0x75329566_BE5AC5EE, 0x6F95D8F3_47B99F50, 0x791E8A30_94027B73),
+        point!(0xC3063330_A5CD5379, 0x2DB79438_5870BCAD, 0xA782481B_8AA4D223, 0x17C072D5_6BDD1382; 0xF7CAE051_B108CD25, 0x8959AC76_265BAD0D, 0xE77C1247_AF1D034F, 0xD901BDF4_283DA064),
+        point!(0xDC8EE3EE_60EE1B40, 0x8CED485B_71E96247, 0xF80949F1_9103CCD4, 0xE1599DB2_9D6AA415; 0xE1D6265E_D78F93A6, 0xA6363A74_BC32999D, 0xEFAF894A_AA2FC7CF, 0x79336223_2A81D4A0),
+        point!(0x19B01552_788E7A66, 0xCDDCD728_2B0EC216, 0xE7B2EA75_8A6A11B9, 0x271D5B07_70CB9C15; 0x7A8D7258_E03C9727, 0xE2A065E3_508A824E, 0xE457D099_49AC877F, 0x5D3AA458_34E7F491),
+        point!(0xF81DFA28_49C00C3E, 0xF00E8F03_C91208E2, 0x436562D3_3D451859, 0xBB0B0497_04406956; 0x799A982D_11955A35, 0xFE67044E_905DC90A, 0x655D2FA1_7AB1B052, 0x4067E458_53AF9F63),
+        point!(0xDA1E87D8_A8A923B7, 0xAEF650EE_D81C5B30, 0x540708AB_68338266, 0xAC2ACB9B_21999A70; 0xA2DC124A_D7CD20A6, 0x60B0D0BF_12D27A4F, 0x1C9AFA0D_F13D9EB3, 0x76844285_11C1724D),
+        point!(0xE0E75B9C_05DD32E6, 0xC663B551_B53E5EE7, 0x9B649DBE_075A5FBF, 0xDC5A4155_4195789E; 0x80E7DB7A_754A99B9, 0x2FF2EE90_76E49BCF, 0x6DCEA5E2_01BF5944, 0x4AF3A8A6_3F9F67A7),
+        point!(0x5793C018_CA2D8DAE, 0xAC32A5F9_1FD25EA9, 0xBA6B25EF_9CB2256E, 0x88271C02_621192F9; 0xFB426C0F_3E1E77D9, 0xC300876D_C717D099, 0x401C8B3A_E5ABF5AC, 0xD719DD53_507176AA),
+        point!(0x9ED45BAC_4544E7CB, 0xA1064225_B5296035, 0xBE3354A5_71014E99, 0x156E1970_39873B9D; 0x6D5392C0_AD250A37, 0x9496D58D_5EB439CD, 0xA939572A_63834BE8, 0x6BC08D9F_8F31907D),
+        point!(0x8F5BADC9_9C89240F, 0x74F8C4BA_FA2E7B85, 0xE18EE091_97E0E176, 0x15B8390D_652D7338; 0x2E2F9EF8_E326F04A, 0xB30367AB_5C1A3335, 0x3ABDD7CC_C7A59F99, 0x786CF20C_8EFE8D08),
+        point!(0xDBFBC29C_C59853CA, 0x48E9626B_9F19BF54, 0x6BCA76A2_28E71613, 0x4269BCCE_CB684382; 0xDA958EF5_35B8D367, 0xFD3940A5_F9E5A8A3, 0x23C84CA9_C431A409, 0xED2B1C1A_82C016B7),
+    ],
+    [
+        point!(0x721D74D2_8134AB83, 0x741B3F9A_F7643397, 0x2BD1770D_89665868, 0x85672C7D_2DE0B7DA; 0xC8E3094F_790313A6, 0xE77F17FC_C5298F44, 0x6374049B_FA62C2E5, 0x7C481B9B_5B43B2EB),
+        point!(0x998B90BC_1F17FC25, 0x3B89EA46_DF2E6D96, 0x36C18612_15C8A61F, 0x534CCF6B_740F9EC0; 0xD71C7F6E_CFE86E76, 0x0AE3D277_BFDD28DD, 0x462AE3DD_32D54355, 0xD5715CB0_9C8B2DDB),
+        point!(0xFDF0723A_83BA9000, 0xC4872F9C_6825E8B6, 0x68487607_5840143D, 0xAC3874F9_FFF1D8C1; 0x4DA3C7D9_6F10CF0A, 0x085E350D_7F66E9FA, 0xB862DDE8_94117F93, 0xAA65E923_08A1C069),
+        point!(0xC7170923_E8BEE8B6, 0x79020D47_ECFBC8D2, 0x081E1420_18F8AAED, 0xA91D1F5C_EE87B7F3; 0x003D16AA_410644C1, 0xF800569F_628CB225, 0x5A7189C8_DDDAD3B2, 0x748A324E_E2DF8EE1),
+        point!(0x1A606F66_ED06DBD4, 0xEC0E3F85_78A20D08, 0x9AD14075_E9A3E729, 0x570D5CE7_AA687013; 0x5A65BECE_BD1ED495, 0xB683A36D_C6460BED, 0x05B66E67_11D01BBB, 0xA6AE5349_420E02F6),
+        point!(0xAE5D630B_17BA402E, 0xC3B81A5C_2D042989, 0x58BEAD0C_4848E3C3, 0x8E891B5C_D18FA02A; 0x8E1B6279_C4FAD9E0, 0xAF650C24_2157A7EE, 0x0B38D0E4_04F2A306, 0xE5D30E0E_6A9EC668),
+        point!(0x434C1F92_092D230E, 0xDAEE32A0_D2933928, 0xF87C229E_E0366EF5, 0x75B5F870_28268BB6; 0x037CBDFB_D51570B8, 0x0267A4B0_0511F8FB, 0x63D78745_54DDFA8E, 0x527CCE21_E3A78523),
+        point!(0xAA1A1C25_5984CF74, 0x0735AE45_BEF61F10, 0xC1A214DD_E2D4383C, 0xC15C8C23_D90C8E35; 0xC4A48CD8_39CCB000, 0x47BF772D_50B015A2, 0xC8DC6F45_E25FD7BA, 0x2BA954D8_28522235),
+        point!(0x47B6BF6A_C34FCC0E, 0x927F10A4_2BE3884C, 0xB0A5B80E_27D259C8, 0x44FC8EFA_E1EDDEC2; 0x9CFF031E_719C420A, 0x720E94A4_BCD0489D, 0xE49267E7_8833D3D7, 0xD2C7DE94_BA9B1367),
+        point!(0x58413F0E_8942DEA9, 0xAB35A98C_B8B7F130, 0x7946AD8B_A60A87FE, 0x15668613_34285DB6; 0xF92546F0_367BE3DF, 0xDE482868_8B23EDCB, 0x04E894EF_FB20FD22, 0x4F3597AA_0E82F051),
+        point!(0xD5A481FC_8BB69991, 0xFFFB3FA2_C3307175, 0x866A1662_0E02F535, 0xDEA2BA47_BACE7BC6; 0x706742A9_F16FE2DF, 0x229DCDB3_621ED9BC, 0xAC5CBFEC_84F7B9E7, 0xAE28BFD6_D90C28E4),
+        point!(0xE61ED61E_536BCFE8, 0x0E0C179E_D69B7BB3, 0x472CA8F2_306B319A, 0x1332F8BC_1CC6999B; 0x51B0DD2C_DAE78402, 0xBA93B424_D5A8C214, 0x13F67C45_35DC0681, 0xC39DC7EE_29602ED7),
+        point!(0x8C240D02_514FC9CC, 0x26D00ABC_29A46165, 0xEFC1CF6C_C4B8A836, 0x3968FC98_A6E168D6; 0x01939489_5FAD37DD, 0x1A146576_FF88F334, 0x1237982D_1AAD32C9, 0x789CBBD0_DB4B5928),
+        point!(0xC023AF3E_14CC84BF, 0x80BF1D6D_EEBA1F27, 0x830E3715_9C2A4953, 0xB5DD6936_4E540816; 0xC785217C_E374750C, 0x353110DE_DD6B1A90, 0x6BD18733_C58D4145, 0x4B36EC58_53C7CB6B),
+        point!(0x0AEC2191_AE0C85F1, 0xA6A52E53_603A3A25, 0x26B25DF0_957945D5, 0x68969106_98E06A29; 0x2C748233_2DC1DE21, 0x1F40C19D_A0D50392, 0x56F305C1_D845D72D, 0xAEFD3FB4_B38C9792),
+    ],
+    [
+        point!(0xFFD959AF_60C82A0A, 0x0F9226C6_0F668832, 0x6B06C9F1_919413B1, 0x0948BF80_9B1988A4; 0xD4CB7F88_D8C8E589, 0x6D4DFF08_C97CD2BE, 0xDC6B74C5_D1C3418C, 0x53A56285_6DCB6646),
+        point!(0x396EB045_7E8B000A, 0xEF16C133_1E825E51, 0x0D5CE4C6_6291F0B6, 0x26952C7F_372E5936; 0x8C3D401F_05EF705A, 0xDEBE398F_653D6731, 0x62BC893D_2D688422, 0xF513EA4C_5800A688),
+        point!(0x7282FE5F_B8C8AC7F, 0x641242EE_65E2AA52, 0xB5C3396D_2056F849, 0x9945B2FB_E3822BBC; 0x96D943A1_69AEA3B0, 0x282F7A23_EEDACDFA, 0x607DB44F_FB28EFF5, 0x3EEFED82_4B0F282D),
+        point!(0xF1025889_76134F96, 0xF5211965_72D6B0E9, 0xBEF2BE8B_131FF243, 0xC62E58E6_FC23C5BD; 0x82277ED4_D14CF97E, 0xBCFB8535_63731C3E, 0x8C3D6767_53141FC5, 0x4397827D_45B1A167),
+        point!(0xED1D79E3_969E353A, 0x10A04408_52BBE1F6, 0x235F8222_7107D5FE, 0x2A314C6B_205870E6; 0xC25926E1_E5746067, 0x138A54AA_DB2658BF, 0x1A463E47_6BAA1BA0, 0x15A4AC0B_F35A27AC),
+        point!(0x3BAA4998_92FCCF64, 0x3BD278DE_2581318E, 0xB068772C_77C95DBF, 0x0C7D115C_0EB4637D; 0x53F6D3B3_2878FEE0, 0x48785D83_959AE68D, 0x7AA424B8_478AF145, 0x4AA8747B_1925B044),
+        point!(0x89E2F49E_E9B84966, 0x1B4F4106_DD7F3FF9, 0x498B6FAF_3A6B6C91, 0x5959A500_B703FC2D; 0x40A66321_87473A6A, 0x9100DCC0_8CFE2426, 0xF9431282_0DC82A70, 0x0370E674_1F5CA897),
+        point!(0x3686F880_0B188CBB, 0xB81C0320_0807DE97, 0x1683329A_716622B0, 0x10746052_0EEC5C74; 0x01D7B636_1F272124, 0x242E0D74_8DCE3DA6, 0x35326B9B_9CF54A11, 0xABE5D4C0_9A21598C),
+        point!(0x3B0C9B92_2BC6B173, 0x7DD8623C_7EED3FEB, 0xE1160C46_305B5F9D, 0x9EEB3139_37222FE8; 0x9539620D_9723A71D, 0x0EA71ABE_D0E70C4B, 0x952AABFD_BBBA1AE3, 0xE121F1E0_110ED58D),
+        point!(0x457E9BC9_2AD2CF25, 0x7BA49B4D_BC927A94, 0x18D1E80B_A27B3C2F, 0x520D9A9B_F3DAA9CB; 0x610B6C83_A285181F, 0x2BAB0B65_11BF70C7, 0x48F0AE71_B4BA9706, 0xE526F49B_10271EAE),
+        point!(0x5772443F_7EC805F3, 0x7DE40F75_09D99591, 0x16EBB238_806E9788, 0x39CC4FE4_C7F718F5; 0xD191A0C1_3A3C48D3, 0xBBB95737_3FC912E4, 0x151E6693_CF42DE7D, 0xECB1472C_5A46F8F3),
+        point!(0x8B5AEBAC_548D5622, 0xE1DB07EF_7A51BD2E, 0xA39586A8_1BF3381B, 0x5FC6C11E_61689535; 0x0D0D8401_01AC1683, 0x0E05AD08_E6C7BF30, 0x9C19BA2D_B0367ACB, 0x10E80E54_9AA1D9DB),
+        point!(0x93D62FB5_CE22580E, 0xC7132896_D2D6F887, 0x6892FFDE_64DBD4D2, 0xF94C8074_66ECDC69; 0x81E523F6_7127DB82, 0x9887EC45_32371D4E, 0x9194BFF6_AB5A81F6, 0x5E9C7FDC_6785225A),
+        point!(0x123AB607_C0A3D8A5, 0x26D77D68_4573EF99, 0x3431274A_33ED09AD, 0x5AA433E5_D872D6E9; 0xFB5F7EF7_6D3C2BA7, 0x16475CF5_2D9D6EF4, 0x19A394AE_C728A561, 0xDDCEA1FC_682AE79A),
+        point!(0x6BE40BAD_10C4F21F, 0x5024A959_4D03DA7A, 0xFCC02068_DB1999D2, 0x8CCCB86C_6AD5D162; 0xF7F126EA_729DFEA0, 0x4B92DCAC_8264BE28, 0x699BEF54_9C8758A5, 0x57F89658_62751C43),
+    ],
+    [
+        point!(0xCCECD819_F38FD8E8, 0xF1B0E44D_FC69752A, 0x4F067CE0_F02873A8, 0x6260CE7F_461801C3; 0xC1A84E95_B2B4AE17, 0xECD29223_8051C198, 0xA7F09049_776A1EF7, 0xBC2DA82B_6FA5B571),
+        point!(0x0766746F_3D477C2D, 0x16E65C51_96AAD27E, 0xDEC8409B_E84F1A13, 0x85D8DA47_48AD1A73; 0x2079816F_C7D1DD70, 0x94B0A020_33C4D5A6, 0x586B5365_31EFC7BC, 0x58948B53_665C6690),
+        point!(0xBB0BA465_41136602, 0x38C46F48_0D9E3A5B, 0x3D058937_F2333B3D, 0x87D12728_0482DFC3; 0x4F683C41_D8AF6AAC, 0x87A88474_6FD3BF7C, 0x926A276C_FF677453, 0x71CE2487_0A5A03DE),
+        point!(0x4850312D_6C0B80D9, 0x4D1E7E10_0FDC47F0, 0x8C0892E9_CC3EE3EE, 0x8E2A7166_E7EC4B96; 0x49C61F2A_D6B29F50, 0x5297B688_D706349A, 0x2CEDD29B_716A9D48, 0xEADB0BA9_AE2CBE59),
+        point!(0x5B205D73_48C5A916, 0x3F5C440D_535610F2, 0x6B0ACC63_DAB54AA1, 0xFD5D7D3F_E261E974; 0xB14B37B0_7ADB8BDA, 0xE5D73814_BDCF6FAA, 0xD2B43CA6_79C7B52F, 0x0DD83ED0_EEB55B07),
+        point!(0x674A35C8_B0E74459, 0xFEF23763_87DDB6DD, 0x590F4658_713C8A91, 0x28DF781D_4EC05680; 0x8DE07CC0_EF5E656F, 0xA3795BA5_01AE6F0A, 0xCB0DB178_90F22794, 0xF1499EA6_6A130F17),
+        point!(0x45D99096_35F7529C, 0x38765B98_B5BD51DD, 0x2BA453C3_2D344381, 0xDE0DD410_981C2612; 0x63C20C02_E4CD88FE, 0x178924C6_889B7740, 0x9B210982_2D7A3570, 0xD70A6E9D_10A2145F),
+        point!(0x9921FA3D_2C4561BE, 0xEE4AB2E8_1359D90F, 0xDC8366EC_D78F8950, 0x769BC758_42BFF58E; 0xD5920BFF_B0D9685F, 0x41A17776_7B7873AD, 0xC8DCE4CE_F73F5D47, 0x4BF81736_2FE783BA),
+        point!(0x3C82F48D_38F76D11, 0x1511000F_BF851557, 0x69C5BEFC_39C292A2, 0xB26C208A_DA4CF44E; 0xDC1C00B1_E3B7356B, 0x05003065_9D82624B, 0xF0C4E87D_90CD6433, 0x1F1CF882_0949D33C),
+        point!(0x31239DA0_948E129E, 0x2BD1DFB2_C0289B10, 0xAF0F8DE2_8E53C2DF, 0x66E3FCEF_B7B24CF7; 0x72BBC360_2EE7BBEA, 0x5FD7D99E_D1A53B51, 0x598B5BF3_4CDF80CB, 0x20BF8B4F_B770C0F6),
+        point!(0xF94E3B06_669E22DB, 0xAC993FF5_9F9C8933, 0xA7E3BD29_018C294E, 0xFCEB14B8_FC7FF523; 0xBF572DB2_5C2260A1, 0xAD550E79_3A4EBE4C, 0xFCD8A5A7_525F0924, 0x64AA6B3A_40D6B8D1),
+        point!(0x346D9DC5_29C71143, 0x2E7EADAC_44F057EA, 0x3CA7CCF9_0023FA35, 0x0EB45F96_22BBEA10; 0xC8A8CDA2_0B70B136, 0xD65969EA_6A2B1BF4, 0x573601F5_901E2857, 0x52C42F58_5838BD0F),
+        point!(0x6F084179_A38A2755, 0x53721A71_6549C550, 0x55C17577_4B507FAA, 0x4E909A62_3CCD5CAC; 0xAF7A49F6_23CCA3DE, 0xB6CD5394_D229800C, 0xCEF246DD_507559DB, 0xAE56DA87_8CCE35AE),
+        point!(0x00CD148D_3C07D7C8, 0xE0F19675_F829E32F, 0x68470411_BF3D4899, 0x3CF9208A_B230B73D; 0x0C4A91DA_DA4CEE08, 0x9D4684C8_D5B8C59C, 0x51ECE6CA_65C16720, 0xDE03C64C_15AF865D),
+        point!(0xA8258959_983BA64D, 0xBDE60F77_88C595A3, 0xBFB0A145_EB8E40E2, 0xC367455C_22E04C03; 0x614CC8F9_6A2181FD, 0x42626AAC_A1778F14, 0x080E0E6E_84D964C7, 0x3A520AD0_69CB6033),
+    ],
+    [
+        point!(0x2953CC8D_2037FA2D, 0x043EC8F5_75BFDC43, 0x3D834841_4BBF4103, 0xE5037DE0_AFC1D8D4; 0xE0E5DC84_1D755BDA, 0xBD5F5B03_EC481F10, 0xF9F98D09_FB990BDD, 0x4571534B_AA94D3B5),
+        point!(0x8C63C8C7_9E3D34EF, 0xC3AB217C_792A2DDB, 0xF40B6CF7_D2D61B3E, 0xA5E00DA4_67FD5494; 0xB855C5CE_2F7ADB4C, 0x5B60DCFE_790900AC, 0x421726FE_99BF43D2, 0x098FE5F5_E5608555),
+        point!(0x388A8A6E_177E7775, 0xB5E15593_88ED95F6, 0xE58543BA_CF5291AE, 0x9D896A3A_FF9633CE; 0xEFCF6D3A_BA056691, 0xE899CD7E_E299253B, 0x94E964ED_7250927D, 0xDD91A9E4_3F49BF0B),
+        point!(0xE6737160_D7B91252, 0x6D4AFD2E_4477572A, 0x3519F4BB_1C9BFBC4, 0xA99415F5_EF3A2B40; 0x73A377AC_4BEDC264, 0x899A16AD_590F4DDD, 0xB9E2F10F_24F6F6B6, 0x82D0E64C_AE81F84B),
+        point!(0x5FDE04DE_3C2A3293, 0x5688B86E_E903476C, 0xD0EB0A57_3282F4CD, 0x8327B8EE_71163792; 0x6BC854E1_8E0DF9BD, 0x96AFDAB4_EE326A41, 0x18BB3EA6_62797084, 0x04997E26_6EE0A98E),
+        point!(0x15957F1E_8C904ED3, 0xDF2A7458_90585784, 0xF97F8E64_31272384, 0x00CF8C2D_2DB818DF; 0xB009963B_796F77C1, 0x22991D79_D32F6827, 0x19DD5C1B_D51BD811, 0xAAAD000E_A781D441),
+        point!(0x2421B26C_4562C042, 0x092D2323_4B8DFB1A, 0x97D6661D_8F9A8ED6, 0x5AE42AAA_2A6DB168; 0xF905CCDF_8F79269C, 0x94E0DB95_107CD8DB, 0xAB5C1DDC_60389D4A, 0x99D93A7C_05FF051E),
+        point!(0xD8C465B6_6A540F17, 0x4C750D70_5F0C132B, 0x7D8EDDE0_98F935F8, 0xB56F4E9F_9E4FD1FC; 0x46A2FCAE_0200102D, 0x21D42963_CBCCA854, 0xD3DC11AD_F0582D1D, 0x32E8E534_29CCA856),
+        point!(0xB3488128_5E85AF61, 0x4E8ED722_DD4AE766, 0x59AEB68F_F6C21B0F, 0x92C23AE4_26A1C8EB; 0x4E723669_C36A2B09, 0x2D23AD82_B6286FED, 0x09F217D5_C738D579, 0x414CF88F_01551BB4),
+        point!(0x280AA482_59E5D1B3, 0x48C9B789_FD6CAA3E, 0xD1E7E4C6_F0FCDB30, 0x3ABA5151_CAF6483B; 0xA85B678D_42F30087, 0xE1846359_D2DE6EE6, 0x9064EC80_47D72CC9, 0x8C1E7223_BFF251D0),
+        point!(0xCE2AF1B2_C7B5EBA8, 0x2DABEDC7_F918EA36, 0x88D0F417_6AF8DF42, 0xFEE5608C_76AFDF10; 0x3F515D36_8D0B9B5C, 0xE380FF42_BF905479, 0xB11402F9_9287C14E, 0x38075991_34FE58AF),
+        point!(0x1B2B5988_8CF8CFFD, 0x432E6B15_B7A45AE6, 0xD45AFB88_F79BDD89, 0x00809B7F_1FE78C1B; 0xD91D45A1_8DB5F32F, 0x12A340FA_D92B9F93, 0x0288C836_DE52FDC9, 0xF817E022_BF41A863),
+        point!(0x81E3BA4B_3C63CAF4, 0xA28FF3AB_28F53D52, 0x20A113B4_C62A6AFC, 0x42E544EB_92E667E6; 0x187AEC09_969C29C8, 0x73A50E7B_6BEFC5F1, 0xF71F220C_17415E78, 0x9FF854E0_F91CC671),
+        point!(0x6C61905E_F7ED9EF9, 0xAD2BA5A0_0EF0C63D, 0x41DF7849_47FC192A, 0x32DDE6DD_D352D4A7; 0x1ABB8468_031FB871, 0x59EC1B37_9C98F4B2, 0xE236A4F9_4D58C1F4, 0x41D17BDC_342067BC),
+        point!(0x80A2BD98_5E0F09A1, 0xF0A109F1_B9403283, 0x83996ED2_A4700EFE, 0x7AED83B6_65532CE3; 0x045D8CDE_85857D73, 0xD88B2F30_0F3F4A54, 0x7DDAFD0A_B1032D8E, 0xF5B8545F_9A31EF7C),
+    ],
+    [
+        point!(0x25866A0A_E4FCE725, 0xE7E8DBD1_C6A6C5B7, 0xF5EA905E_8F1771B4, 0xE06372B0_F4A207AD; 0xB27034F9_4EEE31DD, 0xD7484A77_87104870, 0x12A27BB2_AD5A488C, 0x7A908974_BCE18CFE),
+        point!(0xFE09AEE4_3ED2FF3E, 0x045EBFDB_4EC6ED3C, 0x9C8DBD30_4FAD3F3C, 0x0EAC134C_A2046B8F; 0xE1720620_7D210988, 0xAC19F696_B7F21376, 0x45BF103B_F2B11799, 0x49630DBE_79359B42),
+        point!(0xDBAA8188_DA328D6A, 0xB24D773A_95ADC18B, 0xA3468991_85B08FA7, 0xC663C05B_A6234E00; 0x23B0BB6A_BEC9B8C0, 0xDD8551EA_512BF9CC, 0xD39AFDCF_27571317, 0x3331E98D_5F721C38),
+        point!(0x476706E4_DFBFA4DC, 0xF5948A78_04C85B17, 0x8392119D_7ADBB41F, 0xD6788590_731FEA19; 0xCA7BCD6B_BD3B5406, 0x6206F1C4_DDC9A07C, 0x940EF5C6_D21C13AA, 0x28EAA8C8_9D5063C4),
+        point!(0xBC91C848_3996DE2F, 0x77CEDF2E_E0B25114, 0x9CEB30DE_EA0FE4E9, 0xD3FC2682_DFB86A45; 0x8C492241_D4526F8C, 0x4E59B498_DF7ABF16, 0xF68B4754_D4F781DA, 0xC4F0DF99_A45F0A18),
+        point!(0x6DC35A65_62143FB0, 0xA9FB9283_0BC205DD, 0x0CFAFF33_94799597, 0x292ADC1C_33AF8379; 0x8A9DB63C_E36AD01C, 0xD83E662D_735E9D4D, 0xF3F1968A_0CC4ECF6, 0xA072661B_CEE0B647),
+        point!(0x5C6C48D1_03E697EA, 0x9CE678FB_985F83E8, 0x7DCA1FEC_9A3FABD1, 0xC17A4B43_FEB2C023; 0x0EE3B87D_DEDC6C87, 0x96E1A1B5_7F9F02AB, 0xCAE178F7_19601FAE, 0x39355C2D_55AB5954),
+        point!(0x7275FB40_E48FF9B3, 0xC7B1A620_5599B01A, 0x4ABF210F_12B71D4B, 0x6930FCCB_D9A04097; 0xCFDDAB5F_8EE96A4E, 0x090F9B13_E4ACC51A, 0xDA30FCDB_875F6D78, 0x7F02AE94_B94701EA),
+        point!(0x82D2C302_3163DA1B, 0x9A0EDEE6_0F93A311, 0x68C9349D_7FC11FDE, 0x8F618B7C_A26790B4; 0xC7F31211_03BDD76E, 0x6450C66A_3249B6E7, 0xB5FBDA74_D7AC5A42, 0x78233F25_F08BEB6E),
+        point!(0x84FB09B5_4D0CEC10, 0xBA25F0FC_AADED6B0, 0xAEAB0E61_B2D3CE72, 0x4D952A9C_A3321E69; 0x8EE36B13_BBD06932, 0xBA4AA2DF_C84F54DA, 0x4542AEC7_87B5CB4E, 0xB178184A_66DD279E),
+        point!(0xEE8590FE_0EC8AE90, 0xCFB26B92_E8BBC5DE, 0xB86D5B8D_AC83F1B8, 0x9798C0F1_5B7A1E6E; 0x7A9AC2EA_E52844D3, 0xE2E4965B_D03188E2, 0xDD2EC949_4F9C73BE, 0xBA40E2AA_75A42ED3),
+        point!(0x89EC1E6C_30B90E0B, 0xD6FF1D39_FB094A45, 0xFEDC804A_59C675B3, 0xA43507F9_AD5467D3; 0x3F000B99_3A1FDC20, 0xDC2A2036_53599B5A, 0x142D07CC_B40BE8C0, 0x7C8F2DC9_4C3FD71A),
+        point!(0x05A1978F_11D66B7F, 0x7AA69250_5187DC81, 0x47CF1B90_B8928475, 0xFDFA6EE3_FB5A2D67; 0xDAA8F806_78E5178B, 0xD846F9DD_13D78DE9, 0x93C0C1C9_A3DA18C4, 0x4D9CF31E_224998DB),
+        point!(0xB836B586_59C0D7AD, 0x1DDB64DB_7A2B493A, 0xEFE11CA1_A749E671, 0x72B04ECD_4B7BFBBA; 0x2EFE6FF4_6723CD97, 0x96265D68_1DC6F4FB, 0xCEDD452D_6D934D01, 0x6865B3EA_0E64A358),
+        point!(0xB8075784_9265BCF0, 0x69CE2B78_411C7D30, 0xADDEA9A4_61CF220E, 0xFD58CE38_DE229E43; 0xF33E7A9C_EB996292, 0x87261BFE_28F1CD1D, 0xD5382AB9_A485E4FB, 0xFFE0A5E5_3763215B),
+    ],
+    [
+        point!(0x40AD6908_D0559754, 0x04B10BDD_E2A3F585, 0x58D0BBF9_DC0CE022, 0x213C7A71_5CD5D453; 0xDFF2C275_34B458F2, 0xBB4850F5_F36A7EED, 0x7013AD06_245BA190, 0x4B6DAD0B_5AE46250),
+        point!(0x8993895E_0EC87FAC, 0x0D1AB974_622E7CF0, 0x66AE9FED_8323480E, 0x1C5E5481_32B49A7F; 0x555D7B3D_5FC2D4EF, 0xA3DEACEB_26FE324C, 0x2BB959FA_1D4C2AD3, 0x4FFCF60F_837F468F),
+        point!(0x532D8011_9E05DCCC, 0xAE3FA3ED_4C19A93E, 0x9546E096_B953D172, 0xB8CEF6E1_753DA030; 0x3014A0CF_CC6D5750, 0xDF757FC3_6A6B6813, 0x6A4D4A74_E4D2BD99, 0x302B8A60_A6CC9BBF),
+        point!(0xE20FCBA1_D4531DBC, 0x1F901C19_FED5C970, 0xDEF6E942_10BBC7CE, 0x46276D06_02C5668D; 0xAF873009_9686B8E2, 0x498BADFB_FFE1BC99, 0x4A292287_570DED99, 0x0E0F7F24_D44C75B8),
+        point!(0x85BDFEE1_373BB31A, 0x701F7B6B_5FDB97B4, 0xE02A0BDE_2EBB5F49, 0x03FB33E7_79B47385; 0xE34CBE69_7D215C9E, 0xC65A7C76_C47640D4, 0x1E0C161A_ABBB572B, 0xF36AD952_548EFE28),
+        point!(0x0C4EED01_686DF50D, 0xCD5E792A_1C6F92BD, 0xD6FD8744_2C082060, 0x3A571630_935C1F02; 0xD2158B28_E859679B, 0xE0AAF81E_BB781A11, 0x516F3FF2_E570A0DD, 0x85E13873_B599F32F),
+        point!(0xA5B515EB_DD9A4AB4, 0x717C36C1_855BB7C0, 0x61F16F7B_4D0F7A36, 0x4B177CD1_09EC3E11; 0x8AADDFE4_635AB6F7, 0x6E37E255_F1741F55, 0xFDA8F672_93626B48, 0x3EC966E9_A5E2FA65),
+        point!(0x6114EF13_522F001D, 0x6850E0AC_DC78D899, 0x4E65EB21_1C319163, 0xEFEA68EC_A7A6C24F; 0xC128419F_73BC4415, 0xE413959F_B3848771, 0xDA150307_A3719A17, 0xAAB84786_9D583C14),
+        point!(0x6F46FB29_B01C23A4, 0xC22A4158_47A49CF5, 0xC2A03829_B9B9CD75, 0x5E870352_06A27A06; 0x1217996B_10D986F9, 0xA2171D87_4A4CCFDA, 0xE71755D1_AE2C6E2D, 0x5B96644E_FB9C9221),
+        point!(0x8B41EF22_C291DC6E, 0x661FA8A9_4DF29FBD, 0x5C7136C2_3A3E38F2, 0xCF0BDF47_A3F15B24; 0x9C73C144_DB7A8196, 0xB4FC6CFD_2BB5F2A9, 0xA4C523B4_E57918F6, 0x38C2E01C_6E61F511),
+        point!(0x1F3F35D2_007B0C66, 0x34A7FEE4_656C86FB, 0x4472244F_60ACEB74, 0xE545C301_930D680A; 0xD87F57D2_71F2D470, 0x97E5828E_1B5A239D, 0x7E5C6FDA_3959BCBF, 0xE06A340E_8C62955E),
+        point!(0xE2AA2B67_F88F7E09, 0xB3A03779_BA7D92CD, 0xBD8E12E5_A45DC0BE, 0x5E6A863D_4F205FCB; 0xBBD62867_48DFCA97, 0xA68E5037_23B4CFE3, 0xC444A8D1_F6940234, 0xC2571A07_8A388EDF),
+        point!(0x4A58190B_83648BBA, 0x82A0F0B0_69C3C744, 0xA133EE22_5F01B398, 0xB2A44215_7A54F580; 0x246FAB4A_00FB6452, 0xC0D85515_1C9C17BB, 0x9E9FBC97_8FBD7E31, 0xFE4F5FC2_B6936661),
+        point!(0x679E75E2_B304AB91, 0x25A40C19_42451557, 0x8A638306_3B7811C3, 0x07A1FFEA_8FE5114B; 0x35AF463B_6797E554, 0x287E5DEA_19009D9D, 0x6910437C_4344A595, 0xE10C0421_B0FD7C54),
+        point!(0xCF6DD22E_EBAB27D0, 0xF70B41FA_2C45103F, 0x1BA97019_18D97007, 0x1B908E0F_3D453865; 0x71E8675E_40D3D110, 0xB5BBCA93_587E51B5, 0x6F357C91_817C4B9A, 0x9B6D625E_EC466B5E),
+    ],
+    [
+        point!(0xF0CC3A3B_08FBD53C, 0xE2838C70_ADC62CDD, 0x8DBB9352_A5419A87, 0x4E7C272A_7AF4B34E; 0xE0B39418_17DCAAE6, 0x530B9614_BFF7DD33, 0xE16FD09F_6DEF681B, 0x17749C76_6C9D0B18),
+        point!(0xE2821E6E_7C6E1B4D, 0x9B11F25A_1BEF8790, 0x268A269F_4E385D9C, 0x899017B0_2696888F; 0x009AEBDA_D814AB2B, 0xE4E51BC0_F932B212, 0xBB457983_36358BFA, 0x43AE2CDA_B5B334F0),
+        point!(0xEE298464_E521B3FF, 0x233C0717_E9AA750C, 0x75E44D2B_E9AE24A5, 0x02484E30_10C9455C; 0xCC1AE4B9_0269DA7E, 0xAD7006DE_923AC8BB, 0x07EC2B3C_2B2D0EEB, 0x9619D0A0_AA23E30D),
+        point!(0xAF2635A1_7E5F712F, 0x2831F5BF_91B583A8, 0xA8F4728E_63227F0E, 0x67F644F7_6E905FD4; 0xD68ACB5E_707160E5, 0x5E0E1488_F7D36198, 0xF05ADEB7_B586CF78, 0xB833D68F_66445D04),
+        point!(0xF96B79FA_804BA7B9, 0x20D6A470_30741751, 0xEF79B70D_672C954E, 0x16C1C526_CFB6CE57; 0x475BE3A5_1C5BD741, 0xEC755C01_BDC9A9CC, 0x06262745_9C8A94DE, 0xDB157F7C_34031439),
+        point!(0x2B260961_40692FD1, 0xDCF43C6C_CBD90DFE, 0x0E0AADEF_4B1F190E, 0x4E53C8B4_9901FF80; 0x934930E7_328625E0, 0xA87A50DA_57FC9753, 0x186CD709_A7219DA1, 0xDD6E3E4F_4D41A01E),
+        point!(0xE836487A_A36683FA, 0x95821B53_80EC0825, 0x87A89CE7_74527B66, 0x03973CD7_53F931AF; 0xDF5FB2B7_7F4A577F, 0xD77884E1_8FE981DE, 0x643E12B6_32B5A26C, 0x38CF5A2C_C30CA3A3),
+        point!(0xC10263ED_3B89A762, 0x930DC930_12EE6B8D, 0xFA80A054_968B4712, 0x327F876C_93652555; 0x9C9FD959_B9203301, 0x75535070_FEBD7DFE, 0xB0996925_5E1997B9, 0xB2D404EA_B3524026),
+        point!(0x180B3999_C0BF6F06, 0x713C1332_25043B57, 0x1C2C04F4_B0205765, 0xBA6A9BBA_72ECF151; 0xE32BEF70_781DB551, 0x9E5D14F7_AC697AAF, 0x32020BF2_E558BD8E, 0x13771E38_B34A11B0),
+        point!(0x799F1199_55637B39, 0x6C52B998_5EC75FA2, 0xC3D5B928_6C03016A, 0x0CF3C5DA_6B99AC5C; 0xD247A3D7_A2852A40, 0x37ACD305_23C25C3C, 0x134671AD_C18024A5, 0x02CFB52F_D9F39B64),
+        point!(0xFD1EF9F0_71231A43, 0x44D514D6_65AD43B9, 0xD8AED950_64DE442F, 0x35C5BCCC_27CC4DD2; 0x7DB72E45_1620C8F6, 0x4CF7A4CC_063CF0AE, 0x734E5820_B6FA589D, 0x0E367572_3B9746CE),
+        point!(0x48CE357D_B549827B, 0xFCC4F7F3_E121BD61, 0xA8212E06_DE2CE311, 0xCE47D0F5_E0D375F3; 0x43CC9A5F_EFAED097, 0x29E6592F_F05769DF, 0xD589139F_E5B9AD0F, 0x15C698FB_99630D49),
+        point!(0xBF40BC56_FDD40609, 0xC7419098_0D81CD76, 0xA122F12F_C588356A, 0xFD479C24_5C5586BC; 0x9DE2F136_6A64CA53, 0x9113AED7_261CA473, 0xF2CAD7A4_3C9841FD, 0x3069CFB5_2694AFEF),
+        point!(0xB38FE715_7D48BE00, 0xF60682EA_1698FE13, 0x4EB0E220_7F9543C7, 0x931BF8BD_4A8E42F3; 0x1586A913_1F44F452, 0x6A43EE6A_B333DDF5, 0xD1B388E6_1140EBEE, 0xF1153BF0_489C2083),
+        point!(0x4DB702F5_2B799A7F, 0x4F18B6CD_53B8E842, 0xBDED8EB9_AF2C7D54, 0x6C40002A_3ACCD864; 0x8B5CC82C_D3C81E32, 0xC07ABD4C_820483F6, 0x690B836A_0E1DDCBD, 0x95C2DC15_30B635BE),
+    ],
+    [
+        point!(0x32427E28_40FB27B6, 0xC76E3DB2_BE430576, 0x10F238AD_61686AA5, 0xFEA74E3D_BE778B1B; 0x701D3DB7_F23CB96F, 0x126B596B_973F7B77, 0x7CF674DE_CCB6AF93, 0x6E0568DB_9B0B1329),
+        point!(0x8FD97C96_1F9756E4, 0xBB570EE5_DE373048, 0x180E03D8_50E8CD0E, 0xED9441C8_304280FF; 0xFF0E09F9_3F3ABFAE, 0x09F23774_FE4DE98B, 0xAFA17612_8B13911E, 0x3DBE9E9E_FE8BFA19),
+        point!(0x5DD81AE9_BE889756, 0xF27B6499_7B004BB2, 0x226CD97B_271899F3, 0x762E8BC3_3211FEA8; 0x25E259E0_7CA6B774, 0x1972DB31_4884FA5E, 0x3C7CC4F1_4982E347, 0xC0289426_0AF3E97C),
+        point!(0x15BAD033_D51CF119, 0x5B10BDD8_4FAB4D30, 0xC9FED3F6_24B48751, 0x29D9698E_E67A7C3F; 0x3C887405_75056339, 0xB89C940E_93A7C296, 0x277A1254_04F1C96F, 0x7FD02C51_7DC82B45),
+        point!(0x26F75E97_0975D2EA, 0x1E52ACFA_1014E8EA, 0x8E19BDBB_2308F4A9, 0xDF077D47_DF609534; 0xAA3C2D9E_31936F95, 0x8A1EC5B8_4FBDD277, 0x24C8425C_98A2527C, 0xF8617A88_00EF7F44),
+        point!(0x875580A5_A6714560, 0x247F2102_7E56C6C2, 0x9D47EF64_F1A5A85C, 0x38B82A75_579AD36B; 0xADA87334_A774299E, 0x7D94F23B_EF716284, 0x28BF5634_61643459, 0xF9D8A697_6F261EF4),
+        point!(0x5B8491FB_BC4C92D7, 0x35DB4D6E_E54391B4, 0x2E17DEA8_334B1429, 0x9F3E7D75_8BD3DA03; 0x6CBBBFCF_B14906DD, 0x452A2303_D694E118, 0x58862B21_CBAB1502, 0xECD2841E_A77D466B),
+        point!(0x467C4453_7F422491, 0xFD453E4A_86060CFF, 0x6F3FB7BD_33580A31, 0x126B57D0_5013936D; 0xAFA31F19_9DA3EF84, 0xE148BAC3_0BF39347, 0xE3C4A3EB_A2BF3FB0, 0xC1A7DC13_061662C2),
+        point!(0x5AE0D732_B2A8C483, 0x174B0C88_C5040AC3, 0xDC38C3D2_2EF95281, 0xA0CC795D_7B5CCF9E; 0x50967455_92CC6BA9, 0x6B348F1B_DF693605, 0xE2374FC9_7231DF78, 0xABC30122_F8B3873E),
+        point!(0x0515623A_3AFAF403, 0x38A0AEFB_97AD7576, 0x70CFAFFA_C8A0B5BF, 0x1A241179_B9E81F56; 0x590B6E40_E4D79A16, 0x2C8177F4_178DF60D, 0xC4BF2749_3905B76C, 0x8E1CA0C0_A86740C2),
+        point!(0x170F1B6B_C5DD3AEE, 0x13153A8A_8F96D2F2, 0xE0E22A9A_C6A976CA, 0x6D1C50A5_1553C7CC; 0xFEE354E4_FDF597F7, 0x851E310A_A8ED53AD, 0x97727200_2287D474, 0xAFFF148E_06ABDFDC),
+        point!(0x7622EB04_9B720B26, 0x26F2835F_98CCFCD0, 0x1F46BBED_41390620, 0x3E10C5AE_9AC968EE; 0x346E98C7_ADD92DB5, 0xE2FF79A4_DBE09E43, 0x5A8665B2_87215E28, 0xC735C6CF_FE428891),
+        point!(0x678CA9B7_E4A6D0BB, 0x659D3122_F5A1AFDC, 0xF311A6D8_A8A6418F, 0x5E5F1D61_8B97F9F3; 0x16838479_1033EAF9, 0x72555F2E_506F653A, 0x358F6BCD_04E721DA, 0xD7B1502B_06A7E6F7),
+        point!(0xE0083A7D_65543CB4, 0x3C4529B6_C22D0299, 0x8AB684D0_580EA8A0, 0x73F87BAE_9734854A; 0x3D2DD872_C4856CDD, 0x9D5C42F5_357F2D7A, 0xEEBFBFC1_1A140289, 0x37C66CF0_3A87F86F),
+        point!(0xDDA9B5E4_4F005E3F, 0x5AF68E31_BEC39BD2, 0xFFD3CB9A_CE01A149, 0xF8138A6B_3C16427F; 0x42D7E020_2F357EB7, 0xF4EC41BD_554BE213, 0xF9D015E5_7AA5CB51, 0xCA758F3B_EFB4EBD9),
+    ],
+    [
+        point!(0x52C02A44_17BDDE39, 0x1544E179_B7604329, 0x10A2570D_599968D3, 0x76E64113_F677CF0E; 0xB4B1752D_1901AC01, 0x5E2A33D2_B56D2032, 0x577066D7_0681F0D3, 0xC90DDF8D_EE4E95CF),
+        point!(0x1CF999A8_3A1187A5, 0x005D5762_2C29AE69, 0xEE87C9D8_8161C810, 0x708A530E_9E52C73B; 0x58A4F19B_473DB9C0, 0x3ECDA73C_6D353E8A, 0xFA9656DC_BB6D3828, 0x9B884811_E1F9A897),
+        point!(0x2F05091C_C078EE8D, 0x4EBF20CE_50691944, 0x25FF7263_AA9B4FF6, 0xD08E57AD_859DA9BE; 0xE997F4DC_2DA63E86, 0x123EF7CF_9422ED9D, 0x2D6172EE_757E6DF4, 0x852E9798_4AB488D7),
+        point!(0x2E1B16C6_CDE4F5BE, 0x9F374B6D_86B2B59E, 0x19BD6483_95E462CF, 0x19CF034F_C48B3BE2; 0x0A91532B_6F321AF2, 0xEF91D1C9_3F0F1C0C, 0xC3B4BE68_AB181947, 0x28E32B06_A15AB466),
+        point!(0x15914670_429129EC, 0x0CAE3ACF_1E482548, 0x5F58BE80_ECD31D08, 0x7DA6C085_E4D44D27; 0xEB50AEE2_ACD9FF0E, 0x0448C086_54CA586A, 0x511D0207_491627BF, 0xF498146B_B9F41857),
+        point!(0xD43AE543_0E9C22BC, 0x01DA67D6_91BCC42F, 0x765B3444_D9BC7BE6, 0x5335CEA5_E99EEB23; 0x3C2C2672_CBDACB60, 0xC8C30C23_6CA1F19B, 0xA067F080_F0CEB86A, 0x3BF8D020_769C5224),
+        point!(0x4F83D495_51654F22, 0xB2F7F394_231AAEE9, 0x21BE9001_BE69D94F, 0x90D090CF_F5C1BE6E; 0x0F5DE057_601A43E1, 0x6DD63565_3DA3F874, 0x953F021E_06BF7033, 0xCD569A1D_2BACF61A),
+        point!(0xE3ABC209_D17CF3E8, 0xEE93BD03_4BEA2219, 0x7C719C2F_8397F576, 0xAF6C44A0_78CB5F0D; 0xB8ADD060_1751BAEA, 0xEC362AEA_7CA0D435, 0xAF9E7315_3CB246DF, 0x0784096F_E85D4B30),
+        point!(0x1B6EF651_C76B19FA, 0xD9D6E06D_068819D7, 0xC7322C31_0821292E, 0xDA479858_7CF1D1EC; 0x6AA26EEB_326F5AF7, 0xA67BD13E_9553FC20, 0xD1736907_7E16569E, 0xEBB1D778_9BCCAF01),
+        point!(0x6D2F91ED_D6569044, 0x33C0396C_FA0CEEF9, 0x9D42BAFE_95C58F30, 0xAA21C60D_6F7D6253; 0xC1924C41_C2F37766, 0xEE5ABAC1_13928334, 0x6D1D26AD_D41ADF4E, 0xB2AE6886_FC4E6AEA),
+        point!(0xCD92FB65_B9135DBD, 0xF0C087AF_39D8631E, 0xD817AAF5_82E9897C, 0xE3E47504_A8DDEE6A; 0x6906AF3E_4748045D, 0xA5A202FF_5EC95DA9, 0x0B98C819_52B83FFE, 0x930A5BF8_09CBC6C4),
+        point!(0x5BC1E621_4FF4DE54, 0xA5CABD91_E9D3C2BE, 0xC3B4031C_F14A2858, 0x5716DC35_5DF202A2; 0xD3EA4FD5_50100FA5, 0xEBF3FECC_D047E417, 0x91EAA661_A99324C5, 0x985F6C40_0A5B83F3),
+        point!(0xCBF0B9E5_CDEE455F, 0xFF67FFE6_E8CE4075, 0xA4745338_F80BDB4A, 0x7E8C656F_E870A9B0; 0x173DE998_171B9CBA, 0x4AA2D167_46F46627, 0x2A3F760F_4803A53B, 0xBDDDC632_CA30740F),
+        point!(0x1E61500D_9769B57C, 0xDA5033BC_39E2FC7B, 0x5348F192_5BE3F04D, 0x030651CB_592D282B; 0x7FF3A505_F33236E3, 0x192A4D18_739F7805, 0xAC4E2423_E85682C3, 0x25208833_BB7AC098),
+        point!(0x37796B9B_6698B59E, 0x0A335925_893447A4, 0x5BEB035A_B3E3C726, 0x3DF841F8_91A95EB1; 0xBCF8E679_6B92BAF5, 0x7BFC867B_214949F8, 0xDA02BAE8_F11CF386, 0xB38FE6DF_98B090C1),
+    ],
+    [
+        point!(0x3AB15024_2BCBB891, 0x8F7CC643_DF26CBEE, 0xE8281BAA_743F8F9A, 0xC738C56B_03B2ABE1; 0x17E735D9_699A84C3, 0x82314EEF_7880CFE9, 0x7F718F2E_ACBFBBBB, 0x893FB578_951AD253),
+        point!(0xDDAAB078_4662AB1B, 0x647197EA_49B8C9E4, 0x35B32A69_92E7AA94, 0x5578845E_CD7C0374; 0x316D18F3_056F3511, 0xF653A774_6A5D64DE, 0xCEA6D0A5_1D2A4053, 0xE61D0797_8B6DE2C3),
+        point!(0x34BAAF33_8761D58D, 0xCA4C9BE4_08D60E2F, 0x10A240A3_5720DF7A, 0xB8C46127_823F6146; 0x638EA0BA_9D1051A4, 0x3F750478_5E107C5B, 0x14A458F6_97F3C505, 0x8F9ED96C_5170E37D),
+        point!(0x94F869B8_948B6C29, 0x22F12354_FCE39960, 0x4ABFA3BC_1D0CECCD, 0x47F33838_88A364CC; 0x1EDEE112_0E537EF9, 0x18BB4995_88F994A8, 0x190E4867_5B416C71, 0x48CA9A8D_0F032937),
+        point!(0x2DB0B304_050B0040, 0xF3F47DB9_F0134ADC, 0xA350C993_FE9A3671, 0x08D56E9F_710271F7; 0xB58E267A_5B3FD0A1, 0xF6D7C472_0E1782BE, 0x1FFD150A_8D79B285, 0xA12185AE_BD0A9AA2),
+        point!(0xA14DEA1A_1166FF40, 0xCAEEBF4A_41FFF36D, 0x7C78125D_8E480A2E, 0x6B004033_18818D2B; 0xEB74130E_9D71B847, 0x076883F6_E7F3EDE9, 0x968943A0_590BF2F0, 0x41CD1B3A_E8AAFAC9),
+        point!(0xF56563DF_13573B7F, 0x889A1C5E_D30B6270, 0x41E20F97_7BE65A37, 0xDC13F232_D42FCE63; 0x42ACD228_4C1F2BA6, 0x70455470_17404B1C, 0x3C7410DA_84A90A76, 0xC909BA80_429E340C),
+        point!(0xA81A6C8F_05FF4ADB, 0x14F570D6_FCBEF768, 0xE466B4C9_C6A5D5F6, 0xC0C01F34_AE41B8CF; 0xFC4EFF73_AC351065, 0xCDBC43D1_70D15B85, 0x7C937A0B_4075B8CE, 0x0B84F5BE_E4357F5C),
+        point!(0xB02F590B_87AE9CEB, 0x226C21EA_70EF2D14, 0x3AF19565_DB2119A8, 0x25C02DE6_01DF7F07; 0x47090D13_4BC6E275, 0x37C641DF_0A658726, 0x0C884E0D_CFAD11D5, 0x8A9FEAD2_C812383C),
+        point!(0x365019D7_932C1A1C, 0xA2F32B1A_022BDD6E, 0x3D229EA4_00B63B93, 0x3A932FBC_468ECBDB; 0x84AA9269_7D343540, 0xC0417E15_749A28B6, 0x01C9547D_4DC81D0A, 0xAD3D8054_0F3457A8),
+        point!(0x1F8098F6_EC5A3C34, 0xAECE82C0_295949FE, 0xE8AB9F4C_A7AE3E58, 0xFEDD9D1B_2CF8E49C; 0x62B54BF4_E13D7714, 0xADAEB546_99BF8297, 0xB0BE8531_2C8F4B00, 0xA52E24C3_1853B8E0),
+        point!(0x0452106B_80D6BF1F, 0x46BD033E_9824FCBB, 0xA3521E92_76B346B9, 0xC3337343_451CB83C; 0xF95E5842_3CB01F48, 0x98BA3642_0E266162, 0x13DFF31A_F72C3155, 0x2EEFE939_1B812CDA),
+        point!(0x9B4E54D1_AAB6A396, 0x73021B91_A1136076, 0x2DEE2D7F_5E8488B3, 0x3E0E3286_BC6EA48C; 0x2EB31E48_D980E27E, 0x6AB01041_F3468F6A, 0x4F31FE1D_EB453FC6, 0xCA448172_FA3B3796),
+        point!(0x27BF4C48_DAF027E9, 0xD7CA75DB_EE54677E, 0x3C3C7CD5_37ED6D17, 0x5A62873D_3EA83E25; 0x3199D905_844C68B4, 0x988AF430_9CA675FB, 0xA76409CF_EEC57A43, 0xF6D94479_A5BF6AFD),
+        point!(0xAAF35862_341023EC, 0x5645D8F7_6FECF570, 0x4982E020_0982B9AF, 0x344AB930_80C32D28; 0x04F89829_1E1EEB87, 0xFC4E976A_FC3995D7, 0x73F8F2D1_EA514C5F, 0x6E1C2B04_2B24462A),
+    ],
+    [
+        point!(0x372E9F65_88F6C14B, 0xD1D72E5F_3A925014, 0xE264C763_7C972877, 0xD8956265_48B65B81; 0x79363ED7_5D7D991F, 0x03428D63_2BB067E1, 0x728EC608_18C340EB, 0xFEBFAA38_F2BC7EAE),
+        point!(0xBA5B594B_77078424, 0x03ECAF7A_0EC40C3F, 0x8A3A4362_2003A267, 0xFD136EEF_8971044E; 0xCD61EEEF_C671DDF1, 0x7CF2B1F7_8A2ADFA8, 0x67A1D191_B5C5EFA5, 0x218DA834_F3C652CC),
+        point!(0x80BA87FF_6127B756, 0x03428BE4_ABA09704, 0x72D362DA_5060B416, 0x6D8C782F_716DF126; 0x6551F74A_BF172571, 0x4E3AA6DA_2D7CDCCA, 0x1459A82D_36D34DAF, 0x99AEDF08_96FDB911),
+        point!(0x5F1D84EC_8DB1CB3C, 0x1B7003A0_D43F024A, 0x0E9CCA53_67519F86, 0xD99E8E9D_D9638D14; 0xD88A29E3_6B8637A7, 0x6286FEF8_FFC8765C, 0xA945BB32_1BCEBA6E, 0x36DC19AD_1CC0A3A7),
+        point!(0x4DEE1A73_758CF17A, 0xF1F85DCB_A5882352, 0x8D059AEF_1B4097F8, 0xEBCABEDD_95BF7ACA; 0x446CDC5F_5CAA0CCD, 0x10FAD212_D0ACE95C, 0x02C00A1B_67C32E6B, 0x47D3CE0F_8F22B9CB),
+        point!(0xE4C4B2C5_51A3C43C, 0x0DF701CA_8ECEE258, 0x48CC1F2B_4AAE6714, 0x56C9DA94_67CACD5B; 0x824D8B7D_20D7C9ED, 0x41D40FF9_32EDCAA1, 0xF33C7964_DDCB6852, 0x38D46ACB_42C79EC2),
+        point!(0xBCEEE515_AC855C5B, 0x1185621A_017C8AFB, 0x45331A36_9E17FC28, 0xE8DF4D2E_4BD4CE24; 0x59D5E72C_2E465650, 0x9663B55F_1E1E4D7D, 0x4444DB4F_59EF32C5, 0x6C57FD70_C47F9B26),
+        point!(0xFD46F68D_3C385172, 0xD203351E_0440E636, 0xA1BD8A54_E5B09191, 0x03FDF161_9A198317; 0x79AC67F0_FCCB9794, 0x5B9B929E_90E7232B, 0xFE470C7D_3C857375, 0x408D02C0_6E5C12C3),
+        point!(0xD08F5BDA_52E7E454, 0xFE32EFEF_0F0E6150, 0x91248602_3A981662, 0x4068D3D7_18ECA832; 0x779ACDE5_D8EF191E, 0x988D21ED_13C37332, 0xB9EC1459_7D9D38D1, 0x8CD853B7_254A47AA),
+        point!(0x400C1FF5_35D8247E, 0xCD4780C2_511691EB, 0x56E101F9_AFA0B7A0, 0x33EAC9BB_A2C355CE; 0x37216AB0_AF3DA9BF, 0x84F8CF37_B7EA407F, 0xD4461F2A_FD15EF2D, 0x35973173_E4E17C41),
+        point!(0x0857ADF8_46C5D939, 0xA475A287_7BA933DE, 0xB55BF32E_60CA027E, 0x12550ECD_B1D825E0; 0x92A74FC5_59E757C9, 0x838B741F_ECD8D431, 0x5448E43C_17724A72, 0x7B8F8334_F872D7A7),
+        point!(0xB9E249D0_2DF771C8, 0x335A5211_EE094C87, 0x9D32C2B3_C01F8750, 0xB71E546D_922DC902; 0x2453A26F_93F48C51, 0x26BD522B_55BBA286, 0x78912A4C_CCE288F4, 0xE7CCEFA8_CEC9F900),
+        point!(0x38D0AAC6_56C8A56F, 0x85093C54_34F37421, 0xC69BB7B5_3AFAD08F, 0x944D671A_C3B585D4; 0xB9F4DC65_DA7BE289, 0x67D4C174_DD06517E, 0x4C143936_133AC619, 0xA7106597_7B501FFF),
+        point!(0x68F73092_D3194444, 0x850FEA94_BAA7FEA2, 0x61223966_6D87A19E, 0xFACF5D96_BA1526B8; 0x552BF488_44BAD676, 0xBE72B245_B947D00B, 0x935B7756_392DDAC6, 0x7CEB907B_B8AD44F6),
+        point!(0xAEEB8A18_AE08F15A, 0xFD69E56D_C812900B, 0xFE6B0365_5AD3C305, 0x86A54E91_CD099A0C; 0x3524D1E7_C1388308, 0x77129D12_5B35254D, 0x3A72A8D0_6D756867, 0xCFEE6148_37A78460),
+    ],
+    [
+        point!(0x49150A56_4F676E03, 0xCEFFC736_93E84EDD, 0xEB0F6433_571E8761, 0xB8DA9403_2A957518; 0x1488E4E7_4EFDF6E7, 0x92CC584D_95FF3B51, 0xD7C99CC9_762808B0, 0x2804DFA4_4805A1E4),
+        point!(0xD413F414_C5AF726A, 0x469A3E5C_B25BF6E6, 0x53F2CB69_8AB620F9, 0x6D36D105_ED8CC5CE; 0xC570491A_13F9FC7D, 0xDCC59936_B4108A35, 0x72D8C66C_95C50029, 0xE4BA5C34_E377669E),
+        point!(0xEA19849D_C6E1346B, 0x5ABE7B10_385AF1C5, 0xE54C761F_14D152C0, 0x069068FF_0982D10B; 0x7BB58A54_D7226C13, 0xA4F68939_94C6026E, 0xDA85DB2B_D086442A, 0xB863E3E0_90BFDE26),
+        point!(0x86E2A30C_A540DB99, 0xEB1309C0_534B8122, 0xD06883FA_66F0B0E3, 0x3AB6BDE1_0CD3AC0C; 0xE6873FE3_1BDA78A3, 0x38D137B0_E369C043, 0xFC3117A9_6A13E99C, 0xBACA6207_9BE871D7),
+        point!(0x30E691FC_DCA1F6A1, 0x068CBD14_348CFF1A, 0x5286DC5C_B1E86CE1, 0x898C3493_CB259761; 0xA4ADC20F_164F647C, 0xB2A7CF97_9F2BD79C, 0x9D845424_52AC6E93, 0x75F75986_AB56A554),
+        point!(0x584E2BBC_7235C795, 0x0B0D8958_EBD541D7, 0x393B05B3_7D1C89D7, 0x063C4624_35EF974B; 0x3F32DC09_5B110258, 0x9E4ACBAC_FA49A9BD, 0x431F660D_931C85B6, 0xE27F9BB9_13038404),
+        point!(0xAA69E03C_3D1E3998, 0x5E56C8B9_17A04328, 0x1A5299D7_022A274E, 0xB213E2FE_D2918BF0; 0xEC2CBDC6_325FB81E, 0xD534165B_EBDED175, 0xC3D61EBF_83A43BC3, 0x229F8EC2_0F2D3C12),
+        point!(0x7777BF27_9F1048DA, 0xBA2FD4F3_73DDD3BA, 0xFDBA069D_9D07BCE2, 0x796634E3_F1AD56F0; 0xE8F9BE24_A106CF01, 0x532576D8_CFD74862, 0x56DE7473_5A7927F2, 0x4D8EE2B6_CFB20B89),
+        point!(0x00BE1FEC_A25BE234, 0x0E83E3E7_0DDF3507, 0x5D1D0BA6_91606E06, 0x4B3B3AD8_16C7F93E; 0x063B7E03_920E8362, 0x41E7FD92_CF211B84, 0x7875E96D_FD8CC04C, 0x5EEC023B_85DABC9C),
+        point!(0xAB209AC9_C05201E1, 0xD264CB5A_E836B77D, 0x852BD55C_DEA35F99, 0xC299C6B0_6E6C78AE; 0xADD3313A_50F51BA3, 0xBD74DCF5_83AC2AE9, 0x38884F24_FD774CD7, 0x5AA73992_3611805B),
+        point!(0xB96634A7_E289F55E, 0x2976B0F0_E2C76707, 0x020251B0_278965BE, 0x9F7B88B6_DDB04F96; 0xB3CD3B65_FE1E4BDE, 0xC0E536E0_EB10A2B1, 0xBE3146B4_298FC71F, 0x32F9F784_C70410F7),
+        point!(0xFD37A7F1_6C1CBAAB, 0x1ACAB879_033D564C, 0x598AB6C1_8A70635C, 0xD031AE98_FB356071; 0x4C39047F_85116B26, 0x6DFB4A4F_DBD65FDC, 0x8E6E4C06_ADCB4DCF, 0x415196B4_E954584E),
+        point!(0x16549C8C_1DFE1D2B, 0xC493F509_E56878E5, 0xE76CCADF_D3FB73A4, 0xD58A43E9_CB7448E3; 0xFCF2795B_8CD50922, 0x54D9BA21_5CBD6FBF, 0x10A72913_2828276B, 0xFC17866B_A05883CF),
+        point!(0x626FBD58_447996C0, 0x6E2EED72_76BA269A, 0x39B97957_7BEDD0E9, 0x16E85AD8_6A953564; 0x4888BC64_B8151D48, 0x21B642AD_9C569D54, 0xFB217E5C_E00ED55A, 0x7408B0AE_B424493E),
+        point!(0x124151BE_249C795E, 0xED4406AA_99CE805E, 0xCD6D969C_3C27EA4E, 0x21D27139_71118310; 0xB0012EC3_99208ECE, 0xD671944A_4ADFB761, 0x17735B85_522BB4C3, 0x94C5F9B4_B075ACEA),
+    ],
+    [
+        point!(0xF1A11778_E3C0DF5D, 0x2019EFFB_5156A792, 0x7D8ADAB9_475D7FAB, 0xE80FEA14_441FB33A; 0xFCB4291B_6AC9EC78, 0x2D155E80_AF322EA9, 0x1E89768C_A3CA9447, 0xEED1DE7F_638E0077),
+        point!(0x2DB0E78B_0F83CD58, 0x122DCC38_77D2F916, 0x981AC4ED_1EFE7A37, 0x440CA1F0_8EA41265; 0xB07BC069_B88A3F4B, 0xA21E4D42_69C0A260, 0xAF8954DC_9D4E2F02, 0xA6C8B0D2_CD5EE122),
+        point!(0x2C0359EC_D7592D55, 0x06D5B947_40E35019, 0x08AFEF69_633CE3B1, 0x5D2EC6DB_C4A10526; 0x266E5D0E_AF5183A7, 0x0E2A7BCD_AD115174, 0x58F710FA_268CD695, 0x0A92CDF8_9C6E45EB),
+        point!(0xE81BDA27_12998B10, 0x9BC70EBC_C1B229D9, 0xC5F7F829_D3A90781, 0xF694CBAF_2B966C1C; 0xF6788B17_00F05E51, 0xEB6C64C3_183C2A47, 0x633C5FFA_CC46D82A, 0x40A63EBA_61BEF03D),
+        point!(0x991F4B49_FE8F9F5C, 0x7361F1E1_59880A51, 0xFE2CAE34_215F404D, 0xAC371DC3_B11BF742; 0x17A83FF3_325A503C, 0x22953458_EE751E1D, 0xFF2101E7_3254E735, 0xC51616C1_8709A477),
+        point!(0xA744B8F8_E55BF84C, 0xD964C6ED_570D9027, 0xE5DD3C25_223CAE4F, 0x62782899_CEB96CC8; 0x6F51CFAB_5F15FA2A, 0x9A020DE7_DDD176ED, 0x01C54972_29726783, 0xD670ACA4_0911A289),
+        point!(0xF4A1C8D5_19E33446, 0x40893BC4_52AF385D, 0x9348C0BD_0222A17A, 0x8942003A_14F1840C; 0x40D38A00_E6387689, 0x6BFD7736_79E74F98, 0x4D8B2D6D_0EFDE4E0, 0xA9FD0395_95A5077A),
+        point!(0xF95A1335_8DD553FD, 0x47ABF695_C08B6414, 0x50B6D4F4_39A25950, 0x8B6E862A_35566848; 0xD803DAD3_3E9BE5ED, 0x24AC3C5A_183383D0, 0x0D10BC2D_F4EB9FA1, 0xEA5E0891_0ED11CB4),
+        point!(0x3D728C95_B913CB26, 0x99EB419C_013BD6D0, 0xA2CDA44F_F5505C98, 0x2770266B_30A342A8; 0xBFE71733_F348A7A2, 0xE940C91B_CC815BEB, 0x5552D24D_07F2B324, 0xF649BC5E_79B0DB1A),
+        point!(0x23F4244A_CF0C58BD, 0x28D49F4D_F64464CE, 0x32D9E100_56AAECF6, 0xD3ADF06E_24844C09; 0xE52E4AB9_37C89387, 0xAEE3869F_A4B98C9C, 0xBDFB2FEB_CFF638BA, 0x57E99DB4_FC2A6077),
+        point!(0xF0AAA021_595E8247, 0x97BFDC45_5686CD1A, 0x78E5E6E0_1CF8BA90, 0x75E4D0D3_83CBED21; 0xCA23FD9D_59982D22, 0x7DDAB5B6_EA9AD893, 0x741F970E_C7B726D4, 0x1FCE42A1_75410990),
+        point!(0x399471D0_F39715C2, 0x69006521_F98A529A, 0xB411F78C_EA33FEDD, 0x2F189E88_F19A3D85; 0x66A63584_4171DFEA, 0xEB3333E2_C36854F0, 0xDF708475_ED10F9F7, 0xC91A39F8_F3C5D7CF),
+        point!(0x7479F44D_A6333323, 0x9CBBBC1C_68F4ABF4, 0x3F020D60_5984DA84, 0x3ED1B638_3361BBF0; 0x4B13FC48_B5D61BA3, 0xA9B728A1_0B78F729, 0x5D97C989_688F929E, 0xD84F2DA4_8B5B4EE7),
+        point!(0x87562EDC_FA3EB3FC, 0x77EE6894_4D90E398, 0x77E46B40_888A6F7E, 0xD2CDC8BB_0A1B6BB7; 0x44287DB3_DED37564, 0xC1F217B9_310B4927, 0x6DDE9536_2CF926EE, 0xC5538AF9_E5C56104),
+        point!(0xF25057D4_B33980BF, 0x68A3702E_50DD00BA, 0xB846FD77_2348DD43, 0x70FDD2F0_6A758181; 0x12EEA8CE_A0B75785, 0x0A103583_85D74A56, 0x957A410D_11EE596B, 0x5EDFCAC0_D0D58696),
+    ],
+    [
+        point!(0xDDC07BBC_C4E16070, 0xF2A18203_1EFD6915, 0x13BA48E5_1D567543, 0xA301697B_DFCD7043; 0x0C0D1A04_1E177EA1, 0x1735DBF7_C0A11A13, 0x081809FA_25D40F9B, 0x7370F91C_FB67E4F5),
+        point!(0x11420316_F24BA5AE, 0xB4396500_4C34B5D8, 0xF3E8D241_9E0BFF74, 0x27E1E59C_FF79F049; 0x84A5BFEE_883A45B3, 0xDF48A1A6_9AFA63F7, 0xEE1B5E3C_FC79DF05, 0x310B26A6_C804E209),
+        point!(0x67012700_138011FC, 0x83596A67_AD728562, 0x156B1330_82200A4D, 0x6E8313A3_0815EB11; 0x6ACB69FA_3F15AB7D, 0x26AF915A_E9C51F9A, 0xC1A12DB2_01DAC304, 0xC147818B_DC24F204),
+        point!(0x9969175C_9CAED7AE, 0x5A39ADDE_84FBFB4F, 0x16588EC3_892D7E4F, 0xC712E7A5_F6864AEE; 0x84D148AA_46156294, 0x380D8E54_4B0CE637, 0x65ED4B82_311DD9E5, 0x49644107_516363B3),
+        point!(0x53A749B8_D00E6BA7, 0x3D36EC5B_44916F7F, 0x2BD1E038_A4D9E1B4, 0xF952A909_9784851F; 0x8DBAEEE5_0175E4C1, 0x5557167B_4C62A2B9, 0x861376A2_E27FA0F6, 0xD8A93A5B_08ABCEBF),
+        point!(0xF9549F5C_595B6F7E, 0xBEB32B53_D38B2021, 0x83A053AA_D09876CD, 0xA5AC7D1D_04CDA30C; 0x48DBB730_8AB19C84, 0x99E39160_F5818A48, 0x36CC3C92_47D9EE1B, 0xDF0B8A0A_B540F55B),
+        point!(0x690065A2_83AA0E93, 0x8F2E3943_AEFB1F62, 0x504031A1_9D9E893A, 0x94016D5E_31D3FEE7; 0x38EED268_87ADDAC2, 0xF484373B_AA57B07A, 0x40355D35_4EEE6FD7, 0x675032EE_5C454D96),
+        point!(0x1B43E1FE_44A6DB03, 0xB2C85D6F_58275D79, 0x065C0D42_6B8675FC, 0x0BFC0504_A4B3235D; 0x58AC8D1A_464B8542, 0x37427197_345D4F05, 0x3FB8EC7F_94A6C992, 0x1955467A_6C34F345),
+        point!(0xB0F4862E_0266B17B, 0x023568A2_0262BB32, 0x1BB97884_6A9F09C2, 0xEF22D174_D59FB289; 0x32A6E043_79DAC83E, 0x3EE7276F_71C341B7, 0x68F9CCC3_AE34A107, 0xBC5784C9_7AB24C75),
+        point!(0x57F2C542_EB4318B5, 0x72ED5C81_2F5B8456, 0x55788A38_DF2057EE, 0x9885F5FB_E1948D0F; 0x2FE54254_1599175C, 0x7C82ADED_676904AF, 0x96D2C467_768CEB8E, 0x135FD6F6_9EF198F9),
+        point!(0x2313015A_92D382A0, 0xF28DCBBC_E2ECD9AE, 0x39CACC78_0B2DF927, 0xB5F7EFCE_4CEB892D; 0xBA2F0ABB_A5B4B532, 0x71FB096D_29ACF8C5, 0x3C0957C6_E0CBDDD3, 0x6843545B_51C3F235),
+        point!(0xB93A4AE4_CE81C1BC, 0xD25365E5_8ABB5B33, 0x5AD815B8_D68CE767, 0x03360F05_465C0463; 0x76DD2896_566CBEDD, 0x231728EB_5C811501, 0x4FF69C42_51306753, 0x7E48B5F9_0C575001),
+        point!(0x7E692464_412CFFA5, 0x89ED4208_668CC5C4, 0x408754D8_FBD46243, 0x1CDDC3D2_B2E71076; 0xADE6D89C_D521954E, 0xC41C9842_6B36B528, 0x3FDB126B_F942B08A, 0x1E476A0C_B2F1F8A6),
+        point!(0x1509F734_048993F7, 0x96255223_F8273CE1, 0x6334FBE1_BC8BAE42, 0x0875346A_56A38511; 0x601C2C81_72B9DCC2, 0xC9F4D4F1_B126018E, 0x10E994E6_E75FA287, 0x064E6CA8_A2EF8B03),
+        point!(0xD35FE439_34A9F22F, 0xCC0819C5_217A8E2A, 0xF21F8B8B_948835B0, 0x2ACCB359_F25CE939; 0x7B75DCB3_B75C4927, 0x154BD7BE_C5BA550B, 0x19213A37_EDF0EDF1, 0xD518A4E9_588AD2E5),
+    ],
+    [
+        point!(0xAB7AC63E_3FB04ED4, 0x08CC330B_11307FFF, 0x463F9D05_12678DE2, 0x90AD85B3_89D6B936; 0x991D4D48_CB6EF150, 0x39AEFABE_1582894D, 0xAFFDCBD9_427222B8, 0x0E507A36_20A38261),
+        point!(0x2818B0ED_A7DC0151, 0x7E125BE6_46707BAD, 0xF44B1D15_48425E3D, 0x7E2CD40E_F8C94077; 0x9A3BC539_20721EC7, 0x889BEE40_AEEE082C, 0x82A61A8B_321EF95D, 0x905B7508_2ADCFAB3),
+        point!(0x6275DB33_B0B7B678, 0xF13C4311_BCF63816, 0xA1986BCA_426B6C76, 0x186E4973_34E4231B; 0xA7A076F2_F8D91FC1, 0x11077225_449535DC, 0xAA7FC825_C0B7E67E, 0xC0D460E4_9807BD84),
+        point!(0x0E103AE4_1345E597, 0x9C636BF9_DB853CF9, 0x21C975BB_D1EF52A7, 0xA146F521_95BEDACE; 0x7675676A_F45A770A, 0xEA67A5B2_21F094B0, 0x9AE95DD2_DBB31B40, 0xA5A99B0A_B053FEB0),
+        point!(0xCF06E5CB_B3421FB8, 0x984971F2_EF5A55D0, 0xE7E06B34_C3B72412, 0x061C8D83_4F6DBF62; 0x995EF668_4E3CCD80, 0x8067134B_3A6FCDD2, 0xBA939259_0E05EB5C, 0x6DFC6AD9_9003B4B7),
+        point!(0xD08C89B8_4BB9DC8F, 0xABA9E93E_F5EDD333, 0x248F7035_352787D1, 0xFD9941CE_E1C26864; 0x743EAE53_E59780D5, 0x7872B2FD_EEFE938D, 0x6DE97E05_788859E6, 0x7A41EC75_BD40E6F4),
+        point!(0x7124BE18_AF6B35A4, 0x889F37A6_AB27FB48, 0xB7363240_733A68AD, 0xF6A6B63A_208EE513; 0xCAEB6FEC_81F422A6, 0x19D5756E_75EE1862, 0x1CAD3704_F560186F, 0x3DF7C8A8_002D138B),
+        point!(0x0CBB53FC_4CE45444, 0xDBDE421E_FECCC4E2, 0xBCFBF9DA_B25A8114, 0xD24C75A1_CF1993B9; 0xCB932469_87DD4A57, 0xBF7593F4_99F1E524, 0xCFCB7D18_10E5A78A, 0x58FE1D2D_E84DC1D1),
+        point!(0xB96823E8_A6954C11, 0x7FA8A43C_1560D018, 0x220D1B0F_150778FF, 0x87A2FC28_C286C376; 0x756E332E_272A8B45, 0xCC48933F_2A78124B, 0x97B1948D_A0D27532, 0x33AD518B_45AAECEF),
+        point!(0xEEB1AFE9_87A43DDC, 0x24282CA1_A9956E4A, 0xA101EB85_EE7BDF55, 0xA8B08B86_4946AC6D; 0x87001C82_DFA2A6B8, 0xA325B445_2B1076C3, 0xF31591FF_DF0E8EDC, 0x7F00FE8B_C8A36A01),
+        point!(0xAE47472A_E2706AB6, 0x3C0B341D_D2E53C0D, 0x84556EFC_BC384CFE, 0xE1472E04_6E7881A6; 0x36E86A16_9D58DE05, 0xAA589D55_453CD275, 0x83F69197_8C8621F8, 0x82CD92AA_47C58FC6),
+        point!(0xE3E9432C_3B963645, 0x2DCDB280_7E71F1F8, 0x6E74781A_2E3132FB, 0xC5F0954F_B1B8A63E; 0xDBF8874F_008BE2DD, 0xA330502B_0047BE4E, 0x18A3CB44_0260D7E6, 0xD552D310_21B0B6FB),
+        point!(0xEBFAE542_07A84FB6, 0xC4236875_0FA15021, 0xDE8B4D60_1E48B9EB, 0x1CAF92C8_04F8AEF5; 0x16B18190_27149109, 0xEC1CC169_6A2CE676, 0x481A64F8_FF101622, 0xBC24B85A_C976E53A),
+        point!(0x53A93123_E9C8381F, 0x0ED3D6D6_383C11A7, 0x34EBEB46_34FE1010, 0xDF3ACF55_BC6D97CE; 0x1AFFD696_2347DE3A, 0xB101957E_67E389E6, 0xA6099C1D_EDACD1EB, 0x6B3DD8CA_51907A75),
+        point!(0xB768CFE1_4A955911, 0x107E16A2_60DED52D, 0x2589EFE9_B3D78397, 0x705BFD69_AF09650D; 0x8BB7C693_FFD200E4, 0x297F3B52_2A42E72D, 0xA7E63D81_F54051FA, 0xE14AA413_0B73F990),
+    ],
+    [
+        point!(0x1B7B444C_9EC4C0DA, 0xE88C5678_723EA335, 0x9239C1AD_981F162E, 0x8F68B9D2_F63B5F33; 0xF23CBF79_501FFF82, 0xBBEA2CFE_95510BFD, 0xDE1D90C2_B6BE215D, 0x662A9F2D_BA063986),
+        point!(0xE6847DF8_4CF27076, 0xD89858AD_E7627EAE, 0xFCAFEBE7_7FD9AF59, 0x4D49AEFD_784E8158; 0x6B90B662_03AA781E, 0x6E0F2D1A_7DF4D846, 0xE723F210_359CA6F0, 0xCD32FC59_A10DD135),
+        point!(0x18E2B8ED_D23809FA, 0xFD845CB3_51D954BE, 0x8BA93363_F2451F08, 0x38381DBE_2E509F22; 0xBD707518_331FED52, 0x3681FCCB_32D8F24D, 0xB09405A5_520EB1CC, 0xE4A32D0A_0FB917DC),
+        point!(0xE8DBCB57_29B62026, 0x8D2A3DE0_889D1D4E, 0x37D6619E_1F5C5AA7, 0x7564539E_85D56F85; 0x4172C8FA_DACE0CF3, 0x684AACD9_54B79F33, 0x5231DF52_4A722925, 0xC1D68541_3749B3C6),
+        point!(0x3EA42648_97C2A310, 0xF186AEA5_40122630, 0xF6921B82_AA4699A1, 0x49262724_E4372AE6; 0x0C41B681_5E27DED0, 0x6D163612_A75FF8CE, 0x5A2CFA56_9714303B, 0x1337E773_BCA7ABF9),
+        point!(0xF8166C19_03663DA4, 0xA5A36291_9F5D0B81, 0x6808A6ED_7C44AA2B, 0x6A664A35_6AA5705E; 0xC28313FB_33FC22C4, 0x08991612_7E6C04C9, 0x29F86EC1_96BF0CD5, 0x449A1259_54FDE98B),
+        point!(0x1384B079_CEBD2D31, 0x4DCC1A56_FF06DB8D, 0xD5E253B3_E477E2F8, 0xE306568C_1A240C90; 0x692B4083_92546E44, 0xFFBC8042_BE373826, 0x888F2B10_7F7D0DB6, 0x0EAC6FE3_78934260),
+        point!(0x5364DACD_57B4A278, 0x78F61A5F_1FF4082B, 0x6746FF30_1AD9CCC8, 0x210A917A_D9DF2779; 0x7F2713FD_0C7B2231, 0x3789E61A_AFF20BFC, 0x7A39BE81_F8D6737D, 0x670E1B54_50B5E57B),
+        point!(0xC530C39E_363136B0, 0x74EBF8D9_AAB41DD9, 0x271B0E76_23FBD633, 0x3B9E100E_2428CEFC; 0x953EC16F_6CDBBC8A, 0xA2AE28A3_3AD31F81, 0xDF1533EB_8F475B26, 0xFAFB9815_2D16BB71),
+        point!(0xEA98EB53_DD90124E, 0xC1E80268_5281C85D, 0x77E9A194_6DB38156, 0x7AEACA93_C06C5541; 0x3F507810_22021E31, 0x59BC1BF9_B90A904B, 0x4FACAFF0_46C1D951, 0xDFB29B19_0C1390F0),
+        point!(0x9608F047_2F485D3F, 0x17CA0768_8107BEEE, 0x2B76CA80_F5DEDEF7, 0xBB0AAD49_712AC9A9; 0xE7939250_3CA2F975, 0x895A5AFA_31670BFF, 0x8ECD201F_7297DA34, 0xEA699C53_C5835479),
+        point!(0x70F702D5_05ED815E, 0x66F440AB_C1C017B4, 0xEBB5FAD2_19547AD1, 0x5568DAC6_79F74A32; 0xD9BBF64C_00602DF0, 0xEB05F7EE_E2582151, 0x580D6FE0_1D073F2B, 0x7A85F874_2788BA64),
+        point!(0x4AEED33A_36718DC9, 0xE1E58B4D_B01123DE, 0xD4E8EB19_7AFE0113, 0x79090AC8_E4EEFCC0; 0x963322B1_1CFAE7C5, 0xDD36AFB7_0BA9008B, 0x13D816CB_CD9AAA56, 0xEAAB722B_91905B8F),
+        point!(0xABEC1BDC_2E44A0C8, 0x2C9BE740_2EE29932, 0xBC9BAE60_0C7436C3, 0x601E9E88_4807943C; 0x54793412_BA00E557, 0x424E0C5A_6B46E659, 0x714B8CFA_9FFABBB1, 0x5A7A22AA_6FDC8ECC),
+        point!(0xA269694C_7F60C7D1, 0x8DD71DE7_CD775AD2, 0x1C03DBBC_E549BA66, 0xE77C81AD_E9F97B55; 0x4EC581F2_82D72449, 0x631470F7_1C2986D3, 0xC5FC3B32_3EA81543, 0x3ACF1478_EEF81321),
+    ],
+    [
+        point!(0x6686FD50_53231E11, 0x1F48E865_03681E3E, 0x5FF99FF9_198C3609, 0xE4F3FB01_76AF85D6; 0x822C3857_6FEB73BC, 0x6CC7E74E_C951D1C9, 0x1661A6D0_EA02B728, 0x1E63633A_D0EF4F1C),
+        point!(0x3BFEE223_3BCDAF2F, 0x88531A82_5BA17295, 0x1EC64110_ABDB362F, 0x4B30CBB7_686773E0; 0x68033D46_3D26B5B7, 0x1FDF3C81_E4348575, 0x6F9E2C57_77C3C4A9, 0x74C63502_65BB629B),
+        point!(0x5399F04E_6BF05BD6, 0xA2F56E03_212A9946, 0x0832F51F_EB470DEC, 0x900C3241_BEE44FE9; 0xDE52AD3B_F00D358B, 0xD6985358_3C4EFB15, 0xF95C7204_570B2439, 0x6C31F9E8_E8B1F0F5),
+        point!(0x2FFE9C29_A673059F, 0xEC117150_50E0FA19, 0xCD15B20B_17464817, 0xCBB434AA_7AE1700D; 0xB7DA9642_227C070C, 0x41D45E4F_0AD5F845, 0x562D4923_38B5DFAD, 0x4A1A200A_B4DABD17),
+        point!(0x7A7C28CC_9F105C50, 0x82E0DEEF_7B138525, 0xD628CC34_03C233CD, 0x5A8D0362_AB0590AA; 0x5C9B2018_38A4CDE9, 0xA605F68A_66D013C3, 0x936A6B72_4143FF74, 0xC059EAB1_13D4E536),
+        point!(0xBE6FB2F7_A72E294D, 0xF158EBA3_F00D0DD5, 0x1A6289EA_EDF7EDC6, 0x29DFE480_5CD534B9; 0x44137260_EC469B52, 0xD1F1961C_3929EE69, 0x9B434E75_88A05E4D, 0xBF66D826_D00672E1),
+        point!(0x647E18B9_B2D64FEB, 0x3772F8A5_9292292C, 0x48EF3A57_76CBA4E8, 0xD93F4D03_1232F60A; 0x7B0FBD59_34698359, 0x93E0430B_EEC90DA3, 0xA237311C_54DC8C4D, 0x7925555D_45CB2733),
+        point!(0x4D4786E1_06DE12C0, 0x6D9CDAC5_874610E9, 0xD06D7B1E_7557244C, 0xF478056D_9C102C1C; 0x69A76CE6_CA5361FE, 0x26C17EF6_09AB92D7, 0xE68095E0_1068694C, 0x7F09E610_F33E3946),
+        point!(0x722204CD_439FBC84, 0x7E706291_6057A016, 0x1B14E2BC_662AC65B, 0x2F0769C6_E36F2844; 0x3FB902DE_1C201BEC, 0xCCA79B6A_4C4DD5D7, 0xFA5DF007_990E2149, 0xB434F372_4D73BDDD),
+        point!(0x928B2FD2_D026DEA2, 0x87C84C45_2C59C5D8, 0xD71A1E26_D9A6DD61, 0x1DDCFA48_D561BBFB; 0x06F4873D_183DE522, 0x666FD68C_51C06734, 0x41FEA34D_6402C020, 0xC6FA401F_A9923A7D),
+        point!(0xD537EACC_86A758EA, 0x91188318_927F19C4, 0x9586895A_15416E39, 0xFE7E6780_559E0309; 0xE4F22D95_DB7AB649, 0x3B801707_443120D1, 0x88C08E16_9849251F, 0x38AA6967_A281DCB9),
+        point!(0x3C7F0046_C33773F7, 0x50D44FD3_45B59995, 0x3E1410C0_09E372A1, 0x355B3226_F5DD236D; 0x0053C3FF_1887C182, 0x1E25DF78_CCE25109, 0x49F10EED_DD21FCB8, 0x08ECA480_704703AD),
+        point!(0xDE715626_8CFFFEFA, 0x1018044F_70651AFA, 0xD6DF6F91_50C3AF85, 0x91BAF5CA_75383127; 0xC7D36F34_DF7F6FAF, 0xF4BEF310_E6FD303D, 0xE5D969F1_27DD3D92, 0x04E5CF11_9347BCEB),
+        point!(0xDD3DF406_90785491, 0x5291DE5E_4AEAA5D1, 0x5619F43C_B2F866B7, 0x92B1E0F1_7F260BAE; 0x9C98D360_58BE555B, 0x3985FB77_E2288D7B, 0xE167C1D1_034E5AD0, 0x81EC4402_8D8D9E63),
+        point!(0x2FC0917E_8D33C546, 0x82575FB3_285EDAF3, 0xCB2C5373_E0F5DDD7, 0x44584121_CB3F78A7; 0x60A6D187_000B4FD4, 0x852F0A3C_FB2CA736, 0x77225EAE_391ADA20, 0x308D9377_F3F8753A),
+    ],
+    [
+        point!(0x16FB6EAE_20EAE29E, 0xC7034F2F_0D4E1D07, 0xEB961537_A45A4266, 0x8C00FA9B_18EBF331; 0xE7D2A4C6_6702414B, 0xC2FADAFA_81E36C54, 0xA9DC343A_3736C974, 0xEFA47267_FEA521A1),
+        point!(0xE84C9AB0_C18ADA5F, 0xFD7E297F_29122FB3, 0xA8BB5BF9_636BE1EF, 0x24CFC017_6DA2B46F; 0x68FA6139_978A586B, 0xED959CA1_A4F814F2, 0x9868714D_5DEDA927, 0xEBFF8FBB_079C61A6),
+        point!(0xC3F95603_EBFD913D, 0x50A680E6_EE54C9EA, 0x74D07A08_4C2A8D20, 0x36362AA7_E907DDF8; 0xC44F9AEA_C52E243D, 0xD830BB10_D6B2FAAF, 0x34162443_70DA2A82, 0x48F27867_6CB8AFD5),
+        point!(0xF1031895_94679DA2, 0x16DDD67F_C7F057ED, 0xEA2DED72_A1292EC6, 0x004A7D58_D4B9BC82; 0x4877F484_779FFE26, 0x71C3B494_963FA28A, 0xE6B1D814_7EC71B3B, 0xB98AC5B7_6702CB75),
+        point!(0x7CABABF9_AD132896, 0xFCB1E3BA_B7BC6C96, 0xF295AD96_2DD9200D, 0x4487976D_F32A1E02; 0x48C01B12_AF685248, 0x06B40D5A_6276AA7A, 0xEFC9A907_74561A33, 0x27BD5860_D115AFE1),
+        point!(0x859864D3_775732C0, 0x202FE5E7_233777AA, 0x624A5D1B_7CB1096E, 0x8F3CCF31_F8B74B9B; 0xE73BDE5E_65B9415B, 0xA90E6877_54858825, 0x89EF46D9_97CE53D8, 0x67F1CD3E_2D39E531),
+        point!(0xE08156F6_BFA2670C, 0x7400F82B_A06273E6, 0x8B6A9CAA_83350324, 0x4A4D3AC2_8BCB8378; 0x808B9FFD_D6C1764D, 0x4930594C_BF29BEED, 0x17E7711D_DEF02B9D, 0x70ABB91C_01845A4F),
+        point!(0xCCF34EAD_C87C4B65, 0x96F8808A_1E07B2A9, 0xFC76C5E2_C066CE49, 0xEE7D69C4_CBD001C7; 0x23E8F44E_D136A95A, 0xC33E8999_BAE942E5, 0x1A192ABF_030F2EE2, 0xECC8626E_C1A41382),
+        point!(0x0D96AFE2_4CB24AA7, 0x903108C6_39345C53, 0xC3AE6FD8_9FF8FE18, 0x5F7B2D19_0AE91802; 0x6D780743_4A693D7D, 0xCE8DEB70_93C81D0C, 0x30A3F80A_3CE67C47, 0x07703600_C4FDF2B3),
+        point!(0x842632D7_FF004130, 0x49FD8633_81C36B09, 0x266A14E5_254CB5AA, 0x50D775B5_F72D186B; 0x456B8C64_DF6E406D, 0x2975D945_EEF6A4C3, 0xC5172434_6010063F, 0x3401EE3B_0F4EA557),
+        point!(0x4191BC5F_2F7FB8BD, 0x7BC464A1_5FE4090A, 0x2AEC24E6_D94886D8, 0x51397451_339D90D0; 0x8094AC2D_83908C0F, 0xFAFC32D3_E3F98D5A, 0x4B88FEFD_AE815C47, 0xFCD7143E_47643BF3),
+        point!(0xF2A1F839_34889F3F, 0xC4FEB28B_45095DA7, 0x21EA3469_B169B0EB, 0x87991026_F1B21196; 0x49E2DF9B_C3BB792B, 0xE932F61B_F286037B, 0x2132F2CB_717F279D, 0xDDEAB94C_A5A91D96),
+        point!(0xE2B74915_EE36CA73, 0xEAC864D3_6BBBF3ED, 0xAA429112_BF014887, 0xCF8316D6_2BFBDFB8; 0x5B492194_2EC25534, 0x1C961598_D88D710F, 0xE3844508_90DCCA68, 0x09FEB58D_EB5D9D73),
+        point!(0xAFCD9846_BD9C8F0A, 0xAF3E9F2C_8BD84E64, 0xC3926603_BC03A87C, 0x6E2114ED_297BA44F; 0xBD79148D_D16A2C9B, 0x090BEC75_D0F9595E, 0x7DA6D50F_80C5D91D, 0x05591A0C_C26F06E2),
+        point!(0x3C8937D6_B752F97D, 0x5A2EFBC7_5F347661, 0xE539D286_68CFA616, 0x8610DE9A_4C4BB49D; 0x8057DCD4_1F1B1AF2, 0x57AB4D9F_545543E0, 0x937CD99B_2533F99E, 0xD31997A0_2B630BBC),
+    ],
+    [
+        point!(0x997FDDFC_60CB3E41, 0x143D084F_308B92C0, 0x3E10CEC0_A9E98ED3, 0xE7A26CE6_9DD4829F; 0x0E8A9421_CF2CFD51, 0xD0A6B2C0_420E83E2, 0x471B006A_1AAFBB18, 0x2A758E30_0FA7984B),
+        point!(0xD97E5B91_7B4AE861, 0x203C35E4_D6E32FA9, 0x0D38BFB6_772089F5, 0xF5CAFABA_036BF8D0; 0xCC9C239C_0D82239C, 0x9B3B2A9C_552F05F3, 0x7BFF9904_64083915, 0x19E83B8A_022A6D81),
+        point!(0x70F9FD2B_FAEE42DB, 0xD9BBBC5B_4730714A, 0xFC7B6EDB_91ECBFC1, 0xCC3427E7_D9B59150; 0xC6229C01_15D87BDB, 0x10E5CBAD_8E72422D, 0x885E3FD3_FB215200, 0xEA249841_A521C6A1),
+        point!(0x84FD4CD7_BD2A9651, 0x8F9E509C_494C9820, 0x12DF5156_D7E80542, 0xE9389024_CEB63F1F; 0xFEFDE2B7_5E786824, 0x8D7110CE_C6770BFE, 0xF9287ABA_F671AAF1, 0x86486887_23726595),
+        point!(0xFF4366C6_7ED4A086, 0xB1D75C15_8E9C410E, 0xEC83C585_FBCB5CF4, 0x948F05BA_CD98445D; 0xA2A1800F_9E2BCA4B, 0x1C2328A7_1C3FA2D1, 0x82F450A6_60113941, 0x864CA89F_FB5A2A33),
+        point!(0xCB0A7165_2E96E4F1, 0xFF5F0160_0AE80030, 0x20177708_335EFCCA, 0x56BB148F_0198197E; 0x5889F019_EEB0582E, 0x7313D1D2_004AF0EB, 0xBEB03ED8_BE30EA9F, 0xA09584A5_61300A33),
+        point!(0xC0CBEA6C_B7542C21, 0xAE19F4D2_8EA64C15, 0x0656FC45_451D6D43, 0x25841962_92919AC9; 0x3BEBE319_672BFABF, 0xF3E3A186_E4C2AD7B, 0x01E5DAA4_CC7513D5, 0xFCB35B1F_1CDB2448),
+        point!(0xCF2D41AE_7CDDAB8B, 0x9F0E4D12_53C68E6F, 0xED116900_D82D0C37, 0x264559D8_7829256B; 0xC9F64B45_001DE473, 0x7A8631AF_39CAF1E6, 0xEF7BC637_034072D7, 0x79E5BD19_26D3512C),
+        point!(0x43C461F4_0C1ECBF8, 0x6489F0DE_9AEED7CA, 0x22A9754C_C1E8421F, 0x7E12CDC4_1373C525; 0x9D45B8CD_B0BED615, 0xC969F61D_8FE1D9F5, 0x143F94DA_BE4D1259, 0x71284F88_888DC837),
+        point!(0xE2DA28FB_898A26C1, 0x9A954FA1_7393B04A, 0x6EA35157_9C26E1C5, 0xB6A0C8FD_373B52BB; 0xF5550EDA_8A78217C, 0xFC94A5E8_58AB5A06, 0x9A901E3F_EBDA7FAC, 0x5EF193BF_DA037494),
+        point!(0x17E6651C_14557D86, 0x1CFEEC47_8E667FF4, 0x772B91CA_7A05A6CF, 0x4B8EC2FF_2A3742A6; 0xBDD9B191_B5167EB9, 0x28D5F2EF_0FC3F692, 0x14A4072D_E527AC5F, 0xFDE6C3FF_04C157AB),
+        point!(0xA4C57F59_81F23D1F, 0xB6B25FD7_20C036D8, 0xBD3D489E_1FD8A0A6, 0xB398E815_3D670BBB; 0x1583EBFF_832FF3FC, 0xEA791D8A_D26156CD, 0x07F7FEB2_FB00BA52, 0x69F59C22_572DF47F),
+        point!(0x52B82D3B_4780763C, 0x5F8A6D5E_9265691D, 0x532436DC_697AC07E, 0xFCD5C107_33963A42; 0xB54E7420_38E851CB, 0xB119B22E_716463A4, 0x3B720DB3_5F72E38C, 0x18DDABE2_E5522C46),
+        point!(0x2B1C47A6_33E9FC8D, 0x48B84809_824F28AD, 0xA6CD0B20_54631A73, 0x634A0DC1_BB425DFF; 0x9587F30B_273F87B6, 0x519AD5A1_8ED5C616, 0xAFE05A65_7DE4A9CF, 0xD75BE21F_E75A4B6C),
+        point!(0xBCE3BE30_5D8EF686, 0xF68E48F4_02272E66, 0x2238A5DA_F8266E53, 0x900F2CEE_5843CBE5; 0x073A4967_E121A8CF, 0x596CDFF4_3C9156D5, 0xD61C551B_1349D110, 0x732AC155_EC60425A),
+    ],
+    [
+        point!(0x3CF29EB3_DE6B80EF, 0x71CBCB96_7D79424F, 0xD23540C2_23BCBDC5, 0xB6459E0E_E3662EC8; 0xF30BF0B6_1A71BA45, 0xC4B3AE6D_48E35B2F, 0xE1DADF16_E5661DB3, 0x067C876D_06F3E06D),
+        point!(0x253EF375_033EB51F, 0xB6890576_BE79C211, 0xE4D36F73_01F41593, 0xE5D8E8F0_D9823C88; 0x79D5BD96_5A62A2D9, 0xE509DC46_D9F0F549, 0xABB16A57_D8FEEEF0, 0x4DC1E9B7_861E3E04),
+        point!(0x439CF279_319888E9, 0x3D12BA6B_F2448A8B, 0xDDE60D30_29668167, 0x1F90EA77_3AC3A6E2; 0x56FDFC97_EF113B79, 0x213751FE_E59522E6, 0x958153D2_71EB96A8, 0x89BE367C_15DAA10E),
+        point!(0x37A67648_0F155E64, 0xAB66BE4F_A8A30117, 0xC56B0F73_21BAE0DD, 0xA9CA27F7_7DBC8C3D; 0x1F3F333C_561B3297, 0x875D4182_1BCAF0AE, 0xD197D224_6C02D004, 0xF4BB3356_78FB14D4),
+        point!(0x085EFB6F_3562222C, 0xDE034479_91E6F2C6, 0x6E702BAD_334B52BA, 0x13A4E54D_EDFFE0AD; 0x8CB1668C_A8200145, 0xD4F9C577_ADC904C0, 0xB2CAC2F8_1D609A52, 0xC9D67D4E_5816E813),
+        point!(0x93EAB3DD_C1BF6C42, 0xF7793D9B_11988753, 0xEE746548_16CEE74E, 0xD02E1B3C_D6C105C7; 0x1336328F_87BC54CC, 0xBCA5879B_931B6AAC, 0x12732270_D0D24526, 0x9A0B74F3_CA8E2258),
+        point!(0x437E4DC0_F43B46BB, 0x12ED3A63_FAC92525, 0x1D407C05_D8455DBD, 0x61991EBF_233CAADD; 0xF48F5901_CC20A848, 0x1CA8729C_A52ECD8E, 0x952F3871_66FBADF1, 0x01C5E308_F3FDE492),
+        point!(0x6D4BC629_D6EE247C, 0x1F478E84_7F51CEE0, 0x5EBA1056_11CFE759, 0x68FB7180_0686D7F2; 0xAE1F822D_1A01865D, 0x9A8D580B_04C73C9D, 0x73735427_501B0C07, 0xCD12D234_62DD9636),
+        point!(0x7E5BE3DD_D21ADD3B, 0x8B9A944F_45727D8F, 0x49039CDB_0D162611, 0xEA27AEA4_B787D387; 0x1B5E291D_B68CE7DD, 0x78B212D6_8E4E1BE7, 0x5723CEB2_325C2F62, 0xC70FF1E6_E42A17DE),
+        point!(0x9345ABDB_E2DA1F54, 0x1B2B52C2_231425DF, 0x987550AB_19345116, 0x7137F7B2_3DF260EB; 0x4AF8D436_CDC3EDBC, 0xEBF081D5_46CFDBB8, 0xE5CDACB1_7F2600A7, 0x5FE78AD8_04358074),
+        point!(0x6E8AF8BB_611FF757, 0x9B4CE974_1C4C226E, 0xBA9010F8_B6E2B6DA, 0x59465135_6B7FFD54; 0x28A66ECA_894D031A, 0x961BBC7B_7FBFFE3B, 0x042038D0_15C406F9, 0x3611360C_E5DF2750),
+        point!(0xA1EF47F1_8F6C15C2, 0x3DE76414_C8F38F1C, 0xEA173981_39D14348, 0x8456A852_BCA1757F; 0xFF1EFC32_8B174134, 0x4CC90F09_856FB671, 0xFD349EC3_71079E7F, 0xE1B9DA46_D4842CDE),
+        point!(0x676258A3_636160B5, 0xCCC53F24_70E7EBC0, 0xE843AF79_70091EC0, 0x16B26E39_15F73290; 0x14625FBF_58A1DC0E, 0xE1363AAC_70BA3EF8, 0xB6364352_463654D4, 0x060F565A_6302EF8B),
+        point!(0x3D61DE93_8E1B793E, 0xD36AEEA2_B0D2E518, 0x5C02ECCF_2CFAAE4D, 0x4F15157C_98351CE6; 0xB4FCC1FD_C3BA9889, 0xC0DAFF4B_354A47AA, 0xFC9DA9DB_9CD4A0CE, 0xC094E366_079DB939),
+        point!(0x3964D289_37B32DB8, 0xC91ED749_F647791F, 0x1BEA143C_842CEA85, 0x024B5295_C4559F93; 0x59454FAA_AA64F760, 0x62AB6811_205B81A9, 0x7B6E2821_CC5E5BE8, 0x609FEF8F_5BFF309C),
+    ],
+    [
+        point!(0xDEDA0F5B_496943E8, 0x1D6F1FC6_7E73C5A5, 0x793234AA_118F0623, 0xD68A80C8_280BB840; 0x294C8426_6B133120, 0x5B0E7B8F_7845295A, 0x0C4B1F91_77B0E28B, 0xDB8BA9FF_F4B586D0),
+        point!(0x8BDA222C_A412B6FD, 0xACED053C_6F702B82, 0x402F8EFB_3752373C, 0xF16A409C_677A40BE; 0x268DAE77_ECA052DA, 0x16540659_249EBCA4, 0xD7A6A75A_74E30E4E, 0x2A413117_14532799),
+        point!(0x35B42AA1_8CA1C4F9, 0x4F4EFE34_CAB7E282, 0x4C40B83D_A61F16F4, 0x7815F78F_22BD728C; 0x9AB1EA36_B778BC15, 0x5CB42DE6_1577D1E1, 0x7DD93CF3_1458C35D, 0xC1C601E8_CD39AF6A),
+        point!(0x5C1A0B6E_326DD4E4, 0xB89F4F2F_6BED9832, 0xFBE37F69_9976F84D, 0x4154B506_AB766F42; 0x6C859295_075DED1C, 0x414EA9D9_D1E22DD4, 0x94C6E44D_61025FF6, 0x23AD0750_43C59888),
+        point!(0x6D26E1C3_EE8C8530, 0x512DF681_EF8F4D8E, 0x6E377C64_016A6C6D, 0xC39273CD_A0EC4017; 0xC4030F0F_798446C7, 0x50EDFCBD_A2EED715, 0x9D01D394_41C31998, 0x8C41BE42_57433C8E),
+        point!(0xBDE4CE8B_43ED5996, 0x6831FE59_9360AF53, 0x492FBC90_3281B924, 0x1A46B7E9_FE99A4EA; 0x2EE4ED11_E8C85CAE, 0x508794A9_EF52745C, 0x0E3EE084_A063EBF5, 0x683CE81E_A3F1DC57),
+        point!(0x31F240BB_237A26C1, 0x166C6E58_C0DBF6A5, 0x27522431_C1D03B25, 0xDA317447_F58411B0; 0xF51836EC_ECF74D6F, 0xD36836C5_D7A81525, 0xD2384167_C259D8B7, 0x753B97B8_7F5D8C69),
+        point!(0x4C51A7A6_3F7F0246, 0x93E86E49_FC727DDF, 0x080A8D4D_0B5956EA, 0xB73C6527_69CC95C1; 0x597E5164_EA2A407B, 0x1A0D72E4_C6E554E5, 0x4B535893_C5B6C1EA, 0x9A67DB10_7174CA9D),
+        point!(0xD2B628A9_3739DC49, 0x239392DA_66F55603, 0x1064AC50_8BFF231A, 0x6A4BE6CB_02AF0E6C; 0x3E6A2B9D_F2EDD5CF, 0xAB7E2B4C_B14A4A4D, 0x7DC166E3_60FA8058, 0xF15D8FAA_2A890759),
+        point!(0x8AD9BF9D_D8F8FB8C, 0x4CDC2B9B_AA5BDE14, 0x9512FACB_5C730111, 0x3659BA70_60D8200C; 0x75E46B8C_7CC8A3D1, 0x1E142ED4_E44BB135, 0x139725F8_EC74987F, 0x4EE7A9E8_06E01A17),
+        point!(0x98BE8A0C_46987FAC, 0x8BC90F86_40A86F67, 0x44E98260_3EC98A3C, 0x01F7C69F_13AE7F13; 0x3F91E738_19174C68, 0xCB7F5DE2_19F6BC26, 0xE5B1036E_329AB6F9, 0x9D4ECC3F_36FDA22D),
+        point!(0x1F4D359C_EE2E607B, 0x88AA1EEC_6C0F36AA, 0x20D111F5_657FDD2B, 0x07733F67_FD31772C; 0x6A2F311D_CC614763, 0x89E3BDE0_5BE017D2, 0xEAE63A8C_5BB156F4, 0xFD9FE8FB_AC178285),
+        point!(0x1D33A1BD_76DABA4D, 0x9C65FB0E_20319626, 0xCB7062ED_D8FA2BCB, 0x557E9401_761FD381; 0x51374A1B_B7A52316, 0xC5E9B8F5_FA43590A, 0x46D35CFA_15CCBC86, 0x7A7E8F3A_601FA7E0),
+        point!(0x6E22D9CD_3C05FBDD, 0x79589D52_74E48139, 0x38920654_B6AFA032, 0xE3E4750A_00310C48; 0xCBA2231D_1535B883, 0xB46587BF_C5CFAFFB, 0x05235049_961A1204, 0x0FA79701_ABAD2590),
+        point!(0x038F52FF_29F5341A, 0x9CC7A32C_669636B8, 0x3B29D40E_A7B10141, 0x3ADADA82_199885A9; 0xB5158093_6586C6CC, 0xE9D6D2E5_B45E8FBC, 0x552E35BF_36AB1017, 0xBFEA1E22_8999369F),
+    ],
+    [
+        point!(0x4095980F_C28D3D5D, 0x9612AEB9_73449CEA, 0x52DC0270_907A30B0, 0x324AED7D_F65C8042; 0x967224AF_96AB7C84, 0x19213B0C_7E332843, 0xF130C0C3_5AEC1F4F, 0x648A3657_74B61F2F),
+        point!(0xA8F38381_B2919749, 0xF72B3E4A_8DE0DB1F, 0x28D32681_880D7203, 0x32C9331E_A26F4902; 0x631E97B0_F290B5E3, 0x268A4ABD_AE39AB09, 0x695A2F02_B6F3DBB8, 0xD7CD272B_34209CB5),
+        point!(0xF5C91758_2FD53ED3, 0x163F74FB_9DA56CCE, 0x5B8F60B9_31DF7C49, 0x20840BD5_996772AD; 0x87143FE5_1A7A7132, 0x5487C473_94E70899, 0x487BD476_A6BAFAC2, 0xF2993497_CEC18243),
+        point!(0x1E1C2252_7B9795E4, 0x69C161B6_E1846B8E, 0x4A02F6A7_0FEC6B1C, 0xEB292F3B_3B983785; 0x5B7E9D2F_AE53A0FE, 0x57131D75_09111C6F, 0x1696634A_F145835B, 0x8C43C25A_96EEBE80),
+        point!(0xDBD2CBB5_88A35B35, 0x1AB5D88A_5C0A121E, 0xBD13D029_E588EBFD, 0xE6B6BFF6_0EB339BB; 0xC57293DB_9C1007BD, 0xFE3B6C9D_3BE999C7, 0x266F43E3_835961DD, 0x1A5FF2BD_3300D2F3),
+        point!(0xC48B8E29_2032F9A2, 0xCE566F50_FA1FC6CC, 0xED2374CB_31FF8F63, 0x25AAC6BD_9A6B2640; 0xE76230A1_2B51D4F0, 0x559883BB_C419F3D9, 0x16F32F54_F4F190F6, 0xF5A6C63B_A644546C),
+        point!(0x0D4EF8D2_C0360DD3, 0x47A8A836_D850E209, 0x88B0B808_6E0CE3BB, 0xE0531774_5BE499B2; 0xB2AECD91_3C24F87B, 0x61B987DE_98103DC2, 0xCD809582_B5B6A014, 0xDC1C3B71_A5D92F39),
+        point!(0xAEA39D57_89303FDD, 0x4D71457F_19D1ED35, 0xE620D431_0049FBE1, 0xA65A3A01_DF3B5EF2; 0xDAD44A8D_02E68703, 0x86861D02_4FAED3CA, 0xB8F43D8D_90ED2C76, 0x798EA094_0CFF5C6F),
+        point!(0x7C146662_D098DFEA, 0xFADCB3C9_2EA67879, 0xE018E5D2_A83A5E57, 0xA8153B3A_77886C59; 0x8A49A9DF_6E6D892F, 0xB1B5E0F7_42DAF415, 0x53D8DB9C_57853E03, 0x2A473964_61D060FC),
+        point!(0x61630D52_D24C9489, 0xC0AEF4E6_E14E4A98, 0x9A27887F_F6E9D528, 0x6281BB15_D9D567C2; 0x9187F26B_9DF42BC6, 0x45FD964D_D321B3AD, 0x922738F4_56E9BBF5, 0x2FD3C5A0_908DA865),
+        point!(0x0A7E77A4_8BCECFA5, 0x2A0D62DA_1B16BB02, 0xDEB4695C_638EDA3B, 0x4F05C3B3_0FECADDA; 0x2ECD024A_95D37B53, 0x447EE431_F01B48CD, 0xADD63236_159534B9, 0x53A499EA_603D426F),
+        point!(0x90131CBA_94445A7A, 0xDF9535F0_81CF1EC5, 0xB12F11B6_C601C8D5, 0xF82FEFBC_9F06C6BF; 0x963ACE18_642A7A98, 0xB51C83B6_4640D1E5, 0x5503942C_1940B4A4, 0x94BB37BD_C6FB6B67),
+        point!(0x461E7659_084B96AA, 0xD0298635_522ABCCE, 0xE26405E1_AA3B980E, 0xD9309AB9_9F67A91B; 0xF26C4F37_C2B28A86, 0xD3B6C9FB_AFCFE978, 0xD7E10F06_6274CB5F, 0x6EF99B2A_9B4EC557),
+        point!(0xF6FDD42B_16B7D2D5, 0x2637B84D_1D56EA66, 0xC830F3D3_22EF502E, 0xA285ABAA_97834203; 0xC405D52F_9001F99C, 0xD8657321_B6B753E9, 0x92B952DF_E10B2E2A, 0xE56E9692_CD1B7AC6),
+        point!(0xCF7B8D05_9F8FCF0E, 0xD65B6FFD_E7C4A6FA, 0xBA9C3446_DEAA3411, 0x7D587CA1_38562D35; 0x501C1A09_D8BC9459, 0xC463900A_D323780A, 0x2723400B_6CFD6AD0, 0xA7295C03_88304C61),
+    ],
+    [
+        point!(0x8E8BD373_FD054C96, 0xEEC4143B_A8D1CA88, 0x6D51DFDB_E5FEE5DC, 0x4DF9C149_19CDE61F; 0x92EBAC06_CAD10D5D, 0xB5D506CD_C2884901, 0x050974C2_3A1D85D4, 0x0035EC51_092D8728),
+        point!(0xC0C6B0FC_1DA49E04, 0xE9DE4554_E57A8D70, 0xD25317D4_E4B87088, 0xED32CAD8_D2CC998C; 0xB63120D1_7C1DB9E0, 0x52DA9FAC_B49FAB7D, 0xA541CA37_5859D20B, 0x129FEF5F_1D030204),
+        point!(0xF4F225DA_5DEF001D, 0x21F9BBD6_989BA27E, 0x20E0BD1C_09E3B64B, 0xA549A32D_B27E2CAF; 0xDD5DB1A7_F0624783, 0xEB141E2A_62C9705A, 0xDF4CC30E_D8A59456, 0x799B7A79_06D966CB),
+        point!(0xCB7418D4_E883F855, 0x28C36DCB_63C34016, 0x8049E411_1C70366E, 0xE821AB72_4D6360F1; 0xED4E0C6A_59852DDF, 0x8B3B19C6_14EC23EF, 0x67D0D411_5416CF59, 0xADEFCBF8_63F53CE3),
+        point!(0x05AEF1E5_C52ED4CB, 0xC954DEFB_3C09EC5A, 0x23F08BD9_83532E6E, 0x7E798F30_DA07ECD1; 0x25716607_94BB9462, 0xB173571B_FF9F37A1, 0x041A9549_DEF2F057, 0xD23DCCC4_A24DAC83),
+        point!(0xA24778AC_4206E37A, 0x4D8C1894_8C504731, 0xCF4E83F4_6B5A274F, 0xB526AB87_F2868002; 0xBF3678FF_F0C5CCAB, 0x28CE539B_00FA392A, 0xD5CAACED_60A6F18D, 0x641F35D7_78100D8E),
+        point!(0xFDF3A0FB_D3630834, 0x8463F128_63DAC067, 0xAD5B9E96_BA8C8108, 0x224FA200_31514783; 0xCD3C4737_C47A2272, 0x3D62924E_C0A50F75, 0x3C4B70FA_391D09F5, 0xBBE54E32_BB3EDA5C),
+        point!(0xFBFF59EE_B441742E, 0x2F14A547_AF3806E9, 0xF455452F_BC9693A7, 0x3F0D8994_E51AD212; 0x8C65363B_CFECADBE, 0x1E205E2C_3AD13D95, 0x45E5CB0E_847A6E0B, 0xFBD76C23_F28C3DC4),
+        point!(0xB67649D1_583C19D7, 0xBBBB5E45_AC2CF57B, 0x129A25CC_E4228EF2, 0xC2D4A0CC_600A39E4; 0x72F28B82_C507928D, 0x26804A83_17345C97, 0xD4183B5F_7F41260D, 0x65ADA0F9_C0806294),
+        point!(0xA04541E5_5D848116, 0x1CC9B100_FC9E27BB, 0x05072CE1_0E948454, 0x1693799A_BB39D3BE; 0x83DF66C2_027198B3, 0xC03193E5_C4D992E4, 0x4E6AF60F_16C60DEE, 0xC49C433B_9CB2D8C5),
+        point!(0x84C80AD4_BC940C9A, 0x13CF3CE1_3AF42330, 0x0E5585E2_744DDC21, 0x2EC22A7E_C305F319; 0xCD194FD9_851E7A4D, 0x12405183_D9A35BDA, 0x4C2E24F5_029C7FEB, 0x8B1DA468_DE337968),
+        point!(0x7E93831E_B9D9123F, 0x29FC791B_1F88BA7D, 0x2F95F8EC_AFBD1E91, 0x341B538F_72AA6A6F; 0x19EA0AAF_18BB9A40, 0x72DEE6A7_052A26ED, 0xBA6F2F04_1124D9AC, 0x58581247_19C1C59E),
+        point!(0xAA70EE5F_DD683EEB, 0x3E36EB38_A08D3133, 0xAB1AF652_5084B8F3, 0xAFC66973_8F9A8CEC; 0x91E29CAB_30CD4509, 0x570BAB55_25EDDFDC, 0xBC359C7F_CA239C16, 0x002C2197_6DD22752),
+        point!(0xA7D07B1C_C7AAD77F, 0x6D4EBBDF_0F5A7B7F, 0x3284AFC3_A7699670, 0x4F0EAB3E_C68EB8DE; 0xE6C3F11D_CCE6EEA8, 0xADAA078D_025AC92C, 0x5A08DE30_62AC2F28, 0xB28EA271_76EBB757),
+        point!(0xEA201F87_B76F4293, 0x10E978B5_653C5139, 0x121BC8CF_370B282E, 0xD2557B5A_9F343309; 0x1DB187A2_5282740A, 0xE34B6838_DE405A5F, 0x12682F06_EB4AD21F, 0xA787B343_55E427F0),
+    ],
+    [
+        point!(0xCBFC99C8_AC1F98CD, 0x52348905_4D7F0308, 0xFAED8A9C_1CC66021, 0x9C3919A8_4A474870; 0xBE7E5E03_D4FC599D, 0x905326F7_6C64C8E6, 0x584F044B_F260E641, 0xDDB84F0F_4A4DDD57),
+        point!(0x435EFB7B_694A09EC, 0x0CD326A5_3E8535F0, 0x0A42FC69_D5C92AA4, 0x2E3C0532_6255D80F; 0x25348681_88C7327E, 0xE048A53C_707B6651, 0xBDDAE240_B82FC1AB, 0x001FF891_656C6FB5),
+        point!(0x17B98D53_8FB64DB3, 0xA7EDE4CA_39DD5384, 0x40BBB83F_BE53B8D6, 0xC1142392_29BDCCB7; 0xE230CE9F_FC0259BE, 0xA8758090_4D4567D1, 0xA5CECDE4_FE978BD1, 0x1237F6DC_5B486FC2),
+        point!(0x205F19A2_AB9C7CE6, 0x33710316_A1908934, 0xAE15FB7E_3E24DDA4, 0xE8E2A24C_CFA41587; 0xB2C01EB6_8836267C, 0x6E09E603_B5C27A73, 0x4CAF2B2B_3BEE2059, 0x46C983CE_0C6F5D1B),
+        point!(0x1EDED834_03081E46, 0x3A52218C_554559EA, 0x082D9C2C_19263471, 0x6C5B4BF8_31A77224; 0xED1F9CB8_0BFBCD70, 0x41D0CF82_6AC22A62, 0xB2347863_CE2BE478, 0xCB051371_4926D42F),
+        point!(0x80832ADD_F11349E2, 0x60E3ECF4_17524C05, 0xE62147E9_A41AD78D, 0xF8058324_C6B9C2E7; 0x5FAC469E_5B2025FC, 0x509498B5_626DE88D, 0xCDE81AA6_0EA11223, 0x95C60E5A_0A8856CD),
+        point!(0xDCAE5AEC_464DCD4B, 0x0C30C7D5_9911C124, 0xB5670665_CAB10A45, 0xE1E9A856_670CADE4; 0x2D0B625E_BB041F2C, 0x7F44D19A_ACA16B29, 0xB7AC4359_9B257792, 0x562B0A95_4455C531),
+        point!(0x5B0D3966_8AC7B3C2, 0x12ACAF92_383D5B5F, 0xB2F0A38B_170032A2, 0xA7549AAC_5D8573C2; 0xFA0C9171_9287EAEF, 0x5D6B5115_537116DF, 0x335A1D70_C1947D2B, 0xBD17D1B9_0D1C2415),
+        point!(0x005876FE_2BADD73C, 0x8FD9CDD9_02A64B7D, 0x778A74E4_2EDC1420, 0x51B21A57_AD11B099; 0x6F7D4AE1_EB36D8D1, 0x6C20130F_28C734BA, 0xD54A07F0_1D2C1CFA, 0x00793010_001FD3E5),
+        point!(0xAB7DC996_BD7E2C95, 0x6F3F2BA1_4B878D82, 0xEDD9A12A_8AC54157, 0xCA07CBFB_B24AD1A5; 0x05E99BAC_8D50DFA2, 0x3AC306F3_3B7E0CBF, 0x4A8A2267_BC1066C9, 0xAEDC3110_32DF0EDF),
+        point!(0x6D75D0B7_3B09F34B, 0xE58873E6_08CC66CE, 0x61AB6296_F3F39D61, 0x9701F3A6_3B1CB798; 0xACDC850C_C0DF5793, 0x7104BC39_7FDCC794, 0x5D7031B5_31568337, 0x3DD44BBB_8CAF0ED1),
+        point!(0x17371D11_AE548418, 0xDCE4887D_88983923, 0x2FB072B0_537A341A, 0x2E6DB0C9_E4817E29; 0xE69792C0_10A2B918, 0x4B8E1D73_E1C3F5F1, 0xA2131489_78A91164, 0x74EB6A41_1D776FBF),
+        point!(0x15CE6223_BF1E2F46, 0x5270F71E_87277830, 0x445592E0_40D63C57, 0xA036B41D_2C9E66CC; 0xF731E269_5EFFB349, 0x0680E282_CFF0B1F4, 0x7DF1F6AA_479EB08D, 0xC3BF91A0_03E96B3D),
+        point!(0x09605516_03DB2A09, 0xF6314287_DC201BBF, 0x2BD63880_0A766DA1, 0xB09DCC04_D9C30C35; 0xAED7DB3A_80B1A093, 0x6A951BD4_C16AD4E7, 0xF708D163_5AEAD00E, 0x8A7F3064_81CF240D),
+        point!(0xBCD8B903_8C4CEA08, 0x654B58BA_0E0EA21D, 0x6F4A6115_34004652, 0x6A85FADB_AA4E8C50; 0xA19DECE5_9862F4F3, 0xB4271A4B_43D721B6, 0x531933C1_48FE6230, 0x00B64604_BAE4659D),
+    ],
+    [
+        point!(0x5A213821_20A959E5, 0x91E1493A_8B91D4CC, 0x8DE05F28_1D8E06BB, 0x6057170B_1DD12FDF; 0xE899BE93_2385A2A8, 0x465152BC_3EE24C65, 0xADD9A2DA_F71DF262, 0x9A1AF0B2_6A6A4807),
+        point!(0x1899CA5D_82FD545C, 0x7C133F8D_D4A28E66, 0x0394110A_46DC85DF, 0x6773FD67_7C52E064; 0xE8C7F034_947EB1AE, 0xEAD780F5_A1C6CF98, 0x0F0F25C9_DD2B246B, 0x444EB6D8_CD97652F),
+        point!(0x0D4DA162_971C032B, 0xFF6B668A_17AFDC98, 0x3DD11B5F_DFC766C5, 0x71EBA8FC_D6E00260; 0xE7AB5A8E_1668359F, 0x71931AD1_774B4755, 0xEF660516_F54CBB7F, 0xD2FF1262_4B61D39D),
+        point!(0x373C616B_0B86F021, 0x0374E438_10468050, 0x237C79AA_CE0C87C2, 0xE0F86D94_D17CE565; 0x3B6B79CC_B5BF325A, 0x9A80BCC0_115FC45B, 0x7A91E832_F1C89A2C, 0x0C571C73_730ABCF4),
+        point!(0xD06E4745_2A03A61C, 0x507868F4_69070574, 0x2CA17442_BAAA9DD0, 0x855EC305_B3249D23; 0x5DFC6EAA_C65DFC07, 0xD332F74E_A17E4496, 0xF543CBFF_9B42FB4E, 0x0DD85D2E_C5F01C17),
+        point!(0x6DF0D9F7_3AAA138B, 0x63EFBA0B_EF786CD6, 0x7943EBC1_8C4671FF, 0x417FE249_D3C3AE28; 0xD1C91B33_4A1AE869, 0xC19DAFDE_C05566AF, 0xC20A5D29_F0FA9E57, 0x023589D7_BCD23E38),
+        point!(0xB00C0A48_1F32D706, 0x0618CCA4_746A0678, 0xF150D29A_3095BFA2, 0x3CE4486A_BAB3FBF1; 0x40B73637_FC7FD9E4, 0xEDD15643_CD61E89F, 0x88EFD046_B1094906, 0xE775408D_AAE37852),
+        point!(0x8AD0772C_02DA6E03, 0x4C277DF9_08CAD603, 0xCE991E19_3D696F4F, 0x42CA15AB_9F245041; 0x836889FA_8C347793, 0xD66E85A6_8106BEA7, 0x647CE4D1_FCB800EE, 0x68D2EF26_C81C57C9),
+        point!(0x6DB40CBB_791CD3FB, 0x4A2A33FD_9729BE68, 0xF02E0321_8F8844EB, 0x11CCC514_3F4E37FA; 0xA93716BA_55160D86, 0xFA0B1EFD_2CDD3472, 0xB528E244_938CC239, 0x4AA56B2A_902B11DE),
+        point!(0x108BAF04_29583A3E, 0xB1520599_8B156808, 0x57275DBB_ED94871C, 0x5889573F_16D0F7E5; 0x8F1598CB_7E5B124F, 0x96FE294C_D4CC69CF, 0x044F5DF8_A9A6B4E4, 0x1AFA2862_C55856FE),
+        point!(0x387B9119_618F7552, 0xBD41138B_98054FAC, 0x43B97774_F82382DD, 0x0250BB17_E5149C6F; 0x3F084962_3510B14D, 0xDA006F3C_333D6F1E, 0x342D7DF7_5FE9D817, 0x731930FC_9BBE8082),
+        point!(0x7E158B41_0307E636, 0xB4A77DE7_321611D9, 0x3DDBC29B_DA2112C8, 0x2219F160_16387644; 0xE07A1B2D_71F15109, 0x6DD7230D_3F73AF55, 0x1B806D4F_1B8230BD, 0x04A984AC_5CB6A2F6),
+        point!(0x05D13E93_9F725D12, 0xD94642BB_DCB5F475, 0xAB4A39CE_0A022FBF, 0x1A749C88_B3467FD3; 0x0136514D_23AC56E4, 0x60720869_8990DAA7, 0xF458BE75_C2AF3D8F, 0x7E91D195_73EC0E2C),
+        point!(0x5D22D670_B6C704C0, 0x56044660_ECDE394F, 0xA167F8A6_E3507824, 0xA77AA907_C92F2674; 0xAF8BC704_7EDFEA48, 0x4C921FC4_74159462, 0xF21E188E_5C2BD5A9, 0xB8881AE5_BF35CFE4),
+        point!(0xD61048E5_29FAE458, 0x8B0A5C42_766EC97F, 0x109D156A_5DFB7B32, 0xC750685B_E0F8218C; 0xB0168419_5757B598, 0x0A5DCA50_335951A2, 0xFB52F414_C672C7B4, 0xEAD795F8_CF921E05),
+    ],
+    [
+        point!(0xB1A6973E_ECB94266, 0x80CEF0FB_A7D4DF12, 0x421439A4_518DA318, 0xA576DF8E_23A08411; 0xEE8BE11A_E1B28EC8, 0x432E10A7_F514D9F3, 0xC92B97AF_E58CD82C, 0x40A6BF20_E76640B2),
+        point!(0xCEC5725B_DA704896, 0x6F3BA063_C9ACFB8D, 0x54652086_7BE71BAE, 0x9E5DCC62_EF3B5A3B; 0x48F2769D_CA82C835, 0x79605D18_6030F512, 0xA5FD3A21_54C76122, 0x6FEDD12D_DB925F3E),
+        point!(0xB68AEB58_CD9ED6C1, 0xC24E745B_C3D593D6, 0x80BFC187_705EDD0E, 0x0328336D_CB74F53E; 0xDEC6EBE6_F8FAFEEE, 0x265BF4DF_25CB494A, 0xC46D5943_A20D7C8C, 0x71A89838_12FD9F28),
+        point!(0x953ED587_44BF7EA0, 0xE33625A0_5CEF5833, 0xF8D6E9F9_76F03B20, 0xA7DE0837_5B8745AD; 0xE04C0553_9BBCABAA, 0x645A47C0_FEBC5AA2, 0x2104A0B3_34888E9A, 0xA63D96B0_57ADA5E5),
+        point!(0x919A9A8D_3235983A, 0xE38A1037_013BCEB2, 0xDC63926D_70FE1531, 0xCE4F4EAE_8B911C54; 0xECFD6B19_0D3CDECF, 0x526C6522_8EBF740A, 0x3EADE024_9E707543, 0xF3C9F973_C390FBBB),
+        point!(0x6B75648A_66DA57E4, 0x98C4996E_FDCDD9F8, 0xDBDEA2A7_4631BC1E, 0x690CDAE3_983918B9; 0x2B6195B4_933F33BB, 0xF863D8FB_CE95A3D4, 0x7A9FD91B_F595894D, 0x840ADC79_677B79A4),
+        point!(0xA6DAAB66_55B0E6C9, 0xDB5FF9CF_786E4C89, 0x78E28FB3_6DF8BCBD, 0x4DBEBFA5_4B986222; 0xE94A7855_5EB2CC25, 0x589311B2_BC504EFA, 0xF45A626E_6E2229F3, 0x10FEE7B0_3C913AED),
+        point!(0xA9F8FA10_4AD916FB, 0xE68065FD_E949E4A3, 0xC13C35AC_01CFF4CB, 0xC266658E_689080C9; 0x56AEF52B_A0887814, 0x6B8FECDF_EB611388, 0x0F798170_B24627AB, 0xE7E85938_54E7DAAB),
+        point!(0x64E01A11_A5857295, 0x69C8E47F_03433F01, 0x8B2E118F_CA1CA120, 0xA42A240B_FEF45C21; 0x56B377FE_CA7EB9C1, 0x27A1C22C_D1519395, 0x67A6B22B_24DDA4C4, 0x3AA0B3F2_61005D45),
+        point!(0x269BCFD6_005C5B40, 0x150B9687_9BB9FD1E, 0xDADED382_E8CCC3DF, 0xA4B53CC8_BF53CD14; 0xD1F5ED16_D1C61BFD, 0x199CCA9B_F75259E3, 0x4495CA00_3DFDA587, 0xCEEF08CE_83005B80),
+        point!(0x2220FF55_6FCDC098, 0x550AE00F_6AE46803, 0x7A7F1F86_C0C499C1, 0x34E12B2F_96FB2226; 0x64CB1ADE_6A7474E2, 0x04DF5130_F19883F6, 0xD974CBC8_417972DB, 0xA64AC2DD_D7B5D322),
+        point!(0x5F9898AA_5CFB8036, 0x3AD09A92_C0DA3508, 0x8AE117DD_1FD67E83, 0xCFF62751_FB1647B4; 0x843F3B5A_24F59DE0, 0x78A589D8_F6032825, 0xC5FE34B5_886143C6, 0xCDF4ED26_BD542D1A),
+        point!(0x1CD6EB5E_8F173B92, 0x3B7E72F4_169FB761, 0x9BE31A21_8F4D6568, 0x10F4D240_D9BB91F2; 0xC902006D_633146C2, 0x90BB769D_1486ADB7, 0x211B25A5_A33908BA, 0x850E2D95_091753AD),
+        point!(0xEE6660ED_D04393D7, 0x869DFF25_67749DE1, 0x4317F893_623573E3, 0xDEA1FC01_8A78F3B8; 0xBF97D4EF_95710CCD, 0xAF6DEF9A_2B79CCBD, 0xD3BF134F_F6D66E03, 0x4F6549CC_942E5C50),
+        point!(0xEE6FD1D9_A8D84958, 0x6A11DEB1_6CACC5CA, 0x49F19B97_6D40CC86, 0xE846E80B_69B677E7; 0x40C4647B_7882CF9F, 0xAD51A336_71E0C1A6, 0x41DBD910_4798B76F, 0xA29CB8FC_894C7E25),
+    ],
+    [
+        point!(0x8F763889_BE58AD71, 0xBB30D1F5_CF9A3A20, 0x0A05FE96_29DE8C38, 0x7778A78C_28DEC3E3; 0x3B513FC1_FD9F43AC, 0x87B38411_FF24AC56, 0xF7098E12_F2FF5800, 0x34626D9A_B5A5B22F),
+        point!(0xA7EA1C25_1073E879, 0x2CF16A5A_E104EEA7, 0x632F482D_7F0FE393, 0xE7B9796B_5CA006D1; 0x38E71089_BAA89D98, 0x9CB5BF25_EDA98AF3, 0xDF42102A_737CC1CA, 0x12B8988C_19169E2F),
+        point!(0x2F7C6EAE_3319C869, 0x18A9F95D_761EB270, 0xD0183902_2FCCACDC, 0x3018045D_98173FC8; 0xFC0C76C9_D2B856F0, 0xCA2A84CA_4A3D9A7B, 0xF8662432_0D622E3B, 0xAC5FC578_2503B7B6),
+        point!(0x865D722B_F2628F0F, 0x65423DAA_F2AEE919, 0xC2C915A2_4BE09A73, 0x071BF018_50876203; 0xB57C5CBD_45A1C334, 0x098F9C6A_231C80BB, 0xE33600BC_1C084CE2, 0x527AA15D_504DCF4A),
+        point!(0x4C66986F_A5ED29B5, 0xF5830B60_712ADD1B, 0x43AB67DB_9A63C885, 0x322881B6_1EE57EF3; 0xEF4F126E_7CD15AD2, 0xCA83AEAB_94EE7604, 0xB110B19D_F41531E1, 0x16770284_17A0344E),
+        point!(0x6477DC9B_18953133, 0x7FB3B42A_748721AE, 0x8812806C_0FCCDFA9, 0x0A0B2B4F_ED0DDD23; 0x4BC24A37_34AF0FC9, 0x6FA384E8_B0ED4BA9, 0x03C1C6CA_86458C8D, 0xCDA1182C_FB5ABF2F),
+        point!(0x3BFA8407_86C96100, 0xA163011A_A6E9C8E6, 0xFB43DD9B_2DF3DBCC, 0x3F38473A_C0FB1B9F; 0xF1E2AC1B_F2624707, 0x66571C7C_0B75F1BC, 0x2534C446_2F079CE5, 0x70C69C55_F5D40395),
+        point!(0x6F6376DF_A39620E1, 0x5B1911C8_50121378, 0x33A32E59_4C03C39E, 0x0218343A_CB9BE568; 0x5E0199F6_506998B5, 0x443299A4_2F43C9EC, 0xAF3F24FD_602FBFC0, 0xBEA81D48_970A50BE),
+        point!(0x234C6EC6_FD22D2AC, 0x10DDFE12_6DCAACE0, 0xCB43C0E0_9785DF2F, 0x288113C5_FD27A76D; 0x081EB55F_8F42A268, 0xDDC3D44A_1548C8FD, 0xF2077726_0C976137, 0xA1A7EB01_58FBC5C8),
+        point!(0x8C1A13AE_FEE515DD, 0x9D045323_343AA422, 0x0EF5866A_B4BDFE2E, 0xA2BF9AFE_E6EEC182; 0x5C3A96B0_06C30212, 0x00F2E3AA_9A8E9874, 0x3A0A5E2A_BC05EA28, 0x5A11BC71_C55CAB7B),
+        point!(0xD4BA80DD_245F8EA8, 0xD17248E3_964D2900, 0x553F4D95_D7119FE5, 0xF963A200_C8463A2A; 0x4BC55FB0_59BC99EB, 0x8BB8F235_9D8B6082, 0xA3B72230_1FF42720, 0x9C859426_8C7F83C0),
+        point!(0x7EAF0EC0_DF0A2A53, 0xD51DCAB3_F816611E, 0x3A839457_43A9B892, 0x6F86518D_07C3997C; 0x1139A574_733BFEB2, 0xBF54F772_EAA05F35, 0x9B776EA9_5A709968, 0xD50AF616_FD4582F2),
+        point!(0x9E1A8831_2606A315, 0x40726072_F26963AD, 0xF98D8B2A_590E097E, 0xA5D9224C_FF70D9A7; 0xD3561335_9E3B68D1, 0xED4D0D34_7D11A7C9, 0x343306AB_D7980DE9, 0x87328B00_3B20DA5B),
+        point!(0xAF19EAF4_D8D52891, 0x39A0F7D8_83EB9F52, 0x46644DE9_0B17FFA7, 0x3BC24BC9_CBC58DE3; 0x386D1E28_4D05B79C, 0x4DA84528_0A4872D1, 0x7FEBED84_0BFB48F4, 0xF08E30A4_8A783D8A),
+        point!(0x9CDB7B54_06231493, 0x96411A83_0CCC4D46, 0x4EB4E41D_00E5E6A4, 0xF1FE982E_1B73C360; 0x196A99A6_758848C1, 0xF173FB34_6E5FF0D0, 0x216D3C20_5F7D99D3, 0x2E2019EA_ED9A4DDC),
+    ],
+    [
+        point!(0xB09EDA9C_06D903AC, 0xD5F96274_E5AD7E5C, 0x63729FD3_0E7AFD2E, 0x0928955E_E637A844; 0xC5180E93_5BCD091F, 0xAC3D26EF_A8A8D83F, 0x27B78A13_093A95EE, 0xC2562100_3D3F42A8),
+        point!(0x231047A6_0F59DD9E, 0x7CE5E9C5_5B58F0E7, 0xDAD163B0_4CB18AD6, 0x4F89BDEE_3771D350; 0x1016AE32_0156B049, 0x1E488250_6638DF5C, 0x5C4BAF4C_043BB247, 0xCA7952D5_227A1F69),
+        point!(0x81112875_7874B839, 0xBE4115B3_953D2B41, 0x053DF0DF_B230B6AE, 0x3E03B81F_C0E1E5A8; 0xA0C1CE56_7C0594BA, 0x7DE5C5F0_FDAB5B8D, 0xC44F6607_57198F66, 0xD13AE163_DFF07F42),
+        point!(0x529C314C_FFF01197, 0x94B668F0_79A245BF, 0x0C396BAC_A2C3C4C9, 0xCB9E8304_CAE3C5A8; 0xC334E076_3B989C1D, 0x41B2D18C_015E0A24, 0x127258CD_FF088917, 0x62C7D280_1EB80E6A),
+        point!(0x3A3F78E6_8357A513, 0xF151BD13_45B3023F, 0xD62FA283_AA2922C5, 0xE662C0B7_A2F4492C; 0x192A201C_A017D07E, 0xD8035CD6_25538D89, 0x05DAE208_A121A419, 0xAB0B193C_E6124523),
+        point!(0x9BAE90D3_B9A7D197, 0x5E83A8C1_88EA5945, 0x4778FFE0_A22C234B, 0xC2C58A54_280DF639; 0x4E41CCBF_89EEB5E9, 0x0A47EC45_45AD8248, 0x573041AB_62ED39A6, 0x72EA3288_366E0927),
+        point!(0x4995F7EF_DF37D242, 0xC29FECA6_079C14B0, 0x843862C7_CBE3587D, 0x40BF80B1_C94CF6CB; 0x3D1D8279_A6405088, 0x74321EBA_42BD3558, 0x4F53FE9B_1B26FE12, 0xB579DD35_D856AAF0),
+        point!(0x15F9FFC2_508D2CC2, 0x8E0BEED4_7048C58C, 0x3C8CF2A4_10730DC5, 0xE2F349B0_F89C69BD; 0x7E347BD8_E0D4C04F, 0x42344BE8_E09CBDB3, 0x81860AEC_760215BA, 0x1FEB2F28_0F827237),
+        point!(0xBAF2B364_595A3558, 0xAC071F85_E234A7F2, 0x806F9781_38DB59F6, 0x3D14FE97_601DCA70; 0xC6ADB3AC_6443DF4C, 0x82E0A2E1_47C1D727, 0x8F29A931_DBF56E14, 0x16C6BDD6_E84681EF),
+        point!(0x10FC7024_14EA4178, 0x782E0354_E4C77CD5, 0x680453C6_A77494CB, 0x0470A872_D1756368; 0x079449F1_B8098BD6, 0x19B56870_6984DAEA, 0x676145E9_FD39C0CB, 0x0899A7A9_C1109ACD),
+        point!(0xEDE9F5AD_82B1FC24, 0x2528D81C_7E3F7570, 0x1CC1AC4D_2C924865, 0xDD6FA540_E82E6525; 0x4A431C46_0E9B74CA, 0xEC10DE6B_ED732230, 0xE366F1EB_F1657E77, 0x67E62B7E_B1D3186F),
+        point!(0xD74DE6D9_63369217, 0xCA1C3969_006611EA, 0xA21A278F_5404EACD, 0x42DC50FF_CBA4D624; 0x1C3097E2_3E2DBC57, 0xA7A139EC_A8E01E84, 0x7ED78F5F_160BA853, 0xA5BA9A1F_66385B1D),
+        point!(0x81EA9159_3DD08E02, 0x108547B7_FDADF028, 0x0F869499_AA3D1E80, 0x13FA2DA8_2DE55D78; 0xDA5541DC_3CB03410, 0xE644AFBB_19A42B97, 0x31335620_6BB432C7, 0x136338B0_4555DA72),
+        point!(0x23F1ACE9_1646A9E9, 0x5DCBB7A7_D8AE7B04, 0x7D1F82BB_9CCA75DD, 0x9582B79C_4B0AEF75; 0xBC4D7493_AA56A698, 0x01CBB5C5_F13B82FF, 0xD4EC6ACE_EAF8E6A4, 0x22067D80_0ED426EE),
+        point!(0xAC233AB9_944EE41A, 0x879B56DD_3B7D69A6, 0x3F7C10B6_72C68282, 0xD06C4851_D3C70A44; 0x2F1F84E9_8B295E6F, 0x9B7B8434_6162EE25, 0xBBB0ACD4_F4F63091, 0x5815FD4C_0584D0AD),
+    ],
+    [
+        point!(0x27AAE751_63D82751, 0x5645B4A9_07AD3545, 0x399064F3_A0E3B285, 0x85D0FEF3_EC6DB109; 0x82C237A2_4EB1F962, 0x3E8751E9_68773315, 0xE29D496E_582CF566, 0x1F036484_13A38C0B),
+        point!(0x56356D14_1D5FCADE, 0x68B482E0_09711FF7, 0xF607A6CF_CD11DF04, 0x6B790F4B_19A4C4F4; 0xB6FFF86F_C338D3FF, 0xA83FA5B4_7BE26B0A, 0xF296661F_9CAE09CB, 0xD03A981B_2FF9EB3E),
+        point!(0xB9955061_ECDE4CF3, 0x9340E535_478A066A, 0xBE7C2D26_D15D9AE7, 0x384DAB4A_C11422C3; 0xB7C796B2_252D0566, 0x1F430A33_9751BA73, 0xBFB4ED6D_A2B4C6F0, 0xD6E3C5BC_D1B9CA43),
+        point!(0xBB0CCDAB_E3A3E0CB, 0x3D2479DE_85978BE0, 0xC162C367_ACC4F8FE, 0x41149B2C_2D7EBED3; 0x70E92E98_339033A8, 0xB3EC7805_EF490F24, 0x2B415C9B_9902CF28, 0xC90D5B92_DB7C3054),
+        point!(0x98AF3E92_1E5238C2, 0x40355F5C_380ECB8B, 0x41B1610B_4E82ED48, 0x14F0EC0E_B7D415AA; 0x6E86A347_92F07922, 0xD25C00D8_0304939D, 0x921FC73E_3842747F, 0xAB12D53D_D4835D80),
+        point!(0x8A4366A0_8A972627, 0xE1921604_02521E1F, 0x8198C1EF_CBC441A0, 0x8A318709_49BFE15C; 0xE7B47B56_46BB8AF9, 0x1744365A_C3EFCBCC, 0x09577ED2_8587BAA4, 0x64BB7C87_68F7A64D),
+        point!(0x8F6352EF_D543E94D, 0xDD1302A7_56F98E18, 0xC41C3467_8A5A0DDA, 0xEB42F3BB_782C287E; 0x40559DE4_45924D89, 0x4CD8468D_E60D6D42, 0xC9A0DD57_2FCDFB77, 0x234A6D07_4F25D92D),
+        point!(0xF8DDDD28_E3F3D3FC, 0x664A9B82_05C29CEB, 0xFAEC3DFE_2872A7BA, 0xD1FAD4FA_4E7C849D; 0xED22343C_50F3704D, 0xBAD37131_EFF37326, 0x5473F70E_858B7818, 0x8FE19714_A348FDFE),
+        point!(0x0324E5B5_7E19AAED, 0x459BACDE_8C667327, 0xA9EEC02F_6E06A920, 0x1CF138CA_516820D9; 0xC136EA5D_692F400E, 0x479EEF8F_EFC77FFB, 0x5F4372A6_6B6DDE17, 0xD6FFDAF3_171C6386),
+        point!(0x37473BA2_E0BA68DD, 0xBCA7C943_8C7EAD65, 0x7F266838_C1DAD312, 0x05CBEAAB_FE2B7412; 0x94229F32_7DD03959, 0x7F3D9670_5DE533B5, 0xAE938666_5506EFA0, 0x13CA23B6_FA90156E),
+        point!(0x5F6C0E9F_B6A8AA39, 0x37A6637B_4A78FAC5, 0xC875A096_849990D9, 0xB58686BE_5F40D412; 0x8FC7935D_2B493C1F, 0xABBDAF98_E6DB347B, 0x149478D4_54C0A91D, 0x5632D543_24C78C2C),
+        point!(0x9A544C67_CA3A1550, 0x801DA99F_8EFFDF27, 0x2DD30976_E1F8B751, 0xEBFC69AB_34055741; 0x51F42BF6_41362F6E, 0x7983C818_E68C0B19, 0xA3DF199D_B7F4FD07, 0xE2FFE8CD_B99CB540),
+        point!(0xEE91EBE3_B03B4CC5, 0x572831BA_5A1C77C2, 0x67B5BC4F_E7F3A802, 0x5FC19DB0_6B8BCC2B; 0xA3B39FB8_65B57C28, 0x9276F503_CC087EC4, 0x6EE2138F_9A3A5EF4, 0xEC8E84C0_EC98C7C6),
+        point!(0x48BBF38E_5E8D319F, 0xE66AA561_6D4418E9, 0x1050B653_37AAC980, 0x6CE5C350_CE93A866; 0x08AC6BD7_95CF513F, 0x2DA8F2E0_0EC53783, 0xBED3E3E8_CBBE23F1, 0x950D0026_E2503C85),
+        point!(0xBB4B857D_4CB38CB5, 0xE10B7FDF_4D388948, 0xDEBA8A40_FD3EEA1B, 0xB88FB70D_42EDE289; 0x97BFA756_5965F3D7, 0x4D4F3E12_27AF8C71, 0x6034BB99_4B35A8E2, 0xD6C736AD_46EA3280),
+    ],
+    [
+        point!(0x8F540482_4526087E, 0xFDFB6D88_82DA2030, 0xC1C9B604_1798B85D, 0xFF2B0DCE_97EECE97; 0x2C951E01_F0C29907, 0xC7B7ED6F_B90E2CEB, 0x8AF4C4DC_54D07936, 0x493D13FE_F524BA18),
+        point!(0x3BD3A9AF_3149F8EF, 0x3DEB5C4E_E638B458, 0x78E29EBB_ECB1BB22, 0x2982DBBC_5F366C9F; 0x56E726B0_16C7A248, 0x095DB994_12E3ED84, 0xB9FA5339_C7B5BC9D, 0xA61B5BE9_AF66220A),
+        point!(0xC59054FE_79D681F9, 0x66CE0EEF_AC8FEB9F, 0x88B7FF25_E02C94B0, 0xC745FDF2_775F2308; 0x60317343_7BBB1247, 0x8CDBD335_C67D45C7, 0x8A71394C_70E81867, 0x590222F2_F6B9E5E7),
+        point!(0x85289F61_2380441B, 0x60011F45_80E17658, 0xB436EB59_0497DB58, 0x1A28E504_2AF0C0F6; 0xB05F606A_8452AF25, 0x04B3E75B_46EE67AE, 0x7C78329A_8976F0ED, 0x55779A79_96C59DAB),
+        point!(0x5E04CEED_35CD0EA3, 0xB34478E8_20CAC481, 0x27A59E5E_B672E7F2, 0xABB279F3_A975050B; 0x47093133_7C307BCE, 0x7C976905_9E02F3B6, 0x9FB4BE0C_03078ED4, 0x5DEE103B_BF17970D),
+        point!(0xE77FD534_649A2115, 0xEC796009_17A565EB, 0xDD46ACA9_D640981A, 0xFD73C052_B194C6C6; 0x049CAB7B_A6BA6CDA, 0x04F60156_2E9D421F, 0x020ABAB0_26F7D6D9, 0x620768C1_C8178844),
+        point!(0x5C2E2F3F_1BC9EE3E, 0xEA9FA1A9_63E7382C, 0x27FAEAA7_4267ED11, 0x3F81150B_59FC6828; 0x3CEADB0C_599AAE06, 0x7623B2DC_FCDA8160, 0x4671BEB3_C4795662, 0x19C88A68_FDBFA82D),
+        point!(0x2EEF0E39_621E30A7, 0x2EDD5EB7_985D8324, 0x1D250CC0_BD3F2014, 0x0C8B83E9_535F3060; 0xBEBF7BEB_9FF688DE, 0xAACAD237_B987134D, 0xB850E3F1_7EFDC854, 0x0DCC7077_065FDAC7),
+        point!(0x6171BEFA_F8E4A007, 0x15ECEECF_08BB358B, 0x9594BA33_AA56C7EC, 0x5CF8132D_D0082DE6; 0xCE63F090_9068B883, 0xC8946F96_C79C7FE8, 0xF4AD4158_848C3DF6, 0xB45AEE5C_0D61FA0D),
+        point!(0x3003EFCC_4C3C18E1, 0xBA4AE5FB_BAAD7230, 0x91226711_04BAC7F4, 0xB3FA0545_83510D6B; 0xCB11FBEE_12AA2B4F, 0xAA55A499_F10CE542, 0x7BA18298_064FE6FD, 0xA85C2475_9340F56B),
+        point!(0x0956FE7B_26C2D4A7, 0x1BC36F93_3BCFFFB1, 0x653E3397_3C404ED2, 0xB6D5FE4D_0D4BA494; 0x118DCE7E_3AE86371, 0x745B7E4E_6F5E3CC8, 0x4DFCF920_9DFFFD8E, 0x47F373A1_3ABBD6C1),
+        point!(0xFDB86C35_1B816B2F, 0xEE0CB784_1228EE11, 0x6E8165FE_126C5D5D, 0x5A4D46DD_65951A67; 0xAF97AEB8_73E86C60, 0xB50E6CC5_FB3ECCEB, 0x0283E27F_8F3DFCD6, 0x77C92396_CA0E60AB),
+        point!(0x50933622_FC66DD33, 0xEA8FBC8F_F1DC76DC, 0x06BB1B15_0029B022, 0x61C8BBC0_66CF5887; 0xA3BBA9F3_B6F10BFA, 0x6A5106EA_C2820C8C, 0x44669994_B985D3FB, 0x93599E23_9E54F703),
+        point!(0xFCE648CF_335CACBB, 0x18AC3028_BD635B26, 0x7B977E5B_DC622671, 0x4FD699B1_2C720CCD; 0x33B2EFA8_8E1AD839, 0xAA998EB2_E8223E61, 0xD9479DBF_CDB61E2C, 0xC6C90EF3_F511A0AE),
+        point!(0x9805B7CC_DD6B2FF8, 0x876336C1_7286C8FA, 0x87C9F537_3EC3BDF1, 0x94E32BA5_74267851; 0xADD48555_2CE1AF3E, 0xB33AF042_526BFE13, 0x92F1E488_662D9E8C, 0x0D1B448B_43DA04E1),
+    ],
+    [
+        point!(0x780E5E2C_F856E241, 0x57F1EE14_8CD6DD28, 0x9ED2B2E6_301B212B, 0x827FBBE4_B1E880EA; 0xD605B68B_AEC293EC, 0x7FF7A631_86903166, 0x71BEF2C6_7D1D1268, 0xC60F9C92_3C727B0B),
+        point!(0xEF6E44B7_0CB1815D, 0x60470A92_18B87461, 0x3E2D7C8D_576E6B36, 0xB77F12A7_DCE56B97; 0x6BA3D2BC_8E57DBC5, 0x4C42F0F9_1A44816D, 0x3F0CEFB3_73CC2E65, 0x4B6F85B1_4F86ACC4),
+        point!(0x634485CB_3BB80FA7, 0x3AA7DA6B_B7041388, 0xC0D1A06B_D320819F, 0x0857E31F_6308C2FB; 0xBD98211F_09366B2D, 0x172E3704_3CD7016A, 0x92D7CC9D_1DCB7147, 0xF6439342_3AE01720),
+        point!(0x0FE5DAD2_C45565EC, 0xD858D8DF_4977C597, 0x47B308B2_CB79F956, 0x48973B94_3018BF12; 0x3B879075_FAED07E9, 0x511B3596_580477B8, 0x6437BB3A_01445AF1, 0x761F7568_4F3CDC1B),
+        point!(0xF5D02C3A_09C70E63, 0x9CBB8B78_E753D496, 0x3545C655_764A672E, 0x28AACCEA_56BD6004; 0xB9F03882_F057DA4E, 0xF5FC59CE_444DA1E8, 0x67A89667_C768EBB7, 0x2B69322E_F81A0E15),
+        point!(0x33A00ABE_7B6D6CD3, 0x3416611D_03893788, 0x81A69B87_05652397, 0xEA126616_7F2B8184; 0xAAE62925_F4F450BE, 0xB1C9752D_EBBE1A88, 0xAACE112B_F1145E25, 0x21A4E2E5_078EE3FD),
+        point!(0x601DC52F_73E674B5, 0x92D8F69C_E7060AAC, 0x45DDB2C9_BCEB8A4F, 0xA2F3B625_A055A661; 0xB0938C8E_0E937941, 0x9222FA31_7C33FA53, 0x80044A90_F32A7C4B, 0x620EA159_614C68AE),
+        point!(0xA4D4D742_BBFD71FA, 0x0B775A26_5B4527D4, 0x6D697272_8A704C17, 0xE931258E_8EB5559C; 0x3D9BD0D3_174D3307, 0xB3946CE1_BB5E35F3, 0xE85EB416_9C954B40, 0xFB1E3336_4C3FDEE0),
+        point!(0xC7601631_D91EB056, 0xA002EFDB_8ECFD386, 0x1D98B5C6_A3F515F5, 0x3C4F83C9_EAAE5923; 0x7425893D_A05CECAB, 0x1982B544_81CF6B87, 0x33AD6573_C3B27880, 0x94479007_514A8B6A),
+        point!(0xE643D8CB_548FADF1, 0x00359B87_A712DF9D, 0x9C365F8D_E891817B, 0xEDF38438_7F7F4244; 0x79C436CF_6C415FB6, 0x0F4E8813_DEA37D28, 0xDED80235_120AA6B7, 0x72D3B5B0_A1898500),
+        point!(0x19061FDB_BD86DFA9, 0xDC38B4A4_09B993A7, 0xE29C1E03_E8103BAA, 0xAFF8E128_8A9967D4; 0x07103C36_C1D12681, 0xF9116DA9_4419F9C7, 0xD3D3EBAA_1FCF53F4, 0x8D8B4BB3_21377C3F),
+        point!(0x77123D42_5CFF55C5, 0x5F4EFD28_1A275EC9, 0xA6C12C1E_782B4B63, 0xCF36ADFD_FB25442C; 0xACA27885_21A0228E, 0xA7547EC2_5C0D2706, 0x49C2F58B_3BD9F207, 0xD16A1216_6AE07686),
+        point!(0x28F2736B_989BA9C1, 0xE40F7CA5_A09D338E, 0xE8458262_AECEE579, 0xD3FB7845_28DC00B3; 0x061B88DF_EB51B267, 0x341CEDD5_C011ADE0, 0xCA7CA057_58B70FE6, 0xF894D434_EFA6B4E7),
+        point!(0xFE8540CB_C181F79B, 0xE045D865_0CE4ADB4, 0xE2DF82E7_C6A5CA26, 0x31C80CA2_6C63DFAE; 0xF0B9E526_D5225400, 0x622FF9EA_F51125F1, 0x3094E4D3_68F30D91, 0x92B59EC1_202926DF),
+        point!(0x9DA96C1D_0F2BB909, 0x6391027C_53598D99, 0xFF472988_39187D7F, 0x4EC9300E_0AC9AA88; 0xB88A383F_A4CF4071, 0xD52E1682_0DAC3827, 0x5C80DC45_184C49BA, 0x782BA106_BE1B04DC),
+    ],
+    [
+        point!(0x7F3B58FA_2120E2B3, 0x7A58FDCE_7F47F9AA, 0xE7BE4AE3_4CE6E521, 0xEAA649F2_1F51BDBA; 0xD47A5305_BA5AD93D, 0x01A6B965_F13F7E59, 0xC69A80F8_9879AA5A, 0xBE3279ED_5BBBB03A),
+        point!(0x53DBC1CC_1FC9B0A8, 0x9E337B5C_705F3DB4, 0xC2623EA5_002279EA, 0x3ADB9DB3_BEB997EE; 0x61AE7975_F05BBDDA, 0xAAD9C8F9_870266CC, 0x3C774DE0_7C095FF6, 0x374E2D6D_AEE74E71),
+        point!(0xF583FD3A_3F2E070D, 0x29AAB71C_C52A6A98, 0xF48731C3_B85047E2, 0x4B72A5E9_042F4ABF; 0xE44BA82E_E96DD780, 0xB0B465DD_D2948C3D, 0x60277BB3_6D0F3C10, 0x599E1D4E_1D6AE1CF),
+        point!(0x054E9E0C_90AE86F9, 0xFEFDFF56_963E7CAF, 0x7E10955E_56C5FC69, 0x129E53AC_428E9CBB; 0x822EFDCD_1E89C85D, 0xB2A232FD_16B3E01B, 0xB2DA2115_B712183F, 0x415ECB95_8AEE9A29),
+        point!(0xE9CE7FD8_4A02591C, 0x3EF54996_585125A1, 0x85A6BFBE_B5E1FD61, 0xA9FC93FC_6539C8E2; 0x790ADDEF_69BEC2DC, 0xCA888C41_5FCF7253, 0x3E84C17A_1A9165E5, 0x9C2CE739_DC538717),
+        point!(0x428700A0_F85912EC, 0x27F9CA04_E4609113, 0x6AB49969_4AAF0543, 0xAA7121D4_E3FB5B78; 0xB114CB26_4AE35978, 0xFEE134A8_CE056CBD, 0xC505266B_96A2EDEB, 0xC690F077_DAD09509),
+        point!(0x11006E0E_2D968B59, 0x09A28BAE_13CBBC2E, 0x6A7D7AC1_209B0277, 0xC940017C_1A6F9F0A; 0xFEFD7640_8DE572FB, 0xE2842CB6_4390C9C8, 0x13B8A1BF_A5B5742C, 0x39D92250_0C9B8620),
+        point!(0x922243D5_E855B8DA, 0xC756267D_12894711, 0x5B85ECB6_AEE10956, 0x60144494_C8F69448; 0xAD500590_F34E4BBD, 0x543955C2_7E3F2A4B, 0x9E8BE1FD_9132E65B, 0x8BB5D669_F681E646),
+        point!(0xA2873335_726B3332, 0x310388D0_73EE5DE6, 0xEC6793A5_F70BF8E0, 0x1F84BB9D_7EED0024; 0x554428A3_14E8D52E, 0xC2BCCE2D_436DC3A2, 0x2547C27F_75B9EDF0, 0xEE726D07_2BCA9ECC),
+        point!(0x9DD95979_B1972BCB, 0xC2F22E3E_241ECCEB, 0x8F68E021_3EDA1776, 0x3DF2D057_C8BB9F02; 0xB2822CD3_D65D09DF, 0x1B914496_80194771, 0xD6E6B23B_046A684B, 0xDC7EB1C6_40C86EEA),
+        point!(0x5CDAA54A_CDEFA98E, 0x72484468_11AC2799, 0x6CA8157B_3991E9C7, 0x0F13E089_0945FCD0; 0xC08A7769_BE286767, 0x287E705E_16CEACCD, 0x35E3F8B0_A7B362DB, 0xADD521F1_764E7C50),
+        point!(0x1B806A24_FFD292DC, 0x775E2680_B17714CE, 0xBA48070D_37BD79D9, 0x4B991553_3720F934; 0x433A5FFE_57095CA6, 0x938323C4_5B376AFD, 0x6DFC0A74_B5F01F57, 0xE3277549_61103A22),
+        point!(0xA5E1C03B_E9F59B6B, 0xE9B38C63_C1888E2F, 0xB3728A1D_2D5826D3, 0xDDE191A5_51DDEC7D; 0xF6AD9629_26CB1410, 0x8B5738BB_E7154FEC, 0xE0A757D6_1C52E14E, 0x5B2BFB78_028275CB),
+        point!(0xEEED1B34_23B43EEB, 0x181C9401_BF81184F, 0x7EA4825F_FF359C5C, 0x83C40397_9EB95E93; 0xAFF34086_D484FB51, 0x95B6BFCA_B553FB40, 0x0BFC1E46_D00D61B2, 0x29575FF2_437CEE3D),
+        point!(0xB68C55FD_C9C6B699, 0xA86F5735_6BE46871, 0x5A16D7BF_97B41682, 0x5FAB012E_ED836F7A; 0xB1732152_C1C6C3AA, 0x90AB117F_EA883519, 0xE9EBB411_30D62F7B, 0x2D9BFA70_A3C3D144),
+    ],
+    [
+        point!(0x1E23632D_DA34D24F, 0x41B6D8F0_C9A13740, 0x9391DF6D_ECF42EE5, 0xE4A42D43_C5CF169D; 0x3A7F7131_DEBA9414, 0xE886EEDF_A8D8E4F1, 0x26FC99CC_FB8AD34C, 0x4D9F92E7_16D1C735),
+        point!(0x303F2EA3_3E8F62BB, 0x0553C562_F7AE4D2A, 0x3EF0ACF8_56C4EF4D, 0xFD6451FB_84CFB18D; 0xD0AD9086_132C0911, 0xFA2AB492_D200E83F, 0xB6FE7A5C_1BC344CC, 0xE745CEB2_B1871578),
+        point!(0x5C8410AF_3BEA0C68, 0x09430123_677B392B, 0x4949BC8E_8D396FAF, 0x3E419634_E156A3A2; 0xBCBB6FF7_1A45EDB6, 0x2FA11946_303CDA1A, 0xF373CBFE_37069306, 0x0123C59D_924B21F7),
+        point!(0xFA1D1FB9_D5FE696B, 0x0042E2D5_DCF3C7A1, 0x716E81A0_6F9EDBBB, 0x1EEE207C_B24086BC; 0xBB45670E_7429337B, 0x7A02062E_0AFD694E, 0xD2B196D1_2461C95F, 0x652CBD19_AEF6269C),
+        point!(0xAF75D23C_939824D7, 0x6EB7B64C_351C9897, 0xEED4A3E6_2F7F0B57, 0x8D9438F5_455D7508; 0x8A004F48_9366489F, 0x8FB9E2B8_326B063D, 0x2CA60BD3_1AB6EF6F, 0x3261E073_4FEE6C2A),
+        point!(0x9A9814C4_17D4B84A, 0x621DDF48_F1F433E6, 0x0C62A492_D2850704, 0xF13A99E5_8DC72FCB; 0x85990FC5_53FD1C81, 0xB6E37D47_10F2D962, 0xAA6B91CD_1E3FE06E, 0x33C2C8CD_0F0BE995),
+        point!(0x8E48071A_98D713DE, 0x9360C2FB_7428E620, 0x0D4A912A_2FE54543, 0xB72524C5_58EE5442; 0x76FFE525_9B8350E9, 0x0482D26F_E44A5FCB, 0x1042D182_E9D69415, 0x4C51B39A_8A283E45),
+        point!(0x08D40F19_EF94C0D5, 0xE1C0FC01_7C572579, 0xD465AB2C_346E2111, 0xCC0EA33E_A8A9EB14; 0xDEABE597_AF452FE6, 0xF6074F26_6113F543, 0xB23DD203_B5FBE663, 0xF9907A3B_711C8A2F),
+        point!(0x2CC6C293_FE3B2CB4, 0x3049F35B_F3721040, 0x9ADC76E3_8121149E, 0x3DE45F5A_216D1251; 0xF61E33AC_307EB02F, 0x823E28E6_5A87044C, 0x50E08AA4_A97C9106, 0xBA2A8598_405EADDC),
+        point!(0x9CCBD751_2A0C0222, 0x16937EF9_C82E8B55, 0x46CA18BF_C1700F2C, 0x9E4AC64D_9CA58A04; 0x16CF7673_A4FDC9BB, 0x1C62030F_3DAB904E, 0x3080E8DF_63B300D0, 0xCEA45D1D_9FE4E74D),
+        point!(0x7234766F_6CE4E5BF, 0x9B67CA86_A09005B1, 0xC1E3FCE3_748906AE, 0x8511F1C6_8959BE87; 0x2FC92944_C1789C08, 0x2DA87889_BC536526, 0x4C1DDF7E_C6AA0023, 0xECDFF5CF_91BCD487),
+        point!(0x5F0C3B14_4E735A1E, 0x4003E096_6BE61FA0, 0x46B8CDDA_D61202FC, 0x678AC7C0_799B56CB; 0xA363A5F7_12F7C055, 0x597FFFB3_3D371457, 0x7A70FF3D_57105E8E, 0x08514E33_D9A5285B),
+        point!(0x31B5668A_3D3258AB, 0x57D24281_8ABC14DC, 0x2CFA1C72_EE14AB59, 0x8C5A052E_81CF6022; 0xE57E0853_022D0F8F, 0x46DAE8F9_C0D944AD, 0x079A80C7_904C08C2, 0x6811DB4B_B443BDA1),
+        point!(0xD7BE9770_822AA9B8, 0x00006E29_33506F8E, 0xDD66895D_9C5AD45F, 0x64BA9514_D8680F6C; 0xE93D1FB9_B6163565, 0x5FEF1B6F_68307B35, 0xB7E07728_C19BE49E, 0x81273F22_A6431BDB),
+        point!(0x158C2A0A_BF91BCEE, 0xF5CF1D8F_1E0C0D84, 0xBDC63CEC_556BF137, 0xCEF7F816_DEBD3560; 0x6BB4DA9F_81966B33, 0xA63E4B02_A172F6AC, 0x2463B339_C01D245F, 0x67E74C83_63748701),
+    ],
+    [
+        point!(0x47173B9D_4300BF19, 0x92B53576_A88FEA49, 0x54160FAD_AB352B6B, 0x1EC80FEF_360CBDD9; 0x671CDC1C_C107CEFD, 0x0146E77F_6295A07B, 0x2F3A4958_A7ABBF5E, 0xAEEFE937_56B5340D),
+        point!(0x0DAE805D_414FF9E4, 0x8E8462F6_EBD89056, 0xCBEEAA03_44FC90BB, 0x5BE7EA35_19F04BC6; 0x4030B078_47E0BDBB, 0x0E99C630_2119A309, 0x477F890F_655AB7FE, 0x32F32EC3_F638E605),
+        point!(0x4B17CBBC_52FEA1F9, 0xC4FF0B50_8C0452B9, 0x3BCFDDAB_67106531, 0xA6DC880A_55D1F2E8; 0x3B1C14E4_7BC345E9, 0x057B89DB_7E68F7E6, 0x038683A1_16ACBC50, 0x7EF1A854_7DC367C3),
+        point!(0xE3FB545F_4DDB7BB8, 0xD5002864_9F853991, 0x0813FC86_98DF7F5C, 0x58F09911_6EAE4E65; 0xBDEC7358_2E5B2D6E, 0x507EE406_2D174302, 0x0D62FF76_14638066, 0x7E07002A_AFFE111A),
+        point!(0xC8D40C3F_06D6C9B3, 0x8C6C4CE8_74865637, 0x2BDC229C_78A481BA, 0xFE6BA93F_EA424599; 0x0C2C788F_A948BDFB, 0xD980F1BF_05C2E9B0, 0xB6BB41B3_45413B56, 0x7EE918D7_40539872),
+        point!(0x8B613E77_1C7985C4, 0x134BFC49_5B9E2B10, 0xE5428428_02F74C34, 0x2320B5CA_F7B59B7C; 0x4FF4F1DF_4C2C6D44, 0xD96F1021_1CD9EB8D, 0x18C721B8_1F9BCA93, 0xC79F943D_C88BE943),
+        point!(0x422F1073_0CF95151, 0xB964806E_442C4B64, 0xB020C8C2_DC08DED1, 0x15D5E2F1_46FC98BF; 0x482A07CC_2FF8ECF2, 0xBB204FB9_7DD8C0F9, 0x7F90E109_789023F9, 0xA5B72E31_915FD4EC),
+        point!(0x413C1606_CC9A8E2C, 0x823D8D1A_4CC1A1C1, 0x33BCC04F_D860CB0F, 0xB0F9E4B9_B29790B6; 0x6C2066C4_DF3D0DB4, 0x3350CC02_C171CEE7, 0x41CBB0B9_06FDE3F0, 0x49E82BF1_843ADE6D),
+        point!(0x3D7810B9_296A5658, 0x3814B252_41EF6564, 0xD8C7E009_27AE29BE, 0x84C0E872_5688447A; 0x2D96729B_D81B80D1, 0xD3A61A98_1F4F1FD2, 0xA087E319_0653725E, 0x26598380_C16022C4),
+        point!(0x5AA02F29_D9D6FAD4, 0x94B357CF_1C9A124C, 0x8FAD04CE_213D59F4, 0x56DB6280_670A91EC; 0x67A9C0FD_359E0836, 0xF5B8B2A1_A31E5CC8, 0x50F59101_6D6830C7, 0xDA0D3B94_DB572724),
+        point!(0x375BB5C8_94DC6A0F, 0x74434191_42FE1575, 0x565264E7_A2C077C2, 0xFED6B1C7_1A93731D; 0xCCB8D72D_976FC7E0, 0x1374D593_0A3FC409, 0x8479181A_E4D3AE23, 0x8DE2ABE6_B0FFFF0A),
+        point!(0x79FE0B0B_CA3A809C, 0xDAB662F5_D798346D, 0xBFBCF97E_4254A89E, 0x125E7EC8_3933DF56; 0xDA8C900A_949E70D9, 0x1E4E3B2E_F07FCA3D, 0x887747CA_F5721A37, 0xE0D3F6ED_E254CB38),
+        point!(0xDB3885F1_18E29355, 0x2F1C8E93_39720D86, 0x0FCEF860_4023B9D8, 0x2A6A4DD9_92D3CBC7; 0x0053E00F_B5CC2872, 0xFE905A3A_64C4C76C, 0x760FDC81_E268F90D, 0x8677DFBA_7C4A7E0C),
+        point!(0x7F529511_E7681028, 0x196E235E_AFCB9BB4, 0xBF33CE97_DDAF3D8F, 0xA1ACB3F1_185D20E1; 0x1F1A463E_14A11C9D, 0x66EECB27_1FA0D788, 0x6A047588_288F0C5A, 0x55A883A6_1ABBB29B),
+        point!(0x1F9FA88F_E148DABF, 0xC1ADD2B9_349D78EB, 0xD9764EC2_35FAE735, 0xE42D9302_6C927546; 0xD772C756_811E1361, 0x32727570_19E77EB9, 0xD8B38CEB_FDF38530, 0x6413862C_6E2D84B0),
+    ],
+    [
+        point!(0x9DDB5928_366642BE, 0xCE349071_7D58BA88, 0x91B00AF4_680DFA8B, 0x146A778C_04670C2F; 0xD0B29748_3D83EFD0, 0x0AAA971D_2F7E5ED1, 0xDD669827_F9D4B287, 0xB318E0EC_3354028A),
+        point!(0x11B23655_79DE5CAC, 0xEEFC983C_7ECB2619, 0xE5670B5C_0BCD14CF, 0x574EF0CE_8A597E24; 0xAE59AEA6_C75A4805, 0x1A260A7B_EF10008C, 0x3BD6ADA0_569B7845, 0x09B99930_281F19C7),
+        point!(0x9D938738_27315443, 0x3D79BBD5_4AE86729, 0xF42C8998_20A142A1, 0xFC696C04_0660935F; 0x7490D60B_57D28960, 0x487FF486_109BD1B1, 0xBBF0E1CD_D69677B5, 0xE0CE27EB_F83B5892),
+        point!(0x808C2D74_260966D3, 0x10A770C1_AEFF8645, 0x5D909397_B98C835D, 0xD3D97E79_9D8BF9F8; 0xBDDEB850_833C2E52, 0xB5E48711_BE8DC4EE, 0x6AAA8927_5D403AD3, 0x8DDBB463_76BAC95E),
+        point!(0xD1BB2F5C_16FDB4EB, 0x8FA3DD79_C62E6A79, 0x1AE56A35_62BEB092, 0x974AF221_FF4FF2AD; 0x8409C3DF_A9F6F484, 0x111C572A_3BFFC234, 0x64D1E43D_02C090ED, 0x5552387D_535003CA),
+        point!(0x7F3615FD_7C6B5B56, 0x4185C8AA_ED888B60, 0xCDF587ED_3BF200E5, 0xA0E88657_00EF4338; 0xA16BDE51_3A1D7518, 0x526B871D_70858A51, 0xB99A6B89_B853DEAD, 0xF5BA4683_9FA9AD50),
+        point!(0x73904ADB_5D5AEEE3, 0xE8284BC3_6658C813, 0x09121B3A_669270C5, 0x5BAAB59B_49DE398B; 0x82D29AFB_70F69717, 0x215DDC9D_ABD31EED, 0x6E5A0086_61FFDAF7, 0x8A577F61_7C0F7E85),
+        point!(0x0040FB93_968C6D4B, 0xB2BE78CF_8F494C12, 0x7B974E78_2CBBEE0A, 0xB1AA6532_88B31898; 0x83D9E62A_E891AC51, 0x100A1D90_9D623CC3, 0x2684AA8E_2D63A83B, 0x7ED6071C_60810D71),
+        point!(0x851B1E44_F5726890, 0xE59025FE_AC837D15, 0x01032FCE_BF910A0E, 0xFB685FA7_EB49C43D; 0x606EA8F3_B835783A, 0x4FA6CB12_E90E79B2, 0x869CA16D_9864BA09, 0xD750DF22_C98E5E2E),
+        point!(0xB7EEDAEC_62C6E5BB, 0x24FDFFEE_356CFE6A, 0x358AB0B8_392A597F, 0x25116C41_08C7105F; 0x4DC4732A_0F7E1321, 0x77C508DF_51CC272D, 0x1EB7406D_C815DD8F, 0x60D86913_E77177E0),
+        point!(0x5464BF13_A8BCA48A, 0x78B4D5F4_E83DF62F, 0x0AB27B91_C287F71D, 0x5DE58FF6_59E2995A; 0x58794E80_D2FD41CD, 0x3FE5BD9C_4E0F8AA9, 0x309A623C_252D3C84, 0x685A3B3D_80359B55),
+        point!(0x1D765DF7_50CEDD26, 0x3CC8B99D_EA571554, 0x611D8100_F9639E2A, 0xAF884837_2961EBB2; 0x8B6E7AD3_B0E43F7F, 0x54549E48_67AA4170, 0xD28CBB88_EBED2F96, 0xDC57FBF9_7B167030),
+        point!(0xBDE38344_E5EF63B6, 0x5AB4D016_731B182E, 0xAF1B7FDA_3B4FC1C8, 0x6F1825CD_EAD618BF; 0x12C846CF_45C655F3, 0x3C895A64_027F9454, 0x98F4204D_78333455, 0xFF097E7D_D591911B),
+        point!(0x39F9E5B1_FAA50E2D, 0xB5A275EC_F6AE4BDC, 0x5B256E3E_A081ADD0, 0x5210D660_5B888036; 0xB029A893_5AFDA8EE, 0x5A273DCD_C642686E, 0x39C13C0E_7FF6588B, 0x35F6674A_5DECD301),
+        point!(0x7B5047ED_F4A63F3B, 0x83203B01_9978F972, 0xB587115F_5E76C765, 0xC5ECB895_F762A376; 0xB53F3746_85005024, 0x8BEB65EE_5E30BEE5, 0x74EA4058_FF9233EC, 0x22252518_197881F3),
+    ],
+    [
+        point!(0x6D76A879_3180EEF9, 0x8D001220_9A28B977, 0x7E3ACEBB_1AA07B12, 0xFA50C0F6_1D22E5F0; 0x38CD8D7D_3F4F2811, 0x5E683293_A57A213B, 0xB72CD287_2281A68A, 0x6B84C692_2397EBA9),
+        point!(0x59C5BE23_187F5048, 0xE72328D2_448386D4, 0x780140FE_02E90836, 0x63964EEE_619074E0; 0x383A284D_89309DF8, 0x3D580B93_4DDE6C84, 0xA39FF9B1_C34BFBC9, 0x3B6CFB3A_6B89CF41),
+        point!(0x71EE0E33_91DA5E12, 0xA9C60A40_15CACB29, 0xCDA329F9_3A1CA2B6, 0xF7502E3C_4379E31B; 0x7A4B9C5E_8385F4EB, 0x7B86D32E_F725CEBC, 0x59970945_C3D67204, 0x3C57F5ED_D67CFAFD),
+        point!(0xF814BA1E_BADB2A65, 0xAFD7F12A_D3CBDA31, 0x2D1469DD_F0FC9F75, 0x5A3CE25B_4D15B7E2; 0xD8B170CF_1D327F1D, 0x3EE28BC3_D825FE8E, 0x873A6DBF_BF3F99AF, 0x8B34125B_92E05F63),
+        point!(0x20749331_10B7D105, 0xF4CA5C4B_94E57C9F, 0xA3A4F662_4E3455B3, 0x12FE78F9_83AE5862; 0x458AC6FB_9F794A60, 0x1DECE265_D6EE90B8, 0x786B5AA1_99A7CB77, 0x2062F1A3_38D6BCF7),
+        point!(0x23731207_3E32478E, 0x24AB318F_2A9BA7ED, 0x0FEC6B7F_3F332B20, 0xD42011D6_01061388; 0x1CE05681_D04E88D4, 0x6FB24B2F_35508CC3, 0x1B2D5F5F_44BF847B, 0xBAE5D4E9_A37D4E0C),
+        point!(0xF621D833_9E0C5D05, 0x480E6C50_AA572DAF, 0x58C4BB10_28084B1A, 0x76AAC313_47DF473D; 0x556619B7_51ECE63E, 0x4B1225CF_015E6EE3, 0xABEF32C8_3202625F, 0xEF5576EF_0D5C70EF),
+        point!(0x16A73375_4A9F44D0, 0x85DBCB6E_69A8FA00, 0x6910BE34_F0DE41FF, 0x5CE605AF_98F93EDA; 0xC0D05F34_89D30105, 0xAB3CB1BF_A32ECCC6, 0x7BA56BD0_31C76C58, 0x4CDDCF9B_EC226BFE),
+        point!(0x9E26F485_FC53C086, 0xC6B55C34_9C9A1AEC, 0x87E60C31_45E88104, 0xA663FE5B_BE5C5CCC; 0xE69C5032_A5016201, 0x991ECCA5_73994FC0, 0x5D1B102C_89823DC3, 0xB541997F_6B211FBF),
+        point!(0xAA20D691_C9F40840, 0xA71DF442_9E55359E, 0xFE4EAA23_938A4347, 0xE0346D21_121FF741; 0xC39A1AAF_F3D7CE72, 0x269CD39A_6EBD33ED, 0xD756D4A9_7EE7BA2A, 0x3EE68371_3C998956),
+        point!(0x9AD801ED_4B758574, 0xD8326689_434F9C6E, 0x5B7CA102_7AE2BDF3, 0xDE95527A_0206CD82; 0x4B7DF1FC_FB67232A, 0xBAE6A105_155A2DEB, 0x4E21DC84_7F169ED7, 0x4CD3E056_AC93D14E),
+        point!(0x56A7237B_A16DDCD3, 0x044B1100_21EA0035, 0xF7B5BEB6_CB8761AF, 0x1AE1AD36_F64E99ED; 0x7CC4619D_2E7EB2B7, 0x59616ADD_FCBB613C, 0x59C74F77_52104489, 0x70160549_87A09220),
+        point!(0xD779B8AB_FD85474F, 0x894C4A2B_89634668, 0xBFEDCCBE_44785AEA, 0x8DA6BCE0_66D32ADD; 0x5FDF9C7D_3D7B4EF7, 0x0B1CC593_05DBDD7A, 0x84FDDF5D_071DFFF1, 0x13FC6C6A_F31ED4FF),
+        point!(0x80FFFF89_0A9DE533, 0x2A9BB567_02356E3C, 0x20E979F6_A6471274, 0x1138AD12_333790F9; 0x6A8B7094_FD332573, 0xF679BD0B_454A4000, 0x86F0DC3E_9F95A7DF, 0xB35525B7_58751D94),
+        point!(0x6B5D4256_7DD042EA, 0xEBFA26A6_458EAFD0, 0xB1BD6649_E33C93BE, 0x7329ACC7_CFB3BA39; 0xADA8CFEC_2F5C94A1, 0x18489773_1DFAB4F0, 0x89284E79_4FAB68EE, 0x92724935_41B76F14),
+    ],
+    [
+        point!(0x0E53D32B_5F067EC2, 0x1A288AB5_D5BBA522, 0xB1A5BF6B_7D88E842, 0xDA1D61D0_CA721A11; 0x655FBA0F_1AD836F1, 0x66A73899_D279B48A, 0x79C07661_61C91E29, 0x8157F55A_7C99306C),
+        point!(0xB3C210D2_2CD3C369, 0x23605483_E1F8E934, 0xDF85D5F6_1DC1283A, 0x9C7BE00B_4EF4C444; 0x29EE3FEB_A2329515, 0x3E31153A_16769CBD, 0x52A26D45_5CE40148, 0x9220C0DE_74B20D20),
+        point!(0x15D87732_FA95A8DB, 0xB83EC0C4_479D36F6, 0x19E96646_991B1723, 0xE3E90DA4_6303DD04; 0x1016CF7F_1B0D1CF9, 0xFBB1EF97_CC984D3D, 0xB00FAA90_C702E76A, 0xFBE53BC0_056C178B),
+        point!(0x76BCD92D_7BB8C9E3, 0x74DD06A7_0541178E, 0xB55664B2_38CCC491, 0x0FCD83F4_2825263B; 0xE86D55FB_DF4AA9AD, 0xADBEAECD_F1627BF4, 0xD1D8232D_E5FDB683, 0x6C0BC1CF_EAC5FBCE),
+        point!(0x17AAFD64_112EE214, 0x782A4E97_95931540, 0xA8B650DF_D5C0C01C, 0x9F4FFEC7_32E3D775; 0x3EF44222_5085F37A, 0x9719B693_0BC88028, 0x60E96682_FD75C69D, 0x639CE2E1_318E2F2E),
+        point!(0xF4FC3DCB_541D0CE6, 0x748992A2_CF598D41, 0x2EE14CCE_33A05FB2, 0x14295A29_37F1A941; 0x52A81A8A_D738BB86, 0x31089560_1B5D241A, 0x1B031C44_844083B1, 0xFEA75363_DF115071),
+        point!(0xF8B68145_1F61A0A5, 0x4B1253E1_B3C14E3E, 0xB171A0A4_1F440B2C, 0x431F622D_41134AC1; 0x46B7B7FE_78CEF899, 0x41BAAA04_35565772, 0x6D6DE651_9723AA72, 0xA298327F_E7AA438F),
+        point!(0x5CF39944_B26B64F1, 0xB7EDCF28_F5476D99, 0xD4CDA4C6_2511E59D, 0x7175407F_1B58F010; 0x426E7EFA_B24234D5, 0xB01FE8B7_74471D2A, 0xF36D3401_134CC86E, 0x43B45543_44E3D550),
+        point!(0xCAC267D5_67748503, 0x0E6E0E47_731328E7, 0x6CB62EF3_9F8A12AC, 0x993DBADD_ECE78FB8; 0x9BA73613_7EB0CEE2, 0x17F87BF6_9B8ADAA4, 0x785F7847_87A1F51A, 0xA521A9EE_8569EEEB),
+        point!(0xE464DBA9_97421E21, 0x3ACA86A2_3CEFE2F4, 0xBA828921_2418CFAC, 0x0C20E86B_84E51772; 0xDFC83D64_A1AA6A45, 0x86EE76F6_5BD09349, 0x1A1237A1_1E19A5B7, 0xF561E9BF_76D0ADAC),
+        point!(0x8DEA2275_A6D23D80, 0xE10BD11F_26474D47, 0xA339431C_B90962C0, 0x75F17ABA_06AD5EA5; 0x986A85B0_280DD57E, 0x372FFBED_BB4EA590, 0xC7FC7D0B_F19B6292, 0xA07A62FE_15639A80),
+        point!(0xC718CF9C_4B344F7F, 0xEE8B89FD_48CE60C4, 0x0E97E32C_BD26D410, 0x354CC84B_D972E0AE; 0x7E4B11AE_A5DB82D6, 0x8E36C601_8602E0EC, 0x6750B493_F9BA8855, 0x9C04BF29_291C6B5D),
+        point!(0xAE2B9B6D_61AD3413, 0x8DB9C004_0327D684, 0xA6E95F48_5CB87B7A, 0x6678555E_11D9F4A0; 0x12481020_7EBCA672, 0xA6CE2D15_3ADC6660, 0x925266D9_27CBDF9E, 0x510DDA3D_E278F646),
+        point!(0x7487B5F8_3502B4B1, 0xC4AF2554_D6C08A44, 0x40486C0F_262213A9, 0xACEF1418_DA0F9428; 0x6F81FA79_66E85BFC, 0x2A3F6290_ADF1878C, 0xE04017B5_3D8971CD, 0xC4D3559F_D7E87A4D),
+        point!(0xAC00AF5B_06CC8563, 0x9224DF5B_FFE101EA, 0xADAC82BD_81963688, 0xEBDC4A36_2CDA7748; 0x49B17BCE_A394CCFA, 0xBEC40792_49204F92, 0x9B1EE35E_6CDD4F07, 0x659A3144_E856D35F),
+    ],
+    [
+        point!(0x2A73B061_0D064E13, 0x15311DE0_446F1E06, 0x7215FF98_E8FD4166, 0xA8E282FF_0C970690; 0xCEF7C731_11F4CC0C, 0x8B679A3E_50DD6BD6, 0xABFB7F3C_5B251588, 0x7F97355B_8DB81C09),
+        point!(0xC15EB9EA_7F7653A1, 0xEFBE9EAA_753D67C1, 0xCB876F80_5EA66E63, 0xCAC6F2E7_E27FAECB; 0x68C10AD0_FEC5E556, 0x81E83AE5_688103A0, 0x4CDB65D9_A42A3450, 0xF7D416E5_E2AA6F19),
+        point!(0x22A199B0_BA3979B5, 0xBA288F8D_E67E829E, 0x27F37F0B_1EE40E50, 0xAE2207C5_CDADE263; 0x68F3CD66_8450FA6F, 0xFCA87B7D_37D4F889, 0xAD4C9245_23AD7060, 0xEA91FE51_0C079F71),
+        point!(0xCC9F4AB0_8624003D, 0x4AB76729_4238CB11, 0xEFBC5932_E58E4325, 0xE6DFDE46_EE37D206; 0x7548650E_2216B93B, 0x3B1CE5EA_527FD7DD, 0x8F2F48F7_B88F9220, 0x8727B3B7_BE913949),
+        point!(0xE4101910_0EFCA824, 0xE0BE0C4F_EA2164FA, 0x9CEC5410_06585461, 0xCB8DED0C_AD72ACE5; 0xF140BD05_8F227361, 0x96AD1FDF_C7931742, 0x5B316C48_7A2CCBC4, 0x33A5008F_740D88C8),
+        point!(0x559E11E1_ECE4DD6A, 0x0A00E49C_A3221CEF, 0xBC9ECA37_E8D64C46, 0x49DBE4F7_B2792B64; 0xB13B72A4_2A9E3EAE, 0x1C38552F_CA05BB69, 0x2C5FE33D_B692255A, 0xB8B57298_470481C7),
+        point!(0x9411B4DA_3BCBD327, 0xB04E0852_21E4AAEE, 0x08E94900_D7E76ED6, 0xB0C53B29_8AF18367; 0x1616BC4A_2D7BE436, 0xD82A220C_74636A0F, 0x638F6A60_1B66B2AF, 0xEE2A9740_1FBD7EA1),
+        point!(0xB0E11035_48DCE109, 0xE250E314_9CF211D3, 0x66A40CFC_7AC96082, 0x3C4E089C_D9A6823D; 0xE2BF6592_3A19AEEA, 0x9579E142_D97FE697, 0x80757BCA_15764D37, 0x43FBBE66_9FE191B4),
+        point!(0xDE6EFB63_97B836A1, 0xC806405F_F7679EF7, 0xD4D8862E_CE77F526, 0x6F6BA73F_329DB920; 0x4DBDF2FB_06832B84, 0xD8990C52_88E1FF93, 0x3DB0B8A4_05BEE9C0, 0xDC778A17_FF5FC18E),
+        point!(0x68056B7F_825D5241, 0x563ED7CA_920BD2F6, 0x534277D3_40B3EA8A, 0xF8CC1DAD_A779674E; 0x960AF038_F070E10A, 0x52C2D76C_61EB0BD4, 0xB33E892F_A5166F04, 0x13DE4E44_10B170FE),
+        point!(0x2AEE3420_9EB39EDE, 0x31088032_D70612D4, 0xBBCFF467_E1024360, 0x7D471A7D_C7422AF4; 0x0C9BCD94_ABF6607E, 0x7795CF7D_1C22A8FD, 0x07B82231_EFFC86FA, 0x004A7B19_F3A68565),
+        point!(0x4FE8B5BD_F4E5F03A, 0x96565FEB_1E1606D5, 0x737DCFB3_3D7DFE35, 0x80C34FDD_0289BE33; 0x117F4546_FE3CA504, 0xD30B2AEF_1F3245CB, 0x55EDB570_07DC9A03, 0x247E0856_A78C1ECD),
+        point!(0xA56D49E2_565EB1C1, 0x7FCD7567_CDAE5C5A, 0x7070FCC3_1982F096, 0xF0025163_F9B73FAC; 0x7DA7D704_6DDE2AE0, 0x8576FF5C_D6CD11E5, 0x4C66B4A5_DA26AFED, 0x054EA9E8_883D20DF),
+        point!(0xA610A061_CA14F422, 0xB383E4ED_9E4C4DEC, 0x4AFE8022_14DBC7BC, 0x6CED1AE8_97A9DF3D; 0xAE73DA43_D84FC091, 0x8CA102C8_79A9C932, 0xBB495A72_8BE6B9B8, 0x25888AEB_1B0F8E7A),
+        point!(0x4CF35093_DAC32C64, 0x0E36FE03_ECAE4BF8, 0x0F704E95_0F47715E, 0xAC121400_01ED23AA; 0x8812ADBA_DEB1A867, 0xED5A5D3A_5A53F089, 0x1870B695_730499A3, 0xCE8EBCCB_AC338BAF),
+    ],
+    [
+        point!(0x22F382DE_8319497C, 0x5D59B1FA_512508C0, 0x2D39E56E_6913CAB1, 0x174A53B9_C9A28587; 0x83DA13AC_079AFA73, 0x646B3A1D_8CB98543, 0x57B4155F_2C47F9E6, 0xCCC9DC37_ABFC9C16),
+        point!(0x5F5DA36F_840DD273, 0xD53D280E_0E450111, 0x30C7071E_F1B92EA3, 0x20E6E2E7_96946BB6; 0xC07CFD15_BB46B593, 0x811EC979_3DA8693C, 0x4A0BA1AD_97874655, 0xD3AD7AFE_4F1559E4),
+        point!(0xE948073D_754B8367, 0xB775B77D_67D506BF, 0x40CAA5D4_58436A5D, 0x5E5A094A_E446526E; 0x45329A9D_91CE85CA, 0x796AA9EF_3DE5FFA4, 0xC2C901F1_572A4B7A, 0xAE8AF8C9_A4795E05),
+        point!(0xE7B54F30_1A077674, 0x35AE6813_6CC24CA3, 0xA80280A0_7E71DB70, 0x8E0CA824_D7A351DB; 0x0B84CBEC_12B7ED98, 0xCFF60419_D2F91029, 0xDC569D24_DA62CF57, 0x04EC5607_59192D41),
+        point!(0xF1985793_97B10D9D, 0xE6A52F18_9D1F8FCA, 0x9DECCB83_310CB82D, 0x00D4E0AD_B9702E85; 0xE8210048_7140DCED, 0x0B5898C9_78E2D923, 0x47D2F846_432287F7, 0x87B80198_18376409),
+        point!(0x7B05E836_0CBCAD59, 0x7C845A05_A4E0AAA0, 0x7AA17C85_A5F902F1, 0x2ED76C11_52AC3600; 0x14AC7A89_C4EA66FE, 0x5F72C601_30A7F941, 0x2CDA6EAE_C81767ED, 0x639F4D40_43B85F22),
+        point!(0x20811A60_9C9CAEE8, 0x632E2045_B1A270BB, 0xF6F7A19A_EEC4A667, 0x03665213_68EF74C0; 0x0A9EC6A3_B772B711, 0x01CBA989_3295BA7F, 0x9949FC68_1BA69445, 0x1F18C32B_2A93DED9),
+        point!(0xE96AFC5E_A8192441, 0xAFDB5821_A321B4AF, 0xC5FA6355_3E3D66C1, 0xF7BB50DA_51C982D1; 0xBC640EA1_D45165AE, 0xB1CFDC1F_BBC4C74B, 0x311BC63B_DDE6485D, 0x93CC3BE3_0334A526),
+        point!(0x53FF9751_9446C85C, 0xDE37DE08_E601C063, 0xAA0676C4_53F6C6EA, 0x0E589105_6FDB8F1F; 0x5F6C77E7_2148972E, 0xF490234E_80CDDADD, 0x0EC7418B_AF481898, 0xE39A485F_11FA7BDA),
+        point!(0xE0349010_055AE087, 0xC671B96F_A3494A57, 0x2D3945FB_743A1510, 0xA3466715_5B6EA598; 0xCB3F3663_22887627, 0xC53EAB6F_2394F0BC, 0x8DEFE9A1_AA2A3051, 0x21CA5FA5_E56BC5BD),
+        point!(0xFF18ADF3_C22E1259, 0x931739A4_0681362A, 0x2B6CF5FA_A0077771, 0xA740126D_26F1463F; 0x5F771724_334A5F43, 0x17865E75_3B1D6DAD, 0x1B05F174_A67C7EE5, 0x05A9FFBA_F194F1FF),
+        point!(0x830F4571_E4B84B48, 0x91ADF4C7_99E817EA, 0x41910F57_7D417F70, 0x5FB1DF0E_7D45A4F5; 0x77EDEA6A_37BE8EB8, 0x0629C390_873F21AD, 0xFBCF4646_FE6D2582, 0x2152FBBC_BC3C4D49),
+        point!(0xD88A17A3_CB08F1FE, 0xD7CE7EE7_0BA087BF, 0xA6540576_5D82D177, 0x87E4B821_6D9474E4; 0x75F74AC9_AED5E2EC, 0x6E2F23DB_D6786D9D, 0xCD7D7F8E_A2F8C2CC, 0xF74E3AC0_766348CE),
+        point!(0x1146D987_F9B8F255, 0xE77D43E7_492A0D21, 0x1622E460_8AA82FE9, 0x95BBD974_78E1B8A4; 0x010E3FE8_28361439, 0xF2BA72AE_FC777FC1, 0xDEF2C208_F0F2D0D8, 0xBED43AD1_29E3C77F),
+        point!(0x9A8700E6_A1C4D15C, 0x105EA7BF_07C0E74B, 0xA92C22C0_C1254A44, 0x35EA1B46_3FEA79A5; 0xDC939BAF_7F3FE1EA, 0x5D633AA0_3065095E, 0x7888FA5F_D74D3B18, 0xAD219165_1B9ED2CD),
+    ],
+    [
+        point!(0x884FDFF0_9475B7BA, 0xE039E730_E4918B3D, 0x3D3E57ED_F5018CDB, 0x95939698_1943785C; 0xE9B8ABF8_7524F2FD, 0x9C653F64_C8709385, 0x8BA0386A_4B9CD684, 0x2E7E5528_88C331DD),
+        point!(0x07275A94_0BC8F53B, 0xD702226B_391747C7, 0xAFE32CA7_DD73D95E, 0xCBEE1405_FF0DA7DE; 0x2922E6B2_78C87F45, 0x0D9FF4F6_8126F728, 0xB51F3E68_9B8294CF, 0xF6211F4F_4E75F902),
+        point!(0xA09C5DD9_0FD69985, 0x9F309CCB_6DDF72AE, 0x788F690D_FBCCCF14, 0x0AE97675_CEB72F7E; 0x89C8EB41_1409A003, 0xD0B99D41_7AEE1AFF, 0xE9B8DFEE_051A54C5, 0x91219973_F6E48D14),
+        point!(0xF377C88B_14B311DD, 0x3DE3BEAE_FBD71B9C, 0xDD580BFA_0BA252E0, 0xADD5BAD2_8FAAF5AC; 0x02F982F3_49D6C38D, 0x52D4E1E7_669B9B89, 0x974E434F_8359814F, 0xE9C43CF4_DA3DC3A5),
+        point!(0x9DC193DF_D9262B90, 0xB723C4C1_FE3CC29A, 0xC9B65F17_78025D1F, 0x2B15862A_5AC1612E; 0x991996E6_483D7557, 0x6F534970_F99489A4, 0xA7A30D52_DA874906, 0x2EB0053D_AA0A33FA),
+        point!(0x4E75AE79_6078AFB0, 0x14FF12C6_C4126197, 0x248D4468_C66D1707, 0x209D6BCD_766163B5; 0x85D1B775_A740B310, 0x01EDB793_39F4E3E3, 0xA0965BAB_A9898A5B, 0x1A2F1342_9E7B3280),
+        point!(0x93F9714C_A8E7BE40, 0xF2D2C894_91040EE5, 0x7EE95C16_16E4769A, 0x6AF9EAED_1A96EE67; 0xFA416E02_6E387E1C, 0x45E3F666_A0F59569, 0x6709EA42_8347DC81, 0xB3812A11_69006649),
+        point!(0xF5F6400A_0D7C0979, 0x4A29B314_BC5A8C96, 0x3FA9DF3D_FF41CED2, 0x53F2432B_A8171714; 0xF9F7E90C_537B36A2, 0x4BD5A4F5_C9E8B845, 0xCCD4E3E0_911B07DE, 0xBD52EFFB_C1F079B7),
+        point!(0xD26B6FA0_F482801E, 0x40794F8B_C5BD4155, 0xEB3AAB42_4CB8D6F9, 0x596668EE_0444144E; 0xD477148F_04870C37, 0x8DB6C1CB_63D3535A, 0x1EC8F960_8771304E, 0x949AA0A8_5BBAEF5F),
+        point!(0xEF4040A5_88977063, 0x65BF6F8B_FB130C4F, 0x61D50F83_867E0FDB, 0xA762E69E_047F9A6F; 0xA06369F8_294F6EF1, 0x958B48A3_96DFCE62, 0x81A1732D_93372A89, 0x6B98DD57_CBED1157),
+        point!(0xE4C9B2E7_C32C19FD, 0x4ACAB8E9_058028C3, 0x2FDF4D17_AB570B5B, 0xBE84D188_1B505076; 0x67694A53_72F1281F, 0x7955A7F5_5E50DC37, 0x4D1E65F4_83083994, 0xEFF960CB_32DC5094),
+        point!(0xEBCB0298_DF55A9BF, 0xF6B015EF_29EA5BF8, 0x810307F6_01D7C578, 0x726298EB_9C6E3181; 0xE116F8B0_BC3ACCAE, 0xBE797BCB_C00673B4, 0xA9835A2E_F3D099E0, 0x38D48801_A9DE9BFD),
+        point!(0xBD1B75FD_56C69482, 0xCBEAB540_132FA167, 0x41F57274_CAF2BC88, 0x9867A031_4C0D7EE5; 0x16F06114_6F792CD7, 0x9E6245CF_515628A9, 0x9C792D55_B1BA9963, 0xD02E615A_3B10834F),
+        point!(0x9957F2D8_41A7E7D2, 0xA4C90E8C_3EFABD85, 0x693AF755_5946DE47, 0xB934130F_1339E48A; 0xA4A991A8_98653D39, 0xFFD381DB_E70DCC41, 0xAA0929A5_FBF78D1F, 0x95233F6B_9B2D0576),
+        point!(0x78A239D9_1D557AA1, 0xDF1D92FE_CD01FC29, 0x5927F2EA_0099D6AB, 0xA866F245_40D8815A; 0x7981BBB4_C1430634, 0x61EB69C6_7C611A4B, 0xD793D8FA_5DB0E139, 0xB58739F6_A8022B44),
+    ],
+    [
+        point!(0xBB51340C_9D82B151, 0xCCA0A43D_561FBA2D, 0xD645A115_3B109A8F, 0xD2A63A50_AE401E56; 0xD4142174_DCF89405, 0xA70F750A_F484CA52, 0x565AEE58_B2948220, 0xE82D86FB_6443FCB7),
+        point!(0x7C6C4BAC_CA72DA5F, 0x22B9CD6B_36C41349, 0x23BC7202_033725F9, 0xBAF183A7_6100525E; 0x377CE628_A8F2A0CF, 0x8E2336C5_CA739361, 0x5688BD58_DD69B1D1, 0xDEAC9FBE_9CCB4D33),
+        point!(0x7859F635_EE4B3BA0, 0x5C2ABEF5_F18BF1F1, 0xBEFE170F_31BF245F, 0x41081105_221FFB73; 0x486961DC_17525595, 0x13364985_65A06455, 0xF2CA65AE_1D6B8498, 0xDC37F369_76FF5668),
+        point!(0xFA5ACA58_C56C3943, 0x5ADBD02D_56B76A5F, 0x8F933290_6E48F6FD, 0xF7AEF8A7_E3844023; 0x431F627F_ACF442F1, 0xEEC30184_A8DCD003, 0x7C442BBD_C3AB3FCF, 0x4E3B0B44_D5FFDA79),
+        point!(0x4629C9B8_93D98DED, 0xDBDF2283_3B8A0218, 0xC8CF2299_0531D65E, 0x63A2A210_A16CC0C8; 0x519197D4_E0D1CFC2, 0x1FABF6A0_09C7873A, 0xD0650332_54ED9446, 0x882B42E2_E7FEC76F),
+        point!(0x2089E66A_000E5485, 0x4513068B_F5DA5DF0, 0x8E2C7086_27320F12, 0x1CD276D7_93A2BDFF; 0xCA5CF051_885FBD7F, 0x99D0E270_B209A4EC, 0x8D4B34D4_552E977F, 0x6615BDD1_8B2EAF73),
+        point!(0xEA861A50_B8045445, 0x80FF7371_901E8D7E, 0x3DF12E2E_5D57BEF0, 0x0C15815D_449D67CA; 0x214F87D5_4054A206, 0xBA3054E4_3658CEA3, 0xD0A93C3B_6663CD28, 0x2F30D60A_3AE94115),
+        point!(0x1FAC9457_11924459, 0x7AF2FA25_A3C7A78C, 0xC5A2E29F_0DDDBB1F, 0xDFB547CB_10019036; 0xFA205E0D_CC65FD9E, 0x22AF0930_E5C031DC, 0x8B8389CE_9DC864CC, 0x9ACCD2A9_BA0F4708),
+        point!(0x1E91239C_C3857FAF, 0x882FCADE_1E961970, 0x0D57BA8F_4828EF7F, 0xC757EB6B_89AE8A9C; 0x01D9DB0C_A0584CA4, 0x627D310D_101894C1, 0x257369F2_08BEC61B, 0xF03A59CF_CD23FF36),
+        point!(0x9A8BB351_8EF8B517, 0x77C2771A_47171BAC, 0x95DBC806_4A115E25, 0x712022F7_CCC4DB24; 0x48B7DF28_249DA1AC, 0xA3603EDF_3BD79D77, 0xAE7101F9_B0947541, 0xBD578338_DE4DE928),
+        point!(0x0E4D184F_5459225D, 0x44FAF77E_2D8858D1, 0xF815D409_919C02A2, 0xFA8063AE_ADAFA0D0; 0x3A2B7E3B_7ECE1507, 0x0A2B6C36_D007C59C, 0x160DE220_824C425C, 0x0BA71F90_57C0C069),
+        point!(0x3C753D59_A90BC03E, 0x84B0F393_51E015E3, 0x9E5A925F_74450F80, 0x2CEED8ED_2C82072E; 0x1CB72B8B_7AB82ADC, 0x4A1DF9CF_20D53CD0, 0xCD850EB7_AFC7DAEB, 0xA4276B80_E9B9DE3E),
+        point!(0x9679FE94_16F0D044, 0xAABA9FD4_909E61A7, 0x9E2F69CF_862EE0A5, 0x658CA3C5_9D0257A8; 0xADD1F32E_F7CB872D, 0x55A16763_C418CE89, 0x0F165D62_713BD376, 0xAE5CC137_7AEA2546),
+        point!(0x50CD4E31_42AA2494, 0x7BDCDDFE_7DD10C9A, 0xDD1136E7_C4EBBC82, 0x379C749A_4539E8EC; 0x5BEFA9E8_CEC86C63, 0x1364F6A7_B7E85643, 0x3C9C0EF1_D6783F11, 0xA2546384_8BF9C031),
+        point!(0xAB5AC957_3696756D, 0x676AF160_56561417, 0xCE4C1983_5D3F305C, 0x31C94B4A_AF91F2BA; 0xABC45E8D_DE08AE78, 0x83AEDFCA_E6AD1070, 0xBF4B8B59_AAF41606, 0xF80F884B_556E83A9),
+    ],
+    [
+        point!(0x3436F9B4_5617E073, 0x6BACBDBD_3839317B, 0x90EE7896_D7CFDC86, 0x64587E23_35471EB8; 0x58299E5E_9FAF6589, 0x85B90A39_133AEAB3, 0xAE96DD64_47C299A1, 0xD99FCDD5_BF6902E2),
+        point!(0x44BCD88C_4384480D, 0x94E0B6A2_2A91F2EF, 0x2CF28B54_C92F0C12, 0xB866D6B1_42DF940F; 0x0E659B47_0C4CAFA8, 0x24E52280_4B1D86D6, 0x89A278D7_EA9AD7AC, 0x1914B0B3_426AEB70),
+        point!(0xADF71472_0E103DD6, 0xC34604C0_7C004859, 0x36A213CF_C592A17A, 0xBC477BD5_5A4203F8; 0x639082D8_D6F7C343, 0x5D293572_C63B44AC, 0x6CBAC552_C6DEA639, 0xE31E1E24_29A8DD52),
+        point!(0xD229CDA8_1DB20D6C, 0xE2D52AE4_ED4FE455, 0xC4D9D164_6102BA87, 0xEC2BB890_85DE819E; 0xA0E99C4D_629CF4A0, 0x33A2364B_E87EFA98, 0x332F66F0_650940C6, 0xCCECC176_61E013A1),
+        point!(0x7D0DC3B0_D44EAB31, 0x0AC5AF14_04E63490, 0x0303B423_267BF8E8, 0x589DB4FE_5A6BB838; 0x941AEBE7_51361F6A, 0x1F610E55_2148F8DB, 0xF6070620_24BD90F3, 0x6255445C_108AA2A4),
+        point!(0x92D80B18_36695F94, 0x62A169C6_91627FA5, 0xD0CCB868_3AF9A9CF, 0xB1D25D51_B4558F5F; 0xA5B7EB9A_5EE32736, 0x60F6F18D_AB863EDC, 0xF7BEA0AC_19ABA817, 0x706DDA72_030E90B1),
+        point!(0x7BF96172_9A0C2C41, 0x42831C1C_560336C6, 0xEA8A1860_AD6EDB7C, 0x1339B337_D16E2FA2; 0x185F054B_A9F1BC2B, 0x6B1227F8_7DE923A4, 0xA7A3240F_D113A340, 0x9F9B2963_62C7AE5B),
+        point!(0x38A60ECB_23B09D0F, 0xE5005064_0F50BECF, 0xD39D75EF_5E545905, 0x71C4A7E3_89E296CE; 0x637B1F01_720DDB62, 0x786F2B08_4A62FFC7, 0x0AF3E0A2_92F810AA, 0x1313FADB_737AF3BA),
+        point!(0x3704B1F8_58FC47AF, 0xA8DDFEC8_32C0CDEC, 0xCC50329B_5001B568, 0x7FF3BBA1_1363CF17; 0x7F86164C_F9BA43A7, 0x17A53B1A_D18BAC29, 0xA0BC891B_7473446B, 0x1F6BA7AE_8018A629),
+        point!(0xD1DC4CBA_9E41E20E, 0x38A87A3D_78D33864, 0xBCB0F7F0_03A4D3E9, 0xFC395DC4_A5114DC8; 0x77AD8CC2_E7CF42EE, 0x0221475A_685E4E2B, 0x4CEB81A9_8BDDB6D6, 0x3866E091_08D07D09),
+        point!(0x0184D600_95213775, 0x00B4293B_23D42A99, 0x6141E939_5FEC7F61, 0x8EC67046_3CEB60BB; 0x0E010F3E_7841FFFF, 0x71DA98A0_BCCF51E9, 0x06E64341_5CEDEE0E, 0x8F58A66F_A0DA5BE3),
+        point!(0x49971018_B6F83231, 0xB7C30D8E_760425E3, 0xA8D15827_65B3FDD0, 0x850F8CC5_3502BA26; 0x4A031A9D_5F2E8373, 0x0A85BFA1_7722D77F, 0xD0BF7086_B82CC789, 0xBC4A9DBE_02A79053),
+        point!(0x3CB3FF8D_3452ABBB, 0xAE6AA72F_E2B95C2E, 0x89DBAC3F_ED95D495, 0x83DE61B4_41E701BB; 0x8A05176B_054EB66E, 0xD79C5F36_BBCE4291, 0xA6BFB6AF_8C4E1563, 0x32F0E334_A34C609F),
+        point!(0x88B11064_DF2E6FFA, 0x46BE9F37_B7155763, 0x1445BB6A_94750D46, 0x53FE8AF9_8F9419FB; 0x2A1EB372_64E92EB5, 0x5FB5F8E6_03BD70C2, 0x1640CF6C_4464DE5C, 0x9328817E_7B515462),
+        point!(0xE9688C26_F59276F1, 0x33AE25B2_92A17B15, 0x1A8BB5C0_0F7CD868, 0x3D766389_8F50C1DB; 0x01E4A65B_859CBCB3, 0xF3219820_19B19786, 0x60976E34_DAD8333E, 0xD3766973_7A187DDB),
+    ],
+    [
+        point!(0x358D6C86_DD45E458, 0x2F0AA6CE_A250E7FD, 0xB3A546D3_E549DE04, 0x8481BDE0_E4E4D885; 0x6D64B1B5_9779057E, 0x900A79C4_2B262E55, 0x84A25BF3_9CECB2CA, 0x38EE7B8C_BA5404DD),
+        point!(0xE5443352_6CE9F114, 0x392ED605_299561DD, 0x9FD43C6C_D1D492BF, 0x9629A450_BD383A8B; 0xCA3972C4_A24AA391, 0xB925593E_5C56AF8D, 0x576BEFD2_20CEF64D, 0xBF439B28_0C5FB6D7),
+        point!(0x160A0FA4_152DA17D, 0x11DC13DB_08D0646B, 0x0894E6B0_5FE00BC8, 0x3BEAED1E_0F518C5F; 0xCE704985_ECC768D2, 0x54CDE77B_8DFEC416, 0xFEF4A8BE_BE80E1B5, 0xC3B0D7F5_5AFF7ACD),
+        point!(0x83D38D96_26CA6CC3, 0x1477D747_E187E183, 0xEB1730DA_7CC893DF, 0xB73B1C47_EF1E4688; 0xFAC35D76_A54FDBA3, 0x7322A252_09757F5A, 0x0A57D64B_BCC80509, 0x584315CB_294922A9),
+        point!(0x234FA17D_BA4EDCC5, 0xE74E3221_420311B7, 0x8752DFC0_39275997, 0x6E73DBA0_CBDC9D61; 0x7B31F7CD_D59DA0E4, 0x278A77DA_70067903, 0xBEA45508_88828693, 0xEE0AC1FC_49EECC48),
+        point!(0xC1B9AD04_D063E1BE, 0xA92429A2_F7E39A75, 0x72261525_3DBCF027, 0x131641D1_1D602B14; 0xFDD1FF3E_21FEC890, 0xBD0FAEC0_F9745B77, 0x6A42E30F_CDBD2BEB, 0x4C3BDA61_796039F0),
+        point!(0x409C4C42_4A80B979, 0x0490F5FA_329ABB31, 0x7627D97E_AA47C310, 0x7706DD89_37E5B592; 0x14CF4810_4126CFDE, 0x17FA0A34_FD7EFE32, 0x7778DD79_91EA9C71, 0x8A02A982_7D5BB714),
+        point!(0x9C6E2192_D38F93E0, 0xD05B2A3B_B676899F, 0x11F98920_07A2FEF7, 0xEDFE16B2_DB401803; 0x3D01793D_E29405AD, 0x5B3D5100_C46E227E, 0x94D74FAA_4B05D0D2, 0xEE6902F1_FCA5DB36),
+        point!(0xDCDB9C4B_726FE285, 0xA45BAC29_413EF127, 0x0D2F3C7A_41F9E06A, 0x16D422C5_929BC0F8; 0x33E0FFE6_C155B441, 0xE93F0C11_37364D42, 0xA8AB7E69_A63531B1, 0x0DF888FA_51E22641),
+        point!(0x5D3132B8_69E306A5, 0xB8B5F624_C4C247F1, 0x4927129B_35F2E5C6, 0x972CB363_F9F30C33; 0xD061FD40_B6E86295, 0xB85702ED_CCAD4F35, 0x5F9E77FE_5BC65079, 0xAE634704_E2DF92AA),
+        point!(0xAE310BCA_BACFA513, 0x4E24079F_9A60E3AE, 0xAA5A3447_50F56A9C, 0x1FCC0E47_EEC76D63; 0x75075BA4_107AFC9C, 0x4BEDEDD1_64983918, 0xB48E3875_4339F9B4, 0x165DC1A0_41E35478),
+        point!(0xD12A0352_7C46E079, 0xAB66930B_85E9C78D, 0x0FEF75AD_B02BA374, 0xAB86C2AD_2ACFAC53; 0xEC428BC1_7636FFE1, 0xCE7728E8_4850D7C0, 0xCF5C46D4_D73200C9, 0x1209C286_019F29AB),
+        point!(0x21A2707C_FEDC69B2, 0xA50A6089_B26B7717, 0x73064304_35CAA386, 0xC12B9073_AD6CB8A1; 0x032AE9B0_20DD41DD, 0xB8EE1DBE_3D27B2A0, 0xD9772DC8_9F894976, 0x3173C43F_E534CDF1),
+        point!(0xD3EC7BCE_9E341142, 0x7AE684EB_76472760, 0x1BA1BD21_79A11668, 0xC6701C11_0C2DA3B0; 0x8924C615_9E0F4DAD, 0x5D07FCBA_5BC9BE57, 0x52F869BB_DE19F5A1, 0x2A020EB3_8D6CDD99),
+        point!(0xAB6F585D_F961FE4D, 0x8A14886F_8B107197, 0xA3F3E931_312CA73E, 0xD059BF85_DC327A8F; 0xB9935B06_6AE823C2, 0xFD388BA1_F4DAB64C, 0x3FE0765D_C84955B4, 0x45107A60_C08B525E),
+    ],
+    [
+        point!(0xBCD6303F_6CAF666B, 0x7FFCFED3_C4B1CE30, 0x62B6979A_E817F463, 0x13464A57_A78102AA; 0x3F495A90_7F6ECC27, 0x48F300A8_1D0942E1, 0xEF7E4334_53CCB0CA, 0x69BE1590_04614580),
+        point!(0xC75849C6_065084AE, 0x9182BE7D_CEABE577, 0xEC05C88C_85FE12D1, 0xEB3CF8F5_32245362; 0x6F67C1FF_F96B9480, 0xF52B45C5_E7DBD2A6, 0x43FE63DC_EFDCA4A1, 0xC833C782_22D9D700),
+        point!(0xF216B209_8ECA5F51, 0xDDEA171B_94FC9AEB, 0x2C6ED6B2_BF05B5CF, 0xDDE9D514_DD9EE696; 0x9AD69A73_D0C638F7, 0x50FEEBE8_DE89571F, 0xD891F34B_0A7F8F09, 0xB84E6913_3CE28111),
+        point!(0xF8E5BCBC_C2E9A5D0, 0xA576FCF9_84A201D9, 0x4F7A60F2_184519B2, 0xBDF1A67D_092D9997; 0xCDB4F701_8562FF7B, 0xA6280B61_E5626461, 0xA80BE54A_86BF7BAA, 0x4095902B_AB65A1AA),
+        point!(0x2F906B05_999C88E4, 0x9AED513E_20AD46EC, 0x6E9F406E_B1204B17, 0xFD1A6210_23699373; 0x99C8C916_595BC8DF, 0xDC6B71D4_95CC00F2, 0xFB13C069_54977782, 0x1AC97B54_B9C8C20B),
+        point!(0x1CCC6A55_A09B0CCA, 0xA3457589_24A4D6F0, 0xD5C454F6_8A28DA59, 0x0D538CB1_DBB0F4C8; 0x731B147E_0C929E05, 0x8534CD99_D89134C9, 0xCB0A8D5C_40EED7CF, 0xC8196BAC_7A3EC110),
+        point!(0xBD515B5B_5F8018CE, 0xCF2DA573_8D892D68, 0xF13FFCE4_F2C86DC7, 0x4EE48531_D8C296B9; 0x3C35A61B_1E48381F, 0x71074971_B4E80601, 0xFC7B4408_D0C7C5E6, 0xB68F9ED4_810BF8B5),
+        point!(0xBDE5FC17_3B27E771, 0x8C3B4196_477DA62A, 0xCD5BE267_B64483B4, 0x68856A6E_DDC4EC29; 0x6118D62A_07BBDAB6, 0x331D22F2_93B0733A, 0x13B6FD49_C19F7B4A, 0x77A33DF1_4F79A1FB),
+        point!(0x02852E91_F1473678, 0xCBA05795_094392F7, 0xFF5E314C_55B8C070, 0xFD76CC9C_34C400DB; 0x7E164EEA_8D144F4F, 0x60628EEE_1401C843, 0xE0FEE0A7_3D032CD6, 0xF2046543_787143DA),
+        point!(0xB5F00532_CEE66B24, 0xE8B9C60F_ED7B8366, 0x80E44D99_BF1ADED2, 0x4CD5D8CF_B9E9C7B8; 0x221EFDBA_F40932FD, 0xCA61E45E_12D2FAED, 0x9FE4E8C3_9CD6D103, 0x576EAE82_DEB94B55),
+        point!(0xDF00B714_8D7A2193, 0x2C691E44_F197546E, 0x19E6CE82_74B4EA21, 0x85A2ABA3_3123B402; 0x7F0C83D4_B0CDCF3A, 0xE1C6DADF_9C3B1242, 0xD82C6082_D1CC029F, 0x9C129857_EB2B0516),
+        point!(0x6AE303CE_EDD7E577, 0xD3A0F256_8536C4D5, 0x5AC60472_70EA2107, 0xC352D50F_8828F7B2; 0x3CA4FF88_F8D9B570, 0x00A8ABB2_B6121706, 0x6ABD5314_5BCE79FB, 0xBE772838_BA76B9B9),
+        point!(0x35B0305B_377568B0, 0x35AE8DD0_19E03B05, 0x440B6D6C_5783650F, 0xD25DDBFC_73CFFE74; 0x60A13926_DF39929C, 0x83227388_1E3C3F00, 0x8BEAEF74_CDE7D92B, 0x7A3AFF75_93F84893),
+        point!(0xA33563E8_1E712536, 0xA85051F5_6FF5FE71, 0xE086DE71_AB635E9E, 0x06914E86_B0A43A9F; 0x2A247713_801128F1, 0x896C226B_0AF7A9DA, 0x27D8ACEA_E5AC28C0, 0x2B7E7928_52D9BD05),
+        point!(0x55FE5118_A71D7C13, 0x641AB5F7_FA0FFA38, 0x55F07E9A_97AAC805, 0x45813531_43E94A72; 0x9638BC55_3AFF63CF, 0x70200655_42BD96B9, 0xB57F50F1_77DD69C9, 0x9F5858EC_14979AD8),
+    ],
+    [
+        point!(0xCADA3A0D_2D83F366, 0x0CD9CCED_E2F28588, 0x9AEF430B_CC1DC97A, 0xBC4A9DF5_B713FE2E; 0xD758D666_581F33C1, 0xA6E8A9FB_FA547B16, 0x383937AD_F4B798CA, 0x0D3A81CA_6E785C06),
+        point!(0x282DE545_F3FCEB19, 0x2E89B221_F785C409, 0xABC5C762_6CE7BAB4, 0xDA433D5E_11CECCC0; 0x684E7120_A6F5CC64, 0x8E77FC2D_9227B277, 0x1DEBBDC4_AF95E521, 0xE498DBD3_21A81030),
+        point!(0xB06A2E32_F712BE3C, 0x7295F18E_DA146A66, 0xD3C725DB_AB001534, 0x39D7349D_9331B378; 0xCC3019F4_1C6FF65C, 0xEF5EF7A5_ECA41644, 0xFB127554_C66812E8, 0x8F929B4F_56EF3BF7),
+        point!(0x54DE275C_5ACF692A, 0xBCC4838A_72207E06, 0x1C1C1169_81C16EFD, 0x031E8E1E_E9E8C7EC; 0x9CC2A84E_B16F667A, 0x70649827_C5BF73B0, 0xD9D09702_90D6743B, 0xAD7E7F5B_465B353D),
+        point!(0x5AAE4FBC_DF77F22B, 0xC6997239_94F82E03, 0x9E51CD6C_2995AF26, 0xF0CBA617_F7DC1DD0; 0x1FC8E2C7_5909A03C, 0x04F18C7E_90A09566, 0x9EF326C3_6516D040, 0x1A25AB43_13F9DF98),
+        point!(0xDEC0AA4A_77829372, 0xC49B300C_88BA4553, 0x313626AE_5385796B, 0xB4319CC9_0F3E0D3B; 0x7729CCC0_0ADD5427, 0x707B1215_CA3E11BB, 0xFFB7AFE0_9C21C5A2, 0x4707D449_9A6F502B),
+        point!(0x58ACDCD4_C6509C12, 0x8D6BFE23_5BD1F476, 0x21D170E8_D87268ED, 0x381D7AB9_DB2154D3; 0x54642A8E_2EB46102, 0x20582858_6CE6EAF7, 0xEB5D2457_3A6581E7, 0xA47AAB5B_7FDA3DA9),
+        point!(0x92BBD7FF_81F488B6, 0xE9C9BF36_3FC7A915, 0x5D3E00D8_62657F73, 0xA9878607_A88D6115; 0x495A7BBB_031DAB1D, 0x39D0F019_64AD5C6D, 0x1C063E7C_82157C22, 0xD181A1AB_D58895D6),
+        point!(0x8DB3B4D6_384FE955, 0xF69956A5_A063BCE4, 0x4793B73E_694C8A53, 0xA703F054_67AB87E6; 0xA26B2EFB_D61344BD, 0x539E1629_C597277F, 0xC0EA792D_6B19199B, 0xD500F935_A2B047AD),
+        point!(0xE64093B6_C38A8CC6, 0xBA2E121E_9F69F8FB, 0x48E279FF_6ED7F3DC, 0x331924C7_50B3417E; 0xC4E8E0FB_46587810, 0x7FB99E9A_CE9E9B54, 0x2BA5B601_BE2FFB31, 0xCA8F7941_1B33F018),
+        point!(0x3499656C_06EACE58, 0x1B7E4563_A2F3211D, 0x46C6AD0D_7053A03F, 0x73C6B3C5_AAD550D7; 0x7170A917_2AF8654E, 0xAA6A878D_BBB8D291, 0x1BA90991_366D91C6, 0x3A2FB4A4_733A4F74),
+        point!(0xF4BF1126_CDCBCB9E, 0xD6FA5689_DCD83C3D, 0x9ECC3244_7FD43761, 0xCDCB12D7_119D337A; 0xA08A585A_C961CFD1, 0xA199AF24_358DF340, 0xBFEFBEA0_96DE9949, 0xE699E2E1_7BB9F11F),
+        point!(0x54B388BF_00181D5E, 0xDE71B3A9_B95AAF20, 0xA1D00D3A_1C0EDDB8, 0xC627F3E7_506D47FD; 0x927900A6_369F886D, 0x8EF6B005_49CBE2C3, 0xB2D69601_38A1F7D6, 0xD78F9DD7_1E442A63),
+        point!(0x1D747CF7_325FE9CC, 0xFBB883BB_349D88DB, 0x37041108_42D4AC23, 0xC6ED5E63_28EC31B1; 0x0C0BCE1C_4A8FD94B, 0x844F972B_60F22CF1, 0x2932EE12_5C7F7B2A, 0xDE47405B_49D7F830),
+        point!(0x34E28D9F_B7828B16, 0xA047958F_28A4847F, 0xFA25F40A_6F06DE53, 0xBB88FABE_0EDFD233; 0xC51473A2_0AEA5DF7, 0xEC4A8174_E3A1E969, 0x345DC596_01159163, 0xB73676B3_04F5E8DD),
+    ],
+    [
+        point!(0x954AB30F_E5324CAA, 0x694B65E3_0A9472A3, 0xD23D8C74_9452A32E, 0x8C28A97B_F8298BC0; 0x9E71DC73_CBEF9482, 0x7AE784F0_451CB945, 0x378FEDF3_1F7CC0EB, 0x40A30463_A3305193),
+        point!(0x5DFB201F_7611D8E2, 0xC8130FE8_DE49FC4D, 0x96BED5A6_047F0DA2, 0xAB1AC187_2A38A2F1; 0x01A581F3_C429D15B, 0xF7EF93D3_3E1E545F, 0xE9AA5F39_DB6A42B6, 0x13F4A37A_324D17A1),
+        point!(0x863E8715_4754DD40, 0xA2422631_FC3466CF, 0x45B4841F_CD72F6E9, 0x97292470_32C0DFCF; 0x384B492F_2AA36143, 0x90DAE852_55ACAF49, 0xCD15C75D_CBD4DF36, 0x91D1A244_265FEA1D),
+        point!(0x226AEE64_2651B3FA, 0x8EA1B365_772DF434, 0x3703A607_253F31EF, 0x2564FE9B_5BEEF82D; 0xDB82E6A3_01E5122D, 0x14F37DAB_6B79816E, 0x95FA14AE_1203925F, 0x8AD9F7A6_06783890),
+        point!(0x3C2D82EB_8C2CA7FF, 0x1803645D_95DF021A, 0x050791AD_5A2F27AF, 0x89637F97_580A796E; 0xBED415E1_70493E68, 0xF87BC6A3_8E42EAB7, 0xD57B9CF1_54357489, 0x2D1FE124_8C888424),
+        point!(0x057C3533_0C7A89EE, 0x4BDBC59C_7AB6D4F8, 0x12860B88_FC98658A, 0x71EFA4E2_6A4179E1; 0x4F489329_C1366A2E, 0x2AAAFAB8_8E5E9A0B, 0x9EFB2C32_B17294A2, 0x145FA81F_8BB624AE),
+        point!(0x6186D63A_0CA8DD7F, 0x1BC72803_56A1381A, 0x2FDC9DA0_3D535742, 0x308138E7_1BE25E09; 0xE415F247_8A92C7F2, 0xC8165646_434AD915, 0x5E39EC45_D1408E18, 0x28D1E2D2_8828FC92),
+        point!(0xB178E3D3_AE180068, 0x7EA3D56C_20BD3103, 0xBFC6C5C0_C30DC01A, 0xFF3D6136_FFAC5B0C; 0x70A6BB6E_188C6077, 0x547676F2_4001F5E6, 0x40D0372C_DD96ADC1, 0x133239BE_84E4000E),
+        point!(0xC0DCC568_866A3FB1, 0x6EC00928_1992E206, 0xD1E5750C_85B6862F, 0x575FC4E8_2A6DEB65; 0x09B8E90A_902655AD, 0xED33B573_C6A3AF2F, 0xD671DBC2_978E87DA, 0x6F6EDB90_42A6FCA2),
+        point!(0x954E7FDC_BD21250A, 0xDF45E175_83998AD4, 0x531E342B_A43555E7, 0x3FA91548_0BAB8AD2; 0x91C86C02_FAA76731, 0x2E45E55A_032B5FB7, 0xCF5BDF96_03BB127C, 0xBF9B6690_5499B030),
+        point!(0x21B6A8A4_790117DF, 0xDA253BBB_CE201029, 0x227F26F8_1EEA2A8F, 0xA5EC9036_B64EAB7A; 0xD8128133_F86462FE, 0x1C8461F0_5614A363, 0x0C597983_AD1FA0F7, 0xB79DC662_5EC14040),
+        point!(0x5CB02349_8DE0545F, 0x80E625DA_2106C74F, 0xA375EA00_4B3508AC, 0x52045BCC_58E07124; 0x36EA34C0_506C3D91, 0x793B9D0F_2A8FDAF2, 0xD430ED9C_20CE312B, 0x1B3F31FC_EBE3123A),
+        point!(0x0B0F975C_172AD712, 0x5A398CB0_DE104729, 0x49DE7976_146349B9, 0xA153DFE9_13310B09; 0xC8A5F653_8D48FDD2, 0xBD053F28_5AF0B329, 0xC48318D5_F1F1B89A, 0xFD94D841_3FB05B2F),
+        point!(0x89F13D0C_3220479F, 0x5DE47B95_E5E5B38A, 0x80162C8D_23760363, 0x58B5436E_BE472FDB; 0x44A7375E_567DB17A, 0x96AF27C4_829BB341, 0x4E2913A2_52F6DE83, 0x85D7B6B0_55CD672C),
+        point!(0x030C3701_11CF5B3A, 0xC4433A83_BDB5A781, 0x935C34D0_88EDC824, 0x9A541AC6_AF794615; 0x63F5575F_36A44AE4, 0x5548FEE2_521EA52D, 0xCAFE1AF3_688F475F, 0xB66148C1_CB106AB7),
+    ],
+    [
+        point!(0x48C4BA11_1FACCAE0, 0x3C8B350C_5A4BB337, 0xC1DD94CE_4F071FD2, 0x08EA9666_139527A8; 0x55AF34A3_0E62B945, 0x35B783BE_9CF0F8E9, 0xE24E7C0C_FB95C5D7, 0x620EFABB_C8EE2782),
+        point!(0xA293131D_A190B632, 0x63CF2A23_A4AB5AB9, 0xF3A66DF3_15559D82, 0xC25F6371_76220CD9; 0x6EEBF3D6_FC9590CF, 0x0A9F04FF_9E027A1D, 0x98904990_3809D798, 0x53154FED_E94D2873),
+        point!(0x21A40B59_66A06F5E, 0xA3419251_6EA08370, 0xC37B0D42_1263B716, 0x383B24FB_EA14253A; 0x089F4786_D3C6E772, 0xE8F6AE74_BB8C2B04, 0x44CF566D_54EA5A19, 0x54CF706A_C4EDBA20),
+        point!(0xE2CCB3B7_D466D561, 0x0C7B55DC_31978B4D, 0x3E82D82A_5688544C, 0x2A9E8DFE_3CCE6BAB; 0xF96CCF52_52E76373, 0x5E01EAEC_17A02182, 0xBAC7B5AD_608B96CF, 0x01DFEDA5_C16E651F),
+        point!(0xC7975C1D_4638A136, 0x2B0D1CE3_36838195, 0x789E59C6_B60D790C, 0xE68432D0_3E02ED6D; 0xAAFD1810_8C6C2584, 0x2E09E3EB_DB357336, 0x24D2A730_3A01E647, 0xCA5BE413_98E35A66),
+        point!(0x2139B408_DBCF2DCE, 0x68114A10_5C1DC6C0, 0xBD49E4C9_92FF9980, 0x95E62D42_92E46218; 0x62BDDFDF_58C86594, 0x5E7B3060_3704D20E, 0x183A26E2_B428D52E, 0x06B68184_296C2875),
+        point!(0x7CE87C44_16E8C10C, 0xB472985F_391BD680, 0x0EEFBDEC_E7306E7F, 0x395DD559_E2FE5C2A; 0xBC3D6D93_05FE638E, 0x4C909C04_DC66922C, 0xD0413E87_AFFA4E27, 0xFD62DCD4_B4592AC5),
+        point!(0xE8BD3204_3F8BE384, 0x71EC0AAD_A31DB6C3, 0x251AD6C9_4FDEF072, 0xB23790A4_2BE63E1B; 0xD10A473D_EB19880E, 0x17F004F4_149ECB58, 0xE8D50F88_AA81F945, 0xFC6B6949_19D55EDB),
+        point!(0x51664DB2_0D6C14EF, 0x0858FC75_06F93C8F, 0x9F6EED5B_EEE26480, 0x7A514ADC_35525DCB; 0x7AD3E187_0B3FBD13, 0x29BEAEF7_2AA6E24F, 0xE897F0B9_4C8F5C37, 0x56EDD1FE_D152E4D8),
+        point!(0xFF9F053D_097EC94B, 0x27ED7091_C8D59C2D, 0x1EE215AA_8AE68181, 0xB2113BCB_7B4AEFED; 0xBD5FBFFC_687831AD, 0x0943220B_2357D0FD, 0x105A0B53_454CB4EB, 0x93BE9E73_99E8EE73),
+        point!(0x4A9463D0_4F6D65EB, 0x5A991077_54402152, 0x8EA8EF3B_F4908F23, 0x1EE1FD58_4325D90D; 0x1E036B92_5D22941C, 0xCA32F4E9_863582B1, 0x1CFAB9FC_4F42FCFA, 0xBB692891_BDD6C73C),
+        point!(0xB8052645_97A6257F, 0x1873C3B1_A27A6C40, 0xA6424274_AB07E789, 0x71E935C8_E1F54F25; 0x5B217461_D1B1E582, 0xEF252354_0CF70F7A, 0x3DED86BA_47AE2A52, 0x78D93E59_F47C2251),
+        point!(0x1F1950EF_0E36CB44, 0x617E8B6C_8DCA2019, 0xBC058FD0_D883357D, 0x15515634_D38FAAA8; 0x329AF465_1F495A68, 0xA8C37175_F020C1E5, 0xE370E3F0_B463205D, 0xAB4FFFC7_55575215),
+        point!(0xBF084724_79CA950E, 0x39E01576_2BB13BAC, 0xC52F8BE7_9EE2C461, 0xABC451CA_4EE795CA; 0x07CE7D8C_9A9490D2, 0x52684000_CEA553EC, 0xDB1DFB80_C2C91456, 0xAE2718BE_FE820A59),
+        point!(0x51F94610_7427BACC, 0x9E733164_DD2D62E1, 0x36358EB0_958DC4AA, 0x3BC6BC64_46BF5201; 0x14C33FD5_EAD6FDA6, 0xD71B75A5_228BEB47, 0xCDB62EE2_26D6C02B, 0x8E305CC0_7176C305),
+    ],
+];
@@ -0,0 +1,214 @@
+//! Range proofs of Pedersen-committed values using bit decomposition
+//!
+//! The prover commits to each bit `bᵢ` of the value as `Cᵢ = bᵢ·H + rᵢ·G` choosing the blinding
+//! factors so that `Σ 2ⁱ·rᵢ = r`. Then `Σ 2ⁱ·Cᵢ` equals the original commitment `C = v·H + r·G`
+//! which the verifier checks directly. For each bit the prover shows that either `Cᵢ` or
+//! `Cᵢ - H` is a multiple of `G` - a ring signature with the ring `(Cᵢ, Cᵢ - H)`. The message of
+//! each ring signature is `TaggedHash("toy-secp256k1/rangeproof")(C || n || i)` with `C`
+//! compressed and `n` and `i` as 4-byte big-endian numbers.
+//!
+//! The proof has size linear in the number of bits: each bit is serialized as `Cᵢ || c₀ || s₀ ||
+//! s₁`, 129 bytes. Use it for small ranges only.
+
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use crate::pedersen::Commitment;
+use crate::ring::{self, RingSignature};
+use crate::tagged_hash::TaggedHash;
+use crate::{multiexp, Error, NonZeroScalar, Point, PublicKey, Scalar, SecretKey, H, U256};
+
+const MESSAGE_TAG: &str = "toy-secp256k1/rangeproof";
+const BIT_PROOF_LEN: usize = 33 + 3 * 32;
+
+/// Proof that a commitment opens to a value in range `[0, 2ⁿ)`
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct RangeProof {
+    bits: Vec<BitProof>,
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+struct BitProof {
+    commitment: Commitment,
+    signature: RingSignature,
+}
+
+impl RangeProof {
+    /// The number of bits the proof covers
+    pub fn n_bits(&self) -> usize {
+        self.bits.len()
+    }
+
+    /// Parses the concatenated proofs of individual bits.
+    ///
+    /// Returns `Error::InvalidLength` if the length is not a non-zero multiple of 129,
+    /// `Error::InvalidSignature` if any component is invalid.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.is_empty() || !bytes.len().is_multiple_of(BIT_PROOF_LEN) {
+            return Err(Error::InvalidLength(bytes.len()));
+        }
+        let bits = bytes
+            .chunks(BIT_PROOF_LEN)
+            .map(|chunk| {
+                let commitment = Point::from_sec1_bytes(&chunk[..33]).map_err(|_| Error::InvalidSignature)?;
+                let signature = RingSignature::from_bytes(&chunk[33..])?;
+                Ok(BitProof { commitment: Commitment::from_point(commitment), signature })
+            })
+            .collect::<Result<_, Error>>()?;
+        Ok(RangeProof { bits })
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.bits.len() * BIT_PROOF_LEN);
+        for bit in &self.bits {
+            bytes.extend_from_slice(&bit.commitment.to_point().serialize_compressed());
+            bytes.extend_from_slice(&bit.signature.serialize());
+        }
+        bytes
+    }
+}
+
+fn check_n_bits(n_bits: u32) {
+    assert!(n_bits > 0 && n_bits <= 64, "the number of bits must be in range 1..=64");
+}
+
+fn message(commitment: &Commitment, n_bits: u32, index: u32) -> [u8; 32] {
+    TaggedHash::new(MESSAGE_TAG).hash(&[&commitment.to_point().serialize_compressed(), &n_bits.to_be_bytes(), &index.to_be_bytes()])
+}
+
+/// The keys of which one is a multiple of `G` if the commitment is to 0 or 1
+fn ring(bit_commitment: &Commitment) -> Option<[PublicKey; 2]> {
+    let point = bit_commitment.to_point();
+    Some([PublicKey::from_point(point)?, PublicKey::from_point(point + -H)?])
+}
+
+/// Proves that `Commitment::commit(value.into(), *blinding)` commits to a value less than
+/// `2^n_bits`.
+///
+/// The blinding factor must be non-zero, otherwise the commitment `value·H` would reveal the
+/// small value to anyone trying all of them. Returns `Error::ValueOutOfRange` if the value
+/// doesn't fit into `n_bits` and `Error::InvalidSecretKey` if the blinding factor of some bit is
+/// zero which happens with negligible probability.
+///
+/// # Panics
+///
+/// Panics if `n_bits` is zero or greater than 64.
+pub fn prove<R: RngCore + CryptoRng>(value: u64, blinding: NonZeroScalar, n_bits: u32, rng: &mut R) -> Result<RangeProof, Error> {
+    check_n_bits(n_bits);
+    if n_bits < 64 && value >> n_bits != 0 {
+        return Err(Error::ValueOutOfRange);
+    }
+    let blinding = blinding.to_scalar();
+    let commitment = Commitment::commit(Scalar::from(value), blinding);
+
+    let mut blindings = (1..n_bits).map(|_| Scalar::random(rng)).collect::<Vec<_>>();
+    let weighted_sum = blindings.iter().rev().fold(Scalar::ZERO, |sum, r| sum * Scalar::from(2) + *r);
+    let last_weight = Scalar::from(1 << (n_bits - 1));
+    blindings.push((blinding - weighted_sum) * last_weight.multiplicative_inverse());
+
+    let bits = blindings
+        .iter()
+        .enumerate()
+        .map(|(index, r)| {
+            let bit = (value >> index) & 1;
+            let bit_commitment = Commitment::commit(Scalar::from(bit), *r);
+            let secret_key = SecretKey::from_scalar(*r).ok_or(Error::InvalidSecretKey)?;
+            let ring = ring(&bit_commitment).expect("a commitment to 0 or 1 with non-zero blinding is never at infinity");
+            let msg = message(&commitment, n_bits, index as u32);
+            let signature = ring::sign(&msg, &ring, bit as usize, &secret_key, rng);
+            Ok(BitProof { commitment: bit_commitment, signature })
+        })
+        .collect::<Result<_, Error>>()?;
+    Ok(RangeProof { bits })
+}
+
+/// Verifies that `commitment` commits to a value less than `2^n_bits`
+///
+/// # Panics
+///
+/// Panics if `n_bits` is zero or greater than 64.
+pub fn verify(commitment: &Commitment, proof: &RangeProof, n_bits: u32) -> bool {
+    check_n_bits(n_bits);
+    if proof.bits.len() != n_bits as usize {
+        return false;
+    }
+    let weighted = proof
+        .bits
+        .iter()
+        .enumerate()
+        .map(|(index, bit)| (U256::one() << index, bit.commitment.to_point()))
+        .collect::<Vec<_>>();
+    if multiexp(&weighted) != commitment.to_point() {
+        return false;
+    }
+    proof.bits.iter().enumerate().all(|(index, bit)| match ring(&bit.commitment) {
+        Some(ring) => ring::verify(&message(commitment, n_bits, index as u32), &ring, &bit.signature),
+        None => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{prove, verify, RangeProof, BIT_PROOF_LEN};
+    use crate::pedersen::Commitment;
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, NonZeroScalar, Scalar};
+
+    // kept small because the arithmetic is slow
+    const N_BITS: u32 = 4;
+
+    #[test]
+    fn bounds() {
+        let mut rng = DeterministicRng::new(1);
+        for value in [0, (1 << N_BITS) - 1] {
+            let blinding = NonZeroScalar::random(&mut rng);
+            let commitment = Commitment::commit(Scalar::from(value), *blinding);
+            let proof = prove(value, blinding, N_BITS, &mut rng).unwrap();
+            assert!(verify(&commitment, &proof, N_BITS));
+            assert_eq!(RangeProof::from_bytes(&proof.serialize()), Ok(proof.clone()));
+            assert!(!verify(&commitment, &proof, N_BITS + 1));
+            let other = Commitment::commit(Scalar::from(value ^ 1), *blinding);
+            assert!(!verify(&other, &proof, N_BITS));
+        }
+        let blinding = NonZeroScalar::random(&mut rng);
+        assert_eq!(prove(1 << N_BITS, blinding, N_BITS, &mut rng), Err(Error::ValueOutOfRange));
+        // a single bit uses the blinding factor as is so it must not be zero
+        let proof = prove(1, blinding, 1, &mut rng).unwrap();
+        assert!(verify(&Commitment::commit(Scalar::ONE, *blinding), &proof, 1));
+    }
+
+    #[test]
+    fn out_of_range_commitment() {
+        // a proof for 2ⁿ - 1 doesn't verify against a commitment to 2ⁿ
+        let mut rng = DeterministicRng::new(2);
+        let blinding = NonZeroScalar::random(&mut rng);
+        let proof = prove((1 << N_BITS) - 1, blinding, N_BITS, &mut rng).unwrap();
+        let commitment = Commitment::commit(Scalar::from(1 << N_BITS), *blinding);
+        assert!(!verify(&commitment, &proof, N_BITS));
+    }
+
+    #[test]
+    fn tampering() {
+        let mut rng = DeterministicRng::new(3);
+        let blinding = NonZeroScalar::random(&mut rng);
+        let commitment = Commitment::commit(Scalar::from(5), *blinding);
+        let bytes = prove(5, blinding, N_BITS, &mut rng).unwrap().serialize();
+        assert_eq!(RangeProof::from_bytes(&bytes[1..]), Err(Error::InvalidLength(bytes.len() - 1)));
+        assert_eq!(RangeProof::from_bytes(&[]), Err(Error::InvalidLength(0)));
+
+        // commitment, c₀, s₀ and s₁ of the last bit
+        for offset in [0, 33, 65, 97] {
+            let mut corrupted = bytes.clone();
+            corrupted[BIT_PROOF_LEN * (N_BITS as usize - 1) + offset + 31] ^= 1;
+            match RangeProof::from_bytes(&corrupted) {
+                Ok(proof) => assert!(!verify(&commitment, &proof, N_BITS)),
+                Err(error) => assert_eq!(error, Error::InvalidSignature),
+            }
+        }
+
+        // reordering the bits
+        let mut swapped = bytes[BIT_PROOF_LEN..2 * BIT_PROOF_LEN].to_vec();
+        swapped.extend_from_slice(&bytes[..BIT_PROOF_LEN]);
+        swapped.extend_from_slice(&bytes[2 * BIT_PROOF_LEN..]);
+        assert!(!verify(&commitment, &RangeProof::from_bytes(&swapped).unwrap(), N_BITS));
+    }
+}
@@ -0,0 +1,171 @@
+//! Recoding of scalars into digits for windowed multiplication
+//!
+//! All recodings are little-endian - digit `i` has weight `2^i` for NAF and wNAF and `2^(i·w)`
+//! for fixed windows. The value is reconstructed as `Σ dᵢ·weightᵢ`.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use crate::U256;
+
+/// Maximum number of signed digits - recoding a 256-bit value may carry into bit 256
+pub const MAX_DIGITS: usize = 257;
+
+/// Writes the width-`w` non-adjacent form of `k` into `digits` returning the number of digits.
+///
+/// Every non-zero digit is odd, less than `2^(w-1)` in absolute value and followed by at least
+/// `w - 1` zeros. The highest returned digit is non-zero, zero is recoded as no digits. The
+/// remaining digits are set to zero.
+///
+/// # Panics
+///
+/// Panics if `w` is not in range `2..=8`.
+pub fn wnaf_into(k: U256, w: u32, digits: &mut [i8; MAX_DIGITS]) -> usize {
+    assert!((2..=8).contains(&w), "window width must be between 2 and 8");
+    let modulus = 1i16 << w;
+    let half = modulus / 2;
+    let mut k = k;
+    // bit 256 of the value being recoded, set when adding overflows
+    let mut carry = false;
+    let mut len = 0;
+    *digits = [0; MAX_DIGITS];
+    while !k.is_zero() || carry {
+        if !k.is_even() {
+            let mut digit = (k.low_u64() & (modulus as u64 - 1)) as i16;
+            if digit >= half {
+                digit -= modulus;
+            }
+            if digit > 0 {
+                k = k.wrapping_sub(U256::from(digit as u64));
+            } else {
+                let (sum, overflow) = k.overflowing_add(U256::from(-digit as u64));
+                k = sum;
+                carry |= overflow;
+            }
+            digits[len] = digit as i8;
+        }
+        len += 1;
+        k = k >> 1;
+        if carry {
+            k.0[3] |= 1 << 63;
+            carry = false;
+        }
+    }
+    // the loop ends right after shifting out the last non-zero digit
+    len
+}
+
+/// Computes the width-`w` non-adjacent form of `k`, see `wnaf_into` for details.
+///
+/// # Panics
+///
+/// Panics if `w` is not in range `2..=8`.
+#[cfg(feature = "alloc")]
+pub fn wnaf(k: U256, w: u32) -> Vec<i8> {
+    let mut digits = [0; MAX_DIGITS];
+    let len = wnaf_into(k, w, &mut digits);
+    digits[..len].to_vec()
+}
+
+/// Computes the non-adjacent form of `k` - digits are -1, 0 or 1 and no two adjacent digits are
+/// non-zero
+#[cfg(feature = "alloc")]
+pub fn naf(k: U256) -> Vec<i8> {
+    wnaf(k, 2)
+}
+
+/// Returns the `index`-th unsigned `w`-bit window of `k`
+///
+/// # Panics
+///
+/// Panics if `w` is not in range `1..=8`.
+pub fn fixed_window_digit(k: U256, w: u32, index: usize) -> u8 {
+    assert!((1..=8).contains(&w), "window width must be between 1 and 8");
+    let start = index * w as usize;
+    (start..(start + w as usize))
+        .rev()
+        .filter(|&bit| bit < 256)
+        .fold(0, |digit, bit| digit << 1 | u8::from(k.bit(bit)))
+}
+
+/// Splits `k` into unsigned `w`-bit windows, the highest returned window is non-zero.
+///
+/// # Panics
+///
+/// Panics if `w` is not in range `1..=8`.
+#[cfg(feature = "alloc")]
+pub fn fixed_window(k: U256, w: u32) -> Vec<u8> {
+    assert!((1..=8).contains(&w), "window width must be between 1 and 8");
+    let count = k.bits().div_ceil(w as usize);
+    (0..count).map(|index| fixed_window_digit(k, w, index)).collect()
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{fixed_window, fixed_window_digit, naf, wnaf, MAX_DIGITS};
+    use crate::test_utils::DeterministicRng;
+    use crate::params::N;
+    use crate::{Scalar, U256};
+
+    /// Computes `Σ dᵢ·2^(i·shift)` modulo 2²⁵⁶
+    fn reconstruct(digits: impl DoubleEndedIterator<Item = i16>, shift: usize) -> U256 {
+        digits.rev().fold(U256::zero(), |acc, digit| {
+            let acc = acc.wrapping_shl(shift);
+            if digit >= 0 {
+                acc.wrapping_add(U256::from(digit as u64))
+            } else {
+                acc.wrapping_sub(U256::from(-digit as u64))
+            }
+        })
+    }
+
+    fn check(k: U256) {
+        for w in 2..=8 {
+            let digits = wnaf(k, w);
+            assert!(digits.len() <= MAX_DIGITS);
+            assert_eq!(reconstruct(digits.iter().map(|&d| i16::from(d)), 1), k, "w = {}", w);
+            assert_ne!(digits.last(), Some(&0));
+            for (i, &digit) in digits.iter().enumerate().filter(|(_, &digit)| digit != 0) {
+                assert!(digit % 2 != 0);
+                assert!(i16::from(digit).abs() < 1 << (w - 1));
+                let following = &digits[(i + 1)..];
+                assert!(following.iter().take(w as usize - 1).all(|&d| d == 0));
+            }
+        }
+        assert_eq!(naf(k), wnaf(k, 2));
+        for w in 1..=8 {
+            let windows = fixed_window(k, w);
+            assert_eq!(reconstruct(windows.iter().map(|&d| i16::from(d)), w as usize), k, "w = {}", w);
+            assert_ne!(windows.last(), Some(&0));
+            assert!(windows.iter().all(|&d| u16::from(d) < 1 << w));
+            assert_eq!(fixed_window_digit(k, w, windows.len()), 0);
+        }
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert!(naf(U256::zero()).is_empty());
+        assert!(fixed_window(U256::zero(), 4).is_empty());
+        assert_eq!(naf(U256::one()), [1]);
+        assert_eq!(naf(U256::from(7)), [-1, 0, 0, 1]);
+        assert_eq!(wnaf(U256::from(7), 4), [7]);
+        // all-ones carries into bit 256
+        assert_eq!(naf(U256::max_value()).len(), MAX_DIGITS);
+        for k in &[U256::zero(), U256::one(), N - U256::one(), U256::max_value()] {
+            check(*k);
+        }
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = DeterministicRng::new(183);
+        for _ in 0..32 {
+            check(Scalar::random(&mut rng).to_u256());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn wide_window() {
+        wnaf(U256::one(), 9);
+    }
+}
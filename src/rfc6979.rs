@@ -0,0 +1,130 @@
+//! Deterministic nonce generation as specified by RFC 6979, using HMAC-SHA256
+
+use crate::hashes::{hmac_sha256, HmacSha256};
+use crate::zeroize::{Wipe, Zeroizing};
+use crate::{Scalar, SecretKey};
+
+/// The first HMAC of the initialization, `K = HMAC_K(V || 0x00 || key || ...)`, with the initial
+/// `K` and `V` constants and the key already processed.
+///
+/// It depends only on the key so it can be computed once and reused for signing many messages.
+pub(crate) struct KeyedDrbg {
+    key: Zeroizing<[u8; 32]>,
+    first_mac: HmacSha256,
+}
+
+impl KeyedDrbg {
+    pub(crate) fn new(key: &[u8; 32]) -> Self {
+        let mut first_mac = HmacSha256::new(&[0x00; 32]);
+        first_mac.update(&[0x01; 32]);
+        first_mac.update(&[0x00]);
+        first_mac.update(key);
+        KeyedDrbg {
+            key: Zeroizing::new(*key),
+            first_mac,
+        }
+    }
+
+    /// Initializes the state (steps b. - g.) for the message
+    pub(crate) fn drbg(&self, msg32: &[u8; 32], extra_data: Option<&[u8; 32]>) -> Drbg {
+        // bits2octets - the hash is reduced modulo the order
+        let msg = Scalar::from_be_bytes_reduce(msg32).to_be_bytes();
+        let extra_data: &[u8] = extra_data.map(|data| &data[..]).unwrap_or(&[]);
+
+        // updated in place so that no copies of the secret state are left around
+        let mut first_mac = self.first_mac.clone();
+        first_mac.update(&msg);
+        first_mac.update(extra_data);
+        let mut drbg = Drbg {
+            k: first_mac.finalize(),
+            v: [0x01; 32],
+            started: false,
+        };
+        drbg.v = hmac_sha256(&drbg.k, &[&drbg.v]);
+        drbg.k = hmac_sha256(&drbg.k, &[&drbg.v, &[0x01], &*self.key, &msg, extra_data]);
+        drbg.v = hmac_sha256(&drbg.k, &[&drbg.v]);
+        drbg
+    }
+}
+
+/// The HMAC_DRBG state from section 3.2 of the RFC
+pub(crate) struct Drbg {
+    k: [u8; 32],
+    v: [u8; 32],
+    started: bool,
+}
+
+impl Drbg {
+    /// Initializes the state (steps b. - g.)
+    ///
+    /// `extra_data` is the additional data `k'` from section 3.6, libsecp256k1 uses the same
+    /// construction.
+    pub(crate) fn new(msg32: &[u8; 32], secret_key: &SecretKey, extra_data: Option<&[u8; 32]>) -> Self {
+        Self::with_key_bytes(msg32, &Zeroizing::new(secret_key.to_bytes()), extra_data)
+    }
+
+    /// Same as `new` but accepts the key as big-endian bytes so that it can be used for secrets
+    /// which are not secret keys.
+    pub(crate) fn with_key_bytes(msg32: &[u8; 32], key: &[u8; 32], extra_data: Option<&[u8; 32]>) -> Self {
+        KeyedDrbg::new(key).drbg(msg32, extra_data)
+    }
+
+    /// Returns the next candidate that is in range `[1, n - 1]` (step h.)
+    ///
+    /// Calling this again continues with the state update from step h.3 so it can be used if the
+    /// nonce resulted in an invalid signature.
+    pub(crate) fn next_nonce(&mut self) -> Scalar {
+        loop {
+            if self.started {
+                self.k = hmac_sha256(&self.k, &[&self.v, &[0x00]]);
+                self.v = hmac_sha256(&self.k, &[&self.v]);
+            }
+            self.started = true;
+            self.v = hmac_sha256(&self.k, &[&self.v]);
+            match Scalar::from_be_bytes(&self.v) {
+                Some(nonce) if !nonce.is_zero() => break nonce,
+                _ => (),
+            }
+        }
+    }
+}
+
+impl Drop for Drbg {
+    fn drop(&mut self) {
+        self.k.wipe();
+        self.v.wipe();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::ManuallyDrop;
+    use super::Drbg;
+    use crate::SecretKey;
+    use crate::test_util::hex32;
+
+    /// The continuation used when a candidate is rejected, computed by an independent
+    /// implementation
+    #[test]
+    fn following_candidates() {
+        let secret_key = SecretKey::from_bytes(&hex32("0000000000000000000000000000000000000000000000000000000000000001")).unwrap();
+        // SHA256("Satoshi Nakamoto")
+        let msg = hex32("a0dc65ffca799873cbea0ac274015b9526505daaaed385155425f7337704883e");
+        let mut nonces = Drbg::new(&msg, &secret_key, None);
+        assert_eq!(nonces.next_nonce().to_be_bytes(), hex32("8f8a276c19f4149656b280621e358cce24f5f52542772691ee69063b74f15d15"));
+        assert_eq!(nonces.next_nonce().to_be_bytes(), hex32("f15fb763a6bcbbacbde0a6a9ae2a02482bd92f3e75a50b357bd551ddd771045e"));
+        assert_eq!(nonces.next_nonce().to_be_bytes(), hex32("872b0d837884b32fafbcc50e31a1d92ff5ec12c2db539d36b0a7e69c24ef9999"));
+    }
+
+    #[test]
+    fn state_wiped_on_drop() {
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let mut drbg = ManuallyDrop::new(Drbg::new(&[0x01; 32], &secret_key, None));
+        drbg.next_nonce();
+        // SAFETY: only the plain byte arrays are read after the destructor ran
+        unsafe {
+            ManuallyDrop::drop(&mut drbg);
+        }
+        assert_eq!((drbg.k, drbg.v), ([0; 32], [0; 32]));
+    }
+}
@@ -0,0 +1,264 @@
+//! Ring signatures (SAG) and linkable ring signatures (LSAG)
+//!
+//! The signature proves that one of the keys in the ring signed the message without revealing
+//! which one. The signer at position `π` picks random `α`, sets `c_{π+1} = H(Lπ)` with
+//! `Lπ = α·G` and then for every other position picks random `sᵢ` and computes
+//! `Lᵢ = sᵢ·G + cᵢ·Pᵢ`, `c_{i+1} = H(Lᵢ)` going around the ring. Finally it closes the ring by
+//! setting `sπ = α - cπ·x`. The signature is `(c₀, s₀, …, sₙ₋₁)`, the verifier recomputes the
+//! chain and checks that it ends where it started.
+//!
+//! The linkable variant additionally publishes the key image `I = x·Hp(P)` and each step also
+//! hashes `Rᵢ = sᵢ·Hp(Pᵢ) + cᵢ·I`. Two signatures by the same key have the same key image.
+//!
+//! The challenges are derived from a `Transcript` with domain `toy-secp256k1/ring/challenge`
+//! with `P₀, …, Pₙ₋₁`, `[I]`, `msg`, `Lᵢ`, `[Rᵢ]` appended under labels `P`, `I`, `msg`, `L`
+//! and `R`. `Hp(P) = TaggedHash("toy-secp256k1/ring/key_image")` hashed to curve from the
+//! compressed `P`.
+
+use alloc::{vec, vec::Vec};
+use rand_core::{CryptoRng, RngCore};
+use crate::tagged_hash::TaggedHash;
+use crate::transcript::Transcript;
+use crate::{array_ref_32, multiexp, Error, Point, PublicKey, Scalar, SecretKey, G};
+
+const CHALLENGE_TAG: &str = "toy-secp256k1/ring/challenge";
+const KEY_IMAGE_TAG: &str = "toy-secp256k1/ring/key_image";
+
+/// Ring signature, optionally linkable
+///
+/// Serialized as `c₀ || s₀ || … || sₙ₋₁ || [I]` where `I` is the compressed key image present
+/// only in linkable signatures.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct RingSignature {
+    c0: Scalar,
+    s: Vec<Scalar>,
+    key_image: Option<Point>,
+}
+
+impl RingSignature {
+    /// The key image of the signer if the signature is linkable.
+    ///
+    /// Signatures made by the same key have the same key image regardless of the ring.
+    pub fn key_image(&self) -> Option<Point> {
+        self.key_image
+    }
+
+    /// The number of keys in the ring
+    pub fn ring_size(&self) -> usize {
+        self.s.len()
+    }
+
+    /// Parses the signature, whether it's linkable is decided by the length.
+    ///
+    /// Returns `Error::InvalidLength` if the length is not `32·(n + 1)` or `32·(n + 1) + 33`
+    /// for `n > 0`, `Error::InvalidSignature` if any component is invalid.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let (scalars, key_image) = match bytes.len() % 32 {
+            0 if bytes.len() >= 64 => (bytes, None),
+            1 if bytes.len() >= 97 => {
+                let (scalars, key_image) = bytes.split_at(bytes.len() - 33);
+                (scalars, Some(Point::from_sec1_bytes(key_image).map_err(|_| Error::InvalidSignature)?))
+            },
+            _ => return Err(Error::InvalidLength(bytes.len())),
+        };
+        let mut scalars = scalars
+            .chunks(32)
+            .map(|chunk| Scalar::from_be_bytes(array_ref_32(chunk)).ok_or(Error::InvalidSignature))
+            .collect::<Result<Vec<_>, _>>()?;
+        let c0 = scalars.remove(0);
+        Ok(RingSignature { c0, s: scalars, key_image })
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32 * (self.s.len() + 1) + 33);
+        bytes.extend_from_slice(&self.c0.to_be_bytes());
+        for s in &self.s {
+            bytes.extend_from_slice(&s.to_be_bytes());
+        }
+        if let Some(key_image) = &self.key_image {
+            bytes.extend_from_slice(&key_image.serialize_compressed());
+        }
+        bytes
+    }
+}
+
+/// Hashes the public key to a point with unknown discrete logarithm
+fn hash_to_point(public_key: &PublicKey) -> Point {
+    TaggedHash::new(KEY_IMAGE_TAG).hash_to_curve(&[&public_key.serialize()])
+}
+
+/// The transcript with the ring, key image and message already appended
+fn challenge_transcript(msg: &[u8], ring: &[PublicKey], key_image: Option<&Point>) -> Transcript {
+    let mut transcript = Transcript::new(CHALLENGE_TAG);
+    for public_key in ring {
+        transcript.append_point("P", &public_key.to_point());
+    }
+    if let Some(key_image) = key_image {
+        transcript.append_point("I", key_image);
+    }
+    transcript.append_message("msg", msg);
+    transcript
+}
+
+fn challenge(transcript: &Transcript, l: &Point, r: Option<&Point>) -> Scalar {
+    let mut transcript = transcript.clone();
+    transcript.append_point("L", l);
+    if let Some(r) = r {
+        transcript.append_point("R", r);
+    }
+    transcript.challenge_scalar("c")
+}
+
+fn sign_internal<R: RngCore + CryptoRng>(msg: &[u8], ring: &[PublicKey], signer_index: usize, secret_key: &SecretKey, linkable: bool, rng: &mut R) -> RingSignature {
+    assert!(signer_index < ring.len(), "signer index out of range");
+    assert_eq!(ring[signer_index], secret_key.public_key(), "the secret key doesn't match the key at the signer index");
+
+    let x = secret_key.to_scalar();
+    let key_image = if linkable { Some(hash_to_point(&ring[signer_index]) * x) } else { None };
+    let transcript = challenge_transcript(msg, ring, key_image.as_ref());
+    let n = ring.len();
+
+    let alpha = Scalar::random(rng);
+    let r = key_image.map(|_| hash_to_point(&ring[signer_index]) * alpha);
+    let mut c = vec![Scalar::ZERO; n];
+    let mut s = vec![Scalar::ZERO; n];
+    c[(signer_index + 1) % n] = challenge(&transcript, &(G * alpha), r.as_ref());
+    for offset in 1..n {
+        let i = (signer_index + offset) % n;
+        s[i] = Scalar::random(rng);
+        let l = multiexp(&[(s[i].to_u256(), G), (c[i].to_u256(), ring[i].to_point())]);
+        let r = key_image.map(|key_image| multiexp(&[(s[i].to_u256(), hash_to_point(&ring[i])), (c[i].to_u256(), key_image)]));
+        c[(i + 1) % n] = challenge(&transcript, &l, r.as_ref());
+    }
+    s[signer_index] = alpha - c[signer_index] * x;
+
+    RingSignature { c0: c[0], s, key_image }
+}
+
+/// Signs the message as an anonymous member of the ring.
+///
+/// # Panics
+///
+/// If `signer_index` is out of range or the key at the index doesn't belong to `secret_key`.
+pub fn sign<R: RngCore + CryptoRng>(msg: &[u8], ring: &[PublicKey], signer_index: usize, secret_key: &SecretKey, rng: &mut R) -> RingSignature {
+    sign_internal(msg, ring, signer_index, secret_key, false, rng)
+}
+
+/// Signs the message like `sign` but includes the key image of the signer.
+///
+/// # Panics
+///
+/// If `signer_index` is out of range or the key at the index doesn't belong to `secret_key`.
+pub fn sign_linkable<R: RngCore + CryptoRng>(msg: &[u8], ring: &[PublicKey], signer_index: usize, secret_key: &SecretKey, rng: &mut R) -> RingSignature {
+    sign_internal(msg, ring, signer_index, secret_key, true, rng)
+}
+
+/// Verifies that the message was signed by a member of the ring.
+///
+/// Linkable signatures are verified including the key image.
+pub fn verify(msg: &[u8], ring: &[PublicKey], signature: &RingSignature) -> bool {
+    if ring.is_empty() || signature.s.len() != ring.len() {
+        return false;
+    }
+    let key_image = signature.key_image.as_ref();
+    let transcript = challenge_transcript(msg, ring, key_image);
+    let mut c = signature.c0;
+    for (public_key, s) in ring.iter().zip(&signature.s) {
+        let l = multiexp(&[(s.to_u256(), G), (c.to_u256(), public_key.to_point())]);
+        let r = key_image.map(|key_image| multiexp(&[(s.to_u256(), hash_to_point(public_key)), (c.to_u256(), *key_image)]));
+        c = challenge(&transcript, &l, r.as_ref());
+    }
+    c == signature.c0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sign, sign_linkable, verify, RingSignature};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, PublicKey, Scalar, SecretKey};
+
+    fn keys(n: usize, rng: &mut DeterministicRng) -> (Vec<SecretKey>, Vec<PublicKey>) {
+        let secret_keys = (0..n).map(|_| SecretKey::from_scalar(Scalar::random(rng)).unwrap()).collect::<Vec<_>>();
+        let public_keys = secret_keys.iter().map(SecretKey::public_key).collect();
+        (secret_keys, public_keys)
+    }
+
+    #[test]
+    fn round_trip_and_tampering() {
+        let mut rng = DeterministicRng::new(1);
+        for &n in &[2, 5, 16] {
+            let (secret_keys, ring) = keys(n, &mut rng);
+            let signer = n / 2;
+            for signature in &[sign(b"msg", &ring, signer, &secret_keys[signer], &mut rng), sign_linkable(b"msg", &ring, signer, &secret_keys[signer], &mut rng)] {
+                assert!(verify(b"msg", &ring, signature), "ring size {}", n);
+                assert_eq!(RingSignature::from_bytes(&signature.serialize()).as_ref(), Ok(signature));
+                let mut bytes = signature.serialize();
+                bytes[32 * (n - 1) + 63] ^= 1;
+                assert!(!verify(b"msg", &ring, &RingSignature::from_bytes(&bytes).unwrap()));
+                assert!(!verify(b"msg", &ring[1..], signature));
+                // kept small because the arithmetic is slow
+                if n == 5 {
+                    assert!(!verify(b"msg2", &ring, signature));
+                    let mut other_ring = ring.clone();
+                    other_ring.swap(0, n - 1);
+                    assert!(!verify(b"msg", &other_ring, signature));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn single_key_ring() {
+        let mut rng = DeterministicRng::new(2);
+        let (secret_keys, ring) = keys(1, &mut rng);
+        let signature = sign_linkable(b"msg", &ring, 0, &secret_keys[0], &mut rng);
+        assert!(verify(b"msg", &ring, &signature));
+        assert!(!verify(b"msg", &[], &signature));
+        let signature = sign(b"msg", &ring, 0, &secret_keys[0], &mut rng);
+        assert!(verify(b"msg", &ring, &signature));
+    }
+
+    #[test]
+    fn positions_indistinguishable() {
+        let mut rng = DeterministicRng::new(3);
+        let (secret_keys, ring) = keys(5, &mut rng);
+        let signatures = (0..5).map(|i| sign(b"msg", &ring, i, &secret_keys[i], &mut rng)).collect::<Vec<_>>();
+        for signature in &signatures {
+            assert!(verify(b"msg", &ring, signature));
+            assert_eq!(signature.serialize().len(), signatures[0].serialize().len());
+            assert_eq!(signature.ring_size(), 5);
+            assert_eq!(signature.key_image(), None);
+            // all responses are non-zero scalars, none of them stands out
+            assert!(signature.s.iter().all(|s| !s.is_zero()));
+        }
+    }
+
+    #[test]
+    fn key_image_links() {
+        let mut rng = DeterministicRng::new(4);
+        let (secret_keys, ring) = keys(3, &mut rng);
+        let first = sign_linkable(b"first", &ring, 1, &secret_keys[1], &mut rng);
+        let second = sign_linkable(b"second", &ring[..2], 1, &secret_keys[1], &mut rng);
+        let other = sign_linkable(b"first", &ring, 2, &secret_keys[2], &mut rng);
+        assert!(first.key_image().is_some());
+        assert_eq!(first.key_image(), second.key_image());
+        assert_ne!(first.key_image(), other.key_image());
+        assert!(verify(b"second", &ring[..2], &second));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(RingSignature::from_bytes(&[0; 32]), Err(Error::InvalidLength(32)));
+        assert_eq!(RingSignature::from_bytes(&[0; 70]), Err(Error::InvalidLength(70)));
+        assert_eq!(RingSignature::from_bytes(&[0xFF; 64]), Err(Error::InvalidSignature));
+        assert_eq!(RingSignature::from_bytes(&[0; 97]), Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    #[should_panic]
+    fn wrong_signer_key() {
+        let mut rng = DeterministicRng::new(5);
+        let (secret_keys, ring) = keys(2, &mut rng);
+        sign(b"msg", &ring, 0, &secret_keys[1], &mut rng);
+    }
+}
@@ -0,0 +1,466 @@
+//! Scalars - integers modulo the curve order
+
+use core::cmp::Ordering;
+use rand_core::{CryptoRng, RngCore};
+use core::ops::{Add, AddAssign, Sub, SubAssign, Neg, Mul, MulAssign, Div, DivAssign};
+use crate::{array_ref_32, Error, Zp, U256};
+use crate::params::N;
+#[cfg(feature = "hashes")]
+use crate::tagged_hash::TaggedHash;
+use crate::zeroize::Zeroizing;
+
+/// Implementation of `Z_n` cyclic group where `n` is the order of the secp256k1 curve.
+///
+/// This is the type used to multiply points.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Scalar(U256);
+
+impl Scalar {
+    pub const ZERO: Self = Scalar(U256([0, 0, 0, 0]));
+    pub const ONE: Self = Scalar(U256([1, 0, 0, 0]));
+
+    /// Converts the value % N to Self
+    pub const fn wrapping_from(value: U256) -> Self {
+        match value.const_cmp(&N) {
+            Ordering::Less => Scalar(value),
+            _ => Scalar(value.wrapping_sub(N)),
+        }
+    }
+
+    pub const fn checked_from(value: U256) -> Option<Self> {
+        match value.const_cmp(&N) {
+            Ordering::Less => Some(Scalar(value)),
+            _ => None,
+        }
+    }
+
+    /// Returns `Error::NonCanonicalScalar` if the value is not less than `N`
+    pub const fn try_from_u256(value: U256) -> Result<Self, Error> {
+        match Self::checked_from(value) {
+            Some(value) => Ok(value),
+            None => Err(Error::NonCanonicalScalar),
+        }
+    }
+
+    /// Parses big-endian bytes.
+    ///
+    /// Returns `None` if the value is not less than `N`
+    pub const fn from_be_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        Self::checked_from(U256::from_be_bytes(bytes))
+    }
+
+    /// Parses big-endian bytes reducing them modulo `N`
+    pub const fn from_be_bytes_reduce(bytes: &[u8; 32]) -> Self {
+        Self::wrapping_from(U256::from_be_bytes(bytes))
+    }
+
+    /// Parses 64 big-endian bytes reducing them modulo `N`
+    ///
+    /// Unlike `from_be_bytes_reduce` the result is statistically indistinguishable from uniform
+    /// if the bytes are uniformly random.
+    pub fn from_be_bytes_wide_reduce(bytes: &[u8; 64]) -> Self {
+        // hi·2²⁵⁶ + lo ≡ hi·(2²⁵⁶ - N) + lo (mod N)
+        let hi = Self::from_be_bytes_reduce(array_ref_32(&bytes[..32]));
+        let lo = Self::from_be_bytes_reduce(array_ref_32(&bytes[32..]));
+        hi * Scalar(U256::zero().overflowing_sub(N).0) + lo
+    }
+
+    /// Hashes the inputs to a scalar separating them by `domain`.
+    ///
+    /// The result is `hash(0x00) || hash(0x01)` reduced modulo the curve order where `hash` is
+    /// the tagged hash with `domain` as the tag of the inputs each prefixed with its length as
+    /// 8-byte big-endian number. The 512-bit value makes the result statistically uniform and
+    /// the framing makes different sequences of inputs hash differently. This function is
+    /// stable, its output will not change.
+    ///
+    /// The result is zero only if the 512-bit value is a multiple of `n`, which happens with
+    /// probability about 2⁻²⁵⁶ - it never happens in practice but callers that require a
+    /// non-zero scalar for security should still check it.
+    #[cfg(feature = "hashes")]
+    pub fn hash_to_scalar(domain: &str, inputs: &[&[u8]]) -> Self {
+        let mut hasher = TaggedHash::new(domain);
+        for input in inputs {
+            hasher.update_framed(input);
+        }
+        Self::from_framed_hash(&hasher)
+    }
+
+    /// Finishes `hash_to_scalar` with the framed inputs already hashed
+    #[cfg(feature = "hashes")]
+    pub(crate) fn from_framed_hash(hasher: &TaggedHash) -> Self {
+        let mut wide = [0; 64];
+        wide[..32].copy_from_slice(&hasher.hash(&[&[0]]));
+        wide[32..].copy_from_slice(&hasher.hash(&[&[1]]));
+        Self::from_be_bytes_wide_reduce(&wide)
+    }
+
+    /// Generates uniformly random non-zero scalar
+    pub fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut bytes = Zeroizing::new([0; 32]);
+        loop {
+            rng.fill_bytes(&mut *bytes);
+            match Self::from_be_bytes(&bytes) {
+                Some(scalar) if !scalar.is_zero() => break scalar,
+                _ => (),
+            }
+        }
+    }
+
+    /// Serializes the value as 32 big-endian bytes
+    pub const fn to_be_bytes(&self) -> [u8; 32] {
+        self.0.to_be_bytes()
+    }
+
+    pub const fn to_u256(self) -> U256 {
+        self.0
+    }
+
+    /// Converts the value to a field element, this is exact since `n < p`
+    pub const fn to_zp(&self) -> Zp {
+        Zp::from_reduced(self.0)
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn multiplicative_inverse(self) -> Self {
+        Scalar(self.0.mod_inverse(N))
+    }
+
+    /// Inverts all scalars in place using a single inversion (Montgomery's trick).
+    ///
+    /// The running products of the prefixes are inverted at once and the individual inverses are
+    /// peeled off from the end which costs three multiplications per scalar. The multiplications
+    /// use the reduction specialized for `n` rather than the double-and-add of the operator,
+    /// otherwise they would be slower than the inversions they replace.
+    ///
+    /// Returns `Error::ZeroScalar` if any of the scalars is zero, the slice is not modified in
+    /// that case. See `batch_invert_with_scratch` and `batch_invert_in_place` for versions that
+    /// don't allocate.
+    #[cfg(feature = "alloc")]
+    pub fn batch_invert(scalars: &mut [Scalar]) -> Result<(), Error> {
+        let mut scratch = alloc::vec![Scalar::ZERO; scalars.len()];
+        Self::batch_invert_with_scratch(scalars, &mut scratch)
+    }
+
+    /// Inverts all scalars in place like `batch_invert`, storing the intermediate products in
+    /// `scratch`.
+    ///
+    /// Returns `Error::InvalidLength` with the length of `scratch` if it's shorter than
+    /// `scalars`, `Error::ZeroScalar` if any of the scalars is zero. `scalars` is not modified in
+    /// either case, the contents of `scratch` are unspecified afterwards.
+    pub fn batch_invert_with_scratch(scalars: &mut [Scalar], scratch: &mut [Scalar]) -> Result<(), Error> {
+        if scratch.len() < scalars.len() {
+            return Err(Error::InvalidLength(scratch.len()));
+        }
+        if scalars.iter().any(Scalar::is_zero) {
+            return Err(Error::ZeroScalar);
+        }
+        // prefixes[i] = scalars[0]·…·scalars[i - 1]
+        let prefixes = &mut scratch[..scalars.len()];
+        let mut product = Scalar::ONE;
+        for (scalar, prefix) in scalars.iter().zip(prefixes.iter_mut()) {
+            *prefix = product;
+            product = mul_reduce(product, *scalar);
+        }
+        // the inverse of the product of the scalars not yet processed
+        let mut inverse = product.multiplicative_inverse();
+        for (scalar, prefix) in scalars.iter_mut().zip(prefixes.iter()).rev() {
+            let next = mul_reduce(inverse, *scalar);
+            *scalar = mul_reduce(inverse, *prefix);
+            inverse = next;
+        }
+        Ok(())
+    }
+
+    /// Inverts all scalars of the array in place like `batch_invert`, the intermediate products
+    /// are stored on the stack.
+    ///
+    /// Returns `Error::ZeroScalar` if any of the scalars is zero, the array is not modified in
+    /// that case.
+    pub fn batch_invert_in_place<const LEN: usize>(scalars: &mut [Scalar; LEN]) -> Result<(), Error> {
+        let mut scratch = [Scalar::ZERO; LEN];
+        Self::batch_invert_with_scratch(scalars, &mut scratch)
+    }
+}
+
+/// Multiplies the scalars using the special form `n = 2²⁵⁶ - c` where `c` has 129 bits
+fn mul_reduce(a: Scalar, b: Scalar) -> Scalar {
+    let c = U256::zero().wrapping_sub(N);
+    let (low, high) = a.0.widening_mul(b.0);
+    // high·2²⁵⁶ ≡ high·c, the result has at most 386 bits
+    let (low2, high2) = high.widening_mul(c);
+    let (sum, carry) = low.overflowing_add(low2);
+    // high2 + carry < 2¹³⁰ so the product has at most 259 bits
+    let (low3, high3) = high2.wrapping_add(U256::from_u64(carry as u64)).widening_mul(c);
+    let (sum, carry) = sum.overflowing_add(low3);
+    // high3 + carry < 2⁴ so this fits into 133 bits
+    let extra = high3.wrapping_add(U256::from_u64(carry as u64)).overflowing_mul(c).0;
+    let (sum, carry) = sum.overflowing_add(extra);
+    if carry {
+        // the sum wrapped so it's less than `extra` and adding c doesn't overflow
+        Scalar::wrapping_from(sum.wrapping_add(c))
+    } else {
+        Scalar::wrapping_from(sum)
+    }
+}
+
+impl From<u64> for Scalar {
+    fn from(value: u64) -> Self {
+        Scalar::wrapping_from(U256::from(value))
+    }
+}
+
+// Same as for Zp - simple subtraction instead of modulo
+impl Add for Scalar {
+    type Output = Self;
+
+    fn add(self, rhs: Scalar) -> Self::Output {
+        let (res, overflow) = self.0.overflowing_add(rhs.0);
+        Scalar(if overflow || res >= N {
+            res.wrapping_sub(N)
+        } else {
+            res
+        })
+    }
+}
+
+impl AddAssign for Scalar {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Scalar {
+    type Output = Self;
+
+    fn sub(self, rhs: Scalar) -> Self::Output {
+        let (res, overflow) = self.0.overflowing_sub(rhs.0);
+        Scalar(if overflow || res >= N {
+            res.wrapping_add(N)
+        } else {
+            res
+        })
+    }
+}
+
+impl SubAssign for Scalar {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for Scalar {
+    type Output = Scalar;
+
+    /// Double-and-add algorithm
+    fn mul(self, rhs: Scalar) -> Self::Output {
+        let mut res = Scalar::ZERO;
+
+        for bit in (0..256).rev() {
+            res += res;
+            if rhs.0.bit(bit) {
+                res += self;
+            }
+        }
+
+        res
+    }
+}
+
+impl MulAssign for Scalar {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for Scalar {
+    type Output = Scalar;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Scalar) -> Self::Output {
+        self * rhs.multiplicative_inverse()
+    }
+}
+
+impl DivAssign for Scalar {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Neg for Scalar {
+    type Output = Scalar;
+
+    fn neg(self) -> Self::Output {
+        if self.is_zero() {
+            self
+        } else {
+            Scalar(N - self.0)
+        }
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl subtle::ConditionallySelectable for Scalar {
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        Scalar(U256::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for Scalar {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Scalar, N};
+    use crate::{Error, U256};
+
+    #[test]
+    fn minus_one_wraps_around() {
+        assert_eq!(-Scalar::ONE + Scalar::ONE, Scalar::ZERO);
+        assert_eq!(Scalar::ZERO - Scalar::ONE, -Scalar::ONE);
+    }
+
+    #[test]
+    fn inverse() {
+        let x = Scalar::from(42);
+        assert_eq!(x * x.multiplicative_inverse(), Scalar::ONE);
+        assert_eq!(x / x, Scalar::ONE);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn batch_invert() {
+        let mut rng = crate::test_utils::DeterministicRng::new(217);
+        let scalars = (0..50).map(|_| Scalar::random(&mut rng)).chain(vec![Scalar::ONE, -Scalar::ONE]).collect::<Vec<_>>();
+        let mut inverses = scalars.clone();
+        Scalar::batch_invert(&mut inverses).unwrap();
+        for (scalar, inverse) in scalars.iter().zip(&inverses) {
+            assert_eq!(*inverse, scalar.multiplicative_inverse());
+        }
+        let mut single = [Scalar::from(42)];
+        Scalar::batch_invert(&mut single).unwrap();
+        assert_eq!(single, [Scalar::from(42).multiplicative_inverse()]);
+        Scalar::batch_invert(&mut []).unwrap();
+
+        // the specialized multiplication agrees with the operator, including the largest values
+        let minus_one = -Scalar::ONE;
+        for (a, b) in scalars.iter().zip(scalars.iter().rev()).chain(vec![(&minus_one, &minus_one)]) {
+            assert_eq!(super::mul_reduce(*a, *b), *a * *b);
+        }
+
+        for position in &[0, 25, 51] {
+            let mut with_zero = scalars.clone();
+            with_zero[*position] = Scalar::ZERO;
+            let original = with_zero.clone();
+            assert_eq!(Scalar::batch_invert(&mut with_zero), Err(Error::ZeroScalar), "zero at {}", position);
+            assert_eq!(with_zero, original);
+        }
+    }
+
+    #[test]
+    fn batch_invert_without_allocation() {
+        let mut rng = crate::test_utils::DeterministicRng::new(222);
+        let scalars = [Scalar::random(&mut rng), Scalar::ONE, Scalar::random(&mut rng), -Scalar::ONE];
+        let mut inverses = scalars;
+        Scalar::batch_invert_in_place(&mut inverses).unwrap();
+        for (scalar, inverse) in scalars.iter().zip(&inverses) {
+            assert_eq!(*inverse, scalar.multiplicative_inverse());
+        }
+        Scalar::batch_invert_in_place(&mut []).unwrap();
+
+        let mut with_scratch = scalars;
+        let mut scratch = [Scalar::ZERO; 5];
+        assert_eq!(Scalar::batch_invert_with_scratch(&mut with_scratch, &mut scratch[..3]), Err(Error::InvalidLength(3)));
+        assert_eq!(with_scratch, scalars);
+        Scalar::batch_invert_with_scratch(&mut with_scratch, &mut scratch).unwrap();
+        assert_eq!(with_scratch, inverses);
+
+        let mut with_zero = [Scalar::ONE, Scalar::ZERO];
+        assert_eq!(Scalar::batch_invert_in_place(&mut with_zero), Err(Error::ZeroScalar));
+        assert_eq!(with_zero, [Scalar::ONE, Scalar::ZERO]);
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let minus_one = -Scalar::ONE;
+        assert_eq!(Scalar::from_be_bytes(&minus_one.to_be_bytes()), Some(minus_one));
+        assert_eq!(Scalar::from_be_bytes(&[0xFF; 32]), None);
+    }
+
+    #[test]
+    fn canonical() {
+        assert_eq!(Scalar::try_from_u256(N - U256::one()), Ok(-Scalar::ONE));
+        assert_eq!(Scalar::try_from_u256(N), Err(Error::NonCanonicalScalar));
+        assert_eq!(Scalar::try_from_u256(U256::max_value()), Err(Error::NonCanonicalScalar));
+    }
+
+    #[test]
+    fn wide_reduction() {
+        let mut bytes = [0; 64];
+        bytes[32..].copy_from_slice(&[0xFF; 32]);
+        assert_eq!(Scalar::from_be_bytes_wide_reduce(&bytes), Scalar::from_be_bytes_reduce(&[0xFF; 32]));
+        // 2²⁵⁶
+        bytes = [0; 64];
+        bytes[31] = 1;
+        let two_32 = Scalar::from(1 << 32);
+        let expected = (0..8).fold(Scalar::ONE, |product, _| product * two_32);
+        assert_eq!(Scalar::from_be_bytes_wide_reduce(&bytes), expected);
+        assert_eq!(Scalar::from_be_bytes_wide_reduce(&[0xFF; 64]), expected * expected - Scalar::ONE);
+    }
+
+    /// The outputs must never change, computed by an independent implementation
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn hash_to_scalar_vectors() {
+        let vectors: [(&str, &[&[u8]], &str); 4] = [
+            ("toy-secp256k1/test", &[], "2ed9d93f9b41ac0c67ef1ac831d8d6ff645e4cab1f06825ca5a66cef149079b2"),
+            ("toy-secp256k1/test", &[b""], "82201850d7ce601e046908272d3df3835a020827cef98ec82b71648b4ca76857"),
+            ("toy-secp256k1/test", &[b"abc", b"def"], "d6eb2f70a4e4a77e625434c51f6851cb24219b49a15ccfa32034ee4873065087"),
+            ("other", &[b"abc", b"def"], "ce0b1bbe36ee36ec5bd403d294026b7c5d4fa7d0983b87cc8039900c091ade80"),
+        ];
+        for (domain, inputs, expected) in &vectors {
+            assert_eq!(Scalar::hash_to_scalar(domain, inputs).to_be_bytes(), crate::test_util::hex32(expected));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn hash_to_scalar_reference() {
+        use crate::hashes::sha256;
+
+        let tag = sha256(b"toy-secp256k1/test");
+        let mut preimage = [&tag[..], &tag[..]].concat();
+        preimage.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 3]);
+        preimage.extend_from_slice(b"abc");
+        preimage.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 3]);
+        preimage.extend_from_slice(b"def");
+        let hi = sha256(&[&preimage[..], &[0]].concat());
+        let lo = sha256(&[&preimage[..], &[1]].concat());
+        let two_128 = Scalar::from(1 << 32) * Scalar::from(1 << 32) * Scalar::from(1 << 32) * Scalar::from(1 << 32);
+        let hi = Scalar::from_be_bytes_reduce(&hi);
+        let lo = Scalar::from_be_bytes_reduce(&lo);
+        assert_eq!(Scalar::hash_to_scalar("toy-secp256k1/test", &[b"abc", b"def"]), hi * two_128 * two_128 + lo);
+    }
+
+    #[test]
+    #[cfg(feature = "hashes")]
+    fn hash_to_scalar_separation() {
+        let reference = Scalar::hash_to_scalar("domain", &[b"ab", b"c"]);
+        assert_ne!(Scalar::hash_to_scalar("other", &[b"ab", b"c"]), reference);
+        assert_ne!(Scalar::hash_to_scalar("domai", &[b"n", b"ab", b"c"]), reference);
+        assert_ne!(Scalar::hash_to_scalar("domain", &[b"a", b"bc"]), reference);
+        assert_ne!(Scalar::hash_to_scalar("domain", &[b"abc"]), reference);
+        assert_ne!(Scalar::hash_to_scalar("domain", &[b"ab", b"c", b""]), reference);
+        assert_ne!(Scalar::hash_to_scalar("domain", &[]), Scalar::hash_to_scalar("domain", &[b""]));
+        for i in 0..100u32 {
+            assert!(!Scalar::hash_to_scalar("domain", &[&i.to_be_bytes()]).is_zero());
+        }
+    }
+}
@@ -0,0 +1,366 @@
+//! BIP340 Schnorr signatures
+//!
+//! `sign_hedged` is the recommended way of signing, it supplies fresh `aux_rand` to `sign`.
+
+pub mod adaptor;
+pub mod blind;
+mod batch;
+#[cfg(feature = "alloc")]
+mod half_agg;
+mod s2c;
+
+#[cfg(feature = "alloc")]
+pub use batch::{find_invalid, verify_batch};
+pub use batch::verify_batch_with_scratch;
+#[cfg(feature = "alloc")]
+pub use half_agg::{half_aggregate, verify_half_aggregate};
+pub use s2c::{sign_to_contract, verify_commitment, S2cOpening};
+
+use rand_core::{CryptoRng, RngCore};
+use crate::tagged_hash::{TaggedHash, BIP340_AUX, BIP340_CHALLENGE, BIP340_NONCE};
+use crate::zeroize::Zeroizing;
+use crate::{array_ref_32, Error, Keypair, Message, Parity, Point, Scalar, SignOptions, XOnlyPoint, Zp, G};
+
+/// Computes the challenge `e = H(R.x || P.x || m) mod n`
+pub(crate) fn challenge(r: &[u8; 32], public_key: &XOnlyPoint, msg: &[u8]) -> Scalar {
+    challenge_tagged(BIP340_CHALLENGE, r, public_key, msg)
+}
+
+/// Computes the challenge using the tagged hash with `tag`
+fn challenge_tagged(tag: &str, r: &[u8; 32], public_key: &XOnlyPoint, msg: &[u8]) -> Scalar {
+    let hash = TaggedHash::new(tag).hash(&[r, &public_key.serialize(), msg]);
+    Scalar::from_be_bytes_reduce(&hash)
+}
+
+/// Returns the x-only public key and the secret key negated if needed to match it
+pub(crate) fn signing_key(keypair: &Keypair) -> (XOnlyPoint, Zeroizing<Scalar>) {
+    let (public_key, parity) = keypair.x_only_public_key();
+    let mut d = Zeroizing::new(keypair.secret_key().to_scalar());
+    if parity == Parity::Odd {
+        *d = -*d;
+    }
+    (public_key, d)
+}
+
+/// Returns the nonce hasher with the secret key masked by `aux_rand` and the public key already
+/// processed - everything except the message
+pub(crate) fn nonce_hasher(d: &Scalar, public_key: &XOnlyPoint, aux_rand: &[u8; 32]) -> TaggedHash {
+    nonce_hasher_tagged(BIP340_NONCE, d, public_key, aux_rand)
+}
+
+/// Returns the nonce hasher using the tagged hash with `tag`
+fn nonce_hasher_tagged(tag: &str, d: &Scalar, public_key: &XOnlyPoint, aux_rand: &[u8; 32]) -> TaggedHash {
+    let mask = TaggedHash::new(BIP340_AUX).hash(&[aux_rand]);
+    let mut t = Zeroizing::new(d.to_be_bytes());
+    for (t, mask) in t.iter_mut().zip(&mask) {
+        *t ^= mask;
+    }
+    let mut hasher = TaggedHash::new(tag);
+    hasher.update(&*t);
+    hasher.update(&public_key.serialize());
+    hasher
+}
+
+/// Derives the nonce from the concatenated `msg` using the hasher from `nonce_hasher`
+fn nonce_from(hasher: &TaggedHash, msg: &[&[u8]]) -> Scalar {
+    let k = Scalar::from_be_bytes_reduce(&hasher.hash(msg));
+    assert!(!k.is_zero(), "the probability of zero nonce is negligible");
+    k
+}
+
+/// Derives the BIP340 nonce from the secret key masked by `aux_rand` and the concatenated `msg`
+pub(crate) fn derive_nonce(d: &Scalar, public_key: &XOnlyPoint, msg: &[&[u8]], aux_rand: &[u8; 32]) -> Scalar {
+    nonce_from(&nonce_hasher(d, public_key, aux_rand), msg)
+}
+
+/// Signs the message as defined in BIP340.
+///
+/// BIP340 allows messages of any length, use `sign_arbitrary` for those. This function takes
+/// the usual 32-byte digest as `Message` so that its construction is explicit.
+///
+/// `aux_rand` should be fresh randomness, it protects against side-channel and fault attacks,
+/// `sign_hedged` takes care of that. Using zeros or a counter is still secure otherwise since
+/// the nonce is derived from the key and message.
+pub fn sign(msg: &Message, keypair: &Keypair, aux_rand: &[u8; 32]) -> [u8; 64] {
+    let (public_key, d) = signing_key(keypair);
+    sign_prepared(msg.as_bytes(), &public_key, &d, &nonce_hasher(&d, &public_key, aux_rand))
+}
+
+/// Signs the message of any length as defined in BIP340.
+///
+/// The message is not hashed or padded, a 32-byte message gives the same signature as `sign`
+/// with `Message::from_digest`. `aux_rand` has the same meaning as in `sign`.
+pub fn sign_arbitrary(msg: &[u8], keypair: &Keypair, aux_rand: &[u8; 32]) -> [u8; 64] {
+    let (public_key, d) = signing_key(keypair);
+    sign_prepared(msg, &public_key, &d, &nonce_hasher(&d, &public_key, aux_rand))
+}
+
+/// Signs the message as defined in BIP340 with `aux_rand` drawn from `rng`.
+///
+/// This is the recommended way of signing, the nonce mixes the randomness with the key and
+/// message so it stays secure even if the RNG is broken.
+pub fn sign_hedged<R: RngCore + CryptoRng>(msg: &Message, keypair: &Keypair, rng: &mut R) -> [u8; 64] {
+    let mut aux_rand = Zeroizing::new([0; 32]);
+    rng.fill_bytes(&mut *aux_rand);
+    sign(msg, keypair, &aux_rand)
+}
+
+/// Signs the message as defined in BIP340 honoring `options`.
+///
+/// Without any options enabled the signature is the same as the one returned by `sign`. Returns
+/// `Error::SelfVerificationFailed` if `verify_after_sign` is enabled and the signature doesn't
+/// verify against the cached public key of the keypair.
+pub fn sign_with_options(msg: &Message, keypair: &Keypair, aux_rand: &[u8; 32], options: &SignOptions) -> Result<[u8; 64], Error> {
+    sign_checked(msg, keypair, aux_rand, options, |_| ())
+}
+
+/// Implements `sign_with_options`, `fault` lets the tests corrupt the signature before it's
+/// checked
+fn sign_checked(msg: &Message, keypair: &Keypair, aux_rand: &[u8; 32], options: &SignOptions, fault: impl FnOnce(&mut [u8; 64])) -> Result<[u8; 64], Error> {
+    let mut signature = sign(msg, keypair, aux_rand);
+    fault(&mut signature);
+    if options.verify_after_sign && verify(msg, &signature, &keypair.x_only_public_key().0).is_err() {
+        return Err(Error::SelfVerificationFailed);
+    }
+    Ok(signature)
+}
+
+/// Signs using the values returned by `signing_key` and `nonce_hasher`
+pub(crate) fn sign_prepared(msg: &[u8], public_key: &XOnlyPoint, d: &Scalar, nonce_hasher: &TaggedHash) -> [u8; 64] {
+    sign_prepared_tagged(BIP340_CHALLENGE, msg, public_key, d, nonce_hasher)
+}
+
+/// Signs computing the challenge with `challenge_tag`
+fn sign_prepared_tagged(challenge_tag: &str, msg: &[u8], public_key: &XOnlyPoint, d: &Scalar, nonce_hasher: &TaggedHash) -> [u8; 64] {
+    let mut k = Zeroizing::new(nonce_from(nonce_hasher, &[msg]));
+    let (big_r, parity) = XOnlyPoint::from_point(Point::mul_gen(&k)).expect("k is not zero");
+    if parity == Parity::Odd {
+        *k = -*k;
+    }
+    let r = big_r.serialize();
+    let s = *k + challenge_tagged(challenge_tag, &r, public_key, msg) * *d;
+
+    let mut signature = [0; 64];
+    signature[..32].copy_from_slice(&r);
+    signature[32..].copy_from_slice(&s.to_be_bytes());
+    signature
+}
+
+/// Verifies BIP340 signature of the message.
+///
+/// Returns `Error::InvalidSignature` if `r` is not less than the field size, `s` is not less
+/// than the curve order or the signature doesn't match.
+pub fn verify(msg: &Message, signature: &[u8; 64], public_key: &XOnlyPoint) -> Result<(), Error> {
+    verify_tagged(BIP340_CHALLENGE, msg.as_bytes(), signature, public_key)
+}
+
+/// Verifies BIP340 signature of the message of any length, see `sign_arbitrary`.
+///
+/// Returns `Error::InvalidSignature` in the same cases as `verify`.
+pub fn verify_arbitrary(msg: &[u8], signature: &[u8; 64], public_key: &XOnlyPoint) -> Result<(), Error> {
+    verify_tagged(BIP340_CHALLENGE, msg, signature, public_key)
+}
+
+/// Signs the message of any length like `sign_arbitrary` but with caller-supplied tags of the challenge and nonce hashes.
+///
+/// Changing the tags changes the signature scheme: unless the tags are the BIP340 ones the
+/// signatures don't verify with `verify_arbitrary` nor with other tags, and BIP340 signatures don't
+/// verify with `verify_custom`. Use it to
+/// separate the signatures of an application-specific protocol from Bitcoin. The tags should
+/// be unique to the protocol, e.g. `"MyProtocol/challenge"` and `"MyProtocol/nonce"`, the nonce
+/// tag only affects the derivation of the nonce so it's not needed for verification.
+///
+/// Returns `Error::EmptyTag` if either of the tags is empty.
+pub fn sign_custom(msg: &[u8], keypair: &Keypair, aux_rand: &[u8; 32], challenge_tag: &str, nonce_tag: &str) -> Result<[u8; 64], Error> {
+    if challenge_tag.is_empty() || nonce_tag.is_empty() {
+        return Err(Error::EmptyTag);
+    }
+    let (public_key, d) = signing_key(keypair);
+    let nonce_hasher = nonce_hasher_tagged(nonce_tag, &d, &public_key, aux_rand);
+    Ok(sign_prepared_tagged(challenge_tag, msg, &public_key, &d, &nonce_hasher))
+}
+
+/// Verifies the signature produced by `sign_custom` with the same `challenge_tag`.
+///
+/// Returns `Error::EmptyTag` if the tag is empty and `Error::InvalidSignature` in the same
+/// cases as `verify`.
+pub fn verify_custom(msg: &[u8], signature: &[u8; 64], public_key: &XOnlyPoint, challenge_tag: &str) -> Result<(), Error> {
+    if challenge_tag.is_empty() {
+        return Err(Error::EmptyTag);
+    }
+    verify_tagged(challenge_tag, msg, signature, public_key)
+}
+
+/// Verifies the signature computing the challenge with `challenge_tag`
+fn verify_tagged(challenge_tag: &str, msg: &[u8], signature: &[u8; 64], public_key: &XOnlyPoint) -> Result<(), Error> {
+    let r = array_ref_32(&signature[..32]);
+    let r_x = Zp::from_be_bytes(r).ok_or(Error::InvalidSignature)?;
+    let s = Scalar::from_be_bytes(array_ref_32(&signature[32..])).ok_or(Error::InvalidSignature)?;
+    let e = challenge_tagged(challenge_tag, r, public_key, msg);
+    let big_r = G * s + public_key.to_point() * -e;
+    if big_r.is_at_infinity() || big_r.y().is_odd() || big_r.x() != r_x {
+        return Err(Error::InvalidSignature);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sign, sign_arbitrary, verify, verify_arbitrary};
+    use crate::test_util::{hex, hex32};
+    use crate::{Error, Keypair, Message, SecretKey, XOnlyPoint};
+
+    /// The official test vectors from the BIP340 repository
+    const VECTORS: &str = include_str!("schnorr/bip340_vectors.csv");
+
+    /// Columns of a row: index, secret key, public key, aux_rand, message, signature, result
+    fn vectors() -> impl Iterator<Item = Vec<&'static str>> {
+        VECTORS.lines().skip(1).map(|line| line.splitn(8, ',').collect())
+    }
+
+    #[test]
+    fn bip340_signing() {
+        let mut count = 0;
+        for row in vectors() {
+            if row[1].is_empty() {
+                continue;
+            }
+            let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&hex32(row[1])).unwrap());
+            assert_eq!(keypair.x_only_public_key().0.serialize(), hex32(row[2]), "vector {}", row[0]);
+            let signature = sign_arbitrary(&hex(row[4]), &keypair, &hex32(row[3]));
+            assert_eq!(signature[..], hex(row[5])[..], "vector {}", row[0]);
+            count += 1;
+        }
+        assert_eq!(count, 8);
+    }
+
+    #[test]
+    fn bip340_verification() {
+        let mut count = 0;
+        for row in vectors() {
+            let expected = match row[6] {
+                "TRUE" => Ok(()),
+                "FALSE" => Err(Error::InvalidSignature),
+                result => panic!("unknown result {}", result),
+            };
+            let mut signature = [0; 64];
+            signature.copy_from_slice(&hex(row[5]));
+            // invalid public keys are rejected already when parsing
+            let result = XOnlyPoint::from_bytes(&hex32(row[2]))
+                .map_err(|_| Error::InvalidSignature)
+                .and_then(|public_key| verify_arbitrary(&hex(row[4]), &signature, &public_key));
+            assert_eq!(result, expected, "vector {} {}", row[0], row[7]);
+            count += 1;
+        }
+        assert_eq!(count, 19);
+    }
+
+    #[test]
+    fn sign_verify() {
+        let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap());
+        let public_key = keypair.x_only_public_key().0;
+        let msg = Message::from_digest([0x07; 32]);
+        let signature = sign(&msg, &keypair, &[0; 32]);
+        assert_eq!(verify(&msg, &signature, &public_key), Ok(()));
+        // kept small because the arithmetic is slow
+        for bit in (0..512).step_by(97) {
+            let mut corrupted = signature;
+            corrupted[bit / 8] ^= 1 << (bit % 8);
+            assert_eq!(verify(&msg, &corrupted, &public_key), Err(Error::InvalidSignature), "bit {}", bit);
+        }
+        assert_eq!(verify(&Message::from_digest([0x08; 32]), &signature, &public_key), Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn variable_length_messages() {
+        let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap());
+        let public_key = keypair.x_only_public_key().0;
+        let msg = [0x07; 33];
+        for len in &[0, 1, 31, 33] {
+            let signature = sign_arbitrary(&msg[..*len], &keypair, &[0; 32]);
+            assert_eq!(verify_arbitrary(&msg[..*len], &signature, &public_key), Ok(()), "length {}", len);
+            // the message is not padded
+            let mut padded = msg[..*len].to_vec();
+            padded.push(0);
+            assert_eq!(verify_arbitrary(&padded, &signature, &public_key), Err(Error::InvalidSignature), "length {}", len);
+        }
+        // 32-byte messages are the digests signed by `sign`
+        let digest = Message::from_digest([0x07; 32]);
+        let signature = sign_arbitrary(digest.as_bytes(), &keypair, &[0; 32]);
+        assert_eq!(signature, sign(&digest, &keypair, &[0; 32]));
+        assert_eq!(verify(&digest, &signature, &public_key), Ok(()));
+    }
+
+    #[test]
+    fn verify_after_sign() {
+        use super::{sign_checked, sign_with_options};
+        use crate::SignOptions;
+
+        let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap());
+        let msg = Message::from_digest([0x07; 32]);
+        let options = SignOptions { verify_after_sign: true };
+        let expected = sign(&msg, &keypair, &[0; 32]);
+        assert_eq!(sign_with_options(&msg, &keypair, &[0; 32], &options), Ok(expected));
+        assert_eq!(sign_with_options(&msg, &keypair, &[0; 32], &SignOptions::default()), Ok(expected));
+
+        let fault = |signature: &mut [u8; 64]| signature[63] ^= 1;
+        assert_eq!(sign_checked(&msg, &keypair, &[0; 32], &options, fault), Err(Error::SelfVerificationFailed));
+        // the faulty signature is released without the check
+        let faulty = sign_checked(&msg, &keypair, &[0; 32], &SignOptions::default(), fault).unwrap();
+        assert_ne!(faulty, expected);
+    }
+
+    #[test]
+    fn hedged() {
+        use super::sign_hedged;
+        use crate::test_util::ZeroRng;
+        use crate::test_utils::DeterministicRng;
+
+        let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap());
+        let msg = Message::from_digest([0x07; 32]);
+        let (public_key, _) = keypair.x_only_public_key();
+        let deterministic = sign(&msg, &keypair, &[0; 32]);
+        assert_eq!(sign_hedged(&msg, &keypair, &mut ZeroRng), deterministic);
+
+        let first = sign_hedged(&msg, &keypair, &mut DeterministicRng::new(236));
+        assert_eq!(sign_hedged(&msg, &keypair, &mut DeterministicRng::new(236)), first);
+        let second = sign_hedged(&msg, &keypair, &mut DeterministicRng::new(237));
+        assert_ne!(first, deterministic);
+        assert_ne!(first, second);
+        for signature in &[first, second] {
+            assert_eq!(verify(&msg, signature, &public_key), Ok(()));
+        }
+    }
+
+    #[test]
+    fn custom_tags() {
+        use super::{sign_custom, verify_custom};
+
+        let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap());
+        let (public_key, _) = keypair.x_only_public_key();
+        let signature = sign_custom(b"message", &keypair, &[0; 32], "Test/challenge", "Test/nonce").unwrap();
+        assert_eq!(verify_custom(b"message", &signature, &public_key, "Test/challenge"), Ok(()));
+        assert_eq!(verify_custom(b"other", &signature, &public_key, "Test/challenge"), Err(Error::InvalidSignature));
+        assert_eq!(verify_arbitrary(b"message", &signature, &public_key), Err(Error::InvalidSignature));
+
+        let standard = sign_arbitrary(b"message", &keypair, &[0; 32]);
+        assert_eq!(verify_custom(b"message", &standard, &public_key, "Test/challenge"), Err(Error::InvalidSignature));
+        // the standard tags give the standard scheme
+        let bip340 = sign_custom(b"message", &keypair, &[0; 32], "BIP0340/challenge", "BIP0340/nonce").unwrap();
+        assert_eq!(bip340, standard);
+        assert_eq!(verify_custom(b"message", &standard, &public_key, "BIP0340/challenge"), Ok(()));
+
+        let other = sign_custom(b"message", &keypair, &[0; 32], "Other/challenge", "Other/nonce").unwrap();
+        assert_eq!(verify_custom(b"message", &other, &public_key, "Other/challenge"), Ok(()));
+        assert_eq!(verify_custom(b"message", &other, &public_key, "Test/challenge"), Err(Error::InvalidSignature));
+        assert_eq!(verify_custom(b"message", &signature, &public_key, "Other/challenge"), Err(Error::InvalidSignature));
+        // the nonce tag only changes the nonce
+        let other_nonce = sign_custom(b"message", &keypair, &[0; 32], "Test/challenge", "Other/nonce").unwrap();
+        assert_ne!(other_nonce, signature);
+        assert_eq!(verify_custom(b"message", &other_nonce, &public_key, "Test/challenge"), Ok(()));
+
+        assert_eq!(sign_custom(b"message", &keypair, &[0; 32], "", "Test/nonce"), Err(Error::EmptyTag));
+        assert_eq!(sign_custom(b"message", &keypair, &[0; 32], "Test/challenge", ""), Err(Error::EmptyTag));
+        assert_eq!(verify_custom(b"message", &signature, &public_key, ""), Err(Error::EmptyTag));
+    }
+}
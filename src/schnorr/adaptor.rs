@@ -0,0 +1,179 @@
+//! Adaptor signatures over BIP340 Schnorr signatures
+//!
+//! The presignature commits to the nonce `R = R̂ + T` where `R̂ = k·G` is known to the signer and
+//! `T = t·G` is the adaptor point. Since BIP340 requires `R` to have even y coordinate, the
+//! completed signature uses `k + t` if `R` is even and `-(k + t)` if it's odd. To keep that
+//! information the presignature stores the full point `R` rather than just its x coordinate and
+//! the presignature scalar is `ŝ = ±k + e·d` with the same sign. Completing the signature then
+//! means computing `s = ŝ + t` for even `R` and `s = ŝ - t` for odd `R`.
+
+use super::challenge;
+use crate::tagged_hash::TaggedHash;
+use crate::{array_ref_32, Error, Keypair, Parity, Point, Scalar, XOnlyPoint, G};
+
+const NONCE_TAG: &str = "SchnorrAdaptor/nonce";
+
+/// Incomplete BIP340 signature that becomes valid once the adaptor secret is added
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct AdaptorSignature {
+    big_r: Point,
+    s_hat: Scalar,
+}
+
+impl AdaptorSignature {
+    /// Parses 65 bytes: compressed `R` followed by `ŝ`
+    ///
+    /// Returns `Error::InvalidSignature` if any of the values is invalid.
+    pub fn from_bytes(bytes: &[u8; 65]) -> Result<Self, Error> {
+        let big_r = Point::from_sec1_bytes(&bytes[..33]).map_err(|_| Error::InvalidSignature)?;
+        let s_hat = Scalar::from_be_bytes(array_ref_32(&bytes[33..])).ok_or(Error::InvalidSignature)?;
+        Ok(AdaptorSignature { big_r, s_hat })
+    }
+
+    pub fn serialize(&self) -> [u8; 65] {
+        let mut bytes = [0; 65];
+        bytes[..33].copy_from_slice(&self.big_r.serialize_compressed());
+        bytes[33..].copy_from_slice(&self.s_hat.to_be_bytes());
+        bytes
+    }
+
+    /// Parity of `R` which decides whether the adaptor secret is added or subtracted
+    pub fn nonce_parity(&self) -> Parity {
+        XOnlyPoint::from_point(self.big_r).expect("R is not at infinity").1
+    }
+
+    fn r(&self) -> [u8; 32] {
+        self.big_r.x().to_be_bytes()
+    }
+}
+
+/// Creates a presignature of 32-byte message encrypted to `adaptor`.
+///
+/// The nonce is derived deterministically from the key, adaptor point and message.
+///
+/// # Panics
+///
+/// If `adaptor` is the point at infinity.
+pub fn presign(msg: &[u8; 32], keypair: &Keypair, adaptor: Point) -> AdaptorSignature {
+    assert!(!adaptor.is_at_infinity(), "adaptor point must not be at infinity");
+    let (public_key, parity) = keypair.x_only_public_key();
+    let d = keypair.secret_key().to_scalar();
+    let d = if parity == Parity::Odd { -d } else { d };
+
+    let rand = TaggedHash::new(NONCE_TAG).hash(&[&d.to_be_bytes(), &public_key.serialize(), &adaptor.serialize_compressed(), msg]);
+    let k = Scalar::from_be_bytes_reduce(&rand);
+    assert!(!k.is_zero(), "the probability of zero nonce is negligible");
+    let big_r = G * k + adaptor;
+    let (r, parity) = XOnlyPoint::from_point(big_r).expect("the probability of R̂ = -T is negligible");
+    let k = if parity == Parity::Odd { -k } else { k };
+    let s_hat = k + challenge(&r.serialize(), &public_key, msg) * d;
+    AdaptorSignature { big_r, s_hat }
+}
+
+/// Checks that the presignature becomes a valid signature once completed with the discrete
+/// logarithm of `adaptor`.
+pub fn preverify(msg: &[u8; 32], public_key: &XOnlyPoint, adaptor: Point, signature: &AdaptorSignature) -> bool {
+    let r_hat = signature.big_r + -adaptor;
+    let r_hat = match signature.nonce_parity() {
+        Parity::Even => r_hat,
+        Parity::Odd => -r_hat,
+    };
+    let e = challenge(&signature.r(), public_key, msg);
+    G * signature.s_hat == r_hat + public_key.to_point() * e
+}
+
+/// Completes the presignature using the discrete logarithm of the adaptor point.
+///
+/// The result is a BIP340 signature. The presignature should be verified first, otherwise the
+/// result may be invalid.
+pub fn adapt(signature: &AdaptorSignature, t: Scalar) -> [u8; 64] {
+    let s = match signature.nonce_parity() {
+        Parity::Even => signature.s_hat + t,
+        Parity::Odd => signature.s_hat - t,
+    };
+    let mut bytes = [0; 64];
+    bytes[..32].copy_from_slice(&signature.r());
+    bytes[32..].copy_from_slice(&s.to_be_bytes());
+    bytes
+}
+
+/// Recovers the discrete logarithm of the adaptor point from the completed signature.
+///
+/// The result is meaningless if `completed` is not a completion of `signature`, the caller
+/// should check that multiplying it by `G` gives the adaptor point.
+pub fn extract(signature: &AdaptorSignature, completed: &[u8; 64]) -> Scalar {
+    let s = Scalar::from_be_bytes_reduce(array_ref_32(&completed[32..]));
+    match signature.nonce_parity() {
+        Parity::Even => s - signature.s_hat,
+        Parity::Odd => signature.s_hat - s,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{adapt, extract, preverify, presign, AdaptorSignature};
+    use crate::schnorr::verify;
+    use crate::{Error, Keypair, Message, Parity, Scalar, SecretKey, G};
+
+    fn keypair() -> Keypair {
+        Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap())
+    }
+
+    #[test]
+    fn round_trip_both_parities() {
+        let keypair = keypair();
+        let public_key = keypair.x_only_public_key().0;
+        let t = Scalar::from(0x1234_5678_9abc_def0);
+        let adaptor = G * t;
+        let mut seen = Vec::new();
+        // about two messages are needed to see both parities
+        for i in 0u8.. {
+            let msg = [i; 32];
+            let signature = presign(&msg, &keypair, adaptor);
+            assert!(preverify(&msg, &public_key, adaptor, &signature));
+            assert_eq!(AdaptorSignature::from_bytes(&signature.serialize()), Ok(signature));
+
+            let completed = adapt(&signature, t);
+            assert_eq!(verify(&Message::from_digest(msg), &completed, &public_key), Ok(()));
+            assert_eq!(extract(&signature, &completed), t);
+
+            let parity = signature.nonce_parity();
+            if !seen.contains(&parity) {
+                seen.push(parity);
+            }
+            if seen.len() == 2 {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn odd_key_parity() {
+        // 6·G has odd y so the secret key is negated
+        let mut secret = [0; 32];
+        secret[31] = 6;
+        let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&secret).unwrap());
+        assert_eq!(keypair.x_only_public_key().1, Parity::Odd);
+        let public_key = keypair.x_only_public_key().0;
+        let t = Scalar::from(7);
+        let signature = presign(&[1; 32], &keypair, G * t);
+        assert!(preverify(&[1; 32], &public_key, G * t, &signature));
+        assert_eq!(verify(&Message::from_digest([1; 32]), &adapt(&signature, t), &public_key), Ok(()));
+    }
+
+    #[test]
+    fn wrong_adaptor() {
+        let keypair = keypair();
+        let public_key = keypair.x_only_public_key().0;
+        let t = Scalar::from(0xdead_beef);
+        let msg = [0x07; 32];
+        let signature = presign(&msg, &keypair, G * t);
+
+        assert!(!preverify(&msg, &public_key, G * (t + Scalar::ONE), &signature));
+        assert!(!preverify(&msg, &public_key, -(G * t), &signature));
+        assert!(!preverify(&[0x08; 32], &public_key, G * t, &signature));
+        assert_eq!(verify(&Message::from_digest(msg), &adapt(&signature, t + Scalar::ONE), &public_key), Err(Error::InvalidSignature));
+        // the presignature itself is not a valid signature
+        assert_eq!(verify(&Message::from_digest(msg), &adapt(&signature, Scalar::ZERO), &public_key), Err(Error::InvalidSignature));
+    }
+}
@@ -0,0 +1,173 @@
+//! Batch verification of BIP340 signatures using random linear combination
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use super::challenge;
+use crate::{array_ref_32, multiexp, Error, Point, Scalar, XOnlyPoint, Zp, G, U256};
+
+/// Verifies all signatures at once.
+///
+/// Checks `(Σ aᵢ·sᵢ)·G - Σ aᵢ·Rᵢ - Σ aᵢ·eᵢ·Pᵢ = 0` where `a₀ = 1` and the other coefficients are
+/// random. This is much faster than verifying the signatures separately.
+///
+/// Returns `Error::InvalidSignature` if any signature is invalid, use `find_invalid` to find out
+/// which one.
+#[cfg(feature = "alloc")]
+pub fn verify_batch<R: RngCore + CryptoRng>(items: &[(&[u8; 32], &[u8; 64], &XOnlyPoint)], rng: &mut R) -> Result<(), Error> {
+    let mut scratch = Vec::new();
+    scratch.resize(items.len() * 2 + 1, (U256::zero(), G));
+    verify_batch_with_scratch(items, rng, &mut scratch)
+}
+
+/// Verifies all signatures like `verify_batch` using `scratch` for the terms of the equation.
+///
+/// Each signature needs two entries of `scratch` and one more is needed for the whole batch. If
+/// `scratch` is shorter than `2 * items.len() + 1` the signatures are verified in as many smaller
+/// batches as needed, each with its own `a₀ = 1`. Nothing is allocated.
+///
+/// Returns `Error::InvalidLength` with the length of `scratch` if it can't hold a single
+/// signature (and `items` is not empty), `Error::InvalidSignature` if any signature is invalid.
+pub fn verify_batch_with_scratch<R: RngCore + CryptoRng>(items: &[(&[u8; 32], &[u8; 64], &XOnlyPoint)], rng: &mut R, scratch: &mut [(U256, Point)]) -> Result<(), Error> {
+    let chunk_len = scratch.len().saturating_sub(1) / 2;
+    if chunk_len == 0 && !items.is_empty() {
+        return Err(Error::InvalidLength(scratch.len()));
+    }
+    for chunk in items.chunks(chunk_len.max(1)) {
+        let mut g_scalar = Scalar::ZERO;
+        for (i, (msg, signature, public_key)) in chunk.iter().enumerate() {
+            let r = array_ref_32(&signature[..32]);
+            let big_r = Zp::from_be_bytes(r)
+                .and_then(Point::lift_x)
+                .ok_or(Error::InvalidSignature)?;
+            let s = Scalar::from_be_bytes(array_ref_32(&signature[32..])).ok_or(Error::InvalidSignature)?;
+            let e = challenge(r, public_key, *msg);
+            let a = if i == 0 { Scalar::ONE } else { Scalar::random(rng) };
+            g_scalar += a * s;
+            scratch[2 * i] = ((-a).to_u256(), big_r);
+            scratch[2 * i + 1] = ((-(a * e)).to_u256(), public_key.to_point());
+        }
+        scratch[2 * chunk.len()] = (g_scalar.to_u256(), G);
+
+        if multiexp(&scratch[..2 * chunk.len() + 1]) != Point::AT_INFINITY {
+            return Err(Error::InvalidSignature);
+        }
+    }
+    Ok(())
+}
+
+/// Finds the index of an invalid signature by bisecting the batch.
+///
+/// Returns `None` if all signatures are valid.
+#[cfg(feature = "alloc")]
+pub fn find_invalid<R: RngCore + CryptoRng>(items: &[(&[u8; 32], &[u8; 64], &XOnlyPoint)], rng: &mut R) -> Option<usize> {
+    if verify_batch(items, rng).is_ok() {
+        return None;
+    }
+    let mut offset = 0;
+    let mut items = items;
+    // invariant: `items` contains an invalid signature
+    while items.len() > 1 {
+        let (left, right) = items.split_at(items.len() / 2);
+        if verify_batch(left, rng).is_err() {
+            items = left;
+        } else {
+            offset += left.len();
+            items = right;
+        }
+    }
+    Some(offset)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{find_invalid, verify_batch, verify_batch_with_scratch};
+    use crate::schnorr::sign;
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, Keypair, Message, Scalar, SecretKey, XOnlyPoint, G, U256};
+
+    fn batch(rng: &mut DeterministicRng, len: usize) -> Vec<([u8; 32], [u8; 64], XOnlyPoint)> {
+        // keys are reused because computing them is slow
+        let keypairs = (0..len.min(4))
+            .map(|_| Keypair::from_secret_key(&SecretKey::from_scalar(Scalar::random(rng)).unwrap()))
+            .collect::<Vec<_>>();
+        (0..len)
+            .map(|i| {
+                let keypair = &keypairs[i % keypairs.len()];
+                let msg = Scalar::random(rng).to_be_bytes();
+                let signature = sign(&Message::from_digest(msg), keypair, &Scalar::random(rng).to_be_bytes());
+                (msg, signature, keypair.x_only_public_key().0)
+            })
+            .collect()
+    }
+
+    fn refs(items: &[([u8; 32], [u8; 64], XOnlyPoint)]) -> Vec<(&[u8; 32], &[u8; 64], &XOnlyPoint)> {
+        items.iter().map(|(msg, signature, public_key)| (msg, signature, public_key)).collect()
+    }
+
+    #[test]
+    fn valid_batches() {
+        let mut rng = DeterministicRng::new(42);
+        for &len in &[0, 1, 2, 100] {
+            let items = batch(&mut rng, len);
+            assert_eq!(verify_batch(&refs(&items), &mut rng), Ok(()), "len {}", len);
+        }
+    }
+
+    #[test]
+    fn flipped_bits() {
+        let mut rng = DeterministicRng::new(42);
+        let items = batch(&mut rng, 3);
+        // kept small because the arithmetic is slow
+        for i in 0..items.len() {
+            for bit in (0..(32 + 64) * 8).step_by(101) {
+                let mut corrupted = items.clone();
+                if bit < 256 {
+                    corrupted[i].0[bit / 8] ^= 1 << (bit % 8);
+                } else {
+                    corrupted[i].1[bit / 8 - 32] ^= 1 << (bit % 8);
+                }
+                assert_eq!(verify_batch(&refs(&corrupted), &mut rng), Err(Error::InvalidSignature), "item {} bit {}", i, bit);
+            }
+        }
+    }
+
+    #[test]
+    fn bisection() {
+        let mut rng = DeterministicRng::new(42);
+        let items = batch(&mut rng, 5);
+        assert_eq!(find_invalid(&refs(&items), &mut rng), None);
+        for i in 0..items.len() {
+            let mut corrupted = items.clone();
+            corrupted[i].1[63] ^= 1;
+            assert_eq!(find_invalid(&refs(&corrupted), &mut rng), Some(i));
+        }
+    }
+
+    #[test]
+    fn bounded_scratch() {
+        let mut rng = DeterministicRng::new(222);
+        let items = batch(&mut rng, 5);
+        let mut scratch = [(U256::zero(), G); 5];
+        for &len in &[3, 4, 5] {
+            assert_eq!(verify_batch_with_scratch(&refs(&items), &mut rng, &mut scratch[..len]), Ok(()), "scratch length {}", len);
+        }
+        assert_eq!(verify_batch_with_scratch(&refs(&items), &mut rng, &mut scratch[..2]), Err(Error::InvalidLength(2)));
+        assert_eq!(verify_batch_with_scratch(&[], &mut rng, &mut []), Ok(()));
+        for i in 0..items.len() {
+            let mut corrupted = items.clone();
+            corrupted[i].1[63] ^= 1;
+            assert_eq!(verify_batch_with_scratch(&refs(&corrupted), &mut rng, &mut scratch), Err(Error::InvalidSignature), "item {}", i);
+        }
+    }
+
+    /// The first coefficient is fixed so a single signature is checked exactly
+    #[test]
+    fn single_matches_verify() {
+        let mut rng = DeterministicRng::new(42);
+        let mut items = batch(&mut rng, 1);
+        items[0].1[0] ^= 1;
+        let (msg, signature, public_key) = &items[0];
+        assert_eq!(verify_batch(&refs(&items), &mut rng), crate::schnorr::verify(&Message::from_digest(*msg), signature, public_key));
+    }
+}
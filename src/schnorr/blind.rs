@@ -0,0 +1,163 @@
+//! Blind Schnorr signatures
+//!
+//! The signer signs a message without learning it, and can't later link the signature with the
+//! session that produced it. The protocol has three moves:
+//!
+//! 1. The signer picks `k` and sends `R = k·G`.
+//! 2. The user picks blinding factors `α`, `β`, computes `R' = R + α·G + β·P`, the BIP340
+//!    challenge `c' = H(R'.x || P.x || m)` and sends `c = c' + β`.
+//! 3. The signer responds with `s = k + c·x` and the user computes `s' = s + α`.
+//!
+//! `(R'.x, s')` is then an ordinary BIP340 signature of `m`. The user retries the blinding until
+//! `R'` has even y coordinate as BIP340 requires. Each step consumes the session so the steps
+//! can't be reordered or repeated.
+//!
+//! # Security
+//!
+//! **Do NOT run sessions concurrently!** If the signer has many open sessions at once the user
+//! can forge an additional signature using the ROS attack (Benhamouda, Lepoint, Loss, Orrù,
+//! Raykova 2020) which is practical for a few hundred sessions and has no reasonable
+//! countermeasure in this scheme. The signer must finish or abort each session before starting
+//! the next one. Like the rest of the crate this is an educational implementation.
+
+use rand_core::{CryptoRng, RngCore};
+use super::{challenge, verify};
+use crate::{Error, Keypair, Message, Parity, Point, Scalar, XOnlyPoint, G};
+
+/// The nonce commitment `R` sent by the signer in the first move
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SignerCommitment(Point);
+
+/// The blinded challenge `c` sent by the user in the second move
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct BlindedChallenge(Scalar);
+
+/// The response `s` sent by the signer in the third move
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct BlindSignature(Scalar);
+
+/// State of the signer between the first and the third move
+pub struct SignerSession {
+    secret_key: Scalar,
+    k: Scalar,
+}
+
+impl SignerSession {
+    /// Starts the session by generating the nonce.
+    pub fn new<R: RngCore + CryptoRng>(keypair: &Keypair, rng: &mut R) -> (Self, SignerCommitment) {
+        let d = keypair.secret_key().to_scalar();
+        let secret_key = if keypair.x_only_public_key().1 == Parity::Odd { -d } else { d };
+        let k = Scalar::random(rng);
+        (SignerSession { secret_key, k }, SignerCommitment(G * k))
+    }
+
+    /// Signs the blinded challenge, ending the session.
+    pub fn sign(self, challenge: &BlindedChallenge) -> BlindSignature {
+        BlindSignature(self.k + challenge.0 * self.secret_key)
+    }
+}
+
+/// Doesn't print the secrets so that they don't leak into logs
+impl core::fmt::Debug for SignerSession {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("SignerSession(..)")
+    }
+}
+
+/// State of the user between the second move and unblinding
+pub struct UserSession {
+    public_key: XOnlyPoint,
+    msg: [u8; 32],
+    r: Point,
+    alpha: Scalar,
+    c: Scalar,
+    r_prime: XOnlyPoint,
+}
+
+impl UserSession {
+    /// Blinds the signer's commitment and computes the challenge for `msg`.
+    pub fn new<R: RngCore + CryptoRng>(public_key: &XOnlyPoint, commitment: &SignerCommitment, msg: &[u8; 32], rng: &mut R) -> (Self, BlindedChallenge) {
+        let p = public_key.to_point();
+        let (alpha, beta, r_prime) = loop {
+            let alpha = Scalar::random(rng);
+            let beta = Scalar::random(rng);
+            if let Some((r_prime, Parity::Even)) = XOnlyPoint::from_point(commitment.0 + G * alpha + p * beta) {
+                break (alpha, beta, r_prime);
+            }
+        };
+        let c = challenge(&r_prime.serialize(), public_key, msg) + beta;
+        let session = UserSession {
+            public_key: *public_key,
+            msg: *msg,
+            r: commitment.0,
+            alpha,
+            c,
+            r_prime,
+        };
+        (session, BlindedChallenge(c))
+    }
+
+    /// Unblinds the signer's response producing a BIP340 signature of the message.
+    ///
+    /// Returns `Error::InvalidSignature` if the signer's response is invalid.
+    pub fn finish(self, signature: &BlindSignature) -> Result<[u8; 64], Error> {
+        if G * signature.0 != self.r + self.public_key.to_point() * self.c {
+            return Err(Error::InvalidSignature);
+        }
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&self.r_prime.serialize());
+        bytes[32..].copy_from_slice(&(signature.0 + self.alpha).to_be_bytes());
+        debug_assert_eq!(verify(&Message::from_digest(self.msg), &bytes, &self.public_key), Ok(()));
+        Ok(bytes)
+    }
+}
+
+/// Doesn't print the blinding factors because they link the signature to the session
+impl core::fmt::Debug for UserSession {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("UserSession(..)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BlindSignature, SignerSession, UserSession};
+    use crate::schnorr::{challenge, verify};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, Keypair, Message, Scalar, SecretKey, XOnlyPoint};
+
+    #[test]
+    fn round_trip() {
+        let mut rng = DeterministicRng::new(1);
+        // the first key has odd y coordinate, the second one even
+        for secret in &[[0x42; 32], [0x03; 32]] {
+            let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(secret).unwrap());
+            let public_key = keypair.x_only_public_key().0;
+            let msg = [0x07; 32];
+
+            let (signer, commitment) = SignerSession::new(&keypair, &mut rng);
+            let (user, blinded) = UserSession::new(&public_key, &commitment, &msg, &mut rng);
+            let response = signer.sign(&blinded);
+            let signature = user.finish(&response).unwrap();
+            assert_eq!(verify(&Message::from_digest(msg), &signature, &public_key), Ok(()));
+
+            // the signer's view doesn't contain anything from the final signature
+            assert_ne!(commitment.0.x().to_be_bytes()[..], signature[..32]);
+            assert_ne!(response.0.to_be_bytes()[..], signature[32..]);
+            let r_prime = XOnlyPoint::from_slice(&signature[..32]).unwrap();
+            let unblinded_challenge = challenge(&r_prime.serialize(), &public_key, &msg);
+            assert_ne!(blinded.0, unblinded_challenge);
+        }
+    }
+
+    #[test]
+    fn invalid_response() {
+        let mut rng = DeterministicRng::new(2);
+        let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap());
+        let public_key = keypair.x_only_public_key().0;
+        let (signer, commitment) = SignerSession::new(&keypair, &mut rng);
+        let (user, challenge) = UserSession::new(&public_key, &commitment, &[0x07; 32], &mut rng);
+        let response = signer.sign(&challenge);
+        assert_eq!(user.finish(&BlindSignature(response.0 + Scalar::ONE)), Err(Error::InvalidSignature));
+    }
+}
@@ -0,0 +1,153 @@
+//! Half-aggregation of BIP340 signatures
+//!
+//! Follows the construction of the half-aggregation BIP draft (as implemented in
+//! libsecp256k1-zkp): the aggregate is `r₀ || … || rₙ₋₁ || s` where `s = Σ zᵢ·sᵢ`, `z₀ = 1` and
+//! each other `zᵢ` is a hash of all `(rⱼ, Pⱼ, mⱼ)` with `j ≤ i`.
+
+use alloc::vec::Vec;
+use super::challenge;
+use crate::tagged_hash::{TaggedHash, HALFAGG_RANDOMIZER};
+use crate::{array_ref_32, multiexp, Error, Point, Scalar, XOnlyPoint, Zp, G};
+
+/// Computes the coefficients `zᵢ` from the messages, `r` values and keys
+fn coefficients<'a>(items: impl Iterator<Item = (&'a [u8; 32], &'a [u8], &'a XOnlyPoint)>) -> Vec<Scalar> {
+    let mut hasher = TaggedHash::new(HALFAGG_RANDOMIZER);
+    items
+        .enumerate()
+        .map(|(i, (msg, r, public_key))| {
+            hasher.update(r);
+            hasher.update(&public_key.serialize());
+            hasher.update(msg);
+            if i == 0 { Scalar::ONE } else { Scalar::from_be_bytes_reduce(&hasher.hash(&[])) }
+        })
+        .collect()
+}
+
+/// Aggregates the signatures into `32·(n + 1)` bytes.
+///
+/// The signatures are not verified, so the aggregate is only valid if all of them are. Returns
+/// `Error::InvalidSignature` if any `s` is not less than the curve order.
+pub fn half_aggregate(items: &[(&[u8; 32], &[u8; 64], &XOnlyPoint)]) -> Result<Vec<u8>, Error> {
+    let z = coefficients(items.iter().map(|(msg, signature, public_key)| (*msg, &signature[..32], *public_key)));
+    let mut s = Scalar::ZERO;
+    for ((_, signature, _), z) in items.iter().zip(z) {
+        s += z * Scalar::from_be_bytes(array_ref_32(&signature[32..])).ok_or(Error::InvalidSignature)?;
+    }
+
+    let mut aggregate = Vec::with_capacity(32 * (items.len() + 1));
+    for (_, signature, _) in items {
+        aggregate.extend_from_slice(&signature[..32]);
+    }
+    aggregate.extend_from_slice(&s.to_be_bytes());
+    Ok(aggregate)
+}
+
+/// Verifies the half-aggregate of signatures of the given messages and keys.
+///
+/// Checks `s·G = Σ zᵢ·(Rᵢ + eᵢ·Pᵢ)` using a single multiexp. Returns `Error::InvalidLength` if
+/// the aggregate doesn't have `32·(n + 1)` bytes, `Error::InvalidSignature` otherwise.
+pub fn verify_half_aggregate(items: &[(&[u8; 32], &XOnlyPoint)], aggregate: &[u8]) -> Result<(), Error> {
+    if aggregate.len() != 32 * (items.len() + 1) {
+        return Err(Error::InvalidLength(aggregate.len()));
+    }
+    let (r_values, s) = aggregate.split_at(32 * items.len());
+    let s = Scalar::from_be_bytes(array_ref_32(s)).ok_or(Error::InvalidSignature)?;
+    let z = coefficients(items.iter().zip(r_values.chunks(32)).map(|((msg, public_key), r)| (*msg, r, *public_key)));
+
+    let mut pairs = Vec::with_capacity(items.len() * 2 + 1);
+    for (((msg, public_key), r), z) in items.iter().zip(r_values.chunks(32)).zip(z) {
+        let r = array_ref_32(r);
+        let big_r = Zp::from_be_bytes(r).and_then(Point::lift_x).ok_or(Error::InvalidSignature)?;
+        let e = challenge(r, public_key, *msg);
+        pairs.push((z.to_u256(), big_r));
+        pairs.push(((z * e).to_u256(), public_key.to_point()));
+    }
+    pairs.push(((-s).to_u256(), G));
+
+    if multiexp(&pairs) == Point::AT_INFINITY {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{half_aggregate, verify_half_aggregate};
+    use crate::schnorr::sign;
+    use crate::test_util::{hex, hex32};
+    use crate::{Error, Keypair, Message, SecretKey, XOnlyPoint};
+
+    /// Signs `count` distinct messages reusing a few keys because key generation is slow
+    fn signatures(count: usize) -> Vec<([u8; 32], [u8; 64], XOnlyPoint)> {
+        let keypairs = (1..=3u8).map(|i| Keypair::from_secret_key(&SecretKey::from_bytes(&[i; 32]).unwrap())).collect::<Vec<_>>();
+        (0..count)
+            .map(|i| {
+                let keypair = &keypairs[i % keypairs.len()];
+                let msg = [i as u8; 32];
+                (msg, sign(&Message::from_digest(msg), keypair, &[0; 32]), keypair.x_only_public_key().0)
+            })
+            .collect()
+    }
+
+    fn aggregate(signatures: &[([u8; 32], [u8; 64], XOnlyPoint)]) -> Vec<u8> {
+        let items = signatures.iter().map(|(msg, signature, public_key)| (msg, signature, public_key)).collect::<Vec<_>>();
+        half_aggregate(&items).unwrap()
+    }
+
+    fn verify(signatures: &[([u8; 32], [u8; 64], XOnlyPoint)], aggregate: &[u8]) -> Result<(), Error> {
+        let items = signatures.iter().map(|(msg, _, public_key)| (msg, public_key)).collect::<Vec<_>>();
+        verify_half_aggregate(&items, aggregate)
+    }
+
+    #[test]
+    fn spec_vectors() {
+        assert_eq!(verify_half_aggregate(&[], &[0; 32]), Ok(()));
+
+        let keys = [
+            XOnlyPoint::from_bytes(&hex32("1b84c5567b126440995d3ed5aaba0565d71e1834604819ff9c17f5e9d5dd078f")).unwrap(),
+            XOnlyPoint::from_bytes(&hex32("462779ad4aad39514614751a71085f2f10e1c7a593e4e030efb5b8721ce55b0b")).unwrap(),
+        ];
+        let aggregate = hex("b070aafcea439a4f6f1bbfc2eb66d29d24b0cab74d6b745c3cfb009cc8fe4aa80e066c34819936549ff49b6fd4d41edfc401a367b87ddd59fee38177961c225f");
+        assert_eq!(verify_half_aggregate(&[(&[0x02; 32], &keys[0])], &aggregate), Ok(()));
+        let aggregate = hex("b070aafcea439a4f6f1bbfc2eb66d29d24b0cab74d6b745c3cfb009cc8fe4aa8a3afbdb45a6a34bf7c8c00f1b6d7e7d375b54540f13716c87b62e51e2f4f22ffbf8913ec53226a34892d60252a7052614ca79ae939986828d81d2311957371ad");
+        assert_eq!(verify_half_aggregate(&[(&[0x02; 32], &keys[0]), (&[0x05; 32], &keys[1])], &aggregate), Ok(()));
+    }
+
+    #[test]
+    fn aggregates_verify() {
+        // kept small because the arithmetic is slow
+        let signatures = signatures(50);
+        for count in &[1, 2, 50] {
+            let signatures = &signatures[..*count];
+            let aggregate = aggregate(signatures);
+            assert_eq!(aggregate.len(), 32 * (count + 1));
+            assert_eq!(verify(signatures, &aggregate), Ok(()), "{} signatures", count);
+        }
+        assert_eq!(aggregate(&[]), [0; 32]);
+    }
+
+    #[test]
+    fn tampering_fails() {
+        let signatures = signatures(3);
+        let aggregate = aggregate(&signatures);
+        for i in 0..signatures.len() {
+            let mut changed = signatures.clone();
+            changed[i].0[0] ^= 1;
+            assert_eq!(verify(&changed, &aggregate), Err(Error::InvalidSignature), "message {}", i);
+
+            let mut changed = signatures.clone();
+            changed[i].2 = signatures[(i + 1) % signatures.len()].2;
+            assert_eq!(verify(&changed, &aggregate), Err(Error::InvalidSignature), "key {}", i);
+
+            let mut changed = aggregate.clone();
+            changed[i * 32..(i + 1) * 32].copy_from_slice(&aggregate[((i + 1) % signatures.len()) * 32..][..32]);
+            assert_eq!(verify(&signatures, &changed), Err(Error::InvalidSignature), "R {}", i);
+        }
+        let mut swapped = signatures.clone();
+        swapped.swap(0, 1);
+        assert_eq!(verify(&swapped, &aggregate), Err(Error::InvalidSignature));
+        assert_eq!(verify(&signatures[..2], &aggregate), Err(Error::InvalidLength(128)));
+        assert_eq!(verify(&signatures, &aggregate[..96]), Err(Error::InvalidLength(96)));
+    }
+}
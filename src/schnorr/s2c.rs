@@ -0,0 +1,121 @@
+//! Sign-to-contract commitments in BIP340 signatures
+//!
+//! The signer commits to arbitrary data by tweaking the nonce: `R = R₀ + H(R₀ || H(data))·G`
+//! where `R₀ = k·G` is the original nonce. The signature is an ordinary BIP340 signature, the
+//! commitment is only revealed by publishing `R₀`. If `R` has odd y coordinate the signer uses
+//! `-(k + t)` as the nonce like in any BIP340 signature, so the opening is the same either way.
+//!
+//! The tags follow the naming of the ECDSA sign-to-contract module of libsecp256k1-zkp but this
+//! scheme is not interoperable with any other implementation.
+
+use super::{challenge, derive_nonce, signing_key};
+use crate::tagged_hash::TaggedHash;
+use crate::zeroize::Zeroizing;
+use crate::{Error, Keypair, Parity, Point, Scalar, XOnlyPoint, G};
+
+const DATA_TAG: &str = "s2c/schnorr/data";
+const POINT_TAG: &str = "s2c/schnorr/point";
+
+/// The original nonce `R₀` which opens the commitment
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct S2cOpening {
+    original_nonce: Point,
+}
+
+impl S2cOpening {
+    /// Parses the compressed original nonce.
+    ///
+    /// Returns the error of `Point::from_sec1_bytes` if the bytes are not a valid point.
+    pub fn from_bytes(bytes: &[u8; 33]) -> Result<Self, Error> {
+        Ok(S2cOpening { original_nonce: Point::from_sec1_bytes(bytes)? })
+    }
+
+    pub fn serialize(&self) -> [u8; 33] {
+        self.original_nonce.serialize_compressed()
+    }
+
+    pub fn original_nonce(&self) -> Point {
+        self.original_nonce
+    }
+
+    /// Computes the nonce `R = R₀ + t·G` committing to the data and the tweak `t`
+    fn commit(&self, data_hash: &[u8; 32]) -> (Point, Scalar) {
+        let hash = TaggedHash::new(POINT_TAG).hash(&[&self.original_nonce.serialize_compressed(), data_hash]);
+        let tweak = Scalar::from_be_bytes_reduce(&hash);
+        (self.original_nonce + G * tweak, tweak)
+    }
+}
+
+/// Signs the 32-byte message committing to `data` in the nonce.
+///
+/// The nonce is derived like in BIP340 except that the hash of `data` is appended to the message
+/// so that commitments to different data never share the nonce.
+pub fn sign_to_contract(msg: &[u8; 32], keypair: &Keypair, data: &[u8], aux_rand: &[u8; 32]) -> ([u8; 64], S2cOpening) {
+    let (public_key, d) = signing_key(keypair);
+    let data_hash = TaggedHash::new(DATA_TAG).hash(&[data]);
+    let mut k = Zeroizing::new(derive_nonce(&d, &public_key, &[msg, &data_hash], aux_rand));
+    let opening = S2cOpening { original_nonce: Point::mul_gen(&k) };
+    let (big_r, tweak) = opening.commit(&data_hash);
+    let (big_r, parity) = XOnlyPoint::from_point(big_r).expect("the probability of R being at infinity is negligible");
+    *k += tweak;
+    if parity == Parity::Odd {
+        *k = -*k;
+    }
+    let r = big_r.serialize();
+    let s = *k + challenge(&r, &public_key, msg) * *d;
+
+    let mut signature = [0; 64];
+    signature[..32].copy_from_slice(&r);
+    signature[32..].copy_from_slice(&s.to_be_bytes());
+    (signature, opening)
+}
+
+/// Checks that the nonce of the signature commits to `data`.
+///
+/// This doesn't verify the signature itself.
+pub fn verify_commitment(signature: &[u8; 64], opening: &S2cOpening, data: &[u8]) -> bool {
+    let data_hash = TaggedHash::new(DATA_TAG).hash(&[data]);
+    let (big_r, _) = opening.commit(&data_hash);
+    !big_r.is_at_infinity() && big_r.x().to_be_bytes()[..] == signature[..32]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sign_to_contract, verify_commitment, S2cOpening};
+    use crate::schnorr::verify;
+    use crate::{Keypair, Message, SecretKey};
+
+    #[test]
+    fn commitment_opens() {
+        // the first key has odd y coordinate, the second one even
+        for (i, secret) in [[0x42; 32], [0x03; 32]].iter().enumerate() {
+            let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(secret).unwrap());
+            let public_key = keypair.x_only_public_key().0;
+            let msg = [0x07; 32];
+            for data in &[&b"contract"[..], b"", b"other contract", &[i as u8; 100]] {
+                let (signature, opening) = sign_to_contract(&msg, &keypair, data, &[0; 32]);
+                assert_eq!(verify(&Message::from_digest(msg), &signature, &public_key), Ok(()));
+                assert!(verify_commitment(&signature, &opening, data));
+                assert!(!verify_commitment(&signature, &opening, b"contracT"));
+                assert_eq!(S2cOpening::from_bytes(&opening.serialize()), Ok(opening));
+
+                // the opening is just the nonce, which is unrelated to the secret key
+                let opening = opening.original_nonce();
+                assert_ne!(opening, keypair.public_key().to_point());
+                assert_ne!(opening, -keypair.public_key().to_point());
+                assert_ne!(opening.x().to_be_bytes()[..], signature[..32]);
+            }
+        }
+    }
+
+    #[test]
+    fn different_data_different_nonce() {
+        let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap());
+        let (signature_a, opening_a) = sign_to_contract(&[0x07; 32], &keypair, b"a", &[0; 32]);
+        let (signature_b, opening_b) = sign_to_contract(&[0x07; 32], &keypair, b"b", &[0; 32]);
+        // reusing the original nonce would leak the key
+        assert_ne!(opening_a, opening_b);
+        assert!(!verify_commitment(&signature_a, &opening_b, b"b"));
+        assert!(!verify_commitment(&signature_b, &opening_a, b"a"));
+    }
+}
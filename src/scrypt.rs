@@ -0,0 +1,120 @@
+//! scrypt password-based key derivation (RFC 7914)
+//!
+//! Straightforward implementation of the specification operating on little-endian 32-bit words.
+//! It allocates `128 * r * n` bytes for the table of `ROMix`.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::hashes::pbkdf2_hmac_sha256;
+
+fn salsa_quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[b] ^= x[a].wrapping_add(x[d]).rotate_left(7);
+    x[c] ^= x[b].wrapping_add(x[a]).rotate_left(9);
+    x[d] ^= x[c].wrapping_add(x[b]).rotate_left(13);
+    x[a] ^= x[d].wrapping_add(x[c]).rotate_left(18);
+}
+
+/// Salsa20/8 core replacing `block` with its hash
+fn salsa20_8(block: &mut [u32]) {
+    let mut x = [0; 16];
+    x.copy_from_slice(block);
+    for _ in 0..4 {
+        // columns
+        salsa_quarter_round(&mut x, 0, 4, 8, 12);
+        salsa_quarter_round(&mut x, 5, 9, 13, 1);
+        salsa_quarter_round(&mut x, 10, 14, 2, 6);
+        salsa_quarter_round(&mut x, 15, 3, 7, 11);
+        // rows
+        salsa_quarter_round(&mut x, 0, 1, 2, 3);
+        salsa_quarter_round(&mut x, 5, 6, 7, 4);
+        salsa_quarter_round(&mut x, 10, 11, 8, 9);
+        salsa_quarter_round(&mut x, 15, 12, 13, 14);
+    }
+    for (word, mixed) in block.iter_mut().zip(&x) {
+        *word = word.wrapping_add(*mixed);
+    }
+}
+
+/// `scryptBlockMix` of `2r` 16-word blocks, `scratch` must have the same length as `block`
+fn block_mix(block: &mut [u32], scratch: &mut [u32]) {
+    let blocks = block.len() / 16;
+    let mut x = [0; 16];
+    x.copy_from_slice(&block[(blocks - 1) * 16..]);
+    for i in 0..blocks {
+        for (x, word) in x.iter_mut().zip(&block[i * 16..(i + 1) * 16]) {
+            *x ^= word;
+        }
+        salsa20_8(&mut x);
+        // even blocks go to the first half, odd ones to the second half
+        let position = (i / 2 + (i % 2) * blocks / 2) * 16;
+        scratch[position..(position + 16)].copy_from_slice(&x);
+    }
+    block.copy_from_slice(scratch);
+}
+
+/// `scryptROMix` of one `128 * r` byte block
+fn ro_mix(block: &mut [u32], n: usize) {
+    let len = block.len();
+    let mut table = vec![0; len * n];
+    let mut scratch = vec![0; len];
+    for entry in table.chunks_mut(len) {
+        entry.copy_from_slice(block);
+        block_mix(block, &mut scratch);
+    }
+    for _ in 0..n {
+        // Integerify takes the first word of the last 16-word block, `n` is a power of two
+        let j = block[len - 16] as usize & (n - 1);
+        for (word, entry) in block.iter_mut().zip(&table[j * len..(j + 1) * len]) {
+            *word ^= entry;
+        }
+        block_mix(block, &mut scratch);
+    }
+}
+
+/// Derives `output.len()` bytes from `password` and `salt` with cost parameter `n = 2^log_n`,
+/// block size `r` and parallelization `p`.
+pub(crate) fn scrypt(password: &[u8], salt: &[u8], log_n: u8, r: usize, p: usize, output: &mut [u8]) {
+    let n = 1usize << log_n;
+    let mut bytes = vec![0; 128 * r * p];
+    pbkdf2_hmac_sha256(password, salt, 1, &mut bytes);
+    let mut words = bytes.chunks(4).map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]])).collect::<Vec<_>>();
+    for block in words.chunks_mut(32 * r) {
+        ro_mix(block, n);
+    }
+    for (chunk, word) in bytes.chunks_mut(4).zip(&words) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    pbkdf2_hmac_sha256(password, &bytes, 1, output);
+    bytes.fill(0);
+    words.fill(0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{salsa20_8, scrypt};
+    use crate::test_util::hex;
+
+    /// RFC 7914 section 8
+    #[test]
+    fn salsa20_8_vector() {
+        let input = hex("7e879a214f3ec9867ca940e641718f26baee555b8c61c1b50df846116dcd3b1dee24f319df9b3d8514121e4b5ac5aa3276021d2909c74829edebc68db8b8c25e");
+        let expected = hex("a41f859c6608cc993b81cacb020cef05044b2181a2fd337dfd7b1c6396682f29b4393168e3c9e6bcfe6bc5b7a06d96bae424cc102c91745c24ad673dc7618f81");
+        let mut block = [0; 16];
+        for (word, chunk) in block.iter_mut().zip(input.chunks(4)) {
+            *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        salsa20_8(&mut block);
+        let output = block.iter().flat_map(|word| word.to_le_bytes()).collect::<Vec<_>>();
+        assert_eq!(output, expected);
+    }
+
+    /// The first two vectors of RFC 7914 section 12, the others take too much memory and time
+    #[test]
+    fn vectors() {
+        let mut output = [0; 64];
+        scrypt(b"", b"", 4, 1, 1, &mut output);
+        assert_eq!(output[..], hex("77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906")[..]);
+        scrypt(b"password", b"NaCl", 10, 8, 16, &mut output);
+        assert_eq!(output[..], hex("fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b3731622eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640")[..]);
+    }
+}
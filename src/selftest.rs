@@ -0,0 +1,198 @@
+//! Known-answer self-test of the core operations
+//!
+//! `selftest` checks the field arithmetic, scalar multiplication, ECDSA, BIP340 (with the `hashes`
+//! feature) and SEC1 encoding against fixed vectors computed by an independent implementation.
+//! It's meant to be run at startup by applications required to detect miscompilation or faulty
+//! hardware before using the keys. The cost is dominated by eight scalar multiplications, which
+//! is a few tens of milliseconds with the naive arithmetic of this crate, so every component is
+//! checked once without redundant round trips.
+
+use crate::ecdsa::{self, Signature};
+use crate::nonce::NonceGenerator;
+#[cfg(feature = "hashes")]
+use crate::{schnorr, Keypair, XOnlyPoint};
+use crate::{field, point, zp, Message, Point, PublicKey, Scalar, SecretKey, SelfTestFailure, Zp, G};
+
+/// The vectors, scalars and byte strings are stored as field elements so that they can be
+/// constants
+#[derive(Copy, Clone)]
+struct KnownAnswers {
+    /// `G.x · G.y`
+    field_product: Zp,
+    /// `1 / G.x`
+    field_inverse: Zp,
+    scalar: Zp,
+    /// `scalar · G`
+    multiple: Point,
+    /// Compressed encoding of `multiple`, the prefix and the x coordinate
+    multiple_sec1: (u8, Zp),
+    ecdsa_secret_key: Zp,
+    ecdsa_public_key: Point,
+    ecdsa_msg: Zp,
+    ecdsa_nonce: Zp,
+    ecdsa_signature: (Zp, Zp),
+    /// BIP340 test vector 0
+    #[cfg(feature = "hashes")]
+    schnorr_secret_key: Zp,
+    #[cfg(feature = "hashes")]
+    schnorr_public_key: Zp,
+    #[cfg(feature = "hashes")]
+    schnorr_signature: (Zp, Zp),
+}
+
+const KNOWN_ANSWERS: KnownAnswers = KnownAnswers {
+    field_product: zp!("fd3dc529c6eb60fb9d166034cf3c1a5a72324aa9dfd3428a56d7e1ce0179fd9b"),
+    field_inverse: zp!("237afdf1d2938d86870aaeb8ad77626a67b8e794abfb076be61d003687ca9ef6"),
+    scalar: zp!("4b688df40bcedbe641ddb16ff0a1842d9c67ea1c3bf63f3e0471baa664531d1a"),
+    multiple: point!("02190e9fc01e44ff26b76004101305b074de71c6ebca2210715f3338e9eea0defb"),
+    multiple_sec1: (0x02, zp!("190e9fc01e44ff26b76004101305b074de71c6ebca2210715f3338e9eea0defb")),
+    ecdsa_secret_key: zp!("ebb2c082fd7727890a28ac82f6bdf97bad8de9f5d7c9028692de1a255cad3e0f"),
+    ecdsa_public_key: point!("03779dd197a5df977ed2cf6cb31d82d43328b790dc6b3b7d4437a427bd5847dfcd"),
+    ecdsa_msg: zp!("4b688df40bcedbe641ddb16ff0a1842d9c67ea1c3bf63f3e0471baa664531d1a"),
+    ecdsa_nonce: zp!("49a0d7b786ec9cde0d0721d72804befd06571c974b191efb42ecf322ba9ddd9a"),
+    ecdsa_signature: (
+        zp!("241097efbf8b63bf145c8961dbdf10c310efbb3b2676bbc0f8b08505c9e2f795"),
+        zp!("021006b7838609339e8b415a7f9acb1b661828131aef1ecbc7955dfb01f3ca0e"),
+    ),
+    #[cfg(feature = "hashes")]
+    schnorr_secret_key: zp!("3"),
+    #[cfg(feature = "hashes")]
+    schnorr_public_key: zp!("f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"),
+    #[cfg(feature = "hashes")]
+    schnorr_signature: (
+        zp!("e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca8215"),
+        zp!("25f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0"),
+    ),
+};
+
+/// Runs the known-answer tests, returning the first component that failed.
+///
+/// Available without `std` so it can be used in embedded firmware as well.
+pub fn selftest() -> Result<(), SelfTestFailure> {
+    check(&KNOWN_ANSWERS)
+}
+
+fn check(answers: &KnownAnswers) -> Result<(), SelfTestFailure> {
+    ensure(check_field(answers), SelfTestFailure::Field)?;
+    ensure(check_multiplication(answers), SelfTestFailure::ScalarMultiplication)?;
+    ensure(check_ecdsa(answers), SelfTestFailure::Ecdsa)?;
+    #[cfg(feature = "hashes")]
+    ensure(check_schnorr(answers), SelfTestFailure::Schnorr)?;
+    ensure(check_sec1(answers), SelfTestFailure::Sec1)
+}
+
+fn ensure(passed: bool, failure: SelfTestFailure) -> Result<(), SelfTestFailure> {
+    if passed {
+        Ok(())
+    } else {
+        Err(failure)
+    }
+}
+
+/// Both the generic operators and the multiplication specialized for secp256k1
+fn check_field(answers: &KnownAnswers) -> bool {
+    G.x() * G.y() == answers.field_product
+        && Zp::from_reduced(field::mul(G.x().0, G.y().0)) == answers.field_product
+        && G.x().multiplicative_inverse() == answers.field_inverse
+        && G.x() * answers.field_inverse == Zp::ONE
+}
+
+/// `Point::mul_gen` used for key generation and signing is covered by the signature checks
+fn check_multiplication(answers: &KnownAnswers) -> bool {
+    G * scalar(answers.scalar) == answers.multiple
+}
+
+struct FixedNonce(Scalar);
+
+impl NonceGenerator for FixedNonce {
+    fn nonce(&mut self, _msg32: &[u8; 32], _secret_key: &SecretKey, _attempt: u32) -> Scalar {
+        self.0
+    }
+}
+
+fn check_ecdsa(answers: &KnownAnswers) -> bool {
+    let secret_key = match SecretKey::from_scalar(scalar(answers.ecdsa_secret_key)) {
+        Some(secret_key) => secret_key,
+        None => return false,
+    };
+    let public_key = match PublicKey::from_point(answers.ecdsa_public_key) {
+        Some(public_key) => public_key,
+        None => return false,
+    };
+    let msg = Message::from_digest(answers.ecdsa_msg.to_be_bytes());
+    let signature = ecdsa::sign_with(&msg, &secret_key, &mut FixedNonce(scalar(answers.ecdsa_nonce)));
+    let (r, s) = answers.ecdsa_signature;
+    Signature::from_scalars(scalar(r), scalar(s)) == Some(signature) && ecdsa::verify(&msg, &signature, &public_key).is_ok()
+}
+
+#[cfg(feature = "hashes")]
+fn check_schnorr(answers: &KnownAnswers) -> bool {
+    let secret_key = match SecretKey::from_scalar(scalar(answers.schnorr_secret_key)) {
+        Some(secret_key) => secret_key,
+        None => return false,
+    };
+    let keypair = Keypair::from_secret_key(&secret_key);
+    let (public_key, _) = keypair.x_only_public_key();
+    let msg = Message::from_digest([0; 32]);
+    let signature = schnorr::sign(&msg, &keypair, &[0; 32]);
+    let mut expected = [0; 64];
+    expected[..32].copy_from_slice(&answers.schnorr_signature.0.to_be_bytes());
+    expected[32..].copy_from_slice(&answers.schnorr_signature.1.to_be_bytes());
+    XOnlyPoint::from_bytes(&answers.schnorr_public_key.to_be_bytes()) == Ok(public_key)
+        && signature == expected
+        && schnorr::verify(&msg, &signature, &public_key).is_ok()
+}
+
+fn check_sec1(answers: &KnownAnswers) -> bool {
+    let compressed = answers.multiple.serialize_compressed();
+    let uncompressed = answers.multiple.serialize_uncompressed();
+    compressed[0] == answers.multiple_sec1.0
+        && compressed[1..] == answers.multiple_sec1.1.to_be_bytes()
+        && uncompressed[1..33] == compressed[1..]
+        && Point::from_sec1_bytes(&compressed) == Ok(answers.multiple)
+        && Point::from_sec1_bytes(&uncompressed) == Ok(answers.multiple)
+}
+
+/// All the scalars of the vectors are less than `n`, if a corrupted one isn't it's reduced and
+/// the check fails anyway
+fn scalar(value: Zp) -> Scalar {
+    value.to_scalar_reduce()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, selftest, KnownAnswers, KNOWN_ANSWERS};
+    use crate::{zp, SelfTestFailure, Zp, G};
+
+    /// Modifies one of the constants and names the component whose check should fail
+    type Corruption = (fn(&mut KnownAnswers), SelfTestFailure);
+
+    #[test]
+    fn passes() {
+        assert_eq!(selftest(), Ok(()));
+    }
+
+    #[test]
+    fn corrupted_constants() {
+        let corruptions: [Corruption; 7] = [
+            (|answers| answers.field_inverse += zp!("1"), SelfTestFailure::Field),
+            (|answers| answers.multiple = -answers.multiple, SelfTestFailure::ScalarMultiplication),
+            (|answers| answers.ecdsa_nonce += zp!("1"), SelfTestFailure::Ecdsa),
+            (|answers| answers.ecdsa_public_key = G, SelfTestFailure::Ecdsa),
+            (|answers| answers.ecdsa_msg = Zp::ZERO, SelfTestFailure::Ecdsa),
+            (|answers| answers.multiple_sec1.0 = 0x03, SelfTestFailure::Sec1),
+            (|answers| answers.multiple_sec1.1 = Zp::ZERO, SelfTestFailure::Sec1),
+        ];
+        for (corrupt, failure) in &corruptions {
+            let mut answers = KNOWN_ANSWERS;
+            corrupt(&mut answers);
+            assert_eq!(check(&answers), Err(*failure));
+        }
+        #[cfg(feature = "hashes")]
+        {
+            let mut answers = KNOWN_ANSWERS;
+            answers.schnorr_signature.1 += zp!("1");
+            assert_eq!(check(&answers), Err(SelfTestFailure::Schnorr));
+        }
+    }
+}
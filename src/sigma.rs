@@ -0,0 +1,350 @@
+//! Composition of sigma protocols: AND and OR proofs
+//!
+//! A statement is a linear relation between a secret scalar `x` and public points, e.g.
+//! `P = x·G` (`pok::Statement`) or `A = x·G', B = x·H'` (`dleq::Statement`). The prover commits
+//! to a nonce `k`, receives a challenge `e` and responds with `s = k + e·x`. Knowing `e` and `s`
+//! the commitments can be computed backwards which is used both by the verifier and to simulate
+//! proofs without the witness.
+//!
+//! The drivers make the proofs non-interactive using `Transcript`. The transcript is first fed
+//! the kind of the composition (`"and"` or `"or"`) and the number of statements under the label
+//! `sigma/composition`, then each statement appends its public data, then all commitments are
+//! appended under `sigma/commitment` and finally the challenge is derived with the label
+//! `sigma/challenge`. The caller may append context to the transcript before proving, the
+//! verifier has to append the same.
+//!
+//! * AND proof: all statements share the challenge `e`, the proof is `e || s₁ || … || sₙ`.
+//! * OR proof: the prover simulates the statements it doesn't know the witness of with random
+//!   challenges `eⱼ` and responses `sⱼ` and sets the challenge of the known statement to
+//!   `e - Σ eⱼ`. The verifier checks that the challenges sum to `e`. The proof is
+//!   `e₁ || s₁ || … || eₙ || sₙ` and it's the same for any known statement.
+
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use crate::transcript::Transcript;
+use crate::{array_ref_32, Error, Point, Scalar};
+
+const COMPOSITION_LABEL: &str = "sigma/composition";
+const COMMITMENT_LABEL: &str = "sigma/commitment";
+const CHALLENGE_LABEL: &str = "sigma/challenge";
+
+/// A relation between a secret scalar and public points provable by a sigma protocol
+pub trait SigmaStatement {
+    /// Appends the public data of the statement, including what kind of statement it is
+    fn append_to_transcript(&self, transcript: &mut Transcript);
+
+    /// Computes the first message of the prover from the nonce
+    fn commit(&self, nonce: &Scalar) -> Vec<Point>;
+
+    /// Computes the response `nonce + challenge·witness`
+    fn respond(&self, witness: &Scalar, nonce: &Scalar, challenge: &Scalar) -> Scalar {
+        *nonce + *challenge * *witness
+    }
+
+    /// Computes the commitments that make `(challenge, response)` an accepting transcript.
+    ///
+    /// For honest proofs these are equal to the output of `commit`.
+    fn simulate(&self, challenge: &Scalar, response: &Scalar) -> Vec<Point>;
+}
+
+/// Proof that the prover knows the witnesses of all statements
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct AndProof {
+    challenge: Scalar,
+    responses: Vec<Scalar>,
+}
+
+/// Proof that the prover knows the witness of at least one of the statements
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct OrProof {
+    challenges: Vec<Scalar>,
+    responses: Vec<Scalar>,
+}
+
+fn parse_scalars(bytes: &[u8], chunk_len: usize) -> Result<Vec<Scalar>, Error> {
+    if bytes.is_empty() || !bytes.len().is_multiple_of(chunk_len) {
+        return Err(Error::InvalidLength(bytes.len()));
+    }
+    bytes
+        .chunks_exact(32)
+        .map(|scalar| Scalar::from_be_bytes(array_ref_32(scalar)).ok_or(Error::InvalidSignature))
+        .collect()
+}
+
+fn serialize_scalars<'a, I: IntoIterator<Item = &'a Scalar>>(scalars: I) -> Vec<u8> {
+    scalars.into_iter().flat_map(|scalar| scalar.to_be_bytes()).collect()
+}
+
+impl AndProof {
+    /// Parses `e || s₁ || … || sₙ`
+    ///
+    /// Returns `Error::InvalidLength` if the length is not a multiple of 32 or there are no
+    /// responses, `Error::InvalidSignature` if any scalar is not less than the curve order.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < 64 {
+            return Err(Error::InvalidLength(bytes.len()));
+        }
+        let mut scalars = parse_scalars(bytes, 32)?;
+        let challenge = scalars.remove(0);
+        Ok(AndProof { challenge, responses: scalars })
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        serialize_scalars(core::iter::once(&self.challenge).chain(&self.responses))
+    }
+
+    /// Checks the proof of all `statements`.
+    ///
+    /// `transcript` must be in the same state as the one the prover used.
+    pub fn verify(&self, transcript: &mut Transcript, statements: &[&dyn SigmaStatement]) -> bool {
+        if statements.len() != self.responses.len() {
+            return false;
+        }
+        start(transcript, b"and", statements);
+        for (statement, response) in statements.iter().zip(&self.responses) {
+            append_commitments(transcript, &statement.simulate(&self.challenge, response));
+        }
+        transcript.challenge_scalar(CHALLENGE_LABEL) == self.challenge
+    }
+}
+
+impl OrProof {
+    /// Parses `e₁ || s₁ || … || eₙ || sₙ`
+    ///
+    /// Returns `Error::InvalidLength` if the length is not a positive multiple of 64,
+    /// `Error::InvalidSignature` if any scalar is not less than the curve order.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let scalars = parse_scalars(bytes, 64)?;
+        let challenges = scalars.iter().step_by(2).copied().collect();
+        let responses = scalars.iter().skip(1).step_by(2).copied().collect();
+        Ok(OrProof { challenges, responses })
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        serialize_scalars(self.challenges.iter().zip(&self.responses).flat_map(|(e, s)| [e, s]))
+    }
+
+    /// Checks that the prover knows the witness of at least one of `statements`.
+    ///
+    /// `transcript` must be in the same state as the one the prover used.
+    pub fn verify(&self, transcript: &mut Transcript, statements: &[&dyn SigmaStatement]) -> bool {
+        if statements.len() != self.challenges.len() {
+            return false;
+        }
+        start(transcript, b"or", statements);
+        for ((statement, challenge), response) in statements.iter().zip(&self.challenges).zip(&self.responses) {
+            append_commitments(transcript, &statement.simulate(challenge, response));
+        }
+        let sum = self.challenges.iter().fold(Scalar::ZERO, |sum, challenge| sum + *challenge);
+        transcript.challenge_scalar(CHALLENGE_LABEL) == sum
+    }
+}
+
+fn start(transcript: &mut Transcript, kind: &[u8], statements: &[&dyn SigmaStatement]) {
+    transcript.append_message(COMPOSITION_LABEL, kind);
+    transcript.append_message(COMPOSITION_LABEL, &(statements.len() as u64).to_be_bytes());
+    for statement in statements {
+        statement.append_to_transcript(transcript);
+    }
+}
+
+fn append_commitments(transcript: &mut Transcript, commitments: &[Point]) {
+    for commitment in commitments {
+        transcript.append_point(COMMITMENT_LABEL, commitment);
+    }
+}
+
+/// Proves knowledge of `witnesses[i]` for each `statements[i]` using a shared challenge.
+///
+/// Returns `Error::InvalidLength` with the number of witnesses if it differs from the number of
+/// statements or there are none. Wrong witnesses produce a proof that doesn't verify.
+pub fn and_prove<R: RngCore + CryptoRng>(transcript: &mut Transcript, statements: &[&dyn SigmaStatement], witnesses: &[Scalar], rng: &mut R) -> Result<AndProof, Error> {
+    if statements.len() != witnesses.len() || statements.is_empty() {
+        return Err(Error::InvalidLength(witnesses.len()));
+    }
+    start(transcript, b"and", statements);
+    let nonces = statements.iter().map(|_| Scalar::random(rng)).collect::<Vec<_>>();
+    for (statement, nonce) in statements.iter().zip(&nonces) {
+        append_commitments(transcript, &statement.commit(nonce));
+    }
+    let challenge = transcript.challenge_scalar(CHALLENGE_LABEL);
+    let responses = statements
+        .iter()
+        .zip(witnesses)
+        .zip(&nonces)
+        .map(|((statement, witness), nonce)| statement.respond(witness, nonce, &challenge))
+        .collect();
+    Ok(AndProof { challenge, responses })
+}
+
+/// Proves knowledge of the witness of one of `statements`, `statements[known]`.
+///
+/// Returns `Error::ValueOutOfRange` if `known` is not a valid index. A wrong witness produces a
+/// proof that doesn't verify.
+pub fn or_prove<R: RngCore + CryptoRng>(transcript: &mut Transcript, statements: &[&dyn SigmaStatement], known: usize, witness: &Scalar, rng: &mut R) -> Result<OrProof, Error> {
+    if known >= statements.len() {
+        return Err(Error::ValueOutOfRange);
+    }
+    start(transcript, b"or", statements);
+    let nonce = Scalar::random(rng);
+    let mut challenges = Vec::with_capacity(statements.len());
+    let mut responses = Vec::with_capacity(statements.len());
+    for (i, statement) in statements.iter().enumerate() {
+        if i == known {
+            append_commitments(transcript, &statement.commit(&nonce));
+            // placeholders, computed after the challenge
+            challenges.push(Scalar::ZERO);
+            responses.push(Scalar::ZERO);
+        } else {
+            let (challenge, response) = (Scalar::random(rng), Scalar::random(rng));
+            append_commitments(transcript, &statement.simulate(&challenge, &response));
+            challenges.push(challenge);
+            responses.push(response);
+        }
+    }
+    let challenge = transcript.challenge_scalar(CHALLENGE_LABEL);
+    let simulated = challenges.iter().fold(Scalar::ZERO, |sum, challenge| sum + *challenge);
+    challenges[known] = challenge - simulated;
+    responses[known] = statements[known].respond(witness, &nonce, &challenges[known]);
+    Ok(OrProof { challenges, responses })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{and_prove, or_prove, AndProof, OrProof, SigmaStatement, CHALLENGE_LABEL, COMMITMENT_LABEL, COMPOSITION_LABEL};
+    use crate::pedersen::Commitment;
+    use crate::test_utils::DeterministicRng;
+    use crate::transcript::Transcript;
+    use crate::{dleq, pok, Error, Scalar, G, H};
+
+    fn transcript() -> Transcript {
+        let mut transcript = Transcript::new("toy-secp256k1/test/sigma");
+        transcript.append_message("context", b"session 1");
+        transcript
+    }
+
+    #[test]
+    fn and_proof() {
+        let mut rng = DeterministicRng::new(224);
+        let (x, y) = (Scalar::random(&mut rng), Scalar::random(&mut rng));
+        let pok = pok::Statement::with_base(H, H * x);
+        let dleq = dleq::Statement::new(G, G * y, H, H * y);
+        let statements: [&dyn SigmaStatement; 2] = [&pok, &dleq];
+
+        let proof = and_prove(&mut transcript(), &statements, &[x, y], &mut rng).unwrap();
+        assert!(proof.verify(&mut transcript(), &statements));
+        assert_eq!(AndProof::from_bytes(&proof.serialize()), Ok(proof.clone()));
+        assert!(!proof.verify(&mut Transcript::new("toy-secp256k1/test/sigma"), &statements));
+        assert!(!proof.verify(&mut transcript(), &[&pok]));
+        assert!(!proof.verify(&mut transcript(), &[&dleq, &pok]));
+
+        // either witness wrong
+        let wrong = Scalar::random(&mut rng);
+        for witnesses in &[[wrong, y], [x, wrong], [y, x]] {
+            let proof = and_prove(&mut transcript(), &statements, witnesses, &mut rng).unwrap();
+            assert!(!proof.verify(&mut transcript(), &statements));
+        }
+        assert_eq!(and_prove(&mut transcript(), &statements, &[x], &mut rng), Err(Error::InvalidLength(1)));
+        assert_eq!(and_prove(&mut transcript(), &[], &[], &mut rng), Err(Error::InvalidLength(0)));
+        assert_eq!(AndProof::from_bytes(&[0; 32]), Err(Error::InvalidLength(32)));
+        assert_eq!(AndProof::from_bytes(&[0xFF; 64]), Err(Error::InvalidSignature));
+    }
+
+    /// The commitment `v·H + r·G` opens to `0` iff `C = r·G` and to `1` iff `C - H = r·G`
+    fn bit_statements(commitment: &Commitment) -> [pok::Statement; 2] {
+        let point = commitment.to_point();
+        [pok::Statement::with_base(G, point), pok::Statement::with_base(G, point + -H)]
+    }
+
+    #[test]
+    fn commitment_to_bit() {
+        let mut rng = DeterministicRng::new(224);
+        for bit in 0..2 {
+            let blinding = Scalar::random(&mut rng);
+            let commitment = Commitment::commit(Scalar::from(bit), blinding);
+            let [zero, one] = bit_statements(&commitment);
+            let statements: [&dyn SigmaStatement; 2] = [&zero, &one];
+            let proof = or_prove(&mut transcript(), &statements, bit as usize, &blinding, &mut rng).unwrap();
+            assert!(proof.verify(&mut transcript(), &statements), "bit {}", bit);
+            assert_eq!(OrProof::from_bytes(&proof.serialize()), Ok(proof.clone()));
+            assert!(!proof.verify(&mut transcript(), &[&one, &zero]));
+            // claiming the other branch doesn't work
+            let proof = or_prove(&mut transcript(), &statements, 1 - bit as usize, &blinding, &mut rng).unwrap();
+            assert!(!proof.verify(&mut transcript(), &statements), "bit {}", bit);
+        }
+
+        // two is neither
+        let blinding = Scalar::random(&mut rng);
+        let [zero, one] = bit_statements(&Commitment::commit(Scalar::from(2), blinding));
+        for known in 0..2 {
+            let proof = or_prove(&mut transcript(), &[&zero, &one], known, &blinding, &mut rng).unwrap();
+            assert!(!proof.verify(&mut transcript(), &[&zero, &one]));
+        }
+        assert_eq!(or_prove(&mut transcript(), &[&zero, &one], 2, &blinding, &mut rng), Err(Error::ValueOutOfRange));
+        assert_eq!(OrProof::from_bytes(&[0; 96]), Err(Error::InvalidLength(96)));
+    }
+
+    /// Proofs made with either witness have the same shape and are both accepting transcripts
+    /// in every position, so the branch is not visible in the proof
+    #[test]
+    fn or_proof_hides_branch() {
+        let mut rng = DeterministicRng::new(224);
+        let (x, y) = (Scalar::random(&mut rng), Scalar::random(&mut rng));
+        let first = pok::Statement::with_base(G, G * x);
+        let second = dleq::Statement::new(G, G * y, H, H * y);
+        let statements: [&dyn SigmaStatement; 2] = [&first, &second];
+        let proofs = [
+            or_prove(&mut transcript(), &statements, 0, &x, &mut rng).unwrap(),
+            or_prove(&mut transcript(), &statements, 1, &y, &mut rng).unwrap(),
+        ];
+        for proof in &proofs {
+            assert!(proof.verify(&mut transcript(), &statements));
+            assert_eq!(proof.serialize().len(), 128);
+            assert_eq!(proof.challenges.len(), 2);
+            assert!(proof.challenges.iter().chain(&proof.responses).all(|scalar| !scalar.is_zero()));
+        }
+        assert_ne!(proofs[0], proofs[1]);
+    }
+
+    #[test]
+    fn challenge_splitting() {
+        let mut rng = DeterministicRng::new(224);
+        let x = Scalar::random(&mut rng);
+        let known = pok::Statement::with_base(G, G * x);
+        let unknown = pok::Statement::with_base(G, H);
+        let statements: [&dyn SigmaStatement; 2] = [&unknown, &known];
+        let proof = or_prove(&mut transcript(), &statements, 1, &x, &mut rng).unwrap();
+        assert!(proof.verify(&mut transcript(), &statements));
+
+        // replay the transcript and check that the challenges split the challenge
+        let mut replayed = transcript();
+        replayed.append_message(COMPOSITION_LABEL, b"or");
+        replayed.append_message(COMPOSITION_LABEL, &2u64.to_be_bytes());
+        unknown.append_to_transcript(&mut replayed);
+        known.append_to_transcript(&mut replayed);
+        for (statement, (e, s)) in statements.iter().zip(proof.challenges.iter().zip(&proof.responses)) {
+            let commitments = statement.simulate(e, s);
+            assert_eq!(commitments.len(), 1);
+            replayed.append_point(COMMITMENT_LABEL, &commitments[0]);
+        }
+        let challenge = replayed.challenge_scalar(CHALLENGE_LABEL);
+        assert_eq!(proof.challenges[0] + proof.challenges[1], challenge);
+        // the real response is `k + e₁·x` with `k·G` as the commitment
+        assert_eq!(G * proof.responses[1] + -(G * x * proof.challenges[1]), known.simulate(&proof.challenges[1], &proof.responses[1])[0]);
+
+        // shifting the split keeps the sum but changes the commitments
+        let mut shifted = proof.clone();
+        shifted.challenges[0] += Scalar::ONE;
+        shifted.challenges[1] -= Scalar::ONE;
+        assert!(!shifted.verify(&mut transcript(), &statements));
+        // changing one challenge breaks the sum
+        let mut changed = proof.clone();
+        changed.challenges[0] += Scalar::ONE;
+        assert!(!changed.verify(&mut transcript(), &statements));
+        // a simulated proof of both statements doesn't sum to the challenge
+        let mut simulated = proof;
+        simulated.challenges[1] = Scalar::random(&mut rng);
+        simulated.responses[1] = Scalar::random(&mut rng);
+        assert!(!simulated.verify(&mut transcript(), &statements));
+    }
+}
@@ -0,0 +1,157 @@
+//! Messages signed by Bitcoin keys, compatible with `signmessage` and `verifymessage`
+//!
+//! The signed hash is `SHA256d("\x18Bitcoin Signed Message:\n" || varint(len) || msg)`. The
+//! signature is 65 bytes `header || r || s` encoded in base64 where `header` is
+//! `27 + recovery id`, plus 4 if the signer uses compressed public key. The verifier recovers
+//! the public key and compares its P2PKH address with the expected one.
+
+use alloc::string::String;
+use crate::address::p2pkh_address_to_hash160;
+use crate::ecdsa::{sign_recoverable, RecoverableSignature, RecoveryId};
+use crate::hashes::{hash160, sha256d};
+use crate::{base64, Error, Message, PublicKey, SecretKey};
+
+const MAGIC: &[u8] = b"\x18Bitcoin Signed Message:\n";
+
+/// The expected signer of the message
+#[derive(Copy, Clone, Debug)]
+pub enum Signer<'a> {
+    /// P2PKH address of either network, the key must be compressed if the signature says so
+    Address(&'a str),
+    /// The public key, regardless of compression
+    PublicKey(&'a PublicKey),
+    /// Anyone, the caller is expected to check the returned key
+    Any,
+}
+
+/// Computes the hash that is signed
+pub fn message_hash(msg: &str) -> [u8; 32] {
+    let mut preimage = MAGIC.to_vec();
+    let len = msg.len() as u64;
+    match len {
+        0..=0xFC => preimage.push(len as u8),
+        0xFD..=0xFFFF => {
+            preimage.push(0xFD);
+            preimage.extend_from_slice(&(len as u16).to_le_bytes());
+        },
+        0x10000..=0xFFFF_FFFF => {
+            preimage.push(0xFE);
+            preimage.extend_from_slice(&(len as u32).to_le_bytes());
+        },
+        _ => {
+            preimage.push(0xFF);
+            preimage.extend_from_slice(&len.to_le_bytes());
+        },
+    }
+    preimage.extend_from_slice(msg.as_bytes());
+    sha256d(&preimage)
+}
+
+/// Signs the message returning base64-encoded signature.
+///
+/// `compressed` selects which P2PKH address of the key the signature refers to.
+pub fn sign(msg: &str, secret_key: &SecretKey, compressed: bool) -> String {
+    let (compact, recovery_id) = sign_recoverable(&Message::from_digest(message_hash(msg)), secret_key).serialize_compact();
+    let mut bytes = [0; 65];
+    bytes[0] = 27 + recovery_id.to_u8() + if compressed { 4 } else { 0 };
+    bytes[1..].copy_from_slice(&compact);
+    base64::encode(&bytes)
+}
+
+/// Verifies the base64-encoded signature of the message returning the recovered public key.
+///
+/// Returns `Error::InvalidSignature` if the signature is malformed, doesn't match or the
+/// recovered key is not the expected signer. Errors of decoding base64 and parsing the address
+/// are returned as-is.
+pub fn verify(msg: &str, signer: Signer<'_>, signature: &str) -> Result<PublicKey, Error> {
+    let bytes = base64::decode(signature)?;
+    if bytes.len() != 65 {
+        return Err(Error::InvalidLength(bytes.len()));
+    }
+    let (recovery_id, compressed) = match bytes[0] {
+        header @ 27..=30 => (header - 27, false),
+        header @ 31..=34 => (header - 31, true),
+        _ => return Err(Error::InvalidSignature),
+    };
+    let recovery_id = RecoveryId::from_u8(recovery_id)?;
+    let mut compact = [0; 64];
+    compact.copy_from_slice(&bytes[1..]);
+    let public_key = RecoverableSignature::from_compact(&compact, recovery_id)
+        .and_then(|signature| signature.recover(&Message::from_digest(message_hash(msg))))
+        .map_err(|_| Error::InvalidSignature)?;
+
+    let matches = match signer {
+        Signer::Address(address) => {
+            let (_, expected) = p2pkh_address_to_hash160(address)?;
+            let hash = if compressed {
+                hash160(&public_key.serialize())
+            } else {
+                hash160(&public_key.serialize_uncompressed())
+            };
+            hash == expected
+        },
+        Signer::PublicKey(expected) => public_key == *expected,
+        Signer::Any => true,
+    };
+    if matches {
+        Ok(public_key)
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sign, verify, Signer};
+    use crate::{base58, Error, Network, SecretKey};
+
+    /// Decodes WIF of compressed key
+    fn wif(string: &str) -> SecretKey {
+        let payload = base58::decode_check(string).unwrap();
+        assert_eq!(payload.len(), 34);
+        assert_eq!(payload[33], 0x01);
+        SecretKey::from_bytes(crate::array_ref_32(&payload[1..33])).unwrap()
+    }
+
+    /// The vector from `rpc_signmessagewithprivkey.py` functional test of Bitcoin Core
+    #[test]
+    fn bitcoin_core_vector() {
+        let secret_key = wif("cUeKHd5orzT3mz8P9pxyREHfsWtVfgsfDjiZZBcjUBAaGk1BTj7N");
+        let address = "mpLQjfK79b7CCV4VMJWEWAj5Mpx8Up5zxB";
+        let msg = "This is just a test message";
+        let signature = "INbVnW4e6PeRmsv2Qgu8NuopvrVjkcxob+sX8OcZG0SALhWybUjzMLPdAsXI46YZGb0KQTRii+wWIQzRpG/U+S0=";
+        assert_eq!(secret_key.public_key().p2pkh_address(Network::Testnet), address);
+        assert_eq!(verify(msg, Signer::Address(address), signature), Ok(secret_key.public_key()));
+        // signing is deterministic so the output matches Bitcoin Core
+        assert_eq!(sign(msg, &secret_key, true), signature);
+        assert_eq!(verify("This is just a test message.", Signer::Address(address), signature), Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn compression_flag() {
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let public_key = secret_key.public_key();
+        let compressed = public_key.p2pkh_address(Network::Bitcoin);
+        let uncompressed = public_key.p2pkh_address_uncompressed(Network::Bitcoin);
+        let signature = sign("", &secret_key, false);
+        assert_eq!(verify("", Signer::Address(&uncompressed), &signature), Ok(public_key));
+        assert_eq!(verify("", Signer::Address(&compressed), &signature), Err(Error::InvalidSignature));
+        assert_eq!(verify("", Signer::PublicKey(&public_key), &signature), Ok(public_key));
+        let signature = sign("", &secret_key, true);
+        assert_eq!(verify("", Signer::Address(&compressed), &signature), Ok(public_key));
+        assert_eq!(verify("", Signer::Any, &signature), Ok(public_key));
+        let other = SecretKey::from_bytes(&[0x43; 32]).unwrap().public_key();
+        assert_eq!(verify("", Signer::PublicKey(&other), &signature), Err(Error::InvalidSignature));
+        assert_eq!(verify("", Signer::Any, &signature[4..]), Err(Error::InvalidLength(62)));
+    }
+
+    #[test]
+    fn long_message() {
+        // exercises the 3-byte varint
+        let msg = "a".repeat(300);
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let signature = sign(&msg, &secret_key, true);
+        assert_eq!(verify(&msg, Signer::Any, &signature), Ok(secret_key.public_key()));
+        assert_ne!(super::message_hash(&msg), super::message_hash(&msg[1..]));
+    }
+}
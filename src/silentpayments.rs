@@ -0,0 +1,282 @@
+//! BIP352 silent payments
+//!
+//! Only the cryptographic core is implemented: the caller is responsible for selecting eligible
+//! inputs, extracting their keys and encoding addresses. Outpoints are passed serialized the way
+//! they appear in transactions: 32-byte txid in internal byte order followed by 4-byte
+//! little-endian output index.
+//!
+//! The sender computes `input_hash = hash_BIP0352/Inputs(outpoint_L || A)` where `outpoint_L` is
+//! the lexicographically smallest outpoint and `A` the sum of input public keys, the shared
+//! secret `S = input_hash·a·B_scan` and the outputs
+//! `P_k = B_m + hash_BIP0352/SharedSecret(S || ser32(k))·G`. The receiver computes the same
+//! shared secret as `input_hash·b_scan·A`.
+
+use alloc::vec::Vec;
+use alloc::collections::BTreeMap;
+use crate::tagged_hash::TaggedHash;
+use crate::keys::Parity;
+use crate::{Error, Point, PublicKey, Scalar, SecretKey, XOnlyPoint, G};
+
+const INPUTS_TAG: &str = "BIP0352/Inputs";
+const SHARED_SECRET_TAG: &str = "BIP0352/SharedSecret";
+const LABEL_TAG: &str = "BIP0352/Label";
+
+/// Key of a spent input
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum InputKey {
+    /// Key of a non-taproot input used with its full public key
+    Plain(SecretKey),
+    /// Key of a taproot input, negated if needed so that the public key has even y
+    Taproot(SecretKey),
+}
+
+impl InputKey {
+    fn to_scalar(self) -> Scalar {
+        match self {
+            InputKey::Plain(secret_key) => secret_key.to_scalar(),
+            InputKey::Taproot(secret_key) => match secret_key.public_key().x_only_public_key().1 {
+                Parity::Even => secret_key.to_scalar(),
+                Parity::Odd => -secret_key.to_scalar(),
+            },
+        }
+    }
+}
+
+/// Silent payment address - the scan and spend public keys of the recipient
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Address {
+    scan_key: PublicKey,
+    spend_key: PublicKey,
+}
+
+impl Address {
+    pub fn new(scan_key: PublicKey, spend_key: PublicKey) -> Self {
+        Address { scan_key, spend_key }
+    }
+
+    pub fn scan_key(&self) -> &PublicKey {
+        &self.scan_key
+    }
+
+    pub fn spend_key(&self) -> &PublicKey {
+        &self.spend_key
+    }
+
+    /// Returns the address with the spend key labeled: `B_m = B_spend + label·G`
+    ///
+    /// The label is normally computed using `label_tweak`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the labeled key is the point at infinity which happens with negligible
+    /// probability.
+    pub fn with_label(&self, label: &Scalar) -> Self {
+        let spend_key = PublicKey::from_point(self.spend_key.to_point() + G * *label)
+            .expect("the probability of the label being the negated spend key is negligible");
+        Address { scan_key: self.scan_key, spend_key }
+    }
+}
+
+/// Computes the tweak of label `m`: `hash_BIP0352/Label(ser256(b_scan) || ser32(m))`
+///
+/// Label 0 is reserved for change.
+pub fn label_tweak(scan_key: &SecretKey, m: u32) -> Scalar {
+    Scalar::from_be_bytes_reduce(&TaggedHash::new(LABEL_TAG).hash(&[&scan_key.to_bytes(), &m.to_be_bytes()]))
+}
+
+/// Computes `hash_BIP0352/Inputs(outpoint_L || A)`
+///
+/// # Panics
+///
+/// Panics if `outpoints` is empty.
+pub fn input_hash(outpoints: &[[u8; 36]], input_key_sum: &PublicKey) -> Scalar {
+    let smallest = outpoints.iter().min().expect("a transaction has at least one input");
+    Scalar::from_be_bytes_reduce(&TaggedHash::new(INPUTS_TAG).hash(&[smallest, &input_key_sum.serialize()]))
+}
+
+fn output_tweak(shared_secret: &Point, k: u32) -> Scalar {
+    Scalar::from_be_bytes_reduce(&TaggedHash::new(SHARED_SECRET_TAG).hash(&[&shared_secret.serialize_compressed(), &k.to_be_bytes()]))
+}
+
+/// Computes the output keys paying to `recipients`, in the same order.
+///
+/// Returns `Error::InvalidSecretKey` if the input keys sum up to zero.
+///
+/// # Panics
+///
+/// Panics if `outpoints` is empty.
+pub fn sender_derive_outputs(inputs: &[InputKey], outpoints: &[[u8; 36]], recipients: &[Address]) -> Result<Vec<XOnlyPoint>, Error> {
+    let input_sum = inputs.iter().fold(Scalar::ZERO, |sum, input| sum + input.to_scalar());
+    let input_sum = SecretKey::from_scalar(input_sum).ok_or(Error::InvalidSecretKey)?;
+    let tweak = input_hash(outpoints, &input_sum.public_key()) * input_sum.to_scalar();
+
+    let mut counters = BTreeMap::new();
+    let outputs = recipients
+        .iter()
+        .map(|recipient| {
+            let k = counters.entry(recipient.scan_key.to_point()).or_insert(0u32);
+            let shared_secret = recipient.scan_key.to_point() * tweak;
+            let output = recipient.spend_key.to_point() + G * output_tweak(&shared_secret, *k);
+            *k += 1;
+            XOnlyPoint::from_point(output)
+                .expect("the probability of the tweak being the negated spend key is negligible")
+                .0
+        })
+        .collect();
+    Ok(outputs)
+}
+
+/// An output found by the receiver
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct FoundOutput {
+    output: XOnlyPoint,
+    tweak: Scalar,
+    label: Option<u32>,
+}
+
+impl FoundOutput {
+    pub fn output(&self) -> &XOnlyPoint {
+        &self.output
+    }
+
+    /// The value to add to the spend secret key to obtain the secret key of the output
+    ///
+    /// Includes the label tweak if the output pays to a labeled address.
+    pub fn tweak(&self) -> Scalar {
+        self.tweak
+    }
+
+    /// The label `m` of the address the output pays to
+    pub fn label(&self) -> Option<u32> {
+        self.label
+    }
+
+    /// Computes the secret key of the output.
+    ///
+    /// The result should be used with BIP340 signing which negates it if needed.
+    ///
+    /// Returns `Error::InvalidTweak` if the result is zero.
+    pub fn secret_key(&self, spend_key: &SecretKey) -> Result<SecretKey, Error> {
+        SecretKey::from_scalar(spend_key.to_scalar() + self.tweak).ok_or(Error::InvalidTweak)
+    }
+}
+
+/// Finds outputs paying to the receiver among `outputs`.
+///
+/// `input_key_sum` is the sum of public keys of eligible inputs with taproot keys taken with
+/// even y and `labels` are the labels `m` the receiver wants to scan for.
+///
+/// # Panics
+///
+/// Panics if `outpoints` is empty.
+pub fn receiver_scan(scan_key: &SecretKey, spend_key: &PublicKey, labels: &[u32], input_key_sum: &PublicKey, outpoints: &[[u8; 36]], outputs: &[XOnlyPoint]) -> Vec<FoundOutput> {
+    let shared_secret = input_key_sum.to_point() * (input_hash(outpoints, input_key_sum) * scan_key.to_scalar());
+    let candidates = core::iter::once((None, Scalar::ZERO))
+        .chain(labels.iter().map(|m| (Some(*m), label_tweak(scan_key, *m))))
+        .collect::<Vec<_>>();
+
+    let mut remaining = outputs.to_vec();
+    let mut found = Vec::new();
+    for k in 0u32.. {
+        let t_k = output_tweak(&shared_secret, k);
+        let base = spend_key.to_point() + G * t_k;
+        let matched = candidates.iter().find_map(|(label, label_tweak)| {
+            let candidate = XOnlyPoint::from_point(base + G * *label_tweak)?.0;
+            let position = remaining.iter().position(|output| output.to_point().eq_ct(&candidate.to_point()))?;
+            Some((position, *label, t_k + *label_tweak))
+        });
+        match matched {
+            Some((position, label, tweak)) => found.push(FoundOutput { output: remaining.swap_remove(position), tweak, label }),
+            None => break,
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{label_tweak, receiver_scan, sender_derive_outputs, Address, InputKey};
+    use crate::test_util::hex32;
+    use crate::{PublicKey, SecretKey, XOnlyPoint};
+
+    fn outpoint(txid: &str, vout: u32) -> [u8; 36] {
+        let mut outpoint = [0; 36];
+        outpoint[..32].copy_from_slice(&hex32(txid));
+        outpoint[..32].reverse();
+        outpoint[32..].copy_from_slice(&vout.to_le_bytes());
+        outpoint
+    }
+
+    fn secret_key(hex: &str) -> SecretKey {
+        SecretKey::from_bytes(&hex32(hex)).unwrap()
+    }
+
+    fn receiver() -> (SecretKey, SecretKey) {
+        (
+            secret_key("0f694e068028a717f8af6b9411f9a133dd3565258714cc226594b34db90c1f2c"),
+            secret_key("9d6ad855ce3417ef84e836892e5a56392bfba05fa5d97ccea30e266f540e08b3"),
+        )
+    }
+
+    fn check_vector(inputs: &[(&str, u32, &str)], expected: &str) {
+        let (scan_key, spend_key) = receiver();
+        let address = Address::new(scan_key.public_key(), spend_key.public_key());
+        let outpoints = inputs.iter().map(|(txid, vout, _)| outpoint(txid, *vout)).collect::<Vec<_>>();
+        let keys = inputs.iter().map(|(_, _, key)| InputKey::Plain(secret_key(key))).collect::<Vec<_>>();
+        let outputs = sender_derive_outputs(&keys, &outpoints, &[address]).unwrap();
+        let expected = XOnlyPoint::from_bytes(&hex32(expected)).unwrap();
+        assert_eq!(outputs, [expected]);
+
+        let input_key_sum = keys.iter().map(|key| key.to_scalar()).fold(crate::Scalar::ZERO, |a, b| a + b);
+        let input_key_sum = SecretKey::from_scalar(input_key_sum).unwrap().public_key();
+        let found = receiver_scan(&scan_key, &spend_key.public_key(), &[], &input_key_sum, &outpoints, &outputs);
+        assert_eq!(found.len(), 1);
+        assert_eq!(*found[0].output(), expected);
+        assert_eq!(found[0].label(), None);
+        let output_key = found[0].secret_key(&spend_key).unwrap().public_key();
+        assert_eq!(output_key.x_only_public_key().0, expected);
+    }
+
+    #[test]
+    fn bip352_simple_send() {
+        let first = ("f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16", 0, "eadc78165ff1f8ea94ad7cfdc54990738a4c53f6e0507b42154201b8e5dff3b1");
+        let second = ("a1075db55d416d3ca199f55b6084e2115b9345e16c5cf302fc80e9d5fbf5d48d", 0, "93f5ed907ad5b2bdbbdcb5d9116ebc0a4e1f92f910d5260237fa45a9408aad16");
+        check_vector(&[first, second], "3e9fce73d4e77a4809908e3c3a2e54ee147b9312dc5044a193d1fc85de46e3c1");
+        // order of inputs doesn't matter
+        check_vector(&[second, first], "3e9fce73d4e77a4809908e3c3a2e54ee147b9312dc5044a193d1fc85de46e3c1");
+    }
+
+    #[test]
+    fn bip352_same_transaction() {
+        let txid = "f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16";
+        let first = (txid, 3, "eadc78165ff1f8ea94ad7cfdc54990738a4c53f6e0507b42154201b8e5dff3b1");
+        let second = (txid, 7, "93f5ed907ad5b2bdbbdcb5d9116ebc0a4e1f92f910d5260237fa45a9408aad16");
+        check_vector(&[first, second], "79e71baa2ba3fc66396de3a04f168c7bf24d6870ec88ca877754790c1db357b6");
+    }
+
+    #[test]
+    fn labels_and_multiple_outputs() {
+        let (scan_key, spend_key) = receiver();
+        let address = Address::new(scan_key.public_key(), spend_key.public_key());
+        let labeled = address.with_label(&label_tweak(&scan_key, 1));
+        let inputs = [InputKey::Taproot(secret_key("eadc78165ff1f8ea94ad7cfdc54990738a4c53f6e0507b42154201b8e5dff3b1"))];
+        let outpoints = [[0x42; 36], [0x21; 36]];
+        let outputs = sender_derive_outputs(&inputs, &outpoints, &[address, labeled, address]).unwrap();
+        assert_eq!(outputs.len(), 3);
+        assert_ne!(outputs[0], outputs[2]);
+
+        let input_key_sum = PublicKey::from_point(crate::G * inputs[0].to_scalar()).unwrap();
+        let found = receiver_scan(&scan_key, &spend_key.public_key(), &[1], &input_key_sum, &outpoints, &outputs);
+        assert_eq!(found.len(), 3);
+        for found in &found {
+            let index = outputs.iter().position(|output| output == found.output()).unwrap();
+            assert_eq!(found.label(), if index == 1 { Some(1) } else { None });
+            assert_eq!(found.secret_key(&spend_key).unwrap().public_key().x_only_public_key().0, outputs[index]);
+        }
+
+        // without scanning for the label the outputs after the labeled one are missed
+        let found = receiver_scan(&scan_key, &spend_key.public_key(), &[], &input_key_sum, &outpoints, &outputs);
+        assert_eq!(found.len(), 1);
+        assert_eq!(*found[0].output(), outputs[0]);
+    }
+}
@@ -0,0 +1,189 @@
+//! AVX2 backend of `Zp::mul_many`
+//!
+//! Four field elements are multiplied at once. The elements are split into ten 26-bit limbs and
+//! each 64-bit lane of a vector holds the limb of one element so `_mm256_mul_epu32` computes four
+//! 52-bit limb products per instruction. The columns of the product are summed without carries,
+//! then the limbs above `2²⁶⁰` are folded back using `2²⁶⁰ ≡ 0x400·2²⁶ + 0x3D10 (mod p)`, the bits
+//! above `2²⁵⁶` using `2²⁵⁶ ≡ 0x40·2²⁶ + 0x3D1` and `p` is subtracted if needed.
+//!
+//! This is the only module of the field arithmetic containing `unsafe` code and it's confined to
+//! the intrinsics, the invariants are documented at `mul4_avx2`. This is **NOT CONSTANT
+//! TIME!!!**
+
+use core::arch::x86_64::*;
+use crate::field::reduce_once;
+use crate::U256;
+
+const MASK: i64 = (1 << 26) - 1;
+
+/// Multiplies four pairs of reduced field elements.
+///
+/// Returns `None` if the CPU doesn't support AVX2.
+pub(crate) fn mul4(a: &[U256; 4], b: &[U256; 4]) -> Option<[U256; 4]> {
+    if !std::is_x86_feature_detected!("avx2") {
+        return None;
+    }
+    // SAFETY: AVX2 support was checked above and the elements are reduced
+    Some(unsafe { mul4_avx2(a, b) })
+}
+
+/// Shifts each lane right by `bits`
+#[target_feature(enable = "avx2")]
+unsafe fn shr(value: __m256i, bits: usize) -> __m256i {
+    _mm256_srl_epi64(value, _mm_cvtsi64_si128(bits as i64))
+}
+
+/// Shifts each lane left by `bits`
+#[target_feature(enable = "avx2")]
+unsafe fn shl(value: __m256i, bits: usize) -> __m256i {
+    _mm256_sll_epi64(value, _mm_cvtsi64_si128(bits as i64))
+}
+
+/// Splits the 64-bit words of the elements into ten 26-bit limbs, the last one has 22 bits
+#[target_feature(enable = "avx2")]
+unsafe fn to_limbs(elements: &[U256; 4]) -> [__m256i; 10] {
+    let mut words = [_mm256_setzero_si256(); 4];
+    for (k, word) in words.iter_mut().enumerate() {
+        *word = _mm256_set_epi64x(elements[3].0[k] as i64, elements[2].0[k] as i64, elements[1].0[k] as i64, elements[0].0[k] as i64);
+    }
+    let mask = _mm256_set1_epi64x(MASK);
+    let mut limbs = [_mm256_setzero_si256(); 10];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let (word, offset) = (26 * i / 64, 26 * i % 64);
+        let mut bits = shr(words[word], offset);
+        if offset > 38 && word < 3 {
+            bits = _mm256_or_si256(bits, shl(words[word + 1], 64 - offset));
+        }
+        *limb = _mm256_and_si256(bits, mask);
+    }
+    limbs
+}
+
+/// Joins ten 26-bit limbs into the 64-bit words, returns the bits above `2²⁵⁶` separately
+#[target_feature(enable = "avx2")]
+unsafe fn from_limbs(limbs: &[__m256i; 11]) -> ([__m256i; 4], __m256i) {
+    let mut words = [_mm256_setzero_si256(); 4];
+    for (k, word) in words.iter_mut().enumerate() {
+        for (i, limb) in limbs[..10].iter().enumerate() {
+            let (start, end) = (26 * i, 26 * i + 26);
+            if start >= 64 * k && start < 64 * k + 64 {
+                *word = _mm256_or_si256(*word, shl(*limb, start - 64 * k));
+            } else if start < 64 * k && end > 64 * k {
+                *word = _mm256_or_si256(*word, shr(*limb, 64 * k - start));
+            }
+        }
+    }
+    let overflow = _mm256_add_epi64(_mm256_srli_epi64::<22>(limbs[9]), _mm256_slli_epi64::<4>(limbs[10]));
+    (words, overflow)
+}
+
+/// Propagates the carries so that all limbs except the last one have 26 bits
+#[target_feature(enable = "avx2")]
+unsafe fn carry(limbs: &mut [__m256i]) {
+    let mask = _mm256_set1_epi64x(MASK);
+    for i in 1..limbs.len() {
+        let carry = _mm256_srli_epi64::<26>(limbs[i - 1]);
+        limbs[i - 1] = _mm256_and_si256(limbs[i - 1], mask);
+        limbs[i] = _mm256_add_epi64(limbs[i], carry);
+    }
+}
+
+/// Multiplies the elements.
+///
+/// # Safety
+///
+/// The CPU must support AVX2.
+///
+/// The elements must be reduced so that no lane overflows: the limbs are less than 2²⁶, the
+/// columns of the product are sums of at most ten 52-bit products (< 2⁵⁶), after the first carry
+/// propagation the limbs are less than 2²⁶ except the last one which is less than 2¹⁸ (the product
+/// has 512 bits) so the folded limbs are less than 2⁴¹ and the next carries less than 2¹⁶.
+/// `_mm256_mul_epu32` only reads the low 32 bits of the lanes so all multiplied limbs must be
+/// less than 2³², which they are. The final value is less than `2²⁵⁶ + 2³⁸ < 2p` so subtracting
+/// `p` once reduces it.
+#[target_feature(enable = "avx2")]
+unsafe fn mul4_avx2(a: &[U256; 4], b: &[U256; 4]) -> [U256; 4] {
+    let x = to_limbs(a);
+    let y = to_limbs(b);
+
+    // t[19] only receives the final carry
+    let mut t = [_mm256_setzero_si256(); 20];
+    for (i, x) in x.iter().enumerate() {
+        for (j, y) in y.iter().enumerate() {
+            t[i + j] = _mm256_add_epi64(t[i + j], _mm256_mul_epu32(*x, *y));
+        }
+    }
+    carry(&mut t);
+
+    // going down so that t[10] receives the part of t[19] before it's folded itself
+    let low_factor = _mm256_set1_epi64x(0x3D10);
+    let high_factor = _mm256_set1_epi64x(0x400);
+    for k in (10..20).rev() {
+        t[k - 10] = _mm256_add_epi64(t[k - 10], _mm256_mul_epu32(t[k], low_factor));
+        t[k - 9] = _mm256_add_epi64(t[k - 9], _mm256_mul_epu32(t[k], high_factor));
+    }
+    t[10] = _mm256_setzero_si256();
+    carry(&mut t[..11]);
+    // fold the carry out of t[9] once more
+    t[0] = _mm256_add_epi64(t[0], _mm256_mul_epu32(t[10], low_factor));
+    t[1] = _mm256_add_epi64(t[1], _mm256_mul_epu32(t[10], high_factor));
+    t[10] = _mm256_setzero_si256();
+    carry(&mut t[..11]);
+    // and the bits above 2²⁵⁶
+    let high = _mm256_add_epi64(_mm256_srli_epi64::<22>(t[9]), _mm256_slli_epi64::<4>(t[10]));
+    t[9] = _mm256_and_si256(t[9], _mm256_set1_epi64x((1 << 22) - 1));
+    t[0] = _mm256_add_epi64(t[0], _mm256_mul_epu32(high, _mm256_set1_epi64x(0x3D1)));
+    t[1] = _mm256_add_epi64(t[1], _mm256_mul_epu32(high, _mm256_set1_epi64x(0x40)));
+    t[10] = _mm256_setzero_si256();
+    carry(&mut t[..11]);
+
+    let mut limbs = [_mm256_setzero_si256(); 11];
+    limbs.copy_from_slice(&t[..11]);
+    let (words, overflow) = from_limbs(&limbs);
+    let mut lanes = [[0u64; 4]; 5];
+    for (lane, word) in lanes.iter_mut().zip(words.iter().chain(Some(&overflow))) {
+        _mm256_storeu_si256(lane.as_mut_ptr() as *mut __m256i, *word);
+    }
+    let mut res = [U256::zero(); 4];
+    for (i, element) in res.iter_mut().enumerate() {
+        *element = reduce_once(U256([lanes[0][i], lanes[1][i], lanes[2][i], lanes[3][i]]), lanes[4][i] != 0);
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mul4;
+    use crate::field::mul;
+    use crate::test_utils::DeterministicRng;
+    use crate::params::P;
+    use crate::U256;
+    use rand_core::RngCore;
+
+    fn random(rng: &mut DeterministicRng) -> U256 {
+        let mut bytes = [0; 32];
+        rng.fill_bytes(&mut bytes);
+        let value = U256::from_be_bytes(&bytes);
+        if value < P { value } else { value - P }
+    }
+
+    #[test]
+    fn matches_scalar_backend() {
+        if !std::is_x86_feature_detected!("avx2") {
+            return;
+        }
+        let mut rng = DeterministicRng::new(219);
+        let p_minus = |n: u64| P - U256::from(n);
+        let edge = [U256::zero(), U256::one(), p_minus(1), p_minus(2), U256::one() << 255, U256::max_value() >> 1];
+        let mut cases = edge.iter().flat_map(|a| edge.iter().map(move |b| (*a, *b))).collect::<Vec<_>>();
+        cases.extend((0..4000).map(|_| (random(&mut rng), random(&mut rng))));
+        for chunk in cases.chunks_exact(4) {
+            let a = [chunk[0].0, chunk[1].0, chunk[2].0, chunk[3].0];
+            let b = [chunk[0].1, chunk[1].1, chunk[2].1, chunk[3].1];
+            let products = mul4(&a, &b).unwrap();
+            for ((a, b), product) in a.iter().zip(&b).zip(&products) {
+                assert_eq!(*product, mul(*a, *b), "{:?} · {:?}", a, b);
+            }
+        }
+    }
+}
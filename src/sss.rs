@@ -0,0 +1,300 @@
+//! Shamir's secret sharing over the scalar field
+//!
+//! The secret is the constant term of a random polynomial of degree `threshold - 1`, share `i`
+//! is the value of the polynomial at `i`. Any `threshold` shares determine the polynomial and
+//! thus the secret while fewer shares reveal nothing about it.
+//!
+//! Shares can be refreshed without reconstructing the secret by adding shares of zero to them.
+//! The refreshed shares are shares of the same secret but they can't be combined with the old
+//! ones so shares leaked before the refresh become useless.
+
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use crate::frost::lagrange_coefficient;
+use crate::{array_ref_32, Error, Point, Scalar};
+
+/// One share of the secret
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Share {
+    index: u16,
+    threshold: u16,
+    value: Scalar,
+}
+
+impl Share {
+    pub(crate) fn new(index: u16, threshold: u16, value: Scalar) -> Self {
+        Share { index, threshold, value }
+    }
+
+    /// Index of the share - the point at which the polynomial was evaluated, starting from 1
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// The number of shares needed to reconstruct the secret
+    pub fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    pub fn to_scalar(&self) -> Scalar {
+        self.value
+    }
+
+    /// Parses 36 bytes: big-endian index, big-endian threshold and the value.
+    ///
+    /// Returns `Error::InvalidShare` if the index or threshold is zero or the value is not less
+    /// than the curve order.
+    pub fn from_bytes(bytes: &[u8; 36]) -> Result<Self, Error> {
+        let index = u16::from_be_bytes([bytes[0], bytes[1]]);
+        let threshold = u16::from_be_bytes([bytes[2], bytes[3]]);
+        if index == 0 || threshold == 0 {
+            return Err(Error::InvalidShare(index));
+        }
+        let value = Scalar::from_be_bytes(array_ref_32(&bytes[4..])).ok_or(Error::InvalidShare(index))?;
+        Ok(Share { index, threshold, value })
+    }
+
+    pub fn to_bytes(&self) -> [u8; 36] {
+        let mut bytes = [0; 36];
+        bytes[..2].copy_from_slice(&self.index.to_be_bytes());
+        bytes[2..4].copy_from_slice(&self.threshold.to_be_bytes());
+        bytes[4..].copy_from_slice(&self.value.to_be_bytes());
+        bytes
+    }
+
+    /// Adds a share of zero received from a dealer of `zero_sharing` to this share.
+    ///
+    /// In the distributed model each participant deals a sharing of zero and every participant
+    /// adds the sub-shares it received from all dealers. The sub-shares should be verified with
+    /// `Share::verify` and the first commitment checked to be the point at infinity before
+    /// adding them.
+    ///
+    /// Returns `Error::InvalidShare` if the sub-share has a different index or threshold.
+    pub fn add_zero_share(&self, zero_share: &Share) -> Result<Share, Error> {
+        if zero_share.index != self.index || zero_share.threshold != self.threshold {
+            return Err(Error::InvalidShare(zero_share.index));
+        }
+        Ok(Share { value: self.value + zero_share.value, ..*self })
+    }
+}
+
+/// Doesn't print the value so that it doesn't leak into logs
+impl core::fmt::Debug for Share {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Share {{ index: {}, threshold: {}, .. }}", self.index, self.threshold)
+    }
+}
+
+/// Random polynomial of degree `threshold - 1` with `secret` as the constant term
+pub(crate) fn random_polynomial<R: RngCore + CryptoRng>(secret: Scalar, threshold: u16, rng: &mut R) -> Vec<Scalar> {
+    core::iter::once(secret).chain((1..threshold).map(|_| Scalar::random(rng))).collect()
+}
+
+/// Evaluates the polynomial at `index` using Horner's method
+pub(crate) fn evaluate(coefficients: &[Scalar], index: u16) -> Scalar {
+    let x = Scalar::from(u64::from(index));
+    coefficients.iter().rev().fold(Scalar::ZERO, |acc, coefficient| acc * x + *coefficient)
+}
+
+/// Splits the secret into `shares` shares, any `threshold` of which can reconstruct it.
+///
+/// Returns `Error::InvalidThreshold` if `threshold` is zero or greater than `shares`.
+pub fn split<R: RngCore + CryptoRng>(secret: Scalar, threshold: u16, shares: u16, rng: &mut R) -> Result<Vec<Share>, Error> {
+    if threshold == 0 || threshold > shares {
+        return Err(Error::InvalidThreshold);
+    }
+    let coefficients = random_polynomial(secret, threshold, rng);
+    Ok((1..=shares).map(|index| Share::new(index, threshold, evaluate(&coefficients, index))).collect())
+}
+
+/// Deals a sharing of zero for refreshing shares with indices `1..=shares`.
+///
+/// Returns the shares and Feldman commitments to the polynomial (see the `vss` module), the
+/// first commitment is always the point at infinity which lets the recipients check that the
+/// dealer really shared zero.
+///
+/// Returns `Error::InvalidThreshold` if `threshold` is zero or greater than `shares`.
+pub fn zero_sharing<R: RngCore + CryptoRng>(threshold: u16, shares: u16, rng: &mut R) -> Result<(Vec<Share>, Vec<Point>), Error> {
+    crate::vss::split(Scalar::ZERO, threshold, shares, rng)
+}
+
+/// Refreshes the shares in place by adding a random sharing of zero evaluated at their indices.
+///
+/// This is the trusted-coordinator model where a single party holds (or receives) all the
+/// shares, see `zero_sharing` and `Share::add_zero_share` for the distributed one. The secret
+/// is unchanged but the shares can't be combined with the shares from before the refresh. With
+/// threshold 1 the shares are the secret itself so they stay the same.
+///
+/// Returns `Error::InvalidThreshold` if `threshold` is zero and `Error::InvalidShare` if a share
+/// has a different threshold.
+pub fn refresh<R: RngCore + CryptoRng>(shares: &mut [Share], threshold: u16, rng: &mut R) -> Result<(), Error> {
+    if threshold == 0 {
+        return Err(Error::InvalidThreshold);
+    }
+    if let Some(share) = shares.iter().find(|share| share.threshold != threshold) {
+        return Err(Error::InvalidShare(share.index));
+    }
+    let coefficients = random_polynomial(Scalar::ZERO, threshold, rng);
+    for share in shares {
+        share.value += evaluate(&coefficients, share.index);
+    }
+    Ok(())
+}
+
+/// Interpolates the polynomial at zero without any checks
+fn interpolate(shares: &[Share]) -> Scalar {
+    let indices = shares.iter().map(Share::index).collect::<Vec<_>>();
+    shares
+        .iter()
+        .map(|share| lagrange_coefficient(share.index, &indices) * share.value)
+        .fold(Scalar::ZERO, |acc, value| acc + value)
+}
+
+/// Reconstructs the secret using Lagrange interpolation at zero.
+///
+/// Returns `Error::DuplicateIndex` if two shares have the same index, `Error::InvalidShare` if
+/// the shares disagree on the threshold and `Error::NotEnoughShares` if there are fewer shares
+/// than the threshold. Inconsistent shares can't be detected and produce a wrong secret, see
+/// the `vss` module for verifiable shares.
+pub fn reconstruct(shares: &[Share]) -> Result<Scalar, Error> {
+    let threshold = match shares.first() {
+        Some(share) => share.threshold,
+        None => return Err(Error::NotEnoughShares(0)),
+    };
+    for (i, share) in shares.iter().enumerate() {
+        if share.threshold != threshold {
+            return Err(Error::InvalidShare(share.index));
+        }
+        if shares[..i].iter().any(|other| other.index == share.index) {
+            return Err(Error::DuplicateIndex(share.index));
+        }
+    }
+    if shares.len() < usize::from(threshold) {
+        return Err(Error::NotEnoughShares(shares.len()));
+    }
+    Ok(interpolate(shares))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{interpolate, reconstruct, refresh, split, zero_sharing, Share};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, Point, Scalar, G};
+
+    #[test]
+    fn threshold_subsets() {
+        let mut rng = DeterministicRng::new(1);
+        let secret = Scalar::random(&mut rng);
+        let shares = split(secret, 3, 5, &mut rng).unwrap();
+        assert_eq!(shares.len(), 5);
+        for i in 0..5 {
+            for j in (i + 1)..5 {
+                for k in (j + 1)..5 {
+                    assert_eq!(reconstruct(&[shares[k], shares[i], shares[j]]), Ok(secret), "shares {} {} {}", i, j, k);
+                }
+            }
+        }
+        assert_eq!(reconstruct(&shares), Ok(secret));
+    }
+
+    #[test]
+    fn fewer_shares_reveal_nothing() {
+        let mut rng = DeterministicRng::new(2);
+        let secret = Scalar::from(42);
+        // with a fresh polynomial each time the interpolation of t - 1 shares is random
+        for _ in 0..8 {
+            let shares = split(secret, 3, 5, &mut rng).unwrap();
+            assert_ne!(interpolate(&shares[..2]), secret);
+            assert_ne!(interpolate(&shares[2..4]), secret);
+            assert_eq!(reconstruct(&shares[..2]), Err(Error::NotEnoughShares(2)));
+        }
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        let mut rng = DeterministicRng::new(3);
+        let secret = Scalar::random(&mut rng);
+        assert_eq!(split(secret, 4, 3, &mut rng), Err(Error::InvalidThreshold));
+        assert_eq!(split(secret, 0, 3, &mut rng), Err(Error::InvalidThreshold));
+        let shares = split(secret, 2, 3, &mut rng).unwrap();
+        assert_eq!(reconstruct(&[shares[1], shares[1]]), Err(Error::DuplicateIndex(2)));
+        assert_eq!(reconstruct(&[]), Err(Error::NotEnoughShares(0)));
+        let other = split(secret, 3, 3, &mut rng).unwrap();
+        assert_eq!(reconstruct(&[shares[0], other[1]]), Err(Error::InvalidShare(2)));
+        // a single share of 1-of-n sharing is the secret itself
+        assert_eq!(reconstruct(&split(secret, 1, 3, &mut rng).unwrap()[2..]), Ok(secret));
+    }
+
+    #[test]
+    fn serialization_round_trip() {
+        let mut rng = DeterministicRng::new(4);
+        let shares = split(Scalar::random(&mut rng), 2, 300, &mut rng).unwrap();
+        for share in &shares[..2] {
+            assert_eq!(Share::from_bytes(&share.to_bytes()), Ok(*share));
+        }
+        let last = shares[299];
+        assert_eq!(last.to_bytes()[..4], [0x01, 0x2c, 0x00, 0x02]);
+        assert_eq!(Share::from_bytes(&last.to_bytes()), Ok(last));
+
+        let mut bytes = last.to_bytes();
+        bytes[4..].copy_from_slice(&[0xFF; 32]);
+        assert_eq!(Share::from_bytes(&bytes), Err(Error::InvalidShare(300)));
+        let mut bytes = last.to_bytes();
+        bytes[..2].copy_from_slice(&[0, 0]);
+        assert_eq!(Share::from_bytes(&bytes), Err(Error::InvalidShare(0)));
+    }
+
+    #[test]
+    fn refresh_keeps_secret() {
+        let mut rng = DeterministicRng::new(214);
+        let secret = Scalar::random(&mut rng);
+        let old = split(secret, 3, 5, &mut rng).unwrap();
+        let mut new = old.clone();
+        refresh(&mut new, 3, &mut rng).unwrap();
+        for (old, new) in old.iter().zip(&new) {
+            assert_eq!((old.index(), old.threshold()), (new.index(), new.threshold()));
+            assert_ne!(old.to_scalar(), new.to_scalar());
+        }
+        assert_eq!(reconstruct(&new[..3]), Ok(secret));
+        assert_eq!(reconstruct(&new[2..]), Ok(secret));
+        // mixing old and new shares gives garbage
+        assert_ne!(reconstruct(&[old[0], old[1], new[2]]), Ok(secret));
+        assert_ne!(reconstruct(&[new[0], old[3], new[4]]), Ok(secret));
+
+        let mut other = split(secret, 2, 3, &mut rng).unwrap();
+        assert_eq!(refresh(&mut new, 2, &mut rng), Err(Error::InvalidShare(1)));
+        assert_eq!(refresh(&mut other, 0, &mut rng), Err(Error::InvalidThreshold));
+    }
+
+    #[test]
+    fn distributed_refresh() {
+        let mut rng = DeterministicRng::new(214);
+        let secret = Scalar::random(&mut rng);
+        let (old, mut commitments) = crate::vss::split(secret, 2, 3, &mut rng).unwrap();
+        let mut new = old.clone();
+        // every participant deals a sharing of zero to everyone including itself
+        for _ in 0..3 {
+            let (zero_shares, zero_commitments) = zero_sharing(2, 3, &mut rng).unwrap();
+            assert_eq!(zero_commitments[0], Point::AT_INFINITY);
+            for (share, zero_share) in new.iter_mut().zip(&zero_shares) {
+                assert!(zero_share.verify(&zero_commitments));
+                *share = share.add_zero_share(zero_share).unwrap();
+            }
+            for (commitment, zero_commitment) in commitments.iter_mut().zip(&zero_commitments) {
+                *commitment += *zero_commitment;
+            }
+        }
+        assert_eq!(commitments[0], G * secret);
+        for (old, new) in old.iter().zip(&new) {
+            assert_ne!(old.to_scalar(), new.to_scalar());
+            assert!(new.verify(&commitments));
+            assert!(!old.verify(&commitments));
+        }
+        assert_eq!(reconstruct(&new[1..]), Ok(secret));
+        assert_ne!(reconstruct(&[old[0], new[1]]), Ok(secret));
+
+        let (zero_shares, _) = zero_sharing(2, 3, &mut rng).unwrap();
+        assert_eq!(new[0].add_zero_share(&zero_shares[1]), Err(Error::InvalidShare(2)));
+        assert_eq!(zero_sharing(4, 3, &mut rng).map(|_| ()), Err(Error::InvalidThreshold));
+    }
+}
@@ -0,0 +1,125 @@
+//! Dual-key stealth addresses
+//!
+//! The recipient publishes the scan key `S = s·G` and the spend key `B = b·G`. The sender picks
+//! ephemeral `r`, publishes `R = r·G` and pays to `P = B + t·G` where
+//! `t = TaggedHash("toy-secp256k1/stealth")(r·S)` with the point compressed. The recipient
+//! computes the same `t` from `s·R` and can spend using `b + t`. Knowing only `s` is enough to
+//! detect payments but not to spend them.
+
+use rand_core::{CryptoRng, RngCore};
+use crate::tagged_hash::TaggedHash;
+use crate::{Error, Point, PublicKey, Scalar, SecretKey, G};
+
+const TWEAK_TAG: &str = "toy-secp256k1/stealth";
+
+/// The published pair of scan and spend keys
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct StealthAddress {
+    scan_key: PublicKey,
+    spend_key: PublicKey,
+}
+
+impl StealthAddress {
+    pub fn new(scan_key: PublicKey, spend_key: PublicKey) -> Self {
+        StealthAddress { scan_key, spend_key }
+    }
+
+    pub fn scan_key(&self) -> &PublicKey {
+        &self.scan_key
+    }
+
+    pub fn spend_key(&self) -> &PublicKey {
+        &self.spend_key
+    }
+
+    /// Parses the compressed scan key followed by the compressed spend key
+    pub fn from_bytes(bytes: &[u8; 66]) -> Result<Self, Error> {
+        let scan_key = PublicKey::from_sec1_bytes(&bytes[..33])?;
+        let spend_key = PublicKey::from_sec1_bytes(&bytes[33..])?;
+        Ok(StealthAddress { scan_key, spend_key })
+    }
+
+    pub fn serialize(&self) -> [u8; 66] {
+        let mut bytes = [0; 66];
+        bytes[..33].copy_from_slice(&self.scan_key.serialize());
+        bytes[33..].copy_from_slice(&self.spend_key.serialize());
+        bytes
+    }
+}
+
+fn tweak(shared_point: &Point) -> Scalar {
+    Scalar::from_be_bytes_reduce(&TaggedHash::new(TWEAK_TAG).hash(&[&shared_point.serialize_compressed()]))
+}
+
+/// Derives a one-time payment key returning it together with the ephemeral key `R` that has to
+/// be published alongside the payment.
+pub fn sender_derive<R: RngCore + CryptoRng>(address: &StealthAddress, rng: &mut R) -> (Point, Point) {
+    let r = Scalar::random(rng);
+    let payment_key = address.spend_key.to_point() + G * tweak(&(address.scan_key.to_point() * r));
+    (payment_key, G * r)
+}
+
+/// Computes the payment key the sender derived if the payment with `ephemeral` key is for the
+/// owner of `scan_key` and `spend_key`.
+///
+/// The result is then compared with the actual payment key. Returns `None` if the ephemeral
+/// key or the result is the point at infinity.
+pub fn receiver_scan(scan_key: &SecretKey, spend_key: &PublicKey, ephemeral: &Point) -> Option<Point> {
+    if ephemeral.is_at_infinity() {
+        return None;
+    }
+    let payment_key = spend_key.to_point() + G * tweak(&(*ephemeral * scan_key.to_scalar()));
+    if payment_key.is_at_infinity() {
+        None
+    } else {
+        Some(payment_key)
+    }
+}
+
+/// Computes the secret key of the payment key derived using `ephemeral`
+///
+/// # Panics
+///
+/// Panics if the result is zero which happens with negligible probability.
+pub fn receiver_spend_key(scan_key: &SecretKey, spend_key: &SecretKey, ephemeral: &Point) -> SecretKey {
+    let tweak = tweak(&(*ephemeral * scan_key.to_scalar()));
+    SecretKey::from_scalar(spend_key.to_scalar() + tweak)
+        .expect("the probability of the tweak being the negated spend key is negligible")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{receiver_scan, receiver_spend_key, sender_derive, StealthAddress};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Error, SecretKey};
+
+    #[test]
+    fn round_trip() {
+        let mut rng = DeterministicRng::new(1);
+        let scan_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let spend_key = SecretKey::from_bytes(&[0x43; 32]).unwrap();
+        let address = StealthAddress::new(scan_key.public_key(), spend_key.public_key());
+        let (payment_key, ephemeral) = sender_derive(&address, &mut rng);
+        assert_eq!(receiver_scan(&scan_key, &spend_key.public_key(), &ephemeral), Some(payment_key));
+        assert_eq!(receiver_spend_key(&scan_key, &spend_key, &ephemeral).public_key().to_point(), payment_key);
+
+        let (other_payment_key, _) = sender_derive(&address, &mut rng);
+        assert_ne!(other_payment_key, payment_key);
+
+        let wrong_scan_key = SecretKey::from_bytes(&[0x44; 32]).unwrap();
+        assert_ne!(receiver_scan(&wrong_scan_key, &spend_key.public_key(), &ephemeral), Some(payment_key));
+    }
+
+    #[test]
+    fn encoding() {
+        let scan_key = SecretKey::from_bytes(&[0x42; 32]).unwrap().public_key();
+        let spend_key = SecretKey::from_bytes(&[0x43; 32]).unwrap().public_key();
+        let address = StealthAddress::new(scan_key, spend_key);
+        let bytes = address.serialize();
+        assert_eq!(&bytes[..33], &scan_key.serialize()[..]);
+        assert_eq!(StealthAddress::from_bytes(&bytes), Ok(address));
+        let mut corrupted = bytes;
+        corrupted[33] = 0x05;
+        assert_eq!(StealthAddress::from_bytes(&corrupted), Err(Error::InvalidPrefix(0x05)));
+    }
+}
@@ -0,0 +1,152 @@
+//! Signing and verification of SHA256 of data that arrives in chunks
+//!
+//! The signatures are identical to hashing the whole message at once: `StreamingSigner` produces
+//! the same ECDSA signature as `ecdsa::sign_message_sha256` and the same BIP340 signature as
+//! `schnorr::sign` of the 32-byte digest. With the `std` feature both types implement
+//! `io::Write` so files can be copied into them with `io::copy`.
+
+use crate::hashes::Sha256;
+use crate::{ecdsa, schnorr, Error, Keypair, Message, PublicKey, SecretKey, XOnlyPoint};
+
+/// Accumulates the message and signs its SHA256 digest
+#[derive(Clone, Default)]
+pub struct StreamingSigner {
+    hasher: Sha256,
+}
+
+impl StreamingSigner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `data` to the message
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    /// Signs the digest using ECDSA with RFC 6979 nonce
+    pub fn finalize_ecdsa(self, secret_key: &SecretKey) -> ecdsa::Signature {
+        ecdsa::sign(&Message::from_digest(self.hasher.finalize()), secret_key)
+    }
+
+    /// Signs the digest as a 32-byte BIP340 message
+    pub fn finalize_schnorr(self, keypair: &Keypair, aux_rand: &[u8; 32]) -> [u8; 64] {
+        schnorr::sign(&Message::from_digest(self.hasher.finalize()), keypair, aux_rand)
+    }
+}
+
+/// Accumulates the message and verifies a signature of its SHA256 digest
+#[derive(Clone, Default)]
+pub struct StreamingVerifier {
+    hasher: Sha256,
+}
+
+impl StreamingVerifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `data` to the message
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    /// Verifies ECDSA signature of the digest, see `ecdsa::verify`
+    pub fn verify_ecdsa(self, signature: &ecdsa::Signature, public_key: &PublicKey) -> Result<(), Error> {
+        ecdsa::verify(&Message::from_digest(self.hasher.finalize()), signature, public_key)
+    }
+
+    /// Verifies BIP340 signature of the digest, see `schnorr::verify`
+    pub fn verify_schnorr(self, signature: &[u8; 64], public_key: &XOnlyPoint) -> Result<(), Error> {
+        schnorr::verify(&Message::from_digest(self.hasher.finalize()), signature, public_key)
+    }
+}
+
+macro_rules! impl_write {
+    ($type:ty) => {
+        #[cfg(feature = "std")]
+        impl std::io::Write for $type {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.update(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+    }
+}
+
+impl_write!(StreamingSigner);
+impl_write!(StreamingVerifier);
+
+#[cfg(test)]
+mod tests {
+    use super::{StreamingSigner, StreamingVerifier};
+    use crate::{ecdsa, schnorr, Error, Keypair, Message, SecretKey};
+
+    fn message() -> [u8; 10_000] {
+        let mut message = [0; 10_000];
+        for (i, byte) in message.iter_mut().enumerate() {
+            *byte = (i * 31 % 251) as u8;
+        }
+        message
+    }
+
+    fn chunked<T>(mut state: T, message: &[u8], chunk_size: usize, update: fn(&mut T, &[u8])) -> T {
+        for chunk in message.chunks(chunk_size) {
+            update(&mut state, chunk);
+        }
+        state
+    }
+
+    #[test]
+    fn chunking_matches_one_shot() {
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let keypair = Keypair::from_secret_key(&secret_key);
+        let message = message();
+        let ecdsa_signature = ecdsa::sign_message_sha256(&message, &secret_key);
+        let schnorr_signature = schnorr::sign(&Message::from_hashed_data(&message), &keypair, &[0x07; 32]);
+
+        for chunk_size in &[1, 7, 4096, message.len()] {
+            let signer = chunked(StreamingSigner::new(), &message, *chunk_size, StreamingSigner::update);
+            assert_eq!(signer.clone().finalize_ecdsa(&secret_key), ecdsa_signature, "chunk size {}", chunk_size);
+            assert_eq!(signer.finalize_schnorr(&keypair, &[0x07; 32]), schnorr_signature, "chunk size {}", chunk_size);
+
+            let verifier = chunked(StreamingVerifier::new(), &message, *chunk_size, StreamingVerifier::update);
+            assert_eq!(verifier.clone().verify_ecdsa(&ecdsa_signature, &secret_key.public_key()), Ok(()));
+            assert_eq!(verifier.verify_schnorr(&schnorr_signature, &keypair.x_only_public_key().0), Ok(()));
+        }
+    }
+
+    #[test]
+    fn modified_message_fails() {
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let keypair = Keypair::from_secret_key(&secret_key);
+        let mut signer = StreamingSigner::new();
+        signer.update(b"attack at dawn");
+        let ecdsa_signature = signer.clone().finalize_ecdsa(&secret_key);
+        let schnorr_signature = signer.finalize_schnorr(&keypair, &[0; 32]);
+
+        let mut verifier = StreamingVerifier::new();
+        verifier.update(b"attack at dusk");
+        assert_eq!(verifier.clone().verify_ecdsa(&ecdsa_signature, &secret_key.public_key()), Err(Error::InvalidSignature));
+        assert_eq!(verifier.verify_schnorr(&schnorr_signature, &keypair.x_only_public_key().0), Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn io_copy() {
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let message = message();
+        let mut signer = StreamingSigner::new();
+        std::io::copy(&mut &message[..], &mut signer).unwrap();
+        let signature = signer.finalize_ecdsa(&secret_key);
+        assert_eq!(signature, ecdsa::sign_message_sha256(&message, &secret_key));
+
+        let mut verifier = StreamingVerifier::new();
+        std::io::copy(&mut &message[..], &mut verifier).unwrap();
+        assert_eq!(verifier.verify_ecdsa(&signature, &secret_key.public_key()), Ok(()));
+    }
+}
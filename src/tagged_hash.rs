@@ -0,0 +1,123 @@
+//! BIP340 tagged hashes
+//!
+//! A tagged hash is `SHA256(SHA256(tag) || SHA256(tag) || data)`. The prefix has exactly one
+//! block so the state after processing it can be computed once and reused.
+
+use crate::hashes::{sha256, Sha256};
+use crate::{Point, Zp};
+
+/// BIP340 challenge `e = H(R.x || P.x || m)`
+pub const BIP340_CHALLENGE: &str = "BIP0340/challenge";
+/// BIP340 masking of the secret key with auxiliary randomness
+pub const BIP340_AUX: &str = "BIP0340/aux";
+/// BIP340 nonce derivation
+pub const BIP340_NONCE: &str = "BIP0340/nonce";
+/// BIP341 tweak of the internal key
+pub const TAP_TWEAK: &str = "TapTweak";
+/// BIP341 hash of a script leaf
+pub const TAP_LEAF: &str = "TapLeaf";
+/// BIP341 hash of a script tree branch
+pub const TAP_BRANCH: &str = "TapBranch";
+/// BIP341 signature hash
+pub const TAP_SIGHASH: &str = "TapSighash";
+/// MuSig2 (BIP327) hash of the list of keys
+pub const KEYAGG_LIST: &str = "KeyAgg list";
+/// MuSig2 (BIP327) key aggregation coefficient
+pub const KEYAGG_COEFFICIENT: &str = "KeyAgg coefficient";
+/// MuSig2 (BIP327) masking of the secret key with auxiliary randomness
+pub const MUSIG_AUX: &str = "MuSig/aux";
+/// MuSig2 (BIP327) nonce generation
+pub const MUSIG_NONCE: &str = "MuSig/nonce";
+/// MuSig2 (BIP327) nonce derivation of the deterministic signer
+pub const MUSIG_DETERMINISTIC_NONCE: &str = "MuSig/deterministic/nonce";
+/// MuSig2 (BIP327) nonce coefficient
+pub const MUSIG_NONCE_COEFFICIENT: &str = "MuSig/noncecoef";
+/// Coefficients of half-aggregated BIP340 signatures
+pub const HALFAGG_RANDOMIZER: &str = "HalfAgg/randomizer";
+
+/// Hasher for a fixed tag with the tag prefix already processed
+#[derive(Clone)]
+pub struct TaggedHash {
+    midstate: Sha256,
+}
+
+impl TaggedHash {
+    pub fn new(tag: &str) -> Self {
+        let tag = sha256(tag.as_bytes());
+        let mut midstate = Sha256::new();
+        midstate.update(&tag);
+        midstate.update(&tag);
+        TaggedHash { midstate }
+    }
+
+    /// Appends `data` to the prefix so that all subsequent hashes start with it
+    pub fn update(&mut self, data: &[u8]) {
+        self.midstate.update(data);
+    }
+
+    /// Appends `data` prefixed with its length as 8-byte big-endian number so that different
+    /// sequences of framed items never produce the same input
+    pub(crate) fn update_framed(&mut self, data: &[u8]) {
+        self.update(&(data.len() as u64).to_be_bytes());
+        self.update(data);
+    }
+
+    /// Hashes the concatenation of `chunks`
+    pub fn hash(&self, chunks: &[&[u8]]) -> [u8; 32] {
+        let mut hasher = self.midstate.clone();
+        for chunk in chunks {
+            hasher.update(chunk);
+        }
+        hasher.finalize()
+    }
+
+    /// Hashes the concatenation of `chunks` to a curve point using try-and-increment.
+    ///
+    /// A counter byte starting at zero is appended to the data and the hash is interpreted as the
+    /// x coordinate of a point with even y. The counter is incremented until such point exists.
+    /// Nobody knows the discrete logarithm of the result. This is **NOT CONSTANT TIME!!!**
+    pub fn hash_to_curve(&self, chunks: &[&[u8]]) -> Point {
+        let mut hasher = self.clone();
+        for chunk in chunks {
+            hasher.update(chunk);
+        }
+        (0..=u8::MAX)
+            .find_map(|counter| Zp::from_be_bytes(&hasher.hash(&[&[counter]])).and_then(Point::lift_x))
+            .expect("the probability of 256 consecutive failures is negligible")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TaggedHash, BIP340_AUX, BIP340_CHALLENGE, TAP_TWEAK};
+    use crate::hashes::sha256;
+    use crate::test_util::{hex, hex32};
+
+    #[test]
+    fn bip340_vector_0() {
+        let signature = hex("e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0");
+        let public_key = hex("f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9");
+        let challenge = TaggedHash::new(BIP340_CHALLENGE).hash(&[&signature[..32], &public_key, &[0; 32]]);
+        assert_eq!(challenge, hex32("6bb6b93a91f2ecc0cd924f4f9baabb5e6eb21745bb00f2cebdaac908bb5d86ce"));
+        let aux = TaggedHash::new(BIP340_AUX).hash(&[&[0; 32]]);
+        assert_eq!(aux, hex32("54f169cfc9e2e5727480441f90ba25c488f461c70b5ea5dcaaf7af69270aa514"));
+    }
+
+    #[test]
+    fn matches_definition() {
+        let hasher = TaggedHash::new(TAP_TWEAK);
+        let tag = sha256(b"TapTweak");
+        let data = b"some data split into chunks";
+        let mut preimage = tag.to_vec();
+        preimage.extend_from_slice(&tag);
+        preimage.extend_from_slice(data);
+        assert_eq!(hasher.hash(&[&data[..4], &data[4..]]), sha256(&preimage));
+        // reusing the hasher doesn't carry state over
+        assert_eq!(hasher.hash(&[data]), sha256(&preimage));
+        assert_eq!(hasher.hash(&[]), hex32("8aa4229474ab0100b2d6f0687f031d1fc9d8eef92a042ad97d279bff456b15e4"));
+
+        let mut hasher = hasher;
+        hasher.update(&data[..4]);
+        assert_eq!(hasher.hash(&[&data[4..]]), sha256(&preimage));
+    }
+}
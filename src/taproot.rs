@@ -0,0 +1,529 @@
+//! Taproot output keys (BIP341)
+//!
+//! The output key is `Q = P + t·G` where `P` is the internal key (with even y) and
+//! `t = hash_TapTweak(P || merkle_root)`. Without scripts the merkle root is omitted from the
+//! hash.
+//!
+//! Scripts are committed to in a binary tree. Leaves are hashed as
+//! `hash_TapLeaf(version || compact_size(len) || script)` and branches as
+//! `hash_TapBranch(min(a, b) || max(a, b))` so a spender only needs to reveal the sibling hashes
+//! on the way to the root, not their order. These are carried by the control block together with
+//! the internal key and the parity of the output key.
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+use crate::tagged_hash::{TaggedHash, TAP_BRANCH, TAP_LEAF, TAP_TWEAK};
+use crate::{array_ref_32, Error, Keypair, Parity, Scalar, SecretKey, XOnlyPoint, G};
+
+/// Leaf version of BIP342 tapscript
+pub const TAPSCRIPT_LEAF_VERSION: u8 = 0xC0;
+
+/// Maximum depth of a script tree which is also the maximum number of hashes in a control block
+pub const MAX_DEPTH: usize = 128;
+
+/// Hashing of script tree leaves
+pub enum TapLeaf {}
+
+impl TapLeaf {
+    /// Computes `hash_TapLeaf(version || compact_size(script.len()) || script)`
+    pub fn hash(version: u8, script: &[u8]) -> [u8; 32] {
+        let len = script.len() as u64;
+        let mut prefix = [0; 10];
+        prefix[0] = version;
+        let prefix_len = match len {
+            0..=0xFC => {
+                prefix[1] = len as u8;
+                2
+            },
+            0xFD..=0xFFFF => {
+                prefix[1] = 0xFD;
+                prefix[2..4].copy_from_slice(&(len as u16).to_le_bytes());
+                4
+            },
+            0x10000..=0xFFFF_FFFF => {
+                prefix[1] = 0xFE;
+                prefix[2..6].copy_from_slice(&(len as u32).to_le_bytes());
+                6
+            },
+            _ => {
+                prefix[1] = 0xFF;
+                prefix[2..].copy_from_slice(&len.to_le_bytes());
+                10
+            },
+        };
+        TaggedHash::new(TAP_LEAF).hash(&[&prefix[..prefix_len], script])
+    }
+}
+
+/// Hashing of script tree branches
+pub enum TapBranch {}
+
+impl TapBranch {
+    /// Computes `hash_TapBranch` of the children sorted lexicographically so the order of the
+    /// arguments doesn't matter
+    pub fn hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        let (first, second) = if a <= b { (a, b) } else { (b, a) };
+        TaggedHash::new(TAP_BRANCH).hash(&[first, second])
+    }
+}
+
+/// Computes the tweak `t`, returning `Error::InvalidTweak` if it's not less than the curve order
+fn tap_tweak(internal: &XOnlyPoint, merkle_root: Option<&[u8; 32]>) -> Result<Scalar, Error> {
+    let internal = internal.serialize();
+    let hash = match merkle_root {
+        Some(merkle_root) => TaggedHash::new(TAP_TWEAK).hash(&[&internal, merkle_root]),
+        None => TaggedHash::new(TAP_TWEAK).hash(&[&internal]),
+    };
+    Scalar::from_be_bytes(&hash).ok_or(Error::InvalidTweak)
+}
+
+/// Computes the output key and its parity which is needed for script path spending.
+///
+/// Returns `Error::InvalidTweak` if the tweak is not less than the curve order or the output key
+/// is the point at infinity. Both happen with negligible probability.
+pub fn output_key(internal: &XOnlyPoint, merkle_root: Option<[u8; 32]>) -> Result<(XOnlyPoint, Parity), Error> {
+    let tweak = tap_tweak(internal, merkle_root.as_ref())?;
+    XOnlyPoint::from_point(internal.to_point() + G * tweak).ok_or(Error::InvalidTweak)
+}
+
+/// Checks that the length is `33 + 32·m` with `m` not greater than `MAX_DEPTH`
+fn is_valid_control_block_len(len: usize) -> bool {
+    len >= 33 && (len - 33).is_multiple_of(32) && (len - 33) / 32 <= MAX_DEPTH
+}
+
+/// Checks that the script is committed to in the output key as described by the control block.
+///
+/// The control block is `(leaf_version | parity) || internal_key || path` where the path holds
+/// up to `MAX_DEPTH` sibling hashes starting from the leaf. Returns `false` if it's malformed,
+/// the leaf version differs from `version` or the reconstructed output key doesn't match.
+pub fn verify_control_block(output_key: &XOnlyPoint, script: &[u8], version: u8, control_block: &[u8]) -> bool {
+    if !is_valid_control_block_len(control_block.len()) {
+        return false;
+    }
+    if control_block[0] & 0xFE != version {
+        return false;
+    }
+    let parity = if control_block[0] & 1 == 1 { Parity::Odd } else { Parity::Even };
+    let internal = match XOnlyPoint::from_bytes(array_ref_32(&control_block[1..33])) {
+        Ok(internal) => internal,
+        Err(_) => return false,
+    };
+    let merkle_root = control_block[33..]
+        .chunks_exact(32)
+        .fold(TapLeaf::hash(version, script), |node, sibling| TapBranch::hash(&node, array_ref_32(sibling)));
+    match self::output_key(&internal, Some(merkle_root)) {
+        Ok((key, key_parity)) => key == *output_key && key_parity == parity,
+        Err(_) => false,
+    }
+}
+
+/// Data needed to spend the output key using a script
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ControlBlock {
+    leaf_version: u8,
+    output_parity: Parity,
+    internal_key: XOnlyPoint,
+    path: Vec<[u8; 32]>,
+}
+
+#[cfg(feature = "alloc")]
+impl ControlBlock {
+    /// Parses the control block checking its length and the internal key
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        if !is_valid_control_block_len(bytes.len()) {
+            return Err(Error::InvalidLength(bytes.len()));
+        }
+        let output_parity = if bytes[0] & 1 == 1 { Parity::Odd } else { Parity::Even };
+        Ok(ControlBlock {
+            leaf_version: bytes[0] & 0xFE,
+            output_parity,
+            internal_key: XOnlyPoint::from_bytes(array_ref_32(&bytes[1..33]))?,
+            path: bytes[33..].chunks_exact(32).map(|sibling| *array_ref_32(sibling)).collect(),
+        })
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(33 + self.path.len() * 32);
+        out.push(self.leaf_version | (self.output_parity == Parity::Odd) as u8);
+        out.extend_from_slice(&self.internal_key.serialize());
+        for sibling in &self.path {
+            out.extend_from_slice(sibling);
+        }
+        out
+    }
+
+    pub fn leaf_version(&self) -> u8 {
+        self.leaf_version
+    }
+
+    /// Returns the parity of the output key
+    pub fn output_parity(&self) -> Parity {
+        self.output_parity
+    }
+
+    pub fn internal_key(&self) -> &XOnlyPoint {
+        &self.internal_key
+    }
+
+    /// Returns the sibling hashes starting from the leaf
+    pub fn path(&self) -> &[[u8; 32]] {
+        &self.path
+    }
+}
+
+/// Script tree built from the leaves up
+///
+/// The hashes are computed during construction so `merkle_root` is cheap.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TapTree {
+    node: Node,
+    hash: [u8; 32],
+    depth: usize,
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Node {
+    Leaf { version: u8, script: Vec<u8> },
+    Branch(Box<TapTree>, Box<TapTree>),
+}
+
+/// Leaf of a script tree along with the sibling hashes on the way to the root
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScriptLeaf<'a> {
+    version: u8,
+    script: &'a [u8],
+    merkle_path: Vec<[u8; 32]>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> ScriptLeaf<'a> {
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    pub fn script(&self) -> &'a [u8] {
+        self.script
+    }
+
+    /// Returns the sibling hashes starting from the leaf
+    pub fn merkle_path(&self) -> &[[u8; 32]] {
+        &self.merkle_path
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TapTree {
+    /// Creates a tree consisting of a single script.
+    ///
+    /// Returns `Error::InvalidLeafVersion` if the version is odd.
+    pub fn leaf(version: u8, script: &[u8]) -> Result<Self, Error> {
+        if version & 1 != 0 {
+            return Err(Error::InvalidLeafVersion(version));
+        }
+        Ok(TapTree {
+            hash: TapLeaf::hash(version, script),
+            node: Node::Leaf { version, script: script.to_vec() },
+            depth: 0,
+        })
+    }
+
+    /// Joins two trees under a new root.
+    ///
+    /// Returns `Error::TapTreeTooDeep` if the result would be deeper than `MAX_DEPTH`.
+    pub fn branch(left: TapTree, right: TapTree) -> Result<Self, Error> {
+        let depth = left.depth.max(right.depth) + 1;
+        if depth > MAX_DEPTH {
+            return Err(Error::TapTreeTooDeep);
+        }
+        Ok(TapTree {
+            hash: TapBranch::hash(&left.hash, &right.hash),
+            node: Node::Branch(Box::new(left), Box::new(right)),
+            depth,
+        })
+    }
+
+    pub fn merkle_root(&self) -> [u8; 32] {
+        self.hash
+    }
+
+    /// Returns the length of the longest path from the root to a leaf, zero for a single leaf
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the leaves in left-to-right order with their merkle paths
+    pub fn leaves(&self) -> Vec<ScriptLeaf<'_>> {
+        match &self.node {
+            Node::Leaf { version, script } => alloc::vec![ScriptLeaf { version: *version, script, merkle_path: Vec::new() }],
+            Node::Branch(left, right) => {
+                let mut leaves = left.leaves();
+                for leaf in &mut leaves {
+                    leaf.merkle_path.push(right.hash);
+                }
+                let start = leaves.len();
+                leaves.extend(right.leaves());
+                for leaf in &mut leaves[start..] {
+                    leaf.merkle_path.push(left.hash);
+                }
+                leaves
+            },
+        }
+    }
+
+    /// Computes the output key committing to this tree, see `output_key`
+    pub fn output_key(&self, internal: &XOnlyPoint) -> Result<(XOnlyPoint, Parity), Error> {
+        output_key(internal, Some(self.hash))
+    }
+
+    /// Returns the control blocks of the leaves in left-to-right order
+    pub fn control_blocks(&self, internal: &XOnlyPoint) -> Result<Vec<ControlBlock>, Error> {
+        let (_, output_parity) = self.output_key(internal)?;
+        let control_blocks = self
+            .leaves()
+            .into_iter()
+            .map(|leaf| ControlBlock {
+                leaf_version: leaf.version,
+                output_parity,
+                internal_key: *internal,
+                path: leaf.merkle_path,
+            })
+            .collect();
+        Ok(control_blocks)
+    }
+}
+
+impl Keypair {
+    /// Tweaks the keypair so that it can sign for the output key with the given merkle root.
+    ///
+    /// The secret key is negated first if the internal key has odd y. Returns
+    /// `Error::InvalidTweak` in the same cases as `output_key`.
+    pub fn tap_tweak(&self, merkle_root: Option<[u8; 32]>) -> Result<Keypair, Error> {
+        let (internal, parity) = self.x_only_public_key();
+        let d = self.secret_key().to_scalar();
+        let d = if parity == Parity::Odd { -d } else { d };
+        let tweak = tap_tweak(&internal, merkle_root.as_ref())?;
+        let secret_key = SecretKey::from_scalar(d + tweak).ok_or(Error::InvalidTweak)?;
+        Ok(Keypair::from_secret_key(&secret_key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{output_key, tap_tweak, verify_control_block, TapBranch, TapLeaf, TAPSCRIPT_LEAF_VERSION};
+    #[cfg(feature = "alloc")]
+    use super::{ControlBlock, TapTree, MAX_DEPTH};
+    use crate::test_util::{hex, hex32};
+    use crate::{schnorr, Keypair, Message, SecretKey, XOnlyPoint};
+    #[cfg(feature = "alloc")]
+    use crate::Error;
+
+    /// `scriptPubKey` vectors from `wallet-test-vectors.json` of BIP341: internal key, merkle
+    /// root and the output key
+    const VECTORS: &[(&str, Option<&str>, &str)] = &[
+        ("d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d", None, "53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343"),
+        ("187791b6f712a8ea41c8ecdd0ee77fab3e85263b37e1ec18a3651926b3a6cf27", Some("5b75adecf53548f3ec6ad7d78383bf84cc57b55a3127c72b9a2481752dd88b21"), "147c9c57132f6e7ecddba9800bb0c4449251c92a1e60371ee77557b6620f3ea3"),
+        ("93478e9488f956df2396be2ce6c5cced75f900dfa18e7dabd2428aae78451820", Some("c525714a7f49c28aedbbba78c005931a81c234b2f6c99a73e4d06082adc8bf2b"), "e4d810fd50586274face62b8a807eb9719cef49c04177cc6b76a9a4251d5450e"),
+        ("ee4fe085983462a184015d1f782d6a5f8b9c2b60130aff050ce221ecf3786592", Some("6c2dc106ab816b73f9d07e3cd1ef2c8c1256f519748e0813e4edd2405d277bef"), "712447206d7a5238acc7ff53fbe94a3b64539ad291c7cdbc490b7577e4b17df5"),
+        ("f9f400803e683727b14f463836e1e78e1c64417638aa066919291a225f0e8dd8", Some("ab179431c28d3b68fb798957faf5497d69c883c6fb1e1cd9f81483d87bac90cc"), "77e30a5522dd9f894c3f8b8bd4c4b2cf82ca7da8a3ea6a239655c39c050ab220"),
+        ("e0dfe2300b0dd746a3f8674dfd4525623639042569d829c7f0eed9602d263e6f", Some("ccbd66c6f7e8fdab47b3a486f59d28262be857f30d4773f2d5ea47f7761ce0e2"), "91b64d5324723a985170e4dc5a0f84c041804f2cd12660fa5dec09fc21783605"),
+        ("55adf4e8967fbd2e29f20ac896e60c3b0f1d5b0efa9d34941b5958c7b0a0312d", Some("2f6b2c5397b6d68ca18e09a3f05161668ffe93a988582d55c6f07bd5b3329def"), "75169f4001aa68f15bbed28b218df1d0a62cbbcf1188c6665110c293c907b831"),
+    ];
+
+    #[test]
+    fn bip341_output_keys() {
+        for (i, (internal, merkle_root, expected)) in VECTORS.iter().enumerate() {
+            let internal = XOnlyPoint::from_bytes(&hex32(internal)).unwrap();
+            let (output, parity) = output_key(&internal, merkle_root.map(hex32)).unwrap();
+            assert_eq!(output.serialize(), hex32(expected), "vector {}", i);
+            // a verifier knowing only the public data can check the commitment
+            let tweak = tap_tweak(&internal, merkle_root.map(hex32).as_ref()).unwrap();
+            assert!(internal.tweak_add_check(&output, parity, &tweak), "vector {}", i);
+        }
+    }
+
+    #[test]
+    fn key_path_signing() {
+        let merkle_root = [0x42; 32];
+        for byte in 1..=4 {
+            let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&[byte; 32]).unwrap());
+            let (internal, _) = keypair.x_only_public_key();
+            let tweaked = keypair.tap_tweak(Some(merkle_root)).unwrap();
+            let (output, parity) = output_key(&internal, Some(merkle_root)).unwrap();
+            assert_eq!(tweaked.x_only_public_key(), (output, parity));
+            let signature = schnorr::sign(&Message::from_digest([0x07; 32]), &tweaked, &[0; 32]);
+            assert_eq!(schnorr::verify(&Message::from_digest([0x07; 32]), &signature, &output), Ok(()));
+            assert_ne!(keypair.tap_tweak(None).unwrap().x_only_public_key().0, output);
+        }
+    }
+
+    /// Leaf hashes of the single-leaf and two-leaf trees of BIP341 vectors 1 to 4 give their
+    /// merkle roots
+    #[test]
+    fn bip341_leaf_and_branch_hashes() {
+        let script = hex("20d85a959b0290bf19bb89ed43c916be835475d013da4b362117393e25a48229b8ac");
+        assert_eq!(TapLeaf::hash(TAPSCRIPT_LEAF_VERSION, &script), hex32(VECTORS[1].1.unwrap()));
+        // depth 0 so the control block contains no hashes
+        let output = XOnlyPoint::from_bytes(&hex32(VECTORS[1].2)).unwrap();
+        let control_block = hex("c1187791b6f712a8ea41c8ecdd0ee77fab3e85263b37e1ec18a3651926b3a6cf27");
+        assert!(verify_control_block(&output, &script, TAPSCRIPT_LEAF_VERSION, &control_block));
+        let leaf = TapLeaf::hash(TAPSCRIPT_LEAF_VERSION, &hex("20b617298552a72ade070667e86ca63b8f5789a9fe8731ef91202a91c9f3459007ac"));
+        assert_eq!(leaf, hex32(VECTORS[2].1.unwrap()));
+
+        let a = TapLeaf::hash(TAPSCRIPT_LEAF_VERSION, &hex("20387671353e273264c495656e27e39ba899ea8fee3bb69fb2a680e22093447d48ac"));
+        let b = TapLeaf::hash(0xFA, &hex("06424950333431"));
+        assert_eq!(a, hex32("8ad69ec7cf41c2a4001fd1f738bf1e505ce2277acdcaa63fe4765192497f47a7"));
+        assert_eq!(b, hex32("f224a923cd0021ab202ab139cc56802ddb92dcfc172b9212261a539df79a112a"));
+        assert_eq!(TapBranch::hash(&a, &b), hex32(VECTORS[3].1.unwrap()));
+        assert_eq!(TapBranch::hash(&b, &a), hex32(VECTORS[3].1.unwrap()));
+
+        let a = TapLeaf::hash(TAPSCRIPT_LEAF_VERSION, &hex("2044b178d64c32c4a05cc4f4d1407268f764c940d20ce97abfd44db5c3592b72fdac"));
+        let b = TapLeaf::hash(TAPSCRIPT_LEAF_VERSION, &hex("07546170726f6f74"));
+        assert_eq!(TapBranch::hash(&a, &b), hex32(VECTORS[4].1.unwrap()));
+    }
+
+    /// Script-path vectors of BIP341: single leaf (depth 0) and two leaves with different versions
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bip341_control_blocks() {
+        let internal = XOnlyPoint::from_bytes(&hex32(VECTORS[1].0)).unwrap();
+        let script = hex("20d85a959b0290bf19bb89ed43c916be835475d013da4b362117393e25a48229b8ac");
+        let tree = TapTree::leaf(TAPSCRIPT_LEAF_VERSION, &script).unwrap();
+        assert_eq!(tree.depth(), 0);
+        let (output, _) = tree.output_key(&internal).unwrap();
+        assert_eq!(output.serialize(), hex32(VECTORS[1].2));
+        let control_blocks = tree.control_blocks(&internal).unwrap();
+        assert_eq!(control_blocks.len(), 1);
+        assert_eq!(control_blocks[0].serialize(), hex("c1187791b6f712a8ea41c8ecdd0ee77fab3e85263b37e1ec18a3651926b3a6cf27"));
+        assert!(verify_control_block(&output, &script, TAPSCRIPT_LEAF_VERSION, &control_blocks[0].serialize()));
+
+        let internal = XOnlyPoint::from_bytes(&hex32(VECTORS[3].0)).unwrap();
+        let scripts = [
+            (TAPSCRIPT_LEAF_VERSION, hex("20387671353e273264c495656e27e39ba899ea8fee3bb69fb2a680e22093447d48ac")),
+            (0xFA, hex("06424950333431")),
+        ];
+        let tree = TapTree::branch(
+            TapTree::leaf(scripts[0].0, &scripts[0].1).unwrap(),
+            TapTree::leaf(scripts[1].0, &scripts[1].1).unwrap(),
+        ).unwrap();
+        assert_eq!(tree.merkle_root(), hex32(VECTORS[3].1.unwrap()));
+        let (output, _) = tree.output_key(&internal).unwrap();
+        assert_eq!(output.serialize(), hex32(VECTORS[3].2));
+        let expected = [
+            "c0ee4fe085983462a184015d1f782d6a5f8b9c2b60130aff050ce221ecf3786592f224a923cd0021ab202ab139cc56802ddb92dcfc172b9212261a539df79a112a",
+            "faee4fe085983462a184015d1f782d6a5f8b9c2b60130aff050ce221ecf37865928ad69ec7cf41c2a4001fd1f738bf1e505ce2277acdcaa63fe4765192497f47a7",
+        ];
+        let control_blocks = tree.control_blocks(&internal).unwrap();
+        for (i, ((version, script), control_block)) in scripts.iter().zip(&control_blocks).enumerate() {
+            let bytes = control_block.serialize();
+            assert_eq!(bytes, hex(expected[i]), "leaf {}", i);
+            assert_eq!(ControlBlock::from_slice(&bytes).as_ref(), Ok(control_block));
+            assert!(verify_control_block(&output, script, *version, &bytes), "leaf {}", i);
+            // the control block of the other leaf doesn't prove this one
+            assert!(!verify_control_block(&output, script, *version, &control_blocks[1 - i].serialize()), "leaf {}", i);
+        }
+    }
+
+    /// BIP341 vector 6 has the unbalanced tree `[A, [B, C]]`
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unbalanced_tree() {
+        let internal = XOnlyPoint::from_bytes(&hex32(VECTORS[6].0)).unwrap();
+        let scripts = [
+            hex("2071981521ad9fc9036687364118fb6ccd2035b96a423c59c5430e98310a11abe2ac"),
+            hex("20d5094d2dbe9b76e2c245a2b89b6006888952e2faa6a149ae318d69e520617748ac"),
+            hex("20c440b462ad48c7a77f94cd4532d8f2119dcebbd7c9764557e62726419b08ad4cac"),
+        ];
+        let leaf = |script: &[u8]| TapTree::leaf(TAPSCRIPT_LEAF_VERSION, script).unwrap();
+        let tree = TapTree::branch(leaf(&scripts[0]), TapTree::branch(leaf(&scripts[1]), leaf(&scripts[2])).unwrap()).unwrap();
+        assert_eq!(tree.depth(), 2);
+        assert_eq!(tree.merkle_root(), hex32(VECTORS[6].1.unwrap()));
+        let (output, parity) = tree.output_key(&internal).unwrap();
+        assert_eq!(output.serialize(), hex32(VECTORS[6].2));
+
+        let leaves = tree.leaves();
+        let path_lengths = leaves.iter().map(|leaf| leaf.merkle_path().len()).collect::<Vec<_>>();
+        assert_eq!(path_lengths, [1, 2, 2]);
+        assert_eq!(leaves[1].merkle_path()[0], TapLeaf::hash(TAPSCRIPT_LEAF_VERSION, &scripts[2]));
+        for (script, control_block) in scripts.iter().zip(tree.control_blocks(&internal).unwrap()) {
+            assert_eq!(control_block.output_parity(), parity);
+            assert_eq!(control_block.internal_key(), &internal);
+            let bytes = control_block.serialize();
+            assert!(verify_control_block(&output, script, TAPSCRIPT_LEAF_VERSION, &bytes));
+            assert!(!verify_control_block(&output, script, 0xC2, &bytes));
+            let mut flipped = bytes.clone();
+            flipped[0] ^= 1;
+            assert!(!verify_control_block(&output, script, TAPSCRIPT_LEAF_VERSION, &flipped));
+            let mut corrupted = bytes;
+            *corrupted.last_mut().unwrap() ^= 1;
+            assert!(!verify_control_block(&output, script, TAPSCRIPT_LEAF_VERSION, &corrupted));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn maximum_depth() {
+        let internal = XOnlyPoint::from_bytes(&hex32(VECTORS[0].0)).unwrap();
+        let leaf = |i: usize| TapTree::leaf(TAPSCRIPT_LEAF_VERSION, &i.to_be_bytes()).unwrap();
+        let mut tree = leaf(0);
+        for i in 1..=MAX_DEPTH {
+            tree = TapTree::branch(leaf(i), tree).unwrap();
+        }
+        assert_eq!(tree.depth(), MAX_DEPTH);
+        assert_eq!(TapTree::branch(leaf(0), tree.clone()), Err(Error::TapTreeTooDeep));
+
+        let (output, _) = tree.output_key(&internal).unwrap();
+        let control_block = tree.control_blocks(&internal).unwrap().pop().unwrap();
+        assert_eq!(control_block.path().len(), MAX_DEPTH);
+        let bytes = control_block.serialize();
+        assert!(verify_control_block(&output, &0usize.to_be_bytes(), TAPSCRIPT_LEAF_VERSION, &bytes));
+        // one more hash exceeds the limit even if it would otherwise be valid
+        let mut too_long = bytes;
+        too_long.extend_from_slice(&[0; 32]);
+        assert!(!verify_control_block(&output, &0usize.to_be_bytes(), TAPSCRIPT_LEAF_VERSION, &too_long));
+        assert_eq!(ControlBlock::from_slice(&too_long), Err(Error::InvalidLength(33 + 32 * 129)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn malformed_input() {
+        assert_eq!(TapTree::leaf(0xC1, &[]), Err(Error::InvalidLeafVersion(0xC1)));
+        let internal = XOnlyPoint::from_bytes(&hex32(VECTORS[0].0)).unwrap();
+        let tree = TapTree::leaf(TAPSCRIPT_LEAF_VERSION, &[0x51]).unwrap();
+        let (output, parity) = tree.output_key(&internal).unwrap();
+        let bytes = tree.control_blocks(&internal).unwrap()[0].serialize();
+        assert!(verify_control_block(&output, &[0x51], TAPSCRIPT_LEAF_VERSION, &bytes));
+        assert!(!verify_control_block(&output, &[0x51], TAPSCRIPT_LEAF_VERSION, &[]));
+        assert!(!verify_control_block(&output, &[0x51], TAPSCRIPT_LEAF_VERSION, &bytes[..32]));
+        for extra in &[1, 31, 33] {
+            let mut extended = bytes.clone();
+            extended.resize(bytes.len() + extra, 0);
+            assert!(!verify_control_block(&output, &[0x51], TAPSCRIPT_LEAF_VERSION, &extended));
+        }
+        assert_eq!(ControlBlock::from_slice(&bytes[..32]), Err(Error::InvalidLength(32)));
+        // the internal key is not on the curve
+        let mut invalid_key = bytes;
+        invalid_key[1..].copy_from_slice(&[0xFF; 32]);
+        assert!(!verify_control_block(&output, &[0x51], TAPSCRIPT_LEAF_VERSION, &invalid_key));
+        assert!(ControlBlock::from_slice(&invalid_key).is_err());
+        let parsed = ControlBlock::from_slice(&tree.control_blocks(&internal).unwrap()[0].serialize()).unwrap();
+        assert_eq!(parsed.output_parity(), parity);
+        assert_eq!(parsed.leaf_version(), TAPSCRIPT_LEAF_VERSION);
+    }
+
+    /// Scripts longer than 252 bytes use the 3-byte compact size
+    #[test]
+    fn long_script_leaf_hash() {
+        let script = [0x51; 300];
+        let mut expected = crate::hashes::Sha256::new();
+        let tag = crate::hashes::sha256(b"TapLeaf");
+        expected.update(&tag);
+        expected.update(&tag);
+        expected.update(&[TAPSCRIPT_LEAF_VERSION, 0xFD, 0x2C, 0x01]);
+        expected.update(&script);
+        assert_eq!(TapLeaf::hash(TAPSCRIPT_LEAF_VERSION, &script), expected.finalize());
+    }
+}
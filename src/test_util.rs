@@ -0,0 +1,40 @@
+//! Helpers shared by tests
+
+use core::convert::TryInto;
+#[cfg(feature = "hashes")]
+use rand_core::{CryptoRng, RngCore};
+
+pub(crate) fn hex(s: &str) -> Vec<u8> {
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..(i + 2)], 16).unwrap()).collect()
+}
+
+pub(crate) fn hex32(s: &str) -> [u8; 32] {
+    hex(s).try_into().expect("expected 32 bytes")
+}
+
+/// RNG returning only zeros, for checking that APIs degrade to their deterministic versions
+#[cfg(feature = "hashes")]
+pub(crate) struct ZeroRng;
+
+#[cfg(feature = "hashes")]
+impl RngCore for ZeroRng {
+    fn next_u32(&mut self) -> u32 {
+        0
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        dest.fill(0);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "hashes")]
+impl CryptoRng for ZeroRng {}
@@ -0,0 +1,124 @@
+//! Reproducible randomness for tests
+//!
+//! Available to downstream crates with the `test-utils` feature. **NEVER** use any of this to
+//! generate real keys - the output is fully determined by the seed.
+
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+use crate::chacha::chacha_block;
+use crate::{Keypair, Scalar, SecretKey};
+
+/// ChaCha8 keystream with the seed as the key and zero nonce.
+///
+/// The output for a given seed is stable across versions of this crate so it can be used to pin
+/// expected test results. It's marked `CryptoRng` only so it can be passed to APIs requiring
+/// it - it's not secure unless the seed is secret and random.
+#[derive(Clone, Debug)]
+pub struct DeterministicRng {
+    key: [u8; 32],
+    counter: u32,
+    block: [u8; 64],
+    position: usize,
+}
+
+impl DeterministicRng {
+    /// Shorthand for `SeedableRng::seed_from_u64`
+    pub fn new(seed: u64) -> Self {
+        Self::seed_from_u64(seed)
+    }
+}
+
+impl SeedableRng for DeterministicRng {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        DeterministicRng {
+            key: seed,
+            counter: 0,
+            block: [0; 64],
+            position: 64,
+        }
+    }
+}
+
+impl RngCore for DeterministicRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    /// Panics after 256 GiB of output
+    fn fill_bytes(&mut self, mut dest: &mut [u8]) {
+        while !dest.is_empty() {
+            if self.position == 64 {
+                self.block = chacha_block(&self.key, self.counter, &[0; 12], 8);
+                self.counter = self.counter.checked_add(1).expect("keystream exhausted");
+                self.position = 0;
+            }
+            let len = dest.len().min(64 - self.position);
+            dest[..len].copy_from_slice(&self.block[self.position..(self.position + len)]);
+            self.position += len;
+            dest = &mut dest[len..];
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for DeterministicRng {}
+
+/// Returns a non-zero scalar determined by `index`
+pub fn deterministic_scalar(index: u32) -> Scalar {
+    Scalar::random(&mut DeterministicRng::new(index.into()))
+}
+
+/// Returns a secret key determined by `index`
+pub fn deterministic_secret_key(index: u32) -> SecretKey {
+    SecretKey::from_scalar(deterministic_scalar(index)).expect("the scalar is not zero")
+}
+
+/// Returns a key pair determined by `index`
+pub fn deterministic_keypair(index: u32) -> Keypair {
+    Keypair::from_secret_key(&deterministic_secret_key(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{RngCore, SeedableRng};
+    use super::{deterministic_keypair, deterministic_scalar, DeterministicRng};
+    use crate::test_util::hex;
+
+    /// ChaCha8 test vector for zero key and nonce
+    #[test]
+    fn chacha8_keystream() {
+        let mut output = [0; 64];
+        DeterministicRng::from_seed([0; 32]).fill_bytes(&mut output);
+        assert_eq!(output[..], hex("3e00ef2f895f40d67f5bb8e81f09a5a12c840ec3ce9a7f3b181be188ef711a1e984ce172b9216f419f445367456d5619314a42a3da86b001387bfdb80e0cfe42")[..]);
+    }
+
+    #[test]
+    fn chunking_is_irrelevant() {
+        let mut one_shot = [0; 200];
+        DeterministicRng::new(42).fill_bytes(&mut one_shot);
+        let mut rng = DeterministicRng::new(42);
+        let mut chunked = [0; 200];
+        for chunk in chunked.chunks_mut(7) {
+            rng.fill_bytes(chunk);
+        }
+        assert_eq!(chunked[..], one_shot[..]);
+    }
+
+    /// Tests all over the crate depend on these so they must never change
+    #[test]
+    fn pinned_outputs() {
+        assert_eq!(DeterministicRng::new(1).next_u64(), 0x6709_4cea_8ca4_0db1);
+        assert_eq!(deterministic_scalar(0).to_be_bytes()[..], hex("6c3b9aa767f785b537c0d8ba5fa54677e6a6e281320dfbb27c889b8fa460670f")[..]);
+        assert_eq!(deterministic_keypair(0).secret_key().to_scalar(), deterministic_scalar(0));
+        assert_ne!(deterministic_scalar(0), deterministic_scalar(1));
+    }
+}
@@ -0,0 +1,248 @@
+//! dudect-style detection of secret-dependent timing
+//!
+//! Each target is run many times with inputs from two classes - a fixed input and uniformly random
+//! ones - interleaved randomly. If the running time doesn't depend on the input the two
+//! distributions of measured times are the same, so Welch's t-test is used to check whether their
+//! means differ. Large measurements are dominated by interrupts and other noise so the test is
+//! repeated on several cropped subsets and the largest statistic is reported, as dudect does.
+//!
+//! The measurements are noisy and slow so the tests are `#[ignore]`d, run them with
+//! `cargo test --release --features timing-tests -- --ignored timing`. A new primitive is added
+//! by appending an entry to `TARGETS`.
+
+use core::hint::black_box;
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable};
+use crate::test_utils::DeterministicRng;
+use crate::{Point, Scalar, Zp, G, U256};
+
+/// |t| above which the target is considered leaky.
+///
+/// dudect treats 4.5 as evidence of a leak and 10 as conclusive, the latter is used to keep the
+/// tests from failing on noisy machines.
+const THRESHOLD: f64 = 10.0;
+
+/// Number of measurements per target
+const SAMPLES: usize = 200_000;
+
+/// Percentiles at which the measurements are cropped in addition to using all of them
+const CROP_PERCENTILES: [f64; 4] = [0.5, 0.75, 0.9, 0.95];
+
+/// Primitive measured by the harness
+struct Target {
+    name: &'static str,
+    /// Whether the primitive is supposed to run in constant time - the others are measured to
+    /// check that the harness is able to detect a leak.
+    constant_time: bool,
+    /// Input of the fixed class, the random class uses uniformly random bytes
+    fixed: [u8; 32],
+    /// Runs the primitive on the input, passing the result to `black_box`
+    run: fn(&[u8; 32]),
+}
+
+fn table() -> [Point; 16] {
+    let mut table = [G; 16];
+    for i in 1..16 {
+        table[i] = table[i - 1] + G;
+    }
+    table
+}
+
+const TARGETS: &[Target] = &[
+    Target {
+        name: "Point::ct_select",
+        constant_time: true,
+        fixed: [0; 32],
+        run: |input| {
+            black_box(Point::ct_select(&table(), usize::from(input[0] % 16)));
+        },
+    },
+    Target {
+        name: "Point::conditional_select",
+        constant_time: true,
+        fixed: [0; 32],
+        run: |input| {
+            black_box(Point::conditional_select(&G, &-G, Choice::from(input[0] & 1)));
+        },
+    },
+    Target {
+        name: "Point * Scalar",
+        constant_time: false,
+        fixed: [0; 32],
+        run: |input| {
+            black_box(G * Scalar::from_be_bytes_reduce(input));
+        },
+    },
+    Target {
+        name: "Zp::multiplicative_inverse",
+        constant_time: false,
+        fixed: [0; 32],
+        run: |input| {
+            black_box(Zp::wrapping_from(U256::from_be_bytes(input)).multiplicative_inverse());
+        },
+    },
+];
+
+/// Reads the time stamp counter, serialized so that the measured code can't be reordered around
+/// it.
+#[cfg(target_arch = "x86_64")]
+fn cycles() -> u64 {
+    use core::arch::x86_64::{_mm_lfence, _rdtsc};
+
+    // SAFETY: SSE2 (which provides lfence) and rdtsc are available on every x86_64 CPU
+    unsafe {
+        _mm_lfence();
+        let cycles = _rdtsc();
+        _mm_lfence();
+        cycles
+    }
+}
+
+/// Falls back to nanoseconds from the monotonic clock if there's no cycle counter
+#[cfg(not(target_arch = "x86_64"))]
+fn cycles() -> u64 {
+    use std::sync::OnceLock;
+    use std::time::Instant;
+
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed().as_nanos() as u64
+}
+
+/// Welch's t-test computed online using Welford's algorithm
+#[derive(Default)]
+struct WelchTest {
+    count: [f64; 2],
+    mean: [f64; 2],
+    m2: [f64; 2],
+}
+
+impl WelchTest {
+    fn push(&mut self, class: usize, value: f64) {
+        self.count[class] += 1.0;
+        let delta = value - self.mean[class];
+        self.mean[class] += delta / self.count[class];
+        self.m2[class] += delta * (value - self.mean[class]);
+    }
+
+    /// Returns the t statistic or zero if either class has fewer than two samples
+    fn t(&self) -> f64 {
+        if self.count[0] < 2.0 || self.count[1] < 2.0 {
+            return 0.0;
+        }
+        let var0 = self.m2[0] / (self.count[0] - 1.0);
+        let var1 = self.m2[1] / (self.count[1] - 1.0);
+        let denominator = (var0 / self.count[0] + var1 / self.count[1]).sqrt();
+        if denominator == 0.0 {
+            return 0.0;
+        }
+        (self.mean[0] - self.mean[1]) / denominator
+    }
+}
+
+/// Returns the largest |t| over all measurements and the cropped subsets
+fn max_t(measurements: &[(usize, u64)]) -> f64 {
+    let mut sorted = measurements.iter().map(|(_, time)| *time).collect::<Vec<_>>();
+    sorted.sort_unstable();
+    let thresholds = core::iter::once(u64::MAX)
+        .chain(CROP_PERCENTILES.iter().map(|p| sorted[((sorted.len() - 1) as f64 * p) as usize]));
+
+    thresholds
+        .map(|threshold| {
+            let mut test = WelchTest::default();
+            for (class, time) in measurements.iter().filter(|(_, time)| *time <= threshold) {
+                test.push(*class, *time as f64);
+            }
+            test.t().abs()
+        })
+        .fold(0.0, f64::max)
+}
+
+/// Runs the target `samples` times with randomly interleaved classes and returns the largest |t|
+fn measure(target: &Target, samples: usize, rng: &mut DeterministicRng) -> f64 {
+    // inputs are generated upfront so that the generation doesn't pollute the measurements
+    let inputs = (0..samples)
+        .map(|_| {
+            let class = (rng.next_u32() & 1) as usize;
+            let mut input = target.fixed;
+            if class == 1 {
+                rng.fill_bytes(&mut input);
+            }
+            (class, input)
+        })
+        .collect::<Vec<_>>();
+
+    let measurements = inputs
+        .iter()
+        .map(|(class, input)| {
+            let start = cycles();
+            (target.run)(black_box(input));
+            (*class, cycles().wrapping_sub(start))
+        })
+        .collect::<Vec<_>>();
+
+    max_t(&measurements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{max_t, measure, WelchTest, SAMPLES, TARGETS, THRESHOLD};
+    use crate::test_utils::DeterministicRng;
+
+    #[test]
+    fn welch_statistic() {
+        let mut test = WelchTest::default();
+        for value in &[1.0, 2.0, 3.0, 4.0] {
+            test.push(0, *value);
+        }
+        for value in &[2.0, 4.0, 6.0, 8.0] {
+            test.push(1, *value);
+        }
+        // means 2.5 and 5, variances 5/3 and 20/3
+        let expected = -2.5 / (5.0f64 / 12.0 + 20.0 / 12.0).sqrt();
+        assert!((test.t() - expected).abs() < 1e-12, "{}", test.t());
+
+        let mut test = WelchTest::default();
+        test.push(0, 1.0);
+        test.push(1, 2.0);
+        assert_eq!(test.t(), 0.0);
+    }
+
+    #[test]
+    fn cropping_ignores_outliers() {
+        // identical distributions except for a single huge outlier in one class
+        let mut measurements = (0..1000).map(|i| (i % 2, 100 + (i / 2 % 7) as u64)).collect::<Vec<_>>();
+        measurements.push((0, 1_000_000));
+        assert!(max_t(&measurements) < THRESHOLD);
+
+        let shifted = (0..1000).map(|i| (i % 2, 100 + (i % 2) as u64 * 10 + (i / 2 % 7) as u64)).collect::<Vec<_>>();
+        assert!(max_t(&shifted) > THRESHOLD);
+    }
+
+    #[test]
+    fn harness_runs() {
+        let mut rng = DeterministicRng::new(1);
+        for target in TARGETS {
+            assert!(measure(target, 100, &mut rng).is_finite(), "{}", target.name);
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn timing_constant_time_targets() {
+        let mut rng = DeterministicRng::new(2);
+        for target in TARGETS.iter().filter(|target| target.constant_time) {
+            let t = measure(target, SAMPLES, &mut rng);
+            assert!(t < THRESHOLD, "{} leaks timing: |t| = {}", target.name, t);
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn timing_leaky_targets_are_detected() {
+        let mut rng = DeterministicRng::new(3);
+        for target in TARGETS.iter().filter(|target| !target.constant_time) {
+            let t = measure(target, SAMPLES / 10, &mut rng);
+            assert!(t > THRESHOLD, "leak of {} not detected: |t| = {}", target.name, t);
+        }
+    }
+}
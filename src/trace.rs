@@ -0,0 +1,243 @@
+//! Traced variants of the basic operations for teaching and visualization
+//!
+//! Each traced operation is a separate entry point taking a callback that receives every
+//! intermediate value, the untraced operations are not affected in any way. The traced
+//! algorithms are the textbook ones which are not necessarily the ones used by the operators -
+//! `Point::mul_traced` uses plain double-and-add rather than wNAF and `Zp::inverse_traced`
+//! uses the extended Euclidean algorithm rather than the binary one. They compute the same
+//! results.
+//!
+//! The operations are **NOT CONSTANT TIME!!!** and the events contain the secret values, so they
+//! must not be used with real keys.
+
+use crate::{Point, Scalar, Zp, U256};
+
+/// Step of `Point::mul_traced`
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum MulEvent {
+    /// The bit of the scalar at `index` is processed next, `set` says whether it's one
+    Bit { index: usize, set: bool },
+    /// The accumulator was doubled
+    Double { result: Point },
+    /// The bit was set so `addend` (the base point) was added to the accumulator
+    Add { addend: Point, result: Point },
+}
+
+/// Step of `Point::add_traced`
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum AddEvent {
+    /// One of the operands is the point at infinity so the result is the other one
+    OperandAtInfinity,
+    /// The operands are inverses of each other so the result is the point at infinity
+    Inverses,
+    /// The slope `lambda = numerator / denominator` of the line through the operands, or of the
+    /// tangent if `doubling`
+    Lambda { doubling: bool, numerator: Zp, denominator: Zp, lambda: Zp },
+    /// The coordinates of the sum, `x = lambda² - x₁ - x₂` and `y = lambda·(x₁ - x) - y₁`
+    Result { x: Zp, y: Zp },
+}
+
+/// Step of `Zp::inverse_traced`
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum InverseEvent {
+    /// One division of the extended Euclidean algorithm
+    ///
+    /// `remainder` is the next remainder (the previous two divided with `quotient`) and
+    /// `coefficient` is the Bézout coefficient such that `coefficient·a ≡ remainder (mod p)`
+    /// where `a` is the inverted element.
+    Step { quotient: U256, remainder: U256, coefficient: Zp },
+}
+
+impl Point {
+    /// Adds the points reporting the slope and the cases of the addition formula
+    pub fn add_traced(self, rhs: Point, trace: &mut impl FnMut(AddEvent)) -> Point {
+        if self.is_at_infinity() || rhs.is_at_infinity() {
+            trace(AddEvent::OperandAtInfinity);
+            return self + rhs;
+        }
+        if self == -rhs {
+            trace(AddEvent::Inverses);
+            return Point::AT_INFINITY;
+        }
+        let doubling = self == rhs;
+        let (numerator, denominator) = if doubling {
+            (self.x * self.x * 3, self.y * 2)
+        } else {
+            (rhs.y - self.y, rhs.x - self.x)
+        };
+        let lambda = numerator / denominator;
+        trace(AddEvent::Lambda { doubling, numerator, denominator, lambda });
+        let x = lambda * lambda - self.x - rhs.x;
+        let y = lambda * (self.x - x) - self.y;
+        trace(AddEvent::Result { x, y });
+        Point { x, y }
+    }
+
+    /// Multiplies the point by the scalar using double-and-add from the most significant bit,
+    /// reporting each bit, doubling and addition.
+    ///
+    /// The bits above the highest set bit are skipped, there are no events for zero scalar.
+    pub fn mul_traced(self, k: &Scalar, trace: &mut impl FnMut(MulEvent)) -> Point {
+        let k = k.to_u256();
+        let mut res = Point::AT_INFINITY;
+        for index in (0..k.bits()).rev() {
+            let set = k.bit(index);
+            trace(MulEvent::Bit { index, set });
+            res = res + res;
+            trace(MulEvent::Double { result: res });
+            if set {
+                res += self;
+                trace(MulEvent::Add { addend: self, result: res });
+            }
+        }
+        res
+    }
+}
+
+impl Zp {
+    /// Computes the multiplicative inverse using the extended Euclidean algorithm reporting each
+    /// division.
+    ///
+    /// Returns zero for zero, same as `multiplicative_inverse`.
+    pub fn inverse_traced(self, trace: &mut impl FnMut(InverseEvent)) -> Zp {
+        // invariant: tᵢ·self ≡ rᵢ (mod p)
+        let (mut r0, mut r1) = (crate::params::P, U256::from_be_bytes(&self.to_be_bytes()));
+        let (mut t0, mut t1) = (Zp::ZERO, Zp::ONE);
+        while !r1.is_zero() {
+            let (quotient, remainder) = r0.div_rem(r1);
+            let coefficient = t0 - Zp::wrapping_from(quotient) * t1;
+            trace(InverseEvent::Step { quotient, remainder, coefficient });
+            r0 = r1;
+            r1 = remainder;
+            t0 = t1;
+            t1 = coefficient;
+        }
+        // the GCD is one unless self is zero
+        if r0 == U256::one() {
+            t0
+        } else {
+            Zp::ZERO
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AddEvent, InverseEvent, MulEvent};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Point, Scalar, Zp, G, U256};
+
+    /// Checks that the events describe the computation completely by redoing it from them
+    fn replay_mul(base: Point, events: &[MulEvent]) -> (U256, Point) {
+        let mut k = U256::zero();
+        let mut acc = Point::AT_INFINITY;
+        let mut expected_index = None;
+        let mut pending_add = false;
+        for event in events {
+            match *event {
+                MulEvent::Bit { index, set } => {
+                    assert!(!pending_add, "missing addition");
+                    if let Some(expected) = expected_index {
+                        assert_eq!(index, expected);
+                    }
+                    expected_index = index.checked_sub(1);
+                    k = k << 1;
+                    if set {
+                        k = k + U256::one();
+                    }
+                    pending_add = set;
+                },
+                MulEvent::Double { result } => {
+                    acc = acc + acc;
+                    assert_eq!(result, acc);
+                },
+                MulEvent::Add { addend, result } => {
+                    assert!(pending_add, "addition of a zero bit");
+                    assert_eq!(addend, base);
+                    acc += addend;
+                    assert_eq!(result, acc);
+                    pending_add = false;
+                },
+            }
+        }
+        assert!(!pending_add, "missing addition");
+        assert_eq!(expected_index, None, "not all bits were processed");
+        (k, acc)
+    }
+
+    #[test]
+    fn mul_replay() {
+        let mut rng = DeterministicRng::new(211);
+        let base = G * Scalar::random(&mut rng);
+        let scalars = [Scalar::ONE, Scalar::from(2), Scalar::from(0b1011), -Scalar::ONE, Scalar::random(&mut rng), Scalar::random(&mut rng)];
+        for k in &scalars {
+            let mut events = Vec::new();
+            let res = base.mul_traced(k, &mut |event| events.push(event));
+            assert_eq!(res, base * *k);
+            assert_eq!(replay_mul(base, &events), (k.to_u256(), res));
+            let additions = events.iter().filter(|event| matches!(event, MulEvent::Add { .. })).count();
+            assert_eq!(additions, (0..256).filter(|bit| k.to_u256().bit(*bit)).count());
+        }
+
+        let mut events = Vec::new();
+        assert!(G.mul_traced(&Scalar::ZERO, &mut |event| events.push(event)).is_at_infinity());
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn add_replay() {
+        let p = G * 5;
+        let q = G * 7;
+        let cases = [(p, q, false), (p, p, true), (q, p, false)];
+        for (a, b, doubling) in &cases {
+            let mut events = Vec::new();
+            let res = a.add_traced(*b, &mut |event| events.push(event));
+            assert_eq!(res, *a + *b);
+            let lambda = match events[0] {
+                AddEvent::Lambda { doubling: reported, numerator, denominator, lambda } => {
+                    assert_eq!(reported, *doubling);
+                    assert_eq!(lambda * denominator, numerator);
+                    lambda
+                },
+                other => panic!("unexpected event {:?}", other),
+            };
+            let x = lambda * lambda - a.x() - b.x();
+            let y = lambda * (a.x() - x) - a.y();
+            assert_eq!(events[1..], [AddEvent::Result { x, y }]);
+            assert_eq!(Point::new(x, y), Some(res));
+        }
+
+        let mut events = Vec::new();
+        assert_eq!(p.add_traced(Point::AT_INFINITY, &mut |event| events.push(event)), p);
+        assert_eq!(Point::AT_INFINITY.add_traced(p, &mut |event| events.push(event)), p);
+        assert!(p.add_traced(-p, &mut |event| events.push(event)).is_at_infinity());
+        assert_eq!(events, [AddEvent::OperandAtInfinity, AddEvent::OperandAtInfinity, AddEvent::Inverses]);
+    }
+
+    #[test]
+    fn inverse_replay() {
+        let mut rng = DeterministicRng::new(211);
+        let values = [Zp::ONE, Zp::wrapping_from(U256::from(2)), -Zp::ONE, G.x(), (G * Scalar::random(&mut rng)).x()];
+        for a in &values {
+            let mut events = Vec::new();
+            let inverse = a.inverse_traced(&mut |event| events.push(event));
+            assert_eq!(inverse, a.multiplicative_inverse());
+            assert_eq!(inverse * *a, Zp::ONE);
+
+            // the remainders follow from the quotients and the coefficients satisfy the invariant
+            let (mut r0, mut r1) = (crate::params::P, U256::from_be_bytes(&a.to_be_bytes()));
+            for event in &events {
+                let InverseEvent::Step { quotient, remainder, coefficient } = *event;
+                assert_eq!(r0.div_rem(r1), (quotient, remainder));
+                assert_eq!(coefficient * *a, Zp::wrapping_from(remainder));
+                r0 = r1;
+                r1 = remainder;
+            }
+            assert_eq!((r0, r1), (U256::one(), U256::zero()));
+        }
+
+        let mut events = Vec::new();
+        assert_eq!(Zp::ZERO.inverse_traced(&mut |event| events.push(event)), Zp::ZERO);
+        assert!(events.is_empty());
+    }
+}
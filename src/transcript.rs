@@ -0,0 +1,113 @@
+//! Fiat–Shamir transcripts
+//!
+//! A transcript accumulates the public data of a proof and derives challenges from it. It's a
+//! tagged hash with the domain as the tag where every item is framed as
+//! `len(label) || label || len(data) || data` with lengths as 8-byte big-endian numbers so
+//! different sequences of items can never produce the same hash input.
+//!
+//! Points are appended compressed (the point at infinity as 33 zero bytes) and scalars as 32
+//! big-endian bytes. A challenge with label `l` is `Scalar::hash_to_scalar` of the domain and
+//! all the labels and data followed by `l` and empty data, that is
+//! `hash(state || frame(l, "") || 0x00) || hash(state || frame(l, "") || 0x01)` reduced modulo
+//! the curve order. The challenge is then appended to the transcript under the same label so
+//! subsequent challenges depend on it.
+
+use crate::tagged_hash::TaggedHash;
+use crate::{Point, Scalar};
+
+/// Accumulated public data of a proof
+#[derive(Clone)]
+pub struct Transcript {
+    hasher: TaggedHash,
+}
+
+impl Transcript {
+    /// Starts the transcript of the protocol identified by `domain`
+    pub fn new(domain: &str) -> Self {
+        Transcript { hasher: TaggedHash::new(domain) }
+    }
+
+    pub fn append_message(&mut self, label: &str, bytes: &[u8]) {
+        self.hasher.update_framed(label.as_bytes());
+        self.hasher.update_framed(bytes);
+    }
+
+    pub fn append_point(&mut self, label: &str, point: &Point) {
+        self.append_message(label, &point.serialize_compressed());
+    }
+
+    pub fn append_scalar(&mut self, label: &str, scalar: &Scalar) {
+        self.append_message(label, &scalar.to_be_bytes());
+    }
+
+    /// Derives the challenge from everything appended so far
+    pub fn challenge_scalar(&mut self, label: &str) -> Scalar {
+        let mut hasher = self.clone();
+        hasher.append_message(label, &[]);
+        let challenge = Scalar::from_framed_hash(&hasher.hasher);
+        self.append_scalar(label, &challenge);
+        challenge
+    }
+}
+
+/// Doesn't print the state since it's not meaningful
+impl core::fmt::Debug for Transcript {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Transcript(..)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Transcript;
+    use crate::{Point, Scalar, G};
+
+    fn challenge(items: &[(&str, &[u8])]) -> Scalar {
+        let mut transcript = Transcript::new("test");
+        for (label, data) in items {
+            transcript.append_message(label, data);
+        }
+        transcript.challenge_scalar("challenge")
+    }
+
+    #[test]
+    fn deterministic() {
+        let mut first = Transcript::new("test");
+        let mut second = Transcript::new("test");
+        for transcript in [&mut first, &mut second] {
+            transcript.append_point("point", &G);
+            transcript.append_scalar("scalar", &Scalar::from(42));
+        }
+        let challenge = first.challenge_scalar("e");
+        assert_eq!(second.challenge_scalar("e"), challenge);
+        // the challenge is absorbed
+        assert_ne!(first.challenge_scalar("e"), challenge);
+        assert_ne!(Transcript::new("other").challenge_scalar("e"), Transcript::new("test").challenge_scalar("e"));
+    }
+
+    #[test]
+    fn order_and_labels() {
+        let reference = challenge(&[("a", b"1"), ("b", b"2")]);
+        assert_ne!(challenge(&[("b", b"2"), ("a", b"1")]), reference);
+        assert_ne!(challenge(&[("a", b"1"), ("c", b"2")]), reference);
+        assert_ne!(challenge(&[("a", b"2"), ("b", b"1")]), reference);
+
+        // points are just serialized
+        let mut transcript = Transcript::new("test");
+        transcript.append_point("a", &Point::AT_INFINITY);
+        assert_eq!(transcript.challenge_scalar("challenge"), challenge(&[("a", &[0; 33])]));
+    }
+
+    #[test]
+    fn framing() {
+        assert_ne!(challenge(&[("m", b"ab"), ("m", b"c")]), challenge(&[("m", b"a"), ("m", b"bc")]));
+        assert_ne!(challenge(&[("ab", b"c")]), challenge(&[("a", b"bc")]));
+        assert_ne!(challenge(&[("m", b"")]), challenge(&[]));
+    }
+
+    #[test]
+    fn matches_hash_to_scalar() {
+        let expected = Scalar::hash_to_scalar("test", &[b"a", b"1", b"bc", b"", b"challenge", b""]);
+        assert_eq!(challenge(&[("a", b"1"), ("bc", b"")]), expected);
+    }
+}
@@ -0,0 +1,571 @@
+//! 256-bit unsigned integers
+//!
+//! Only the operations this crate needs are implemented. Most of them are `const fn` so that
+//! constants can be computed at compile time. Like with primitive integers the operators panic
+//! on overflow and division by zero, use the `overflowing_*` and `wrapping_*` methods to wrap
+//! around instead.
+//!
+//! The conversions from and to `bigint::U256` used by older versions of this crate are available
+//! with the `bigint` feature.
+//!
+//! Addition, subtraction and multiplication of limbs are implemented by one of two backends. On
+//! 32-bit targets or with the `limbs32` feature the limbs are processed as 32-bit digits so that
+//! no 128-bit products are needed. The layout and the results are the same for both.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, BitAnd, Div, Mul, Rem, Shl, Shr, Sub};
+
+#[cfg(any(not(any(feature = "limbs32", target_pointer_width = "32")), test))]
+mod limbs64;
+#[cfg(any(feature = "limbs32", target_pointer_width = "32", test))]
+mod limbs32;
+
+#[cfg(not(any(feature = "limbs32", target_pointer_width = "32")))]
+use limbs64 as backend;
+#[cfg(any(feature = "limbs32", target_pointer_width = "32"))]
+use limbs32 as backend;
+
+/// 256-bit unsigned integer stored as four little-endian 64-bit limbs
+///
+/// `U256([1, 0, 0, 0])` is one.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct U256(pub [u64; 4]);
+
+impl U256 {
+    pub const fn zero() -> Self {
+        U256([0; 4])
+    }
+
+    pub const fn one() -> Self {
+        U256([1, 0, 0, 0])
+    }
+
+    pub const fn max_value() -> Self {
+        U256([u64::MAX; 4])
+    }
+
+    pub const fn from_u64(value: u64) -> Self {
+        U256([value, 0, 0, 0])
+    }
+
+    /// Parses 32 big-endian bytes
+    pub const fn from_be_bytes(bytes: &[u8; 32]) -> Self {
+        let mut limbs = [0; 4];
+        let mut i = 0;
+        while i < 32 {
+            limbs[3 - i / 8] |= (bytes[i] as u64) << (56 - i % 8 * 8);
+            i += 1;
+        }
+        U256(limbs)
+    }
+
+    /// Serializes the value as 32 big-endian bytes
+    pub const fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        let mut i = 0;
+        while i < 32 {
+            bytes[i] = (self.0[3 - i / 8] >> (56 - i % 8 * 8)) as u8;
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Serializes the value as 32 little-endian bytes
+    pub const fn to_le_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        let mut i = 0;
+        while i < 32 {
+            bytes[i] = (self.0[i / 8] >> (i % 8 * 8)) as u8;
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Parses up to 32 little-endian bytes
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is longer than 32.
+    pub const fn from_little_endian(bytes: &[u8]) -> Self {
+        assert!(bytes.len() <= 32, "U256 can't hold more than 32 bytes");
+        let mut limbs = [0; 4];
+        let mut i = 0;
+        while i < bytes.len() {
+            limbs[i / 8] |= (bytes[i] as u64) << (i % 8 * 8);
+            i += 1;
+        }
+        U256(limbs)
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.0[0] | self.0[1] | self.0[2] | self.0[3] == 0
+    }
+
+    const fn is_one(&self) -> bool {
+        self.0[0] == 1 && self.0[1] | self.0[2] | self.0[3] == 0
+    }
+
+    pub const fn is_even(&self) -> bool {
+        self.0[0] & 1 == 0
+    }
+
+    pub const fn low_u64(&self) -> u64 {
+        self.0[0]
+    }
+
+    /// Returns the bit at `index`, zero being the least significant one
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than 256.
+    pub const fn bit(&self, index: usize) -> bool {
+        self.0[index / 64] >> (index % 64) & 1 == 1
+    }
+
+    /// The number of bits needed to represent the value
+    pub const fn bits(&self) -> usize {
+        let mut i = 4;
+        while i > 0 {
+            i -= 1;
+            if self.0[i] != 0 {
+                return i * 64 + 64 - self.0[i].leading_zeros() as usize;
+            }
+        }
+        0
+    }
+
+    /// Compares the values, usable in `const` contexts unlike `Ord`
+    pub const fn const_cmp(&self, other: &Self) -> Ordering {
+        let mut i = 4;
+        while i > 0 {
+            i -= 1;
+            if self.0[i] < other.0[i] {
+                return Ordering::Less;
+            }
+            if self.0[i] > other.0[i] {
+                return Ordering::Greater;
+            }
+        }
+        Ordering::Equal
+    }
+
+    /// Adds the values returning the result modulo `2²⁵⁶` and whether it overflowed
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (res, carry) = backend::add(&self.0, &rhs.0);
+        (U256(res), carry)
+    }
+
+    /// Subtracts the values returning the result modulo `2²⁵⁶` and whether it underflowed
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (res, borrow) = backend::sub(&self.0, &rhs.0);
+        (U256(res), borrow)
+    }
+
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Computes the full 512-bit product returning the low and high halves
+    pub const fn widening_mul(self, rhs: Self) -> (Self, Self) {
+        let res = backend::widening_mul(&self.0, &rhs.0);
+        (U256([res[0], res[1], res[2], res[3]]), U256([res[4], res[5], res[6], res[7]]))
+    }
+
+    /// Multiplies the values returning the result modulo `2²⁵⁶` and whether it overflowed
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (low, high) = self.widening_mul(rhs);
+        (low, !high.is_zero())
+    }
+
+    /// Shifts the value left discarding the bits shifted out, the result is zero for `bits >= 256`
+    pub const fn wrapping_shl(self, bits: usize) -> Self {
+        if bits >= 256 {
+            return U256::zero();
+        }
+        let limbs = bits / 64;
+        let bits = bits % 64;
+        let mut res = [0; 4];
+        let mut i = limbs;
+        while i < 4 {
+            res[i] = self.0[i - limbs] << bits;
+            if bits > 0 && i > limbs {
+                res[i] |= self.0[i - limbs - 1] >> (64 - bits);
+            }
+            i += 1;
+        }
+        U256(res)
+    }
+
+    /// Shifts the value right, the result is zero for `bits >= 256`
+    pub const fn wrapping_shr(self, bits: usize) -> Self {
+        if bits >= 256 {
+            return U256::zero();
+        }
+        let limbs = bits / 64;
+        let bits = bits % 64;
+        let mut res = [0; 4];
+        let mut i = 0;
+        while i + limbs < 4 {
+            res[i] = self.0[i + limbs] >> bits;
+            if bits > 0 && i + limbs < 3 {
+                res[i] |= self.0[i + limbs + 1] << (64 - bits);
+            }
+            i += 1;
+        }
+        U256(res)
+    }
+
+    /// Computes the quotient and the remainder using long division
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    pub const fn div_rem(self, divisor: Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "division by zero");
+        let mut quotient = U256::zero();
+        let mut remainder = U256::zero();
+        let mut i = self.bits();
+        while i > 0 {
+            i -= 1;
+            // remainder < divisor < 2²⁵⁶ so the shifted value has at most 257 bits
+            let overflow = remainder.bit(255);
+            remainder = remainder.wrapping_shl(1);
+            remainder.0[0] |= self.bit(i) as u64;
+            if overflow || !matches!(remainder.const_cmp(&divisor), Ordering::Less) {
+                remainder = remainder.wrapping_sub(divisor);
+                quotient.0[i / 64] |= 1 << (i % 64);
+            }
+        }
+        (quotient, remainder)
+    }
+
+    /// Computes the multiplicative inverse modulo `modulus` using binary extended Euclidean
+    /// algorithm.
+    ///
+    /// Returns zero if the inverse doesn't exist - `self` is a multiple of `modulus` or they are
+    /// not coprime. This is **NOT CONSTANT TIME!!!**
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is even.
+    pub const fn mod_inverse(self, modulus: Self) -> Self {
+        assert!(!modulus.is_even(), "the modulus must be odd");
+        // invariants: x1·self ≡ u and x2·self ≡ v (mod modulus)
+        let mut u = self.div_rem(modulus).1;
+        let mut v = modulus;
+        let mut x1 = U256::one();
+        let mut x2 = U256::zero();
+        loop {
+            if u.is_one() {
+                return x1;
+            }
+            if v.is_one() {
+                return x2;
+            }
+            // both are divisible by the GCD so they can only be zero if it's greater than one
+            if u.is_zero() || v.is_zero() {
+                return U256::zero();
+            }
+            while u.is_even() {
+                u = u.wrapping_shr(1);
+                x1 = half_mod(x1, modulus);
+            }
+            while v.is_even() {
+                v = v.wrapping_shr(1);
+                x2 = half_mod(x2, modulus);
+            }
+            if matches!(u.const_cmp(&v), Ordering::Less) {
+                v = v.wrapping_sub(u);
+                x2 = sub_mod(x2, x1, modulus);
+            } else {
+                u = u.wrapping_sub(v);
+                x1 = sub_mod(x1, x2, modulus);
+            }
+        }
+    }
+}
+
+/// Computes `value / 2 mod modulus` for odd modulus and `value < modulus`
+const fn half_mod(value: U256, modulus: U256) -> U256 {
+    if value.is_even() {
+        return value.wrapping_shr(1);
+    }
+    let (sum, carry) = value.overflowing_add(modulus);
+    let mut res = sum.wrapping_shr(1);
+    res.0[3] |= (carry as u64) << 63;
+    res
+}
+
+/// Computes `a - b mod modulus` for `a, b < modulus`
+const fn sub_mod(a: U256, b: U256, modulus: U256) -> U256 {
+    let (diff, borrow) = a.overflowing_sub(b);
+    if borrow {
+        diff.wrapping_add(modulus)
+    } else {
+        diff
+    }
+}
+
+impl From<u64> for U256 {
+    fn from(value: u64) -> Self {
+        U256::from_u64(value)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl From<bigint::U256> for U256 {
+    fn from(value: bigint::U256) -> Self {
+        U256(value.0)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl From<U256> for bigint::U256 {
+    fn from(value: U256) -> Self {
+        bigint::U256(value.0)
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.const_cmp(other)
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for U256 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        match self.overflowing_add(rhs) {
+            (res, false) => res,
+            (_, true) => panic!("arithmetic operation overflow"),
+        }
+    }
+}
+
+impl Sub for U256 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        match self.overflowing_sub(rhs) {
+            (res, false) => res,
+            (_, true) => panic!("arithmetic operation overflow"),
+        }
+    }
+}
+
+impl Mul for U256 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        match self.overflowing_mul(rhs) {
+            (res, false) => res,
+            (_, true) => panic!("arithmetic operation overflow"),
+        }
+    }
+}
+
+impl Div for U256 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for U256 {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Shl<usize> for U256 {
+    type Output = Self;
+
+    fn shl(self, rhs: usize) -> Self::Output {
+        self.wrapping_shl(rhs)
+    }
+}
+
+impl Shr<usize> for U256 {
+    type Output = Self;
+
+    fn shr(self, rhs: usize) -> Self::Output {
+        self.wrapping_shr(rhs)
+    }
+}
+
+impl BitAnd for U256 {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        U256([self.0[0] & rhs.0[0], self.0[1] & rhs.0[1], self.0[2] & rhs.0[2], self.0[3] & rhs.0[3]])
+    }
+}
+
+/// Displays the value as big-endian hex
+impl fmt::Debug for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("0x")?;
+        crate::write_hex(f, &self.to_be_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::U256;
+    use crate::test_utils::DeterministicRng;
+    use rand_core::RngCore;
+
+    // kept small because bigint's mod_inverse is slow
+    const INVERSES: usize = 1000;
+    const ITERATIONS: usize = 100_000;
+
+    /// Random values with varying bit lengths so that short operands are covered too
+    fn random(rng: &mut DeterministicRng) -> U256 {
+        let value = U256([rng.next_u64(), rng.next_u64(), rng.next_u64(), rng.next_u64()]);
+        value.wrapping_shr(rng.next_u32() as usize % 256)
+    }
+
+    fn old(value: U256) -> bigint::U256 {
+        bigint::U256(value.0)
+    }
+
+    #[test]
+    fn matches_bigint() {
+        let mut rng = DeterministicRng::new(1);
+        for _ in 0..ITERATIONS {
+            let a = random(&mut rng);
+            let b = random(&mut rng);
+            let shift = rng.next_u32() as usize % 300;
+            assert_eq!(a.overflowing_add(b).0 .0, old(a).overflowing_add(old(b)).0 .0);
+            assert_eq!(a.overflowing_add(b).1, old(a).overflowing_add(old(b)).1);
+            assert_eq!(a.overflowing_sub(b).0 .0, old(a).overflowing_sub(old(b)).0 .0);
+            assert_eq!(a.overflowing_sub(b).1, old(a).overflowing_sub(old(b)).1);
+            assert_eq!(a.overflowing_mul(b).0 .0, old(a).overflowing_mul(old(b)).0 .0);
+            assert_eq!(a.overflowing_mul(b).1, old(a).overflowing_mul(old(b)).1);
+            assert_eq!(a.cmp(&b), old(a).cmp(&old(b)));
+            assert_eq!((a << shift).0, (old(a) << shift).0);
+            assert_eq!((a >> shift).0, (old(a) >> shift).0);
+            assert_eq!(a.bits(), old(a).bits());
+            assert_eq!(a.to_be_bytes(), { let mut bytes = [0; 32]; old(a).to_big_endian(&mut bytes); bytes });
+            assert_eq!(U256::from_be_bytes(&a.to_be_bytes()), a);
+            assert_eq!(a.to_le_bytes(), { let mut bytes = [0; 32]; old(a).to_little_endian(&mut bytes); bytes });
+            assert_eq!(U256::from_little_endian(&a.to_le_bytes()), a);
+            if !b.is_zero() {
+                assert_eq!((a / b).0, (old(a) / old(b)).0);
+                assert_eq!((a % b).0, (old(a) % old(b)).0);
+            }
+        }
+    }
+
+    #[test]
+    fn mod_inverse_matches_bigint() {
+        let mut rng = DeterministicRng::new(2);
+        for modulus in [crate::params::P, crate::params::N] {
+            assert_eq!(U256::zero().mod_inverse(modulus), U256::zero());
+            assert_eq!(modulus.mod_inverse(modulus), U256::zero());
+            assert_eq!(U256::one().mod_inverse(modulus), U256::one());
+            for _ in 0..INVERSES {
+                let a = random(&mut rng) % modulus;
+                assert_eq!(a.mod_inverse(modulus).0, old(a).mod_inverse(old(modulus)).0);
+            }
+        }
+        // not coprime
+        assert_eq!(U256::from(6).mod_inverse(U256::from(9)), U256::zero());
+        assert_eq!(U256::from(2).mod_inverse(U256::from(9)), U256::from(5));
+    }
+
+    #[test]
+    fn const_evaluation() {
+        const SHIFTED: U256 = U256::from_u64(3).wrapping_shl(254);
+        const BYTES: [u8; 32] = SHIFTED.to_be_bytes();
+        const INVERSE: U256 = U256::from_u64(3).mod_inverse(crate::params::P);
+        assert_eq!(BYTES[0], 0xC0);
+        assert_eq!(crate::Zp::wrapping_from(INVERSE) * crate::Zp::wrapping_from(U256::from(3)), crate::Zp::ONE);
+        assert_eq!(U256::from_little_endian(&[1, 2]), U256::from(0x0201));
+        assert_eq!(U256::max_value().overflowing_add(U256::one()), (U256::zero(), true));
+        assert_eq!(format!("{:?}", U256::from(0xAB)), format!("0x{:064x}", 0xAB));
+    }
+
+    macro_rules! backend_tests {
+        ($backend:ident) => {
+            mod $backend {
+                use super::{old, random, ITERATIONS};
+                use crate::test_utils::DeterministicRng;
+                use crate::uint::$backend as backend;
+
+                #[test]
+                fn matches_bigint() {
+                    let mut rng = DeterministicRng::new(3);
+                    for _ in 0..ITERATIONS {
+                        let a = random(&mut rng);
+                        let b = random(&mut rng);
+                        let (sum, carry) = old(a).overflowing_add(old(b));
+                        assert_eq!(backend::add(&a.0, &b.0), (sum.0, carry));
+                        let (diff, borrow) = old(a).overflowing_sub(old(b));
+                        assert_eq!(backend::sub(&a.0, &b.0), (diff.0, borrow));
+                        assert_eq!(backend::widening_mul(&a.0, &b.0), old(a).full_mul(old(b)).0);
+                    }
+                }
+
+                #[test]
+                fn edge_cases() {
+                    let max = [u64::MAX; 4];
+                    assert_eq!(backend::add(&max, &[1, 0, 0, 0]), ([0; 4], true));
+                    assert_eq!(backend::add(&[u64::MAX, 0, 0, 0], &[1, 0, 0, 0]), ([0, 1, 0, 0], false));
+                    assert_eq!(backend::sub(&[0; 4], &[1, 0, 0, 0]), (max, true));
+                    assert_eq!(backend::sub(&[0, 1, 0, 0], &[1, 0, 0, 0]), ([u64::MAX, 0, 0, 0], false));
+                    // (2²⁵⁶ - 1)² = 2⁵¹² - 2²⁵⁷ + 1
+                    assert_eq!(backend::widening_mul(&max, &max), [1, 0, 0, 0, u64::MAX - 1, u64::MAX, u64::MAX, u64::MAX]);
+                }
+            }
+        }
+    }
+
+    backend_tests!(limbs64);
+    backend_tests!(limbs32);
+
+    #[test]
+    fn backends_agree() {
+        // random sequences of operations feeding the results back
+        let mut rng = DeterministicRng::new(4);
+        let mut state64 = random(&mut rng).0;
+        let mut state32 = state64;
+        for _ in 0..ITERATIONS {
+            let operand = random(&mut rng).0;
+            match rng.next_u32() % 3 {
+                0 => {
+                    state64 = super::limbs64::add(&state64, &operand).0;
+                    state32 = super::limbs32::add(&state32, &operand).0;
+                },
+                1 => {
+                    state64 = super::limbs64::sub(&state64, &operand).0;
+                    state32 = super::limbs32::sub(&state32, &operand).0;
+                },
+                _ => {
+                    let product64 = super::limbs64::widening_mul(&state64, &operand);
+                    let product32 = super::limbs32::widening_mul(&state32, &operand);
+                    assert_eq!(product64, product32);
+                    // the high half so that the state doesn't collapse to zero
+                    state64 = [product64[4], product64[5], product64[6], product64[7] | 1];
+                    state32 = [product32[4], product32[5], product32[6], product32[7] | 1];
+                },
+            }
+            assert_eq!(state64, state32);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Arithmetic on 32-bit digits for targets without fast 64×64-bit multiplication
+//!
+//! The limbs are still stored as `u64` so that the layout of `U256` is the same on all targets,
+//! each of them is processed as two 32-bit digits. This avoids 128-bit products which need
+//! compiler intrinsics on 32-bit targets.
+
+/// Returns the `i`-th least significant 32-bit digit
+const fn digit(limbs: &[u64; 4], i: usize) -> u64 {
+    limbs[i / 2] >> (i % 2 * 32) & 0xFFFF_FFFF
+}
+
+/// Adds the values returning the carry
+pub(super) const fn add(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut res = [0; 4];
+    let mut carry = 0;
+    let mut i = 0;
+    while i < 8 {
+        let sum = digit(a, i) + digit(b, i) + carry;
+        res[i / 2] |= (sum & 0xFFFF_FFFF) << (i % 2 * 32);
+        carry = sum >> 32;
+        i += 1;
+    }
+    (res, carry == 1)
+}
+
+/// Subtracts the values returning the borrow
+pub(super) const fn sub(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut res = [0; 4];
+    let mut borrow = 0;
+    let mut i = 0;
+    while i < 8 {
+        // wraps around to a value with the top bit set if the digit underflows
+        let diff = digit(a, i).wrapping_sub(digit(b, i)).wrapping_sub(borrow);
+        res[i / 2] |= (diff & 0xFFFF_FFFF) << (i % 2 * 32);
+        borrow = diff >> 63;
+        i += 1;
+    }
+    (res, borrow == 1)
+}
+
+/// Schoolbook multiplication using 32×32→64-bit products
+pub(super) const fn widening_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 8] {
+    let mut digits = [0u64; 16];
+    let mut i = 0;
+    while i < 8 {
+        let mut carry = 0;
+        let mut j = 0;
+        while j < 8 {
+            // at most (2³² - 1)² + 2·(2³² - 1) = 2⁶⁴ - 1
+            let product = digit(a, i) * digit(b, j) + digits[i + j] + carry;
+            digits[i + j] = product & 0xFFFF_FFFF;
+            carry = product >> 32;
+            j += 1;
+        }
+        digits[i + 8] = carry;
+        i += 1;
+    }
+    let mut res = [0; 8];
+    let mut k = 0;
+    while k < 8 {
+        res[k] = digits[2 * k] | digits[2 * k + 1] << 32;
+        k += 1;
+    }
+    res
+}
@@ -0,0 +1,50 @@
+//! Arithmetic on the 64-bit limbs directly, used on 64-bit targets
+
+/// Adds the values returning the carry
+pub(super) const fn add(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut res = [0; 4];
+    let mut carry = false;
+    let mut i = 0;
+    while i < 4 {
+        let (sum, first) = a[i].overflowing_add(b[i]);
+        let (sum, second) = sum.overflowing_add(carry as u64);
+        res[i] = sum;
+        carry = first | second;
+        i += 1;
+    }
+    (res, carry)
+}
+
+/// Subtracts the values returning the borrow
+pub(super) const fn sub(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut res = [0; 4];
+    let mut borrow = false;
+    let mut i = 0;
+    while i < 4 {
+        let (diff, first) = a[i].overflowing_sub(b[i]);
+        let (diff, second) = diff.overflowing_sub(borrow as u64);
+        res[i] = diff;
+        borrow = first | second;
+        i += 1;
+    }
+    (res, borrow)
+}
+
+/// Schoolbook multiplication using 64×64→128-bit products
+pub(super) const fn widening_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 8] {
+    let mut res = [0; 8];
+    let mut i = 0;
+    while i < 4 {
+        let mut carry = 0;
+        let mut j = 0;
+        while j < 4 {
+            let product = a[i] as u128 * b[j] as u128 + res[i + j] as u128 + carry as u128;
+            res[i + j] = product as u64;
+            carry = (product >> 64) as u64;
+            j += 1;
+        }
+        res[i + 4] = carry;
+        i += 1;
+    }
+    res
+}
@@ -0,0 +1,196 @@
+//! Validation of raw bytes without constructing the types
+//!
+//! Each function answers whether the corresponding parser would accept the bytes. They never
+//! panic and they accept exactly what the parsers accept so they can be used as a cheap pre-check
+//! (e.g. behind an FFI boundary) without a risk of disagreeing with them later.
+
+use crate::field::{add, eq, mul, pow};
+use crate::params::{B, N, P, SQRT_EXPONENT};
+use crate::{array_ref_32, U256};
+
+/// Checks whether the curve has a point with the x coordinate, `x` must be reduced.
+fn has_y(x: U256) -> bool {
+    let rhs = add(mul(mul(x, x), x), B.0);
+    let y = pow(rhs, SQRT_EXPONENT);
+    eq(mul(y, y), rhs)
+}
+
+/// Returns the value if it's less than `p`
+fn field_element(bytes: &[u8]) -> Option<U256> {
+    let value = U256::from_be_bytes(array_ref_32(bytes));
+    if value < P {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Checks that the big-endian value is non-zero and less than the curve order `n`.
+///
+/// Agrees with `SecretKey::from_bytes`.
+pub fn is_valid_seckey(bytes: &[u8; 32]) -> bool {
+    let value = U256::from_be_bytes(bytes);
+    !value.is_zero() && value < N
+}
+
+/// Checks that the bytes are a compressed (33 bytes, prefix `0x02` or `0x03`) or uncompressed
+/// (65 bytes, prefix `0x04`) SEC1 encoding of a point on the curve.
+///
+/// The coordinates must be less than `p`, hybrid encodings (prefix `0x06` or `0x07`) and the
+/// point at infinity are rejected. Agrees with `PublicKey::from_sec1_bytes`.
+pub fn is_valid_pubkey_sec1(bytes: &[u8]) -> bool {
+    match (bytes.len(), bytes.first()) {
+        (33, Some(0x02)) | (33, Some(0x03)) => is_valid_xonly(array_ref_32(&bytes[1..])),
+        (65, Some(0x04)) => match (field_element(&bytes[1..33]), field_element(&bytes[33..])) {
+            (Some(x), Some(y)) => eq(mul(y, y), add(mul(mul(x, x), x), B.0)),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Checks that the big-endian x coordinate is less than `p` and there's a point with it on the
+/// curve (BIP340 public key).
+///
+/// Agrees with `XOnlyPoint::from_bytes`.
+pub fn is_valid_xonly(bytes: &[u8; 32]) -> bool {
+    match field_element(bytes) {
+        Some(x) => has_y(x),
+        None => false,
+    }
+}
+
+/// Checks that both halves of the ECDSA `r || s` signature are non-zero and less than the curve
+/// order `n`.
+///
+/// This doesn't check whether `s` is low. Agrees with `ecdsa::Signature::from_compact`.
+pub fn is_valid_compact_sig(bytes: &[u8; 64]) -> bool {
+    is_valid_seckey(array_ref_32(&bytes[..32])) && is_valid_seckey(array_ref_32(&bytes[32..]))
+}
+
+/// Checks that the big-endian value is less than `p`.
+///
+/// Agrees with `Zp::from_be_bytes`.
+pub fn is_canonical_field_element(bytes: &[u8; 32]) -> bool {
+    field_element(bytes).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_canonical_field_element, is_valid_compact_sig, is_valid_pubkey_sec1, is_valid_seckey, is_valid_xonly};
+    use crate::params::{N, P};
+    use crate::test_utils::DeterministicRng;
+    use crate::{ecdsa, PublicKey, Scalar, SecretKey, XOnlyPoint, Zp, G, U256};
+    use rand_core::RngCore;
+
+    fn check_32(bytes: &[u8; 32]) {
+        assert_eq!(is_valid_seckey(bytes), SecretKey::from_bytes(bytes).is_ok(), "{:?}", bytes);
+        assert_eq!(is_valid_xonly(bytes), XOnlyPoint::from_bytes(bytes).is_ok(), "{:?}", bytes);
+        assert_eq!(is_canonical_field_element(bytes), Zp::from_be_bytes(bytes).is_some(), "{:?}", bytes);
+    }
+
+    fn check_64(bytes: &[u8; 64]) {
+        assert_eq!(is_valid_compact_sig(bytes), ecdsa::Signature::from_compact(bytes).is_ok(), "{:?}", &bytes[..]);
+    }
+
+    fn check_sec1(bytes: &[u8]) {
+        assert_eq!(is_valid_pubkey_sec1(bytes), PublicKey::from_sec1_bytes(bytes).is_ok(), "{:?}", bytes);
+    }
+
+    /// Values around the boundaries of the ranges
+    fn boundaries() -> Vec<[u8; 32]> {
+        let mut values = vec![[0; 32], [0xFF; 32]];
+        for bound in &[N, P] {
+            for offset in 0..3 {
+                values.push((*bound - U256::from(offset)).to_be_bytes());
+                values.push((*bound + U256::from(offset)).to_be_bytes());
+            }
+        }
+        values.push(U256::one().to_be_bytes());
+        values
+    }
+
+    #[test]
+    fn agrees_with_parsers_on_random_bytes() {
+        let mut rng = DeterministicRng::new(220);
+        for _ in 0..500 {
+            let mut bytes = [0; 65];
+            rng.fill_bytes(&mut bytes);
+            check_32(super::array_ref_32(&bytes[..32]));
+            let mut signature = [0; 64];
+            signature.copy_from_slice(&bytes[1..]);
+            check_64(&signature);
+            // random bytes are almost never valid without a valid prefix
+            for prefix in 0..8 {
+                bytes[0] = prefix;
+                check_sec1(&bytes);
+                check_sec1(&bytes[..33]);
+            }
+            let len = (rng.next_u32() % 66) as usize;
+            check_sec1(&bytes[..len]);
+        }
+    }
+
+    #[test]
+    fn agrees_with_parsers_on_structured_inputs() {
+        let mut rng = DeterministicRng::new(220);
+        let boundaries = boundaries();
+        for value in &boundaries {
+            check_32(value);
+            for other in &boundaries {
+                let mut signature = [0; 64];
+                signature[..32].copy_from_slice(value);
+                signature[32..].copy_from_slice(other);
+                check_64(&signature);
+            }
+        }
+
+        for _ in 0..50 {
+            let point = G * Scalar::random(&mut rng);
+            let compressed = point.serialize_compressed();
+            let uncompressed = point.serialize_uncompressed();
+            assert!(is_valid_pubkey_sec1(&compressed));
+            assert!(is_valid_pubkey_sec1(&uncompressed));
+            assert!(is_valid_xonly(super::array_ref_32(&compressed[1..])));
+            check_sec1(&compressed);
+            check_sec1(&uncompressed);
+
+            // wrong prefixes, truncated and extended encodings, off-curve y, the other y
+            for prefix in &[0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0xFF] {
+                let mut bytes = uncompressed;
+                bytes[0] = *prefix;
+                check_sec1(&bytes);
+                let mut bytes = compressed;
+                bytes[0] = *prefix;
+                check_sec1(&bytes);
+            }
+            check_sec1(&compressed[..32]);
+            check_sec1(&[&uncompressed[..], &[0]].concat());
+            let mut off_curve = uncompressed;
+            off_curve[64] ^= 1;
+            check_sec1(&off_curve);
+            let mut negated = uncompressed;
+            negated[33..].copy_from_slice(&(-point).y().to_be_bytes());
+            check_sec1(&negated);
+            // coordinates not less than p
+            for value in &boundaries {
+                let mut bytes = compressed;
+                bytes[1..].copy_from_slice(value);
+                check_sec1(&bytes);
+                let mut bytes = uncompressed;
+                bytes[33..].copy_from_slice(value);
+                check_sec1(&bytes);
+                let mut bytes = uncompressed;
+                bytes[1..33].copy_from_slice(value);
+                check_sec1(&bytes);
+            }
+        }
+        // the point at infinity
+        check_sec1(&[0; 65]);
+        let mut infinity = [0; 65];
+        infinity[0] = 0x04;
+        check_sec1(&infinity);
+        assert!(!is_valid_pubkey_sec1(&infinity));
+        check_sec1(&[]);
+    }
+}
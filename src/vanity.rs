@@ -0,0 +1,214 @@
+//! Search for keys whose encoding has a chosen prefix
+//!
+//! Computing `k·G` for each candidate would be wasteful. The search instead picks a random
+//! starting secret `k` and walks `k, k + 1, k + 2, ...` while adding `G` to the public point, so
+//! each candidate costs a single point addition. The match is as unpredictable as the starting
+//! point but keys found by continuing the same walk are related - knowing one of them reveals
+//! the others, so a fresh search should be started for each key.
+//!
+//! The cost of the search is dominated by the matcher for address prefixes since computing the
+//! address requires hashing and encoding. Each character of a hex prefix divides the probability
+//! of a match by 16, each character of a base58 or bech32 prefix by roughly 58 or 32.
+//! **NOT CONSTANT TIME!!!**
+
+use rand_core::{CryptoRng, RngCore};
+use crate::{Point, PublicKey, Scalar, SecretKey, G};
+#[cfg(all(feature = "bitcoin-hashes", feature = "alloc"))]
+use crate::Network;
+
+/// Consecutive secrets starting at a given one paired with their public keys
+struct Walk {
+    secret: Scalar,
+    point: Point,
+}
+
+impl Walk {
+    fn new(start: Scalar) -> Self {
+        Walk { secret: start, point: Point::mul_gen(&start) }
+    }
+}
+
+impl Iterator for Walk {
+    type Item = (SecretKey, PublicKey);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (secret, point) = (self.secret, self.point);
+            self.secret += Scalar::ONE;
+            self.point += G;
+            // zero is skipped when the walk wraps around the curve order
+            if let Some(secret_key) = SecretKey::from_scalar(secret) {
+                let public_key = PublicKey::from_point(point).expect("non-zero secret has finite point");
+                break Some((secret_key, public_key));
+            }
+        }
+    }
+}
+
+/// Tries up to `max_iters` consecutive keys starting at a random one, returning the first one
+/// accepted by `matcher`.
+pub fn search<R: RngCore + CryptoRng>(matcher: impl Fn(&PublicKey) -> bool, rng: &mut R, max_iters: usize) -> Option<(SecretKey, PublicKey)> {
+    Walk::new(Scalar::random(rng))
+        .take(max_iters)
+        .find(|(_, public_key)| matcher(public_key))
+}
+
+/// Same as `search` but splits the `max_iters` keys into `threads` disjoint consecutive ranges
+/// searched in parallel.
+///
+/// All threads stop as soon as one of them finds a match. If several threads find a match at
+/// the same time the one searching the lowest range is returned. Panics if `threads` is zero.
+#[cfg(feature = "std")]
+pub fn search_parallel<R: RngCore + CryptoRng>(matcher: impl Fn(&PublicKey) -> bool + Sync, rng: &mut R, max_iters: usize, threads: usize) -> Option<(SecretKey, PublicKey)> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    assert!(threads > 0, "at least one thread is required");
+    let start = Scalar::random(rng);
+    let range_len = max_iters.div_ceil(threads);
+    let found = AtomicBool::new(false);
+    let (matcher, found) = (&matcher, &found);
+    std::thread::scope(|scope| {
+        let handles = (0..threads)
+            .map(|i| {
+                let offset = i * range_len;
+                let len = range_len.min(max_iters.saturating_sub(offset));
+                scope.spawn(move || {
+                    let result = Walk::new(start + Scalar::from(offset as u64))
+                        .take(len)
+                        .take_while(|_| !found.load(Ordering::Relaxed))
+                        .find(|(_, public_key)| matcher(public_key));
+                    if result.is_some() {
+                        found.store(true, Ordering::Relaxed);
+                    }
+                    result
+                })
+            })
+            .collect::<std::vec::Vec<_>>();
+        handles
+            .into_iter()
+            .filter_map(|handle| handle.join().expect("the matcher panicked"))
+            .next()
+    })
+}
+
+/// Checks whether hex encoding of `bytes` starts with `prefix`, ignoring case
+fn hex_starts_with(bytes: &[u8], prefix: &str) -> bool {
+    prefix.len() <= bytes.len() * 2 && prefix.chars().enumerate().all(|(i, c)| {
+        let nibble = if i % 2 == 0 { bytes[i / 2] >> 4 } else { bytes[i / 2] & 0x0F };
+        c.to_digit(16) == Some(u32::from(nibble))
+    })
+}
+
+/// Matches keys whose compressed SEC1 encoding in hex starts with `prefix`.
+///
+/// The first byte is `02` or `03` so useful prefixes start with it.
+pub fn compressed_hex_prefix(prefix: &str) -> impl Fn(&PublicKey) -> bool + Send + Sync + '_ {
+    move |public_key| hex_starts_with(&public_key.serialize(), prefix)
+}
+
+/// Matches keys whose x-only (BIP340) encoding in hex starts with `prefix`
+pub fn x_only_hex_prefix(prefix: &str) -> impl Fn(&PublicKey) -> bool + Send + Sync + '_ {
+    move |public_key| hex_starts_with(&public_key.x_only_public_key().0.serialize(), prefix)
+}
+
+/// Matches keys whose P2PKH address (of the compressed key) starts with `prefix`.
+///
+/// Mainnet addresses start with `1` and testnet ones with `m` or `n` so useful prefixes start
+/// with it.
+#[cfg(all(feature = "bitcoin-hashes", feature = "alloc"))]
+pub fn p2pkh_address_prefix(prefix: &str, network: Network) -> impl Fn(&PublicKey) -> bool + Send + Sync + '_ {
+    move |public_key| public_key.p2pkh_address(network).starts_with(prefix)
+}
+
+/// Matches keys whose P2TR address spending by the key only (BIP86) starts with `prefix`.
+///
+/// The key is the internal key tweaked without a script tree. The addresses start with `hrp`
+/// followed by `1p` so useful prefixes start with it. Computing the tweak requires a scalar
+/// multiplication so this is much slower than the other matchers.
+#[cfg(all(feature = "hashes", feature = "alloc"))]
+pub fn p2tr_address_prefix<'a>(prefix: &'a str, hrp: &'a str) -> impl Fn(&PublicKey) -> bool + Send + Sync + 'a {
+    move |public_key| {
+        let internal = public_key.x_only_public_key().0;
+        crate::taproot::output_key(&internal, None)
+            .map(|(output_key, _)| output_key.p2tr_address(hrp).starts_with(prefix))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compressed_hex_prefix, search, x_only_hex_prefix, Walk};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Point, Scalar, G};
+
+    #[test]
+    fn walk_matches_multiplication() {
+        let mut rng = DeterministicRng::new(243);
+        let start = Scalar::random(&mut rng);
+        let checkpoints = [0, 1, 2, 17, 100, 255];
+        let mut walk = Walk::new(start).enumerate();
+        for checkpoint in &checkpoints {
+            let (_, (secret_key, public_key)) = walk.find(|(i, _)| i == checkpoint).unwrap();
+            let expected = start + Scalar::from(*checkpoint as u64);
+            assert_eq!(secret_key.to_scalar(), expected);
+            assert_eq!(public_key.to_point(), G * expected);
+        }
+    }
+
+    #[test]
+    fn walk_skips_zero() {
+        let minus_two = -Scalar::from(2);
+        let secrets = Walk::new(minus_two).take(3).map(|(secret_key, _)| secret_key.to_scalar()).collect::<Vec<_>>();
+        assert_eq!(secrets, [minus_two, -Scalar::ONE, Scalar::ONE]);
+        let points = Walk::new(minus_two).take(3).map(|(_, public_key)| public_key.to_point()).collect::<Vec<_>>();
+        assert_eq!(points, [G * minus_two, -G, G]);
+        assert!(!points.contains(&Point::AT_INFINITY));
+    }
+
+    #[test]
+    fn hex_prefixes() {
+        let mut rng = DeterministicRng::new(243);
+        let (secret_key, public_key) = search(x_only_hex_prefix("f"), &mut rng, 1000).unwrap();
+        assert_eq!(secret_key.public_key(), public_key);
+        assert_eq!(public_key.x_only_public_key().0.serialize()[0] >> 4, 0xF);
+
+        let (secret_key, public_key) = search(compressed_hex_prefix("03A"), &mut rng, 1000).unwrap();
+        assert_eq!(secret_key.public_key(), public_key);
+        assert_eq!(public_key.serialize()[0], 0x03);
+        assert_eq!(public_key.serialize()[1] >> 4, 0xA);
+
+        // impossible prefixes
+        assert_eq!(search(compressed_hex_prefix("04"), &mut rng, 100), None);
+        assert_eq!(search(x_only_hex_prefix(&"0".repeat(65)), &mut rng, 10), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "bitcoin-hashes", feature = "alloc"))]
+    fn address_prefixes() {
+        use super::{p2pkh_address_prefix, p2tr_address_prefix};
+        use crate::Network;
+
+        let mut rng = DeterministicRng::new(243);
+        let (secret_key, public_key) = search(p2pkh_address_prefix("1A", Network::Bitcoin), &mut rng, 10000).unwrap();
+        assert_eq!(secret_key.public_key(), public_key);
+        assert!(public_key.p2pkh_address(Network::Bitcoin).starts_with("1A"));
+
+        let (secret_key, public_key) = search(p2tr_address_prefix("bc1pq", "bc"), &mut rng, 1000).unwrap();
+        let internal = secret_key.public_key().x_only_public_key().0;
+        let (output_key, _) = crate::taproot::output_key(&internal, None).unwrap();
+        assert_eq!(internal, public_key.x_only_public_key().0);
+        assert!(output_key.p2tr_address("bc").starts_with("bc1pq"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parallel() {
+        use super::search_parallel;
+
+        let mut rng = DeterministicRng::new(243);
+        let (secret_key, public_key) = search_parallel(compressed_hex_prefix("02ff"), &mut rng, 100_000, 4).unwrap();
+        assert_eq!(secret_key.public_key(), public_key);
+        assert_eq!(public_key.serialize()[..2], [0x02, 0xFF]);
+        assert_eq!(search_parallel(compressed_hex_prefix("04"), &mut rng, 100, 3), None);
+    }
+}
@@ -0,0 +1,143 @@
+//! Verifiable random function following the structure of ECVRF (RFC 9381)
+//!
+//! There's no standard secp256k1 ciphersuite so this module defines its own, identified by the
+//! suite string `toy-secp256k1/ECVRF-SHA256-TAI`. All hashes are tagged hashes with the tag
+//! `<suite string>/<purpose>` and all points are compressed:
+//!
+//! * `H = hash_to_curve(Y || alpha)` with the `hash_to_curve` purpose, using try-and-increment
+//!   of `TaggedHash::hash_to_curve`
+//! * `Γ = x·H`
+//! * `k = derive_proof_nonce(x, Y || H)` with the `nonce` purpose as the domain
+//! * `c` is the challenge of a `Transcript` with the `challenge` purpose as the domain and the
+//!   points `Y`, `H`, `Γ`, `k·G`, `k·H` appended under labels `Y`, `H`, `Gamma`, `U`, `V`
+//! * `s = k + c·x`
+//! * `beta = proof_to_hash(Γ)`
+//!
+//! where `x` is the secret key and `Y` the public key. The proof is serialized as 97 bytes:
+//! `Γ || c || s`. Unlike RFC 9381 the challenge is not truncated.
+
+use alloc::format;
+use crate::nonce::derive_proof_nonce;
+use crate::tagged_hash::TaggedHash;
+use crate::transcript::Transcript;
+use crate::{array_ref_32, Error, Point, PublicKey, Scalar, SecretKey, G};
+
+/// Identifies the ciphersuite, the tags of the hashes are derived from it
+pub const SUITE_STRING: &str = "toy-secp256k1/ECVRF-SHA256-TAI";
+
+fn tagged(purpose: &str) -> TaggedHash {
+    TaggedHash::new(&format!("{}/{}", SUITE_STRING, purpose))
+}
+
+/// The proof that `beta` was computed correctly
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct VrfProof {
+    gamma: Point,
+    c: Scalar,
+    s: Scalar,
+}
+
+impl VrfProof {
+    /// Parses `Γ || c || s`
+    ///
+    /// Returns `Error::InvalidSignature` if any component is invalid.
+    pub fn from_bytes(bytes: &[u8; 97]) -> Result<Self, Error> {
+        let gamma = Point::from_sec1_bytes(&bytes[..33]).map_err(|_| Error::InvalidSignature)?;
+        let c = Scalar::from_be_bytes(array_ref_32(&bytes[33..65])).ok_or(Error::InvalidSignature)?;
+        let s = Scalar::from_be_bytes(array_ref_32(&bytes[65..])).ok_or(Error::InvalidSignature)?;
+        Ok(VrfProof { gamma, c, s })
+    }
+
+    pub fn serialize(&self) -> [u8; 97] {
+        let mut bytes = [0; 97];
+        bytes[..33].copy_from_slice(&self.gamma.serialize_compressed());
+        bytes[33..65].copy_from_slice(&self.c.to_be_bytes());
+        bytes[65..].copy_from_slice(&self.s.to_be_bytes());
+        bytes
+    }
+}
+
+fn hash_to_curve(public_key: &PublicKey, alpha: &[u8]) -> Point {
+    tagged("hash_to_curve").hash_to_curve(&[&public_key.serialize(), alpha])
+}
+
+fn challenge(points: [&Point; 5]) -> Scalar {
+    let mut transcript = Transcript::new(&format!("{}/challenge", SUITE_STRING));
+    for (label, point) in ["Y", "H", "Gamma", "U", "V"].iter().zip(&points) {
+        transcript.append_point(label, point);
+    }
+    transcript.challenge_scalar("c")
+}
+
+/// Computes the VRF proof of `alpha`, the output can be obtained using `proof_to_hash`.
+pub fn prove(secret_key: &SecretKey, alpha: &[u8]) -> VrfProof {
+    let x = secret_key.to_scalar();
+    let public_key = secret_key.public_key();
+    let h = hash_to_curve(&public_key, alpha);
+    let gamma = h * x;
+    let mut transcript = public_key.serialize().to_vec();
+    transcript.extend_from_slice(&h.serialize_compressed());
+    let k = derive_proof_nonce(&x, &transcript, &format!("{}/nonce", SUITE_STRING));
+    let c = challenge([&public_key.to_point(), &h, &gamma, &(G * k), &(h * k)]);
+    VrfProof { gamma, c, s: k + c * x }
+}
+
+/// Computes the VRF output `beta` from the proof without verifying it
+pub fn proof_to_hash(proof: &VrfProof) -> [u8; 32] {
+    tagged("proof_to_hash").hash(&[&proof.gamma.serialize_compressed()])
+}
+
+/// Verifies the proof of `alpha` and returns the VRF output.
+///
+/// Returns `Error::InvalidSignature` if the proof is invalid.
+pub fn verify(public_key: &PublicKey, alpha: &[u8], proof: &VrfProof) -> Result<[u8; 32], Error> {
+    let h = hash_to_curve(public_key, alpha);
+    let u = G * proof.s + -(public_key.to_point() * proof.c);
+    let v = h * proof.s + -(proof.gamma * proof.c);
+    if challenge([&public_key.to_point(), &h, &proof.gamma, &u, &v]) != proof.c {
+        return Err(Error::InvalidSignature);
+    }
+    Ok(proof_to_hash(proof))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{proof_to_hash, prove, verify, VrfProof};
+    use crate::{Error, SecretKey};
+
+    #[test]
+    fn deterministic() {
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let proof = prove(&secret_key, b"sample");
+        assert_eq!(prove(&secret_key, b"sample"), proof);
+        let beta = verify(&secret_key.public_key(), b"sample", &proof).unwrap();
+        assert_eq!(beta, proof_to_hash(&proof));
+        assert_ne!(proof_to_hash(&prove(&secret_key, b"test")), beta);
+    }
+
+    #[test]
+    fn unique_to_key() {
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let other = SecretKey::from_bytes(&[0x43; 32]).unwrap();
+        let proof = prove(&other, b"sample");
+        assert_eq!(verify(&secret_key.public_key(), b"sample", &proof), Err(Error::InvalidSignature));
+        assert_ne!(proof_to_hash(&proof), proof_to_hash(&prove(&secret_key, b"sample")));
+        let proof = prove(&secret_key, b"sample");
+        assert_eq!(verify(&secret_key.public_key(), b"Sample", &proof), Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn serialization_round_trip() {
+        let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+        let proof = prove(&secret_key, b"");
+        let bytes = proof.serialize();
+        assert_eq!(VrfProof::from_bytes(&bytes), Ok(proof));
+        let mut corrupted = bytes;
+        corrupted[0] = 0x04;
+        assert_eq!(VrfProof::from_bytes(&corrupted), Err(Error::InvalidSignature));
+        let mut corrupted = bytes;
+        corrupted[96] ^= 1;
+        let corrupted = VrfProof::from_bytes(&corrupted).unwrap();
+        assert_eq!(verify(&secret_key.public_key(), b"", &corrupted), Err(Error::InvalidSignature));
+    }
+}
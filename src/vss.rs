@@ -0,0 +1,78 @@
+//! Feldman's verifiable secret sharing
+//!
+//! Extends Shamir's secret sharing with commitments `Cⱼ = aⱼ·G` to the coefficients of the
+//! polynomial. Each participant can check that its share lies on the committed polynomial so a
+//! dealer handing out inconsistent shares gets caught. The first commitment is the public key of
+//! the secret.
+
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+use crate::sss::{evaluate, random_polynomial, Share};
+use crate::{Error, Point, Scalar, G};
+
+/// Splits the secret like `sss::split` and additionally returns the commitments to the
+/// coefficients of the polynomial which should be published to all participants.
+pub fn split<R: RngCore + CryptoRng>(secret: Scalar, threshold: u16, shares: u16, rng: &mut R) -> Result<(Vec<Share>, Vec<Point>), Error> {
+    if threshold == 0 || threshold > shares {
+        return Err(Error::InvalidThreshold);
+    }
+    let coefficients = random_polynomial(secret, threshold, rng);
+    let shares = (1..=shares).map(|index| Share::new(index, threshold, evaluate(&coefficients, index))).collect();
+    let commitments = coefficients.iter().map(|coefficient| G * *coefficient).collect();
+    Ok((shares, commitments))
+}
+
+impl Share {
+    /// Checks that `share·G == Σ indexʲ·Cⱼ`.
+    ///
+    /// Also returns `false` if the number of commitments doesn't match the threshold.
+    pub fn verify(&self, commitments: &[Point]) -> bool {
+        if commitments.len() != usize::from(self.threshold()) {
+            return false;
+        }
+        // Horner's method
+        let x = Scalar::from(u64::from(self.index()));
+        let expected = commitments.iter().rev().fold(Point::AT_INFINITY, |acc, commitment| acc * x + *commitment);
+        (G * self.to_scalar()).eq_ct(&expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split;
+    use crate::sss::{reconstruct, Share};
+    use crate::test_utils::DeterministicRng;
+    use crate::{Scalar, SecretKey};
+
+    #[test]
+    fn honest_shares_verify() {
+        let mut rng = DeterministicRng::new(1);
+        let secret = Scalar::random(&mut rng);
+        let (shares, commitments) = split(secret, 3, 5, &mut rng).unwrap();
+        assert_eq!(commitments.len(), 3);
+        assert_eq!(commitments[0], SecretKey::from_scalar(secret).unwrap().public_key().to_point());
+        for share in &shares {
+            assert!(share.verify(&commitments), "share {}", share.index());
+        }
+        assert!(!shares[0].verify(&commitments[..2]));
+        assert_eq!(reconstruct(&shares[1..4]), Ok(secret));
+    }
+
+    #[test]
+    fn tampered_share_detected() {
+        let mut rng = DeterministicRng::new(2);
+        let secret = Scalar::random(&mut rng);
+        let (shares, commitments) = split(secret, 2, 3, &mut rng).unwrap();
+        let mut bytes = shares[1].to_bytes();
+        bytes[35] ^= 1;
+        let tampered = Share::from_bytes(&bytes).unwrap();
+        assert!(!tampered.verify(&commitments));
+        let reconstructed = reconstruct(&[shares[0], tampered]).unwrap();
+        assert_ne!(reconstructed, secret);
+
+        // a valid value presented under another index
+        let mut bytes = shares[1].to_bytes();
+        bytes[1] = 3;
+        assert!(!Share::from_bytes(&bytes).unwrap().verify(&commitments));
+    }
+}
@@ -0,0 +1,107 @@
+//! Best-effort erasure of secret intermediates
+//!
+//! The signing code keeps the secret key scalar, the nonce `k`, its inverse, the BIP340 masked key
+//! `t` and the RFC 6979 `K` and `V` values in `Zeroizing` containers or in types overwriting
+//! them on drop. The writes are volatile so the compiler can't optimize them away.
+//!
+//! Not covered - and not fixable without changing the public API or the dependencies:
+//!
+//! * `SecretKey` and `Scalar` are `Copy` so the values the caller holds are never erased
+//! * copies made by moves, registers and temporaries inside the arithmetic (e.g. the limbs of the
+//!   intermediate products of `U256`)
+//! * with the `sha2` feature, the internal states of SHA256 and HMAC in the hash crates, which
+//!   contain the secret key and `t` while they are being hashed - the built-in implementation
+//!   wipes them
+
+use core::ops::{Deref, DerefMut};
+use core::sync::atomic::{compiler_fence, Ordering};
+use crate::Scalar;
+
+/// Value that can be overwritten with zeros
+pub(crate) trait Wipe {
+    fn wipe(&mut self);
+}
+
+impl Wipe for Scalar {
+    fn wipe(&mut self) {
+        // SAFETY: the pointer comes from a valid mutable reference
+        unsafe {
+            core::ptr::write_volatile(self, Scalar::ZERO);
+        }
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+impl<const N: usize> Wipe for [u8; N] {
+    fn wipe(&mut self) {
+        // SAFETY: the pointer comes from a valid mutable reference
+        unsafe {
+            core::ptr::write_volatile(self, [0; N]);
+        }
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+impl<const N: usize> Wipe for [u32; N] {
+    fn wipe(&mut self) {
+        // SAFETY: the pointer comes from a valid mutable reference
+        unsafe {
+            core::ptr::write_volatile(self, [0; N]);
+        }
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+/// Wipes the contained value when dropped
+pub(crate) struct Zeroizing<T: Wipe>(T);
+
+impl<T: Wipe> Zeroizing<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Zeroizing(value)
+    }
+}
+
+impl<T: Wipe> Deref for Zeroizing<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Wipe> DerefMut for Zeroizing<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Wipe> Drop for Zeroizing<T> {
+    fn drop(&mut self) {
+        self.0.wipe();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::ManuallyDrop;
+    use super::{Wipe, Zeroizing};
+    use crate::Scalar;
+
+    /// Runs the destructor in place and returns the memory left behind
+    fn contents_after_drop<T: Wipe + Copy>(mut value: ManuallyDrop<Zeroizing<T>>) -> T {
+        // SAFETY: the value is only read after being dropped, the memory still holds a valid
+        // bit pattern
+        unsafe {
+            ManuallyDrop::drop(&mut value);
+        }
+        value.0
+    }
+
+    #[test]
+    fn wiped_on_drop() {
+        let bytes = ManuallyDrop::new(Zeroizing::new([0x42u8; 32]));
+        assert_eq!(contents_after_drop(bytes), [0; 32]);
+        let scalar = ManuallyDrop::new(Zeroizing::new(Scalar::from(42)));
+        assert_eq!(contents_after_drop(scalar), Scalar::ZERO);
+    }
+}
@@ -0,0 +1,124 @@
+//! Checks that signing, verification and the batch operations taking scratch space don't allocate
+//!
+//! The global allocator counts allocations of the current thread so that other tests running in
+//! parallel don't interfere.
+
+#![cfg(feature = "hashes")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use toy_secp256k1::{ecdsa, multiexp, schnorr, Keypair, Message, Point, Scalar, SecretKey, Zp, G, U256};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // `try_with` because the thread local may be already destroyed when the thread exits
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the result of `f` and the number of allocations it made
+fn count<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let res = f();
+    (res, ALLOCATIONS.with(Cell::get) - before)
+}
+
+/// Deterministic xorshift generator - NOT secure, only makes tests reproducible
+struct TestRng(u64);
+
+impl rand_core::RngCore for TestRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl rand_core::CryptoRng for TestRng {}
+
+#[test]
+fn counts_allocations() {
+    let (_, allocations) = count(|| Vec::<u8>::with_capacity(1));
+    assert_eq!(allocations, 1);
+}
+
+#[test]
+fn ecdsa() {
+    let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+    let public_key = secret_key.public_key();
+    let msg = Message::from_digest([0x01; 32]);
+    let (signature, allocations) = count(|| ecdsa::sign(&msg, &secret_key));
+    assert_eq!(allocations, 0, "signing");
+    let (res, allocations) = count(|| ecdsa::verify(&msg, &signature, &public_key));
+    assert_eq!((res, allocations), (Ok(()), 0), "verification");
+
+    let items = [
+        ([0x01; 32], ecdsa::sign_recoverable(&msg, &secret_key), public_key),
+        ([0x02; 32], ecdsa::sign_recoverable(&Message::from_digest([0x02; 32]), &secret_key), public_key),
+    ];
+    let mut scratch = [(U256::zero(), G); 5];
+    let (res, allocations) = count(|| ecdsa::verify_batch_with_scratch(&items, &mut TestRng(1), &mut scratch));
+    assert_eq!((res, allocations), (Ok(()), 0), "batch verification");
+}
+
+#[test]
+fn schnorr() {
+    let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap());
+    let (public_key, _) = keypair.x_only_public_key();
+    let msg = Message::from_digest([0x01; 32]);
+    let (signature, allocations) = count(|| schnorr::sign(&msg, &keypair, &[0; 32]));
+    assert_eq!(allocations, 0, "signing");
+    let (res, allocations) = count(|| schnorr::verify(&msg, &signature, &public_key));
+    assert_eq!((res, allocations), (Ok(()), 0), "verification");
+
+    let other = schnorr::sign(&Message::from_digest([0x02; 32]), &keypair, &[0; 32]);
+    let items = [(&[0x01; 32], &signature, &public_key), (&[0x02; 32], &other, &public_key)];
+    let mut scratch = [(U256::zero(), G); 5];
+    let (res, allocations) = count(|| schnorr::verify_batch_with_scratch(&items, &mut TestRng(1), &mut scratch));
+    assert_eq!((res, allocations), (Ok(()), 0), "batch verification");
+}
+
+#[test]
+fn batch_operations() {
+    let mut scalars = [Scalar::from(3), Scalar::from(5), -Scalar::ONE];
+    let (res, allocations) = count(|| Scalar::batch_invert_in_place(&mut scalars));
+    assert_eq!((res, allocations), (Ok(()), 0), "scalar inversion");
+    let mut elements = [G.x(), G.y()];
+    let (res, allocations) = count(|| Zp::batch_invert_in_place(&mut elements));
+    assert_eq!((res, allocations), (Ok(()), 0), "field inversion");
+
+    let pairs = [(U256::from(3), G), (U256::from(5), G * 7)];
+    let mut scratch = [Point::AT_INFINITY; multiexp::SCRATCH_PER_POINT * 2];
+    let mut res = Point::AT_INFINITY;
+    let ((), allocations) = count(|| multiexp::multiexp_into(&mut res, &pairs, &mut scratch));
+    assert_eq!(allocations, 0, "multiexp");
+    assert_eq!(res, G * 38);
+}
@@ -0,0 +1,125 @@
+//! Uses the core API from a `no_std` crate
+//!
+//! Build with `--no-default-features` (optionally with `hashes`) to check that the library itself
+//! compiles without `std`, this crate checks that using it doesn't need `std` either.
+
+#![no_std]
+
+use toy_secp256k1::nonce::RngNonce;
+#[cfg(feature = "hashes")]
+use toy_secp256k1::Keypair;
+use toy_secp256k1::{ecdh, ecdsa, multiexp, multiexp_into, Error, Message, Point, PublicKey, Scalar, SecretKey, Zp, G, U256};
+
+/// Deterministic xorshift generator - NOT secure, only makes tests reproducible
+struct TestRng(u64);
+
+impl rand_core::RngCore for TestRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl rand_core::CryptoRng for TestRng {}
+
+#[test]
+fn arithmetic() {
+    let a = Scalar::from(3);
+    let b = Scalar::from(5);
+    assert_eq!(G * a + G * b, G * (a + b));
+    assert_eq!(G + -G, Point::AT_INFINITY);
+}
+
+#[test]
+fn keys() {
+    let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+    let public_key = secret_key.public_key();
+    assert_eq!(PublicKey::from_sec1_bytes(&public_key.serialize_uncompressed()), Ok(public_key));
+    assert_eq!(SecretKey::from_bytes(&[0; 32]), Err(Error::InvalidSecretKey));
+    let (x_only, _) = public_key.x_only_public_key();
+    assert_eq!(x_only.serialize()[..], public_key.serialize()[1..]);
+}
+
+#[test]
+fn ecdsa_and_ecdh() {
+    let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+    let other = SecretKey::from_bytes(&[0x43; 32]).unwrap();
+    let msg = Message::from_digest([0x01; 32]);
+    let signature = ecdsa::sign_with(&msg, &secret_key, &mut RngNonce(TestRng(1)));
+    assert_eq!(ecdsa::verify(&msg, &signature, &secret_key.public_key()), Ok(()));
+    let (der, len) = signature.to_der_array();
+    assert_eq!(ecdsa::Signature::from_der(&der[..len]), Ok(signature));
+    assert_eq!(
+        ecdh::shared_secret_with(&secret_key, &other.public_key(), |point| point.serialize_compressed()),
+        ecdh::shared_secret_with(&other, &secret_key.public_key(), |point| point.serialize_compressed()),
+    );
+}
+
+#[cfg(feature = "hashes")]
+#[test]
+fn signatures() {
+    let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+    let msg = Message::from_digest([0x01; 32]);
+    let signature = ecdsa::sign(&msg, &secret_key);
+    assert_eq!(ecdsa::verify(&msg, &signature, &secret_key.public_key()), Ok(()));
+
+    let keypair = Keypair::from_secret_key(&secret_key);
+    let msg = Message::from_hashed_data(b"message");
+    let signature = toy_secp256k1::schnorr::sign(&msg, &keypair, &[0; 32]);
+    assert!(toy_secp256k1::schnorr::verify(&msg, &signature, &keypair.x_only_public_key().0).is_ok());
+}
+
+#[test]
+fn batch_operations() {
+    let mut scalars = [Scalar::from(3), Scalar::from(5), -Scalar::ONE];
+    Scalar::batch_invert_in_place(&mut scalars).unwrap();
+    assert_eq!(scalars[0] * Scalar::from(3), Scalar::ONE);
+    assert_eq!(scalars[2], -Scalar::ONE);
+    let mut elements = [G.x(), G.y()];
+    Zp::batch_invert_in_place(&mut elements).unwrap();
+    assert_eq!(elements[1] * G.y(), Zp::ONE);
+
+    let pairs = [(U256::from(3), G), (U256::from(5), G * 7), (U256::max_value(), -G)];
+    let mut scratch = [Point::AT_INFINITY; toy_secp256k1::multiexp::SCRATCH_PER_POINT * 2];
+    let mut res = Point::AT_INFINITY;
+    multiexp_into(&mut res, &pairs, &mut scratch);
+    assert_eq!(res, multiexp(&pairs));
+
+    let secret_key = SecretKey::from_bytes(&[0x42; 32]).unwrap();
+    let mut rng = TestRng(1);
+    let items = [
+        ([0x01; 32], ecdsa::sign_recoverable_with(&Message::from_digest([0x01; 32]), &secret_key, &mut RngNonce(&mut rng)), secret_key.public_key()),
+        ([0x02; 32], ecdsa::sign_recoverable_with(&Message::from_digest([0x02; 32]), &secret_key, &mut RngNonce(&mut rng)), secret_key.public_key()),
+    ];
+    let mut scratch = [(U256::zero(), G); 3];
+    assert_eq!(ecdsa::verify_batch_with_scratch(&items, &mut rng, &mut scratch), Ok(()));
+}
+
+#[cfg(feature = "hashes")]
+#[test]
+fn schnorr_batch() {
+    let keypair = Keypair::from_secret_key(&SecretKey::from_bytes(&[0x42; 32]).unwrap());
+    let (public_key, _) = keypair.x_only_public_key();
+    let signatures = [
+        toy_secp256k1::schnorr::sign(&Message::from_digest([0x01; 32]), &keypair, &[0; 32]),
+        toy_secp256k1::schnorr::sign(&Message::from_digest([0x02; 32]), &keypair, &[0; 32]),
+    ];
+    let items = [(&[0x01; 32], &signatures[0], &public_key), (&[0x02; 32], &signatures[1], &public_key)];
+    let mut scratch = [(U256::zero(), G); 5];
+    assert_eq!(toy_secp256k1::schnorr::verify_batch_with_scratch(&items, &mut TestRng(1), &mut scratch), Ok(()));
+}